@@ -198,8 +198,8 @@ export function CangkulanGame({
   const isWaitingForOpponent = (() => {
     if (!gameState || gameState.lifecycle_state === LIFECYCLE.FINISHED) return false;
     if (gameState.lifecycle_state === LIFECYCLE.SEED_COMMIT) {
-      if (isPlayer1 && gameState.seed_commit1 != null && gameState.seed_commit2 == null) return true;
-      if (isPlayer2 && gameState.seed_commit2 != null && gameState.seed_commit1 == null) return true;
+      if (isPlayer1 && gameState.seed_committed1 && !gameState.seed_committed2) return true;
+      if (isPlayer2 && gameState.seed_committed2 && !gameState.seed_committed1) return true;
     }
     if (gameState.lifecycle_state === LIFECYCLE.SEED_REVEAL) {
       if (isPlayer1 && gameState.seed_revealed1 && !gameState.seed_revealed2) return true;
@@ -10,13 +10,13 @@ interface GameTableProps {
 }
 
 export function GameTable({ gameState, isPlayer1, isPlayer2 }: GameTableProps) {
-  const drawPileSize = gameState.draw_pile?.length ?? 0;
+  const drawPileSize = gameState.draw_pile_size;
   const flippedCard = gameState.flipped_card ?? null;
   const trickState = gameState.trick_state ?? TRICK.NONE;
 
   // During commit phase, show "committed" indicator if the player has committed
-  const p1Committed = gameState.play_commit1 != null;
-  const p2Committed = gameState.play_commit2 != null;
+  const p1Committed = gameState.play_committed1;
+  const p2Committed = gameState.play_committed2;
 
   // Determine if we're in commit or reveal phase
   const isCommitPhase = trickState === TRICK.COMMIT_WAIT_BOTH
@@ -172,7 +172,7 @@ export function PlayingPhase({
   const opponentHandSize = opponentHandSizeOverride ?? rawOpponentSize;
   const trickSuit = gameState.trick_suit ?? null;
   const trickState = gameState.trick_state ?? TRICK.NONE;
-  const drawPileSize = gameState.draw_pile?.length ?? 0;
+  const drawPileSize = gameState.draw_pile_size;
 
   // Commit phase: player can commit if state is COMMIT_WAIT_BOTH or waiting for them
   const isMyCommitTurn = trickState === TRICK.COMMIT_WAIT_BOTH
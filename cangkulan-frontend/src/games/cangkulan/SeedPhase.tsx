@@ -93,12 +93,12 @@ export function SeedPhase({
   const isRevealPhase = gameState.lifecycle_state === LIFECYCLE.SEED_REVEAL;
 
   const hasCommittedSeed = isPlayer1
-    ? gameState.seed_commit1 != null
+    ? gameState.seed_committed1
     : isPlayer2
-      ? gameState.seed_commit2 != null
+      ? gameState.seed_committed2
       : false;
   const hasRevealedSeed = isPlayer1 ? gameState.seed_revealed1 : isPlayer2 ? gameState.seed_revealed2 : false;
-  const bothCommitted = gameState.seed_commit1 != null && gameState.seed_commit2 != null;
+  const bothCommitted = gameState.seed_committed1 && gameState.seed_committed2;
   const bothRevealed = gameState.seed_revealed1 && gameState.seed_revealed2;
   const savedSeed = loadSeedData(sessionId, userAddress);
 
@@ -117,14 +117,14 @@ export function SeedPhase({
             isYou={isPlayer1}
             address={gameState.player1}
             points={gameState.player1_points}
-            status={gameState.seed_commit1 != null ? 'committed' : 'waiting'}
+            status={gameState.seed_committed1 ? 'committed' : 'waiting'}
           />
           <PlayerStatusCard
             label="Player 2"
             isYou={isPlayer2}
             address={gameState.player2}
             points={gameState.player2_points}
-            status={gameState.seed_commit2 != null ? 'committed' : 'waiting'}
+            status={gameState.seed_committed2 ? 'committed' : 'waiting'}
           />
         </div>
 
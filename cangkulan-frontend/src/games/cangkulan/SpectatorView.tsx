@@ -112,7 +112,7 @@ export function SpectatorView({ sessionId, onExit }: SpectatorViewProps) {
   }
 
   const trickState = gameState.trick_state ?? TRICK.NONE;
-  const drawPileSize = gameState.draw_pile?.length ?? 0;
+  const drawPileSize = gameState.draw_pile_size;
   const isFinished = gameState.lifecycle_state === LIFECYCLE.FINISHED;
 
   return (
@@ -194,22 +194,22 @@ export function SpectatorView({ sessionId, onExit }: SpectatorViewProps) {
             {gameState.lifecycle_state === LIFECYCLE.SEED_COMMIT ? '🎲 Seed Commitment' : '🔓 Seed Reveal'}
           </p>
           <div className="flex justify-center gap-4">
-            <span className={`px-3 py-1 rounded-full text-xs font-bold ${gameState.seed_commit1 != null
+            <span className={`px-3 py-1 rounded-full text-xs font-bold ${gameState.seed_committed1
               ? 'bg-green-100 text-green-700'
               : 'bg-gray-100 text-gray-500'
               }`}>
               P1: {gameState.lifecycle_state === LIFECYCLE.SEED_REVEAL
                 ? (gameState.seed_revealed1 ? '✓ Revealed' : '🔒 Locked')
-                : (gameState.seed_commit1 != null ? '✓ Committed' : 'Waiting')
+                : (gameState.seed_committed1 ? '✓ Committed' : 'Waiting')
               }
             </span>
-            <span className={`px-3 py-1 rounded-full text-xs font-bold ${gameState.seed_commit2 != null
+            <span className={`px-3 py-1 rounded-full text-xs font-bold ${gameState.seed_committed2
               ? 'bg-green-100 text-green-700'
               : 'bg-gray-100 text-gray-500'
               }`}>
               P2: {gameState.lifecycle_state === LIFECYCLE.SEED_REVEAL
                 ? (gameState.seed_revealed2 ? '✓ Revealed' : '🔒 Locked')
-                : (gameState.seed_commit2 != null ? '✓ Committed' : 'Waiting')
+                : (gameState.seed_committed2 ? '✓ Committed' : 'Waiting')
               }
             </span>
           </div>
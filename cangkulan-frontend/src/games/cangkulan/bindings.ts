@@ -39,6 +39,141 @@ export const networks = {
 } as const
 
 
+/**
+ * One player's share of a finished session's stakes.
+ */
+export interface Payout {
+  amount: i128;
+  claimed: boolean;
+  player: string;
+}
+
+
+/**
+ * The bot's view of the trick it is asked to play.
+ */
+export interface BotTurn {
+  hand: Array<u32>;
+  session_id: u32;
+  trick: u32;
+  trick_suit: u32;
+}
+
+
+/**
+ * The action and salt behind the bot's commit.
+ */
+export interface BotReveal {
+  card_id: u32;
+  salt: Buffer;
+}
+
+
+/**
+ * An open challenge awaiting an opponent.
+ */
+export interface Challenge {
+  creator: string;
+  expires_ledger: u32;
+  points: i128;
+  rake_bps: u32;
+  stake_token: Option<string>;
+  treasury: Option<string>;
+}
+
+
+/**
+ * Card encoding and size bounds for the Mode 7 and 8 checks. See the
+ * crate docs.
+ */
+export interface DeckParams {
+  /**
+ * Cards per suit; `deck_size` is a whole number of suits.
+ */
+cards_per_suit: u32;
+  /**
+ * Cards in the deck; ids run `0..deck_size`.
+ */
+deck_size: u32;
+  /**
+ * Largest Mode 8 hand.
+ */
+max_hand_size: u32;
+  /**
+ * Largest Mode 7/12 ring, and the member count of a padded ring.
+ */
+max_ring_size: u32;
+}
+
+
+/**
+ * House rules of one game, fixed at start. `start_game` and
+ * `start_multiplayer_game` use `GameConfig::standard()`; other rules are
+ * chosen with `start_game_with_config`.
+ */
+export interface GameConfig {
+  cards_per_suit: u32;
+  deck_size: u32;
+  draw_until_follow: boolean;
+  hand_size: u32;
+  penalty_draw: u32;
+  strict_reveal: boolean;
+  teams: boolean;
+  tie_break: u32;
+  time_bank_ledgers: u32;
+  timeout_ledgers: u32;
+  zk_required: boolean;
+}
+
+
+/**
+ * A match in progress or decided.
+ */
+export interface MatchState {
+  players: Array<string>;
+  round: u32;
+  scores: Array<u32>;
+  session_id: u32;
+  target: u32;
+  winner: u32;
+}
+
+
+/**
+ * One player's seat. Slot `n` (1-based, as in trick states, outcome codes
+ * and events) is `CangkulanGame::players[n - 1]`.
+ */
+export interface PlayerSlot {
+  hand: Array<u32>;
+  play_commit: Option<Buffer>;
+  play_revealed: boolean;
+  player: string;
+  points: i128;
+  seed_commit: Option<Buffer>;
+  seed_hash: Option<Buffer>;
+  seed_mode: Option<u32>;
+  seed_revealed: boolean;
+  trick_card: Option<u32>;
+  tricks_won: u32;
+  zk_play: boolean;
+}
+
+
+/**
+ * A seat as `get_game` shows it to anyone: counts and progress, no cards.
+ */
+export interface PublicSeat {
+  hand_size: u32;
+  play_committed: boolean;
+  play_revealed: boolean;
+  player: string;
+  points: i128;
+  seed_committed: boolean;
+  seed_revealed: boolean;
+  tricks_won: u32;
+}
+
+
 /**
  * Compact summary of a finished game, stored persistently per player.
  */
@@ -52,41 +187,245 @@ export interface GameSummary {
 }
 
 
+/**
+ * A page of a player's history. `next_cursor` is where the next call
+ * resumes, `None` once the whole history has been read.
+ */
+export interface HistoryPage {
+  entries: Array<GameSummary>;
+  next_cursor: Option<u32>;
+}
+
+
+/**
+ * Token escrow for stakes. While set, `start_game` transfers each stake
+ * into the contract in `token`, and `claim_winnings` pays the pot out
+ * less `rake_bps` of a won pot, which is credited to `treasury` for
+ * `withdraw_treasury`. New games
+ * snapshot this at `start_game`, so changing it never affects games in
+ * progress.
+ */
+export interface StakeEscrow {
+  rake_bps: u32;
+  token: string;
+  treasury: string;
+}
+
+
+/**
+ * One resolved trick, as chained into the game transcript. `cards` has
+ * one entry per seat, `CANNOT_FOLLOW_SENTINEL` if absent; `winner` is 0
+ * for a waste trick. `ledger` is not part of the transcript.
+ */
+export interface TrickRecord {
+  cards: Array<u32>;
+  flipped: u32;
+  ledger: u32;
+  winner: u32;
+}
+
+
+/**
+ * The state a settlement will finish with, unless a later one arrives
+ * by `challenge_ends`.
+ */
+export interface ChannelClaim {
+  challenge_ends: u32;
+  state: ChannelState;
+}
+
+
+/**
+ * A game state every seat has signed off-chain.
+ */
+export interface ChannelState {
+  hands_hash: Buffer;
+  move_counter: u32;
+  outcome: u32;
+  session_id: u32;
+  tricks: Array<u32>;
+}
+
+
+/**
+ * Every contract a client depends on, resolved in one read. Optional
+ * integrations are `None` until the admin configures them.
+ */
+export interface Integrations {
+  /**
+ * keccak256 of the XDR-encoded rule config; changes whenever it does.
+ */
+config_hash: Buffer;
+  hub: string;
+  leaderboard: Option<string>;
+  mode_verifiers: Map<u32, string>;
+  verifier: string;
+}
+
+
+/**
+ * Stakes awaiting `claim_winnings`, recorded by `finalize_game`.
+ */
+export interface PendingClaim {
+  expires_ledger: u32;
+  payouts: Array<Payout>;
+  rake: i128;
+  token: Option<string>;
+  treasury: Option<string>;
+}
+
+
+/**
+ * A pending rematch of a finished session. Seats join `agreed` through
+ * `request_rematch` / `accept_rematch`; the rematch starts as soon as
+ * every seat has agreed. The offer carries the seats, stakes and terms of
+ * the finished game, so it can be accepted or cancelled after that game
+ * has expired.
+ */
+export interface RematchOffer {
+  agreed: Array<string>;
+  config: GameConfig;
+  players: Array<string>;
+  points: Array<i128>;
+  rake_bps: u32;
+  rematch_id: u32;
+  stake_token: Option<string>;
+  treasury: Option<string>;
+}
+
+
+/**
+ * One player's half of a trick for `submit_trick`: a legacy play commit
+ * and its opening, signed by the player (or its session delegate) ahead
+ * of time.
+ */
+export interface TrickPayload {
+  card_id: u32;
+  commit_hash: Buffer;
+  nonce: u32;
+  player: string;
+  salt: Buffer;
+}
+
+
+/**
+ * What a seated player may do next, from `get_valid_actions`.
+ */
+export interface ValidActions {
+  next_call: string;
+  nonce: u32;
+  playable: Array<u32>;
+}
+
 
 export interface CangkulanGame {
   action_nonce: u32;
+  /**
+ * House rules fixed at start
+ */
+config: GameConfig;
   deadline_ledger: Option<u32>;
   deadline_nonce: Option<u32>;
   draw_pile: Array<u32>;
   flipped_card: Option<u32>;
-  hand1: Array<u32>;
-  hand2: Array<u32>;
+  /**
+ * Seat that forfeited, 0 if none
+ */
+forfeited_by: u32;
   /**
  * Ledger sequence of the last tick_timeout call (rate limiting)
  */
 last_tick_ledger: u32;
   lifecycle_state: u32;
+  max_hand_size: u32;
+  /**
+ * Per-mode verifiers pinned at start, overriding `verifier`
+ */
+mode_verifiers: Map<u32, string>;
   outcome: u32;
-  play_commit1: Option<Buffer>;
-  play_commit2: Option<Buffer>;
-  player1: string;
-  player1_points: i128;
-  player2: string;
-  player2_points: i128;
-  seed_commit1: Option<Buffer>;
-  seed_commit2: Option<Buffer>;
-  seed_hash1: Option<Buffer>;
-  seed_hash2: Option<Buffer>;
-  seed_revealed1: boolean;
-  seed_revealed2: boolean;
-  trick_card1: Option<u32>;
-  trick_card2: Option<u32>;
+  overflow_rule: u32;
+  /**
+ * 2–4 seats in play order
+ */
+players: Array<PlayerSlot>;
+  rake_bps: u32;
+  /**
+ * Layout version, `GAME_SCHEMA_VERSION` once read
+ */
+schema_version: u32;
+  stake_token: Option<string>;
+  /**
+ * Blitz time bank left per seat, in ledgers; empty without a time bank
+ */
+time_banks: Array<u32>;
+  /**
+ * Running keccak256 chain over the deal seed and every resolved trick
+ */
+transcript: Buffer;
+  treasury: Option<string>;
+  /**
+ * The last `TRICK_LOG_LEN` resolved tricks, oldest first
+ */
+trick_log: Array<TrickRecord>;
+  /**
+ * Transcript just before the oldest trick in `trick_log`, so chaining
+ * the logged tricks onto it reproduces `transcript`
+ */
+trick_log_base: Buffer;
   trick_state: u32;
   trick_suit: Option<u32>;
-  tricks_won1: u32;
-  tricks_won2: u32;
-  zk_play1: boolean;
-  zk_play2: boolean;
+  /**
+ * Tricks dropped from the front of `trick_log`
+ */
+tricks_pruned: u32;
+  /**
+ * Ledger sequence of the last write; the entry expires
+ * `GAME_TTL_LEDGERS` after it
+ */
+updated_ledger: u32;
+  /**
+ * ZK verifier pinned at start; `set_verifier` affects new games only
+ */
+verifier: string;
+  /**
+ * Practice game: seat 2 is a `CangkulanBot` the game plays for
+ */
+vs_bot: boolean;
+}
+
+
+/**
+ * Seed entropy rules, stored in instance storage by each contract that
+ * checks seeds. See the crate docs for what each field rejects.
+ */
+export interface EntropyPolicy {
+  /**
+ * Upper bound on the chi-square statistic of the 64 nibbles over 16
+ * bins; 0 disables. Uniform seeds average 15 (15 degrees of freedom).
+ */
+max_chi_square: u32;
+  /**
+ * Reject seeds periodic with any period up to this (0..=16); 0 disables.
+ */
+max_period: u32;
+  /**
+ * Longest allowed run of one repeated byte; 0 disables the check.
+ */
+max_run: u32;
+  /**
+ * Minimum number of distinct byte values (1..=32).
+ */
+min_distinct: u32;
+}
+
+
+/**
+ * Hand-size cap applied to penalty draws. New games snapshot this at
+ * `start_game`, so changing it never affects games in progress.
+ */
+export interface HandCapConfig {
+  max_hand_size: u32;
+  overflow_rule: u32;
 }
 
 
@@ -126,17 +465,110 @@ export const CangkulanError = {
   33: {message:"ZkPlaySetEmpty"},
   34: {message:"ZkPlayOpeningMismatch"},
   35: {message:"ZkCangkulProofInvalid"},
-  38: {message:"TickTooSoon"}
+  38: {message:"TickTooSoon"},
+  39: {message:"NothingToClaim"},
+  40: {message:"ClaimExpired"},
+  41: {message:"ClaimNotExpired"},
+  42: {message:"InvalidEmote"},
+  43: {message:"InvalidGuestSponsorship"},
+  44: {message:"GuestNotSponsored"},
+  45: {message:"InvalidHandCap"},
+  46: {message:"InvalidSeedProofMode"},
+  47: {message:"SeedProofModeMismatch"},
+  48: {message:"GameNotFinished"},
+  49: {message:"InvalidEntropyPolicy"},
+  50: {message:"OutcomeNotForced"},
+  51: {message:"InvalidOverrideDuration"},
+  52: {message:"OverrideNotScheduled"},
+  53: {message:"TimelockNotElapsed"},
+  54: {message:"InvalidPlayerCount"},
+  55: {message:"InvalidStakeEscrow"},
+  56: {message:"RematchNotRequested"},
+  57: {message:"RematchAlreadyAgreed"},
+  58: {message:"ChallengeNotFound"},
+  59: {message:"ChallengeExpired"},
+  60: {message:"InvalidChallengeExpiry"},
+  61: {message:"LobbyFull"},
+  62: {message:"InvalidGameConfig"},
+  63: {message:"ZkPlayRequired"},
+  64: {message:"DeckMismatch"},
+  65: {message:"RevealDeadlinePassed"},
+  66: {message:"UnroutableProofMode"},
+  67: {message:"ContractPaused"},
+  68: {message:"UnsupportedGameSchema"},
+  69: {message:"InvalidDelegate"},
+  70: {message:"ChannelNotOpen"},
+  71: {message:"InvalidChannelState"},
+  72: {message:"StaleChannelState"},
+  73: {message:"ChallengeWindowOpen"},
+  74: {message:"NoChannelClaim"},
+  75: {message:"TimeBankExhausted"},
+  76: {message:"MatchNotFound"},
+  77: {message:"MatchOver"},
+  78: {message:"InvalidMatchTarget"},
+  79: {message:"AuditBlindingCount"},
+  80: {message:"ShuffleDisputeRejected"},
+  81: {message:"MalformedReplay"},
+  82: {message:"ChannelKeysLocked"},
+  83: {message:"ChannelSuperseded"},
+  84: {message:"ReservedSessionId"},
+  85: {message:"TooManyChallenges"}
 }
 
 
+/**
+ * The intentionally public part of a game, returned by `get_game`. It is
+ * built field by field rather than redacted from `CangkulanGame`, so a
+ * field added to the game stays private until it is added here. The
+ * flipped card is face up on the table; hands, the pile and trick cards
+ * only appear as counts or flags.
+ */
+export interface PublicGameView {
+  action_nonce: u32;
+  config: GameConfig;
+  deadline_ledger: Option<u32>;
+  draw_pile_size: u32;
+  flipped_card: Option<u32>;
+  forfeited_by: u32;
+  lifecycle_state: u32;
+  outcome: u32;
+  players: Array<PublicSeat>;
+  stake_token: Option<string>;
+  time_banks: Array<u32>;
+  trick_state: u32;
+  trick_suit: Option<u32>;
+  vs_bot: boolean;
+}
 
 
+/**
+ * A sponsor's allowance for an ephemeral guest key. Games the guest starts
+ * are reported to the Game Hub under `sponsor` until `games_left` runs out.
+ */
+export interface GuestSponsorship {
+  games_left: u32;
+  sponsor: string;
+}
 
 
+/**
+ * Per-player notification opt-ins, read by relays and by the contract's own
+ * event paths. A player with no stored record gets `Default` (all off).
+ */
+export interface NotificationPrefs {
+  deadline_warnings: boolean;
+  mute_emotes: boolean;
+  rematch_offers: boolean;
+}
 
 
-
+/**
+ * An emergency verifier override waiting out its timelock.
+ */
+export interface PendingVerifierOverride {
+  duration_ledgers: u32;
+  executable_ledger: u32;
+}
 
 
 export interface Client {
@@ -145,15 +577,36 @@ export interface Client {
    * Forfeit the game. The caller immediately loses.
    * 
    * This allows a player to withdraw from an active game at any point.
-   * The opponent is declared the winner. This is irreversible.
+   * The opponent is declared the winner; with more seats, the best of
+   * the remaining players by the usual ranking. This is irreversible.
+   * 
+   * The game records the seat in `forfeited_by`. Leaving once the cards
+   * are dealt is a rage-quit: the Leaderboard is told with its
+   * `OUTCOME_FORFEIT` flag and takes extra rating off the quitter.
+   * Leaving during the seed phases, before anyone has seen a hand, is
+   * reported as an ordinary loss.
    */
   forfeit: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a get_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Protocol fee on won pots, in basis points: the escrow's rake, or 0
+   * while stakes are not escrowed.
+   */
+  get_fee: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
+  /**
+   * Construct and simulate a set_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Change the escrow's rake for games started from now on, keeping its
+   * token and treasury. At most `MAX_RAKE_BPS`; needs an escrow.
+   */
+  set_fee: ({fee_bps}: {fee_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -166,37 +619,80 @@ export interface Client {
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game state with both hands redacted for active games.
-   * 
-   * Returns full state (including hands) only for FINISHED games.
-   * During active games, hands are cleared to prevent card snooping
-   * via unauthenticated RPC queries. Use `get_game_view` with a viewer
-   * address to see your own hand during gameplay.
+   * Public state of a game: the `PublicGameView` anyone may read, in
+   * every phase. Use `get_game_view` with a viewer address to see your
+   * own hand during gameplay, and `get_game_record` for the full state
+   * of a finished game.
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<CangkulanGame>>>
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PublicGameView>>>
 
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
+  /**
+   * Construct and simulate a get_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_match: ({match_id}: {match_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MatchState>>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a next_deal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Score the finished current deal and either end the match or deal
+   * the next game. Needs no auth: the players agreed to every deal at
+   * `start_match`. Returns the updated match.
+   */
+  next_deal: ({match_id}: {match_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MatchState>>>
+
   /**
    * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a send_emote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send a predefined emote to every opponent in an active game.
+   * 
+   * Opponents who muted emotes get nothing; returns `false` if that is
+   * all of them.
+   */
+  send_emote: ({session_id, player, emote}: {session_id: u32, player: string, emote: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a set_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause or resume the contract, for incident response. While paused,
+   * new games, lobby challenges and commits fail with `ContractPaused`;
+   * reveals, timeouts, forfeits, forced wins and claims still work, so
+   * games in flight can be wound down.
+   */
+  set_paused: ({paused}: {paused: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a cancel_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a live session with `OUTCOME_CANCELLED`, for sessions stranded
+   * by a misconfigured hub or verifier. Every seat gets its stake back
+   * and nothing goes to the Game Hub, which only takes a winner, or the
+   * Leaderboard.
+   */
+  cancel_game: ({session_id, reason}: {session_id: u32, reason: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a commit_play transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Commit a play action: `commit_hash = keccak256(action_u32_be || salt)`.
    * 
    * `action` is either a valid `card_id` (0-35) to play a card, or
    * `CANNOT_FOLLOW_SENTINEL` (0xFFFFFFFF) to declare cannot follow suit.
-   * The actual action is hidden until both players have committed.
+   * The actual action is hidden until every player has committed.
    * 
    * `expected_nonce` must equal the current `action_nonce` to prevent
    * replay attacks and stale-state submissions.
@@ -205,9 +701,19 @@ export interface Client {
 
   /**
    * Construct and simulate a commit_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Commit a seed hash. Both players must commit before reveal begins.
+   * Commit a seed hash. Every player must commit before reveal begins.
+   * 
+   * `proof_mode` (`SEED_PROOF_NIZK`, `SEED_PROOF_PEDERSEN` or
+   * `SEED_PROOF_NOIR`) is the proof type this player will reveal with.
+   * It is published in `EvSeedCommitted` so the opponent knows what to
+   * expect, and `reveal_seed` rejects a proof of any other type.
    */
-  commit_seed: ({session_id, player, commit_hash}: {session_id: u32, player: string, commit_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  commit_seed: ({session_id, player, commit_hash, proof_mode}: {session_id: u32, player: string, commit_hash: Buffer, proof_mode: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_rematch: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<RematchOffer>>>
 
   /**
    * Construct and simulate a reveal_play transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -237,7 +743,7 @@ export interface Client {
    * BLS12-381 Pedersen commitment + Schnorr on blinding.
    * commit_hash = keccak256(C), verified on-chain for binding.
    * 
-   * Once both seeds are revealed, the deck is shuffled and cards are dealt.
+   * Once every seed is revealed, the deck is shuffled and cards are dealt.
    * 
    * # Arguments
    * * `seed_hash` - `keccak256(seed)`, the one-way hash of the raw seed
@@ -245,31 +751,211 @@ export interface Client {
    */
   reveal_seed: ({session_id, player, seed_hash, proof}: {session_id: u32, player: string, seed_hash: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a start_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match to `target` points and deal its first game. Every
+   * player authorizes `(match_id, target)`. Returns the session id of
+   * the first deal.
+   */
+  start_match: ({match_id, players, target}: {match_id: u32, players: Array<string>, target: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a audit_reveal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Recheck `player`'s ZK card plays once the game is finished. Each
+   * play's Pedersen commitment is recomputed from the card the trick
+   * history shows for the player's seat and the matching entry of
+   * `blindings`, one per resolved play in order. A play whose trick never
+   * resolved, because the game ended first, was never revealed and is
+   * skipped. Anyone may call this. Returns whether every commitment
+   * opened.
+   */
+  audit_reveal: ({session_id, player, blindings}: {session_id: u32, player: string, blindings: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a get_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ledger deadline of an active game for client-side clocks.
+   * 
+   * Returns `(deadline_ledger, current_ledger, (lifecycle_state, trick_state))`;
+   * the state pair tells the UI whose move the clock is running for.
+   * `None` if the game is unknown, finished, or its clock has not started.
+   */
+  get_deadline: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<readonly [u32, u32, readonly [u32, u32]]>>>
+
+  /**
+   * Construct and simulate a get_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_delegate: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_hand_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hand_cap: (options?: MethodOptions) => Promise<AssembledTransaction<HandCapConfig>>
+
   /**
    * Construct and simulate a get_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
+  /**
+   * Construct and simulate a migrate_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rewrite a session stored under an older `CangkulanGame` layout in
+   * the current one. Every read already migrates in memory, so this only
+   * saves redoing it; anyone may call it. Leaves the deadline and TTL
+   * alone. Returns the version the session was stored under.
+   */
+  migrate_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a open_channel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register `player`'s Ed25519 key for signing channel states of
+   * `session_id`. Registering again replaces the key until every seat
+   * has one; the keys are then locked.
+   */
+  open_channel: ({session_id, player, key}: {session_id: u32, player: string, key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_guest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw the remaining allowance of a guest. Games already started
+   * keep their attribution.
+   */
+  revoke_guest: ({sponsor, guest}: {sponsor: string, guest: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_hand_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the hand-size cap and overflow rule for games started from now on.
+   * The cap must be at least the deal size.
+   */
+  set_hand_cap: ({max_hand_size, overflow_rule}: {max_hand_size: u32, overflow_rule: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a set_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   set_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a submit_trick transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Land a whole two-player trick in one transaction: both commits, then
+   * both reveals, as `commit_play` and `reveal_play` would.
+   * 
+   * Each payload is authorized with `require_auth_for_args((session_id,
+   * payload))` by its player, or its session delegate, so a relayer can
+   * gather the two signed halves off-chain and submit them. Payloads must
+   * be in seat order and carry the current `action_nonce`, which makes a
+   * signed half good for this trick only.
+   */
+  submit_trick: ({session_id, p1_payload, p2_payload}: {session_id: u32, p1_payload: TrickPayload, p2_payload: TrickPayload}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a tick_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   tick_timeout: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
+  /**
+   * Construct and simulate a close_channel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Finish a game from its channel claim once the challenge window has
+   * passed, unless play has since moved on-chain. Needs no auth. Returns
+   * the outcome.
+   */
+  close_channel: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a export_replay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Everything needed to replay a finished game off-chain, in the
+   * `REPLAY_VERSION` layout: the seed hashes, the shuffled deck, every
+   * trick in order and the result. Check it with `verify_replay`.
+   */
+  export_replay: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a get_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_challenge: ({challenge_id}: {challenge_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Challenge>>>
+
   /**
    * Construct and simulate a get_game_view transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get game state with privacy: only the viewer's own hand is visible.
-   * The opponent's hand is redacted (empty) to prevent casual snooping
-   * via RPC queries. Non-players see both hands redacted.
-   * During the reveal phase, the opponent's trick_card is also redacted
-   * (it only becomes visible after both players have revealed).
+   * Opponents' hands are redacted (empty) to prevent casual snooping
+   * via RPC queries. Non-players see every hand redacted.
+   * During the reveal phase, opponents' trick_cards are also redacted
+   * until the viewer has revealed too.
    */
   get_game_view: ({session_id, viewer}: {session_id: u32, viewer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<CangkulanGame>>>
 
+  /**
+   * Construct and simulate a prune_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Finalize abandoned sessions in bulk. Returns the ids it ended.
+   * 
+   * Needs no auth. A session is ended once its ledger deadline is
+   * `TIMEOUT_GRACE_LEDGERS` behind, with the timeout outcome (a draw
+   * where none applies), or as a draw once its storage entry is within
+   * `TIMEOUT_GRACE_LEDGERS` of expiring. Missing, finished and live
+   * sessions are skipped. One `EvSessionsPruned` lists the ended ids.
+   */
+  prune_expired: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a sponsor_guest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let an ephemeral `guest` key play up to `max_games` sessions on the
+   * sponsor's behalf.
+   * 
+   * The guest still signs its own moves; only Game Hub accounting is
+   * attributed to the sponsor. Re-sponsoring replaces the allowance.
+   */
+  sponsor_guest: ({sponsor, guest, max_games}: {sponsor: string, guest: string, max_games: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a verify_replay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check an `export_replay` blob on its own, reading no game state, by
+   * replaying the rules: the deck must be the shuffle of its seed hashes
+   * and is dealt as the contract deals it; each trick must flip the next
+   * card, every played card must be in its seat's hand and follow suit,
+   * a seat may only pass when it holds none of the suit, and the
+   * recorded winner and penalty draws must follow. The tricks must chain
+   * from the deal seed into its transcript, each seat's tricks won must
+   * match the replay, and the outcome must be the one the last trick
+   * ends the game with. A game that ended off the board (timeout,
+   * forfeit, cancel, channel settlement) only needs some recorded
+   * outcome, since those moves are not in the blob. Fails with
+   * `MalformedReplay` if the blob does not parse.
+   */
+  verify_replay: ({replay}: {replay: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a accept_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending rematch. Once every seat has agreed the new game
+   * starts. Returns the rematch session id.
+   */
+  accept_rematch: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a cancel_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a pending rematch. Any seat of the finished game may cancel;
+   * stakes already locked for it are returned. Works from the offer
+   * alone, so the stakes come back even once the finished game expired.
+   */
+  cancel_rematch: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a channel_digest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Message each seat signs for `state`:
+   * `keccak256("cangkulan-channel" ∥ xdr(contract) ∥ xdr(state))`.
+   */
+  channel_digest: ({state}: {state: ChannelState}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a claim_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay `player` their share of the stakes recorded when the game finished.
+   * 
+   * Finalization only records the outcome and notifies the hub; payouts
+   * happen here so a failing transfer can never block or revert the game
+   * result. Each player claims on their own, so one recipient whose
+   * transfer fails never holds up the others. Anyone may call this —
+   * funds only go to `player`. Once every share is paid the record is
+   * cleared and the rake of an escrowed game is credited to its
+   * treasury. Returns the amount paid.
+   */
+  claim_winnings: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
   /**
    * Construct and simulate a commit_play_zk transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Commit a play action with a ZK ring sigma proof of suit compliance.
@@ -281,10 +967,27 @@ export interface Client {
    * proving the committed card is in the player's valid set (hand ∩ trick suit)
    * without revealing which specific card.
    * 
-   * The ZK verifier (Mode 7) checks the ring sigma and binding.
+   * The ZK verifier (Mode 7) checks the ring sigma and binding. An empty
+   * `zk_proof` consumes a proof recorded earlier with `verify_and_record`.
+   * 
+   * A proof padded to a full suit of members (`RING_PADDED_FLAG`) is
+   * recognised by its length, so every play proof can be the same size
+   * whatever the number of suit-matching cards. A recorded proof is
+   * looked up unpadded first, then padded.
    */
   commit_play_zk: ({session_id, player, commit_hash, expected_nonce, zk_proof}: {session_id: u32, player: string, commit_hash: Buffer, expected_nonce: u32, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a export_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Canonical serialization of a finished game, for NFT metadata or
+   * off-chain archives (layout in `SUMMARY_VERSION`).
+   * 
+   * Its keccak256 is recorded when the game ends and stays readable via
+   * `get_summary_hash` after the game itself expires, so an archived blob
+   * can be authenticated later.
+   */
+  export_summary: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
   /**
    * Construct and simulate a get_game_debug transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get full raw game state (admin-only). Requires admin authentication.
@@ -293,27 +996,185 @@ export interface Client {
    */
   get_game_debug: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<CangkulanGame>>>
 
+  /**
+   * Construct and simulate a settle_channel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a state with one signature per seat, in seat order, over
+   * `channel_digest(state)`. Needs no auth. Starts the challenge window,
+   * or replaces a pending claim with a higher `move_counter`. Returns
+   * the ledger the window ends at.
+   */
+  settle_channel: ({session_id, state, signatures}: {session_id: u32, state: ChannelState, signatures: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
    * Construct and simulate a verify_shuffle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Recompute and return the full shuffled deck order from ZK-verified seed hashes.
+   * Recompute and return the shuffled deck order from ZK-verified seed hashes:
+   * `GameConfig::deck_size` cards, 36 under standard rules.
    * Anyone can call this to independently verify the shuffle was fair. The
    * seed hashes are `keccak256(raw_seed)` — the raw seeds never appear on-chain.
-   * Only available after both seeds have been revealed (PLAYING or FINISHED).
+   * Only available after every seed has been revealed (PLAYING or FINISHED).
    */
   verify_shuffle: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
 
+  /**
+   * Construct and simulate a dispute_shuffle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Dispute the deal of a game in play: the card dealt at `index` of the
+   * deck differs from `verify_shuffle` at the same index. The contract
+   * deals, so no seat is at fault. A proven mismatch cancels the game,
+   * refunding every stake, and pauses the contract, since the running
+   * code no longer deals what it verifies. A claim that does not hold is
+   * rejected with `ShuffleDisputeRejected`.
+   */
+  dispute_shuffle: ({session_id, player, index}: {session_id: u32, player: string, index: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a expire_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Clear the shares that were not claimed within the claim window.
+   * 
+   * Callable by anyone once `expires_ledger` has passed. Unclaimed
+   * escrowed tokens are credited to the treasury with the rake. Returns
+   * the total amount forfeited.
+   */
+  expire_winnings: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_game_record transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Full state of a finished game, hands and pile included; nothing is
+   * secret once play is over. Fails with `GameNotFinished` before that.
+   */
+  get_game_record: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<CangkulanGame>>>
+
+  /**
+   * Construct and simulate a get_history_len transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of games recorded in a player's history.
+   */
+  get_history_len: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Leaderboard contract address, if configured.
+   */
+  get_leaderboard: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a request_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer a rematch of a finished game with the same seats, stakes and
+   * escrow terms. The new game gets a contract-allocated session id;
+   * a finished game is rematched at most once.
+   * 
+   * Each seat authorizes only its own request or acceptance; in an
+   * escrowed game that call also locks its stake. Returns the rematch
+   * session id.
+   */
+  request_rematch: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
    * Construct and simulate a resolve_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game whose deadline has passed, with the outcome of
+   * `determine_timeout_outcome`.
+   * 
+   * Players may call this as soon as the deadline passes. Anyone else
+   * may once the ledger deadline is `TIMEOUT_GRACE_LEDGERS` behind, so a
+   * game both players abandoned still settles and reaches the hub.
+   * 
+   * On a blitz clock (`time_bank_ledgers`) anyone may call it as soon as
+   * every seat it waits on has used up its time bank past the deadline.
    */
   resolve_timeout: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a revoke_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take back signing of `player`'s moves in `session_id`.
+   */
+  revoke_delegate: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the Leaderboard contract address (admin only).
+   */
+  set_leaderboard: ({leaderboard}: {leaderboard: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take an open challenge, matching its stake. Starts the game at a
+   * contract-allocated session id with the creator in seat 1 and
+   * returns that session id.
+   */
+  accept_challenge: ({challenge_id, player}: {challenge_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a cancel_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw an open challenge and return the creator's stake. The
+   * creator may cancel at any time; anyone may once it has expired.
+   */
+  cancel_challenge: ({challenge_id, caller}: {challenge_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_forced_win transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game whose result can no longer change, without playing out
+   * the remaining tricks.
+   * 
+   * Either player may call this; the contract checks on-chain that the
+   * position is decided (see `forced_outcome`) and finalizes with that
+   * outcome. Returns the outcome, or `OutcomeNotForced` if the trailing
+   * player could still win or draw.
+   */
+  claim_forced_win: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a create_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post an open challenge staking `points`, open until `expires_ledger`
+   * (at most `MAX_CHALLENGE_LEDGERS` ahead). Expired challenges are
+   * delisted first; their stakes stay refundable through
+   * `cancel_challenge`. Returns the challenge id.
+   */
+  create_challenge: ({creator, points, expires_ledger}: {creator: string, points: i128, expires_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_active_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sessions `player` is seated in that have not finished, oldest first,
+   * so a returning player can find the games waiting on them. Games that
+   * expired unfinished are left out.
+   */
+  get_active_games: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_channel_keys transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Channel keys registered so far, by seat.
+   */
+  get_channel_keys: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, Buffer>>>
+
+  /**
+   * Construct and simulate a get_integrations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve every dependent contract address and the rule config hash in
+   * one call, so clients need a single simulation before building a proof.
+   */
+  get_integrations: (options?: MethodOptions) => Promise<AssembledTransaction<Result<Integrations>>>
+
+  /**
+   * Construct and simulate a get_stake_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_stake_escrow: (options?: MethodOptions) => Promise<AssembledTransaction<Option<StakeEscrow>>>
+
+  /**
+   * Construct and simulate a get_summary_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the recorded keccak256 of a finished game's summary blob.
+   */
+  get_summary_hash: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a set_stake_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Escrow stakes in a token for games started from now on, or stop
+   * escrowing with `None`. The rake is at most `MAX_RAKE_BPS`.
+   */
+  set_stake_escrow: ({escrow}: {escrow: Option<StakeEscrow>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a verify_noir_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Verify a Noir UltraKeccakHonk proof on-chain in a **separate
    * transaction** from `reveal_seed`.  This splits the ~260M CPU cost
    * into two transactions that each fit within Soroban limits:
    * 
-   * TX 1: `verify_noir_seed` → UltraHonk verification (~215M CPU)
+   * TX 1: `verify_noir_seed` → UltraHonk verification via the ZK verifier (~215M CPU)
    * TX 2: `reveal_seed` with an empty proof → game logic (~50M CPU)
    * 
    * The verified flag is stored in temporary storage and consumed by
@@ -323,7 +1184,7 @@ export interface Client {
    * * `session_id` - game session id
    * * `player` - the player revealing (requires auth)
    * * `seed_hash` - blake2s(seed) — the Noir public input
-   * * `proof` - raw UltraKeccakHonk proof (>4KB)
+   * * `proof` - raw UltraKeccakHonk proof (> `ULTRAHONK_MIN_PROOF_LEN` bytes)
    */
   verify_noir_seed: ({session_id, player, seed_hash, proof}: {session_id: u32, player: string, seed_hash: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
@@ -344,29 +1205,235 @@ export interface Client {
    * 1. Aggregate Pedersen binding (keccak256(A) == commit_hash)
    * 2. Schnorr proof of knowledge of aggregate blinding
    * 3. Suit exclusion: no card matches trick_suit
+   * 
+   * As with `commit_play_zk`, an empty `zk_proof` consumes a proof
+   * recorded earlier with `verify_and_record`.
    */
   commit_cangkul_zk: ({session_id, player, commit_hash, expected_nonce, zk_proof}: {session_id: u32, player: string, commit_hash: Buffer, expected_nonce: u32, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a get_channel_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_channel_claim: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<ChannelClaim>>>
+
+  /**
+   * Construct and simulate a get_guest_sponsor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a guest's sponsor and remaining games, if sponsored.
+   */
+  get_guest_sponsor: ({guest}: {guest: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<GuestSponsorship>>>
+
+  /**
+   * Construct and simulate a get_pending_claim transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the unclaimed stakes for a finished session, if any.
+   */
+  get_pending_claim: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingClaim>>>
+
+  /**
+   * Construct and simulate a get_trick_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Every resolved trick of a session, oldest first, unlike the game's
+   * `trick_log`, which keeps only the last `TRICK_LOG_LEN`. Empty before
+   * the first trick, or once the entry has expired with the game.
+   */
+  get_trick_history: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<TrickRecord>>>
+
+  /**
+   * Construct and simulate a get_valid_actions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The viewer's playable cards for the current trick and the call
+   * expected from them next, so frontends need not re-derive the rules.
+   * Like `get_game_view` it needs no auth and shows only the viewer's
+   * own hand. Fails with `NotAPlayer` for a non-player and `WrongPhase`
+   * outside the playing phase.
+   */
+  get_valid_actions: ({session_id, viewer}: {session_id: u32, viewer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ValidActions>>>
+
+  /**
+   * Construct and simulate a start_game_vs_bot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a practice game of `player` against `bot` under standard
+   * rules. Only the player authorizes.
+   */
+  start_game_vs_bot: ({session_id, player, bot}: {session_id: u32, player: string, bot: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdraw_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay `treasury` everything credited to it in `token`. Only the
+   * treasury may withdraw. Returns the amount paid.
+   */
+  withdraw_treasury: ({treasury, token}: {treasury: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a authorize_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let `delegate` sign `player`'s commits and reveals in `session_id`,
+   * e.g. a browser session key or a relayer. From then on those calls
+   * need the delegate's auth instead of the player's; forfeits, timeouts
+   * and claims still need the player. Re-authorizing replaces the key.
+   */
+  authorize_delegate: ({session_id, player, delegate}: {session_id: u32, player: string, delegate: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_entropy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_entropy_policy: (options?: MethodOptions) => Promise<AssembledTransaction<EntropyPolicy>>
+
+  /**
+   * Construct and simulate a get_mode_verifiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verifiers serving a single proof mode instead of the default one,
+   * keyed by mode.
+   */
+  get_mode_verifiers: (options?: MethodOptions) => Promise<AssembledTransaction<Map<u32, string>>>
+
   /**
    * Construct and simulate a get_player_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get a player's game history (up to 50 most recent games).
-   * Returns a Vec of GameSummary with outcome from the player's perspective:
-   * 1 = win, 2 = loss, 3 = draw
+   * Get a page of a player's game history, oldest first, with outcome
+   * from the player's perspective: 1 = win, 2 = loss, 3 = draw.
+   * `cursor` is a position in the whole history (0 = oldest game), and
+   * `opponent` and `outcome` keep only matching games. A call returns
+   * at most `limit` games, capped at `MAX_HISTORY_PAGE`, and reads at
+   * most `MAX_HISTORY_SCAN_CHUNKS` chunks, so a filtered page can come
+   * back short or empty with a `next_cursor` to continue from. Every
+   * chunk read has its TTL extended; chunks nobody reads or writes for
+   * the history TTL expire and read as empty.
+   */
+  get_player_history: ({player, cursor, limit, opponent, outcome}: {player: string, cursor: u32, limit: u32, opponent: Option<string>, outcome: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<HistoryPage>>
+
+  /**
+   * Construct and simulate a rematch_session_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session id of the rematch of `session_id`, pending or started.
+   */
+  rematch_session_id: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a set_entropy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the entropy policy that revealed seed hashes must satisfy.
+   * Applies to every reveal from now on, including games in progress.
+   */
+  set_entropy_policy: ({policy}: {policy: EntropyPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a compute_play_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Legacy play commit `keccak256(card_id_u32_be ∥ salt)`, as checked by
+   * `reveal_play` for a `commit_play` commit.
+   */
+  compute_play_commit: ({card_id, salt}: {card_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a compute_seed_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hash-based (NIZK) seed commit
+   * `keccak256(seed_hash ∥ blinding ∥ player as strkey)` for `commit_seed`.
+   */
+  compute_seed_commit: ({seed_hash, blinding, player}: {seed_hash: Buffer, blinding: Buffer, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_open_challenges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ids of the challenges still open to accept, oldest first.
+   */
+  get_open_challenges: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_treasury_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Amount credited to `treasury` in `token` and not yet withdrawn.
+   */
+  get_treasury_balance: ({treasury, token}: {treasury: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_verifier_override transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Expiry ledger of the active override, or `None` when proofs are
+   * verified normally.
+   */
+  get_verifier_override: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a set_verifier_for_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send proofs of `mode` to `verifier` in games started from now on.
+   * `mode` is one of `ROUTABLE_PROOF_MODES`.
+   */
+  set_verifier_for_mode: ({mode, verifier}: {mode: u32, verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute_after_timelock transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Activate a scheduled override once its timelock has elapsed.
+   * 
+   * Until the returned expiry ledger, ZK verifier calls are skipped (each
+   * skip emits `EvVerifierBypassed`) while the checks the contract can do
+   * itself still apply: NIZK seed reveals must open their hash commitment,
+   * Pedersen seed reveals must match `keccak256(C)`, and card reveals are
+   * still opened and rule-checked in `reveal_play`. Noir proofs are
+   * unaffected. The override lapses on its own.
+   */
+  execute_after_timelock: (options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_notification_prefs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's notification preferences (all off if never set).
+   */
+  get_notification_prefs: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<NotificationPrefs>>
+
+  /**
+   * Construct and simulate a set_notification_prefs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Store the caller's notification preferences.
+   * 
+   * Relays read these via `get_notification_prefs`; the contract itself
+   * respects `deadline_warnings` (in `tick_timeout`) and `mute_emotes`
+   * (in `send_emote`).
+   */
+  set_notification_prefs: ({player, prefs}: {player: string, prefs: NotificationPrefs}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a start_game_with_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a game under house rules; `None` plays `GameConfig::standard()`.
+   * 
+   * With a config every player authorizes `(session_id, points, config)`,
+   * so nobody is seated under rules they did not agree to.
+   */
+  start_game_with_config: ({session_id, players, points, config}: {session_id: u32, players: Array<string>, points: Array<i128>, config: Option<GameConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_multiplayer_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a game for 2–4 players, seated in the given order, each staking
+   * the matching entry of `points`.
+   * 
+   * Every player authorizes `(session_id, points)` as for `start_game`.
+   * Games with more than two seats are not reported to the Game Hub, so
+   * guest sponsorships are not charged for them.
+   */
+  start_multiplayer_game: ({session_id, players, points}: {session_id: u32, players: Array<string>, points: Array<i128>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_verifier_for_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send proofs of `mode` back to the default verifier in new games.
+   */
+  clear_verifier_for_mode: ({mode}: {mode: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_verifier_override transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drop a scheduled override and end an active one immediately.
+   */
+  cancel_verifier_override: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a schedule_verifier_override transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Schedule an emergency override of the ZK verifier, for incidents
+   * where a broken verifier would otherwise strand every game in progress.
+   * 
+   * Nothing changes until `execute_after_timelock` is called at least
+   * `OVERRIDE_TIMELOCK_LEDGERS` (~24h) later, which gives players and
+   * indexers watching `EvVerifierOverrideScheduled` time to react. Use a
+   * multisig account as admin so no single key can trigger this.
+   * Rescheduling restarts the timelock. Returns the executable ledger.
    */
-  get_player_history: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<GameSummary>>>
+  schedule_verifier_override: ({duration_ledgers}: {duration_ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a get_ultrahonk_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the UltraHonk verifier contract address (Noir proof verifier).
+   * Construct and simulate a compute_pedersen_commit_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * ZK play commit `keccak256(card_id·G + blinding·H)`, as checked by
+   * `reveal_play` for a `commit_play_zk` commit. For a `commit_cangkul_zk`
+   * commit pass the sum of the hand's card ids and the aggregate blinding.
    */
-  get_ultrahonk_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  compute_pedersen_commit_hash: ({card_id, blinding}: {card_id: u32, blinding: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
 
   /**
-   * Construct and simulate a set_ultrahonk_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set the UltraHonk verifier contract address (admin only).
-   * This enables optional Noir UltraKeccakHonk proof verification.
+   * Construct and simulate a get_pending_verifier_override transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_ultrahonk_verifier: ({verifier_addr}: {verifier_addr: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_pending_verifier_override: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingVerifierOverride>>>
 
 }
 export class Client extends ContractClient {
@@ -388,75 +1455,238 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAENDb21wYWN0IHN1bW1hcnkgb2YgYSBmaW5pc2hlZCBnYW1lLCBzdG9yZWQgcGVyc2lzdGVudGx5IHBlciBwbGF5ZXIuAAAAAAAAAAALR2FtZVN1bW1hcnkAAAAABgAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAhvcHBvbmVudAAAABMAAAAAAAAAB291dGNvbWUAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAALdHJpY2tzX2xvc3QAAAAABAAAAAAAAAAKdHJpY2tzX3dvbgAAAAAABA==",
-        "AAAABQAAAAAAAAAAAAAAC0V2R2FtZUVuZGVkAAAAAAEAAAANZXZfZ2FtZV9lbmRlZAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAAAAAAdvdXRjb21lAAAAAAQAAAAAAAAAAg==",
-        "AAAAAQAAAAAAAAAAAAAADUNhbmdrdWxhbkdhbWUAAAAAAAAeAAAAAAAAAAxhY3Rpb25fbm9uY2UAAAAEAAAAAAAAAA9kZWFkbGluZV9sZWRnZXIAAAAD6AAAAAQAAAAAAAAADmRlYWRsaW5lX25vbmNlAAAAAAPoAAAABAAAAAAAAAAJZHJhd19waWxlAAAAAAAD6gAAAAQAAAAAAAAADGZsaXBwZWRfY2FyZAAAA+gAAAAEAAAAAAAAAAVoYW5kMQAAAAAAA+oAAAAEAAAAAAAAAAVoYW5kMgAAAAAAA+oAAAAEAAAAPUxlZGdlciBzZXF1ZW5jZSBvZiB0aGUgbGFzdCB0aWNrX3RpbWVvdXQgY2FsbCAocmF0ZSBsaW1pdGluZykAAAAAAAAQbGFzdF90aWNrX2xlZGdlcgAAAAQAAAAAAAAAD2xpZmVjeWNsZV9zdGF0ZQAAAAAEAAAAAAAAAAdvdXRjb21lAAAAAAQAAAAAAAAADHBsYXlfY29tbWl0MQAAA+gAAAPuAAAAIAAAAAAAAAAMcGxheV9jb21taXQyAAAD6AAAA+4AAAAgAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAxzZWVkX2NvbW1pdDEAAAPoAAAD7gAAACAAAAAAAAAADHNlZWRfY29tbWl0MgAAA+gAAAPuAAAAIAAAAAAAAAAKc2VlZF9oYXNoMQAAAAAD6AAAA+4AAAAgAAAAAAAAAApzZWVkX2hhc2gyAAAAAAPoAAAD7gAAACAAAAAAAAAADnNlZWRfcmV2ZWFsZWQxAAAAAAABAAAAAAAAAA5zZWVkX3JldmVhbGVkMgAAAAAAAQAAAAAAAAALdHJpY2tfY2FyZDEAAAAD6AAAAAQAAAAAAAAAC3RyaWNrX2NhcmQyAAAAA+gAAAAEAAAAAAAAAAt0cmlja19zdGF0ZQAAAAAEAAAAAAAAAAp0cmlja19zdWl0AAAAAAPoAAAABAAAAAAAAAALdHJpY2tzX3dvbjEAAAAABAAAAAAAAAALdHJpY2tzX3dvbjIAAAAABAAAAAAAAAAIemtfcGxheTEAAAABAAAAAAAAAAh6a19wbGF5MgAAAAE=",
-        "AAAABQAAAAAAAAAAAAAADUV2R2FtZVN0YXJ0ZWQAAAAAAAABAAAAD2V2X2dhbWVfc3RhcnRlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAI=",
-        "AAAABAAAAAAAAAAAAAAADkNhbmdrdWxhbkVycm9yAAAAAAAkAAAAAAAAAAxHYW1lTm90Rm91bmQAAAABAAAAAAAAABRTZXNzaW9uQWxyZWFkeUV4aXN0cwAAAAIAAAAAAAAACk5vdEFQbGF5ZXIAAAAAAAMAAAAAAAAAElNlbGZQbGF5Tm90QWxsb3dlZAAAAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAACldyb25nUGhhc2UAAAAAAAYAAAAAAAAAFkNvbW1pdEFscmVhZHlTdWJtaXR0ZWQAAAAAAAcAAAAAAAAAFlJldmVhbEFscmVhZHlTdWJtaXR0ZWQAAAAAAAgAAAAAAAAAEkNvbW1pdEhhc2hNaXNtYXRjaAAAAAAACQAAAAAAAAAOSW52YWxpZFprUHJvb2YAAAAAAAoAAAAAAAAADU1pc3NpbmdDb21taXQAAAAAAAALAAAAAAAAAAtOb3RZb3VyVHVybgAAAAAMAAAAAAAAAA1DYXJkTm90SW5IYW5kAAAAAAAADQAAAAAAAAAJV3JvbmdTdWl0AAAAAAAADgAAAAAAAAAPSGFzTWF0Y2hpbmdTdWl0AAAAAA8AAAAAAAAADURyYXdQaWxlRW1wdHkAAAAAAAAQAAAAAAAAABFOb1RyaWNrSW5Qcm9ncmVzcwAAAAAAABEAAAAAAAAAC0FkbWluTm90U2V0AAAAABIAAAAAAAAADUdhbWVIdWJOb3RTZXQAAAAAAAATAAAAAAAAAA5WZXJpZmllck5vdFNldAAAAAAAFAAAAAAAAAARVGltZW91dE5vdFJlYWNoZWQAAAAAAAAVAAAAAAAAABRUaW1lb3V0Tm90Q29uZmlndXJlZAAAABYAAAAAAAAAFFRpbWVvdXROb3RBcHBsaWNhYmxlAAAAFwAAAAAAAAAPV2Vha1NlZWRFbnRyb3B5AAAAABgAAAAAAAAADEludmFsaWROb25jZQAAABkAAAAAAAAAGlBsYXlDb21taXRBbHJlYWR5U3VibWl0dGVkAAAAAAAaAAAAAAAAABFQbGF5Q29tbWl0TWlzc2luZwAAAAAAABsAAAAAAAAAElBsYXlSZXZlYWxNaXNtYXRjaAAAAAAAHAAAAAAAAAANSW52YWxpZENhcmRJZAAAAAAAAB0AAAAAAAAAF1VsdHJhSG9ua1ZlcmlmaWVyTm90U2V0AAAAAB4AAAAAAAAAG1VsdHJhSG9ua1ZlcmlmaWNhdGlvbkZhaWxlZAAAAAAfAAAAAAAAABJaa1BsYXlQcm9vZkludmFsaWQAAAAAACAAAAAAAAAADlprUGxheVNldEVtcHR5AAAAAAAhAAAAAAAAABVaa1BsYXlPcGVuaW5nTWlzbWF0Y2gAAAAAAAAiAAAAAAAAABVaa0NhbmdrdWxQcm9vZkludmFsaWQAAAAAAAAjAAAAAAAAAAtUaWNrVG9vU29vbgAAAAAm",
-        "AAAABQAAAAAAAAAAAAAADkV2RGVja1NodWZmbGVkAAAAAAABAAAAEGV2X2RlY2tfc2h1ZmZsZWQAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAI=",
-        "AAAABQAAAD9FbWl0dGVkIHdoZW4gYSBwbGF5ZXIgcmV2ZWFscyB0aGVpciBwbGF5IChjYXJkX2lkIG5vdyB2aXNpYmxlKS4AAAAAAAAAAA5FdlBsYXlSZXZlYWxlZAAAAAAAAQAAABBldl9wbGF5X3JldmVhbGVkAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAAAAAAB2NhcmRfaWQAAAAABAAAAAAAAAAAAAAACmlzX2NhbmdrdWwAAAAAAAEAAAAAAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAADkV2U2VlZFJldmVhbGVkAAAAAAABAAAAEGV2X3NlZWRfcmV2ZWFsZWQAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAI=",
-        "AAAABQAAAD1FbWl0dGVkIHdoZW4gYSBwbGF5ZXIgY29tbWl0cyB0aGVpciBwbGF5IChjYXJkX2lkIGlzIGhpZGRlbikuAAAAAAAAAAAAAA9FdlBsYXlDb21taXR0ZWQAAAAAAQAAABFldl9wbGF5X2NvbW1pdHRlZAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAAD0V2U2VlZENvbW1pdHRlZAAAAAABAAAAEWV2X3NlZWRfY29tbWl0dGVkAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAC",
-        "AAAABQAAAAAAAAAAAAAAD0V2VHJpY2tSZXNvbHZlZAAAAAABAAAAEWV2X3RyaWNrX3Jlc29sdmVkAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAABndpbm5lcgAAAAAABAAAAAAAAAAAAAAABWNhcmQxAAAAAAAD6AAAAAQAAAAAAAAAAAAAAAVjYXJkMgAAAAAAA+gAAAAEAAAAAAAAAAI=",
-        "AAAABQAAAAAAAAAAAAAAEEV2SHViRW5kUmVwb3J0ZWQAAAABAAAAE2V2X2h1Yl9lbmRfcmVwb3J0ZWQAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAAA2h1YgAAAAATAAAAAAAAAAAAAAALcGxheWVyMV93b24AAAAAAQAAAAAAAAAC",
-        "AAAAAAAAAK5Gb3JmZWl0IHRoZSBnYW1lLiBUaGUgY2FsbGVyIGltbWVkaWF0ZWx5IGxvc2VzLgoKVGhpcyBhbGxvd3MgYSBwbGF5ZXIgdG8gd2l0aGRyYXcgZnJvbSBhbiBhY3RpdmUgZ2FtZSBhdCBhbnkgcG9pbnQuClRoZSBvcHBvbmVudCBpcyBkZWNsYXJlZCB0aGUgd2lubmVyLiBUaGlzIGlzIGlycmV2ZXJzaWJsZS4AAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+      new ContractSpec([ "AAAAAQAAADJPbmUgcGxheWVyJ3Mgc2hhcmUgb2YgYSBmaW5pc2hlZCBzZXNzaW9uJ3Mgc3Rha2VzLgAAAAAAAAAAAAZQYXlvdXQAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAHY2xhaW1lZAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
+        "AAAAAQAAADBUaGUgYm90J3MgdmlldyBvZiB0aGUgdHJpY2sgaXQgaXMgYXNrZWQgdG8gcGxheS4AAAAAAAAAB0JvdFR1cm4AAAAABAAAAAAAAAAEaGFuZAAAA+oAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAV0cmljawAAAAAAAAQAAAAAAAAACnRyaWNrX3N1aXQAAAAAAAQ=",
+        "AAAAAAAAAhZGb3JmZWl0IHRoZSBnYW1lLiBUaGUgY2FsbGVyIGltbWVkaWF0ZWx5IGxvc2VzLgoKVGhpcyBhbGxvd3MgYSBwbGF5ZXIgdG8gd2l0aGRyYXcgZnJvbSBhbiBhY3RpdmUgZ2FtZSBhdCBhbnkgcG9pbnQuClRoZSBvcHBvbmVudCBpcyBkZWNsYXJlZCB0aGUgd2lubmVyOyB3aXRoIG1vcmUgc2VhdHMsIHRoZSBiZXN0IG9mCnRoZSByZW1haW5pbmcgcGxheWVycyBieSB0aGUgdXN1YWwgcmFua2luZy4gVGhpcyBpcyBpcnJldmVyc2libGUuCgpUaGUgZ2FtZSByZWNvcmRzIHRoZSBzZWF0IGluIGBmb3JmZWl0ZWRfYnlgLiBMZWF2aW5nIG9uY2UgdGhlIGNhcmRzCmFyZSBkZWFsdCBpcyBhIHJhZ2UtcXVpdDogdGhlIExlYWRlcmJvYXJkIGlzIHRvbGQgd2l0aCBpdHMKYE9VVENPTUVfRk9SRkVJVGAgZmxhZyBhbmQgdGFrZXMgZXh0cmEgcmF0aW5nIG9mZiB0aGUgcXVpdHRlci4KTGVhdmluZyBkdXJpbmcgdGhlIHNlZWQgcGhhc2VzLCBiZWZvcmUgYW55b25lIGhhcyBzZWVuIGEgaGFuZCwgaXMKcmVwb3J0ZWQgYXMgYW4gb3JkaW5hcnkgbG9zcy4AAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAGFQcm90b2NvbCBmZWUgb24gd29uIHBvdHMsIGluIGJhc2lzIHBvaW50czogdGhlIGVzY3JvdydzIHJha2UsIG9yIDAKd2hpbGUgc3Rha2VzIGFyZSBub3QgZXNjcm93ZWQuAAAAAAAAB2dldF9mZWUAAAAAAAAAAAEAAAAE",
         "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAIBDaGFuZ2UgdGhlIGVzY3JvdydzIHJha2UgZm9yIGdhbWVzIHN0YXJ0ZWQgZnJvbSBub3cgb24sIGtlZXBpbmcgaXRzCnRva2VuIGFuZCB0cmVhc3VyeS4gQXQgbW9zdCBgTUFYX1JBS0VfQlBTYDsgbmVlZHMgYW4gZXNjcm93LgAAAAdzZXRfZmVlAAAAAAEAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
         "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
         "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
-        "AAAAAAAAASlHZXQgZ2FtZSBzdGF0ZSB3aXRoIGJvdGggaGFuZHMgcmVkYWN0ZWQgZm9yIGFjdGl2ZSBnYW1lcy4KClJldHVybnMgZnVsbCBzdGF0ZSAoaW5jbHVkaW5nIGhhbmRzKSBvbmx5IGZvciBGSU5JU0hFRCBnYW1lcy4KRHVyaW5nIGFjdGl2ZSBnYW1lcywgaGFuZHMgYXJlIGNsZWFyZWQgdG8gcHJldmVudCBjYXJkIHNub29waW5nCnZpYSB1bmF1dGhlbnRpY2F0ZWQgUlBDIHF1ZXJpZXMuIFVzZSBgZ2V0X2dhbWVfdmlld2Agd2l0aCBhIHZpZXdlcgphZGRyZXNzIHRvIHNlZSB5b3VyIG93biBoYW5kIGR1cmluZyBnYW1lcGxheS4AAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADUNhbmdrdWxhbkdhbWUAAAAAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
-        "AAAABQAAAAAAAAAAAAAAEkV2SHViU3RhcnRSZXBvcnRlZAAAAAAAAQAAABVldl9odWJfc3RhcnRfcmVwb3J0ZWQAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAAAAAADaHViAAAAABMAAAAAAAAAAg==",
+        "AAAAAAAAANpQdWJsaWMgc3RhdGUgb2YgYSBnYW1lOiB0aGUgYFB1YmxpY0dhbWVWaWV3YCBhbnlvbmUgbWF5IHJlYWQsIGluCmV2ZXJ5IHBoYXNlLiBVc2UgYGdldF9nYW1lX3ZpZXdgIHdpdGggYSB2aWV3ZXIgYWRkcmVzcyB0byBzZWUgeW91cgpvd24gaGFuZCBkdXJpbmcgZ2FtZXBsYXksIGFuZCBgZ2V0X2dhbWVfcmVjb3JkYCBmb3IgdGhlIGZ1bGwgc3RhdGUKb2YgYSBmaW5pc2hlZCBnYW1lLgAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAA5QdWJsaWNHYW1lVmlldwAAAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAACxUaGUgYWN0aW9uIGFuZCBzYWx0IGJlaGluZCB0aGUgYm90J3MgY29tbWl0LgAAAAAAAAAJQm90UmV2ZWFsAAAAAAAAAgAAAAAAAAAHY2FyZF9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACA=",
+        "AAAAAQAAACdBbiBvcGVuIGNoYWxsZW5nZSBhd2FpdGluZyBhbiBvcHBvbmVudC4AAAAAAAAAAAlDaGFsbGVuZ2UAAAAAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAADmV4cGlyZXNfbGVkZ2VyAAAAAAAEAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAACHJha2VfYnBzAAAABAAAAAAAAAALc3Rha2VfdG9rZW4AAAAD6AAAABMAAAAAAAAACHRyZWFzdXJ5AAAD6AAAABM=",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAAAAAAAJZ2V0X21hdGNoAAAAAAAAAQAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+gAAAfQAAAACk1hdGNoU3RhdGUAAA==",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAKxTY29yZSB0aGUgZmluaXNoZWQgY3VycmVudCBkZWFsIGFuZCBlaXRoZXIgZW5kIHRoZSBtYXRjaCBvciBkZWFsCnRoZSBuZXh0IGdhbWUuIE5lZWRzIG5vIGF1dGg6IHRoZSBwbGF5ZXJzIGFncmVlZCB0byBldmVyeSBkZWFsIGF0CmBzdGFydF9tYXRjaGAuIFJldHVybnMgdGhlIHVwZGF0ZWQgbWF0Y2guAAAACW5leHRfZGVhbAAAAAAAAAEAAAAAAAAACG1hdGNoX2lkAAAABAAAAAEAAAPpAAAH0AAAAApNYXRjaFN0YXRlAAAAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
         "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAABQAAAEpFbWl0dGVkIHdoZW4gYSBaSyBjYW5na3VsIGhhbmQgcHJvb2YgdmFsaWRhdGVzIGEgY2Fubm90LWZvbGxvdy1zdWl0IGNsYWltLgAAAAAAAAAAABNFdlprQ2FuZ2t1bFZlcmlmaWVkAAAAAAEAAAAWZXZfemtfY2FuZ2t1bF92ZXJpZmllZAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAAAAAACWhhbmRfc2l6ZQAAAAAAAAQAAAAAAAAAAAAAAAp0cmlja19zdWl0AAAAAAAEAAAAAAAAAAI=",
+        "AAAAAQAAAE5DYXJkIGVuY29kaW5nIGFuZCBzaXplIGJvdW5kcyBmb3IgdGhlIE1vZGUgNyBhbmQgOCBjaGVja3MuIFNlZSB0aGUKY3JhdGUgZG9jcy4AAAAAAAAAAAAKRGVja1BhcmFtcwAAAAAABAAAADdDYXJkcyBwZXIgc3VpdDsgYGRlY2tfc2l6ZWAgaXMgYSB3aG9sZSBudW1iZXIgb2Ygc3VpdHMuAAAAAA5jYXJkc19wZXJfc3VpdAAAAAAABAAAACpDYXJkcyBpbiB0aGUgZGVjazsgaWRzIHJ1biBgMC4uZGVja19zaXplYC4AAAAAAAlkZWNrX3NpemUAAAAAAAAEAAAAFExhcmdlc3QgTW9kZSA4IGhhbmQuAAAADW1heF9oYW5kX3NpemUAAAAAAAAEAAAAPkxhcmdlc3QgTW9kZSA3LzEyIHJpbmcsIGFuZCB0aGUgbWVtYmVyIGNvdW50IG9mIGEgcGFkZGVkIHJpbmcuAAAAAAANbWF4X3Jpbmdfc2l6ZQAAAAAAAAQ=",
+        "AAAAAQAAAKZIb3VzZSBydWxlcyBvZiBvbmUgZ2FtZSwgZml4ZWQgYXQgc3RhcnQuIGBzdGFydF9nYW1lYCBhbmQKYHN0YXJ0X211bHRpcGxheWVyX2dhbWVgIHVzZSBgR2FtZUNvbmZpZzo6c3RhbmRhcmQoKWA7IG90aGVyIHJ1bGVzIGFyZQpjaG9zZW4gd2l0aCBgc3RhcnRfZ2FtZV93aXRoX2NvbmZpZ2AuAAAAAAAAAAAACkdhbWVDb25maWcAAAAAAAsAAAAAAAAADmNhcmRzX3Blcl9zdWl0AAAAAAAEAAAAAAAAAAlkZWNrX3NpemUAAAAAAAAEAAAAAAAAABFkcmF3X3VudGlsX2ZvbGxvdwAAAAAAAAEAAAAAAAAACWhhbmRfc2l6ZQAAAAAAAAQAAAAAAAAADHBlbmFsdHlfZHJhdwAAAAQAAAAAAAAADXN0cmljdF9yZXZlYWwAAAAAAAABAAAAAAAAAAV0ZWFtcwAAAAAAAAEAAAAAAAAACXRpZV9icmVhawAAAAAAAAQAAAAAAAAAEXRpbWVfYmFua19sZWRnZXJzAAAAAAAABAAAAAAAAAAPdGltZW91dF9sZWRnZXJzAAAAAAQAAAAAAAAAC3prX3JlcXVpcmVkAAAAAAE=",
+        "AAAAAQAAAB9BIG1hdGNoIGluIHByb2dyZXNzIG9yIGRlY2lkZWQuAAAAAAAAAAAKTWF0Y2hTdGF0ZQAAAAAABgAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAAZzY29yZXMAAAAAA+oAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZ0YXJnZXQAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAABA==",
+        "AAAAAQAAAHdPbmUgcGxheWVyJ3Mgc2VhdC4gU2xvdCBgbmAgKDEtYmFzZWQsIGFzIGluIHRyaWNrIHN0YXRlcywgb3V0Y29tZSBjb2RlcwphbmQgZXZlbnRzKSBpcyBgQ2FuZ2t1bGFuR2FtZTo6cGxheWVyc1tuIC0gMV1gLgAAAAAAAAAAClBsYXllclNsb3QAAAAAAAwAAAAAAAAABGhhbmQAAAPqAAAABAAAAAAAAAALcGxheV9jb21taXQAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5X3JldmVhbGVkAAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAAC3NlZWRfY29tbWl0AAAAA+gAAAPuAAAAIAAAAAAAAAAJc2VlZF9oYXNoAAAAAAAD6AAAA+4AAAAgAAAAAAAAAAlzZWVkX21vZGUAAAAAAAPoAAAABAAAAAAAAAANc2VlZF9yZXZlYWxlZAAAAAAAAAEAAAAAAAAACnRyaWNrX2NhcmQAAAAAA+gAAAAEAAAAAAAAAAp0cmlja3Nfd29uAAAAAAAEAAAAAAAAAAd6a19wbGF5AAAAAAE=",
+        "AAAAAQAAAEdBIHNlYXQgYXMgYGdldF9nYW1lYCBzaG93cyBpdCB0byBhbnlvbmU6IGNvdW50cyBhbmQgcHJvZ3Jlc3MsIG5vIGNhcmRzLgAAAAAAAAAAClB1YmxpY1NlYXQAAAAAAAgAAAAAAAAACWhhbmRfc2l6ZQAAAAAAAAQAAAAAAAAADnBsYXlfY29tbWl0dGVkAAAAAAABAAAAAAAAAA1wbGF5X3JldmVhbGVkAAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAADnNlZWRfY29tbWl0dGVkAAAAAAABAAAAAAAAAA1zZWVkX3JldmVhbGVkAAAAAAAAAQAAAAAAAAAKdHJpY2tzX3dvbgAAAAAABA==",
+        "AAAAAAAAAI1TZW5kIGEgcHJlZGVmaW5lZCBlbW90ZSB0byBldmVyeSBvcHBvbmVudCBpbiBhbiBhY3RpdmUgZ2FtZS4KCk9wcG9uZW50cyB3aG8gbXV0ZWQgZW1vdGVzIGdldCBub3RoaW5nOyByZXR1cm5zIGBmYWxzZWAgaWYgdGhhdCBpcwphbGwgb2YgdGhlbS4AAAAAAAAKc2VuZF9lbW90ZQAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVlbW90ZQAAAAAAAAQAAAABAAAD6QAAAAEAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAOxQYXVzZSBvciByZXN1bWUgdGhlIGNvbnRyYWN0LCBmb3IgaW5jaWRlbnQgcmVzcG9uc2UuIFdoaWxlIHBhdXNlZCwKbmV3IGdhbWVzLCBsb2JieSBjaGFsbGVuZ2VzIGFuZCBjb21taXRzIGZhaWwgd2l0aCBgQ29udHJhY3RQYXVzZWRgOwpyZXZlYWxzLCB0aW1lb3V0cywgZm9yZmVpdHMsIGZvcmNlZCB3aW5zIGFuZCBjbGFpbXMgc3RpbGwgd29yaywgc28KZ2FtZXMgaW4gZmxpZ2h0IGNhbiBiZSB3b3VuZCBkb3duLgAAAApzZXRfcGF1c2VkAAAAAAABAAAAAAAAAAZwYXVzZWQAAAAAAAEAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
         "AAAAAAAAAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAABQAAAERFbWl0dGVkIHdoZW4gYSBaSyByaW5nIHNpZ21hIHByb29mIHZhbGlkYXRlcyBhIGNhcmQgcGxheSBjb21taXRtZW50LgAAAAAAAAAURXZaa0NhcmRQbGF5VmVyaWZpZWQAAAABAAAAGGV2X3prX2NhcmRfcGxheV92ZXJpZmllZAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAAAAAA52YWxpZF9zZXRfc2l6ZQAAAAAABAAAAAAAAAAC",
-        "AAAAAAAAAXpDb21taXQgYSBwbGF5IGFjdGlvbjogYGNvbW1pdF9oYXNoID0ga2VjY2FrMjU2KGFjdGlvbl91MzJfYmUgfHwgc2FsdClgLgoKYGFjdGlvbmAgaXMgZWl0aGVyIGEgdmFsaWQgYGNhcmRfaWRgICgwLTM1KSB0byBwbGF5IGEgY2FyZCwgb3IKYENBTk5PVF9GT0xMT1dfU0VOVElORUxgICgweEZGRkZGRkZGKSB0byBkZWNsYXJlIGNhbm5vdCBmb2xsb3cgc3VpdC4KVGhlIGFjdHVhbCBhY3Rpb24gaXMgaGlkZGVuIHVudGlsIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4KCmBleHBlY3RlZF9ub25jZWAgbXVzdCBlcXVhbCB0aGUgY3VycmVudCBgYWN0aW9uX25vbmNlYCB0byBwcmV2ZW50CnJlcGxheSBhdHRhY2tzIGFuZCBzdGFsZS1zdGF0ZSBzdWJtaXNzaW9ucy4AAAAAAAtjb21taXRfcGxheQAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAAAAAA5leHBlY3RlZF9ub25jZQAAAAAABAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAEJDb21taXQgYSBzZWVkIGhhc2guIEJvdGggcGxheWVycyBtdXN0IGNvbW1pdCBiZWZvcmUgcmV2ZWFsIGJlZ2lucy4AAAAAAAtjb21taXRfc2VlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAANZFbmQgYSBsaXZlIHNlc3Npb24gd2l0aCBgT1VUQ09NRV9DQU5DRUxMRURgLCBmb3Igc2Vzc2lvbnMgc3RyYW5kZWQKYnkgYSBtaXNjb25maWd1cmVkIGh1YiBvciB2ZXJpZmllci4gRXZlcnkgc2VhdCBnZXRzIGl0cyBzdGFrZSBiYWNrCmFuZCBub3RoaW5nIGdvZXMgdG8gdGhlIEdhbWUgSHViLCB3aGljaCBvbmx5IHRha2VzIGEgd2lubmVyLCBvciB0aGUKTGVhZGVyYm9hcmQuAAAAAAALY2FuY2VsX2dhbWUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcmVhc29uAAAAAAARAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAXlDb21taXQgYSBwbGF5IGFjdGlvbjogYGNvbW1pdF9oYXNoID0ga2VjY2FrMjU2KGFjdGlvbl91MzJfYmUgfHwgc2FsdClgLgoKYGFjdGlvbmAgaXMgZWl0aGVyIGEgdmFsaWQgYGNhcmRfaWRgICgwLTM1KSB0byBwbGF5IGEgY2FyZCwgb3IKYENBTk5PVF9GT0xMT1dfU0VOVElORUxgICgweEZGRkZGRkZGKSB0byBkZWNsYXJlIGNhbm5vdCBmb2xsb3cgc3VpdC4KVGhlIGFjdHVhbCBhY3Rpb24gaXMgaGlkZGVuIHVudGlsIGV2ZXJ5IHBsYXllciBoYXMgY29tbWl0dGVkLgoKYGV4cGVjdGVkX25vbmNlYCBtdXN0IGVxdWFsIHRoZSBjdXJyZW50IGBhY3Rpb25fbm9uY2VgIHRvIHByZXZlbnQKcmVwbGF5IGF0dGFja3MgYW5kIHN0YWxlLXN0YXRlIHN1Ym1pc3Npb25zLgAAAAAAAAtjb21taXRfcGxheQAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAAAAAA5leHBlY3RlZF9ub25jZQAAAAAABAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAUBDb21taXQgYSBzZWVkIGhhc2guIEV2ZXJ5IHBsYXllciBtdXN0IGNvbW1pdCBiZWZvcmUgcmV2ZWFsIGJlZ2lucy4KCmBwcm9vZl9tb2RlYCAoYFNFRURfUFJPT0ZfTklaS2AsIGBTRUVEX1BST09GX1BFREVSU0VOYCBvcgpgU0VFRF9QUk9PRl9OT0lSYCkgaXMgdGhlIHByb29mIHR5cGUgdGhpcyBwbGF5ZXIgd2lsbCByZXZlYWwgd2l0aC4KSXQgaXMgcHVibGlzaGVkIGluIGBFdlNlZWRDb21taXR0ZWRgIHNvIHRoZSBvcHBvbmVudCBrbm93cyB3aGF0IHRvCmV4cGVjdCwgYW5kIGByZXZlYWxfc2VlZGAgcmVqZWN0cyBhIHByb29mIG9mIGFueSBvdGhlciB0eXBlLgAAAAtjb21taXRfc2VlZAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAAAAAApwcm9vZl9tb2RlAAAAAAAEAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAENDb21wYWN0IHN1bW1hcnkgb2YgYSBmaW5pc2hlZCBnYW1lLCBzdG9yZWQgcGVyc2lzdGVudGx5IHBlciBwbGF5ZXIuAAAAAAAAAAALR2FtZVN1bW1hcnkAAAAABgAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAhvcHBvbmVudAAAABMAAAAAAAAAB291dGNvbWUAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAALdHJpY2tzX2xvc3QAAAAABAAAAAAAAAAKdHJpY2tzX3dvbgAAAAAABA==",
+        "AAAAAAAAAAAAAAALZ2V0X3JlbWF0Y2gAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAxSZW1hdGNoT2ZmZXI=",
+        "AAAAAQAAAHhBIHBhZ2Ugb2YgYSBwbGF5ZXIncyBoaXN0b3J5LiBgbmV4dF9jdXJzb3JgIGlzIHdoZXJlIHRoZSBuZXh0IGNhbGwKcmVzdW1lcywgYE5vbmVgIG9uY2UgdGhlIHdob2xlIGhpc3RvcnkgaGFzIGJlZW4gcmVhZC4AAAAAAAAAC0hpc3RvcnlQYWdlAAAAAAIAAAAAAAAAB2VudHJpZXMAAAAD6gAAB9AAAAALR2FtZVN1bW1hcnkAAAAAAAAAAAtuZXh0X2N1cnNvcgAAAAPoAAAABA==",
         "AAAAAAAAAWBSZXZlYWwgYSBwcmV2aW91c2x5IGNvbW1pdHRlZCBwbGF5IGFjdGlvbi4KCmBjYXJkX2lkYCBpcyB0aGUgYWN0dWFsIGNhcmQgdG8gcGxheSAoMC0zNSksIG9yIGBDQU5OT1RfRk9MTE9XX1NFTlRJTkVMYAp0byBkZWNsYXJlIGNhbm5vdCBmb2xsb3cgc3VpdC4KYHNhbHRgIGlzIHRoZSByYW5kb20gMzItYnl0ZSB2YWx1ZSB1c2VkIGluIHRoZSBjb21taXQ6Ci0gTGVnYWN5IG1vZGU6IGtlY2NhazI1NihjYXJkX2lkX3UzMl9iZSB8fCBzYWx0KSA9PSBjb21taXRfaGFzaAotIFpLIG1vZGU6IHNhbHQgPSBibGluZGluZyBmYWN0b3I7IGtlY2NhazI1NihjYXJkX2lkwrdHICsgYmxpbmRpbmfCt0gpID09IGNvbW1pdF9oYXNoAAAAC3JldmVhbF9wbGF5AAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHY2FyZF9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
-        "AAAAAAAAAxNSZXZlYWwgYSBzZWVkIHVzaW5nIGEgWmVyby1Lbm93bGVkZ2UgUHJvb2YuIFRoZSByYXcgc2VlZCBpcwoqKm5ldmVyKiogc3VibWl0dGVkIG9uLWNoYWluOyBvbmx5IGBzZWVkX2hhc2ggPSBrZWNjYWsyNTYoc2VlZClgIGlzCnJldmVhbGVkLiBUaGUgcHJvb2YgZGVtb25zdHJhdGVzIGtub3dsZWRnZSBvZiB0aGUgc2VlZCB3aXRob3V0CmRpc2Nsb3NpbmcgaXQsIHZlcmlmaWVkIHZpYSB0aGUgWksgdmVyaWZpZXIgY29udHJhY3QuCgpUd28gcHJvb2YgbW9kZXMgYXJlIHN1cHBvcnRlZCAoYXV0by1kZXRlY3RlZCBieSBwcm9vZiBsZW5ndGgpOgoKKipOSVpLIE1vZGUgKDY0IGJ5dGVzKToqKiBgYmxpbmRpbmcoMzIpIHx8IHJlc3BvbnNlKDMyKWAKSGFzaC1iYXNlZCBGaWF0LVNoYW1pciBwcm9vZi4KCioqUGVkZXJzZW4gTW9kZSAoMjI0IGJ5dGVzKToqKiBgQyg5NikgfHwgUig5NikgfHwgel9yKDMyKWAKQkxTMTItMzgxIFBlZGVyc2VuIGNvbW1pdG1lbnQgKyBTY2hub3JyIG9uIGJsaW5kaW5nLgpjb21taXRfaGFzaCA9IGtlY2NhazI1NihDKSwgdmVyaWZpZWQgb24tY2hhaW4gZm9yIGJpbmRpbmcuCgpPbmNlIGJvdGggc2VlZHMgYXJlIHJldmVhbGVkLCB0aGUgZGVjayBpcyBzaHVmZmxlZCBhbmQgY2FyZHMgYXJlIGRlYWx0LgoKIyBBcmd1bWVudHMKKiBgc2VlZF9oYXNoYCAtIGBrZWNjYWsyNTYoc2VlZClgLCB0aGUgb25lLXdheSBoYXNoIG9mIHRoZSByYXcgc2VlZAoqIGBwcm9vZmAgLSBaSyBwcm9vZiAoNjQgYnl0ZXMgZm9yIE5JWkssIDIyNCBieXRlcyBmb3IgUGVkZXJzZW4pAAAAAAtyZXZlYWxfc2VlZAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACXNlZWRfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAxJSZXZlYWwgYSBzZWVkIHVzaW5nIGEgWmVyby1Lbm93bGVkZ2UgUHJvb2YuIFRoZSByYXcgc2VlZCBpcwoqKm5ldmVyKiogc3VibWl0dGVkIG9uLWNoYWluOyBvbmx5IGBzZWVkX2hhc2ggPSBrZWNjYWsyNTYoc2VlZClgIGlzCnJldmVhbGVkLiBUaGUgcHJvb2YgZGVtb25zdHJhdGVzIGtub3dsZWRnZSBvZiB0aGUgc2VlZCB3aXRob3V0CmRpc2Nsb3NpbmcgaXQsIHZlcmlmaWVkIHZpYSB0aGUgWksgdmVyaWZpZXIgY29udHJhY3QuCgpUd28gcHJvb2YgbW9kZXMgYXJlIHN1cHBvcnRlZCAoYXV0by1kZXRlY3RlZCBieSBwcm9vZiBsZW5ndGgpOgoKKipOSVpLIE1vZGUgKDY0IGJ5dGVzKToqKiBgYmxpbmRpbmcoMzIpIHx8IHJlc3BvbnNlKDMyKWAKSGFzaC1iYXNlZCBGaWF0LVNoYW1pciBwcm9vZi4KCioqUGVkZXJzZW4gTW9kZSAoMjI0IGJ5dGVzKToqKiBgQyg5NikgfHwgUig5NikgfHwgel9yKDMyKWAKQkxTMTItMzgxIFBlZGVyc2VuIGNvbW1pdG1lbnQgKyBTY2hub3JyIG9uIGJsaW5kaW5nLgpjb21taXRfaGFzaCA9IGtlY2NhazI1NihDKSwgdmVyaWZpZWQgb24tY2hhaW4gZm9yIGJpbmRpbmcuCgpPbmNlIGV2ZXJ5IHNlZWQgaXMgcmV2ZWFsZWQsIHRoZSBkZWNrIGlzIHNodWZmbGVkIGFuZCBjYXJkcyBhcmUgZGVhbHQuCgojIEFyZ3VtZW50cwoqIGBzZWVkX2hhc2hgIC0gYGtlY2NhazI1NihzZWVkKWAsIHRoZSBvbmUtd2F5IGhhc2ggb2YgdGhlIHJhdyBzZWVkCiogYHByb29mYCAtIFpLIHByb29mICg2NCBieXRlcyBmb3IgTklaSywgMjI0IGJ5dGVzIGZvciBQZWRlcnNlbikAAAAAAAtyZXZlYWxfc2VlZAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACXNlZWRfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAQAAATlUb2tlbiBlc2Nyb3cgZm9yIHN0YWtlcy4gV2hpbGUgc2V0LCBgc3RhcnRfZ2FtZWAgdHJhbnNmZXJzIGVhY2ggc3Rha2UKaW50byB0aGUgY29udHJhY3QgaW4gYHRva2VuYCwgYW5kIGBjbGFpbV93aW5uaW5nc2AgcGF5cyB0aGUgcG90IG91dApsZXNzIGByYWtlX2Jwc2Agb2YgYSB3b24gcG90LCB3aGljaCBpcyBjcmVkaXRlZCB0byBgdHJlYXN1cnlgIGZvcgpgd2l0aGRyYXdfdHJlYXN1cnlgLiBOZXcgZ2FtZXMKc25hcHNob3QgdGhpcyBhdCBgc3RhcnRfZ2FtZWAsIHNvIGNoYW5naW5nIGl0IG5ldmVyIGFmZmVjdHMgZ2FtZXMgaW4KcHJvZ3Jlc3MuAAAAAAAAAAAAAAtTdGFrZUVzY3JvdwAAAAADAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAIdHJlYXN1cnkAAAAT",
+        "AAAAAAAAAJFTdGFydCBhIG1hdGNoIHRvIGB0YXJnZXRgIHBvaW50cyBhbmQgZGVhbCBpdHMgZmlyc3QgZ2FtZS4gRXZlcnkKcGxheWVyIGF1dGhvcml6ZXMgYChtYXRjaF9pZCwgdGFyZ2V0KWAuIFJldHVybnMgdGhlIHNlc3Npb24gaWQgb2YKdGhlIGZpcnN0IGRlYWwuAAAAAAAAC3N0YXJ0X21hdGNoAAAAAAMAAAAAAAAACG1hdGNoX2lkAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAGdGFyZ2V0AAAAAAAEAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAMVPbmUgcmVzb2x2ZWQgdHJpY2ssIGFzIGNoYWluZWQgaW50byB0aGUgZ2FtZSB0cmFuc2NyaXB0LiBgY2FyZHNgIGhhcwpvbmUgZW50cnkgcGVyIHNlYXQsIGBDQU5OT1RfRk9MTE9XX1NFTlRJTkVMYCBpZiBhYnNlbnQ7IGB3aW5uZXJgIGlzIDAKZm9yIGEgd2FzdGUgdHJpY2suIGBsZWRnZXJgIGlzIG5vdCBwYXJ0IG9mIHRoZSB0cmFuc2NyaXB0LgAAAAAAAAAAAAALVHJpY2tSZWNvcmQAAAAABAAAAAAAAAAFY2FyZHMAAAAAAAPqAAAABAAAAAAAAAAHZmxpcHBlZAAAAAAEAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAABA==",
+        "AAAAAAAAAY9SZWNoZWNrIGBwbGF5ZXJgJ3MgWksgY2FyZCBwbGF5cyBvbmNlIHRoZSBnYW1lIGlzIGZpbmlzaGVkLiBFYWNoCnBsYXkncyBQZWRlcnNlbiBjb21taXRtZW50IGlzIHJlY29tcHV0ZWQgZnJvbSB0aGUgY2FyZCB0aGUgdHJpY2sKaGlzdG9yeSBzaG93cyBmb3IgdGhlIHBsYXllcidzIHNlYXQgYW5kIHRoZSBtYXRjaGluZyBlbnRyeSBvZgpgYmxpbmRpbmdzYCwgb25lIHBlciByZXNvbHZlZCBwbGF5IGluIG9yZGVyLiBBIHBsYXkgd2hvc2UgdHJpY2sgbmV2ZXIKcmVzb2x2ZWQsIGJlY2F1c2UgdGhlIGdhbWUgZW5kZWQgZmlyc3QsIHdhcyBuZXZlciByZXZlYWxlZCBhbmQgaXMKc2tpcHBlZC4gQW55b25lIG1heSBjYWxsIHRoaXMuIFJldHVybnMgd2hldGhlciBldmVyeSBjb21taXRtZW50Cm9wZW5lZC4AAAAADGF1ZGl0X3JldmVhbAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJYmxpbmRpbmdzAAAAAAAD6gAAA+4AAAAgAAAAAQAAA+kAAAABAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAFhUaGUgc3RhdGUgYSBzZXR0bGVtZW50IHdpbGwgZmluaXNoIHdpdGgsIHVubGVzcyBhIGxhdGVyIG9uZSBhcnJpdmVzCmJ5IGBjaGFsbGVuZ2VfZW5kc2AuAAAAAAAAAAxDaGFubmVsQ2xhaW0AAAACAAAAAAAAAA5jaGFsbGVuZ2VfZW5kcwAAAAAABAAAAAAAAAAFc3RhdGUAAAAAAAfQAAAADENoYW5uZWxTdGF0ZQ==",
+        "AAAAAQAAAC1BIGdhbWUgc3RhdGUgZXZlcnkgc2VhdCBoYXMgc2lnbmVkIG9mZi1jaGFpbi4AAAAAAAAAAAAADENoYW5uZWxTdGF0ZQAAAAUAAAAAAAAACmhhbmRzX2hhc2gAAAAAA+4AAAAgAAAAAAAAAAxtb3ZlX2NvdW50ZXIAAAAEAAAAAAAAAAdvdXRjb21lAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnRyaWNrcwAAAAAD6gAAAAQ=",
+        "AAAAAAAAARdHZXQgdGhlIGxlZGdlciBkZWFkbGluZSBvZiBhbiBhY3RpdmUgZ2FtZSBmb3IgY2xpZW50LXNpZGUgY2xvY2tzLgoKUmV0dXJucyBgKGRlYWRsaW5lX2xlZGdlciwgY3VycmVudF9sZWRnZXIsIChsaWZlY3ljbGVfc3RhdGUsIHRyaWNrX3N0YXRlKSlgOwp0aGUgc3RhdGUgcGFpciB0ZWxscyB0aGUgVUkgd2hvc2UgbW92ZSB0aGUgY2xvY2sgaXMgcnVubmluZyBmb3IuCmBOb25lYCBpZiB0aGUgZ2FtZSBpcyB1bmtub3duLCBmaW5pc2hlZCwgb3IgaXRzIGNsb2NrIGhhcyBub3Qgc3RhcnRlZC4AAAAADGdldF9kZWFkbGluZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAA+0AAAADAAAABAAAAAQAAAPtAAAAAgAAAAQAAAAE",
+        "AAAAAAAAAAAAAAAMZ2V0X2RlbGVnYXRlAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAMZ2V0X2hhbmRfY2FwAAAAAAAAAAEAAAfQAAAADUhhbmRDYXBDb25maWcAAAA=",
         "AAAAAAAAAAAAAAAMZ2V0X3ZlcmlmaWVyAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAQAAAHtFdmVyeSBjb250cmFjdCBhIGNsaWVudCBkZXBlbmRzIG9uLCByZXNvbHZlZCBpbiBvbmUgcmVhZC4gT3B0aW9uYWwKaW50ZWdyYXRpb25zIGFyZSBgTm9uZWAgdW50aWwgdGhlIGFkbWluIGNvbmZpZ3VyZXMgdGhlbS4AAAAAAAAAAAxJbnRlZ3JhdGlvbnMAAAAFAAAAQ2tlY2NhazI1NiBvZiB0aGUgWERSLWVuY29kZWQgcnVsZSBjb25maWc7IGNoYW5nZXMgd2hlbmV2ZXIgaXQgZG9lcy4AAAAAC2NvbmZpZ19oYXNoAAAAA+4AAAAgAAAAAAAAAANodWIAAAAAEwAAAAAAAAALbGVhZGVyYm9hcmQAAAAD6AAAABMAAAAAAAAADm1vZGVfdmVyaWZpZXJzAAAAAAPsAAAABAAAABMAAAAAAAAACHZlcmlmaWVyAAAAEw==",
+        "AAAAAAAAAQFSZXdyaXRlIGEgc2Vzc2lvbiBzdG9yZWQgdW5kZXIgYW4gb2xkZXIgYENhbmdrdWxhbkdhbWVgIGxheW91dCBpbgp0aGUgY3VycmVudCBvbmUuIEV2ZXJ5IHJlYWQgYWxyZWFkeSBtaWdyYXRlcyBpbiBtZW1vcnksIHNvIHRoaXMgb25seQpzYXZlcyByZWRvaW5nIGl0OyBhbnlvbmUgbWF5IGNhbGwgaXQuIExlYXZlcyB0aGUgZGVhZGxpbmUgYW5kIFRUTAphbG9uZS4gUmV0dXJucyB0aGUgdmVyc2lvbiB0aGUgc2Vzc2lvbiB3YXMgc3RvcmVkIHVuZGVyLgAAAAAAAAxtaWdyYXRlX2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAKJSZWdpc3RlciBgcGxheWVyYCdzIEVkMjU1MTkga2V5IGZvciBzaWduaW5nIGNoYW5uZWwgc3RhdGVzIG9mCmBzZXNzaW9uX2lkYC4gUmVnaXN0ZXJpbmcgYWdhaW4gcmVwbGFjZXMgdGhlIGtleSB1bnRpbCBldmVyeSBzZWF0CmhhcyBvbmU7IHRoZSBrZXlzIGFyZSB0aGVuIGxvY2tlZC4AAAAAAAxvcGVuX2NoYW5uZWwAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAA2tleQAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAQAAAD5TdGFrZXMgYXdhaXRpbmcgYGNsYWltX3dpbm5pbmdzYCwgcmVjb3JkZWQgYnkgYGZpbmFsaXplX2dhbWVgLgAAAAAAAAAAAAxQZW5kaW5nQ2xhaW0AAAAFAAAAAAAAAA5leHBpcmVzX2xlZGdlcgAAAAAABAAAAAAAAAAHcGF5b3V0cwAAAAPqAAAH0AAAAAZQYXlvdXQAAAAAAAAAAAAEcmFrZQAAAAsAAAAAAAAABXRva2VuAAAAAAAD6AAAABMAAAAAAAAACHRyZWFzdXJ5AAAD6AAAABM=",
+        "AAAAAQAAASNBIHBlbmRpbmcgcmVtYXRjaCBvZiBhIGZpbmlzaGVkIHNlc3Npb24uIFNlYXRzIGpvaW4gYGFncmVlZGAgdGhyb3VnaApgcmVxdWVzdF9yZW1hdGNoYCAvIGBhY2NlcHRfcmVtYXRjaGA7IHRoZSByZW1hdGNoIHN0YXJ0cyBhcyBzb29uIGFzCmV2ZXJ5IHNlYXQgaGFzIGFncmVlZC4gVGhlIG9mZmVyIGNhcnJpZXMgdGhlIHNlYXRzLCBzdGFrZXMgYW5kIHRlcm1zIG9mCnRoZSBmaW5pc2hlZCBnYW1lLCBzbyBpdCBjYW4gYmUgYWNjZXB0ZWQgb3IgY2FuY2VsbGVkIGFmdGVyIHRoYXQgZ2FtZQpoYXMgZXhwaXJlZC4AAAAAAAAAAAxSZW1hdGNoT2ZmZXIAAAAIAAAAAAAAAAZhZ3JlZWQAAAAAA+oAAAATAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAAAAAAdwbGF5ZXJzAAAAA+oAAAATAAAAAAAAAAZwb2ludHMAAAAAA+oAAAALAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAAAAAAACnJlbWF0Y2hfaWQAAAAAAAQAAAAAAAAAC3N0YWtlX3Rva2VuAAAAA+gAAAATAAAAAAAAAAh0cmVhc3VyeQAAA+gAAAAT",
+        "AAAAAAAAAFpXaXRoZHJhdyB0aGUgcmVtYWluaW5nIGFsbG93YW5jZSBvZiBhIGd1ZXN0LiBHYW1lcyBhbHJlYWR5IHN0YXJ0ZWQKa2VlcCB0aGVpciBhdHRyaWJ1dGlvbi4AAAAAAAxyZXZva2VfZ3Vlc3QAAAACAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAABWd1ZXN0AAAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAG5TZXQgdGhlIGhhbmQtc2l6ZSBjYXAgYW5kIG92ZXJmbG93IHJ1bGUgZm9yIGdhbWVzIHN0YXJ0ZWQgZnJvbSBub3cgb24uClRoZSBjYXAgbXVzdCBiZSBhdCBsZWFzdCB0aGUgZGVhbCBzaXplLgAAAAAADHNldF9oYW5kX2NhcAAAAAIAAAAAAAAADW1heF9oYW5kX3NpemUAAAAAAAAEAAAAAAAAAA1vdmVyZmxvd19ydWxlAAAAAAAABAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
         "AAAAAAAAAAAAAAAMc2V0X3ZlcmlmaWVyAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAbZMYW5kIGEgd2hvbGUgdHdvLXBsYXllciB0cmljayBpbiBvbmUgdHJhbnNhY3Rpb246IGJvdGggY29tbWl0cywgdGhlbgpib3RoIHJldmVhbHMsIGFzIGBjb21taXRfcGxheWAgYW5kIGByZXZlYWxfcGxheWAgd291bGQuCgpFYWNoIHBheWxvYWQgaXMgYXV0aG9yaXplZCB3aXRoIGByZXF1aXJlX2F1dGhfZm9yX2FyZ3MoKHNlc3Npb25faWQsCnBheWxvYWQpKWAgYnkgaXRzIHBsYXllciwgb3IgaXRzIHNlc3Npb24gZGVsZWdhdGUsIHNvIGEgcmVsYXllciBjYW4KZ2F0aGVyIHRoZSB0d28gc2lnbmVkIGhhbHZlcyBvZmYtY2hhaW4gYW5kIHN1Ym1pdCB0aGVtLiBQYXlsb2FkcyBtdXN0CmJlIGluIHNlYXQgb3JkZXIgYW5kIGNhcnJ5IHRoZSBjdXJyZW50IGBhY3Rpb25fbm9uY2VgLCB3aGljaCBtYWtlcyBhCnNpZ25lZCBoYWxmIGdvb2QgZm9yIHRoaXMgdHJpY2sgb25seS4AAAAAAAxzdWJtaXRfdHJpY2sAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAApwMV9wYXlsb2FkAAAAAAfQAAAADFRyaWNrUGF5bG9hZAAAAAAAAAAKcDJfcGF5bG9hZAAAAAAH0AAAAAxUcmlja1BheWxvYWQAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
         "AAAAAAAAAAAAAAAMdGlja190aW1lb3V0AAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAJRPbmUgcGxheWVyJ3MgaGFsZiBvZiBhIHRyaWNrIGZvciBgc3VibWl0X3RyaWNrYDogYSBsZWdhY3kgcGxheSBjb21taXQKYW5kIGl0cyBvcGVuaW5nLCBzaWduZWQgYnkgdGhlIHBsYXllciAob3IgaXRzIHNlc3Npb24gZGVsZWdhdGUpIGFoZWFkCm9mIHRpbWUuAAAAAAAAAAxUcmlja1BheWxvYWQAAAAFAAAAAAAAAAdjYXJkX2lkAAAAAAQAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAAAAAAVub25jZQAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAg",
+        "AAAAAQAAADtXaGF0IGEgc2VhdGVkIHBsYXllciBtYXkgZG8gbmV4dCwgZnJvbSBgZ2V0X3ZhbGlkX2FjdGlvbnNgLgAAAAAAAAAADFZhbGlkQWN0aW9ucwAAAAMAAAAAAAAACW5leHRfY2FsbAAAAAAAABEAAAAAAAAABW5vbmNlAAAAAAAABAAAAAAAAAAIcGxheWFibGUAAAPqAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAADUNhbmdrdWxhbkdhbWUAAAAAAAAcAAAAAAAAAAxhY3Rpb25fbm9uY2UAAAAEAAAAGkhvdXNlIHJ1bGVzIGZpeGVkIGF0IHN0YXJ0AAAAAAAGY29uZmlnAAAAAAfQAAAACkdhbWVDb25maWcAAAAAAAAAAAAPZGVhZGxpbmVfbGVkZ2VyAAAAA+gAAAAEAAAAAAAAAA5kZWFkbGluZV9ub25jZQAAAAAD6AAAAAQAAAAAAAAACWRyYXdfcGlsZQAAAAAAA+oAAAAEAAAAAAAAAAxmbGlwcGVkX2NhcmQAAAPoAAAABAAAAB5TZWF0IHRoYXQgZm9yZmVpdGVkLCAwIGlmIG5vbmUAAAAAAAxmb3JmZWl0ZWRfYnkAAAAEAAAAPUxlZGdlciBzZXF1ZW5jZSBvZiB0aGUgbGFzdCB0aWNrX3RpbWVvdXQgY2FsbCAocmF0ZSBsaW1pdGluZykAAAAAAAAQbGFzdF90aWNrX2xlZGdlcgAAAAQAAAAAAAAAD2xpZmVjeWNsZV9zdGF0ZQAAAAAEAAAAAAAAAA1tYXhfaGFuZF9zaXplAAAAAAAABAAAADlQZXItbW9kZSB2ZXJpZmllcnMgcGlubmVkIGF0IHN0YXJ0LCBvdmVycmlkaW5nIGB2ZXJpZmllcmAAAAAAAAAObW9kZV92ZXJpZmllcnMAAAAAA+wAAAAEAAAAEwAAAAAAAAAHb3V0Y29tZQAAAAAEAAAAAAAAAA1vdmVyZmxvd19ydWxlAAAAAAAABAAAABky4oCTNCBzZWF0cyBpbiBwbGF5IG9yZGVyAAAAAAAAB3BsYXllcnMAAAAD6gAAB9AAAAAKUGxheWVyU2xvdAAAAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAAvTGF5b3V0IHZlcnNpb24sIGBHQU1FX1NDSEVNQV9WRVJTSU9OYCBvbmNlIHJlYWQAAAAADnNjaGVtYV92ZXJzaW9uAAAAAAAEAAAAAAAAAAtzdGFrZV90b2tlbgAAAAPoAAAAEwAAAERCbGl0eiB0aW1lIGJhbmsgbGVmdCBwZXIgc2VhdCwgaW4gbGVkZ2VyczsgZW1wdHkgd2l0aG91dCBhIHRpbWUgYmFuawAAAAp0aW1lX2JhbmtzAAAAAAPqAAAABAAAAENSdW5uaW5nIGtlY2NhazI1NiBjaGFpbiBvdmVyIHRoZSBkZWFsIHNlZWQgYW5kIGV2ZXJ5IHJlc29sdmVkIHRyaWNrAAAAAAp0cmFuc2NyaXB0AAAAAAPuAAAAIAAAAAAAAAAIdHJlYXN1cnkAAAPoAAAAEwAAADZUaGUgbGFzdCBgVFJJQ0tfTE9HX0xFTmAgcmVzb2x2ZWQgdHJpY2tzLCBvbGRlc3QgZmlyc3QAAAAAAAl0cmlja19sb2cAAAAAAAPqAAAH0AAAAAtUcmlja1JlY29yZAAAAAB1VHJhbnNjcmlwdCBqdXN0IGJlZm9yZSB0aGUgb2xkZXN0IHRyaWNrIGluIGB0cmlja19sb2dgLCBzbyBjaGFpbmluZwp0aGUgbG9nZ2VkIHRyaWNrcyBvbnRvIGl0IHJlcHJvZHVjZXMgYHRyYW5zY3JpcHRgAAAAAAAADnRyaWNrX2xvZ19iYXNlAAAAAAPuAAAAIAAAAAAAAAALdHJpY2tfc3RhdGUAAAAABAAAAAAAAAAKdHJpY2tfc3VpdAAAAAAD6AAAAAQAAAAsVHJpY2tzIGRyb3BwZWQgZnJvbSB0aGUgZnJvbnQgb2YgYHRyaWNrX2xvZ2AAAAANdHJpY2tzX3BydW5lZAAAAAAAAAQAAABQTGVkZ2VyIHNlcXVlbmNlIG9mIHRoZSBsYXN0IHdyaXRlOyB0aGUgZW50cnkgZXhwaXJlcwpgR0FNRV9UVExfTEVER0VSU2AgYWZ0ZXIgaXQAAAAOdXBkYXRlZF9sZWRnZXIAAAAAAAQAAABCWksgdmVyaWZpZXIgcGlubmVkIGF0IHN0YXJ0OyBgc2V0X3ZlcmlmaWVyYCBhZmZlY3RzIG5ldyBnYW1lcyBvbmx5AAAAAAAIdmVyaWZpZXIAAAATAAAAPFByYWN0aWNlIGdhbWU6IHNlYXQgMiBpcyBhIGBDYW5na3VsYW5Cb3RgIHRoZSBnYW1lIHBsYXlzIGZvcgAAAAZ2c19ib3QAAAAAAAE=",
+        "AAAAAAAAAJRGaW5pc2ggYSBnYW1lIGZyb20gaXRzIGNoYW5uZWwgY2xhaW0gb25jZSB0aGUgY2hhbGxlbmdlIHdpbmRvdyBoYXMKcGFzc2VkLCB1bmxlc3MgcGxheSBoYXMgc2luY2UgbW92ZWQgb24tY2hhaW4uIE5lZWRzIG5vIGF1dGguIFJldHVybnMKdGhlIG91dGNvbWUuAAAADWNsb3NlX2NoYW5uZWwAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAIJTZWVkIGVudHJvcHkgcnVsZXMsIHN0b3JlZCBpbiBpbnN0YW5jZSBzdG9yYWdlIGJ5IGVhY2ggY29udHJhY3QgdGhhdApjaGVja3Mgc2VlZHMuIFNlZSB0aGUgY3JhdGUgZG9jcyBmb3Igd2hhdCBlYWNoIGZpZWxkIHJlamVjdHMuAAAAAAAAAAAADUVudHJvcHlQb2xpY3kAAAAAAAAEAAAAhVVwcGVyIGJvdW5kIG9uIHRoZSBjaGktc3F1YXJlIHN0YXRpc3RpYyBvZiB0aGUgNjQgbmliYmxlcyBvdmVyIDE2CmJpbnM7IDAgZGlzYWJsZXMuIFVuaWZvcm0gc2VlZHMgYXZlcmFnZSAxNSAoMTUgZGVncmVlcyBvZiBmcmVlZG9tKS4AAAAAAAAObWF4X2NoaV9zcXVhcmUAAAAAAAQAAABGUmVqZWN0IHNlZWRzIHBlcmlvZGljIHdpdGggYW55IHBlcmlvZCB1cCB0byB0aGlzICgwLi49MTYpOyAwIGRpc2FibGVzLgAAAAAACm1heF9wZXJpb2QAAAAAAAQAAAA/TG9uZ2VzdCBhbGxvd2VkIHJ1biBvZiBvbmUgcmVwZWF0ZWQgYnl0ZTsgMCBkaXNhYmxlcyB0aGUgY2hlY2suAAAAAAdtYXhfcnVuAAAAAAQAAAAwTWluaW11bSBudW1iZXIgb2YgZGlzdGluY3QgYnl0ZSB2YWx1ZXMgKDEuLj0zMikuAAAADG1pbl9kaXN0aW5jdAAAAAQ=",
+        "AAAAAAAAAL5FdmVyeXRoaW5nIG5lZWRlZCB0byByZXBsYXkgYSBmaW5pc2hlZCBnYW1lIG9mZi1jaGFpbiwgaW4gdGhlCmBSRVBMQVlfVkVSU0lPTmAgbGF5b3V0OiB0aGUgc2VlZCBoYXNoZXMsIHRoZSBzaHVmZmxlZCBkZWNrLCBldmVyeQp0cmljayBpbiBvcmRlciBhbmQgdGhlIHJlc3VsdC4gQ2hlY2sgaXQgd2l0aCBgdmVyaWZ5X3JlcGxheWAuAAAAAAANZXhwb3J0X3JlcGxheQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAA4AAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAAAAAAANZ2V0X2NoYWxsZW5nZQAAAAAAAAEAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAABAAAD6AAAB9AAAAAJQ2hhbGxlbmdlAAAA",
+        "AAAAAAAAAR9HZXQgZ2FtZSBzdGF0ZSB3aXRoIHByaXZhY3k6IG9ubHkgdGhlIHZpZXdlcidzIG93biBoYW5kIGlzIHZpc2libGUuCk9wcG9uZW50cycgaGFuZHMgYXJlIHJlZGFjdGVkIChlbXB0eSkgdG8gcHJldmVudCBjYXN1YWwgc25vb3BpbmcKdmlhIFJQQyBxdWVyaWVzLiBOb24tcGxheWVycyBzZWUgZXZlcnkgaGFuZCByZWRhY3RlZC4KRHVyaW5nIHRoZSByZXZlYWwgcGhhc2UsIG9wcG9uZW50cycgdHJpY2tfY2FyZHMgYXJlIGFsc28gcmVkYWN0ZWQKdW50aWwgdGhlIHZpZXdlciBoYXMgcmV2ZWFsZWQgdG9vLgAAAAANZ2V0X2dhbWVfdmlldwAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnZpZXdlcgAAAAAAEwAAAAEAAAPpAAAH0AAAAA1DYW5na3VsYW5HYW1lAAAAAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAAIBIYW5kLXNpemUgY2FwIGFwcGxpZWQgdG8gcGVuYWx0eSBkcmF3cy4gTmV3IGdhbWVzIHNuYXBzaG90IHRoaXMgYXQKYHN0YXJ0X2dhbWVgLCBzbyBjaGFuZ2luZyBpdCBuZXZlciBhZmZlY3RzIGdhbWVzIGluIHByb2dyZXNzLgAAAAAAAAANSGFuZENhcENvbmZpZwAAAAAAAAIAAAAAAAAADW1heF9oYW5kX3NpemUAAAAAAAAEAAAAAAAAAA1vdmVyZmxvd19ydWxlAAAAAAAABA==",
+        "AAAAAAAAAYNGaW5hbGl6ZSBhYmFuZG9uZWQgc2Vzc2lvbnMgaW4gYnVsay4gUmV0dXJucyB0aGUgaWRzIGl0IGVuZGVkLgoKTmVlZHMgbm8gYXV0aC4gQSBzZXNzaW9uIGlzIGVuZGVkIG9uY2UgaXRzIGxlZGdlciBkZWFkbGluZSBpcwpgVElNRU9VVF9HUkFDRV9MRURHRVJTYCBiZWhpbmQsIHdpdGggdGhlIHRpbWVvdXQgb3V0Y29tZSAoYSBkcmF3CndoZXJlIG5vbmUgYXBwbGllcyksIG9yIGFzIGEgZHJhdyBvbmNlIGl0cyBzdG9yYWdlIGVudHJ5IGlzIHdpdGhpbgpgVElNRU9VVF9HUkFDRV9MRURHRVJTYCBvZiBleHBpcmluZy4gTWlzc2luZywgZmluaXNoZWQgYW5kIGxpdmUKc2Vzc2lvbnMgYXJlIHNraXBwZWQuIE9uZSBgRXZTZXNzaW9uc1BydW5lZGAgbGlzdHMgdGhlIGVuZGVkIGlkcy4AAAAADXBydW5lX2V4cGlyZWQAAAAAAAABAAAAAAAAAAtzZXNzaW9uX2lkcwAAAAPqAAAABAAAAAEAAAPpAAAD6gAAAAQAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAANhMZXQgYW4gZXBoZW1lcmFsIGBndWVzdGAga2V5IHBsYXkgdXAgdG8gYG1heF9nYW1lc2Agc2Vzc2lvbnMgb24gdGhlCnNwb25zb3IncyBiZWhhbGYuCgpUaGUgZ3Vlc3Qgc3RpbGwgc2lnbnMgaXRzIG93biBtb3Zlczsgb25seSBHYW1lIEh1YiBhY2NvdW50aW5nIGlzCmF0dHJpYnV0ZWQgdG8gdGhlIHNwb25zb3IuIFJlLXNwb25zb3JpbmcgcmVwbGFjZXMgdGhlIGFsbG93YW5jZS4AAAANc3BvbnNvcl9ndWVzdAAAAAAAAAMAAAAAAAAAB3Nwb25zb3IAAAAAEwAAAAAAAAAFZ3Vlc3QAAAAAAAATAAAAAAAAAAltYXhfZ2FtZXMAAAAAAAAEAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAv1DaGVjayBhbiBgZXhwb3J0X3JlcGxheWAgYmxvYiBvbiBpdHMgb3duLCByZWFkaW5nIG5vIGdhbWUgc3RhdGUsIGJ5CnJlcGxheWluZyB0aGUgcnVsZXM6IHRoZSBkZWNrIG11c3QgYmUgdGhlIHNodWZmbGUgb2YgaXRzIHNlZWQgaGFzaGVzCmFuZCBpcyBkZWFsdCBhcyB0aGUgY29udHJhY3QgZGVhbHMgaXQ7IGVhY2ggdHJpY2sgbXVzdCBmbGlwIHRoZSBuZXh0CmNhcmQsIGV2ZXJ5IHBsYXllZCBjYXJkIG11c3QgYmUgaW4gaXRzIHNlYXQncyBoYW5kIGFuZCBmb2xsb3cgc3VpdCwKYSBzZWF0IG1heSBvbmx5IHBhc3Mgd2hlbiBpdCBob2xkcyBub25lIG9mIHRoZSBzdWl0LCBhbmQgdGhlCnJlY29yZGVkIHdpbm5lciBhbmQgcGVuYWx0eSBkcmF3cyBtdXN0IGZvbGxvdy4gVGhlIHRyaWNrcyBtdXN0IGNoYWluCmZyb20gdGhlIGRlYWwgc2VlZCBpbnRvIGl0cyB0cmFuc2NyaXB0LCBlYWNoIHNlYXQncyB0cmlja3Mgd29uIG11c3QKbWF0Y2ggdGhlIHJlcGxheSwgYW5kIHRoZSBvdXRjb21lIG11c3QgYmUgdGhlIG9uZSB0aGUgbGFzdCB0cmljawplbmRzIHRoZSBnYW1lIHdpdGguIEEgZ2FtZSB0aGF0IGVuZGVkIG9mZiB0aGUgYm9hcmQgKHRpbWVvdXQsCmZvcmZlaXQsIGNhbmNlbCwgY2hhbm5lbCBzZXR0bGVtZW50KSBvbmx5IG5lZWRzIHNvbWUgcmVjb3JkZWQKb3V0Y29tZSwgc2luY2UgdGhvc2UgbW92ZXMgYXJlIG5vdCBpbiB0aGUgYmxvYi4gRmFpbHMgd2l0aApgTWFsZm9ybWVkUmVwbGF5YCBpZiB0aGUgYmxvYiBkb2VzIG5vdCBwYXJzZS4AAAAAAAANdmVyaWZ5X3JlcGxheQAAAAAAAAEAAAAAAAAABnJlcGxheQAAAAAADgAAAAEAAAPpAAAAAQAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
         "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAh2ZXJpZmllcgAAABMAAAAA",
-        "AAAAAAAAATxHZXQgZ2FtZSBzdGF0ZSB3aXRoIHByaXZhY3k6IG9ubHkgdGhlIHZpZXdlcidzIG93biBoYW5kIGlzIHZpc2libGUuClRoZSBvcHBvbmVudCdzIGhhbmQgaXMgcmVkYWN0ZWQgKGVtcHR5KSB0byBwcmV2ZW50IGNhc3VhbCBzbm9vcGluZwp2aWEgUlBDIHF1ZXJpZXMuIE5vbi1wbGF5ZXJzIHNlZSBib3RoIGhhbmRzIHJlZGFjdGVkLgpEdXJpbmcgdGhlIHJldmVhbCBwaGFzZSwgdGhlIG9wcG9uZW50J3MgdHJpY2tfY2FyZCBpcyBhbHNvIHJlZGFjdGVkCihpdCBvbmx5IGJlY29tZXMgdmlzaWJsZSBhZnRlciBib3RoIHBsYXllcnMgaGF2ZSByZXZlYWxlZCkuAAAADWdldF9nYW1lX3ZpZXcAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZ2aWV3ZXIAAAAAABMAAAABAAAD6QAAB9AAAAANQ2FuZ2t1bGFuR2FtZQAAAAAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAb5Db21taXQgYSBwbGF5IGFjdGlvbiB3aXRoIGEgWksgcmluZyBzaWdtYSBwcm9vZiBvZiBzdWl0IGNvbXBsaWFuY2UuCgpgY29tbWl0X2hhc2ggPSBrZWNjYWsyNTYoQ19ieXRlcylgIHdoZXJlIEMgaXMgYSBCTFMxMi0zODEgUGVkZXJzZW4KY29tbWl0bWVudCB0byB0aGUgY2FyZF9pZDogYEMgPSBjYXJkX2lkwrdHICsgYmxpbmRpbmfCt0hgLgoKYHprX3Byb29mYCBpcyB0aGUgcmluZyBzaWdtYSBwcm9vZjogYEMoOTYpIHx8IFtlX2koMzIpIHx8IHpfaSgzMildIMOXIE5gCnByb3ZpbmcgdGhlIGNvbW1pdHRlZCBjYXJkIGlzIGluIHRoZSBwbGF5ZXIncyB2YWxpZCBzZXQgKGhhbmQg4oipIHRyaWNrIHN1aXQpCndpdGhvdXQgcmV2ZWFsaW5nIHdoaWNoIHNwZWNpZmljIGNhcmQuCgpUaGUgWksgdmVyaWZpZXIgKE1vZGUgNykgY2hlY2tzIHRoZSByaW5nIHNpZ21hIGFuZCBiaW5kaW5nLgAAAAAADmNvbW1pdF9wbGF5X3prAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+4AAAAgAAAAAAAAAA5leHBlY3RlZF9ub25jZQAAAAAABAAAAAAAAAAIemtfcHJvb2YAAAAOAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAGlBY2NlcHQgYSBwZW5kaW5nIHJlbWF0Y2guIE9uY2UgZXZlcnkgc2VhdCBoYXMgYWdyZWVkIHRoZSBuZXcgZ2FtZQpzdGFydHMuIFJldHVybnMgdGhlIHJlbWF0Y2ggc2Vzc2lvbiBpZC4AAAAAAAAOYWNjZXB0X3JlbWF0Y2gAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAMlXaXRoZHJhdyBhIHBlbmRpbmcgcmVtYXRjaC4gQW55IHNlYXQgb2YgdGhlIGZpbmlzaGVkIGdhbWUgbWF5IGNhbmNlbDsKc3Rha2VzIGFscmVhZHkgbG9ja2VkIGZvciBpdCBhcmUgcmV0dXJuZWQuIFdvcmtzIGZyb20gdGhlIG9mZmVyCmFsb25lLCBzbyB0aGUgc3Rha2VzIGNvbWUgYmFjayBldmVuIG9uY2UgdGhlIGZpbmlzaGVkIGdhbWUgZXhwaXJlZC4AAAAAAAAOY2FuY2VsX3JlbWF0Y2gAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAABAAAAAAAAAAAAAAADkNhbmdrdWxhbkVycm9yAAAAAABTAAAAAAAAAAxHYW1lTm90Rm91bmQAAAABAAAAAAAAABRTZXNzaW9uQWxyZWFkeUV4aXN0cwAAAAIAAAAAAAAACk5vdEFQbGF5ZXIAAAAAAAMAAAAAAAAAElNlbGZQbGF5Tm90QWxsb3dlZAAAAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAACldyb25nUGhhc2UAAAAAAAYAAAAAAAAAFkNvbW1pdEFscmVhZHlTdWJtaXR0ZWQAAAAAAAcAAAAAAAAAFlJldmVhbEFscmVhZHlTdWJtaXR0ZWQAAAAAAAgAAAAAAAAAEkNvbW1pdEhhc2hNaXNtYXRjaAAAAAAACQAAAAAAAAAOSW52YWxpZFprUHJvb2YAAAAAAAoAAAAAAAAADU1pc3NpbmdDb21taXQAAAAAAAALAAAAAAAAAAtOb3RZb3VyVHVybgAAAAAMAAAAAAAAAA1DYXJkTm90SW5IYW5kAAAAAAAADQAAAAAAAAAJV3JvbmdTdWl0AAAAAAAADgAAAAAAAAAPSGFzTWF0Y2hpbmdTdWl0AAAAAA8AAAAAAAAADURyYXdQaWxlRW1wdHkAAAAAAAAQAAAAAAAAABFOb1RyaWNrSW5Qcm9ncmVzcwAAAAAAABEAAAAAAAAAC0FkbWluTm90U2V0AAAAABIAAAAAAAAADUdhbWVIdWJOb3RTZXQAAAAAAAATAAAAAAAAAA5WZXJpZmllck5vdFNldAAAAAAAFAAAAAAAAAARVGltZW91dE5vdFJlYWNoZWQAAAAAAAAVAAAAAAAAABRUaW1lb3V0Tm90Q29uZmlndXJlZAAAABYAAAAAAAAAFFRpbWVvdXROb3RBcHBsaWNhYmxlAAAAFwAAAAAAAAAPV2Vha1NlZWRFbnRyb3B5AAAAABgAAAAAAAAADEludmFsaWROb25jZQAAABkAAAAAAAAAGlBsYXlDb21taXRBbHJlYWR5U3VibWl0dGVkAAAAAAAaAAAAAAAAABFQbGF5Q29tbWl0TWlzc2luZwAAAAAAABsAAAAAAAAAElBsYXlSZXZlYWxNaXNtYXRjaAAAAAAAHAAAAAAAAAANSW52YWxpZENhcmRJZAAAAAAAAB0AAAAAAAAAF1VsdHJhSG9ua1ZlcmlmaWVyTm90U2V0AAAAAB4AAAAAAAAAG1VsdHJhSG9ua1ZlcmlmaWNhdGlvbkZhaWxlZAAAAAAfAAAAAAAAABJaa1BsYXlQcm9vZkludmFsaWQAAAAAACAAAAAAAAAADlprUGxheVNldEVtcHR5AAAAAAAhAAAAAAAAABVaa1BsYXlPcGVuaW5nTWlzbWF0Y2gAAAAAAAAiAAAAAAAAABVaa0NhbmdrdWxQcm9vZkludmFsaWQAAAAAAAAjAAAAAAAAAAtUaWNrVG9vU29vbgAAAAAmAAAAAAAAAA5Ob3RoaW5nVG9DbGFpbQAAAAAAJwAAAAAAAAAMQ2xhaW1FeHBpcmVkAAAAKAAAAAAAAAAPQ2xhaW1Ob3RFeHBpcmVkAAAAACkAAAAAAAAADEludmFsaWRFbW90ZQAAACoAAAAAAAAAF0ludmFsaWRHdWVzdFNwb25zb3JzaGlwAAAAACsAAAAAAAAAEUd1ZXN0Tm90U3BvbnNvcmVkAAAAAAAALAAAAAAAAAAOSW52YWxpZEhhbmRDYXAAAAAAAC0AAAAAAAAAFEludmFsaWRTZWVkUHJvb2ZNb2RlAAAALgAAAAAAAAAVU2VlZFByb29mTW9kZU1pc21hdGNoAAAAAAAALwAAAAAAAAAPR2FtZU5vdEZpbmlzaGVkAAAAADAAAAAAAAAAFEludmFsaWRFbnRyb3B5UG9saWN5AAAAMQAAAAAAAAAQT3V0Y29tZU5vdEZvcmNlZAAAADIAAAAAAAAAF0ludmFsaWRPdmVycmlkZUR1cmF0aW9uAAAAADMAAAAAAAAAFE92ZXJyaWRlTm90U2NoZWR1bGVkAAAANAAAAAAAAAASVGltZWxvY2tOb3RFbGFwc2VkAAAAAAA1AAAAAAAAABJJbnZhbGlkUGxheWVyQ291bnQAAAAAADYAAAAAAAAAEkludmFsaWRTdGFrZUVzY3JvdwAAAAAANwAAAAAAAAATUmVtYXRjaE5vdFJlcXVlc3RlZAAAAAA4AAAAAAAAABRSZW1hdGNoQWxyZWFkeUFncmVlZAAAADkAAAAAAAAAEUNoYWxsZW5nZU5vdEZvdW5kAAAAAAAAOgAAAAAAAAAQQ2hhbGxlbmdlRXhwaXJlZAAAADsAAAAAAAAAFkludmFsaWRDaGFsbGVuZ2VFeHBpcnkAAAAAADwAAAAAAAAACUxvYmJ5RnVsbAAAAAAAAD0AAAAAAAAAEUludmFsaWRHYW1lQ29uZmlnAAAAAAAAPgAAAAAAAAAOWmtQbGF5UmVxdWlyZWQAAAAAAD8AAAAAAAAADERlY2tNaXNtYXRjaAAAAEAAAAAAAAAAFFJldmVhbERlYWRsaW5lUGFzc2VkAAAAQQAAAAAAAAATVW5yb3V0YWJsZVByb29mTW9kZQAAAABCAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAQwAAAAAAAAAVVW5zdXBwb3J0ZWRHYW1lU2NoZW1hAAAAAAAARAAAAAAAAAAPSW52YWxpZERlbGVnYXRlAAAAAEUAAAAAAAAADkNoYW5uZWxOb3RPcGVuAAAAAABGAAAAAAAAABNJbnZhbGlkQ2hhbm5lbFN0YXRlAAAAAEcAAAAAAAAAEVN0YWxlQ2hhbm5lbFN0YXRlAAAAAAAASAAAAAAAAAATQ2hhbGxlbmdlV2luZG93T3BlbgAAAABJAAAAAAAAAA5Ob0NoYW5uZWxDbGFpbQAAAAAASgAAAAAAAAARVGltZUJhbmtFeGhhdXN0ZWQAAAAAAABLAAAAAAAAAA1NYXRjaE5vdEZvdW5kAAAAAAAATAAAAAAAAAAJTWF0Y2hPdmVyAAAAAAAATQAAAAAAAAASSW52YWxpZE1hdGNoVGFyZ2V0AAAAAABOAAAAAAAAABJBdWRpdEJsaW5kaW5nQ291bnQAAAAAAE8AAAAAAAAAFlNodWZmbGVEaXNwdXRlUmVqZWN0ZWQAAAAAAFAAAAAAAAAAD01hbGZvcm1lZFJlcGxheQAAAABRAAAAAAAAABFDaGFubmVsS2V5c0xvY2tlZAAAAAAAAFIAAAAAAAAAEUNoYW5uZWxTdXBlcnNlZGVkAAAAAAAAUwAAAAAAAAARUmVzZXJ2ZWRTZXNzaW9uSWQAAAAAAABUAAAAAAAAABFUb29NYW55Q2hhbGxlbmdlcwAAAAAAAFU=",
+        "AAAAAAAAAGdNZXNzYWdlIGVhY2ggc2VhdCBzaWducyBmb3IgYHN0YXRlYDoKYGtlY2NhazI1NigiY2FuZ2t1bGFuLWNoYW5uZWwiIOKIpSB4ZHIoY29udHJhY3QpIOKIpSB4ZHIoc3RhdGUpKWAuAAAAAA5jaGFubmVsX2RpZ2VzdAAAAAAAAQAAAAAAAAAFc3RhdGUAAAAAAAfQAAAADENoYW5uZWxTdGF0ZQAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAfVQYXkgYHBsYXllcmAgdGhlaXIgc2hhcmUgb2YgdGhlIHN0YWtlcyByZWNvcmRlZCB3aGVuIHRoZSBnYW1lIGZpbmlzaGVkLgoKRmluYWxpemF0aW9uIG9ubHkgcmVjb3JkcyB0aGUgb3V0Y29tZSBhbmQgbm90aWZpZXMgdGhlIGh1YjsgcGF5b3V0cwpoYXBwZW4gaGVyZSBzbyBhIGZhaWxpbmcgdHJhbnNmZXIgY2FuIG5ldmVyIGJsb2NrIG9yIHJldmVydCB0aGUgZ2FtZQpyZXN1bHQuIEVhY2ggcGxheWVyIGNsYWltcyBvbiB0aGVpciBvd24sIHNvIG9uZSByZWNpcGllbnQgd2hvc2UKdHJhbnNmZXIgZmFpbHMgbmV2ZXIgaG9sZHMgdXAgdGhlIG90aGVycy4gQW55b25lIG1heSBjYWxsIHRoaXMg4oCUCmZ1bmRzIG9ubHkgZ28gdG8gYHBsYXllcmAuIE9uY2UgZXZlcnkgc2hhcmUgaXMgcGFpZCB0aGUgcmVjb3JkIGlzCmNsZWFyZWQgYW5kIHRoZSByYWtlIG9mIGFuIGVzY3Jvd2VkIGdhbWUgaXMgY3JlZGl0ZWQgdG8gaXRzCnRyZWFzdXJ5LiBSZXR1cm5zIHRoZSBhbW91bnQgcGFpZC4AAAAAAAAOY2xhaW1fd2lubmluZ3MAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAACwAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAvpDb21taXQgYSBwbGF5IGFjdGlvbiB3aXRoIGEgWksgcmluZyBzaWdtYSBwcm9vZiBvZiBzdWl0IGNvbXBsaWFuY2UuCgpgY29tbWl0X2hhc2ggPSBrZWNjYWsyNTYoQ19ieXRlcylgIHdoZXJlIEMgaXMgYSBCTFMxMi0zODEgUGVkZXJzZW4KY29tbWl0bWVudCB0byB0aGUgY2FyZF9pZDogYEMgPSBjYXJkX2lkwrdHICsgYmxpbmRpbmfCt0hgLgoKYHprX3Byb29mYCBpcyB0aGUgcmluZyBzaWdtYSBwcm9vZjogYEMoOTYpIHx8IFtlX2koMzIpIHx8IHpfaSgzMildIMOXIE5gCnByb3ZpbmcgdGhlIGNvbW1pdHRlZCBjYXJkIGlzIGluIHRoZSBwbGF5ZXIncyB2YWxpZCBzZXQgKGhhbmQg4oipIHRyaWNrIHN1aXQpCndpdGhvdXQgcmV2ZWFsaW5nIHdoaWNoIHNwZWNpZmljIGNhcmQuCgpUaGUgWksgdmVyaWZpZXIgKE1vZGUgNykgY2hlY2tzIHRoZSByaW5nIHNpZ21hIGFuZCBiaW5kaW5nLiBBbiBlbXB0eQpgemtfcHJvb2ZgIGNvbnN1bWVzIGEgcHJvb2YgcmVjb3JkZWQgZWFybGllciB3aXRoIGB2ZXJpZnlfYW5kX3JlY29yZGAuCgpBIHByb29mIHBhZGRlZCB0byBhIGZ1bGwgc3VpdCBvZiBtZW1iZXJzIChgUklOR19QQURERURfRkxBR2ApIGlzCnJlY29nbmlzZWQgYnkgaXRzIGxlbmd0aCwgc28gZXZlcnkgcGxheSBwcm9vZiBjYW4gYmUgdGhlIHNhbWUgc2l6ZQp3aGF0ZXZlciB0aGUgbnVtYmVyIG9mIHN1aXQtbWF0Y2hpbmcgY2FyZHMuIEEgcmVjb3JkZWQgcHJvb2YgaXMKbG9va2VkIHVwIHVucGFkZGVkIGZpcnN0LCB0aGVuIHBhZGRlZC4AAAAAAA5jb21taXRfcGxheV96awAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtjb21taXRfaGFzaAAAAAPuAAAAIAAAAAAAAAAOZXhwZWN0ZWRfbm9uY2UAAAAAAAQAAAAAAAAACHprX3Byb29mAAAADgAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAARhDYW5vbmljYWwgc2VyaWFsaXphdGlvbiBvZiBhIGZpbmlzaGVkIGdhbWUsIGZvciBORlQgbWV0YWRhdGEgb3IKb2ZmLWNoYWluIGFyY2hpdmVzIChsYXlvdXQgaW4gYFNVTU1BUllfVkVSU0lPTmApLgoKSXRzIGtlY2NhazI1NiBpcyByZWNvcmRlZCB3aGVuIHRoZSBnYW1lIGVuZHMgYW5kIHN0YXlzIHJlYWRhYmxlIHZpYQpgZ2V0X3N1bW1hcnlfaGFzaGAgYWZ0ZXIgdGhlIGdhbWUgaXRzZWxmIGV4cGlyZXMsIHNvIGFuIGFyY2hpdmVkIGJsb2IKY2FuIGJlIGF1dGhlbnRpY2F0ZWQgbGF0ZXIuAAAADmV4cG9ydF9zdW1tYXJ5AAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAOAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
         "AAAAAAAAAMBHZXQgZnVsbCByYXcgZ2FtZSBzdGF0ZSAoYWRtaW4tb25seSkuIFJlcXVpcmVzIGFkbWluIGF1dGhlbnRpY2F0aW9uLgpVc2VkIGZvciBkZWJ1Z2dpbmcgYW5kIHBvc3QtZ2FtZSB2ZXJpZmljYXRpb24uIEZvciBub3JtYWwgZ2FtZXBsYXkKdXNlIGBnZXRfZ2FtZV92aWV3YCB3aGljaCByZXNwZWN0cyBwbGF5ZXItbGV2ZWwgcHJpdmFjeS4AAAAOZ2V0X2dhbWVfZGVidWcAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAANQ2FuZ2t1bGFuR2FtZQAAAAAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAS9SZWNvbXB1dGUgYW5kIHJldHVybiB0aGUgZnVsbCBzaHVmZmxlZCBkZWNrIG9yZGVyIGZyb20gWkstdmVyaWZpZWQgc2VlZCBoYXNoZXMuCkFueW9uZSBjYW4gY2FsbCB0aGlzIHRvIGluZGVwZW5kZW50bHkgdmVyaWZ5IHRoZSBzaHVmZmxlIHdhcyBmYWlyLiBUaGUKc2VlZCBoYXNoZXMgYXJlIGBrZWNjYWsyNTYocmF3X3NlZWQpYCDigJQgdGhlIHJhdyBzZWVkcyBuZXZlciBhcHBlYXIgb24tY2hhaW4uCk9ubHkgYXZhaWxhYmxlIGFmdGVyIGJvdGggc2VlZHMgaGF2ZSBiZWVuIHJldmVhbGVkIChQTEFZSU5HIG9yIEZJTklTSEVEKS4AAAAADnZlcmlmeV9zaHVmZmxlAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAABAAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAAAAAAAPcmVzb2x2ZV90aW1lb3V0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAnNWZXJpZnkgYSBOb2lyIFVsdHJhS2VjY2FrSG9uayBwcm9vZiBvbi1jaGFpbiBpbiBhICoqc2VwYXJhdGUKdHJhbnNhY3Rpb24qKiBmcm9tIGByZXZlYWxfc2VlZGAuICBUaGlzIHNwbGl0cyB0aGUgfjI2ME0gQ1BVIGNvc3QKaW50byB0d28gdHJhbnNhY3Rpb25zIHRoYXQgZWFjaCBmaXQgd2l0aGluIFNvcm9iYW4gbGltaXRzOgoKVFggMTogYHZlcmlmeV9ub2lyX3NlZWRgIOKGkiBVbHRyYUhvbmsgdmVyaWZpY2F0aW9uICh+MjE1TSBDUFUpClRYIDI6IGByZXZlYWxfc2VlZGAgd2l0aCBhbiBlbXB0eSBwcm9vZiDihpIgZ2FtZSBsb2dpYyAofjUwTSBDUFUpCgpUaGUgdmVyaWZpZWQgZmxhZyBpcyBzdG9yZWQgaW4gdGVtcG9yYXJ5IHN0b3JhZ2UgYW5kIGNvbnN1bWVkIGJ5CmByZXZlYWxfc2VlZGAgd2hlbiBpdCBzZWVzIGEgemVyby1sZW5ndGggcHJvb2YuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIGdhbWUgc2Vzc2lvbiBpZAoqIGBwbGF5ZXJgIC0gdGhlIHBsYXllciByZXZlYWxpbmcgKHJlcXVpcmVzIGF1dGgpCiogYHNlZWRfaGFzaGAgLSBibGFrZTJzKHNlZWQpIOKAlCB0aGUgTm9pciBwdWJsaWMgaW5wdXQKKiBgcHJvb2ZgIC0gcmF3IFVsdHJhS2VjY2FrSG9uayBwcm9vZiAoPjRLQikAAAAAEHZlcmlmeV9ub2lyX3NlZWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACXNlZWRfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
-        "AAAAAAAAAnRDb21taXQgYSBjYW5na3VsIChjYW5ub3QgZm9sbG93IHN1aXQpIGFjdGlvbiB3aXRoIGEgWksgaGFuZCBwcm9vZi4KClRoZSBwbGF5ZXIgcHJvdmVzIHRoYXQgTk9ORSBvZiB0aGUgY2FyZHMgaW4gdGhlaXIgaGFuZCBtYXRjaAp0aGUgY3VycmVudCB0cmljayBzdWl0LiBVc2VzIGFuIGFnZ3JlZ2F0ZSBQZWRlcnNlbiBjb21taXRtZW50Cm92ZXIgdGhlIGVudGlyZSBoYW5kIHdpdGggYSBTY2hub3JyIHByb29mIG9mIGtub3dsZWRnZS4KCmBjb21taXRfaGFzaCA9IGtlY2NhazI1NihBX2J5dGVzKWAgd2hlcmUgQSBpcyB0aGUgYWdncmVnYXRlClBlZGVyc2VuIGNvbW1pdG1lbnQ6IGBBID0gzqMoY2FyZF9pwrdHICsgcl9pwrdIKWAuCgpgemtfcHJvb2ZgIGxheW91dDogYGsoNCkgfHwgQSg5NiwgRzEpIHx8IFIoOTYsIEcxKSB8fCB6KDMyLCBGcikgPSAyMjggYnl0ZXNgCgpUaGUgWksgdmVyaWZpZXIgKE1vZGUgOCkgY2hlY2tzOgoxLiBBZ2dyZWdhdGUgUGVkZXJzZW4gYmluZGluZyAoa2VjY2FrMjU2KEEpID09IGNvbW1pdF9oYXNoKQoyLiBTY2hub3JyIHByb29mIG9mIGtub3dsZWRnZSBvZiBhZ2dyZWdhdGUgYmxpbmRpbmcKMy4gU3VpdCBleGNsdXNpb246IG5vIGNhcmQgbWF0Y2hlcyB0cmlja19zdWl0AAAAEWNvbW1pdF9jYW5na3VsX3prAAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtjb21taXRfaGFzaAAAAAPuAAAAIAAAAAAAAAAOZXhwZWN0ZWRfbm9uY2UAAAAAAAQAAAAAAAAACHprX3Byb29mAAAADgAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
-        "AAAAAAAAAJ5HZXQgYSBwbGF5ZXIncyBnYW1lIGhpc3RvcnkgKHVwIHRvIDUwIG1vc3QgcmVjZW50IGdhbWVzKS4KUmV0dXJucyBhIFZlYyBvZiBHYW1lU3VtbWFyeSB3aXRoIG91dGNvbWUgZnJvbSB0aGUgcGxheWVyJ3MgcGVyc3BlY3RpdmU6CjEgPSB3aW4sIDIgPSBsb3NzLCAzID0gZHJhdwAAAAAAEmdldF9wbGF5ZXJfaGlzdG9yeQAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+oAAAfQAAAAC0dhbWVTdW1tYXJ5AA==",
-        "AAAAAAAAAEJHZXQgdGhlIFVsdHJhSG9uayB2ZXJpZmllciBjb250cmFjdCBhZGRyZXNzIChOb2lyIHByb29mIHZlcmlmaWVyKS4AAAAAABZnZXRfdWx0cmFob25rX3ZlcmlmaWVyAAAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
-        "AAAAAAAAAHhTZXQgdGhlIFVsdHJhSG9uayB2ZXJpZmllciBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvbmx5KS4KVGhpcyBlbmFibGVzIG9wdGlvbmFsIE5vaXIgVWx0cmFLZWNjYWtIb25rIHByb29mIHZlcmlmaWNhdGlvbi4AAAAWc2V0X3VsdHJhaG9ua192ZXJpZmllcgAAAAAAAQAAAAAAAAANdmVyaWZpZXJfYWRkcgAAAAAAABMAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=" ]),
+        "AAAAAQAAATNUaGUgaW50ZW50aW9uYWxseSBwdWJsaWMgcGFydCBvZiBhIGdhbWUsIHJldHVybmVkIGJ5IGBnZXRfZ2FtZWAuIEl0IGlzCmJ1aWx0IGZpZWxkIGJ5IGZpZWxkIHJhdGhlciB0aGFuIHJlZGFjdGVkIGZyb20gYENhbmdrdWxhbkdhbWVgLCBzbyBhCmZpZWxkIGFkZGVkIHRvIHRoZSBnYW1lIHN0YXlzIHByaXZhdGUgdW50aWwgaXQgaXMgYWRkZWQgaGVyZS4gVGhlCmZsaXBwZWQgY2FyZCBpcyBmYWNlIHVwIG9uIHRoZSB0YWJsZTsgaGFuZHMsIHRoZSBwaWxlIGFuZCB0cmljayBjYXJkcwpvbmx5IGFwcGVhciBhcyBjb3VudHMgb3IgZmxhZ3MuAAAAAAAAAAAOUHVibGljR2FtZVZpZXcAAAAAAA4AAAAAAAAADGFjdGlvbl9ub25jZQAAAAQAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApHYW1lQ29uZmlnAAAAAAAAAAAAD2RlYWRsaW5lX2xlZGdlcgAAAAPoAAAABAAAAAAAAAAOZHJhd19waWxlX3NpemUAAAAAAAQAAAAAAAAADGZsaXBwZWRfY2FyZAAAA+gAAAAEAAAAAAAAAAxmb3JmZWl0ZWRfYnkAAAAEAAAAAAAAAA9saWZlY3ljbGVfc3RhdGUAAAAABAAAAAAAAAAHb3V0Y29tZQAAAAAEAAAAAAAAAAdwbGF5ZXJzAAAAA+oAAAfQAAAAClB1YmxpY1NlYXQAAAAAAAAAAAALc3Rha2VfdG9rZW4AAAAD6AAAABMAAAAAAAAACnRpbWVfYmFua3MAAAAAA+oAAAAEAAAAAAAAAAt0cmlja19zdGF0ZQAAAAAEAAAAAAAAAAp0cmlja19zdWl0AAAAAAPoAAAABAAAAAAAAAAGdnNfYm90AAAAAAAB",
+        "AAAAAAAAAOVTdWJtaXQgYSBzdGF0ZSB3aXRoIG9uZSBzaWduYXR1cmUgcGVyIHNlYXQsIGluIHNlYXQgb3JkZXIsIG92ZXIKYGNoYW5uZWxfZGlnZXN0KHN0YXRlKWAuIE5lZWRzIG5vIGF1dGguIFN0YXJ0cyB0aGUgY2hhbGxlbmdlIHdpbmRvdywKb3IgcmVwbGFjZXMgYSBwZW5kaW5nIGNsYWltIHdpdGggYSBoaWdoZXIgYG1vdmVfY291bnRlcmAuIFJldHVybnMKdGhlIGxlZGdlciB0aGUgd2luZG93IGVuZHMgYXQuAAAAAAAADnNldHRsZV9jaGFubmVsAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAVzdGF0ZQAAAAAAB9AAAAAMQ2hhbm5lbFN0YXRlAAAAAAAAAApzaWduYXR1cmVzAAAAAAPqAAAD7gAAAEAAAAABAAAD6QAAAAQAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAWFSZWNvbXB1dGUgYW5kIHJldHVybiB0aGUgc2h1ZmZsZWQgZGVjayBvcmRlciBmcm9tIFpLLXZlcmlmaWVkIHNlZWQgaGFzaGVzOgpgR2FtZUNvbmZpZzo6ZGVja19zaXplYCBjYXJkcywgMzYgdW5kZXIgc3RhbmRhcmQgcnVsZXMuCkFueW9uZSBjYW4gY2FsbCB0aGlzIHRvIGluZGVwZW5kZW50bHkgdmVyaWZ5IHRoZSBzaHVmZmxlIHdhcyBmYWlyLiBUaGUKc2VlZCBoYXNoZXMgYXJlIGBrZWNjYWsyNTYocmF3X3NlZWQpYCDigJQgdGhlIHJhdyBzZWVkcyBuZXZlciBhcHBlYXIgb24tY2hhaW4uCk9ubHkgYXZhaWxhYmxlIGFmdGVyIGV2ZXJ5IHNlZWQgaGFzIGJlZW4gcmV2ZWFsZWQgKFBMQVlJTkcgb3IgRklOSVNIRUQpLgAAAAAAAA52ZXJpZnlfc2h1ZmZsZQAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6gAAAAQAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAXlEaXNwdXRlIHRoZSBkZWFsIG9mIGEgZ2FtZSBpbiBwbGF5OiB0aGUgY2FyZCBkZWFsdCBhdCBgaW5kZXhgIG9mIHRoZQpkZWNrIGRpZmZlcnMgZnJvbSBgdmVyaWZ5X3NodWZmbGVgIGF0IHRoZSBzYW1lIGluZGV4LiBUaGUgY29udHJhY3QKZGVhbHMsIHNvIG5vIHNlYXQgaXMgYXQgZmF1bHQuIEEgcHJvdmVuIG1pc21hdGNoIGNhbmNlbHMgdGhlIGdhbWUsCnJlZnVuZGluZyBldmVyeSBzdGFrZSwgYW5kIHBhdXNlcyB0aGUgY29udHJhY3QsIHNpbmNlIHRoZSBydW5uaW5nCmNvZGUgbm8gbG9uZ2VyIGRlYWxzIHdoYXQgaXQgdmVyaWZpZXMuIEEgY2xhaW0gdGhhdCBkb2VzIG5vdCBob2xkIGlzCnJlamVjdGVkIHdpdGggYFNodWZmbGVEaXNwdXRlUmVqZWN0ZWRgLgAAAAAAAA9kaXNwdXRlX3NodWZmbGUAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAN9DbGVhciB0aGUgc2hhcmVzIHRoYXQgd2VyZSBub3QgY2xhaW1lZCB3aXRoaW4gdGhlIGNsYWltIHdpbmRvdy4KCkNhbGxhYmxlIGJ5IGFueW9uZSBvbmNlIGBleHBpcmVzX2xlZGdlcmAgaGFzIHBhc3NlZC4gVW5jbGFpbWVkCmVzY3Jvd2VkIHRva2VucyBhcmUgY3JlZGl0ZWQgdG8gdGhlIHRyZWFzdXJ5IHdpdGggdGhlIHJha2UuIFJldHVybnMKdGhlIHRvdGFsIGFtb3VudCBmb3JmZWl0ZWQuAAAAAA9leHBpcmVfd2lubmluZ3MAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAACwAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAIZGdWxsIHN0YXRlIG9mIGEgZmluaXNoZWQgZ2FtZSwgaGFuZHMgYW5kIHBpbGUgaW5jbHVkZWQ7IG5vdGhpbmcgaXMKc2VjcmV0IG9uY2UgcGxheSBpcyBvdmVyLiBGYWlscyB3aXRoIGBHYW1lTm90RmluaXNoZWRgIGJlZm9yZSB0aGF0LgAAAAAAD2dldF9nYW1lX3JlY29yZAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADUNhbmdrdWxhbkdhbWUAAAAAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAC9OdW1iZXIgb2YgZ2FtZXMgcmVjb3JkZWQgaW4gYSBwbGF5ZXIncyBoaXN0b3J5LgAAAAAPZ2V0X2hpc3RvcnlfbGVuAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAADRHZXQgdGhlIExlYWRlcmJvYXJkIGNvbnRyYWN0IGFkZHJlc3MsIGlmIGNvbmZpZ3VyZWQuAAAAD2dldF9sZWFkZXJib2FyZAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAATxPZmZlciBhIHJlbWF0Y2ggb2YgYSBmaW5pc2hlZCBnYW1lIHdpdGggdGhlIHNhbWUgc2VhdHMsIHN0YWtlcyBhbmQKZXNjcm93IHRlcm1zLiBUaGUgbmV3IGdhbWUgZ2V0cyBhIGNvbnRyYWN0LWFsbG9jYXRlZCBzZXNzaW9uIGlkOwphIGZpbmlzaGVkIGdhbWUgaXMgcmVtYXRjaGVkIGF0IG1vc3Qgb25jZS4KCkVhY2ggc2VhdCBhdXRob3JpemVzIG9ubHkgaXRzIG93biByZXF1ZXN0IG9yIGFjY2VwdGFuY2U7IGluIGFuCmVzY3Jvd2VkIGdhbWUgdGhhdCBjYWxsIGFsc28gbG9ja3MgaXRzIHN0YWtlLiBSZXR1cm5zIHRoZSByZW1hdGNoCnNlc3Npb24gaWQuAAAAD3JlcXVlc3RfcmVtYXRjaAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAQAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAadFbmQgYSBnYW1lIHdob3NlIGRlYWRsaW5lIGhhcyBwYXNzZWQsIHdpdGggdGhlIG91dGNvbWUgb2YKYGRldGVybWluZV90aW1lb3V0X291dGNvbWVgLgoKUGxheWVycyBtYXkgY2FsbCB0aGlzIGFzIHNvb24gYXMgdGhlIGRlYWRsaW5lIHBhc3Nlcy4gQW55b25lIGVsc2UKbWF5IG9uY2UgdGhlIGxlZGdlciBkZWFkbGluZSBpcyBgVElNRU9VVF9HUkFDRV9MRURHRVJTYCBiZWhpbmQsIHNvIGEKZ2FtZSBib3RoIHBsYXllcnMgYWJhbmRvbmVkIHN0aWxsIHNldHRsZXMgYW5kIHJlYWNoZXMgdGhlIGh1Yi4KCk9uIGEgYmxpdHogY2xvY2sgKGB0aW1lX2JhbmtfbGVkZ2Vyc2ApIGFueW9uZSBtYXkgY2FsbCBpdCBhcyBzb29uIGFzCmV2ZXJ5IHNlYXQgaXQgd2FpdHMgb24gaGFzIHVzZWQgdXAgaXRzIHRpbWUgYmFuayBwYXN0IHRoZSBkZWFkbGluZS4AAAAAD3Jlc29sdmVfdGltZW91dAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZjYWxsZXIAAAAAABMAAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAADZUYWtlIGJhY2sgc2lnbmluZyBvZiBgcGxheWVyYCdzIG1vdmVzIGluIGBzZXNzaW9uX2lkYC4AAAAAAA9yZXZva2VfZGVsZWdhdGUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAADJTZXQgdGhlIExlYWRlcmJvYXJkIGNvbnRyYWN0IGFkZHJlc3MgKGFkbWluIG9ubHkpLgAAAAAAD3NldF9sZWFkZXJib2FyZAAAAAABAAAAAAAAAAtsZWFkZXJib2FyZAAAAAATAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAJZUYWtlIGFuIG9wZW4gY2hhbGxlbmdlLCBtYXRjaGluZyBpdHMgc3Rha2UuIFN0YXJ0cyB0aGUgZ2FtZSBhdCBhCmNvbnRyYWN0LWFsbG9jYXRlZCBzZXNzaW9uIGlkIHdpdGggdGhlIGNyZWF0b3IgaW4gc2VhdCAxIGFuZApyZXR1cm5zIHRoYXQgc2Vzc2lvbiBpZC4AAAAAABBhY2NlcHRfY2hhbGxlbmdlAAAAAgAAAAAAAAAMY2hhbGxlbmdlX2lkAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAH5XaXRoZHJhdyBhbiBvcGVuIGNoYWxsZW5nZSBhbmQgcmV0dXJuIHRoZSBjcmVhdG9yJ3Mgc3Rha2UuIFRoZQpjcmVhdG9yIG1heSBjYW5jZWwgYXQgYW55IHRpbWU7IGFueW9uZSBtYXkgb25jZSBpdCBoYXMgZXhwaXJlZC4AAAAAABBjYW5jZWxfY2hhbGxlbmdlAAAAAgAAAAAAAAAMY2hhbGxlbmdlX2lkAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAUJFbmQgYSBnYW1lIHdob3NlIHJlc3VsdCBjYW4gbm8gbG9uZ2VyIGNoYW5nZSwgd2l0aG91dCBwbGF5aW5nIG91dAp0aGUgcmVtYWluaW5nIHRyaWNrcy4KCkVpdGhlciBwbGF5ZXIgbWF5IGNhbGwgdGhpczsgdGhlIGNvbnRyYWN0IGNoZWNrcyBvbi1jaGFpbiB0aGF0IHRoZQpwb3NpdGlvbiBpcyBkZWNpZGVkIChzZWUgYGZvcmNlZF9vdXRjb21lYCkgYW5kIGZpbmFsaXplcyB3aXRoIHRoYXQKb3V0Y29tZS4gUmV0dXJucyB0aGUgb3V0Y29tZSwgb3IgYE91dGNvbWVOb3RGb3JjZWRgIGlmIHRoZSB0cmFpbGluZwpwbGF5ZXIgY291bGQgc3RpbGwgd2luIG9yIGRyYXcuAAAAAAAQY2xhaW1fZm9yY2VkX3dpbgAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAABAAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAOdQb3N0IGFuIG9wZW4gY2hhbGxlbmdlIHN0YWtpbmcgYHBvaW50c2AsIG9wZW4gdW50aWwgYGV4cGlyZXNfbGVkZ2VyYAooYXQgbW9zdCBgTUFYX0NIQUxMRU5HRV9MRURHRVJTYCBhaGVhZCkuIEV4cGlyZWQgY2hhbGxlbmdlcyBhcmUKZGVsaXN0ZWQgZmlyc3Q7IHRoZWlyIHN0YWtlcyBzdGF5IHJlZnVuZGFibGUgdGhyb3VnaApgY2FuY2VsX2NoYWxsZW5nZWAuIFJldHVybnMgdGhlIGNoYWxsZW5nZSBpZC4AAAAAEGNyZWF0ZV9jaGFsbGVuZ2UAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBvaW50cwAAAAAACwAAAAAAAAAOZXhwaXJlc19sZWRnZXIAAAAAAAQAAAABAAAD6QAAAAQAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAKpTZXNzaW9ucyBgcGxheWVyYCBpcyBzZWF0ZWQgaW4gdGhhdCBoYXZlIG5vdCBmaW5pc2hlZCwgb2xkZXN0IGZpcnN0LApzbyBhIHJldHVybmluZyBwbGF5ZXIgY2FuIGZpbmQgdGhlIGdhbWVzIHdhaXRpbmcgb24gdGhlbS4gR2FtZXMgdGhhdApleHBpcmVkIHVuZmluaXNoZWQgYXJlIGxlZnQgb3V0LgAAAAAAEGdldF9hY3RpdmVfZ2FtZXMAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAChDaGFubmVsIGtleXMgcmVnaXN0ZXJlZCBzbyBmYXIsIGJ5IHNlYXQuAAAAEGdldF9jaGFubmVsX2tleXMAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+wAAAAEAAAD7gAAACA=",
+        "AAAAAAAAAItSZXNvbHZlIGV2ZXJ5IGRlcGVuZGVudCBjb250cmFjdCBhZGRyZXNzIGFuZCB0aGUgcnVsZSBjb25maWcgaGFzaCBpbgpvbmUgY2FsbCwgc28gY2xpZW50cyBuZWVkIGEgc2luZ2xlIHNpbXVsYXRpb24gYmVmb3JlIGJ1aWxkaW5nIGEgcHJvb2YuAAAAABBnZXRfaW50ZWdyYXRpb25zAAAAAAAAAAEAAAPpAAAH0AAAAAxJbnRlZ3JhdGlvbnMAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAAAAAAAQZ2V0X3N0YWtlX2VzY3JvdwAAAAAAAAABAAAD6AAAB9AAAAALU3Rha2VFc2Nyb3cA",
+        "AAAAAAAAAD1HZXQgdGhlIHJlY29yZGVkIGtlY2NhazI1NiBvZiBhIGZpbmlzaGVkIGdhbWUncyBzdW1tYXJ5IGJsb2IuAAAAAAAAEGdldF9zdW1tYXJ5X2hhc2gAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAPuAAAAIA==",
+        "AAAAAQAAAJJBIHNwb25zb3IncyBhbGxvd2FuY2UgZm9yIGFuIGVwaGVtZXJhbCBndWVzdCBrZXkuIEdhbWVzIHRoZSBndWVzdCBzdGFydHMKYXJlIHJlcG9ydGVkIHRvIHRoZSBHYW1lIEh1YiB1bmRlciBgc3BvbnNvcmAgdW50aWwgYGdhbWVzX2xlZnRgIHJ1bnMgb3V0LgAAAAAAAAAAABBHdWVzdFNwb25zb3JzaGlwAAAAAgAAAAAAAAAKZ2FtZXNfbGVmdAAAAAAABAAAAAAAAAAHc3BvbnNvcgAAAAAT",
+        "AAAAAAAAAHpFc2Nyb3cgc3Rha2VzIGluIGEgdG9rZW4gZm9yIGdhbWVzIHN0YXJ0ZWQgZnJvbSBub3cgb24sIG9yIHN0b3AKZXNjcm93aW5nIHdpdGggYE5vbmVgLiBUaGUgcmFrZSBpcyBhdCBtb3N0IGBNQVhfUkFLRV9CUFNgLgAAAAAAEHNldF9zdGFrZV9lc2Nyb3cAAAABAAAAAAAAAAZlc2Nyb3cAAAAAA+gAAAfQAAAAC1N0YWtlRXNjcm93AAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAqRWZXJpZnkgYSBOb2lyIFVsdHJhS2VjY2FrSG9uayBwcm9vZiBvbi1jaGFpbiBpbiBhICoqc2VwYXJhdGUKdHJhbnNhY3Rpb24qKiBmcm9tIGByZXZlYWxfc2VlZGAuICBUaGlzIHNwbGl0cyB0aGUgfjI2ME0gQ1BVIGNvc3QKaW50byB0d28gdHJhbnNhY3Rpb25zIHRoYXQgZWFjaCBmaXQgd2l0aGluIFNvcm9iYW4gbGltaXRzOgoKVFggMTogYHZlcmlmeV9ub2lyX3NlZWRgIOKGkiBVbHRyYUhvbmsgdmVyaWZpY2F0aW9uIHZpYSB0aGUgWksgdmVyaWZpZXIgKH4yMTVNIENQVSkKVFggMjogYHJldmVhbF9zZWVkYCB3aXRoIGFuIGVtcHR5IHByb29mIOKGkiBnYW1lIGxvZ2ljICh+NTBNIENQVSkKClRoZSB2ZXJpZmllZCBmbGFnIGlzIHN0b3JlZCBpbiB0ZW1wb3Jhcnkgc3RvcmFnZSBhbmQgY29uc3VtZWQgYnkKYHJldmVhbF9zZWVkYCB3aGVuIGl0IHNlZXMgYSB6ZXJvLWxlbmd0aCBwcm9vZi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gZ2FtZSBzZXNzaW9uIGlkCiogYHBsYXllcmAgLSB0aGUgcGxheWVyIHJldmVhbGluZyAocmVxdWlyZXMgYXV0aCkKKiBgc2VlZF9oYXNoYCAtIGJsYWtlMnMoc2VlZCkg4oCUIHRoZSBOb2lyIHB1YmxpYyBpbnB1dAoqIGBwcm9vZmAgLSByYXcgVWx0cmFLZWNjYWtIb25rIHByb29mICg+IGBVTFRSQUhPTktfTUlOX1BST09GX0xFTmAgYnl0ZXMpAAAAEHZlcmlmeV9ub2lyX3NlZWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACXNlZWRfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAAAIAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAt9Db21taXQgYSBjYW5na3VsIChjYW5ub3QgZm9sbG93IHN1aXQpIGFjdGlvbiB3aXRoIGEgWksgaGFuZCBwcm9vZi4KClRoZSBwbGF5ZXIgcHJvdmVzIHRoYXQgTk9ORSBvZiB0aGUgY2FyZHMgaW4gdGhlaXIgaGFuZCBtYXRjaAp0aGUgY3VycmVudCB0cmljayBzdWl0LiBVc2VzIGFuIGFnZ3JlZ2F0ZSBQZWRlcnNlbiBjb21taXRtZW50Cm92ZXIgdGhlIGVudGlyZSBoYW5kIHdpdGggYSBTY2hub3JyIHByb29mIG9mIGtub3dsZWRnZS4KCmBjb21taXRfaGFzaCA9IGtlY2NhazI1NihBX2J5dGVzKWAgd2hlcmUgQSBpcyB0aGUgYWdncmVnYXRlClBlZGVyc2VuIGNvbW1pdG1lbnQ6IGBBID0gzqMoY2FyZF9pwrdHICsgcl9pwrdIKWAuCgpgemtfcHJvb2ZgIGxheW91dDogYGsoNCkgfHwgQSg5NiwgRzEpIHx8IFIoOTYsIEcxKSB8fCB6KDMyLCBGcikgPSAyMjggYnl0ZXNgCgpUaGUgWksgdmVyaWZpZXIgKE1vZGUgOCkgY2hlY2tzOgoxLiBBZ2dyZWdhdGUgUGVkZXJzZW4gYmluZGluZyAoa2VjY2FrMjU2KEEpID09IGNvbW1pdF9oYXNoKQoyLiBTY2hub3JyIHByb29mIG9mIGtub3dsZWRnZSBvZiBhZ2dyZWdhdGUgYmxpbmRpbmcKMy4gU3VpdCBleGNsdXNpb246IG5vIGNhcmQgbWF0Y2hlcyB0cmlja19zdWl0CgpBcyB3aXRoIGBjb21taXRfcGxheV96a2AsIGFuIGVtcHR5IGB6a19wcm9vZmAgY29uc3VtZXMgYSBwcm9vZgpyZWNvcmRlZCBlYXJsaWVyIHdpdGggYHZlcmlmeV9hbmRfcmVjb3JkYC4AAAAAEWNvbW1pdF9jYW5na3VsX3prAAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtjb21taXRfaGFzaAAAAAPuAAAAIAAAAAAAAAAOZXhwZWN0ZWRfbm9uY2UAAAAAAAQAAAAAAAAACHprX3Byb29mAAAADgAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAAAAAAARZ2V0X2NoYW5uZWxfY2xhaW0AAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADENoYW5uZWxDbGFpbQ==",
+        "AAAAAAAAADhHZXQgYSBndWVzdCdzIHNwb25zb3IgYW5kIHJlbWFpbmluZyBnYW1lcywgaWYgc3BvbnNvcmVkLgAAABFnZXRfZ3Vlc3Rfc3BvbnNvcgAAAAAAAAEAAAAAAAAABWd1ZXN0AAAAAAAAEwAAAAEAAAPoAAAH0AAAABBHdWVzdFNwb25zb3JzaGlw",
+        "AAAAAAAAADhHZXQgdGhlIHVuY2xhaW1lZCBzdGFrZXMgZm9yIGEgZmluaXNoZWQgc2Vzc2lvbiwgaWYgYW55LgAAABFnZXRfcGVuZGluZ19jbGFpbQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAAMUGVuZGluZ0NsYWlt",
+        "AAAAAAAAAMVFdmVyeSByZXNvbHZlZCB0cmljayBvZiBhIHNlc3Npb24sIG9sZGVzdCBmaXJzdCwgdW5saWtlIHRoZSBnYW1lJ3MKYHRyaWNrX2xvZ2AsIHdoaWNoIGtlZXBzIG9ubHkgdGhlIGxhc3QgYFRSSUNLX0xPR19MRU5gLiBFbXB0eSBiZWZvcmUKdGhlIGZpcnN0IHRyaWNrLCBvciBvbmNlIHRoZSBlbnRyeSBoYXMgZXhwaXJlZCB3aXRoIHRoZSBnYW1lLgAAAAAAABFnZXRfdHJpY2tfaGlzdG9yeQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6gAAB9AAAAALVHJpY2tSZWNvcmQA",
+        "AAAAAAAAASNUaGUgdmlld2VyJ3MgcGxheWFibGUgY2FyZHMgZm9yIHRoZSBjdXJyZW50IHRyaWNrIGFuZCB0aGUgY2FsbApleHBlY3RlZCBmcm9tIHRoZW0gbmV4dCwgc28gZnJvbnRlbmRzIG5lZWQgbm90IHJlLWRlcml2ZSB0aGUgcnVsZXMuCkxpa2UgYGdldF9nYW1lX3ZpZXdgIGl0IG5lZWRzIG5vIGF1dGggYW5kIHNob3dzIG9ubHkgdGhlIHZpZXdlcidzCm93biBoYW5kLiBGYWlscyB3aXRoIGBOb3RBUGxheWVyYCBmb3IgYSBub24tcGxheWVyIGFuZCBgV3JvbmdQaGFzZWAKb3V0c2lkZSB0aGUgcGxheWluZyBwaGFzZS4AAAAAEWdldF92YWxpZF9hY3Rpb25zAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGdmlld2VyAAAAAAATAAAAAQAAA+kAAAfQAAAADFZhbGlkQWN0aW9ucwAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAQAAAI9QZXItcGxheWVyIG5vdGlmaWNhdGlvbiBvcHQtaW5zLCByZWFkIGJ5IHJlbGF5cyBhbmQgYnkgdGhlIGNvbnRyYWN0J3Mgb3duCmV2ZW50IHBhdGhzLiBBIHBsYXllciB3aXRoIG5vIHN0b3JlZCByZWNvcmQgZ2V0cyBgRGVmYXVsdGAgKGFsbCBvZmYpLgAAAAAAAAAAEU5vdGlmaWNhdGlvblByZWZzAAAAAAAAAwAAAAAAAAARZGVhZGxpbmVfd2FybmluZ3MAAAAAAAABAAAAAAAAAAttdXRlX2Vtb3RlcwAAAAABAAAAAAAAAA5yZW1hdGNoX29mZmVycwAAAAAAAQ==",
+        "AAAAAAAAAGFTdGFydCBhIHByYWN0aWNlIGdhbWUgb2YgYHBsYXllcmAgYWdhaW5zdCBgYm90YCB1bmRlciBzdGFuZGFyZApydWxlcy4gT25seSB0aGUgcGxheWVyIGF1dGhvcml6ZXMuAAAAAAAAEXN0YXJ0X2dhbWVfdnNfYm90AAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAANib3QAAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAG1QYXkgYHRyZWFzdXJ5YCBldmVyeXRoaW5nIGNyZWRpdGVkIHRvIGl0IGluIGB0b2tlbmAuIE9ubHkgdGhlCnRyZWFzdXJ5IG1heSB3aXRoZHJhdy4gUmV0dXJucyB0aGUgYW1vdW50IHBhaWQuAAAAAAAAEXdpdGhkcmF3X3RyZWFzdXJ5AAAAAAAAAgAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAADkNhbmdrdWxhbkVycm9yAAA=",
+        "AAAAAAAAAQ1MZXQgYGRlbGVnYXRlYCBzaWduIGBwbGF5ZXJgJ3MgY29tbWl0cyBhbmQgcmV2ZWFscyBpbiBgc2Vzc2lvbl9pZGAsCmUuZy4gYSBicm93c2VyIHNlc3Npb24ga2V5IG9yIGEgcmVsYXllci4gRnJvbSB0aGVuIG9uIHRob3NlIGNhbGxzCm5lZWQgdGhlIGRlbGVnYXRlJ3MgYXV0aCBpbnN0ZWFkIG9mIHRoZSBwbGF5ZXInczsgZm9yZmVpdHMsIHRpbWVvdXRzCmFuZCBjbGFpbXMgc3RpbGwgbmVlZCB0aGUgcGxheWVyLiBSZS1hdXRob3JpemluZyByZXBsYWNlcyB0aGUga2V5LgAAAAAAABJhdXRob3JpemVfZGVsZWdhdGUAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAAAAAAASZ2V0X2VudHJvcHlfcG9saWN5AAAAAAAAAAAAAQAAB9AAAAANRW50cm9weVBvbGljeQAAAA==",
+        "AAAAAAAAAFBWZXJpZmllcnMgc2VydmluZyBhIHNpbmdsZSBwcm9vZiBtb2RlIGluc3RlYWQgb2YgdGhlIGRlZmF1bHQgb25lLAprZXllZCBieSBtb2RlLgAAABJnZXRfbW9kZV92ZXJpZmllcnMAAAAAAAAAAAABAAAD7AAAAAQAAAAT",
+        "AAAAAAAAAjVHZXQgYSBwYWdlIG9mIGEgcGxheWVyJ3MgZ2FtZSBoaXN0b3J5LCBvbGRlc3QgZmlyc3QsIHdpdGggb3V0Y29tZQpmcm9tIHRoZSBwbGF5ZXIncyBwZXJzcGVjdGl2ZTogMSA9IHdpbiwgMiA9IGxvc3MsIDMgPSBkcmF3LgpgY3Vyc29yYCBpcyBhIHBvc2l0aW9uIGluIHRoZSB3aG9sZSBoaXN0b3J5ICgwID0gb2xkZXN0IGdhbWUpLCBhbmQKYG9wcG9uZW50YCBhbmQgYG91dGNvbWVgIGtlZXAgb25seSBtYXRjaGluZyBnYW1lcy4gQSBjYWxsIHJldHVybnMKYXQgbW9zdCBgbGltaXRgIGdhbWVzLCBjYXBwZWQgYXQgYE1BWF9ISVNUT1JZX1BBR0VgLCBhbmQgcmVhZHMgYXQKbW9zdCBgTUFYX0hJU1RPUllfU0NBTl9DSFVOS1NgIGNodW5rcywgc28gYSBmaWx0ZXJlZCBwYWdlIGNhbiBjb21lCmJhY2sgc2hvcnQgb3IgZW1wdHkgd2l0aCBhIGBuZXh0X2N1cnNvcmAgdG8gY29udGludWUgZnJvbS4gRXZlcnkKY2h1bmsgcmVhZCBoYXMgaXRzIFRUTCBleHRlbmRlZDsgY2h1bmtzIG5vYm9keSByZWFkcyBvciB3cml0ZXMgZm9yCnRoZSBoaXN0b3J5IFRUTCBleHBpcmUgYW5kIHJlYWQgYXMgZW1wdHkuAAAAAAAAEmdldF9wbGF5ZXJfaGlzdG9yeQAAAAAABQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAAAAAAIb3Bwb25lbnQAAAPoAAAAEwAAAAAAAAAHb3V0Y29tZQAAAAPoAAAABAAAAAEAAAfQAAAAC0hpc3RvcnlQYWdlAA==",
+        "AAAAAAAAAD5TZXNzaW9uIGlkIG9mIHRoZSByZW1hdGNoIG9mIGBzZXNzaW9uX2lkYCwgcGVuZGluZyBvciBzdGFydGVkLgAAAAAAEnJlbWF0Y2hfc2Vzc2lvbl9pZAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAIBTZXQgdGhlIGVudHJvcHkgcG9saWN5IHRoYXQgcmV2ZWFsZWQgc2VlZCBoYXNoZXMgbXVzdCBzYXRpc2Z5LgpBcHBsaWVzIHRvIGV2ZXJ5IHJldmVhbCBmcm9tIG5vdyBvbiwgaW5jbHVkaW5nIGdhbWVzIGluIHByb2dyZXNzLgAAABJzZXRfZW50cm9weV9wb2xpY3kAAAAAAAEAAAAAAAAABnBvbGljeQAAAAAH0AAAAA1FbnRyb3B5UG9saWN5AAAAAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAHBMZWdhY3kgcGxheSBjb21taXQgYGtlY2NhazI1NihjYXJkX2lkX3UzMl9iZSDiiKUgc2FsdClgLCBhcyBjaGVja2VkIGJ5CmByZXZlYWxfcGxheWAgZm9yIGEgYGNvbW1pdF9wbGF5YCBjb21taXQuAAAAE2NvbXB1dGVfcGxheV9jb21taXQAAAAAAgAAAAAAAAAHY2FyZF9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAGlIYXNoLWJhc2VkIChOSVpLKSBzZWVkIGNvbW1pdApga2VjY2FrMjU2KHNlZWRfaGFzaCDiiKUgYmxpbmRpbmcg4oilIHBsYXllciBhcyBzdHJrZXkpYCBmb3IgYGNvbW1pdF9zZWVkYC4AAAAAAAATY29tcHV0ZV9zZWVkX2NvbW1pdAAAAAADAAAAAAAAAAlzZWVkX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAIYmxpbmRpbmcAAAPuAAAAIAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+4AAAAg",
+        "AAAAAAAAADlJZHMgb2YgdGhlIGNoYWxsZW5nZXMgc3RpbGwgb3BlbiB0byBhY2NlcHQsIG9sZGVzdCBmaXJzdC4AAAAAAAATZ2V0X29wZW5fY2hhbGxlbmdlcwAAAAAAAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAD9BbW91bnQgY3JlZGl0ZWQgdG8gYHRyZWFzdXJ5YCBpbiBgdG9rZW5gIGFuZCBub3QgeWV0IHdpdGhkcmF3bi4AAAAAFGdldF90cmVhc3VyeV9iYWxhbmNlAAAAAgAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAFJFeHBpcnkgbGVkZ2VyIG9mIHRoZSBhY3RpdmUgb3ZlcnJpZGUsIG9yIGBOb25lYCB3aGVuIHByb29mcyBhcmUKdmVyaWZpZWQgbm9ybWFsbHkuAAAAAAAVZ2V0X3ZlcmlmaWVyX292ZXJyaWRlAAAAAAAAAAAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAGpTZW5kIHByb29mcyBvZiBgbW9kZWAgdG8gYHZlcmlmaWVyYCBpbiBnYW1lcyBzdGFydGVkIGZyb20gbm93IG9uLgpgbW9kZWAgaXMgb25lIG9mIGBST1VUQUJMRV9QUk9PRl9NT0RFU2AuAAAAAAAVc2V0X3ZlcmlmaWVyX2Zvcl9tb2RlAAAAAAAAAgAAAAAAAAAEbW9kZQAAAAQAAAAAAAAACHZlcmlmaWVyAAAAEwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAcJBY3RpdmF0ZSBhIHNjaGVkdWxlZCBvdmVycmlkZSBvbmNlIGl0cyB0aW1lbG9jayBoYXMgZWxhcHNlZC4KClVudGlsIHRoZSByZXR1cm5lZCBleHBpcnkgbGVkZ2VyLCBaSyB2ZXJpZmllciBjYWxscyBhcmUgc2tpcHBlZCAoZWFjaApza2lwIGVtaXRzIGBFdlZlcmlmaWVyQnlwYXNzZWRgKSB3aGlsZSB0aGUgY2hlY2tzIHRoZSBjb250cmFjdCBjYW4gZG8KaXRzZWxmIHN0aWxsIGFwcGx5OiBOSVpLIHNlZWQgcmV2ZWFscyBtdXN0IG9wZW4gdGhlaXIgaGFzaCBjb21taXRtZW50LApQZWRlcnNlbiBzZWVkIHJldmVhbHMgbXVzdCBtYXRjaCBga2VjY2FrMjU2KEMpYCwgYW5kIGNhcmQgcmV2ZWFscyBhcmUKc3RpbGwgb3BlbmVkIGFuZCBydWxlLWNoZWNrZWQgaW4gYHJldmVhbF9wbGF5YC4gTm9pciBwcm9vZnMgYXJlCnVuYWZmZWN0ZWQuIFRoZSBvdmVycmlkZSBsYXBzZXMgb24gaXRzIG93bi4AAAAAABZleGVjdXRlX2FmdGVyX3RpbWVsb2NrAAAAAAAAAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAD9HZXQgYSBwbGF5ZXIncyBub3RpZmljYXRpb24gcHJlZmVyZW5jZXMgKGFsbCBvZmYgaWYgbmV2ZXIgc2V0KS4AAAAAFmdldF9ub3RpZmljYXRpb25fcHJlZnMAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAAEU5vdGlmaWNhdGlvblByZWZzAAAA",
+        "AAAAAAAAAMdTdG9yZSB0aGUgY2FsbGVyJ3Mgbm90aWZpY2F0aW9uIHByZWZlcmVuY2VzLgoKUmVsYXlzIHJlYWQgdGhlc2UgdmlhIGBnZXRfbm90aWZpY2F0aW9uX3ByZWZzYDsgdGhlIGNvbnRyYWN0IGl0c2VsZgpyZXNwZWN0cyBgZGVhZGxpbmVfd2FybmluZ3NgIChpbiBgdGlja190aW1lb3V0YCkgYW5kIGBtdXRlX2Vtb3Rlc2AKKGluIGBzZW5kX2Vtb3RlYCkuAAAAABZzZXRfbm90aWZpY2F0aW9uX3ByZWZzAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXByZWZzAAAAAAAH0AAAABFOb3RpZmljYXRpb25QcmVmcwAAAAAAAAA=",
+        "AAAAAAAAAMRTdGFydCBhIGdhbWUgdW5kZXIgaG91c2UgcnVsZXM7IGBOb25lYCBwbGF5cyBgR2FtZUNvbmZpZzo6c3RhbmRhcmQoKWAuCgpXaXRoIGEgY29uZmlnIGV2ZXJ5IHBsYXllciBhdXRob3JpemVzIGAoc2Vzc2lvbl9pZCwgcG9pbnRzLCBjb25maWcpYCwKc28gbm9ib2R5IGlzIHNlYXRlZCB1bmRlciBydWxlcyB0aGV5IGRpZCBub3QgYWdyZWUgdG8uAAAAFnN0YXJ0X2dhbWVfd2l0aF9jb25maWcAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcnMAAAAD6gAAABMAAAAAAAAABnBvaW50cwAAAAAD6gAAAAsAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAAR1TdGFydCBhIGdhbWUgZm9yIDLigJM0IHBsYXllcnMsIHNlYXRlZCBpbiB0aGUgZ2l2ZW4gb3JkZXIsIGVhY2ggc3Rha2luZwp0aGUgbWF0Y2hpbmcgZW50cnkgb2YgYHBvaW50c2AuCgpFdmVyeSBwbGF5ZXIgYXV0aG9yaXplcyBgKHNlc3Npb25faWQsIHBvaW50cylgIGFzIGZvciBgc3RhcnRfZ2FtZWAuCkdhbWVzIHdpdGggbW9yZSB0aGFuIHR3byBzZWF0cyBhcmUgbm90IHJlcG9ydGVkIHRvIHRoZSBHYW1lIEh1Yiwgc28KZ3Vlc3Qgc3BvbnNvcnNoaXBzIGFyZSBub3QgY2hhcmdlZCBmb3IgdGhlbS4AAAAAAAAWc3RhcnRfbXVsdGlwbGF5ZXJfZ2FtZQAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAGcG9pbnRzAAAAAAPqAAAACwAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAEBTZW5kIHByb29mcyBvZiBgbW9kZWAgYmFjayB0byB0aGUgZGVmYXVsdCB2ZXJpZmllciBpbiBuZXcgZ2FtZXMuAAAAF2NsZWFyX3ZlcmlmaWVyX2Zvcl9tb2RlAAAAAAEAAAAAAAAABG1vZGUAAAAEAAAAAQAAA+kAAAACAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAQAAADhBbiBlbWVyZ2VuY3kgdmVyaWZpZXIgb3ZlcnJpZGUgd2FpdGluZyBvdXQgaXRzIHRpbWVsb2NrLgAAAAAAAAAXUGVuZGluZ1ZlcmlmaWVyT3ZlcnJpZGUAAAAAAgAAAAAAAAAQZHVyYXRpb25fbGVkZ2VycwAAAAQAAAAAAAAAEWV4ZWN1dGFibGVfbGVkZ2VyAAAAAAAABA==",
+        "AAAAAAAAADxEcm9wIGEgc2NoZWR1bGVkIG92ZXJyaWRlIGFuZCBlbmQgYW4gYWN0aXZlIG9uZSBpbW1lZGlhdGVseS4AAAAYY2FuY2VsX3ZlcmlmaWVyX292ZXJyaWRlAAAAAAAAAAEAAAPpAAAAAgAAB9AAAAAOQ2FuZ2t1bGFuRXJyb3IAAA==",
+        "AAAAAAAAAdFTY2hlZHVsZSBhbiBlbWVyZ2VuY3kgb3ZlcnJpZGUgb2YgdGhlIFpLIHZlcmlmaWVyLCBmb3IgaW5jaWRlbnRzCndoZXJlIGEgYnJva2VuIHZlcmlmaWVyIHdvdWxkIG90aGVyd2lzZSBzdHJhbmQgZXZlcnkgZ2FtZSBpbiBwcm9ncmVzcy4KCk5vdGhpbmcgY2hhbmdlcyB1bnRpbCBgZXhlY3V0ZV9hZnRlcl90aW1lbG9ja2AgaXMgY2FsbGVkIGF0IGxlYXN0CmBPVkVSUklERV9USU1FTE9DS19MRURHRVJTYCAofjI0aCkgbGF0ZXIsIHdoaWNoIGdpdmVzIHBsYXllcnMgYW5kCmluZGV4ZXJzIHdhdGNoaW5nIGBFdlZlcmlmaWVyT3ZlcnJpZGVTY2hlZHVsZWRgIHRpbWUgdG8gcmVhY3QuIFVzZSBhCm11bHRpc2lnIGFjY291bnQgYXMgYWRtaW4gc28gbm8gc2luZ2xlIGtleSBjYW4gdHJpZ2dlciB0aGlzLgpSZXNjaGVkdWxpbmcgcmVzdGFydHMgdGhlIHRpbWVsb2NrLiBSZXR1cm5zIHRoZSBleGVjdXRhYmxlIGxlZGdlci4AAAAAAAAac2NoZWR1bGVfdmVyaWZpZXJfb3ZlcnJpZGUAAAAAAAEAAAAAAAAAEGR1cmF0aW9uX2xlZGdlcnMAAAAEAAAAAQAAA+kAAAAEAAAH0AAAAA5DYW5na3VsYW5FcnJvcgAA",
+        "AAAAAAAAANFaSyBwbGF5IGNvbW1pdCBga2VjY2FrMjU2KGNhcmRfaWTCt0cgKyBibGluZGluZ8K3SClgLCBhcyBjaGVja2VkIGJ5CmByZXZlYWxfcGxheWAgZm9yIGEgYGNvbW1pdF9wbGF5X3prYCBjb21taXQuIEZvciBhIGBjb21taXRfY2FuZ2t1bF96a2AKY29tbWl0IHBhc3MgdGhlIHN1bSBvZiB0aGUgaGFuZCdzIGNhcmQgaWRzIGFuZCB0aGUgYWdncmVnYXRlIGJsaW5kaW5nLgAAAAAAABxjb21wdXRlX3BlZGVyc2VuX2NvbW1pdF9oYXNoAAAAAgAAAAAAAAAHY2FyZF9pZAAAAAAEAAAAAAAAAAhibGluZGluZwAAA+4AAAAgAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAAAAAAAdZ2V0X3BlbmRpbmdfdmVyaWZpZXJfb3ZlcnJpZGUAAAAAAAAAAAAAAQAAA+gAAAfQAAAAF1BlbmRpbmdWZXJpZmllck92ZXJyaWRlAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
     forfeit: this.txFromJSON<Result<void>>,
+        get_fee: this.txFromJSON<u32>,
         get_hub: this.txFromJSON<Result<string>>,
+        set_fee: this.txFromJSON<Result<void>>,
         set_hub: this.txFromJSON<Result<void>>,
         upgrade: this.txFromJSON<Result<void>>,
-        get_game: this.txFromJSON<Result<CangkulanGame>>,
+        get_game: this.txFromJSON<Result<PublicGameView>>,
         get_admin: this.txFromJSON<Result<string>>,
+        get_match: this.txFromJSON<Option<MatchState>>,
+        is_paused: this.txFromJSON<boolean>,
+        next_deal: this.txFromJSON<Result<MatchState>>,
         set_admin: this.txFromJSON<Result<void>>,
+        send_emote: this.txFromJSON<Result<boolean>>,
+        set_paused: this.txFromJSON<Result<void>>,
         start_game: this.txFromJSON<Result<void>>,
+        cancel_game: this.txFromJSON<Result<void>>,
         commit_play: this.txFromJSON<Result<void>>,
         commit_seed: this.txFromJSON<Result<void>>,
+        get_rematch: this.txFromJSON<Option<RematchOffer>>,
         reveal_play: this.txFromJSON<Result<void>>,
         reveal_seed: this.txFromJSON<Result<void>>,
+        start_match: this.txFromJSON<Result<u32>>,
+        audit_reveal: this.txFromJSON<Result<boolean>>,
+        get_deadline: this.txFromJSON<Option<readonly [u32, u32, readonly [u32, u32]]>>,
+        get_delegate: this.txFromJSON<Option<string>>,
+        get_hand_cap: this.txFromJSON<HandCapConfig>,
         get_verifier: this.txFromJSON<Result<string>>,
+        migrate_game: this.txFromJSON<Result<u32>>,
+        open_channel: this.txFromJSON<Result<void>>,
+        revoke_guest: this.txFromJSON<Result<void>>,
+        set_hand_cap: this.txFromJSON<Result<void>>,
         set_verifier: this.txFromJSON<Result<void>>,
+        submit_trick: this.txFromJSON<Result<void>>,
         tick_timeout: this.txFromJSON<Result<u32>>,
+        close_channel: this.txFromJSON<Result<u32>>,
+        export_replay: this.txFromJSON<Result<Buffer>>,
+        get_challenge: this.txFromJSON<Option<Challenge>>,
         get_game_view: this.txFromJSON<Result<CangkulanGame>>,
+        prune_expired: this.txFromJSON<Result<Array<u32>>>,
+        sponsor_guest: this.txFromJSON<Result<void>>,
+        verify_replay: this.txFromJSON<Result<boolean>>,
+        accept_rematch: this.txFromJSON<Result<u32>>,
+        cancel_rematch: this.txFromJSON<Result<void>>,
+        channel_digest: this.txFromJSON<Buffer>,
+        claim_winnings: this.txFromJSON<Result<i128>>,
         commit_play_zk: this.txFromJSON<Result<void>>,
+        export_summary: this.txFromJSON<Result<Buffer>>,
         get_game_debug: this.txFromJSON<Result<CangkulanGame>>,
+        settle_channel: this.txFromJSON<Result<u32>>,
         verify_shuffle: this.txFromJSON<Result<Array<u32>>>,
+        dispute_shuffle: this.txFromJSON<Result<void>>,
+        expire_winnings: this.txFromJSON<Result<i128>>,
+        get_game_record: this.txFromJSON<Result<CangkulanGame>>,
+        get_history_len: this.txFromJSON<u32>,
+        get_leaderboard: this.txFromJSON<Option<string>>,
+        request_rematch: this.txFromJSON<Result<u32>>,
         resolve_timeout: this.txFromJSON<Result<void>>,
+        revoke_delegate: this.txFromJSON<Result<void>>,
+        set_leaderboard: this.txFromJSON<Result<void>>,
+        accept_challenge: this.txFromJSON<Result<u32>>,
+        cancel_challenge: this.txFromJSON<Result<void>>,
+        claim_forced_win: this.txFromJSON<Result<u32>>,
+        create_challenge: this.txFromJSON<Result<u32>>,
+        get_active_games: this.txFromJSON<Array<u32>>,
+        get_channel_keys: this.txFromJSON<Map<u32, Buffer>>,
+        get_integrations: this.txFromJSON<Result<Integrations>>,
+        get_stake_escrow: this.txFromJSON<Option<StakeEscrow>>,
+        get_summary_hash: this.txFromJSON<Option<Buffer>>,
+        set_stake_escrow: this.txFromJSON<Result<void>>,
         verify_noir_seed: this.txFromJSON<Result<void>>,
         commit_cangkul_zk: this.txFromJSON<Result<void>>,
-        get_player_history: this.txFromJSON<Array<GameSummary>>,
-        get_ultrahonk_verifier: this.txFromJSON<Result<string>>,
-        set_ultrahonk_verifier: this.txFromJSON<Result<void>>
+        get_channel_claim: this.txFromJSON<Option<ChannelClaim>>,
+        get_guest_sponsor: this.txFromJSON<Option<GuestSponsorship>>,
+        get_pending_claim: this.txFromJSON<Option<PendingClaim>>,
+        get_trick_history: this.txFromJSON<Array<TrickRecord>>,
+        get_valid_actions: this.txFromJSON<Result<ValidActions>>,
+        start_game_vs_bot: this.txFromJSON<Result<void>>,
+        withdraw_treasury: this.txFromJSON<Result<i128>>,
+        authorize_delegate: this.txFromJSON<Result<void>>,
+        get_entropy_policy: this.txFromJSON<EntropyPolicy>,
+        get_mode_verifiers: this.txFromJSON<Map<u32, string>>,
+        get_player_history: this.txFromJSON<HistoryPage>,
+        rematch_session_id: this.txFromJSON<Option<u32>>,
+        set_entropy_policy: this.txFromJSON<Result<void>>,
+        compute_play_commit: this.txFromJSON<Buffer>,
+        compute_seed_commit: this.txFromJSON<Buffer>,
+        get_open_challenges: this.txFromJSON<Array<u32>>,
+        get_treasury_balance: this.txFromJSON<i128>,
+        get_verifier_override: this.txFromJSON<Option<u32>>,
+        set_verifier_for_mode: this.txFromJSON<Result<void>>,
+        execute_after_timelock: this.txFromJSON<Result<u32>>,
+        get_notification_prefs: this.txFromJSON<NotificationPrefs>,
+        set_notification_prefs: this.txFromJSON<null>,
+        start_game_with_config: this.txFromJSON<Result<void>>,
+        start_multiplayer_game: this.txFromJSON<Result<void>>,
+        clear_verifier_for_mode: this.txFromJSON<Result<void>>,
+        cancel_verifier_override: this.txFromJSON<Result<void>>,
+        schedule_verifier_override: this.txFromJSON<Result<u32>>,
+        compute_pedersen_commit_hash: this.txFromJSON<Buffer>,
+        get_pending_verifier_override: this.txFromJSON<Option<PendingVerifierOverride>>
   }
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
-import { Client as CangkulanClient, type CangkulanGame, type GameSummary } from './bindings';
+import {
+  Client as CangkulanClient, type CangkulanGame, type GameSummary, type PublicGameView,
+} from './bindings';
+import { SEED_PROOF_MODE, type GameState, type ProofMode } from './types';
 import {
   DEFAULT_METHOD_OPTIONS, MULTI_SIG_AUTH_TTL_MINUTES,
   getActiveRpcUrl, getActivePassphrase, needsAllowHttp,
@@ -23,6 +26,72 @@ function i128ToBigInt(parts: { hi: () => { toBigInt: () => bigint }; lo: () => {
   return (hi << 64n) | (lo & 0xFFFFFFFFFFFFFFFFn);
 }
 
+/** Largest page `get_player_history` returns (contract `MAX_HISTORY_PAGE`). */
+const HISTORY_PAGE_LIMIT = 50;
+
+/** Flatten the first two seats of a full game (`get_game_view`). */
+function gameStateFromGame(game: CangkulanGame): GameState {
+  const [p1, p2] = game.players;
+  return {
+    player1: p1.player,
+    player2: p2.player,
+    player1_points: p1.points,
+    player2_points: p2.points,
+    lifecycle_state: game.lifecycle_state,
+    trick_state: game.trick_state,
+    outcome: game.outcome,
+    action_nonce: game.action_nonce,
+    deadline_nonce: game.deadline_nonce,
+    draw_pile_size: game.draw_pile.length,
+    flipped_card: game.flipped_card,
+    trick_suit: game.trick_suit,
+    hand1: p1.hand,
+    hand2: p2.hand,
+    tricks_won1: p1.tricks_won,
+    tricks_won2: p2.tricks_won,
+    seed_committed1: p1.seed_commit != null,
+    seed_committed2: p2.seed_commit != null,
+    seed_revealed1: p1.seed_revealed,
+    seed_revealed2: p2.seed_revealed,
+    play_committed1: p1.play_commit != null,
+    play_committed2: p2.play_commit != null,
+    trick_card1: p1.trick_card,
+    trick_card2: p2.trick_card,
+    zk_play1: p1.zk_play,
+    zk_play2: p2.zk_play,
+  };
+}
+
+/** Flatten the first two seats of the public view (`get_game`). */
+function gameStateFromView(view: PublicGameView): GameState {
+  const [p1, p2] = view.players;
+  return {
+    player1: p1.player,
+    player2: p2.player,
+    player1_points: p1.points,
+    player2_points: p2.points,
+    lifecycle_state: view.lifecycle_state,
+    trick_state: view.trick_state,
+    outcome: view.outcome,
+    action_nonce: view.action_nonce,
+    draw_pile_size: view.draw_pile_size,
+    flipped_card: view.flipped_card,
+    trick_suit: view.trick_suit,
+    hand1: [],
+    hand2: [],
+    tricks_won1: p1.tricks_won,
+    tricks_won2: p2.tricks_won,
+    seed_committed1: p1.seed_committed,
+    seed_committed2: p2.seed_committed,
+    seed_revealed1: p1.seed_revealed,
+    seed_revealed2: p2.seed_revealed,
+    play_committed1: p1.play_committed,
+    play_committed2: p2.play_committed,
+    zk_play1: false,
+    zk_play2: false,
+  };
+}
+
 /** Network configuration for service instances. */
 export interface NetworkConfig {
   rpcUrl: string;
@@ -80,12 +149,15 @@ export class CangkulanService {
   //  Read-Only Queries
   // ═══════════════════════════════════════════════════════════════════════════
 
-  async getGame(sessionId: number): Promise<CangkulanGame | null> {
+  /**
+   * Public game query: seat progress and counts, no cards. Anyone can call it.
+   */
+  async getGame(sessionId: number): Promise<GameState | null> {
     try {
       const tx = await this.baseClient.get_game({ session_id: sessionId });
       const result = await tx.simulate();
       if (result.result.isOk()) {
-        return result.result.unwrap();
+        return gameStateFromView(result.result.unwrap());
       } else {
         // Distinguish between actual "not found" vs other simulation errors
         const errStr = String(result.result.unwrapErr());
@@ -107,12 +179,12 @@ export class CangkulanService {
    * Privacy-aware game query: only the viewer's own hand is visible.
    * The opponent's hand is redacted to prevent card snooping.
    */
-  async getGameView(sessionId: number, viewer: string): Promise<CangkulanGame | null> {
+  async getGameView(sessionId: number, viewer: string): Promise<GameState | null> {
     try {
       const tx = await this.baseClient.get_game_view({ session_id: sessionId, viewer });
       const result = await tx.simulate();
       if (result.result.isOk()) {
-        return result.result.unwrap();
+        return gameStateFromGame(result.result.unwrap());
       }
       const errStr = String(result.result.unwrapErr());
       log.warn(`[getGameView] Simulation Error for Game #${sessionId}, Viewer ${viewer}:`, errStr);
@@ -142,19 +214,27 @@ export class CangkulanService {
   }
 
   /**
-   * Get a player's game history (up to 50 most recent games).
-   * Returns summaries with outcome from the player's perspective:
+   * Get a player's whole game history, oldest first, one page at a time
+   * until `next_cursor` runs out. Returns summaries with outcome from the
+   * player's perspective:
    *   1 = win, 2 = loss, 3 = draw
    */
   async getPlayerHistory(player: string): Promise<GameSummary[]> {
+    const games: GameSummary[] = [];
+    let cursor: number | undefined = 0;
     try {
-      const tx = await this.baseClient.get_player_history({ player });
-      const result = await tx.simulate();
-      return result.result ?? [];
+      while (cursor !== undefined) {
+        const tx = await this.baseClient.get_player_history({
+          player, cursor, limit: HISTORY_PAGE_LIMIT, opponent: undefined, outcome: undefined,
+        });
+        const { result } = await tx.simulate();
+        games.push(...result.entries);
+        cursor = result.next_cursor;
+      }
     } catch (err) {
       log.debug('[getPlayerHistory] Error:', err);
-      return [];
     }
+    return games;
   }
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -547,7 +627,7 @@ export class CangkulanService {
   // ═══════════════════════════════════════════════════════════════════════════
 
   async commitSeed(
-    sessionId: number, playerAddress: string, commitHash: Buffer,
+    sessionId: number, playerAddress: string, commitHash: Buffer, proofMode: ProofMode,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
@@ -555,6 +635,7 @@ export class CangkulanService {
       const client = this.createSigningClient(playerAddress, signer);
       const tx = await client.commit_seed({
         session_id: sessionId, player: playerAddress, commit_hash: commitHash,
+        proof_mode: SEED_PROOF_MODE[proofMode],
       }, DEFAULT_METHOD_OPTIONS);
       try {
         const sentTx = await simulateAndSend(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds);
@@ -83,8 +83,8 @@ function ContractInspector() {
           <div><span className="text-gray-500">Player 2:</span> {gameState.player2?.slice(0, 12)}...</div>
           <div><span className="text-gray-500">Tricks:</span> P1={gameState.tricks_won1} P2={gameState.tricks_won2}</div>
           <div><span className="text-gray-500">Outcome:</span> {gameState.outcome === 0 ? 'Unresolved' : gameState.outcome === 1 ? 'P1 Win' : gameState.outcome === 2 ? 'P2 Win' : 'Draw'}</div>
-          <div><span className="text-gray-500">Seed P1:</span> {gameState.seed_commit1 ? '✅ Committed' : '❌'} {gameState.seed_revealed1 ? '/ ✅ Revealed' : ''}</div>
-          <div><span className="text-gray-500">Seed P2:</span> {gameState.seed_commit2 ? '✅ Committed' : '❌'} {gameState.seed_revealed2 ? '/ ✅ Revealed' : ''}</div>
+          <div><span className="text-gray-500">Seed P1:</span> {gameState.seed_committed1 ? '✅ Committed' : '❌'} {gameState.seed_revealed1 ? '/ ✅ Revealed' : ''}</div>
+          <div><span className="text-gray-500">Seed P2:</span> {gameState.seed_committed2 ? '✅ Committed' : '❌'} {gameState.seed_revealed2 ? '/ ✅ Revealed' : ''}</div>
           <details className="mt-2">
             <summary className="cursor-pointer text-blue-500 hover:underline">Raw JSON</summary>
             <pre className="mt-1 p-2 rounded bg-gray-100 dark:bg-gray-900 overflow-x-auto text-[10px] max-h-48">
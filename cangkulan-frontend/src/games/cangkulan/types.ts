@@ -1,7 +1,39 @@
-import type { CangkulanGame as GameState } from './bindings';
 import type { AppRoute } from '@/hooks/useHashRouter';
 
-export type { GameState };
+/**
+ * Two-seat game state as the UI reads it, flattened from the contract's
+ * `CangkulanGame` (`get_game_view`) or `PublicGameView` (`get_game`).
+ * The public view carries no cards, so its hands are empty and its
+ * trick cards and deadline nonce are unset.
+ */
+export interface GameState {
+  player1: string;
+  player2: string;
+  player1_points: bigint;
+  player2_points: bigint;
+  lifecycle_state: number;
+  trick_state: number;
+  outcome: number;
+  action_nonce: number;
+  deadline_nonce?: number;
+  draw_pile_size: number;
+  flipped_card?: number;
+  trick_suit?: number;
+  hand1: number[];
+  hand2: number[];
+  tricks_won1: number;
+  tricks_won2: number;
+  seed_committed1: boolean;
+  seed_committed2: boolean;
+  seed_revealed1: boolean;
+  seed_revealed2: boolean;
+  play_committed1: boolean;
+  play_committed2: boolean;
+  trick_card1?: number;
+  trick_card2?: number;
+  zk_play1: boolean;
+  zk_play2: boolean;
+}
 
 export type GamePhase = 'create' | 'seed-commit' | 'seed-reveal' | 'playing' | 'complete';
 
@@ -20,6 +52,13 @@ export interface TrickRecord {
 
 export type ProofMode = 'nizk' | 'pedersen' | 'noir';
 
+/** `proof_mode` argument of `commit_seed` (contract `SEED_PROOF_*`). */
+export const SEED_PROOF_MODE: Record<ProofMode, number> = {
+  nizk: 2,
+  pedersen: 4,
+  noir: 100,
+};
+
 export type GameMode = 'ai' | 'multiplayer' | 'dev';
 
 export interface SeedData {
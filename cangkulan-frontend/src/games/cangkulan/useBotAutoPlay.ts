@@ -144,7 +144,7 @@ export function useBotAutoPlay({
   useEffect(() => {
     if (disabled || !gameState) return;
     if (gameState.lifecycle_state !== LIFECYCLE.SEED_COMMIT) return;
-    if (gameState.seed_commit2 != null) return; // already committed
+    if (gameState.seed_committed2) return; // already committed
 
     const timer = setTimeout(() => {
       runBotAction('Commit Seed', async () => {
@@ -166,7 +166,7 @@ export function useBotAutoPlay({
         }
 
         const signer = bot!.getContractSigner();
-        const result = await service.commitSeed(sessionId, bot!.address, commitHash, signer);
+        const result = await service.commitSeed(sessionId, bot!.address, commitHash, proofMode, signer);
         if (result.txHash) addTx(`🤖 Bot Commit Seed (${proofMode})`, result.txHash, bot!.address);
 
         // Save for reveal phase (ref + sessionStorage for page-refresh recovery)
@@ -176,7 +176,7 @@ export function useBotAutoPlay({
     }, 1500); // Wait 1.5s for natural pacing
 
     return () => clearTimeout(timer);
-  }, [disabled, gameState?.lifecycle_state, gameState?.seed_commit2, runBotAction, service, sessionId, addTx, bot]);
+  }, [disabled, gameState?.lifecycle_state, gameState?.seed_committed2, runBotAction, service, sessionId, addTx, bot]);
 
   // ═══════════════════════════════════════════════════════════════════════════
   //  Phase: Seed Reveal — bot reveals its seed with Pedersen proof
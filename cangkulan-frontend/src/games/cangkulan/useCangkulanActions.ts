@@ -161,7 +161,7 @@ export function useCangkulanActions(config: CangkulanActionsConfig): CangkulanAc
         }
 
         const signer = getContractSigner();
-        const commitResult = await service.commitSeed(sessionId, userAddress, commitHash, signer);
+        const commitResult = await service.commitSeed(sessionId, userAddress, commitHash, proofMode, signer);
         if (commitResult.txHash) addTx('Commit Seed', commitResult.txHash, userAddress);
         playSound('commit');
         const modeLabel = proofMode === 'noir' ? 'Noir blake2s' : proofMode === 'nizk' ? 'Hash-NIZK' : 'Pedersen blinding';
@@ -311,7 +311,7 @@ const diagrams: DiagramSection[] = [
     Player->>Player: commitHash = Pedersen(seedHash, blinding)
     Player->>LS: Save {seed, blinding}
 
-    Player->>CC: commit_seed(commitHash)
+    Player->>CC: commit_seed(commitHash, proofMode)
     CC->>CC: Store commitHash
 
     Note over Player: REVEAL PHASE (after both commit)
@@ -13,10 +13,12 @@ import { PageHero } from '@/components/PageHero';
    ═══════════════════════════════════════════════════════════════════════════════
 
    Reads the player's game history from the cangkulan contract's persistent
-   storage via `get_player_history(player)`. Each entry is a compact GameSummary
-   with outcome from the player's perspective (1=win, 2=loss, 3=draw).
+   storage via `get_player_history`, one page per call, following `next_cursor`
+   to the end. Each entry is a compact GameSummary with outcome from the
+   player's perspective (1=win, 2=loss, 3=draw).
 
-   Data is stored on-chain for 120 days per player, max 50 games.
+   The whole history is kept on-chain in chunks of 50 games, each with a
+   120-day TTL renewed whenever it is written or read.
    ═══════════════════════════════════════════════════════════════════════════════ */
 
 
@@ -340,7 +342,7 @@ export function HistoryPage({ navigate }: { navigate: (route: AppRoute) => void
           {/* Data notice */}
           <div className="text-center">
             <p className="text-xs text-gray-400 dark:text-gray-500">
-              Stored on-chain in persistent storage (120 day TTL, max 50 games)
+              Stored on-chain in persistent storage (chunks of 50 games, 120 day TTL renewed on use)
             </p>
           </div>
         </>
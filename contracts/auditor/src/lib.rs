@@ -0,0 +1,597 @@
+#![no_std]
+
+//! # Auditor Contract
+//!
+//! One-call consistency audit of a Cangkulan session. Given the game
+//! contract and a session id, `audit` reads the game, re-derives the deck
+//! with `verify_shuffle`, recomputes what it can of the result from the
+//! trick log and final hands, and cross-checks the records the game left in
+//! its own storage and in the leaderboard. It changes nothing and needs no
+//! auth, so integrators and bug bounty hunters can run it against any
+//! deployment.
+//!
+//! ## Checks
+//!
+//! | Bit | Check | Runs when | Fails when |
+//! |-----|-------|-----------|------------|
+//! | `CHECK_SHUFFLE` | Deck | cards dealt | `verify_shuffle` errors or does not hold the game's `deck_size` distinct cards, the top `deck_size / 4` values of each suit; a finished game's draw pile is not the tail of the deck |
+//! | `CHECK_TRANSCRIPT` | Transcript | cards dealt | the trick log does not chain from `trick_log_base` to `transcript`; with nothing pruned, the base is not the deal seed |
+//! | `CHECK_TRICKS` | Trick log | cards dealt | a logged trick breaks the suit or ranking rules, or logged wins exceed (or, with nothing pruned, differ from) a seat's `tricks_won` |
+//! | `CHECK_OUTCOME` | Outcome | finished by play | the outcome differs from the one recomputed from the final hands |
+//! | `CHECK_SUMMARY` | Summary hash | finished | the recorded summary hash is missing or differs from `keccak256(export_summary)` |
+//! | `CHECK_HISTORY` | Player history | finished | any player's history disagrees with the game |
+//! | `CHECK_LEADERBOARD` | Leaderboard | finished two-seat game, not cancelled or against a bot, leaderboard set | any player has no stats, or no result counter matching the outcome |
+//!
+//! A game finished by timeout, forfeit, forced win or hand-cap loss has no
+//! outcome to recompute, so `CHECK_OUTCOME` is skipped. The Game Hub has no
+//! read interface; its side is the game's `EvHubEndReported` event.
+//!
+//! The report lists what ran in `checked` and what found an inconsistency
+//! in `failed`, so `failed == 0` means every check that could run passed.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, Bytes, BytesN,
+    Env, Map, Vec,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Types
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Result of `audit`. `checked` and `failed` are `CHECK_*` bitmasks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditReport {
+    pub session_id: u32,
+    pub lifecycle_state: u32,
+    pub outcome: u32,
+    /// Outcome recomputed from the final hands; 0 if the game did not end by play
+    pub recomputed_outcome: u32,
+    /// Checks that ran
+    pub checked: u32,
+    /// Checks that found an inconsistency
+    pub failed: u32,
+}
+
+// Mirrors of the game and leaderboard types. Contract values are decoded
+// field by field, so these must match the originals by name and type.
+
+/// Mirror of `cangkulan::PlayerSlot`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerSlot {
+    pub player: Address,
+    pub points: i128,
+    pub seed_commit: Option<BytesN<32>>,
+    pub seed_hash: Option<BytesN<32>>,
+    pub seed_revealed: bool,
+    pub seed_mode: Option<u32>,
+    pub hand: Vec<u32>,
+    pub play_commit: Option<BytesN<32>>,
+    pub zk_play: bool,
+    pub play_revealed: bool,
+    pub trick_card: Option<u32>,
+    pub tricks_won: u32,
+}
+
+/// Mirror of `cangkulan::CangkulanGame`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CangkulanGame {
+    pub schema_version: u32,
+    pub players: Vec<PlayerSlot>,
+    pub draw_pile: Vec<u32>,
+    pub trick_state: u32,
+    pub trick_suit: Option<u32>,
+    pub flipped_card: Option<u32>,
+    pub lifecycle_state: u32,
+    pub outcome: u32,
+    pub forfeited_by: u32,
+    pub vs_bot: bool,
+    pub action_nonce: u32,
+    pub deadline_nonce: Option<u32>,
+    pub deadline_ledger: Option<u32>,
+    pub last_tick_ledger: u32,
+    pub updated_ledger: u32,
+    pub time_banks: Vec<u32>,
+    pub max_hand_size: u32,
+    pub overflow_rule: u32,
+    pub config: GameConfig,
+    pub verifier: Address,
+    pub mode_verifiers: Map<u32, Address>,
+    pub stake_token: Option<Address>,
+    pub rake_bps: u32,
+    pub treasury: Option<Address>,
+    pub transcript: BytesN<32>,
+    pub trick_log: Vec<TrickRecord>,
+    pub trick_log_base: BytesN<32>,
+    pub tricks_pruned: u32,
+}
+
+/// Mirror of `cangkulan::GameConfig`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub hand_size: u32,
+    pub cards_per_suit: u32,
+    pub deck_size: u32,
+    pub timeout_ledgers: u32,
+    pub tie_break: u32,
+    pub penalty_draw: u32,
+    pub zk_required: bool,
+    pub draw_until_follow: bool,
+    pub strict_reveal: bool,
+    pub time_bank_ledgers: u32,
+    pub teams: bool,
+}
+
+/// Mirror of `cangkulan::TrickRecord`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrickRecord {
+    pub flipped: u32,
+    pub cards: Vec<u32>,
+    pub winner: u32,
+    pub ledger: u32,
+}
+
+/// Mirror of `cangkulan::GameSummary`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSummary {
+    pub session_id: u32,
+    pub opponent: Address,
+    pub outcome: u32,
+    pub tricks_won: u32,
+    pub tricks_lost: u32,
+    pub ledger: u32,
+}
+
+/// Mirror of `cangkulan::HistoryPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryPage {
+    pub entries: Vec<GameSummary>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Mirror of `leaderboard::PlayerStats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub address: Address,
+    pub elo: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub games_played: u32,
+    pub win_streak: u32,
+    pub best_streak: u32,
+    pub last_updated: u64,
+    pub registration: u32,
+    pub flags: u32,
+    pub flag_count: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AuditorError {
+    GameNotFound = 1,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  External trait interfaces
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// The read-only part of the Cangkulan contract the audit relies on.
+#[contractclient(name = "CangkulanClient")]
+pub trait Cangkulan {
+    fn get_game_record(env: Env, session_id: u32) -> CangkulanGame;
+    fn get_game_view(env: Env, session_id: u32, viewer: Address) -> CangkulanGame;
+    fn verify_shuffle(env: Env, session_id: u32) -> Vec<u32>;
+    fn export_summary(env: Env, session_id: u32) -> Bytes;
+    fn get_summary_hash(env: Env, session_id: u32) -> Option<BytesN<32>>;
+    fn get_player_history(
+        env: Env,
+        player: Address,
+        cursor: u32,
+        limit: u32,
+        opponent: Option<Address>,
+        outcome: Option<u32>,
+    ) -> HistoryPage;
+    fn get_leaderboard(env: Env) -> Option<Address>;
+}
+
+#[contractclient(name = "LeaderboardClient")]
+pub trait Leaderboard {
+    fn get_player(env: Env, player: Address) -> Option<PlayerStats>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Constants
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub const CHECK_SHUFFLE: u32 = 1 << 0;
+pub const CHECK_TRANSCRIPT: u32 = 1 << 1;
+pub const CHECK_TRICKS: u32 = 1 << 2;
+pub const CHECK_OUTCOME: u32 = 1 << 3;
+pub const CHECK_SUMMARY: u32 = 1 << 4;
+pub const CHECK_HISTORY: u32 = 1 << 5;
+pub const CHECK_LEADERBOARD: u32 = 1 << 6;
+
+// Game constants the checks depend on (see the cangkulan crate)
+const STATE_FINISHED: u32 = 4;
+const OUTCOME_UNRESOLVED: u32 = 0;
+const OUTCOME_PLAYER1_WIN: u32 = 1;
+const OUTCOME_PLAYER2_WIN: u32 = 2;
+const OUTCOME_DRAW: u32 = 3;
+const OUTCOME_PLAYER3_WIN: u32 = 4;
+const OUTCOME_PLAYER4_WIN: u32 = 5;
+const OUTCOME_CANCELLED: u32 = 6;
+/// Largest deck a game can play: 4 suits of 13
+const MAX_DECK_SIZE: u32 = 52;
+const CANNOT_FOLLOW_SENTINEL: u32 = 0xFFFF_FFFF;
+const MAX_PLAYERS: usize = 4;
+const TIE_BREAK_CARDS: u32 = 1;
+/// Largest page `get_player_history` returns
+const HISTORY_PAGE: u32 = 50;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Contract
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[contract]
+pub struct Auditor;
+
+#[contractimpl]
+impl Auditor {
+    /// Audit session `session_id` of the Cangkulan contract at `game`.
+    pub fn audit(env: Env, game: Address, session_id: u32) -> Result<AuditReport, AuditorError> {
+        let client = CangkulanClient::new(&env, &game);
+        // A finished game is read in full; one in play as a non-player sees it
+        let state = match client.try_get_game_record(&session_id) {
+            Ok(Ok(state)) => state,
+            _ => match client.try_get_game_view(&session_id, &env.current_contract_address()) {
+                Ok(Ok(state)) => state,
+                _ => return Err(AuditorError::GameNotFound),
+            },
+        };
+
+        let mut report = AuditReport {
+            session_id,
+            lifecycle_state: state.lifecycle_state,
+            outcome: state.outcome,
+            recomputed_outcome: OUTCOME_UNRESOLVED,
+            checked: 0,
+            failed: 0,
+        };
+
+        // The deal happens once every seed is revealed
+        if state.players.iter().all(|seat| seat.seed_revealed) {
+            Self::record(&mut report, CHECK_SHUFFLE, Self::shuffle_consistent(&client, session_id, &state));
+            Self::record(&mut report, CHECK_TRANSCRIPT, Self::transcript_consistent(&env, session_id, &state));
+            Self::record(&mut report, CHECK_TRICKS, Self::tricks_consistent(&state));
+        }
+
+        if state.lifecycle_state == STATE_FINISHED {
+            if let Some(outcome) = Self::replayed_outcome(&state) {
+                report.recomputed_outcome = outcome;
+                Self::record(&mut report, CHECK_OUTCOME, outcome == state.outcome);
+            }
+            Self::record(&mut report, CHECK_SUMMARY, Self::summary_consistent(&env, &client, session_id));
+            Self::record(&mut report, CHECK_HISTORY, Self::history_consistent(&client, session_id, &state));
+            // Only two-seat games are rated, and never when cancelled
+            let leaderboard = client
+                .get_leaderboard()
+                .filter(|_| state.players.len() == 2 && !state.vs_bot && state.outcome != OUTCOME_CANCELLED);
+            if let Some(leaderboard) = leaderboard {
+                let ok = Self::leaderboard_consistent(&LeaderboardClient::new(&env, &leaderboard), &state);
+                Self::record(&mut report, CHECK_LEADERBOARD, ok);
+            }
+        }
+
+        Ok(report)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Internal: Checks
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    fn record(report: &mut AuditReport, check: u32, ok: bool) {
+        report.checked |= check;
+        if !ok {
+            report.failed |= check;
+        }
+    }
+
+    /// The deck holds the game's `deck_size` distinct cards, the top
+    /// `deck_size / 4` values of each suit, and the draw pile (drawn from
+    /// the front) is still its tail. Hands and pile are only public once
+    /// the game is finished.
+    fn shuffle_consistent(client: &CangkulanClient, session_id: u32, state: &CangkulanGame) -> bool {
+        let deck = match client.try_verify_shuffle(&session_id) {
+            Ok(Ok(deck)) => deck,
+            _ => return false,
+        };
+        let size = state.config.deck_size;
+        let per_suit = state.config.cards_per_suit;
+        if deck.len() != size || size > 4 * per_suit || 4 * per_suit > MAX_DECK_SIZE {
+            return false;
+        }
+        let lowest = per_suit - size / 4;
+        let mut seen = [false; MAX_DECK_SIZE as usize];
+        for card in deck.iter() {
+            if card >= 4 * per_suit || card % per_suit < lowest || seen[card as usize] {
+                return false;
+            }
+            seen[card as usize] = true;
+        }
+
+        if state.lifecycle_state == STATE_FINISHED {
+            let pile = &state.draw_pile;
+            if pile.len() > size || deck.slice(size - pile.len()..) != *pile {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Chaining the logged tricks onto `trick_log_base` reproduces the
+    /// transcript. With nothing pruned the base is the transcript right
+    /// after the deal: keccak256(0^32 || keccak256(seed_hash per seat || session_id)).
+    fn transcript_consistent(env: &Env, session_id: u32, state: &CangkulanGame) -> bool {
+        let mut hash = state.trick_log_base.clone();
+        for trick in state.trick_log.iter() {
+            let mut data = Bytes::from_array(env, &hash.to_array());
+            data.append(&Bytes::from_array(env, &trick.flipped.to_be_bytes()));
+            for card in trick.cards.iter() {
+                data.append(&Bytes::from_array(env, &card.to_be_bytes()));
+            }
+            data.append(&Bytes::from_array(env, &trick.winner.to_be_bytes()));
+            hash = env.crypto().keccak256(&data).into();
+        }
+        if hash != state.transcript {
+            return false;
+        }
+
+        if state.tricks_pruned == 0 {
+            let mut seed_data = Bytes::new(env);
+            for seat in state.players.iter() {
+                let Some(seed_hash) = seat.seed_hash else {
+                    return false;
+                };
+                seed_data.append(&Bytes::from_array(env, &seed_hash.to_array()));
+            }
+            seed_data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+            let seed_hash = env.crypto().keccak256(&seed_data);
+            let mut data = Bytes::from_array(env, &[0u8; 32]);
+            data.append(&Bytes::from_array(env, &seed_hash.to_array()));
+            let dealt: BytesN<32> = env.crypto().keccak256(&data).into();
+            if dealt != state.trick_log_base {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Every logged trick follows the rules, and its wins add up.
+    fn tricks_consistent(state: &CangkulanGame) -> bool {
+        let seats = state.players.len();
+        let per_suit = state.config.cards_per_suit;
+        if per_suit == 0 {
+            return false;
+        }
+        let mut wins = [0u32; MAX_PLAYERS];
+        for trick in state.trick_log.iter() {
+            if trick.flipped >= 4 * per_suit || trick.cards.len() != seats {
+                return false;
+            }
+            let suit = trick.flipped / per_suit;
+            // Highest value wins, the earliest seat (P1 leads) on ties; 0 if nobody followed
+            let mut expected = 0u32;
+            let mut best = 0u32;
+            for (i, card) in trick.cards.iter().enumerate() {
+                if card == CANNOT_FOLLOW_SENTINEL {
+                    continue;
+                }
+                if card >= 4 * per_suit || card / per_suit != suit {
+                    return false;
+                }
+                if expected == 0 || card % per_suit > best {
+                    expected = i as u32 + 1;
+                    best = card % per_suit;
+                }
+            }
+            if trick.winner != expected {
+                return false;
+            }
+            if expected != 0 {
+                wins[expected as usize - 1] += 1;
+            }
+        }
+
+        state.players.iter().zip(wins).all(|(seat, won)| {
+            if state.tricks_pruned == 0 { won == seat.tricks_won } else { won <= seat.tricks_won }
+        })
+    }
+
+    /// The outcome `determine_winner` gives for the final state, if the game
+    /// ended by play: at least one trick resolved, none in progress, and a
+    /// hand or the draw pile empty. Every other ending (timeout, forfeit,
+    /// forced win, hand-cap loss) stops before one of those holds.
+    fn replayed_outcome(state: &CangkulanGame) -> Option<u32> {
+        let by_play = !state.trick_log.is_empty()
+            && state.flipped_card.is_none()
+            && (state.players.iter().any(|seat| seat.hand.is_empty()) || state.draw_pile.is_empty());
+        if !by_play {
+            return None;
+        }
+        if state.config.teams {
+            return Some(Self::replayed_team_outcome(state));
+        }
+
+        // Empty hands first, then most tricks and fewest cards in the order
+        // of the tie-break rule, then lowest total; each rule keeps the
+        // seats that are best on it
+        let per_suit = state.config.cards_per_suit;
+        let total = |hand: &Vec<u32>| hand.iter().map(|card| card % per_suit + 2).sum::<u32>();
+        let tricks = |seat: &PlayerSlot| seat.tricks_won;
+        let cards = |seat: &PlayerSlot| u32::MAX - seat.hand.len();
+        let mut contenders = Self::keep_best(&state.players, |seat| seat.hand.is_empty() as u32);
+        if state.config.tie_break == TIE_BREAK_CARDS {
+            contenders = Self::keep_best(&contenders, cards);
+            contenders = Self::keep_best(&contenders, tricks);
+        } else {
+            contenders = Self::keep_best(&contenders, tricks);
+            contenders = Self::keep_best(&contenders, cards);
+        }
+        contenders = Self::keep_best(&contenders, |seat| u32::MAX - total(&seat.hand));
+
+        if contenders.len() != 1 {
+            return Some(OUTCOME_DRAW);
+        }
+        let winner = contenders.get_unchecked(0).player;
+        let slot = state.players.iter().position(|seat| seat.player == winner)? as u32 + 1;
+        Some(Self::win_outcome(slot))
+    }
+
+    /// Team games rank seats 1 and 3 against seats 2 and 4 by the same rules
+    /// on the partners' totals; the win is recorded under seat 1 or 2.
+    fn replayed_team_outcome(state: &CangkulanGame) -> u32 {
+        let per_suit = state.config.cards_per_suit;
+        let rank = |team: u32| {
+            let partners = [state.players.get_unchecked(team - 1), state.players.get_unchecked(team + 1)];
+            let emptied = partners.iter().any(|seat| seat.hand.is_empty());
+            let tricks: u32 = partners.iter().map(|seat| seat.tricks_won).sum();
+            let cards = u32::MAX - partners.iter().map(|seat| seat.hand.len()).sum::<u32>();
+            let total = u32::MAX
+                - partners
+                    .iter()
+                    .flat_map(|seat| seat.hand.iter())
+                    .map(|card| card % per_suit + 2)
+                    .sum::<u32>();
+            if state.config.tie_break == TIE_BREAK_CARDS {
+                (emptied, cards, tricks, total)
+            } else {
+                (emptied, tricks, cards, total)
+            }
+        };
+        match rank(1).cmp(&rank(2)) {
+            core::cmp::Ordering::Greater => OUTCOME_PLAYER1_WIN,
+            core::cmp::Ordering::Less => OUTCOME_PLAYER2_WIN,
+            core::cmp::Ordering::Equal => OUTCOME_DRAW,
+        }
+    }
+
+    /// The seats of `seats` with the highest `score`.
+    fn keep_best(seats: &Vec<PlayerSlot>, score: impl Fn(&PlayerSlot) -> u32) -> Vec<PlayerSlot> {
+        let top = seats.iter().map(|seat| score(&seat)).max().unwrap_or(0);
+        let mut best = Vec::new(seats.env());
+        for seat in seats.iter() {
+            if score(&seat) == top {
+                best.push_back(seat);
+            }
+        }
+        best
+    }
+
+    fn win_outcome(slot: u32) -> u32 {
+        match slot {
+            1 => OUTCOME_PLAYER1_WIN,
+            2 => OUTCOME_PLAYER2_WIN,
+            3 => OUTCOME_PLAYER3_WIN,
+            _ => OUTCOME_PLAYER4_WIN,
+        }
+    }
+
+    /// Slot that won under `outcome`; `None` for a draw.
+    fn outcome_winner(outcome: u32) -> Option<u32> {
+        match outcome {
+            OUTCOME_PLAYER1_WIN => Some(1),
+            OUTCOME_PLAYER2_WIN => Some(2),
+            OUTCOME_PLAYER3_WIN => Some(3),
+            OUTCOME_PLAYER4_WIN => Some(4),
+            _ => None,
+        }
+    }
+
+    /// The summary hash recorded at the end still matches the game.
+    fn summary_consistent(env: &Env, client: &CangkulanClient, session_id: u32) -> bool {
+        let Some(recorded) = client.get_summary_hash(&session_id) else {
+            return false;
+        };
+        match client.try_export_summary(&session_id) {
+            Ok(Ok(blob)) => BytesN::<32>::from(env.crypto().keccak256(&blob)) == recorded,
+            _ => false,
+        }
+    }
+
+    /// Every player's history holds this session with the game's result:
+    /// win, loss or draw from their side, the next seat as opponent, and
+    /// everyone else's tricks as lost.
+    fn history_consistent(client: &CangkulanClient, session_id: u32, state: &CangkulanGame) -> bool {
+        let seats = state.players.len();
+        let total_tricks: u32 = state.players.iter().map(|seat| seat.tricks_won).sum();
+        let winner = Self::outcome_winner(state.outcome);
+        // In a team game a seat shares its partner's result and tricks
+        let side = |slot: u32| if state.config.teams { (slot - 1) % 2 + 1 } else { slot };
+
+        for (i, seat) in state.players.iter().enumerate() {
+            let slot = i as u32 + 1;
+            let opponent = state.players.get_unchecked(slot % seats).player;
+            let outcome = match winner {
+                Some(w) if w == side(slot) => OUTCOME_PLAYER1_WIN,
+                Some(_) => OUTCOME_PLAYER2_WIN,
+                None => state.outcome,
+            };
+            let side_tricks: u32 = state
+                .players
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| side(*j as u32 + 1) == side(slot))
+                .map(|(_, other)| other.tricks_won)
+                .sum();
+            // Page through the games against this opponent only
+            let mut cursor = 0;
+            let entry = loop {
+                let page = client.get_player_history(&seat.player, &cursor, &HISTORY_PAGE, &Some(opponent.clone()), &None);
+                if let Some(entry) = page.entries.iter().find(|s| s.session_id == session_id) {
+                    break Some(entry);
+                }
+                match page.next_cursor {
+                    Some(next) => cursor = next,
+                    None => break None,
+                }
+            };
+            let Some(entry) = entry else {
+                return false;
+            };
+            if entry.outcome != outcome
+                || entry.tricks_won != seat.tricks_won
+                || entry.tricks_lost != total_tricks - side_tricks
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Every player is on the leaderboard with a counter for this result.
+    fn leaderboard_consistent(leaderboard: &LeaderboardClient, state: &CangkulanGame) -> bool {
+        let winner = Self::outcome_winner(state.outcome);
+        state.players.iter().enumerate().all(|(i, seat)| {
+            let Some(stats) = leaderboard.get_player(&seat.player) else {
+                return false;
+            };
+            match winner {
+                Some(w) if w == i as u32 + 1 => stats.wins > 0,
+                Some(_) => stats.losses > 0,
+                None => stats.draws > 0,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test;
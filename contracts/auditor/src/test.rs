@@ -0,0 +1,268 @@
+#![cfg(test)]
+
+//! Unit tests for the Auditor contract.
+//!
+//! Audits run against the real Cangkulan and Leaderboard contracts, so a
+//! mirrored type that drifts from its original fails here. The Game Hub
+//! and ZK verifier are mocks (the verifier accepts any non-empty proof).
+
+use crate::{
+    AuditReport, Auditor, AuditorClient, AuditorError, CHECK_HISTORY, CHECK_LEADERBOARD,
+    CHECK_OUTCOME, CHECK_SHUFFLE, CHECK_SUMMARY, CHECK_TRANSCRIPT, CHECK_TRICKS,
+};
+use cangkulan::{
+    CangkulanContract, CangkulanContractClient, GameConfig, CANNOT_FOLLOW_SENTINEL, CARDS_PER_SUIT_36,
+    SEED_PROOF_NIZK, STATE_FINISHED,
+};
+use leaderboard::{Leaderboard, LeaderboardClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, Vec};
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Mocks
+// ════════════════════════════════════════════════════════════════════════════
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+}
+
+#[contract]
+pub struct MockZkVerifier;
+
+#[contractimpl]
+impl MockZkVerifier {
+    pub fn verify_from(_env: Env, _caller: Address, _public_inputs: Bytes, proof: Bytes) -> bool {
+        !proof.is_empty()
+    }
+
+    pub fn consume_verification(
+        _env: Env,
+        _consumer: Address,
+        _session_id: u32,
+        _player: Address,
+        _mode: u32,
+        _commitment: BytesN<32>,
+    ) -> bool {
+        false
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Test Helpers
+// ════════════════════════════════════════════════════════════════════════════
+
+struct Setup {
+    env: Env,
+    auditor: AuditorClient<'static>,
+    game: CangkulanContractClient<'static>,
+    board: LeaderboardClient<'static>,
+    admin: Address,
+    player1: Address,
+    player2: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let hub = env.register(MockGameHub, ());
+    let verifier = env.register(MockZkVerifier, ());
+    let admin = Address::generate(&env);
+    let game_id = env.register(CangkulanContract, (&admin, &hub, &verifier));
+    let board_id = env.register(Leaderboard, (&admin,));
+    let auditor_id = env.register(Auditor, ());
+
+    Setup {
+        auditor: AuditorClient::new(&env, &auditor_id),
+        game: CangkulanContractClient::new(&env, &game_id),
+        board: LeaderboardClient::new(&env, &board_id),
+        admin,
+        player1: Address::generate(&env),
+        player2: Address::generate(&env),
+        env,
+    }
+}
+
+/// Start a game and take it through seed commit + reveal to PLAYING.
+fn start_and_deal(s: &Setup, sid: u32) {
+    s.game.start_game(&sid, &s.player1, &s.player2, &100_0000000, &100_0000000);
+    deal(s, sid);
+}
+
+/// Commit and reveal every seat's seed, dealing the game.
+fn deal(s: &Setup, sid: u32) {
+    let mut revealed = Vec::new(&s.env);
+    for (i, seat) in s.game.get_game_debug(&sid).players.iter().enumerate() {
+        let (player, blinding) = (&seat.player, 0xAA + 0x11 * i as u8);
+        let mut seed = [0x13 + 0x44 * i as u8; 32];
+        for (j, b) in seed.iter_mut().enumerate() {
+            *b ^= j as u8;
+        }
+        let seed_hash: BytesN<32> = s.env.crypto().keccak256(&Bytes::from_array(&s.env, &seed)).into();
+        let mut pre = Bytes::from_array(&s.env, &seed_hash.to_array());
+        pre.append(&Bytes::from_array(&s.env, &[blinding; 32]));
+        pre.append(&player.to_string().to_bytes());
+        let commit: BytesN<32> = s.env.crypto().keccak256(&pre).into();
+        s.game.commit_seed(&sid, player, &commit, &SEED_PROOF_NIZK);
+
+        // Mode 2 proof: blinding || response
+        let mut proof = Bytes::from_array(&s.env, &[blinding; 32]);
+        proof.append(&Bytes::from_array(&s.env, &[0u8; 32]));
+        revealed.push_back((player.clone(), seed_hash, proof));
+    }
+    for (player, seed_hash, proof) in revealed.iter() {
+        s.game.reveal_seed(&sid, &player, &seed_hash, &proof);
+    }
+}
+
+/// Play one trick, every seat following suit when it can.
+fn play_one_trick(s: &Setup, sid: u32) {
+    let game = s.game.get_game_debug(&sid);
+    let suit = game.trick_suit.unwrap();
+    let pick = |hand: &Vec<u32>| {
+        hand.iter().find(|c| c / CARDS_PER_SUIT_36 == suit).unwrap_or(CANNOT_FOLLOW_SENTINEL)
+    };
+    let mut actions = Vec::new(&s.env);
+    for (i, seat) in game.players.iter().enumerate() {
+        let salt = BytesN::from_array(&s.env, &[0x11 * (i as u8 + 1); 32]);
+        actions.push_back((seat.player.clone(), pick(&seat.hand), salt));
+    }
+
+    for (player, card, salt) in actions.iter() {
+        let mut preimage = Bytes::from_array(&s.env, &card.to_be_bytes());
+        preimage.append(&Bytes::from_array(&s.env, &salt.to_array()));
+        let commit: BytesN<32> = s.env.crypto().keccak256(&preimage).into();
+        let nonce = s.game.get_game_debug(&sid).action_nonce;
+        s.game.commit_play(&sid, &player, &commit, &nonce);
+    }
+    for (player, card, salt) in actions.iter() {
+        if s.game.get_game_debug(&sid).lifecycle_state == STATE_FINISHED {
+            return;
+        }
+        s.game.reveal_play(&sid, &player, &card, &salt);
+    }
+}
+
+fn play_to_finish(s: &Setup, sid: u32) {
+    start_and_deal(s, sid);
+    play_out(s, sid);
+}
+
+/// Play tricks until the dealt game `sid` ends.
+fn play_out(s: &Setup, sid: u32) {
+    for _ in 0..200 {
+        if s.game.get_game_debug(&sid).lifecycle_state == STATE_FINISHED {
+            return;
+        }
+        play_one_trick(s, sid);
+    }
+    panic!("game did not finish after 200 tricks");
+}
+
+fn audit(s: &Setup, sid: u32) -> AuditReport {
+    s.auditor.audit(&s.game.address, &sid)
+}
+
+const DEAL_CHECKS: u32 = CHECK_SHUFFLE | CHECK_TRANSCRIPT | CHECK_TRICKS;
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn finished_game_passes_every_check() {
+    let s = setup();
+    let sid = 7u32;
+    play_to_finish(&s, sid);
+
+    s.game.set_leaderboard(&s.board.address);
+    let outcome = s.game.get_game(&sid).outcome;
+    s.board.record_match(&s.admin, &s.player1, &s.player2, &outcome);
+
+    let report = audit(&s, sid);
+    assert_eq!(report.session_id, sid);
+    assert_eq!(report.lifecycle_state, STATE_FINISHED);
+    assert_eq!(report.outcome, outcome);
+    assert_eq!(report.recomputed_outcome, outcome);
+    assert_eq!(
+        report.checked,
+        DEAL_CHECKS | CHECK_OUTCOME | CHECK_SUMMARY | CHECK_HISTORY | CHECK_LEADERBOARD
+    );
+    assert_eq!(report.failed, 0);
+}
+
+#[test]
+fn active_game_runs_deal_checks_only() {
+    let s = setup();
+    let sid = 8u32;
+    s.game.start_game(&sid, &s.player1, &s.player2, &100_0000000, &100_0000000);
+    let report = audit(&s, sid);
+    assert_eq!((report.checked, report.failed), (0, 0));
+
+    let sid = 9u32;
+    start_and_deal(&s, sid);
+    play_one_trick(&s, sid);
+    play_one_trick(&s, sid);
+
+    let report = audit(&s, sid);
+    assert_eq!(report.checked, DEAL_CHECKS);
+    assert_eq!(report.failed, 0);
+    assert_eq!(report.recomputed_outcome, 0);
+}
+
+#[test]
+fn missing_leaderboard_record_is_flagged() {
+    let s = setup();
+    let sid = 10u32;
+    play_to_finish(&s, sid);
+
+    // Without a leaderboard the check cannot run
+    let report = audit(&s, sid);
+    assert_eq!(report.checked & CHECK_LEADERBOARD, 0);
+    assert_eq!(report.failed, 0);
+
+    // Configured, but the result was never recorded
+    s.game.set_leaderboard(&s.board.address);
+    let report = audit(&s, sid);
+    assert_eq!(report.checked & CHECK_LEADERBOARD, CHECK_LEADERBOARD);
+    assert_eq!(report.failed, CHECK_LEADERBOARD);
+}
+
+#[test]
+fn team_game_audits_by_partnership() {
+    let s = setup();
+    let sid = 11u32;
+    let players = vec![&s.env, s.player1.clone(), s.player2.clone(), Address::generate(&s.env), Address::generate(&s.env)];
+    s.game.start_game_with_config(&sid, &players, &vec![&s.env, 0, 0, 0, 0], &Some(GameConfig::teams()));
+    deal(&s, sid);
+    play_out(&s, sid);
+
+    // Team games are never rated, so the leaderboard is not consulted
+    s.game.set_leaderboard(&s.board.address);
+    let report = audit(&s, sid);
+    assert_eq!(report.recomputed_outcome, report.outcome);
+    assert_eq!(report.checked, DEAL_CHECKS | CHECK_OUTCOME | CHECK_SUMMARY | CHECK_HISTORY);
+    assert_eq!(report.failed, 0);
+}
+
+#[test]
+fn unknown_session_rejected() {
+    let s = setup();
+    let result = s.auditor.try_audit(&s.game.address, &404u32);
+    assert_eq!(result, Err(Ok(AuditorError::GameNotFound)));
+}
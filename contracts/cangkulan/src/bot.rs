@@ -0,0 +1,147 @@
+//! # Bot opponents
+//!
+//! Single-player practice against a contract implementing `CangkulanBot`.
+//! `start_game_vs_bot` seats the human in seat 1 and the bot in seat 2.
+//! The bot never signs anything: each time the human moves, the game asks
+//! the bot for its reply in the same transaction and applies it as the
+//! bot's move.
+//!
+//! The bot's seed is derived from the human's seed commitment, so the deal
+//! needs no bot reveal. Its plays go through the usual commit-reveal
+//! checks: `choose_commit` once the human has committed to a trick,
+//! `choose_reveal` once the human has revealed. Soroban forbids calling
+//! back into the game, so the bot gets its view as a `BotTurn`. A bot that
+//! fails or answers with an illegal move forfeits.
+//!
+//! Practice games carry no stakes and are reported to neither the Game Hub
+//! nor the Leaderboard.
+
+use soroban_sdk::{contractclient, contractimpl, contracttype, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    CangkulanContract, CangkulanContractArgs, CangkulanContractClient, CangkulanError, CangkulanGame, EvGameForfeited,
+    GameConfig, OUTCOME_PLAYER1_WIN, STATE_PLAYING, STATE_SEED_COMMIT,
+};
+
+/// Seat the bot plays.
+const BOT_SLOT: u32 = 2;
+
+/// The bot's view of the trick it is asked to play.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BotTurn {
+    pub session_id: u32,
+    pub trick: u32,          // tricks resolved so far; fixed between commit and reveal
+    pub hand: Vec<u32>,      // the bot's hand
+    pub trick_suit: u32,
+}
+
+/// The action and salt behind the bot's commit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BotReveal {
+    pub card_id: u32, // or CANNOT_FOLLOW_SENTINEL
+    pub salt: BytesN<32>,
+}
+
+/// A contract that plays seat 2 of a practice game.
+#[contractclient(name = "CangkulanBotClient")]
+pub trait CangkulanBot {
+    /// `keccak256(action_u32_be ∥ salt)` for this trick's action, as in
+    /// `commit_play`.
+    fn choose_commit(env: Env, turn: BotTurn) -> BytesN<32>;
+
+    /// The action and salt committed to for the same trick.
+    fn choose_reveal(env: Env, turn: BotTurn) -> BotReveal;
+}
+
+#[contractimpl]
+impl CangkulanContract {
+    /// Start a practice game of `player` against `bot` under standard
+    /// rules. Only the player authorizes.
+    pub fn start_game_vs_bot(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        bot: Address,
+    ) -> Result<(), CangkulanError> {
+        player.require_auth();
+        Self::require_caller_session_id(session_id)?;
+        if player == bot {
+            return Err(CangkulanError::SelfPlayNotAllowed);
+        }
+        let players = soroban_sdk::vec![&env, player, bot];
+        let points = soroban_sdk::vec![&env, 0i128, 0i128];
+        Self::open_session(&env, session_id, players, points, None, false, GameConfig::standard(), true)
+    }
+}
+
+impl CangkulanContract {
+    /// Make the bot's move once the human's has landed: its seed after the
+    /// human's seed commit, its commit after theirs, its reveal after theirs.
+    pub(crate) fn drive_bot(env: &Env, session_id: u32, game: &mut CangkulanGame) -> Result<(), CangkulanError> {
+        if !game.vs_bot {
+            return Ok(());
+        }
+        let (human, mut bot) = (game.seat(1), game.seat(BOT_SLOT));
+        match game.lifecycle_state {
+            STATE_SEED_COMMIT if bot.seed_commit.is_none() => {
+                let Some(human_commit) = human.seed_commit else {
+                    return Ok(());
+                };
+                let mut preimage = Bytes::from_slice(env, b"cangkulan-bot");
+                preimage.extend_from_array(&session_id.to_be_bytes());
+                preimage.extend_from_array(&human_commit.to_array());
+                let seed_hash: BytesN<32> = env.crypto().keccak256(&preimage).into();
+                bot.seed_commit = Some(seed_hash.clone());
+                bot.seed_hash = Some(seed_hash);
+                bot.seed_revealed = true;
+                game.set_seat(BOT_SLOT, bot);
+                Ok(())
+            }
+            STATE_PLAYING => {
+                let client = CangkulanBotClient::new(env, &bot.player);
+                let turn = BotTurn {
+                    session_id,
+                    trick: game.tricks_pruned + game.trick_log.len(),
+                    hand: bot.hand.clone(),
+                    trick_suit: game.trick_suit.unwrap_or(0),
+                };
+                let mut attempt = game.clone();
+                let played = if human.play_commit.is_some() && bot.play_commit.is_none() {
+                    match client.try_choose_commit(&turn) {
+                        Ok(Ok(commit)) => {
+                            Self::apply_play_commit(env, session_id, &mut attempt, &bot.player, commit).is_ok()
+                        }
+                        _ => false,
+                    }
+                } else if human.play_revealed && !bot.play_revealed {
+                    match client.try_choose_reveal(&turn) {
+                        Ok(Ok(reveal)) => Self::apply_play_reveal(
+                            env,
+                            session_id,
+                            &mut attempt,
+                            &bot.player,
+                            reveal.card_id,
+                            reveal.salt,
+                        )
+                        .is_ok(),
+                        _ => false,
+                    }
+                } else {
+                    return Ok(());
+                };
+                if played {
+                    *game = attempt;
+                    return Ok(());
+                }
+
+                // The bot forfeits a move it cannot make
+                game.forfeited_by = BOT_SLOT;
+                EvGameForfeited { session_id, player: bot.player, penalized: false }.publish(env);
+                Self::finalize_game(env, session_id, game, OUTCOME_PLAYER1_WIN)
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! # State channel
+//!
+//! Fast play off-chain. Each seat registers an Ed25519 channel key with
+//! `open_channel`; the players then exchange `ChannelState` updates signed
+//! with those keys instead of sending every commit and reveal on-chain.
+//! Whoever wants to end the game submits the latest state carrying every
+//! seat's signature to `settle_channel`, which opens a challenge window of
+//! `CHANNEL_CHALLENGE_LEDGERS`. Within it anyone may replace the claim with
+//! a co-signed state of a higher `move_counter`, restarting the window.
+//! Once it passes, `close_channel` finishes the game with the claim's
+//! outcome, or, for a state still in play, the seat (or team) with the most
+//! tricks (a draw on a tie).
+//!
+//! Keys are fixed once every seat has registered, so a state signed under
+//! them stays valid for the rest of the game. The channel covers play from
+//! that point: a move made on-chain afterwards supersedes it, and it can no
+//! longer be settled or closed.
+//!
+//! Signatures cover `channel_digest(state)`, which binds this contract and
+//! the session, so a state cannot be replayed elsewhere. `hands_hash` is
+//! not checked on-chain; it is published with each claim as evidence of the
+//! hands the players agreed on.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, Vec};
+
+use crate::{
+    CangkulanContract, CangkulanContractArgs, CangkulanContractClient, CangkulanError, CangkulanGame, StorageKey,
+    GAME_TTL_LEDGERS, OUTCOME_DRAW, OUTCOME_UNRESOLVED,
+};
+
+/// Ledgers a channel claim stays open to a later state: ~1 hour.
+pub const CHANNEL_CHALLENGE_LEDGERS: u32 = 60 * 60 / crate::LEDGER_RATE_SECS; // 720 ledgers
+
+/// A game state every seat has signed off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChannelState {
+    pub session_id: u32,
+    pub move_counter: u32,     // raised with every update; the highest wins
+    pub hands_hash: BytesN<32>, // commitment to the hands, evidence only
+    pub tricks: Vec<u32>,      // tricks won per seat, in seat order
+    pub outcome: u32,          // OUTCOME_UNRESOLVED while play goes on
+}
+
+/// The state a settlement will finish with, unless a later one arrives
+/// by `challenge_ends`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChannelClaim {
+    pub state: ChannelState,
+    pub challenge_ends: u32,
+}
+
+#[contractevent]
+pub struct EvChannelOpened {
+    pub session_id: u32,
+    pub slot: u32,
+    pub key: BytesN<32>,
+}
+
+#[contractevent]
+pub struct EvChannelClaimed {
+    pub session_id: u32,
+    pub move_counter: u32,
+    pub hands_hash: BytesN<32>,
+    pub challenge_ends: u32,
+}
+
+#[contractevent]
+pub struct EvChannelClosed {
+    pub session_id: u32,
+    pub move_counter: u32,
+    pub outcome: u32,
+}
+
+#[contractimpl]
+impl CangkulanContract {
+    /// Register `player`'s Ed25519 key for signing channel states of
+    /// `session_id`. Registering again replaces the key until every seat
+    /// has one; the keys are then locked.
+    pub fn open_channel(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        key: BytesN<32>,
+    ) -> Result<(), CangkulanError> {
+        player.require_auth();
+
+        let game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+        let slot = Self::resolve_slot(&game, &player)?;
+
+        let mut keys = Self::get_channel_keys(env.clone(), session_id);
+        if keys.len() == game.players.len() {
+            return Err(CangkulanError::ChannelKeysLocked);
+        }
+        keys.set(slot, key.clone());
+        let storage_key = StorageKey::ChannelKeys(session_id);
+        env.storage().temporary().set(&storage_key, &keys);
+        env.storage()
+            .temporary()
+            .extend_ttl(&storage_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        if keys.len() == game.players.len() {
+            let base_key = StorageKey::ChannelBase(session_id);
+            env.storage().temporary().set(&base_key, &game.action_nonce);
+            env.storage()
+                .temporary()
+                .extend_ttl(&base_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        EvChannelOpened { session_id, slot, key }.publish(&env);
+        Ok(())
+    }
+
+    /// Submit a state with one signature per seat, in seat order, over
+    /// `channel_digest(state)`. Needs no auth. Starts the challenge window,
+    /// or replaces a pending claim with a higher `move_counter`. Returns
+    /// the ledger the window ends at.
+    pub fn settle_channel(
+        env: Env,
+        session_id: u32,
+        state: ChannelState,
+        signatures: Vec<BytesN<64>>,
+    ) -> Result<u32, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+
+        let seats = game.players.len();
+        let sides = if game.config.teams { 2 } else { seats };
+        let keys = Self::get_channel_keys(env.clone(), session_id);
+        if keys.len() != seats {
+            return Err(CangkulanError::ChannelNotOpen);
+        }
+        Self::require_channel_current(&env, session_id, &game)?;
+        let outcome_valid = state.outcome == OUTCOME_UNRESOLVED
+            || state.outcome == OUTCOME_DRAW
+            || (1..=sides).any(|slot| Self::win_outcome(slot) == state.outcome);
+        if state.session_id != session_id || state.tricks.len() != seats || !outcome_valid {
+            return Err(CangkulanError::InvalidChannelState);
+        }
+        if signatures.len() != seats {
+            return Err(CangkulanError::InvalidChannelState);
+        }
+        if let Some(claim) = Self::get_channel_claim(env.clone(), session_id) {
+            if state.move_counter <= claim.state.move_counter {
+                return Err(CangkulanError::StaleChannelState);
+            }
+        }
+
+        // Traps on a bad signature; the host offers no fallible check
+        let digest: Bytes = Self::channel_digest(env.clone(), state.clone()).into();
+        for slot in 1..=seats {
+            let key = keys.get_unchecked(slot);
+            env.crypto().ed25519_verify(&key, &digest, &signatures.get_unchecked(slot - 1));
+        }
+
+        let challenge_ends = env.ledger().sequence().saturating_add(CHANNEL_CHALLENGE_LEDGERS);
+        let storage_key = StorageKey::ChannelClaim(session_id);
+        EvChannelClaimed {
+            session_id,
+            move_counter: state.move_counter,
+            hands_hash: state.hands_hash.clone(),
+            challenge_ends,
+        }.publish(&env);
+        env.storage()
+            .temporary()
+            .set(&storage_key, &ChannelClaim { state, challenge_ends });
+        env.storage()
+            .temporary()
+            .extend_ttl(&storage_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(challenge_ends)
+    }
+
+    /// Finish a game from its channel claim once the challenge window has
+    /// passed, unless play has since moved on-chain. Needs no auth. Returns
+    /// the outcome.
+    pub fn close_channel(env: Env, session_id: u32) -> Result<u32, CangkulanError> {
+        let claim = Self::get_channel_claim(env.clone(), session_id).ok_or(CangkulanError::NoChannelClaim)?;
+        if env.ledger().sequence() <= claim.challenge_ends {
+            return Err(CangkulanError::ChallengeWindowOpen);
+        }
+
+        let mut game = Self::read_game(&env, session_id)?;
+        Self::require_channel_current(&env, session_id, &game)?;
+        let outcome = match claim.state.outcome {
+            OUTCOME_UNRESOLVED => Self::channel_tally_outcome(&game, &claim.state.tricks),
+            outcome => outcome,
+        };
+        Self::finalize_game(&env, session_id, &mut game, outcome)?;
+        Self::write_game(&env, session_id, &mut game);
+        env.storage().temporary().remove(&StorageKey::ChannelClaim(session_id));
+
+        EvChannelClosed { session_id, move_counter: claim.state.move_counter, outcome }.publish(&env);
+        Ok(outcome)
+    }
+
+    /// Message each seat signs for `state`:
+    /// `keccak256("cangkulan-channel" ∥ xdr(contract) ∥ xdr(state))`.
+    pub fn channel_digest(env: Env, state: ChannelState) -> BytesN<32> {
+        let mut message = Bytes::from_slice(&env, b"cangkulan-channel");
+        message.append(&env.current_contract_address().to_xdr(&env));
+        message.append(&state.to_xdr(&env));
+        env.crypto().keccak256(&message).into()
+    }
+
+    /// Channel keys registered so far, by seat.
+    pub fn get_channel_keys(env: Env, session_id: u32) -> Map<u32, BytesN<32>> {
+        env.storage()
+            .temporary()
+            .get(&StorageKey::ChannelKeys(session_id))
+            .unwrap_or(Map::new(&env))
+    }
+
+    pub fn get_channel_claim(env: Env, session_id: u32) -> Option<ChannelClaim> {
+        env.storage().temporary().get(&StorageKey::ChannelClaim(session_id))
+    }
+}
+
+impl CangkulanContract {
+    /// Fail if a move has been made on-chain since the channel opened.
+    fn require_channel_current(env: &Env, session_id: u32, game: &CangkulanGame) -> Result<(), CangkulanError> {
+        let base: Option<u32> = env.storage().temporary().get(&StorageKey::ChannelBase(session_id));
+        match base {
+            Some(base) if base == game.action_nonce => Ok(()),
+            _ => Err(CangkulanError::ChannelSuperseded),
+        }
+    }
+
+    /// The seat with the most tricks wins, or in a team game the team with
+    /// the most between its partners; a tie for the most is a draw.
+    fn channel_tally_outcome(game: &CangkulanGame, tricks: &Vec<u32>) -> u32 {
+        let mut tricks = tricks.clone();
+        if game.config.teams {
+            let team = |slot: u32| tricks.get_unchecked(slot - 1) + tricks.get_unchecked(slot + 1);
+            tricks = Vec::from_array(tricks.env(), [team(1), team(2)]);
+        }
+        let best = tricks.iter().max().unwrap_or(0);
+        let mut leaders = tricks.iter().enumerate().filter(|(_, won)| *won == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((i, _)), None) => Self::win_outcome(i as u32 + 1),
+            _ => OUTCOME_DRAW,
+        }
+    }
+}
@@ -2,40 +2,76 @@
 
 //! # Cangkulan Lite
 //!
-//! A two-player Indonesian card game using a 36-card deck (4 suits × values 2-10).
+//! An Indonesian card game for 2–4 players using a 36-card deck (4 suits × values 2-10).
 //! The draw pile acts as "lead" — a flipped card determines the suit for each trick.
 //!
 //! ## Game flow
-//! 1. Both players commit a random seed hash (ZK commitment).
-//! 2. Both players reveal their seed (verified by on-chain ZK verifier).
+//! 1. Every player commits a random seed hash (ZK commitment).
+//! 2. Every player reveals their seed (verified by on-chain ZK verifier).
 //! 3. Seeds are combined to derive a deterministic PRNG seed for the deck shuffle.
-//! 4. 5 cards dealt to each player, 26 go to the draw pile.
+//! 4. 5 cards dealt to each player, the rest (26 with two players) go to the draw pile.
 //! 5. A card is flipped from the draw pile — its suit is the trick suit.
 //! 6. Each trick uses a **commit-reveal** protocol:
-//!    a. Both players commit `keccak256(action || salt)` where action is either
-//!       `card_id` (0-35) or `CANNOT_FOLLOW_SENTINEL` (0xFFFFFFFF).
-//!    b. Once both have committed, both reveal their action + salt.
-//!    c. The contract verifies the reveal matches the commit, validates the
-//!       action (card in hand, correct suit, etc.), and resolves the trick.
+//!    - Every player commits `keccak256(action || salt)` where action is either
+//!      `card_id` (0-35) or `CANNOT_FOLLOW_SENTINEL` (0xFFFFFFFF).
+//!    - Once all have committed, all reveal their action + salt.
+//!    - The contract verifies the reveal matches the commit, validates the
+//!      action (card in hand, correct suit, etc.), and resolves the trick.
+//!
 //!    This prevents opponents from seeing each other's card choices before
-//!    both have committed.
-//! 7. Winner: first to empty their hand, or fewer cards when the pile runs out.
+//!    all have committed.
+//! 7. The highest card of the trick suit wins the trick (the earliest seat on
+//!    ties); every player who could not follow draws a penalty card.
+//! 8. Winner: first to empty their hand, or fewer cards when the pile runs out.
 //!    Tiebreaker: most tricks won, then lowest total card value, then draw.
 //!
+//! Hand size, deck size (36 or 52 cards), timeouts, tie-break order, penalty
+//! draws (a fixed count, or the traditional draw until following) and
+//! whether plays must be ZK are house rules (`GameConfig`), fixed per game
+//! at `start_game_with_config`; the numbers above are the standard rules.
+//!
+//! ## Seats
+//! `start_game` seats two players; `start_multiplayer_game` seats two to
+//! four. Seat order is play order: seat 1 wins tied tricks. Only two-seat
+//! games are reported to the Game Hub, whose interface has two players.
+//!
 //! ## Card encoding
 //! `card_id = suit * 9 + (value - 2)` where suit ∈ [0,3] and value ∈ [2,10].
 //! Decode: `suit = id / 9`, `value = id % 9 + 2`.
 //!
+//! A game started with `cards_per_suit = 13` plays the standard 52-card deck
+//! instead: `card_id = suit * 13 + (value - 2)`, value ∈ [2,14] with the ace
+//! high. Card ids in the Mode 7 and 8 public inputs use the game's encoding,
+//! so ZK plays need the verifier's `DeckParams` to match
+//! (`DeckParams::standard()` for 52 cards).
+//!
 //! ## ZK seed commitment
 //! Uses an on-chain ZK verifier contract for provably fair shuffle.
 //! `commit_hash = keccak256(seed || player_address)`, verified on-chain.
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype, vec,
-    Address, Bytes, BytesN, Env, IntoVal, Vec,
+    Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
 };
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
 
+use cangkulan_common::{deal_seed_n, shuffled_deck_n, spec, DeckParams};
+pub use cangkulan_common::EntropyPolicy;
+
+mod lobby;
+pub use lobby::{Challenge, MAX_CHALLENGES_PER_CREATOR, MAX_CHALLENGE_LEDGERS, MAX_OPEN_CHALLENGES};
+
+mod channel;
+pub use channel::{ChannelClaim, ChannelState, CHANNEL_CHALLENGE_LEDGERS};
+
+mod match_play;
+pub use match_play::{MatchState, MAX_MATCH_TARGET};
+
+mod bot;
+pub use bot::{BotReveal, BotTurn, CangkulanBot, CangkulanBotClient};
+
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Contract Events
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -43,14 +79,14 @@ use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
 #[contractevent]
 pub struct EvGameStarted {
     pub session_id: u32,
-    pub player1: Address,
-    pub player2: Address,
+    pub players: Vec<Address>,
 }
 
 #[contractevent]
 pub struct EvSeedCommitted {
     pub session_id: u32,
     pub player: Address,
+    pub proof_mode: u32,
 }
 
 #[contractevent]
@@ -79,6 +115,26 @@ pub struct EvZkCardPlayVerified {
     pub valid_set_size: u32,
 }
 
+/// Emitted when `audit_reveal` rechecks a player's ZK card plays.
+#[contractevent]
+pub struct EvRevealAudited {
+    pub session_id: u32,
+    pub player: Address,
+    pub plays: u32,
+    pub valid: bool,
+}
+
+/// Emitted when `dispute_shuffle` proves a dealt card differs from the
+/// verified shuffle.
+#[contractevent]
+pub struct EvShuffleDisputed {
+    pub session_id: u32,
+    pub player: Address,
+    pub index: u32,
+    pub dealt: u32,
+    pub shuffled: u32,
+}
+
 /// Emitted when a ZK cangkul hand proof validates a cannot-follow-suit claim.
 #[contractevent]
 pub struct EvZkCangkulVerified {
@@ -97,12 +153,29 @@ pub struct EvPlayRevealed {
     pub is_cangkul: bool,   // true if player declared cannot follow
 }
 
+/// `winner` is a slot, 0 for a waste trick; `cards` is one entry per
+/// seat, `None` for a player who could not follow.
 #[contractevent]
 pub struct EvTrickResolved {
     pub session_id: u32,
     pub winner: u32,
-    pub card1: Option<u32>,
-    pub card2: Option<u32>,
+    pub cards: Vec<Option<u32>>,
+}
+
+/// One card drawn under `GameConfig::draw_until_follow`; `matched` on the
+/// trick-suit card that ends the draw.
+#[contractevent]
+pub struct EvCangkulDraw {
+    pub session_id: u32,
+    pub player: u32,
+    pub matched: bool,
+}
+
+#[contractevent]
+pub struct EvHandCapReached {
+    pub session_id: u32,
+    pub player: u32,
+    pub overflow_rule: u32,
 }
 
 #[contractevent]
@@ -111,6 +184,200 @@ pub struct EvGameEnded {
     pub outcome: u32,
 }
 
+/// `player` forfeited; `penalized` when it came after the deal and is
+/// reported to the Leaderboard as a rage-quit.
+#[contractevent]
+pub struct EvGameForfeited {
+    pub session_id: u32,
+    pub player: Address,
+    pub penalized: bool,
+}
+
+#[contractevent]
+pub struct EvSessionsPruned {
+    pub session_ids: Vec<u32>,
+}
+
+#[contractevent]
+pub struct EvForcedWin {
+    pub session_id: u32,
+    pub outcome: u32,
+    pub tricks_left: u32,
+}
+
+#[contractevent]
+pub struct EvWinningsClaimable {
+    pub session_id: u32,
+    pub expires_ledger: u32,
+}
+
+#[contractevent]
+pub struct EvWinningsClaimed {
+    pub session_id: u32,
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EvWinningsExpired {
+    pub session_id: u32,
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EvRematchRequested {
+    pub session_id: u32,
+    pub player: Address,
+    pub rematch_id: u32,
+}
+
+#[contractevent]
+pub struct EvRematchStarted {
+    pub session_id: u32,
+    pub rematch_id: u32,
+}
+
+#[contractevent]
+pub struct EvRematchCancelled {
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct EvRakeCollected {
+    pub session_id: u32,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EvFeeSet {
+    pub fee_bps: u32,
+}
+
+#[contractevent]
+pub struct EvTreasuryWithdrawn {
+    pub treasury: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EvGuestSponsored {
+    pub sponsor: Address,
+    pub guest: Address,
+    pub max_games: u32,
+}
+
+#[contractevent]
+pub struct EvGuestRevoked {
+    pub sponsor: Address,
+    pub guest: Address,
+}
+
+#[contractevent]
+pub struct EvDelegateAuthorized {
+    pub session_id: u32,
+    pub player: Address,
+    pub delegate: Address,
+}
+
+#[contractevent]
+pub struct EvDelegateRevoked {
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct EvGuestGameAttributed {
+    pub session_id: u32,
+    pub guest: Address,
+    pub sponsor: Address,
+    pub games_left: u32,
+}
+
+#[contractevent]
+pub struct EvNotificationPrefsSet {
+    pub player: Address,
+    pub deadline_warnings: bool,
+    pub mute_emotes: bool,
+    pub rematch_offers: bool,
+}
+
+#[contractevent]
+pub struct EvDeadlineSet {
+    pub session_id: u32,
+    pub deadline_ledger: u32,
+    pub waiting_on: Vec<Address>,
+}
+
+#[contractevent]
+pub struct EvTimeoutResolved {
+    pub session_id: u32,
+    pub outcome: u32,
+}
+
+#[contractevent]
+pub struct EvDeadlineWarning {
+    pub session_id: u32,
+    pub player: Address,
+    pub deadline_ledger: u32,
+}
+
+#[contractevent]
+pub struct EvEmote {
+    pub session_id: u32,
+    pub from: Address,
+    pub to: Address,
+    pub emote: u32,
+}
+
+#[contractevent]
+pub struct EvVerifierOverrideScheduled {
+    pub executable_ledger: u32,
+    pub duration_ledgers: u32,
+}
+
+#[contractevent]
+pub struct EvVerifierOverrideActivated {
+    pub expires_ledger: u32,
+}
+
+#[contractevent]
+pub struct EvVerifierOverrideCancelled {
+    pub was_active: bool,
+}
+
+#[contractevent]
+pub struct EvPauseSet {
+    pub paused: bool,
+}
+
+#[contractevent]
+pub struct EvGameCancelled {
+    pub session_id: u32,
+    pub reason: Symbol,
+}
+
+/// A proof check skipped because the verifier override is active.
+#[contractevent]
+pub struct EvVerifierBypassed {
+    pub session_id: u32,
+    pub player: Address,
+    pub expires_ledger: u32,
+}
+
+/// A Noir proof the verifier rejected. The call then fails with
+/// `UltraHonkVerificationFailed`, so this only shows up in simulation and
+/// diagnostics; the verifier's `EvVerifyFailed` carries the reason.
+#[contractevent]
+pub struct EvNoirProofRejected {
+    pub session_id: u32,
+    pub player: Address,
+    pub proof_len: u32,
+}
+
 #[contractevent]
 pub struct EvHubStartReported {
     pub session_id: u32,
@@ -124,6 +391,15 @@ pub struct EvHubEndReported {
     pub player1_won: bool,
 }
 
+/// A finished two-seat game reported to the Leaderboard. `recorded` is
+/// false if the Leaderboard refused it (e.g. this game is not authorized).
+#[contractevent]
+pub struct EvLeaderboardReported {
+    pub session_id: u32,
+    pub leaderboard: Address,
+    pub recorded: bool,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 //  External trait interfaces
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -143,6 +419,19 @@ pub trait CangkulanGameHub {
     fn end_game(env: Env, session_id: u32, player1_won: bool);
 }
 
+/// Leaderboard ratings. `outcome` is 1 for a player 1 win, 2 for a player 2
+/// win and 3 for a draw, with `LEADERBOARD_FORFEIT` set when the loser
+/// forfeited. The game reports as `caller` and must be authorized with the
+/// Leaderboard's `authorize_game`.
+#[contractclient(name = "LeaderboardClient")]
+pub trait CangkulanLeaderboard {
+    fn record_match(env: Env, caller: Address, player1: Address, player2: Address, outcome: u32);
+}
+
+/// Flag the Leaderboard reads on a `record_match` outcome as "the loser
+/// forfeited" (its `OUTCOME_FORFEIT`).
+const LEADERBOARD_FORFEIT: u32 = 4;
+
 /// ZK verifier for seed commitment.
 ///
 /// Public inputs layout for seed verification:
@@ -152,19 +441,23 @@ pub trait CangkulanGameHub {
 ///   [68..)    player         : variable-length address string bytes
 ///
 /// Proof layout: [0..32) salt : 32 bytes
+///
+/// Calls go through `verify_from` with this contract as the caller, so the
+/// game keeps working when the verifier admin enables its caller allowlist.
+/// Noir proofs go the same way: the verifier forwards them to the UltraHonk
+/// verifier registered with it (Mode 22).
 #[contractclient(name = "ZkVerifierClient")]
 pub trait ZkVerifier {
-    fn verify(env: Env, public_inputs: Bytes, proof: Bytes) -> bool;
-}
-
-/// UltraHonk verifier for Noir ZK proofs (UltraKeccakHonk proving system).
-///
-/// This verifier contract is deployed separately with a verification key
-/// baked in at deploy time. It verifies Noir circuit proofs on-chain.
-/// Used as an optional alternative to the Pedersen/NIZK verifier.
-#[contractclient(name = "UltraHonkClient")]
-pub trait UltraHonkVerifier {
-    fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes);
+    fn verify_from(env: Env, caller: Address, public_inputs: Bytes, proof: Bytes) -> bool;
+    fn consume_verification(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        mode: u32,
+        commitment: BytesN<32>,
+    ) -> bool;
+    fn get_deck_params(env: Env) -> DeckParams;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -204,13 +497,60 @@ pub enum CangkulanError {
     PlayCommitMissing = 27,
     PlayRevealMismatch = 28,
     InvalidCardId = 29,
-    UltraHonkVerifierNotSet = 30,
+    UltraHonkVerifierNotSet = 30, // retired: the ZK verifier owns the UltraHonk route
     UltraHonkVerificationFailed = 31,
     ZkPlayProofInvalid = 32,
     ZkPlaySetEmpty = 33,
     ZkPlayOpeningMismatch = 34,
     ZkCangkulProofInvalid = 35,
     TickTooSoon = 38,
+    NothingToClaim = 39,
+    ClaimExpired = 40,
+    ClaimNotExpired = 41,
+    InvalidEmote = 42,
+    InvalidGuestSponsorship = 43,
+    GuestNotSponsored = 44,
+    InvalidHandCap = 45,
+    InvalidSeedProofMode = 46,
+    SeedProofModeMismatch = 47,
+    GameNotFinished = 48,
+    InvalidEntropyPolicy = 49,
+    OutcomeNotForced = 50,
+    InvalidOverrideDuration = 51,
+    OverrideNotScheduled = 52,
+    TimelockNotElapsed = 53,
+    InvalidPlayerCount = 54,
+    InvalidStakeEscrow = 55,
+    RematchNotRequested = 56,
+    RematchAlreadyAgreed = 57,
+    ChallengeNotFound = 58,
+    ChallengeExpired = 59,
+    InvalidChallengeExpiry = 60,
+    LobbyFull = 61,
+    InvalidGameConfig = 62,
+    ZkPlayRequired = 63,
+    DeckMismatch = 64,
+    RevealDeadlinePassed = 65,
+    UnroutableProofMode = 66,
+    ContractPaused = 67,
+    UnsupportedGameSchema = 68,
+    InvalidDelegate = 69,
+    ChannelNotOpen = 70,
+    InvalidChannelState = 71,
+    StaleChannelState = 72,
+    ChallengeWindowOpen = 73,
+    NoChannelClaim = 74,
+    TimeBankExhausted = 75,
+    MatchNotFound = 76,
+    MatchOver = 77,
+    InvalidMatchTarget = 78,
+    AuditBlindingCount = 79,
+    ShuffleDisputeRejected = 80,
+    MalformedReplay = 81,
+    ChannelKeysLocked = 82,
+    ChannelSuperseded = 83,
+    ReservedSessionId = 84,
+    TooManyChallenges = 85,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -227,7 +567,9 @@ pub const STATE_FINISHED: LifecycleState = 4;
 // Trick sub-states (who the contract is waiting for)
 //
 // Commit-Reveal protocol for each trick:
-//   COMMIT_WAIT_BOTH → COMMIT_WAIT_P1/P2 → REVEAL_WAIT_BOTH → REVEAL_WAIT_P1/P2 → NONE (resolved)
+//   COMMIT_WAIT_BOTH → COMMIT_WAIT_Pn → REVEAL_WAIT_BOTH → REVEAL_WAIT_Pn → NONE (resolved)
+//
+// `_BOTH` waits on two or more players; `_Pn` on seat n alone.
 pub(crate) type TrickState = u32;
 
 pub const TRICK_NONE: TrickState = 0;
@@ -236,13 +578,22 @@ pub const TRICK_NONE: TrickState = 0;
 pub const TRICK_COMMIT_WAIT_BOTH: TrickState = 10;
 pub const TRICK_COMMIT_WAIT_P1: TrickState = 11;
 pub const TRICK_COMMIT_WAIT_P2: TrickState = 12;
+pub const TRICK_COMMIT_WAIT_P3: TrickState = 13;
+pub const TRICK_COMMIT_WAIT_P4: TrickState = 14;
 pub const TRICK_REVEAL_WAIT_BOTH: TrickState = 20;
 pub const TRICK_REVEAL_WAIT_P1: TrickState = 21;
 pub const TRICK_REVEAL_WAIT_P2: TrickState = 22;
+pub const TRICK_REVEAL_WAIT_P3: TrickState = 23;
+pub const TRICK_REVEAL_WAIT_P4: TrickState = 24;
 
 /// Sentinel card_id used in commit_play to signal "cannot follow suit".
 pub const CANNOT_FOLLOW_SENTINEL: u32 = 0xFFFF_FFFF;
 
+/// Layout version of `CangkulanGame`. Bump it with every change to the
+/// struct and add the step filling the new fields to `migrate_layout`, so
+/// sessions stored by the previous WASM still load after `upgrade`.
+pub const GAME_SCHEMA_VERSION: u32 = 5;
+
 // Outcome codes
 pub(crate) type Outcome = u32;
 
@@ -250,57 +601,201 @@ pub const OUTCOME_UNRESOLVED: Outcome = 0;
 pub const OUTCOME_PLAYER1_WIN: Outcome = 1;
 pub const OUTCOME_PLAYER2_WIN: Outcome = 2;
 pub const OUTCOME_DRAW: Outcome = 3;
+pub const OUTCOME_PLAYER3_WIN: Outcome = 4;
+pub const OUTCOME_PLAYER4_WIN: Outcome = 5;
+/// Ended by the admin with `cancel_game`; stakes go back like a draw.
+pub const OUTCOME_CANCELLED: Outcome = 6;
+
+// Hand-size overflow rules (what happens when a penalty card would push a
+// hand above `max_hand_size`)
+pub(crate) type OverflowRule = u32;
+
+pub const OVERFLOW_SKIP_PENALTY: OverflowRule = 0; // card stays on the draw pile
+pub const OVERFLOW_INSTANT_LOSS: OverflowRule = 1; // penalized player loses
+
+// Seed proof modes declared at commit_seed and enforced at reveal
+pub(crate) type SeedProofMode = u32;
+
+pub const SEED_PROOF_NIZK: SeedProofMode = spec::MODE_NIZK_SEED;         // 64-byte proof
+pub const SEED_PROOF_PEDERSEN: SeedProofMode = spec::MODE_PEDERSEN_SEED; // 224-byte proof
+pub const SEED_PROOF_NOIR: SeedProofMode = 100; // UltraHonk, routed by the verifier as Mode 22
+
+/// Pedersen seed proof as submitted to `reveal_seed`: C(96) || Mode 4 proof.
+const PEDERSEN_SEED_PROOF_LEN: u32 = 96 + spec::PEDERSEN_PROOF_LEN;
+
+// Seats per game
+pub const MIN_PLAYERS: u32 = 2;
+pub const MAX_PLAYERS: u32 = 4;
 
-// Player slots
-const PLAYER_1: u32 = 1;
-const PLAYER_2: u32 = 2;
+// Tie-break rules (`GameConfig::tie_break`), applied after empty hands
+pub const TIE_BREAK_TRICKS: u32 = 0; // most tricks, then fewest cards, then lowest value
+pub const TIE_BREAK_CARDS: u32 = 1;  // fewest cards, then most tricks, then lowest value
+
+// Suit sizes (`GameConfig::cards_per_suit`)
+pub const CARDS_PER_SUIT_36: u32 = 9;  // values 2-10, the Cangkulan deck
+pub const CARDS_PER_SUIT_52: u32 = 13; // values 2-14 (ace high), the standard deck
+
+// Rake on escrowed pots, in basis points
+pub const MAX_RAKE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Set on session ids the contract allocates for games it opens itself
+/// (lobby challenges, rematches and match deals); callers of `start_game`
+/// cannot pick them.
+pub const CONTRACT_SESSION_BIT: u32 = 1 << 31;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Game state & storage keys
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// One player's seat. Slot `n` (1-based, as in trick states, outcome codes
+/// and events) is `CangkulanGame::players[n - 1]`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CangkulanGame {
-    pub player1: Address,
-    pub player2: Address,
-    pub player1_points: i128,
-    pub player2_points: i128,
+pub struct PlayerSlot {
+    pub player: Address,
+    pub points: i128,
     // Seed commitment phase (NIZK ZK proof)
-    pub seed_commit1: Option<BytesN<32>>,
-    pub seed_commit2: Option<BytesN<32>>,
-    pub seed_hash1: Option<BytesN<32>>,
-    pub seed_hash2: Option<BytesN<32>>,
-    pub seed_revealed1: bool,
-    pub seed_revealed2: bool,
-    // Hands and draw pile (card IDs 0-35)
-    pub hand1: Vec<u32>,
-    pub hand2: Vec<u32>,
+    pub seed_commit: Option<BytesN<32>>,
+    pub seed_hash: Option<BytesN<32>>,
+    pub seed_revealed: bool,
+    // Proof mode declared at commit (SEED_PROOF_*)
+    pub seed_mode: Option<u32>,
+    // Hand (card IDs 0-35)
+    pub hand: Vec<u32>,
+    // Commit-reveal for the current trick
+    pub play_commit: Option<BytesN<32>>,
+    // ZK card play flag (true = Pedersen commit, false = keccak256 commit)
+    pub zk_play: bool,
+    pub play_revealed: bool,
+    // Card played this trick; None after a reveal means cannot follow
+    pub trick_card: Option<u32>,
+    // Scoring
+    pub tricks_won: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CangkulanGame {
+    /// Layout version, `GAME_SCHEMA_VERSION` once read
+    pub schema_version: u32,
+    /// 2–4 seats in play order
+    pub players: Vec<PlayerSlot>,
+    // Draw pile (card IDs 0-35)
     pub draw_pile: Vec<u32>,
     // Current trick
     pub trick_state: u32,
     pub trick_suit: Option<u32>,
-    pub trick_card1: Option<u32>,
-    pub trick_card2: Option<u32>,
     pub flipped_card: Option<u32>,
-    // Commit-reveal for card plays
-    pub play_commit1: Option<BytesN<32>>,
-    pub play_commit2: Option<BytesN<32>>,
-    // ZK card play flags (true = Pedersen commit, false = keccak256 commit)
-    pub zk_play1: bool,
-    pub zk_play2: bool,
-    // Scoring
-    pub tricks_won1: u32,
-    pub tricks_won2: u32,
     // State machine
     pub lifecycle_state: u32,
     pub outcome: u32,
+    /// Seat that forfeited, 0 if none
+    pub forfeited_by: u32,
+    /// Practice game: seat 2 is a `CangkulanBot` the game plays for
+    pub vs_bot: bool,
     // Timeout
     pub action_nonce: u32,
     pub deadline_nonce: Option<u32>,
     pub deadline_ledger: Option<u32>,
     /// Ledger sequence of the last tick_timeout call (rate limiting)
     pub last_tick_ledger: u32,
+    /// Ledger sequence of the last write; the entry expires
+    /// `GAME_TTL_LEDGERS` after it
+    pub updated_ledger: u32,
+    /// Blitz time bank left per seat, in ledgers; empty without a time bank
+    pub time_banks: Vec<u32>,
+    // Hand-size cap (snapshot of `HandCapConfig` at start_game)
+    pub max_hand_size: u32,
+    pub overflow_rule: u32,
+    /// House rules fixed at start
+    pub config: GameConfig,
+    /// ZK verifier pinned at start; `set_verifier` affects new games only
+    pub verifier: Address,
+    /// Per-mode verifiers pinned at start, overriding `verifier`
+    pub mode_verifiers: Map<u32, Address>,
+    // Token escrow (snapshot of `StakeEscrow` at start_game); no token
+    // when stakes are only points reported to the hub
+    pub stake_token: Option<Address>,
+    pub rake_bps: u32,
+    pub treasury: Option<Address>,
+    /// Running keccak256 chain over the deal seed and every resolved trick
+    pub transcript: BytesN<32>,
+    /// The last `TRICK_LOG_LEN` resolved tricks, oldest first
+    pub trick_log: Vec<TrickRecord>,
+    /// Transcript just before the oldest trick in `trick_log`, so chaining
+    /// the logged tricks onto it reproduces `transcript`
+    pub trick_log_base: BytesN<32>,
+    /// Tricks dropped from the front of `trick_log`
+    pub tricks_pruned: u32,
+}
+
+impl CangkulanGame {
+    /// Seat of `slot` (1-based).
+    pub fn seat(&self, slot: u32) -> PlayerSlot {
+        self.players.get_unchecked(slot - 1)
+    }
+
+    pub fn set_seat(&mut self, slot: u32, seat: PlayerSlot) {
+        self.players.set(slot - 1, seat);
+    }
+}
+
+/// A seat as `get_game` shows it to anyone: counts and progress, no cards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicSeat {
+    pub player: Address,
+    pub points: i128,
+    pub hand_size: u32,
+    pub tricks_won: u32,
+    pub seed_committed: bool,
+    pub seed_revealed: bool,
+    pub play_committed: bool,
+    pub play_revealed: bool,
+}
+
+/// The intentionally public part of a game, returned by `get_game`. It is
+/// built field by field rather than redacted from `CangkulanGame`, so a
+/// field added to the game stays private until it is added here. The
+/// flipped card is face up on the table; hands, the pile and trick cards
+/// only appear as counts or flags.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicGameView {
+    pub players: Vec<PublicSeat>,
+    pub config: GameConfig,
+    pub lifecycle_state: u32,
+    pub trick_state: u32,
+    pub trick_suit: Option<u32>,
+    pub flipped_card: Option<u32>,
+    pub draw_pile_size: u32,
+    pub outcome: u32,
+    pub forfeited_by: u32,
+    pub action_nonce: u32,
+    pub deadline_ledger: Option<u32>,
+    pub time_banks: Vec<u32>,
+    pub stake_token: Option<Address>,
+    pub vs_bot: bool,
+}
+
+impl PublicGameView {
+    /// Seat of `slot` (1-based).
+    pub fn seat(&self, slot: u32) -> PublicSeat {
+        self.players.get_unchecked(slot - 1)
+    }
+}
+
+/// One resolved trick, as chained into the game transcript. `cards` has
+/// one entry per seat, `CANNOT_FOLLOW_SENTINEL` if absent; `winner` is 0
+/// for a waste trick. `ledger` is not part of the transcript.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrickRecord {
+    pub flipped: u32,
+    pub cards: Vec<u32>,
+    pub winner: u32,
+    pub ledger: u32, // sequence the trick resolved at
 }
 
 /// Compact summary of a finished game, stored persistently per player.
@@ -308,60 +803,410 @@ pub struct CangkulanGame {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameSummary {
     pub session_id: u32,
-    pub opponent: Address,
+    pub opponent: Address,   // next seat in play order
     pub outcome: u32,        // 1=win, 2=loss, 3=draw (from this player's perspective)
     pub tricks_won: u32,
-    pub tricks_lost: u32,
+    pub tricks_lost: u32,    // tricks won by everyone else
     pub ledger: u32,         // ledger sequence when game ended
 }
 
+/// A page of a player's history. `next_cursor` is where the next call
+/// resumes, `None` once the whole history has been read.
 #[contracttype]
-#[derive(Clone)]
-enum StorageKey {
-    Game(u32),
-    Admin,
-    GameHubAddress,
-    VerifierAddress,
-    UltraHonkVerifierAddress,
-    PlayerHistory(Address),
-    /// Flag: Noir proof verified for (session_id, player_slot).
-    /// Stored in temp storage; consumed by `reveal_seed`.
-    NoirSeedVerified(u32, u32),
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryPage {
+    pub entries: Vec<GameSummary>,
+    pub next_cursor: Option<u32>,
 }
 
-// ═══════════════════════════════════════════════════════════════════════════════
-//  Constants
-// ═══════════════════════════════════════════════════════════════════════════════
+/// One player's share of a finished session's stakes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payout {
+    pub player: Address,
+    pub amount: i128,
+    pub claimed: bool, // paid out by `claim_winnings`
+}
 
-const DECK_SIZE: u32 = 36;
-const CARDS_PER_SUIT: u32 = 9;
-const HAND_SIZE: u32 = 5;
-const TIMEOUT_ACTIONS: u32 = 2;
+/// Stakes awaiting `claim_winnings`, recorded by `finalize_game`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingClaim {
+    pub payouts: Vec<Payout>,
+    pub expires_ledger: u32,  // claims after this ledger are rejected
+    pub token: Option<Address>,    // escrowed token the payouts are made in
+    pub rake: i128,                // taken from a won pot for `treasury`
+    pub treasury: Option<Address>, // receives the rake and expired claims
+}
 
-// Ledger rate is approximately 5 seconds per ledger on Stellar
-const LEDGER_RATE_SECS: u32 = 5;
+/// A pending rematch of a finished session. Seats join `agreed` through
+/// `request_rematch` / `accept_rematch`; the rematch starts as soon as
+/// every seat has agreed. The offer carries the seats, stakes and terms of
+/// the finished game, so it can be accepted or cancelled after that game
+/// has expired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RematchOffer {
+    pub rematch_id: u32,       // session id of the new game
+    pub agreed: Vec<Address>,  // seats that agreed, stakes locked if escrowed
+    pub players: Vec<Address>, // seats of the finished game, in order
+    pub points: Vec<i128>,     // each seat's stake, locked on agreement
+    pub config: GameConfig,
+    // Escrow terms of the finished game (see `StakeEscrow`); no token when
+    // stakes are only points reported to the hub
+    pub stake_token: Option<Address>,
+    pub rake_bps: u32,
+    pub treasury: Option<Address>,
+}
 
-// Timeout for player actions: ~10 minutes
-const TIMEOUT_MINUTES: u32 = 10;
-const TIMEOUT_LEDGERS: u32 = TIMEOUT_MINUTES * 60 / LEDGER_RATE_SECS; // ~120 ledgers
+/// Token escrow for stakes. While set, `start_game` transfers each stake
+/// into the contract in `token`, and `claim_winnings` pays the pot out
+/// less `rake_bps` of a won pot, which is credited to `treasury` for
+/// `withdraw_treasury`. New games
+/// snapshot this at `start_game`, so changing it never affects games in
+/// progress.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeEscrow {
+    pub token: Address,
+    pub rake_bps: u32,     // at most `MAX_RAKE_BPS`; draws are never raked
+    pub treasury: Address, // credited with the rake and expired claims
+}
 
-/// Minimum ledger gap between tick_timeout calls to prevent nonce-pump exploit.
-/// Equal to TIMEOUT_LEDGERS / TIMEOUT_ACTIONS so that the nonce path cannot
-/// resolve faster than the ledger deadline.
-const MIN_TICK_GAP_LEDGERS: u32 = TIMEOUT_LEDGERS / TIMEOUT_ACTIONS; // ~60 ledgers = ~5 min
+/// House rules of one game, fixed at start. `start_game` and
+/// `start_multiplayer_game` use `GameConfig::standard()`; other rules are
+/// chosen with `start_game_with_config`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub hand_size: u32,       // cards dealt to each seat
+    pub cards_per_suit: u32,  // CARDS_PER_SUIT_36 or CARDS_PER_SUIT_52
+    pub deck_size: u32,       // 24 up to 4 * cards_per_suit: the top deck_size / 4 values of each suit
+    pub timeout_ledgers: u32, // deadline for each action
+    pub tie_break: u32,       // TIE_BREAK_*
+    pub penalty_draw: u32,    // cards drawn by each player who cannot follow
+    pub zk_required: bool,    // plays only through commit_play_zk / commit_cangkul_zk
+    pub draw_until_follow: bool, // non-followers draw until a trick-suit card, not penalty_draw
+    pub strict_reveal: bool,     // reveals share one deadline and none is accepted after it
+    pub time_bank_ledgers: u32,  // blitz: per-player reserve past each move's deadline; 0 = off
+    pub teams: bool,             // 2v2: seats 1 and 3 partner against seats 2 and 4
+}
 
-// TTL expressed in human-readable time units (30 days)
-const TTL_SECONDS: u32 = 30 * 24 * 60 * 60;      // 2,592,000 seconds
+impl GameConfig {
+    /// The rules every game used before configs: 5 cards from 36, ~10
+    /// minute timeouts, one penalty card.
+    pub fn standard() -> Self {
+        GameConfig {
+            hand_size: HAND_SIZE,
+            cards_per_suit: CARDS_PER_SUIT_36,
+            deck_size: DECK_SIZE,
+            timeout_ledgers: TIMEOUT_LEDGERS,
+            tie_break: TIE_BREAK_TRICKS,
+            penalty_draw: 1,
+            zk_required: false,
+            draw_until_follow: false,
+            strict_reveal: false,
+            time_bank_ledgers: 0,
+            teams: false,
+        }
+    }
 
-/// TTL for game storage in ledgers: 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = TTL_SECONDS / LEDGER_RATE_SECS;
+    /// Standard rules on a blitz clock: ~30 seconds a move, a ~3 minute
+    /// time bank per player, and a loss once both run out.
+    pub fn blitz() -> Self {
+        GameConfig {
+            timeout_ledgers: BLITZ_MOVE_LEDGERS,
+            time_bank_ledgers: BLITZ_TIME_BANK_LEDGERS,
+            ..Self::standard()
+        }
+    }
+
+    /// Standard rules for four seats in two partnerships: seats 1 and 3
+    /// against seats 2 and 4, scored on the partners' combined tricks.
+    pub fn teams() -> Self {
+        GameConfig { teams: true, ..Self::standard() }
+    }
+
+    /// Whether these rules can deal `seats` hands within `max_hand_size`
+    /// and still flip a card.
+    pub fn is_valid(&self, seats: u32, max_hand_size: u32) -> bool {
+        (self.cards_per_suit == CARDS_PER_SUIT_36 || self.cards_per_suit == CARDS_PER_SUIT_52)
+            && self.deck_size.is_multiple_of(4)
+            && (4 * (CARDS_PER_SUIT_36 - 3)..=4 * self.cards_per_suit).contains(&self.deck_size)
+            && self.hand_size >= 1
+            && self.hand_size <= max_hand_size
+            && seats * self.hand_size < self.deck_size
+            && self.timeout_ledgers <= MAX_TIMEOUT_LEDGERS
+            && match self.time_bank_ledgers {
+                0 => self.timeout_ledgers >= MIN_TIMEOUT_LEDGERS,
+                bank => self.timeout_ledgers >= MIN_BLITZ_MOVE_LEDGERS && bank <= MAX_TIME_BANK_LEDGERS,
+            }
+            && (self.tie_break == TIE_BREAK_TRICKS || self.tie_break == TIE_BREAK_CARDS)
+            && (1..=MAX_PENALTY_DRAW).contains(&self.penalty_draw)
+            && (!self.teams || seats == 4)
+    }
+}
+
+/// Hand-size cap applied to penalty draws. New games snapshot this at
+/// `start_game`, so changing it never affects games in progress.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandCapConfig {
+    pub max_hand_size: u32,
+    pub overflow_rule: u32,
+}
+
+/// An emergency verifier override waiting out its timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingVerifierOverride {
+    pub executable_ledger: u32, // `execute_after_timelock` allowed from here
+    pub duration_ledgers: u32,  // how long the override lasts once executed
+}
+
+/// Every contract a client depends on, resolved in one read. Optional
+/// integrations are `None` until the admin configures them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Integrations {
+    pub hub: Address,
+    pub verifier: Address,
+    pub mode_verifiers: Map<u32, Address>,
+    pub leaderboard: Option<Address>,
+    /// keccak256 of the XDR-encoded rule config; changes whenever it does.
+    pub config_hash: BytesN<32>,
+}
+
+/// One player's half of a trick for `submit_trick`: a legacy play commit
+/// and its opening, signed by the player (or its session delegate) ahead
+/// of time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrickPayload {
+    pub player: Address,
+    pub nonce: u32,             // `action_nonce` when the trick opens
+    pub commit_hash: BytesN<32>, // keccak256(card_id_u32_be || salt)
+    pub card_id: u32,
+    pub salt: BytesN<32>,
+}
+
+/// What a seated player may do next, from `get_valid_actions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidActions {
+    // Cards in the viewer's hand that follow the trick suit, or just
+    // `CANNOT_FOLLOW_SENTINEL` when none does
+    pub playable: Vec<u32>,
+    // `commit_play`, `commit_play_zk`, `commit_cangkul_zk`, `reveal_play`,
+    // or `wait` while the viewer has nothing to submit
+    pub next_call: Symbol,
+    pub nonce: u32, // `action_nonce` to pass as `expected_nonce`
+}
+
+/// A sponsor's allowance for an ephemeral guest key. Games the guest starts
+/// are reported to the Game Hub under `sponsor` until `games_left` runs out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuestSponsorship {
+    pub sponsor: Address,
+    pub games_left: u32,
+}
+
+/// Per-player notification opt-ins, read by relays and by the contract's own
+/// event paths. A player with no stored record gets `Default` (all off).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NotificationPrefs {
+    pub deadline_warnings: bool, // EvDeadlineWarning when the opponent ticks the clock
+    pub mute_emotes: bool,       // suppress EvEmote addressed to this player
+    pub rematch_offers: bool,    // relays may forward rematch offers
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum StorageKey {
+    Game(u32),
+    Admin,
+    GameHubAddress,
+    VerifierAddress,
+    /// Verifier per proof mode, overriding `VerifierAddress` (instance storage).
+    ModeVerifiers,
+    LeaderboardAddress,
+    /// Pre-chunking history: one ring-buffered Vec, moved into chunk 0
+    /// by the player's next finished game (persistent storage).
+    PlayerHistory(Address),
+    /// `HISTORY_CHUNK_LEN` summaries of a player's history (persistent storage).
+    HistoryChunk(Address, u32),
+    /// Summaries ever recorded for a player (persistent storage).
+    HistoryLen(Address),
+    /// Unfinished sessions a player is seated in (persistent storage).
+    ActiveGames(Address),
+    /// Flag: Noir proof verified for (session_id, player_slot).
+    /// Stored in temp storage; consumed by `reveal_seed`.
+    NoirSeedVerified(u32, u32),
+    /// Unclaimed stakes for a finished session (persistent storage).
+    PendingClaim(u32),
+    /// Notification preferences per player (persistent storage).
+    NotificationPrefs(Address),
+    /// Sponsorship for a guest key (persistent storage).
+    GuestSponsor(Address),
+    /// Key signing a player's commits and reveals in one session (temporary storage).
+    SessionDelegate(u32, Address),
+    /// Ed25519 channel keys by seat for a session (temporary storage).
+    ChannelKeys(u32),
+    /// `action_nonce` when a session's last channel key was registered (temporary storage).
+    ChannelBase(u32),
+    /// Pending channel settlement for a session (temporary storage).
+    ChannelClaim(u32),
+    /// Multi-deal match (temporary storage).
+    Match(u32),
+    /// Hand-size cap for new games (instance storage).
+    HandCap,
+    /// keccak256 of a finished game's `export_summary` blob (persistent storage).
+    SummaryHash(u32),
+    /// Seed entropy policy checked on reveal (instance storage).
+    EntropyPolicy,
+    /// Scheduled verifier override awaiting its timelock (instance storage).
+    PendingVerifierOverride,
+    /// Expiry ledger of the active verifier override (instance storage).
+    VerifierOverride,
+    /// Circuit breaker blocking new games and commits (instance storage).
+    Paused,
+    /// Token escrow for new games (instance storage).
+    StakeEscrow,
+    /// Rake and expired stakes owed to a treasury in a token (persistent storage).
+    TreasuryBalance(Address, Address),
+    /// Pending rematch of a finished session (persistent storage).
+    Rematch(u32),
+    /// Session id of a finished session's started rematch (temporary storage).
+    RematchedAs(u32),
+    /// Every resolved trick of a session, oldest first (temporary storage).
+    TrickHistory(u32),
+    /// (trick, Pedersen commit hash) of each ZK card play by a seat (temporary storage).
+    ZkPlayCommits(u32, u32),
+    /// Deck order a session was dealt from (temporary storage).
+    DealtDeck(u32),
+    /// Open lobby challenge (persistent storage).
+    Challenge(u32),
+    /// Next lobby challenge id (instance storage).
+    NextChallengeId,
+    /// Ids of the open lobby challenges (instance storage).
+    OpenChallenges,
+    /// Next contract-allocated session id, without `CONTRACT_SESSION_BIT` (instance storage).
+    NextContractSession,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Constants
+// ═══════════════════════════════════════════════════════════════════════════════
+
+const DECK_SIZE: u32 = 36;
+const HAND_SIZE: u32 = 5;
+/// Default cap on hand size; penalty draws beyond it follow the overflow rule.
+const DEFAULT_MAX_HAND_SIZE: u32 = 15;
+const TIMEOUT_ACTIONS: u32 = 2;
+
+// Ledger rate is approximately 5 seconds per ledger on Stellar
+const LEDGER_RATE_SECS: u32 = 5;
+
+// Timeout for player actions: ~10 minutes
+const TIMEOUT_MINUTES: u32 = 10;
+const TIMEOUT_LEDGERS: u32 = TIMEOUT_MINUTES * 60 / LEDGER_RATE_SECS; // ~120 ledgers
+
+/// Bounds on `GameConfig::timeout_ledgers`: ~1 minute to ~24 hours.
+const MIN_TIMEOUT_LEDGERS: u32 = 60 / LEDGER_RATE_SECS; // 12 ledgers
+const MAX_TIMEOUT_LEDGERS: u32 = 24 * 60 * 60 / LEDGER_RATE_SECS; // 17,280 ledgers
+
+/// Blitz clock of `GameConfig::blitz()`: ~30 seconds a move plus a ~3
+/// minute time bank per player.
+const BLITZ_MOVE_LEDGERS: u32 = 30 / LEDGER_RATE_SECS; // 6 ledgers
+const BLITZ_TIME_BANK_LEDGERS: u32 = 3 * 60 / LEDGER_RATE_SECS; // 36 ledgers
+
+/// Shortest `timeout_ledgers` of a game with a time bank: ~10 seconds.
+const MIN_BLITZ_MOVE_LEDGERS: u32 = 10 / LEDGER_RATE_SECS; // 2 ledgers
+/// Largest `GameConfig::time_bank_ledgers`: ~1 hour.
+const MAX_TIME_BANK_LEDGERS: u32 = 60 * 60 / LEDGER_RATE_SECS; // 720 ledgers
+
+/// Ledgers past a deadline before anyone, not only a player, may resolve
+/// the timeout: ~24 hours.
+const TIMEOUT_GRACE_LEDGERS: u32 = 24 * 60 * 60 / LEDGER_RATE_SECS; // 17,280 ledgers
+
+/// Most cards a `GameConfig` may make a cangkul draw.
+const MAX_PENALTY_DRAW: u32 = 3;
+
+/// Proof modes the contract sends to a verifier, each routable to its own
+/// with `set_verifier_for_mode`.
+pub const ROUTABLE_PROOF_MODES: [u32; 5] = [
+    spec::MODE_NIZK_SEED,
+    spec::MODE_PEDERSEN_SEED,
+    spec::MODE_CARD_RING,
+    spec::MODE_CANGKUL_HAND,
+    spec::MODE_ULTRAHONK,
+];
+
+// TTL expressed in human-readable time units (30 days)
+const TTL_SECONDS: u32 = 30 * 24 * 60 * 60;      // 2,592,000 seconds
+
+/// TTL for game storage in ledgers: 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const GAME_TTL_LEDGERS: u32 = TTL_SECONDS / LEDGER_RATE_SECS;
 
 // History TTL: 120 days — persistent storage for player game history
 const HISTORY_TTL_SECONDS: u32 = 120 * 24 * 60 * 60; // 10,368,000 seconds
 const HISTORY_TTL_LEDGERS: u32 = HISTORY_TTL_SECONDS / LEDGER_RATE_SECS; // 2,073,600 ledgers
 
-/// Max game summaries stored per player (ring buffer)
-const MAX_HISTORY_PER_PLAYER: u32 = 50;
+// Claim window: 30 days — unclaimed stakes expire after this
+const CLAIM_WINDOW_SECONDS: u32 = 30 * 24 * 60 * 60; // 2,592,000 seconds
+const CLAIM_WINDOW_LEDGERS: u32 = CLAIM_WINDOW_SECONDS / LEDGER_RATE_SECS; // 518,400 ledgers
+
+// Verifier override: 24h timelock, at most 3 days active
+const OVERRIDE_TIMELOCK_LEDGERS: u32 = 24 * 60 * 60 / LEDGER_RATE_SECS; // 17,280 ledgers
+const MAX_OVERRIDE_LEDGERS: u32 = 3 * 24 * 60 * 60 / LEDGER_RATE_SECS; // 51,840 ledgers
+
+/// Game summaries per persistent history entry
+const HISTORY_CHUNK_LEN: u32 = 50;
+
+/// Most summaries `get_player_history` returns per call
+pub const MAX_HISTORY_PAGE: u32 = 50;
+
+/// Most history chunks `get_player_history` reads per call, so a filter
+/// that rarely matches cannot make one call scan the whole history.
+pub const MAX_HISTORY_SCAN_CHUNKS: u32 = 2;
+
+/// Resolved tricks kept in full per game; older ones survive only as
+/// `trick_log_base`, which bounds storage in long penalty-heavy games.
+const TRICK_LOG_LEN: u32 = 8;
+
+/// Number of predefined emotes; ids are [0, EMOTE_COUNT).
+const EMOTE_COUNT: u32 = 16;
+
+/// Layout version of the `export_summary` blob:
+/// version(4) || session_id(4) || player1(56) || player2(56) || outcome(4)
+/// || tricks_won1(4) || tricks_won2(4) || transcript(32), integers big-endian,
+/// players as strkey text.
+const SUMMARY_VERSION: u32 = 1;
+
+/// Layout of the blob for three or four seats: as `SUMMARY_VERSION`, with
+/// one player and one tricks_won per seat. The seat count follows from the
+/// length.
+const SUMMARY_VERSION_TABLE: u32 = 2;
+
+/// Layout version of the `export_replay` blob, integers big-endian:
+/// version(4) || session_id(4) || seats(4) || cards_per_suit(4)
+/// || deck_size(4) || hand_size(4) || penalty_draw(4)
+/// || draw_until_follow(4) || tie_break(4) || teams(4)
+/// || max_hand_size(4) || overflow_rule(4) || seed_hash(32) per seat
+/// || deck(4 per card) || tricks(4) || per trick: flipped(4) || card(4)
+/// per seat || winner(4) || outcome(4) || tricks_won(4) per seat
+/// || transcript(32). Version 1 lacked the rule words after hand_size.
+const REPLAY_VERSION: u32 = 2;
+
+/// Rebuild the `StakeEscrow` snapshotted into a game or challenge.
+fn escrow_terms(token: &Option<Address>, rake_bps: u32, treasury: &Option<Address>) -> Option<StakeEscrow> {
+    token
+        .clone()
+        .zip(treasury.clone())
+        .map(|(token, treasury)| StakeEscrow { token, rake_bps, treasury })
+}
 
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Contract
@@ -396,83 +1241,176 @@ impl CangkulanContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), CangkulanError> {
-        if player1 == player2 {
-            return Err(CangkulanError::SelfPlayNotAllowed);
+        let players = vec![&env, player1, player2];
+        let points = vec![&env, player1_points, player2_points];
+        Self::start_multiplayer_game(env, session_id, players, points)
+    }
+
+    /// Start a game for 2–4 players, seated in the given order, each staking
+    /// the matching entry of `points`.
+    ///
+    /// Every player authorizes `(session_id, points)` as for `start_game`.
+    /// Games with more than two seats are not reported to the Game Hub, so
+    /// guest sponsorships are not charged for them.
+    pub fn start_multiplayer_game(
+        env: Env,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    ) -> Result<(), CangkulanError> {
+        Self::start_game_with_config(env, session_id, players, points, None)
+    }
+
+    /// Start a game under house rules; `None` plays `GameConfig::standard()`.
+    ///
+    /// With a config every player authorizes `(session_id, points, config)`,
+    /// so nobody is seated under rules they did not agree to.
+    pub fn start_game_with_config(
+        env: Env,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+        config: Option<GameConfig>,
+    ) -> Result<(), CangkulanError> {
+        Self::require_caller_session_id(session_id)?;
+        let n = players.len();
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&n) || points.len() != n {
+            return Err(CangkulanError::InvalidPlayerCount);
+        }
+        for i in 0..n {
+            if players.slice(i + 1..).contains(players.get_unchecked(i)) {
+                return Err(CangkulanError::SelfPlayNotAllowed);
+            }
+        }
+        if let Some(config) = &config {
+            if !config.is_valid(n, Self::load_hand_cap(&env).max_hand_size) {
+                return Err(CangkulanError::InvalidGameConfig);
+            }
+        }
+
+        for (player, stake) in players.iter().zip(points.iter()) {
+            let mut args = vec![&env, session_id.into_val(&env), stake.into_val(&env)];
+            if let Some(config) = &config {
+                args.push_back(config.into_val(&env));
+            }
+            player.require_auth_for_args(args);
+        }
+
+        let escrow = Self::get_stake_escrow(env.clone());
+        let config = config.unwrap_or(GameConfig::standard());
+        Self::open_session(&env, session_id, players, points, escrow, true, config, false)
+    }
+
+    /// Fail for an id in the range the contract allocates from.
+    pub(crate) fn require_caller_session_id(session_id: u32) -> Result<(), CangkulanError> {
+        if session_id & CONTRACT_SESSION_BIT != 0 {
+            return Err(CangkulanError::ReservedSessionId);
         }
+        Ok(())
+    }
 
-        player1.require_auth_for_args(vec![
-            &env,
-            session_id.into_val(&env),
-            player1_points.into_val(&env),
-        ]);
-        player2.require_auth_for_args(vec![
-            &env,
-            session_id.into_val(&env),
-            player2_points.into_val(&env),
-        ]);
+    /// Take the next free session id with `CONTRACT_SESSION_BIT` set. No
+    /// caller can open a game there, so the id cannot be taken first.
+    pub(crate) fn allocate_session_id(env: &Env) -> u32 {
+        let mut next: u32 = env.storage().instance().get(&StorageKey::NextContractSession).unwrap_or(0);
+        let mut session_id = CONTRACT_SESSION_BIT | next;
+        while env.storage().temporary().has(&StorageKey::Game(session_id)) {
+            next = (next + 1) & !CONTRACT_SESSION_BIT;
+            session_id = CONTRACT_SESSION_BIT | next;
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::NextContractSession, &((next + 1) & !CONTRACT_SESSION_BIT));
+        session_id
+    }
 
+    /// Create the game for an authorized seating under `config`: report it
+    /// to the hub (two seats or two teams, and not against a bot), lock the
+    /// stakes in `escrow` unless the caller has already done so, and store
+    /// the initial state.
+    #[allow(clippy::too_many_arguments)]
+    fn open_session(
+        env: &Env,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+        escrow: Option<StakeEscrow>,
+        lock_stakes: bool,
+        config: GameConfig,
+        vs_bot: bool,
+    ) -> Result<(), CangkulanError> {
+        Self::require_not_paused(env)?;
+        let n = players.len();
         let key = StorageKey::Game(session_id);
         if env.storage().temporary().has(&key) {
             return Err(CangkulanError::SessionAlreadyExists);
         }
+        let verifier = Self::load_verifier(env)?;
+        let mode_verifiers = Self::get_mode_verifiers(env.clone());
+
+        if !vs_bot && Self::hub_tracked(n, &config) {
+            // In a team game seats 1 and 2 stand for their teams, staking the
+            // team total
+            let (player1, player2) = (players.get_unchecked(0), players.get_unchecked(1));
+            let side_points = |side: u32| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i as u32 % 2 == side)
+                    .fold(0i128, |total, (_, stake)| total.saturating_add(stake))
+            };
+
+            // Sponsored guests are accounted to their sponsor on the hub
+            let hub_player1 = Self::consume_guest_game(env, session_id, &player1);
+            let hub_player2 = Self::consume_guest_game(env, session_id, &player2);
+            if hub_player1 == hub_player2 {
+                return Err(CangkulanError::SelfPlayNotAllowed);
+            }
 
-        // Game Hub lifecycle: start_game BEFORE storing state.
-        let hub_addr = Self::load_hub(&env)?;
-        let hub = GameHubClient::new(&env, &hub_addr);
-        hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+            // Game Hub lifecycle: start_game BEFORE storing state.
+            let hub_addr = Self::load_hub(env)?;
+            let hub = GameHubClient::new(env, &hub_addr);
+            hub.start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &hub_player1,
+                &hub_player2,
+                &side_points(0),
+                &side_points(1),
+            );
+
+            EvHubStartReported {
+                session_id,
+                hub: hub_addr,
+            }.publish(env);
+        }
 
-        EvHubStartReported {
-            session_id,
-            hub: hub_addr,
-        }.publish(&env);
+        let hand_cap = Self::load_hand_cap(env);
+        if let Some(escrow) = escrow.as_ref().filter(|_| lock_stakes) {
+            if points.iter().any(|stake| stake < 0) {
+                return Err(CangkulanError::InvalidStakeEscrow);
+            }
+            for (player, stake) in players.iter().zip(points.iter()) {
+                Self::lock_stake(env, escrow, &player, stake);
+            }
+        }
 
-        let game = CangkulanGame {
-            player1,
-            player2,
-            player1_points,
-            player2_points,
-            seed_commit1: None,
-            seed_commit2: None,
-            seed_hash1: None,
-            seed_hash2: None,
-            seed_revealed1: false,
-            seed_revealed2: false,
-            hand1: Vec::new(&env),
-            hand2: Vec::new(&env),
-            draw_pile: Vec::new(&env),
-            trick_state: TRICK_NONE,
-            trick_suit: None,
-            trick_card1: None,
-            trick_card2: None,
-            flipped_card: None,
-            play_commit1: None,
-            play_commit2: None,
-            zk_play1: false,
-            zk_play2: false,
-            tricks_won1: 0,
-            tricks_won2: 0,
-            lifecycle_state: STATE_SEED_COMMIT,
-            outcome: OUTCOME_UNRESOLVED,
-            action_nonce: 0,
-            deadline_nonce: None,
-            deadline_ledger: None,
-            last_tick_ledger: 0,
-        };
+        let mut game = Self::new_game(env, &players, &points, config, hand_cap, verifier);
+        game.vs_bot = vs_bot;
+        game.mode_verifiers = mode_verifiers;
+        game.stake_token = escrow.as_ref().map(|escrow| escrow.token.clone());
+        game.rake_bps = escrow.as_ref().map_or(0, |escrow| escrow.rake_bps);
+        game.treasury = escrow.map(|escrow| escrow.treasury);
 
         EvGameStarted {
             session_id,
-            player1: game.player1.clone(),
-            player2: game.player2.clone(),
-        }.publish(&env);
+            players,
+        }.publish(env);
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(env, session_id, &mut game);
+        for seat in game.players.iter() {
+            Self::update_active_games(env, &seat.player, session_id, true);
+        }
         Ok(())
     }
 
@@ -480,14 +1418,25 @@ impl CangkulanContract {
     //  Public: Seed Commit-Reveal
     // ───────────────────────────────────────────────────────────────────────────
 
-    /// Commit a seed hash. Both players must commit before reveal begins.
+    /// Commit a seed hash. Every player must commit before reveal begins.
+    ///
+    /// `proof_mode` (`SEED_PROOF_NIZK`, `SEED_PROOF_PEDERSEN` or
+    /// `SEED_PROOF_NOIR`) is the proof type this player will reveal with.
+    /// It is published in `EvSeedCommitted` so the opponent knows what to
+    /// expect, and `reveal_seed` rejects a proof of any other type.
     pub fn commit_seed(
         env: Env,
         session_id: u32,
         player: Address,
         commit_hash: BytesN<32>,
+        proof_mode: u32,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
+        Self::require_not_paused(&env)?;
+
+        if !matches!(proof_mode, SEED_PROOF_NIZK | SEED_PROOF_PEDERSEN | SEED_PROOF_NOIR) {
+            return Err(CangkulanError::InvalidSeedProofMode);
+        }
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_SEED_COMMIT {
@@ -495,46 +1444,37 @@ impl CangkulanContract {
         }
 
         let slot = Self::resolve_slot(&game, &player)?;
-        match slot {
-            PLAYER_1 => {
-                if game.seed_commit1.is_some() {
-                    return Err(CangkulanError::CommitAlreadySubmitted);
-                }
-                game.seed_commit1 = Some(commit_hash);
-            }
-            _ => {
-                if game.seed_commit2.is_some() {
-                    return Err(CangkulanError::CommitAlreadySubmitted);
-                }
-                game.seed_commit2 = Some(commit_hash);
-            }
+        Self::charge_time_bank(&env, &mut game, slot)?;
+        let mut seat = game.seat(slot);
+        if seat.seed_commit.is_some() {
+            return Err(CangkulanError::CommitAlreadySubmitted);
         }
+        seat.seed_commit = Some(commit_hash);
+        seat.seed_mode = Some(proof_mode);
+        game.set_seat(slot, seat);
 
         Self::bump_nonce(&mut game);
 
         // Start deadline on first commit
         if game.deadline_nonce.is_none() {
-            game.deadline_nonce =
-                Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-            game.deadline_ledger =
-                Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+            Self::reset_deadline(&env, session_id, &mut game);
         }
 
         EvSeedCommitted {
             session_id,
             player: player.clone(),
+            proof_mode,
         }.publish(&env);
 
-        // Transition to reveal phase when both committed
-        if game.seed_commit1.is_some() && game.seed_commit2.is_some() {
+        Self::drive_bot(&env, session_id, &mut game)?;
+
+        // Transition to reveal phase when everyone committed
+        if game.players.iter().all(|seat| seat.seed_commit.is_some()) {
             game.lifecycle_state = STATE_SEED_REVEAL;
-            game.deadline_nonce =
-                Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-            game.deadline_ledger =
-                Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+            Self::reset_deadline(&env, session_id, &mut game);
         }
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
@@ -552,7 +1492,7 @@ impl CangkulanContract {
     ///   BLS12-381 Pedersen commitment + Schnorr on blinding.
     ///   commit_hash = keccak256(C), verified on-chain for binding.
     ///
-    /// Once both seeds are revealed, the deck is shuffled and cards are dealt.
+    /// Once every seed is revealed, the deck is shuffled and cards are dealt.
     ///
     /// # Arguments
     /// * `seed_hash` - `keccak256(seed)`, the one-way hash of the raw seed
@@ -564,7 +1504,7 @@ impl CangkulanContract {
         seed_hash: BytesN<32>,
         proof: Bytes,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_SEED_REVEAL {
@@ -572,29 +1512,23 @@ impl CangkulanContract {
         }
 
         let slot = Self::resolve_slot(&game, &player)?;
+        Self::check_reveal_deadline(&env, &game)?;
+        Self::charge_time_bank(&env, &mut game, slot)?;
+        let mut seat = game.seat(slot);
 
         // Verify seed_hash against commitment
-        let commit_hash = match slot {
-            PLAYER_1 => {
-                if game.seed_revealed1 {
-                    return Err(CangkulanError::RevealAlreadySubmitted);
-                }
-                game.seed_commit1
-                    .clone()
-                    .ok_or(CangkulanError::MissingCommit)?
-            }
-            _ => {
-                if game.seed_revealed2 {
-                    return Err(CangkulanError::RevealAlreadySubmitted);
-                }
-                game.seed_commit2
-                    .clone()
-                    .ok_or(CangkulanError::MissingCommit)?
-            }
-        };
+        if seat.seed_revealed {
+            return Err(CangkulanError::RevealAlreadySubmitted);
+        }
+        let commit_hash = seat.seed_commit
+            .clone()
+            .ok_or(CangkulanError::MissingCommit)?;
 
         // Reject trivially weak seed hashes
-        Self::check_seed_entropy(&seed_hash)?;
+        Self::check_seed_entropy(&env, &seed_hash)?;
+
+        // The proof must be of the type declared at commit
+        Self::check_seed_proof_mode(&game, slot, Self::seed_proof_mode_of(&proof))?;
 
         // Call NIZK ZK verifier for on-chain proof verification.
         // The verifier checks:
@@ -602,19 +1536,12 @@ impl CangkulanContract {
         //   2. nullifier derivation matches session
         //   3. Fiat-Shamir response is valid
         //   4. Entropy check on seed_hash
-        Self::call_seed_verifier(&env, session_id, slot, &player, &seed_hash, &commit_hash, &proof)?;
+        Self::call_seed_verifier(&env, session_id, &game, slot, &player, &seed_hash, &commit_hash, &proof)?;
 
         // Mark as revealed and store seed_hash for shuffle derivation
-        match slot {
-            PLAYER_1 => {
-                game.seed_revealed1 = true;
-                game.seed_hash1 = Some(seed_hash.clone());
-            }
-            _ => {
-                game.seed_revealed2 = true;
-                game.seed_hash2 = Some(seed_hash.clone());
-            }
-        }
+        seat.seed_revealed = true;
+        seat.seed_hash = Some(seed_hash.clone());
+        game.set_seat(slot, seat);
 
         Self::bump_nonce(&mut game);
 
@@ -623,8 +1550,8 @@ impl CangkulanContract {
             player: player.clone(),
         }.publish(&env);
 
-        // Both revealed → shuffle and deal
-        if game.seed_revealed1 && game.seed_revealed2 {
+        // Everyone revealed → shuffle and deal
+        if game.players.iter().all(|seat| seat.seed_revealed) {
             Self::shuffle_and_deal(&env, &mut game, session_id);
             game.lifecycle_state = STATE_PLAYING;
 
@@ -632,13 +1559,10 @@ impl CangkulanContract {
 
             // Flip the first card from draw pile
             Self::flip_next_card(&env, &mut game);
-            game.deadline_nonce =
-                Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-            game.deadline_ledger =
-                Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+            Self::reset_deadline(&env, session_id, &mut game);
         }
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
@@ -650,7 +1574,7 @@ impl CangkulanContract {
     ///
     /// `action` is either a valid `card_id` (0-35) to play a card, or
     /// `CANNOT_FOLLOW_SENTINEL` (0xFFFFFFFF) to declare cannot follow suit.
-    /// The actual action is hidden until both players have committed.
+    /// The actual action is hidden until every player has committed.
     ///
     /// `expected_nonce` must equal the current `action_nonce` to prevent
     /// replay attacks and stale-state submissions.
@@ -661,7 +1585,8 @@ impl CangkulanContract {
         commit_hash: BytesN<32>,
         expected_nonce: u32,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
+        Self::require_not_paused(&env)?;
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_PLAYING {
@@ -671,38 +1596,9 @@ impl CangkulanContract {
             return Err(CangkulanError::InvalidNonce);
         }
 
-        let slot = Self::resolve_slot(&game, &player)?;
-        Self::require_commit_phase(&game, slot)?;
-
-        match slot {
-            PLAYER_1 => {
-                if game.play_commit1.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-                game.play_commit1 = Some(commit_hash);
-            }
-            _ => {
-                if game.play_commit2.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-                game.play_commit2 = Some(commit_hash);
-            }
-        }
-
-        EvPlayCommitted {
-            session_id,
-            player: player.clone(),
-        }.publish(&env);
-
-        // Advance commit state
-        Self::advance_commit_state(&mut game, slot);
-        Self::bump_nonce(&mut game);
-
-        // Reset deadline on state transition
-        game.deadline_nonce = Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-        game.deadline_ledger = Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
-
-        Self::write_game(&env, session_id, &game);
+        Self::apply_play_commit(&env, session_id, &mut game, &player, commit_hash)?;
+        Self::drive_bot(&env, session_id, &mut game)?;
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
@@ -715,7 +1611,13 @@ impl CangkulanContract {
     /// proving the committed card is in the player's valid set (hand ∩ trick suit)
     /// without revealing which specific card.
     ///
-    /// The ZK verifier (Mode 7) checks the ring sigma and binding.
+    /// The ZK verifier (Mode 7) checks the ring sigma and binding. An empty
+    /// `zk_proof` consumes a proof recorded earlier with `verify_and_record`.
+    ///
+    /// A proof padded to a full suit of members (`RING_PADDED_FLAG`) is
+    /// recognised by its length, so every play proof can be the same size
+    /// whatever the number of suit-matching cards. A recorded proof is
+    /// looked up unpadded first, then padded.
     pub fn commit_play_zk(
         env: Env,
         session_id: u32,
@@ -724,7 +1626,8 @@ impl CangkulanContract {
         expected_nonce: u32,
         zk_proof: Bytes,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
+        Self::require_not_paused(&env)?;
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_PLAYING {
@@ -736,28 +1639,21 @@ impl CangkulanContract {
 
         let slot = Self::resolve_slot(&game, &player)?;
         Self::require_commit_phase(&game, slot)?;
+        Self::charge_time_bank(&env, &mut game, slot)?;
 
-        match slot {
-            PLAYER_1 => {
-                if game.play_commit1.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-            }
-            _ => {
-                if game.play_commit2.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-            }
+        let mut seat = game.seat(slot);
+        if seat.play_commit.is_some() {
+            return Err(CangkulanError::PlayCommitAlreadySubmitted);
         }
 
         // Compute the valid set: cards in hand matching the trick suit
         let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
-        let hand = Self::get_hand(&game, slot);
+        let hand = seat.hand.clone();
         let mut valid_set: Vec<u32> = Vec::new(&env);
         let mut vi = 0u32;
         while vi < hand.len() {
             let card = hand.get(vi).unwrap();
-            if card / CARDS_PER_SUIT == trick_suit {
+            if card / game.config.cards_per_suit == trick_suit {
                 valid_set.push_back(card);
             }
             vi += 1;
@@ -767,37 +1663,28 @@ impl CangkulanContract {
             return Err(CangkulanError::ZkPlaySetEmpty);
         }
 
-        // Build public_inputs for ZK verifier Mode 7:
-        // commit_hash(32) || N(4, u32 BE) || valid_set[N](4 each, u32 BE) || session_id(4) || player(var)
-        let mut public_inputs = Bytes::from_array(&env, &commit_hash.to_array());
-        public_inputs.append(&Bytes::from_array(&env, &n.to_be_bytes()));
-        let mut wi = 0u32;
-        while wi < n {
-            let card = valid_set.get(wi).unwrap();
-            public_inputs.append(&Bytes::from_array(&env, &card.to_be_bytes()));
-            wi += 1;
+        // Call ZK verifier (or consume a `verify_and_record` result)
+        let cards_per_suit = game.config.cards_per_suit;
+        let padded = Self::ring_proof_padded(&zk_proof, n, cards_per_suit);
+        let public_inputs = Self::ring_public_inputs(&env, &commit_hash, &valid_set, padded, session_id, &player);
+        let mut verified = Self::check_play_proof(
+            &env, session_id, &game, &player, spec::MODE_CARD_RING, &public_inputs, &zk_proof,
+        )?;
+        if !verified && zk_proof.is_empty() && n < cards_per_suit {
+            let public_inputs = Self::ring_public_inputs(&env, &commit_hash, &valid_set, true, session_id, &player);
+            verified = Self::check_play_proof(
+                &env, session_id, &game, &player, spec::MODE_CARD_RING, &public_inputs, &zk_proof,
+            )?;
         }
-        public_inputs.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        public_inputs.append(&player.to_string().to_bytes());
-
-        // Call ZK verifier
-        let verifier_addr = Self::load_verifier(&env)?;
-        let verifier = ZkVerifierClient::new(&env, &verifier_addr);
-        if !verifier.verify(&public_inputs, &zk_proof) {
+        if !verified {
             return Err(CangkulanError::ZkPlayProofInvalid);
         }
 
         // Store commit and set ZK flag
-        match slot {
-            PLAYER_1 => {
-                game.play_commit1 = Some(commit_hash);
-                game.zk_play1 = true;
-            }
-            _ => {
-                game.play_commit2 = Some(commit_hash);
-                game.zk_play2 = true;
-            }
-        }
+        Self::record_zk_play(&env, session_id, slot, game.tricks_pruned + game.trick_log.len(), &commit_hash);
+        seat.play_commit = Some(commit_hash);
+        seat.zk_play = true;
+        game.set_seat(slot, seat);
 
         EvZkCardPlayVerified {
             session_id,
@@ -811,14 +1698,14 @@ impl CangkulanContract {
         }.publish(&env);
 
         // Advance commit state
-        Self::advance_commit_state(&mut game, slot);
+        Self::advance_commit_state(&mut game);
         Self::bump_nonce(&mut game);
 
         // Reset deadline on state transition
-        game.deadline_nonce = Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-        game.deadline_ledger = Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+        Self::reset_deadline(&env, session_id, &mut game);
+        Self::drive_bot(&env, session_id, &mut game)?;
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
@@ -837,6 +1724,9 @@ impl CangkulanContract {
     /// 1. Aggregate Pedersen binding (keccak256(A) == commit_hash)
     /// 2. Schnorr proof of knowledge of aggregate blinding
     /// 3. Suit exclusion: no card matches trick_suit
+    ///
+    /// As with `commit_play_zk`, an empty `zk_proof` consumes a proof
+    /// recorded earlier with `verify_and_record`.
     pub fn commit_cangkul_zk(
         env: Env,
         session_id: u32,
@@ -845,7 +1735,8 @@ impl CangkulanContract {
         expected_nonce: u32,
         zk_proof: Bytes,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
+        Self::require_not_paused(&env)?;
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_PLAYING {
@@ -857,24 +1748,17 @@ impl CangkulanContract {
 
         let slot = Self::resolve_slot(&game, &player)?;
         Self::require_commit_phase(&game, slot)?;
+        Self::charge_time_bank(&env, &mut game, slot)?;
 
-        match slot {
-            PLAYER_1 => {
-                if game.play_commit1.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-            }
-            _ => {
-                if game.play_commit2.is_some() {
-                    return Err(CangkulanError::PlayCommitAlreadySubmitted);
-                }
-            }
+        let mut seat = game.seat(slot);
+        if seat.play_commit.is_some() {
+            return Err(CangkulanError::PlayCommitAlreadySubmitted);
         }
 
         // Verify: player has no cards matching the trick suit
         let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
-        let hand = Self::get_hand(&game, slot);
-        if Self::has_suit_in_hand(&hand, trick_suit) {
+        let hand = seat.hand.clone();
+        if Self::has_suit_in_hand(&hand, trick_suit, game.config.cards_per_suit) {
             return Err(CangkulanError::HasMatchingSuit);
         }
 
@@ -895,23 +1779,16 @@ impl CangkulanContract {
         public_inputs.append(&player.to_string().to_bytes());
 
         // Call ZK verifier (auto-detects Mode 8 from 228-byte proof)
-        let verifier_addr = Self::load_verifier(&env)?;
-        let verifier = ZkVerifierClient::new(&env, &verifier_addr);
-        if !verifier.verify(&public_inputs, &zk_proof) {
+        if !Self::check_play_proof(
+            &env, session_id, &game, &player, spec::MODE_CANGKUL_HAND, &public_inputs, &zk_proof,
+        )? {
             return Err(CangkulanError::ZkCangkulProofInvalid);
         }
 
         // Store commit and set ZK flag
-        match slot {
-            PLAYER_1 => {
-                game.play_commit1 = Some(commit_hash);
-                game.zk_play1 = true;
-            }
-            _ => {
-                game.play_commit2 = Some(commit_hash);
-                game.zk_play2 = true;
-            }
-        }
+        seat.play_commit = Some(commit_hash);
+        seat.zk_play = true;
+        game.set_seat(slot, seat);
 
         EvZkCangkulVerified {
             session_id,
@@ -926,14 +1803,14 @@ impl CangkulanContract {
         }.publish(&env);
 
         // Advance commit state
-        Self::advance_commit_state(&mut game, slot);
+        Self::advance_commit_state(&mut game);
         Self::bump_nonce(&mut game);
 
         // Reset deadline on state transition
-        game.deadline_nonce = Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-        game.deadline_ledger = Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+        Self::reset_deadline(&env, session_id, &mut game);
+        Self::drive_bot(&env, session_id, &mut game)?;
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
@@ -951,123 +1828,63 @@ impl CangkulanContract {
         card_id: u32,
         salt: BytesN<32>,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
 
         let mut game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_PLAYING {
             return Err(CangkulanError::WrongPhase);
         }
 
-        let slot = Self::resolve_slot(&game, &player)?;
-        Self::require_reveal_phase(&game, slot)?;
-
-        // Verify commit hash matches
-        let commit = match slot {
-            PLAYER_1 => game.play_commit1.clone().ok_or(CangkulanError::PlayCommitMissing)?,
-            _ => game.play_commit2.clone().ok_or(CangkulanError::PlayCommitMissing)?,
-        };
-
-        let is_zk = match slot {
-            PLAYER_1 => game.zk_play1,
-            _ => game.zk_play2,
-        };
+        Self::apply_play_reveal(&env, session_id, &mut game, &player, card_id, salt)?;
+        Self::drive_bot(&env, session_id, &mut game)?;
+        Self::write_game(&env, session_id, &mut game);
+        Ok(())
+    }
 
-        let is_cangkul = card_id == CANNOT_FOLLOW_SENTINEL;
+    /// Land a whole two-player trick in one transaction: both commits, then
+    /// both reveals, as `commit_play` and `reveal_play` would.
+    ///
+    /// Each payload is authorized with `require_auth_for_args((session_id,
+    /// payload))` by its player, or its session delegate, so a relayer can
+    /// gather the two signed halves off-chain and submit them. Payloads must
+    /// be in seat order and carry the current `action_nonce`, which makes a
+    /// signed half good for this trick only.
+    pub fn submit_trick(
+        env: Env,
+        session_id: u32,
+        p1_payload: TrickPayload,
+        p2_payload: TrickPayload,
+    ) -> Result<(), CangkulanError> {
+        Self::require_not_paused(&env)?;
 
-        if is_zk {
-            if is_cangkul {
-                // Mode 8 aggregate opening: A = (Σhand_card_i)·G + r_agg·H
-                // The player supplies r_agg as the `salt`.
-                let hand = Self::get_hand(&game, slot);
-                let card_sum: u32 = hand.iter().sum();
-                let a_point = Self::pedersen_commit(&env, card_sum, &salt);
-                let a_bytes = a_point.to_bytes();
-                let computed_hash: BytesN<32> = env.crypto().keccak256(
-                    &Bytes::from_array(&env, &a_bytes.to_array())
-                ).into();
-                if computed_hash != commit {
-                    return Err(CangkulanError::ZkPlayOpeningMismatch);
-                }
-            } else {
-                // Mode 7 single Pedersen opening: C = card_id·G + blinding·H
-                let c_point = Self::pedersen_commit(&env, card_id, &salt);
-                let c_bytes = c_point.to_bytes();
-                let computed_hash: BytesN<32> = env.crypto().keccak256(
-                    &Bytes::from_array(&env, &c_bytes.to_array())
-                ).into();
-                if computed_hash != commit {
-                    return Err(CangkulanError::ZkPlayOpeningMismatch);
-                }
-            }
-        } else {
-            // Legacy keccak256 hash opening
-            let mut preimage = Bytes::from_array(&env, &card_id.to_be_bytes());
-            preimage.append(&Bytes::from_array(&env, &salt.to_array()));
-            let computed_hash: BytesN<32> = env.crypto().keccak256(&preimage).into();
-            if computed_hash != commit {
-                return Err(CangkulanError::PlayRevealMismatch);
-            }
+        let mut game = Self::read_game(&env, session_id)?;
+        if game.lifecycle_state != STATE_PLAYING {
+            return Err(CangkulanError::WrongPhase);
         }
-
-        if is_cangkul {
-            // Validate: player truly has no matching suit cards
-            let hand = Self::get_hand(&game, slot);
-            let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
-            if Self::has_suit_in_hand(&hand, trick_suit) {
-                return Err(CangkulanError::HasMatchingSuit);
-            }
-            // trick_card for this slot remains None — signals cannot_follow
-        } else {
-            // Validate card_id range
-            if card_id >= DECK_SIZE {
-                return Err(CangkulanError::InvalidCardId);
+        if game.players.len() != 2 {
+            return Err(CangkulanError::InvalidPlayerCount);
+        }
+        let payloads = [p1_payload, p2_payload];
+        for (slot, payload) in (1..).zip(payloads.iter()) {
+            if payload.player != game.seat(slot).player {
+                return Err(CangkulanError::NotAPlayer);
             }
-
-            // Validate card is in hand
-            let hand = Self::get_hand(&game, slot);
-            let card_pos = Self::find_card_position(&hand, card_id)?;
-
-            // Validate card matches trick suit
-            let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
-            let card_suit = card_id / CARDS_PER_SUIT;
-            if card_suit != trick_suit {
-                return Err(CangkulanError::WrongSuit);
+            if payload.nonce != game.action_nonce {
+                return Err(CangkulanError::InvalidNonce);
             }
+            Self::play_signer(&env, session_id, &payload.player)
+                .require_auth_for_args(vec![&env, session_id.into_val(&env), payload.into_val(&env)]);
+        }
 
-            // Remove card from hand and record it
-            let mut hand = hand;
-            hand.remove(card_pos);
-            Self::set_hand(&mut game, slot, hand);
-
-            match slot {
-                PLAYER_1 => game.trick_card1 = Some(card_id),
-                _ => game.trick_card2 = Some(card_id),
-            }
+        for payload in payloads.iter() {
+            Self::apply_play_commit(&env, session_id, &mut game, &payload.player, payload.commit_hash.clone())?;
+        }
+        for payload in payloads {
+            Self::apply_play_reveal(&env, session_id, &mut game, &payload.player, payload.card_id, payload.salt)?;
         }
-
-        EvPlayRevealed {
-            session_id,
-            player: player.clone(),
-            card_id,
-            is_cangkul,
-        }.publish(&env);
-
-        // Advance reveal state
-        Self::advance_reveal_state(&mut game, slot);
-        Self::bump_nonce(&mut game);
-
-        // If both have revealed, resolve the trick
-        if game.trick_state == TRICK_NONE {
-            Self::resolve_trick(&env, session_id, &mut game)?;
-        } else {
-            // Reset deadline for reveal phase
-            game.deadline_nonce = Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-            game.deadline_ledger = Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
-        }
-
-        Self::write_game(&env, session_id, &game);
-        Ok(())
-    }
+        Self::write_game(&env, session_id, &mut game);
+        Ok(())
+    }
 
     // ───────────────────────────────────────────────────────────────────────────
     //  Public: Timeout
@@ -1087,17 +1904,38 @@ impl CangkulanContract {
 
         // Rate-limit: enforce minimum ledger gap between ticks to prevent
         // nonce-pump exploits (calling tick_timeout rapidly to force-win).
+        // The gap is timeout / TIMEOUT_ACTIONS, so the nonce path cannot
+        // resolve faster than the ledger deadline.
         let current_ledger = env.ledger().sequence();
-        if current_ledger < game.last_tick_ledger.saturating_add(MIN_TICK_GAP_LEDGERS) {
+        let min_gap = game.config.timeout_ledgers / TIMEOUT_ACTIONS;
+        if current_ledger < game.last_tick_ledger.saturating_add(min_gap) {
             return Err(CangkulanError::TickTooSoon);
         }
         game.last_tick_ledger = current_ledger;
 
         Self::bump_nonce(&mut game);
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
+
+        // The clock runs against the opponents — warn those who opted in
+        if let Some(deadline_ledger) = game.deadline_ledger {
+            for seat in game.players.iter() {
+                if seat.player != caller && Self::read_prefs(&env, &seat.player).deadline_warnings {
+                    EvDeadlineWarning { session_id, player: seat.player, deadline_ledger }.publish(&env);
+                }
+            }
+        }
         Ok(game.action_nonce)
     }
 
+    /// End a game whose deadline has passed, with the outcome of
+    /// `determine_timeout_outcome`.
+    ///
+    /// Players may call this as soon as the deadline passes. Anyone else
+    /// may once the ledger deadline is `TIMEOUT_GRACE_LEDGERS` behind, so a
+    /// game both players abandoned still settles and reaches the hub.
+    ///
+    /// On a blitz clock (`time_bank_ledgers`) anyone may call it as soon as
+    /// every seat it waits on has used up its time bank past the deadline.
     pub fn resolve_timeout(
         env: Env,
         session_id: u32,
@@ -1107,31 +1945,85 @@ impl CangkulanContract {
 
         let mut game = Self::read_game(&env, session_id)?;
         Self::require_active(&game)?;
-        // Only players in the game may resolve a timeout
-        Self::resolve_slot(&game, &caller)?;
+        if game.time_banks.is_empty() {
+            if Self::resolve_slot(&game, &caller).is_err() {
+                let abandoned = game
+                    .deadline_ledger
+                    .is_some_and(|dl| env.ledger().sequence() >= dl.saturating_add(TIMEOUT_GRACE_LEDGERS));
+                if !abandoned {
+                    return Err(CangkulanError::NotAPlayer);
+                }
+            }
 
-        let deadline = game
-            .deadline_nonce
-            .ok_or(CangkulanError::TimeoutNotConfigured)?;
+            let deadline = game
+                .deadline_nonce
+                .ok_or(CangkulanError::TimeoutNotConfigured)?;
 
-        // Timeout is reached if EITHER the nonce deadline passed OR
-        // the ledger-based deadline has elapsed.
-        let nonce_expired = game.action_nonce >= deadline;
-        let ledger_expired = game
-            .deadline_ledger
-            .map_or(false, |dl| env.ledger().sequence() >= dl);
+            // Timeout is reached if EITHER the nonce deadline passed OR
+            // the ledger-based deadline has elapsed.
+            let nonce_expired = game.action_nonce >= deadline;
+            let ledger_expired = game
+                .deadline_ledger
+                .is_some_and(|dl| env.ledger().sequence() >= dl);
 
-        if !nonce_expired && !ledger_expired {
+            if !nonce_expired && !ledger_expired {
+                return Err(CangkulanError::TimeoutNotReached);
+            }
+        } else if !Self::blitz_flagged(&env, &game) {
+            // Blitz: anyone may flag a seat out of time; ticks play no part
             return Err(CangkulanError::TimeoutNotReached);
         }
 
         let outcome = Self::determine_timeout_outcome(&game)?;
         Self::finalize_game(&env, session_id, &mut game, outcome)?;
+        EvTimeoutResolved { session_id, outcome }.publish(&env);
 
-        Self::write_game(&env, session_id, &game);
+        Self::write_game(&env, session_id, &mut game);
         Ok(())
     }
 
+    /// Finalize abandoned sessions in bulk. Returns the ids it ended.
+    ///
+    /// Needs no auth. A session is ended once its ledger deadline is
+    /// `TIMEOUT_GRACE_LEDGERS` behind, with the timeout outcome (a draw
+    /// where none applies), or as a draw once its storage entry is within
+    /// `TIMEOUT_GRACE_LEDGERS` of expiring. Missing, finished and live
+    /// sessions are skipped. One `EvSessionsPruned` lists the ended ids.
+    pub fn prune_expired(env: Env, session_ids: Vec<u32>) -> Result<Vec<u32>, CangkulanError> {
+        let now = env.ledger().sequence();
+        let mut pruned = Vec::new(&env);
+        for session_id in session_ids.iter() {
+            let Ok(mut game) = Self::read_game(&env, session_id) else {
+                continue;
+            };
+            if game.lifecycle_state == STATE_FINISHED {
+                continue;
+            }
+            let abandoned = game
+                .deadline_ledger
+                .is_some_and(|dl| now >= dl.saturating_add(TIMEOUT_GRACE_LEDGERS));
+            let expiring = now.saturating_add(TIMEOUT_GRACE_LEDGERS)
+                >= game.updated_ledger.saturating_add(GAME_TTL_LEDGERS);
+            let outcome = if abandoned {
+                Self::determine_timeout_outcome(&game).unwrap_or(OUTCOME_DRAW)
+            } else if expiring {
+                OUTCOME_DRAW
+            } else {
+                continue;
+            };
+            Self::finalize_game(&env, session_id, &mut game, outcome)?;
+            if abandoned {
+                EvTimeoutResolved { session_id, outcome }.publish(&env);
+            }
+            Self::write_game(&env, session_id, &mut game);
+            pruned.push_back(session_id);
+        }
+        if !pruned.is_empty() {
+            EvSessionsPruned { session_ids: pruned.clone() }.publish(&env);
+        }
+        Ok(pruned)
+    }
+
     // ───────────────────────────────────────────────────────────────────────────
     //  Public: Forfeit (Withdraw = Lose)
     // ───────────────────────────────────────────────────────────────────────────
@@ -1139,7 +2031,14 @@ impl CangkulanContract {
     /// Forfeit the game. The caller immediately loses.
     ///
     /// This allows a player to withdraw from an active game at any point.
-    /// The opponent is declared the winner. This is irreversible.
+    /// The opponent is declared the winner; with more seats, the best of
+    /// the remaining players by the usual ranking. This is irreversible.
+    ///
+    /// The game records the seat in `forfeited_by`. Leaving once the cards
+    /// are dealt is a rage-quit: the Leaderboard is told with its
+    /// `OUTCOME_FORFEIT` flag and takes extra rating off the quitter.
+    /// Leaving during the seed phases, before anyone has seen a hand, is
+    /// reported as an ordinary loss.
     pub fn forfeit(
         env: Env,
         session_id: u32,
@@ -1151,39 +2050,582 @@ impl CangkulanContract {
         Self::require_active(&game)?;
         let slot = Self::resolve_slot(&game, &caller)?;
 
-        // The caller loses — opponent wins
-        let outcome = if slot == PLAYER_1 {
-            OUTCOME_PLAYER2_WIN
+        // The caller loses — the best of the others wins
+        let outcome = Self::determine_winner_among(&game, Self::all_slots(&game) & !Self::slot_bit(slot));
+        game.forfeited_by = slot;
+        EvGameForfeited {
+            session_id,
+            player: caller,
+            penalized: Self::is_rage_quit(&game),
+        }.publish(&env);
+
+        Self::finalize_game(&env, session_id, &mut game, outcome)?;
+        Self::write_game(&env, session_id, &mut game);
+        Ok(())
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Forced win (early finish of a decided game)
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// End a game whose result can no longer change, without playing out
+    /// the remaining tricks.
+    ///
+    /// Either player may call this; the contract checks on-chain that the
+    /// position is decided (see `forced_outcome`) and finalizes with that
+    /// outcome. Returns the outcome, or `OutcomeNotForced` if the trailing
+    /// player could still win or draw.
+    pub fn claim_forced_win(
+        env: Env,
+        session_id: u32,
+        caller: Address,
+    ) -> Result<u32, CangkulanError> {
+        caller.require_auth();
+
+        let mut game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+        Self::resolve_slot(&game, &caller)?;
+        if game.lifecycle_state != STATE_PLAYING {
+            return Err(CangkulanError::WrongPhase);
+        }
+
+        let outcome = Self::forced_outcome(&game).ok_or(CangkulanError::OutcomeNotForced)?;
+        EvForcedWin {
+            session_id,
+            outcome,
+            tricks_left: Self::max_tricks_left(&game),
+        }.publish(&env);
+
+        Self::finalize_game(&env, session_id, &mut game, outcome)?;
+        Self::write_game(&env, session_id, &mut game);
+        Ok(outcome)
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Claims (second phase of finalization)
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Pay `player` their share of the stakes recorded when the game finished.
+    ///
+    /// Finalization only records the outcome and notifies the hub; payouts
+    /// happen here so a failing transfer can never block or revert the game
+    /// result. Each player claims on their own, so one recipient whose
+    /// transfer fails never holds up the others. Anyone may call this —
+    /// funds only go to `player`. Once every share is paid the record is
+    /// cleared and the rake of an escrowed game is credited to its
+    /// treasury. Returns the amount paid.
+    pub fn claim_winnings(env: Env, session_id: u32, player: Address) -> Result<i128, CangkulanError> {
+        let key = StorageKey::PendingClaim(session_id);
+        let mut claim: PendingClaim = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(CangkulanError::NothingToClaim)?;
+
+        if env.ledger().sequence() > claim.expires_ledger {
+            return Err(CangkulanError::ClaimExpired);
+        }
+
+        let index = claim
+            .payouts
+            .iter()
+            .position(|payout| payout.player == player && !payout.claimed)
+            .ok_or(CangkulanError::NothingToClaim)? as u32;
+        let mut payout = claim.payouts.get_unchecked(index);
+        payout.claimed = true;
+        claim.payouts.set(index, payout.clone());
+
+        if claim.payouts.iter().all(|payout| payout.claimed) {
+            env.storage().persistent().remove(&key);
+            if let (Some(token), Some(treasury)) = (&claim.token, &claim.treasury) {
+                if claim.rake > 0 {
+                    Self::credit_treasury(&env, treasury, token, claim.rake);
+                    EvRakeCollected {
+                        session_id,
+                        treasury: treasury.clone(),
+                        amount: claim.rake,
+                    }.publish(&env);
+                }
+            }
         } else {
-            OUTCOME_PLAYER1_WIN
+            env.storage().persistent().set(&key, &claim);
+        }
+
+        if let Some(token) = &claim.token {
+            TokenClient::new(&env, token).transfer(&env.current_contract_address(), &player, &payout.amount);
+        }
+        EvWinningsClaimed {
+            session_id,
+            player,
+            amount: payout.amount,
+        }.publish(&env);
+        Ok(payout.amount)
+    }
+
+    /// Clear the shares that were not claimed within the claim window.
+    ///
+    /// Callable by anyone once `expires_ledger` has passed. Unclaimed
+    /// escrowed tokens are credited to the treasury with the rake. Returns
+    /// the total amount forfeited.
+    pub fn expire_winnings(env: Env, session_id: u32) -> Result<i128, CangkulanError> {
+        let key = StorageKey::PendingClaim(session_id);
+        let claim: PendingClaim = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(CangkulanError::NothingToClaim)?;
+
+        if env.ledger().sequence() <= claim.expires_ledger {
+            return Err(CangkulanError::ClaimNotExpired);
+        }
+
+        env.storage().persistent().remove(&key);
+
+        let mut total: i128 = 0;
+        for payout in claim.payouts.iter().filter(|payout| !payout.claimed) {
+            total += payout.amount;
+            EvWinningsExpired {
+                session_id,
+                player: payout.player,
+                amount: payout.amount,
+            }.publish(&env);
+        }
+        // Escrowed tokens nobody claimed go to the treasury with the rake
+        if let (Some(token), Some(treasury)) = (claim.token, claim.treasury) {
+            let swept = total + claim.rake;
+            if swept > 0 {
+                Self::credit_treasury(&env, &treasury, &token, swept);
+            }
+        }
+        Ok(total)
+    }
+
+    /// Pay `treasury` everything credited to it in `token`. Only the
+    /// treasury may withdraw. Returns the amount paid.
+    pub fn withdraw_treasury(env: Env, treasury: Address, token: Address) -> Result<i128, CangkulanError> {
+        treasury.require_auth();
+        let key = StorageKey::TreasuryBalance(treasury.clone(), token.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount == 0 {
+            return Err(CangkulanError::NothingToClaim);
+        }
+        env.storage().persistent().remove(&key);
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &treasury, &amount);
+        EvTreasuryWithdrawn { treasury, token, amount }.publish(&env);
+        Ok(amount)
+    }
+
+    /// Amount credited to `treasury` in `token` and not yet withdrawn.
+    pub fn get_treasury_balance(env: Env, treasury: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::TreasuryBalance(treasury, token))
+            .unwrap_or(0)
+    }
+
+    /// Get the unclaimed stakes for a finished session, if any.
+    pub fn get_pending_claim(env: Env, session_id: u32) -> Option<PendingClaim> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::PendingClaim(session_id))
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Rematch
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Offer a rematch of a finished game with the same seats, stakes and
+    /// escrow terms. The new game gets a contract-allocated session id;
+    /// a finished game is rematched at most once.
+    ///
+    /// Each seat authorizes only its own request or acceptance; in an
+    /// escrowed game that call also locks its stake. Returns the rematch
+    /// session id.
+    pub fn request_rematch(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<u32, CangkulanError> {
+        player.require_auth();
+
+        let game = Self::read_game(&env, session_id)?;
+        if game.lifecycle_state != STATE_FINISHED {
+            return Err(CangkulanError::GameNotFinished);
+        }
+        let slot = Self::resolve_slot(&game, &player)?;
+        let key = StorageKey::Rematch(session_id);
+        if env.storage().persistent().has(&key) {
+            return Err(CangkulanError::RematchAlreadyAgreed);
+        }
+        if env.storage().temporary().has(&StorageKey::RematchedAs(session_id)) {
+            return Err(CangkulanError::SessionAlreadyExists);
+        }
+        let rematch_id = Self::allocate_session_id(&env);
+
+        let mut players = Vec::new(&env);
+        let mut points = Vec::new(&env);
+        for seat in game.players.iter() {
+            players.push_back(seat.player);
+            points.push_back(seat.points);
+        }
+        let offer = RematchOffer {
+            rematch_id,
+            agreed: Vec::new(&env),
+            players,
+            points,
+            config: game.config,
+            stake_token: game.stake_token,
+            rake_bps: game.rake_bps,
+            treasury: game.treasury,
         };
+        Self::agree_rematch(&env, session_id, slot - 1, offer)?;
+        EvRematchRequested { session_id, player, rematch_id }.publish(&env);
+        Ok(rematch_id)
+    }
 
-        Self::finalize_game(&env, session_id, &mut game, outcome)?;
-        Self::write_game(&env, session_id, &game);
+    /// Accept a pending rematch. Once every seat has agreed the new game
+    /// starts. Returns the rematch session id.
+    pub fn accept_rematch(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<u32, CangkulanError> {
+        player.require_auth();
+
+        let offer: RematchOffer = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Rematch(session_id))
+            .ok_or(CangkulanError::RematchNotRequested)?;
+        let index = offer
+            .players
+            .first_index_of(&player)
+            .ok_or(CangkulanError::NotAPlayer)?;
+        if offer.agreed.contains(&player) {
+            return Err(CangkulanError::RematchAlreadyAgreed);
+        }
+        let rematch_id = offer.rematch_id;
+        Self::agree_rematch(&env, session_id, index, offer)?;
+        Ok(rematch_id)
+    }
+
+    /// Withdraw a pending rematch. Any seat of the finished game may cancel;
+    /// stakes already locked for it are returned. Works from the offer
+    /// alone, so the stakes come back even once the finished game expired.
+    pub fn cancel_rematch(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), CangkulanError> {
+        player.require_auth();
+
+        let key = StorageKey::Rematch(session_id);
+        let offer: RematchOffer = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(CangkulanError::RematchNotRequested)?;
+        if !offer.players.contains(&player) {
+            return Err(CangkulanError::NotAPlayer);
+        }
+        env.storage().persistent().remove(&key);
+
+        if let Some(token) = &offer.stake_token {
+            let token = TokenClient::new(&env, token);
+            for (seat, points) in offer.players.iter().zip(offer.points.iter()) {
+                if points > 0 && offer.agreed.contains(&seat) {
+                    token.transfer(&env.current_contract_address(), &seat, &points);
+                }
+            }
+        }
+        EvRematchCancelled { session_id, player }.publish(&env);
         Ok(())
     }
 
+    pub fn get_rematch(env: Env, session_id: u32) -> Option<RematchOffer> {
+        env.storage().persistent().get(&StorageKey::Rematch(session_id))
+    }
+
+    /// Session id of the rematch of `session_id`, pending or started.
+    pub fn rematch_session_id(env: Env, session_id: u32) -> Option<u32> {
+        let started = env.storage().temporary().get(&StorageKey::RematchedAs(session_id));
+        started.or_else(|| Self::get_rematch(env, session_id).map(|offer| offer.rematch_id))
+    }
+
     // ───────────────────────────────────────────────────────────────────────────
-    //  Public: Read & Admin
+    //  Public: Guest accounts
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Let an ephemeral `guest` key play up to `max_games` sessions on the
+    /// sponsor's behalf.
+    ///
+    /// The guest still signs its own moves; only Game Hub accounting is
+    /// attributed to the sponsor. Re-sponsoring replaces the allowance.
+    pub fn sponsor_guest(
+        env: Env,
+        sponsor: Address,
+        guest: Address,
+        max_games: u32,
+    ) -> Result<(), CangkulanError> {
+        sponsor.require_auth();
+
+        if sponsor == guest || max_games == 0 {
+            return Err(CangkulanError::InvalidGuestSponsorship);
+        }
+        // No sponsorship chains: a sponsored guest cannot sponsor others
+        if env.storage().persistent().has(&StorageKey::GuestSponsor(sponsor.clone())) {
+            return Err(CangkulanError::InvalidGuestSponsorship);
+        }
+
+        let key = StorageKey::GuestSponsor(guest.clone());
+        env.storage().persistent().set(
+            &key,
+            &GuestSponsorship { sponsor: sponsor.clone(), games_left: max_games },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        EvGuestSponsored { sponsor, guest, max_games }.publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw the remaining allowance of a guest. Games already started
+    /// keep their attribution.
+    pub fn revoke_guest(env: Env, sponsor: Address, guest: Address) -> Result<(), CangkulanError> {
+        sponsor.require_auth();
+
+        let key = StorageKey::GuestSponsor(guest.clone());
+        let sponsorship: GuestSponsorship = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(CangkulanError::GuestNotSponsored)?;
+        if sponsorship.sponsor != sponsor {
+            return Err(CangkulanError::GuestNotSponsored);
+        }
+
+        env.storage().persistent().remove(&key);
+        EvGuestRevoked { sponsor, guest }.publish(&env);
+        Ok(())
+    }
+
+    /// Get a guest's sponsor and remaining games, if sponsored.
+    pub fn get_guest_sponsor(env: Env, guest: Address) -> Option<GuestSponsorship> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::GuestSponsor(guest))
+    }
+
     // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Session delegates
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Let `delegate` sign `player`'s commits and reveals in `session_id`,
+    /// e.g. a browser session key or a relayer. From then on those calls
+    /// need the delegate's auth instead of the player's; forfeits, timeouts
+    /// and claims still need the player. Re-authorizing replaces the key.
+    pub fn authorize_delegate(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        delegate: Address,
+    ) -> Result<(), CangkulanError> {
+        player.require_auth();
+
+        let game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+        Self::resolve_slot(&game, &player)?;
+        if delegate == player {
+            return Err(CangkulanError::InvalidDelegate);
+        }
+
+        let key = StorageKey::SessionDelegate(session_id, player.clone());
+        env.storage().temporary().set(&key, &delegate);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        EvDelegateAuthorized { session_id, player, delegate }.publish(&env);
+        Ok(())
+    }
+
+    /// Take back signing of `player`'s moves in `session_id`.
+    pub fn revoke_delegate(env: Env, session_id: u32, player: Address) -> Result<(), CangkulanError> {
+        player.require_auth();
+
+        let key = StorageKey::SessionDelegate(session_id, player.clone());
+        if !env.storage().temporary().has(&key) {
+            return Err(CangkulanError::InvalidDelegate);
+        }
+        env.storage().temporary().remove(&key);
+        EvDelegateRevoked { session_id, player }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_delegate(env: Env, session_id: u32, player: Address) -> Option<Address> {
+        env.storage()
+            .temporary()
+            .get(&StorageKey::SessionDelegate(session_id, player))
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Notification preferences & emotes
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Store the caller's notification preferences.
+    ///
+    /// Relays read these via `get_notification_prefs`; the contract itself
+    /// respects `deadline_warnings` (in `tick_timeout`) and `mute_emotes`
+    /// (in `send_emote`).
+    pub fn set_notification_prefs(env: Env, player: Address, prefs: NotificationPrefs) {
+        player.require_auth();
+
+        let key = StorageKey::NotificationPrefs(player.clone());
+        env.storage().persistent().set(&key, &prefs);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
 
-    /// Get game state with both hands redacted for active games.
+        EvNotificationPrefsSet {
+            player,
+            deadline_warnings: prefs.deadline_warnings,
+            mute_emotes: prefs.mute_emotes,
+            rematch_offers: prefs.rematch_offers,
+        }.publish(&env);
+    }
+
+    /// Get a player's notification preferences (all off if never set).
+    pub fn get_notification_prefs(env: Env, player: Address) -> NotificationPrefs {
+        Self::read_prefs(&env, &player)
+    }
+
+    /// Send a predefined emote to every opponent in an active game.
     ///
-    /// Returns full state (including hands) only for FINISHED games.
-    /// During active games, hands are cleared to prevent card snooping
-    /// via unauthenticated RPC queries. Use `get_game_view` with a viewer
-    /// address to see your own hand during gameplay.
-    pub fn get_game(env: Env, session_id: u32) -> Result<CangkulanGame, CangkulanError> {
+    /// Opponents who muted emotes get nothing; returns `false` if that is
+    /// all of them.
+    pub fn send_emote(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        emote: u32,
+    ) -> Result<bool, CangkulanError> {
+        player.require_auth();
+
+        if emote >= EMOTE_COUNT {
+            return Err(CangkulanError::InvalidEmote);
+        }
+        let game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+        Self::resolve_slot(&game, &player)?;
+
+        let mut sent = false;
+        for seat in game.players.iter() {
+            if seat.player == player || Self::read_prefs(&env, &seat.player).mute_emotes {
+                continue;
+            }
+            EvEmote { session_id, from: player.clone(), to: seat.player, emote }.publish(&env);
+            sent = true;
+        }
+        Ok(sent)
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Commitment helpers
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Legacy play commit `keccak256(card_id_u32_be ∥ salt)`, as checked by
+    /// `reveal_play` for a `commit_play` commit.
+    pub fn compute_play_commit(env: Env, card_id: u32, salt: BytesN<32>) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(&env, &card_id.to_be_bytes());
+        preimage.append(&Bytes::from_array(&env, &salt.to_array()));
+        env.crypto().keccak256(&preimage).into()
+    }
+
+    /// ZK play commit `keccak256(card_id·G + blinding·H)`, as checked by
+    /// `reveal_play` for a `commit_play_zk` commit. For a `commit_cangkul_zk`
+    /// commit pass the sum of the hand's card ids and the aggregate blinding.
+    pub fn compute_pedersen_commit_hash(env: Env, card_id: u32, blinding: BytesN<32>) -> BytesN<32> {
+        let point = Self::pedersen_commit(&env, card_id, &blinding);
+        env.crypto().keccak256(&Bytes::from_array(&env, &point.to_bytes().to_array())).into()
+    }
+
+    /// Hash-based (NIZK) seed commit
+    /// `keccak256(seed_hash ∥ blinding ∥ player as strkey)` for `commit_seed`.
+    pub fn compute_seed_commit(env: Env, seed_hash: BytesN<32>, blinding: BytesN<32>, player: Address) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(&env, &seed_hash.to_array());
+        preimage.append(&Bytes::from_array(&env, &blinding.to_array()));
+        preimage.append(&player.to_string().to_bytes());
+        env.crypto().keccak256(&preimage).into()
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Public: Read & Admin
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Public state of a game: the `PublicGameView` anyone may read, in
+    /// every phase. Use `get_game_view` with a viewer address to see your
+    /// own hand during gameplay, and `get_game_record` for the full state
+    /// of a finished game.
+    pub fn get_game(env: Env, session_id: u32) -> Result<PublicGameView, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        let mut players = Vec::new(&env);
+        for seat in game.players.iter() {
+            players.push_back(PublicSeat {
+                player: seat.player,
+                points: seat.points,
+                hand_size: seat.hand.len(),
+                tricks_won: seat.tricks_won,
+                seed_committed: seat.seed_commit.is_some(),
+                seed_revealed: seat.seed_revealed,
+                play_committed: seat.play_commit.is_some(),
+                play_revealed: seat.play_revealed,
+            });
+        }
+        Ok(PublicGameView {
+            players,
+            config: game.config,
+            lifecycle_state: game.lifecycle_state,
+            trick_state: game.trick_state,
+            trick_suit: game.trick_suit,
+            flipped_card: game.flipped_card,
+            draw_pile_size: game.draw_pile.len(),
+            outcome: game.outcome,
+            forfeited_by: game.forfeited_by,
+            action_nonce: game.action_nonce,
+            deadline_ledger: game.deadline_ledger,
+            time_banks: game.time_banks,
+            stake_token: game.stake_token,
+            vs_bot: game.vs_bot,
+        })
+    }
+
+    /// Full state of a finished game, hands and pile included; nothing is
+    /// secret once play is over. Fails with `GameNotFinished` before that.
+    pub fn get_game_record(env: Env, session_id: u32) -> Result<CangkulanGame, CangkulanError> {
         let game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state != STATE_FINISHED {
-            let mut view = game;
-            view.hand1 = Vec::new(&env);
-            view.hand2 = Vec::new(&env);
-            view.draw_pile = Vec::new(&env);
-            Ok(view)
-        } else {
-            Ok(game)
+            return Err(CangkulanError::GameNotFinished);
+        }
+        Ok(game)
+    }
+
+    /// Get the ledger deadline of an active game for client-side clocks.
+    ///
+    /// Returns `(deadline_ledger, current_ledger, (lifecycle_state, trick_state))`;
+    /// the state pair tells the UI whose move the clock is running for.
+    /// `None` if the game is unknown, finished, or its clock has not started.
+    pub fn get_deadline(env: Env, session_id: u32) -> Option<(u32, u32, (u32, u32))> {
+        let game = Self::read_game(&env, session_id).ok()?;
+        if game.lifecycle_state == STATE_FINISHED {
+            return None;
         }
+        let deadline_ledger = game.deadline_ledger?;
+        Some((
+            deadline_ledger,
+            env.ledger().sequence(),
+            (game.lifecycle_state, game.trick_state),
+        ))
     }
 
     /// Get full raw game state (admin-only). Requires admin authentication.
@@ -1196,10 +2638,10 @@ impl CangkulanContract {
     }
 
     /// Get game state with privacy: only the viewer's own hand is visible.
-    /// The opponent's hand is redacted (empty) to prevent casual snooping
-    /// via RPC queries. Non-players see both hands redacted.
-    /// During the reveal phase, the opponent's trick_card is also redacted
-    /// (it only becomes visible after both players have revealed).
+    /// Opponents' hands are redacted (empty) to prevent casual snooping
+    /// via RPC queries. Non-players see every hand redacted.
+    /// During the reveal phase, opponents' trick_cards are also redacted
+    /// until the viewer has revealed too.
     pub fn get_game_view(
         env: Env,
         session_id: u32,
@@ -1207,85 +2649,433 @@ impl CangkulanContract {
     ) -> Result<CangkulanGame, CangkulanError> {
         let game = Self::read_game(&env, session_id)?;
         let mut view = game;
-        match Self::resolve_slot(&view, &viewer) {
-            Ok(PLAYER_1) => {
-                view.hand2 = Vec::new(&env);
-                // Redact P2's trick_card during reveal phase (P2 revealed but P1 hasn't yet)
-                if view.trick_state == TRICK_REVEAL_WAIT_P1 {
-                    view.trick_card2 = None;
-                }
+        let viewer_slot = Self::resolve_slot(&view, &viewer).ok();
+        // Cards revealed by others stay hidden until the viewer has revealed
+        let hide_cards = viewer_slot.is_some_and(|slot| {
+            Self::is_reveal_state(view.trick_state) && !view.seat(slot).play_revealed
+        });
+        for slot in 1..=view.players.len() {
+            if viewer_slot == Some(slot) {
+                continue;
             }
-            Ok(_) => {
-                view.hand1 = Vec::new(&env);
-                // Redact P1's trick_card during reveal phase (P1 revealed but P2 hasn't yet)
-                if view.trick_state == TRICK_REVEAL_WAIT_P2 {
-                    view.trick_card1 = None;
+            let mut seat = view.seat(slot);
+            seat.hand = Vec::new(&env);
+            if hide_cards {
+                seat.trick_card = None;
+            }
+            view.set_seat(slot, seat);
+        }
+        Ok(view)
+    }
+
+    /// The viewer's playable cards for the current trick and the call
+    /// expected from them next, so frontends need not re-derive the rules.
+    /// Like `get_game_view` it needs no auth and shows only the viewer's
+    /// own hand. Fails with `NotAPlayer` for a non-player and `WrongPhase`
+    /// outside the playing phase.
+    pub fn get_valid_actions(
+        env: Env,
+        session_id: u32,
+        viewer: Address,
+    ) -> Result<ValidActions, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        let slot = Self::resolve_slot(&game, &viewer)?;
+        if game.lifecycle_state != STATE_PLAYING {
+            return Err(CangkulanError::WrongPhase);
+        }
+
+        let seat = game.seat(slot);
+        let mut playable = Vec::new(&env);
+        if let Some(trick_suit) = game.trick_suit {
+            for card in seat.hand.iter() {
+                if card / game.config.cards_per_suit == trick_suit {
+                    playable.push_back(card);
                 }
             }
-            Err(_) => {
-                view.hand1 = Vec::new(&env);
-                view.hand2 = Vec::new(&env);
+        }
+        let cangkul = playable.is_empty();
+        if cangkul {
+            playable.push_back(CANNOT_FOLLOW_SENTINEL);
+        }
+
+        let next_call = if Self::require_commit_phase(&game, slot).is_ok() && seat.play_commit.is_none() {
+            match (game.config.zk_required, cangkul) {
+                (false, _) => "commit_play",
+                (true, false) => "commit_play_zk",
+                (true, true) => "commit_cangkul_zk",
+            }
+        } else if Self::require_reveal_phase(&game, slot).is_ok() && !seat.play_revealed {
+            "reveal_play"
+        } else {
+            "wait"
+        };
+        Ok(ValidActions { playable, next_call: Symbol::new(&env, next_call), nonce: game.action_nonce })
+    }
+
+    /// Sessions `player` is seated in that have not finished, oldest first,
+    /// so a returning player can find the games waiting on them. Games that
+    /// expired unfinished are left out.
+    pub fn get_active_games(env: Env, player: Address) -> Vec<u32> {
+        let mut active = Vec::new(&env);
+        for session_id in Self::read_active_games(&env, &player).iter() {
+            if env.storage().temporary().has(&StorageKey::Game(session_id)) {
+                active.push_back(session_id);
             }
         }
-        Ok(view)
+        active
+    }
+
+    /// Get a page of a player's game history, oldest first, with outcome
+    /// from the player's perspective: 1 = win, 2 = loss, 3 = draw.
+    /// `cursor` is a position in the whole history (0 = oldest game), and
+    /// `opponent` and `outcome` keep only matching games. A call returns
+    /// at most `limit` games, capped at `MAX_HISTORY_PAGE`, and reads at
+    /// most `MAX_HISTORY_SCAN_CHUNKS` chunks, so a filtered page can come
+    /// back short or empty with a `next_cursor` to continue from. Every
+    /// chunk read has its TTL extended; chunks nobody reads or writes for
+    /// the history TTL expire and read as empty.
+    pub fn get_player_history(
+        env: Env,
+        player: Address,
+        cursor: u32,
+        limit: u32,
+        opponent: Option<Address>,
+        outcome: Option<u32>,
+    ) -> HistoryPage {
+        let mut entries = Vec::new(&env);
+        let limit = limit.min(MAX_HISTORY_PAGE);
+        let len = Self::get_history_len(env.clone(), player.clone());
+        if limit == 0 || cursor >= len {
+            return HistoryPage { entries, next_cursor: None };
+        }
+        let first = cursor / HISTORY_CHUNK_LEN;
+        let end = len.div_ceil(HISTORY_CHUNK_LEN).min(first + MAX_HISTORY_SCAN_CHUNKS);
+        for chunk in first..end {
+            let base = chunk * HISTORY_CHUNK_LEN;
+            let history = Self::read_history_chunk(&env, &player, chunk);
+            Self::extend_history_chunk(&env, &player, chunk);
+            for (i, entry) in history.iter().enumerate() {
+                let position = base + i as u32;
+                if position < cursor
+                    || opponent.as_ref().is_some_and(|o| *o != entry.opponent)
+                    || outcome.is_some_and(|o| o != entry.outcome)
+                {
+                    continue;
+                }
+                entries.push_back(entry);
+                if entries.len() == limit {
+                    let next_cursor = Some(position + 1).filter(|next| *next < len);
+                    return HistoryPage { entries, next_cursor };
+                }
+            }
+        }
+        let next_cursor = Some(end * HISTORY_CHUNK_LEN).filter(|next| *next < len);
+        HistoryPage { entries, next_cursor }
+    }
+
+    /// Number of games recorded in a player's history.
+    pub fn get_history_len(env: Env, player: Address) -> u32 {
+        let storage = env.storage().persistent();
+        storage.get(&StorageKey::HistoryLen(player.clone())).unwrap_or_else(|| {
+            storage
+                .get::<_, Vec<GameSummary>>(&StorageKey::PlayerHistory(player))
+                .map_or(0, |legacy| legacy.len())
+        })
     }
 
-    /// Get a player's game history (up to 50 most recent games).
-    /// Returns a Vec of GameSummary with outcome from the player's perspective:
-    ///   1 = win, 2 = loss, 3 = draw
-    pub fn get_player_history(
-        env: Env,
-        player: Address,
-    ) -> Vec<GameSummary> {
-        let key = StorageKey::PlayerHistory(player);
+    /// Every resolved trick of a session, oldest first, unlike the game's
+    /// `trick_log`, which keeps only the last `TRICK_LOG_LEN`. Empty before
+    /// the first trick, or once the entry has expired with the game.
+    pub fn get_trick_history(env: Env, session_id: u32) -> Vec<TrickRecord> {
         env.storage()
-            .persistent()
-            .get(&key)
+            .temporary()
+            .get(&StorageKey::TrickHistory(session_id))
             .unwrap_or_else(|| Vec::new(&env))
     }
 
-    /// Recompute and return the full shuffled deck order from ZK-verified seed hashes.
+    /// Recheck `player`'s ZK card plays once the game is finished. Each
+    /// play's Pedersen commitment is recomputed from the card the trick
+    /// history shows for the player's seat and the matching entry of
+    /// `blindings`, one per resolved play in order. A play whose trick never
+    /// resolved, because the game ended first, was never revealed and is
+    /// skipped. Anyone may call this. Returns whether every commitment
+    /// opened.
+    pub fn audit_reveal(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        blindings: Vec<BytesN<32>>,
+    ) -> Result<bool, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        if game.lifecycle_state != STATE_FINISHED {
+            return Err(CangkulanError::GameNotFinished);
+        }
+        let slot = Self::resolve_slot(&game, &player)?;
+        let history = Self::get_trick_history(env.clone(), session_id);
+        let committed: Vec<(u32, BytesN<32>)> = env
+            .storage()
+            .temporary()
+            .get(&StorageKey::ZkPlayCommits(session_id, slot))
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut plays = Vec::new(&env);
+        for play in committed.iter().filter(|(trick, _)| *trick < history.len()) {
+            plays.push_back(play);
+        }
+        if blindings.len() != plays.len() {
+            return Err(CangkulanError::AuditBlindingCount);
+        }
+
+        let valid = plays.iter().zip(blindings.iter()).all(|((trick, commit), blinding)| {
+            history
+                .get(trick)
+                .and_then(|record| record.cards.get(slot - 1))
+                .filter(|card| *card != CANNOT_FOLLOW_SENTINEL)
+                .is_some_and(|card| Self::compute_pedersen_commit_hash(env.clone(), card, blinding) == commit)
+        });
+
+        EvRevealAudited { session_id, player, plays: plays.len(), valid }.publish(&env);
+        Ok(valid)
+    }
+
+    /// Dispute the deal of a game in play: the card dealt at `index` of the
+    /// deck differs from `verify_shuffle` at the same index. The contract
+    /// deals, so no seat is at fault. A proven mismatch cancels the game,
+    /// refunding every stake, and pauses the contract, since the running
+    /// code no longer deals what it verifies. A claim that does not hold is
+    /// rejected with `ShuffleDisputeRejected`.
+    pub fn dispute_shuffle(env: Env, session_id: u32, player: Address, index: u32) -> Result<(), CangkulanError> {
+        player.require_auth();
+        let mut game = Self::read_game(&env, session_id)?;
+        Self::resolve_slot(&game, &player)?;
+        if game.lifecycle_state != STATE_PLAYING {
+            return Err(CangkulanError::WrongPhase);
+        }
+
+        let dealt: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&StorageKey::DealtDeck(session_id))
+            .ok_or(CangkulanError::ShuffleDisputeRejected)?;
+        let shuffled = Self::verify_shuffle(env.clone(), session_id)?;
+        let (dealt, shuffled) = match (dealt.get(index), shuffled.get(index)) {
+            (Some(dealt), Some(shuffled)) if dealt != shuffled => (dealt, shuffled),
+            _ => return Err(CangkulanError::ShuffleDisputeRejected),
+        };
+
+        Self::finalize_game(&env, session_id, &mut game, OUTCOME_CANCELLED)?;
+        Self::write_game(&env, session_id, &mut game);
+        EvShuffleDisputed { session_id, player, index, dealt, shuffled }.publish(&env);
+        EvGameCancelled { session_id, reason: Symbol::new(&env, "shuffle") }.publish(&env);
+
+        env.storage().instance().set(&StorageKey::Paused, &true);
+        EvPauseSet { paused: true }.publish(&env);
+        Ok(())
+    }
+
+    /// Recompute and return the shuffled deck order from ZK-verified seed hashes:
+    /// `GameConfig::deck_size` cards, 36 under standard rules.
     /// Anyone can call this to independently verify the shuffle was fair. The
     /// seed hashes are `keccak256(raw_seed)` — the raw seeds never appear on-chain.
-    /// Only available after both seeds have been revealed (PLAYING or FINISHED).
+    /// Only available after every seed has been revealed (PLAYING or FINISHED).
     pub fn verify_shuffle(env: Env, session_id: u32) -> Result<Vec<u32>, CangkulanError> {
         let game = Self::read_game(&env, session_id)?;
         if game.lifecycle_state < STATE_PLAYING {
             return Err(CangkulanError::WrongPhase);
         }
 
-        let sh1 = game.seed_hash1.clone().ok_or(CangkulanError::MissingCommit)?;
-        let sh2 = game.seed_hash2.clone().ok_or(CangkulanError::MissingCommit)?;
+        let seed_hashes = Self::seed_hashes(&env, &game).ok_or(CangkulanError::MissingCommit)?;
+        Ok(Self::game_deck(&env, &game.config, &deal_seed_n(&env, &seed_hashes, session_id)))
+    }
 
-        let mut seed_data = Bytes::from_array(&env, &sh1.to_array());
-        seed_data.append(&Bytes::from_array(&env, &sh2.to_array()));
-        seed_data.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        let seed_hash = env.crypto().keccak256(&seed_data);
-        env.prng().seed(seed_hash.into());
+    /// Canonical serialization of a finished game, for NFT metadata or
+    /// off-chain archives (layout in `SUMMARY_VERSION`).
+    ///
+    /// Its keccak256 is recorded when the game ends and stays readable via
+    /// `get_summary_hash` after the game itself expires, so an archived blob
+    /// can be authenticated later.
+    pub fn export_summary(env: Env, session_id: u32) -> Result<Bytes, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        if game.lifecycle_state != STATE_FINISHED {
+            return Err(CangkulanError::GameNotFinished);
+        }
+        Ok(Self::summary_blob(&env, session_id, &game))
+    }
 
-        let mut deck: [u32; 36] = [0; 36];
-        let mut i: u32 = 0;
-        while i < DECK_SIZE {
-            deck[i as usize] = i;
-            i += 1;
+    /// Everything needed to replay a finished game off-chain, in the
+    /// `REPLAY_VERSION` layout: the seed hashes, the shuffled deck, every
+    /// trick in order and the result. Check it with `verify_replay`.
+    pub fn export_replay(env: Env, session_id: u32) -> Result<Bytes, CangkulanError> {
+        let game = Self::read_game(&env, session_id)?;
+        if game.lifecycle_state != STATE_FINISHED {
+            return Err(CangkulanError::GameNotFinished);
+        }
+        let seed_hashes = Self::seed_hashes(&env, &game).ok_or(CangkulanError::MissingCommit)?;
+        let config = &game.config;
+        let mut blob = Bytes::new(&env);
+        for word in [
+            REPLAY_VERSION,
+            session_id,
+            game.players.len(),
+            config.cards_per_suit,
+            config.deck_size,
+            config.hand_size,
+            config.penalty_draw,
+            config.draw_until_follow as u32,
+            config.tie_break,
+            config.teams as u32,
+            game.max_hand_size,
+            game.overflow_rule,
+        ] {
+            blob.extend_from_array(&word.to_be_bytes());
+        }
+        for seed_hash in seed_hashes.iter() {
+            blob.extend_from_array(&seed_hash.to_array());
+        }
+        for card in Self::game_deck(&env, config, &deal_seed_n(&env, &seed_hashes, session_id)) {
+            blob.extend_from_array(&card.to_be_bytes());
+        }
+        let history = Self::get_trick_history(env.clone(), session_id);
+        blob.extend_from_array(&history.len().to_be_bytes());
+        for record in history.iter() {
+            blob.append(&Self::trick_entry(&env, &record));
         }
+        blob.extend_from_array(&game.outcome.to_be_bytes());
+        for seat in game.players.iter() {
+            blob.extend_from_array(&seat.tricks_won.to_be_bytes());
+        }
+        blob.extend_from_array(&game.transcript.to_array());
+        Ok(blob)
+    }
+
+    /// Check an `export_replay` blob on its own, reading no game state, by
+    /// replaying the rules: the deck must be the shuffle of its seed hashes
+    /// and is dealt as the contract deals it; each trick must flip the next
+    /// card, every played card must be in its seat's hand and follow suit,
+    /// a seat may only pass when it holds none of the suit, and the
+    /// recorded winner and penalty draws must follow. The tricks must chain
+    /// from the deal seed into its transcript, each seat's tricks won must
+    /// match the replay, and the outcome must be the one the last trick
+    /// ends the game with. A game that ended off the board (timeout,
+    /// forfeit, cancel, channel settlement) only needs some recorded
+    /// outcome, since those moves are not in the blob. Fails with
+    /// `MalformedReplay` if the blob does not parse.
+    pub fn verify_replay(env: Env, replay: Bytes) -> Result<bool, CangkulanError> {
+        let word = |at: u32| -> Result<u32, CangkulanError> {
+            let mut buf = [0u8; 4];
+            if at + 4 > replay.len() {
+                return Err(CangkulanError::MalformedReplay);
+            }
+            replay.slice(at..at + 4).copy_into_slice(&mut buf);
+            Ok(u32::from_be_bytes(buf))
+        };
+        let hash = |at: u32| -> Result<BytesN<32>, CangkulanError> {
+            if at + 32 > replay.len() {
+                return Err(CangkulanError::MalformedReplay);
+            }
+            replay.slice(at..at + 32).try_into().map_err(|_| CangkulanError::MalformedReplay)
+        };
 
-        let mut idx = DECK_SIZE;
-        while idx > 1 {
-            idx -= 1;
-            let j = env.prng().gen_range::<u64>(0..=(idx as u64)) as u32;
-            let tmp = deck[idx as usize];
-            deck[idx as usize] = deck[j as usize];
-            deck[j as usize] = tmp;
+        let (version, session_id, seats) = (word(0)?, word(4)?, word(8)?);
+        let (draw_until_follow, teams) = (word(28)?, word(36)?);
+        let hand_cap = HandCapConfig { max_hand_size: word(40)?, overflow_rule: word(44)? };
+        let config = GameConfig {
+            cards_per_suit: word(12)?,
+            deck_size: word(16)?,
+            hand_size: word(20)?,
+            penalty_draw: word(24)?,
+            draw_until_follow: draw_until_follow == 1,
+            tie_break: word(32)?,
+            teams: teams == 1,
+            ..GameConfig::standard()
+        };
+        if version != REPLAY_VERSION
+            || !(MIN_PLAYERS..=MAX_PLAYERS).contains(&seats)
+            || draw_until_follow > 1
+            || teams > 1
+            || hand_cap.overflow_rule > OVERFLOW_INSTANT_LOSS
+            || !config.is_valid(seats, hand_cap.max_hand_size)
+        {
+            return Err(CangkulanError::MalformedReplay);
+        }
+        let mut at = 48;
+        let mut seed_hashes = Vec::new(&env);
+        for _ in 0..seats {
+            seed_hashes.push_back(hash(at)?);
+            at += 32;
+        }
+        let seed = deal_seed_n(&env, &seed_hashes, session_id);
+        let deck = Self::game_deck(&env, &config, &seed);
+        let mut valid = true;
+        for card in deck.iter() {
+            valid &= word(at)? == card;
+            at += 4;
         }
 
-        let mut result = Vec::new(&env);
-        let mut d: u32 = 0;
-        while d < DECK_SIZE {
-            result.push_back(deck[d as usize]);
-            d += 1;
+        // Seats are placeholders: the rules never look at who sits where
+        let contract = env.current_contract_address();
+        let mut players = Vec::new(&env);
+        let mut points = Vec::new(&env);
+        for _ in 0..seats {
+            players.push_back(contract.clone());
+            points.push_back(0i128);
+        }
+        let mut game = Self::new_game(&env, &players, &points, config, hand_cap, contract);
+        Self::deal(&mut game, &deck);
+        Self::flip_next_card(&env, &mut game);
+
+        let mut transcript = Self::chain_entry(&env, &BytesN::from_array(&env, &[0u8; 32]), &seed.into());
+        let mut ended: Option<Outcome> = None;
+        let tricks = word(at)?;
+        at += 4;
+        for _ in 0..tricks {
+            let entry_len = 4 * (seats + 2);
+            let mut cards = Vec::new(&env);
+            for seat in 0..seats {
+                cards.push_back(word(at + 4 * (seat + 1))?);
+            }
+            let winner = word(at + entry_len - 4)?;
+            if winner > seats {
+                return Err(CangkulanError::MalformedReplay);
+            }
+            if valid && ended.is_none() {
+                match Self::replay_trick(&env, &mut game, word(at)?, &cards, winner) {
+                    Some(capped) => {
+                        ended = Self::trick_end_outcome(&game, capped);
+                        if ended.is_none() {
+                            Self::flip_next_card(&env, &mut game);
+                        }
+                    }
+                    None => valid = false,
+                }
+            } else {
+                // Tricks past the end of the game, or past a broken one
+                valid = false;
+            }
+            transcript = Self::chain_entry(&env, &transcript, &replay.slice(at..at + entry_len));
+            at += entry_len;
+        }
+        let outcome = word(at)?;
+        valid &= match ended {
+            Some(replayed) => outcome == replayed,
+            None => outcome != OUTCOME_UNRESOLVED,
+        };
+        at += 4;
+        for seat in game.players.iter() {
+            valid &= word(at)? == seat.tricks_won;
+            at += 4;
+        }
+        valid &= hash(at)? == transcript;
+        if at + 32 != replay.len() {
+            return Err(CangkulanError::MalformedReplay);
         }
-        Ok(result)
+        Ok(valid)
+    }
+
+    /// Get the recorded keccak256 of a finished game's summary blob.
+    pub fn get_summary_hash(env: Env, session_id: u32) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::SummaryHash(session_id))
     }
 
     pub fn get_admin(env: Env) -> Result<Address, CangkulanError> {
@@ -1327,32 +3117,283 @@ impl CangkulanContract {
         Ok(())
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CangkulanError> {
+    /// Verifiers serving a single proof mode instead of the default one,
+    /// keyed by mode.
+    pub fn get_mode_verifiers(env: Env) -> Map<u32, Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::ModeVerifiers)
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Send proofs of `mode` to `verifier` in games started from now on.
+    /// `mode` is one of `ROUTABLE_PROOF_MODES`.
+    pub fn set_verifier_for_mode(env: Env, mode: u32, verifier: Address) -> Result<(), CangkulanError> {
         let admin = Self::load_admin(&env)?;
         admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        if !ROUTABLE_PROOF_MODES.contains(&mode) {
+            return Err(CangkulanError::UnroutableProofMode);
+        }
+        let mut routes = Self::get_mode_verifiers(env.clone());
+        routes.set(mode, verifier);
+        env.storage().instance().set(&StorageKey::ModeVerifiers, &routes);
+        Ok(())
+    }
+
+    /// Send proofs of `mode` back to the default verifier in new games.
+    pub fn clear_verifier_for_mode(env: Env, mode: u32) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        let mut routes = Self::get_mode_verifiers(env.clone());
+        routes.remove(mode);
+        env.storage().instance().set(&StorageKey::ModeVerifiers, &routes);
         Ok(())
     }
 
-    /// Get the UltraHonk verifier contract address (Noir proof verifier).
-    pub fn get_ultrahonk_verifier(env: Env) -> Result<Address, CangkulanError> {
-        Self::load_ultrahonk_verifier(&env)
+    pub fn get_hand_cap(env: Env) -> HandCapConfig {
+        Self::load_hand_cap(&env)
     }
 
-    /// Set the UltraHonk verifier contract address (admin only).
-    /// This enables optional Noir UltraKeccakHonk proof verification.
-    pub fn set_ultrahonk_verifier(
+    /// Set the hand-size cap and overflow rule for games started from now on.
+    /// The cap must be at least the deal size.
+    pub fn set_hand_cap(
         env: Env,
-        verifier_addr: Address,
+        max_hand_size: u32,
+        overflow_rule: u32,
     ) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if max_hand_size < HAND_SIZE
+            || (overflow_rule != OVERFLOW_SKIP_PENALTY && overflow_rule != OVERFLOW_INSTANT_LOSS)
+        {
+            return Err(CangkulanError::InvalidHandCap);
+        }
+        env.storage().instance().set(
+            &StorageKey::HandCap,
+            &HandCapConfig { max_hand_size, overflow_rule },
+        );
+        Ok(())
+    }
+
+    pub fn get_stake_escrow(env: Env) -> Option<StakeEscrow> {
+        env.storage().instance().get(&StorageKey::StakeEscrow)
+    }
+
+    /// Protocol fee on won pots, in basis points: the escrow's rake, or 0
+    /// while stakes are not escrowed.
+    pub fn get_fee(env: Env) -> u32 {
+        Self::get_stake_escrow(env).map_or(0, |escrow| escrow.rake_bps)
+    }
+
+    /// Change the escrow's rake for games started from now on, keeping its
+    /// token and treasury. At most `MAX_RAKE_BPS`; needs an escrow.
+    pub fn set_fee(env: Env, fee_bps: u32) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        let mut escrow = Self::get_stake_escrow(env.clone()).ok_or(CangkulanError::InvalidStakeEscrow)?;
+        if fee_bps > MAX_RAKE_BPS {
+            return Err(CangkulanError::InvalidStakeEscrow);
+        }
+        escrow.rake_bps = fee_bps;
+        env.storage().instance().set(&StorageKey::StakeEscrow, &escrow);
+        EvFeeSet { fee_bps }.publish(&env);
+        Ok(())
+    }
+
+    /// Escrow stakes in a token for games started from now on, or stop
+    /// escrowing with `None`. The rake is at most `MAX_RAKE_BPS`.
+    pub fn set_stake_escrow(env: Env, escrow: Option<StakeEscrow>) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        match escrow {
+            Some(escrow) => {
+                if escrow.rake_bps > MAX_RAKE_BPS {
+                    return Err(CangkulanError::InvalidStakeEscrow);
+                }
+                env.storage().instance().set(&StorageKey::StakeEscrow, &escrow);
+            }
+            None => env.storage().instance().remove(&StorageKey::StakeEscrow),
+        }
+        Ok(())
+    }
+
+    pub fn get_entropy_policy(env: Env) -> EntropyPolicy {
+        Self::load_entropy_policy(&env)
+    }
+
+    /// Set the entropy policy that revealed seed hashes must satisfy.
+    /// Applies to every reveal from now on, including games in progress.
+    pub fn set_entropy_policy(env: Env, policy: EntropyPolicy) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if !policy.is_valid() {
+            return Err(CangkulanError::InvalidEntropyPolicy);
+        }
+        env.storage().instance().set(&StorageKey::EntropyPolicy, &policy);
+        Ok(())
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Admin: Circuit breaker
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Pause or resume the contract, for incident response. While paused,
+    /// new games, lobby challenges and commits fail with `ContractPaused`;
+    /// reveals, timeouts, forfeits, forced wins and claims still work, so
+    /// games in flight can be wound down.
+    pub fn set_paused(env: Env, paused: bool) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.storage().instance().set(&StorageKey::Paused, &paused);
+        EvPauseSet { paused }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&StorageKey::Paused).unwrap_or(false)
+    }
+
+    /// End a live session with `OUTCOME_CANCELLED`, for sessions stranded
+    /// by a misconfigured hub or verifier. Every seat gets its stake back
+    /// and nothing goes to the Game Hub, which only takes a winner, or the
+    /// Leaderboard.
+    pub fn cancel_game(env: Env, session_id: u32, reason: Symbol) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+
+        let mut game = Self::read_game(&env, session_id)?;
+        Self::require_active(&game)?;
+        Self::finalize_game(&env, session_id, &mut game, OUTCOME_CANCELLED)?;
+        Self::write_game(&env, session_id, &mut game);
+
+        EvGameCancelled { session_id, reason }.publish(&env);
+        Ok(())
+    }
+
+    // ───────────────────────────────────────────────────────────────────────────
+    //  Admin: Emergency verifier override
+    // ───────────────────────────────────────────────────────────────────────────
+
+    /// Schedule an emergency override of the ZK verifier, for incidents
+    /// where a broken verifier would otherwise strand every game in progress.
+    ///
+    /// Nothing changes until `execute_after_timelock` is called at least
+    /// `OVERRIDE_TIMELOCK_LEDGERS` (~24h) later, which gives players and
+    /// indexers watching `EvVerifierOverrideScheduled` time to react. Use a
+    /// multisig account as admin so no single key can trigger this.
+    /// Rescheduling restarts the timelock. Returns the executable ledger.
+    pub fn schedule_verifier_override(
+        env: Env,
+        duration_ledgers: u32,
+    ) -> Result<u32, CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if duration_ledgers == 0 || duration_ledgers > MAX_OVERRIDE_LEDGERS {
+            return Err(CangkulanError::InvalidOverrideDuration);
+        }
+        let executable_ledger = env.ledger().sequence().saturating_add(OVERRIDE_TIMELOCK_LEDGERS);
+        env.storage().instance().set(
+            &StorageKey::PendingVerifierOverride,
+            &PendingVerifierOverride { executable_ledger, duration_ledgers },
+        );
+        EvVerifierOverrideScheduled { executable_ledger, duration_ledgers }.publish(&env);
+        Ok(executable_ledger)
+    }
+
+    /// Activate a scheduled override once its timelock has elapsed.
+    ///
+    /// Until the returned expiry ledger, ZK verifier calls are skipped (each
+    /// skip emits `EvVerifierBypassed`) while the checks the contract can do
+    /// itself still apply: NIZK seed reveals must open their hash commitment,
+    /// Pedersen seed reveals must match `keccak256(C)`, and card reveals are
+    /// still opened and rule-checked in `reveal_play`. Noir proofs are
+    /// unaffected. The override lapses on its own.
+    pub fn execute_after_timelock(env: Env) -> Result<u32, CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        let pending: PendingVerifierOverride = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PendingVerifierOverride)
+            .ok_or(CangkulanError::OverrideNotScheduled)?;
+        let now = env.ledger().sequence();
+        if now < pending.executable_ledger {
+            return Err(CangkulanError::TimelockNotElapsed);
+        }
+        let expires_ledger = now.saturating_add(pending.duration_ledgers);
+        env.storage().instance().remove(&StorageKey::PendingVerifierOverride);
+        env.storage().instance().set(&StorageKey::VerifierOverride, &expires_ledger);
+        EvVerifierOverrideActivated { expires_ledger }.publish(&env);
+        Ok(expires_ledger)
+    }
+
+    /// Drop a scheduled override and end an active one immediately.
+    pub fn cancel_verifier_override(env: Env) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        let was_active = Self::active_verifier_override(&env).is_some();
+        env.storage().instance().remove(&StorageKey::PendingVerifierOverride);
+        env.storage().instance().remove(&StorageKey::VerifierOverride);
+        EvVerifierOverrideCancelled { was_active }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_pending_verifier_override(env: Env) -> Option<PendingVerifierOverride> {
+        env.storage().instance().get(&StorageKey::PendingVerifierOverride)
+    }
+
+    /// Expiry ledger of the active override, or `None` when proofs are
+    /// verified normally.
+    pub fn get_verifier_override(env: Env) -> Option<u32> {
+        Self::active_verifier_override(&env)
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CangkulanError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Rewrite a session stored under an older `CangkulanGame` layout in
+    /// the current one. Every read already migrates in memory, so this only
+    /// saves redoing it; anyone may call it. Leaves the deadline and TTL
+    /// alone. Returns the version the session was stored under.
+    pub fn migrate_game(env: Env, session_id: u32) -> Result<u32, CangkulanError> {
+        let (game, stored) = Self::read_stored_game(&env, session_id)?;
+        if stored < GAME_SCHEMA_VERSION {
+            env.storage().temporary().set(&StorageKey::Game(session_id), &game);
+        }
+        Ok(stored)
+    }
+
+    /// Get the Leaderboard contract address, if configured.
+    pub fn get_leaderboard(env: Env) -> Option<Address> {
+        Self::load_leaderboard(&env)
+    }
+
+    /// Set the Leaderboard contract address (admin only).
+    pub fn set_leaderboard(env: Env, leaderboard: Address) -> Result<(), CangkulanError> {
         let admin = Self::load_admin(&env)?;
         admin.require_auth();
         env.storage()
             .instance()
-            .set(&StorageKey::UltraHonkVerifierAddress, &verifier_addr);
+            .set(&StorageKey::LeaderboardAddress, &leaderboard);
         Ok(())
     }
 
+    /// Resolve every dependent contract address and the rule config hash in
+    /// one call, so clients need a single simulation before building a proof.
+    pub fn get_integrations(env: Env) -> Result<Integrations, CangkulanError> {
+        Ok(Integrations {
+            hub: Self::load_hub(&env)?,
+            verifier: Self::load_verifier(&env)?,
+            mode_verifiers: Self::get_mode_verifiers(env.clone()),
+            leaderboard: Self::load_leaderboard(&env),
+            config_hash: Self::config_hash(&env),
+        })
+    }
+
     // ───────────────────────────────────────────────────────────────────────────
     //  Public: Split Noir Verification (budget-friendly two-TX flow)
     // ───────────────────────────────────────────────────────────────────────────
@@ -1361,7 +3402,7 @@ impl CangkulanContract {
     /// transaction** from `reveal_seed`.  This splits the ~260M CPU cost
     /// into two transactions that each fit within Soroban limits:
     ///
-    ///   TX 1: `verify_noir_seed` → UltraHonk verification (~215M CPU)
+    ///   TX 1: `verify_noir_seed` → UltraHonk verification via the ZK verifier (~215M CPU)
     ///   TX 2: `reveal_seed` with an empty proof → game logic (~50M CPU)
     ///
     /// The verified flag is stored in temporary storage and consumed by
@@ -1371,7 +3412,7 @@ impl CangkulanContract {
     /// * `session_id` - game session id
     /// * `player` - the player revealing (requires auth)
     /// * `seed_hash` - blake2s(seed) — the Noir public input
-    /// * `proof` - raw UltraKeccakHonk proof (>4KB)
+    /// * `proof` - raw UltraKeccakHonk proof (> `ULTRAHONK_MIN_PROOF_LEN` bytes)
     pub fn verify_noir_seed(
         env: Env,
         session_id: u32,
@@ -1379,7 +3420,7 @@ impl CangkulanContract {
         seed_hash: BytesN<32>,
         proof: Bytes,
     ) -> Result<(), CangkulanError> {
-        player.require_auth();
+        Self::require_play_auth(&env, session_id, &player);
 
         // Validate game is in reveal phase
         let game = Self::read_game(&env, session_id)?;
@@ -1387,29 +3428,21 @@ impl CangkulanContract {
             return Err(CangkulanError::WrongPhase);
         }
         let slot = Self::resolve_slot(&game, &player)?;
+        let seat = game.seat(slot);
 
         // Check not already revealed
-        match slot {
-            PLAYER_1 => {
-                if game.seed_revealed1 {
-                    return Err(CangkulanError::RevealAlreadySubmitted);
-                }
-            }
-            _ => {
-                if game.seed_revealed2 {
-                    return Err(CangkulanError::RevealAlreadySubmitted);
-                }
-            }
+        if seat.seed_revealed {
+            return Err(CangkulanError::RevealAlreadySubmitted);
         }
 
         // Entropy check
-        Self::check_seed_entropy(&seed_hash)?;
+        Self::check_seed_entropy(&env, &seed_hash)?;
+
+        // Only a player who declared Noir at commit may reveal with it
+        Self::check_seed_proof_mode(&game, slot, Some(SEED_PROOF_NOIR))?;
 
         // Commit binding: commit_hash = keccak256(seed_hash) for Noir mode
-        let commit_hash = match slot {
-            PLAYER_1 => game.seed_commit1.clone().ok_or(CangkulanError::MissingCommit)?,
-            _ => game.seed_commit2.clone().ok_or(CangkulanError::MissingCommit)?,
-        };
+        let commit_hash = seat.seed_commit.ok_or(CangkulanError::MissingCommit)?;
         let expected_commit: BytesN<32> = env
             .crypto()
             .keccak256(&Bytes::from_array(&env, &seed_hash.to_array()))
@@ -1418,28 +3451,13 @@ impl CangkulanContract {
             return Err(CangkulanError::CommitHashMismatch);
         }
 
-        // Proof must be >4000 bytes (Noir proof)
-        if proof.len() <= 4000 {
+        // Proof must be a Noir proof, or the verifier would pick another mode
+        if proof.len() <= spec::ULTRAHONK_MIN_PROOF_LEN {
             return Err(CangkulanError::InvalidZkProof);
         }
 
-        // Call UltraHonk verifier — the expensive part (~200M CPU)
-        let ultrahonk_addr = Self::load_ultrahonk_verifier(&env)?;
-        let ultrahonk = UltraHonkClient::new(&env, &ultrahonk_addr);
-
-        let seed_bytes = seed_hash.to_array();
-        let mut noir_public_inputs = Bytes::new(&env);
-        for byte_val in seed_bytes.iter() {
-            let mut k = 0u32;
-            while k < 31 {
-                noir_public_inputs.push_back(0u8);
-                k += 1;
-            }
-            noir_public_inputs.push_back(*byte_val);
-        }
-
-        // This panics on failure (UltraHonk contract behavior)
-        ultrahonk.verify_proof(&noir_public_inputs, &proof);
+        // The expensive part (~200M CPU)
+        Self::verify_noir(&env, &game, session_id, &player, &seed_hash, &proof)?;
 
         // Store verified flag in temporary storage
         let flag_key = StorageKey::NoirSeedVerified(session_id, slot);
@@ -1451,125 +3469,519 @@ impl CangkulanContract {
         Ok(())
     }
 
-    /// Get the RISC Zero verifier contract address (removed — Noir SNARK is the production path).
-    /// Kept as no-op for ABI compatibility with existing deployments.
-
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Internal: Finalization (single end_game call-site)
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    fn finalize_game(
+    // Get the RISC Zero verifier contract address (removed — Noir SNARK is the production path).
+    // Kept as no-op for ABI compatibility with existing deployments.
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Internal: Finalization (single end_game call-site)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    fn finalize_game(
+        env: &Env,
+        session_id: u32,
+        game: &mut CangkulanGame,
+        outcome: Outcome,
+    ) -> Result<(), CangkulanError> {
+        if game.lifecycle_state == STATE_FINISHED {
+            return Err(CangkulanError::GameAlreadyEnded);
+        }
+
+        // The Game Hub only tracks two sides: two players or two teams. It
+        // only takes a winner, so a cancelled session is not reported.
+        let hub_tracked = !game.vs_bot && Self::hub_tracked(game.players.len(), &game.config);
+        if hub_tracked && outcome != OUTCOME_CANCELLED {
+            let hub_addr = Self::load_hub(env)?;
+            let hub = GameHubClient::new(env, &hub_addr);
+
+            // Game Hub only supports bool (player1_won), so on DRAW we
+            // derive a fair coin-flip from the combined seed commitments
+            // (unbiasable — neither player controls the combined hash).
+            let player1_won = match outcome {
+                OUTCOME_PLAYER1_WIN => true,
+                OUTCOME_DRAW => match (game.seat(1).seed_commit, game.seat(2).seed_commit) {
+                    (Some(c1), Some(c2)) => {
+                        let mut tb = Bytes::from_array(env, &c1.to_array());
+                        tb.append(&Bytes::from_array(env, &c2.to_array()));
+                        let h = env.crypto().keccak256(&tb);
+                        h.to_array()[0].is_multiple_of(2)
+                    }
+                    _ => session_id.is_multiple_of(2),
+                },
+                _ => false,
+            };
+
+            // Game Hub lifecycle: end_game BEFORE finalizing state.
+            hub.end_game(&session_id, &player1_won);
+
+            EvHubEndReported {
+                session_id,
+                hub: hub_addr,
+                player1_won,
+            }.publish(env);
+
+            // Ratings are one-on-one, so team games are not rated
+            if let Some(leaderboard) = Self::load_leaderboard(env).filter(|_| game.players.len() == 2) {
+                Self::report_to_leaderboard(env, session_id, game, outcome, leaderboard);
+            }
+        }
+
+        EvGameEnded {
+            session_id,
+            outcome,
+        }.publish(env);
+
+        game.outcome = outcome;
+        game.lifecycle_state = STATE_FINISHED;
+        game.deadline_nonce = None;
+        game.deadline_ledger = None;
+
+        // Persist game summary to every player's history. A finished game
+        // takes no more moves, so session delegates are dropped too.
+        for slot in 1..=game.players.len() {
+            let player = game.seat(slot).player;
+            Self::save_player_history(env, session_id, game, slot);
+            Self::update_active_games(env, &player, session_id, false);
+            env.storage()
+                .temporary()
+                .remove(&StorageKey::SessionDelegate(session_id, player));
+        }
+
+        Self::record_pending_claim(env, session_id, game, outcome);
+        Self::record_summary_hash(env, session_id, game);
+
+        Ok(())
+    }
+
+    /// Record a two-seat result with the Leaderboard. A refusal is published
+    /// rather than raised, so a misconfigured Leaderboard cannot keep a
+    /// game from ending.
+    fn report_to_leaderboard(
+        env: &Env,
+        session_id: u32,
+        game: &CangkulanGame,
+        outcome: Outcome,
+        leaderboard: Address,
+    ) {
+        let mut code = match outcome {
+            OUTCOME_PLAYER1_WIN => 1,
+            OUTCOME_PLAYER2_WIN => 2,
+            _ => 3,
+        };
+        if Self::is_rage_quit(game) {
+            code |= LEADERBOARD_FORFEIT;
+        }
+        let result = LeaderboardClient::new(env, &leaderboard).try_record_match(
+            &env.current_contract_address(),
+            &game.seat(1).player,
+            &game.seat(2).player,
+            &code,
+        );
+        EvLeaderboardReported {
+            session_id,
+            leaderboard,
+            recorded: matches!(result, Ok(Ok(()))),
+        }.publish(env);
+    }
+
+    /// Whether the game is ending by a forfeit after the deal, which the
+    /// Leaderboard penalizes beyond a loss.
+    fn is_rage_quit(game: &CangkulanGame) -> bool {
+        game.forfeited_by != 0 && game.lifecycle_state == STATE_PLAYING
+    }
+
+    /// Transfer `stake` from `player` into the contract.
+    fn lock_stake(env: &Env, escrow: &StakeEscrow, player: &Address, stake: i128) {
+        if stake > 0 {
+            TokenClient::new(env, &escrow.token).transfer(
+                player,
+                env.current_contract_address(),
+                &stake,
+            );
+        }
+    }
+
+    /// Add `offer.players[index]` to a rematch offer, locking its stake in
+    /// an escrowed game, and start the rematch once every seat has agreed.
+    fn agree_rematch(
+        env: &Env,
+        session_id: u32,
+        index: u32,
+        mut offer: RematchOffer,
+    ) -> Result<(), CangkulanError> {
+        let player = offer.players.get_unchecked(index);
+        let escrow = escrow_terms(&offer.stake_token, offer.rake_bps, &offer.treasury);
+        if let Some(escrow) = &escrow {
+            Self::lock_stake(env, escrow, &player, offer.points.get_unchecked(index));
+        }
+        offer.agreed.push_back(player);
+
+        let key = StorageKey::Rematch(session_id);
+        if offer.agreed.len() < offer.players.len() {
+            env.storage().persistent().set(&key, &offer);
+            // Outlive the finished game so a late cancel can still refund the stakes
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, GAME_TTL_LEDGERS * 2, GAME_TTL_LEDGERS * 2);
+            return Ok(());
+        }
+
+        env.storage().persistent().remove(&key);
+        let started = StorageKey::RematchedAs(session_id);
+        env.storage().temporary().set(&started, &offer.rematch_id);
+        env.storage()
+            .temporary()
+            .extend_ttl(&started, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::open_session(
+            env,
+            offer.rematch_id,
+            offer.players,
+            offer.points,
+            escrow,
+            false,
+            offer.config,
+            false,
+        )?;
+        EvRematchStarted { session_id, rematch_id: offer.rematch_id }.publish(env);
+        Ok(())
+    }
+
+    /// Record the escrowed stakes owed for a finished game. The winner takes
+    /// every stake, less the rake; on a draw each player gets their own back.
+    /// Nothing is recorded for points-only games or when no stakes were put
+    /// up.
+    fn record_pending_claim(
         env: &Env,
         session_id: u32,
-        game: &mut CangkulanGame,
+        game: &CangkulanGame,
         outcome: Outcome,
-    ) -> Result<(), CangkulanError> {
-        if game.lifecycle_state == STATE_FINISHED {
-            return Err(CangkulanError::GameAlreadyEnded);
+    ) {
+        // Points-only stakes are settled by the hub; there is nothing to pay out
+        if game.stake_token.is_none() {
+            return;
+        }
+        let pot = game
+            .players
+            .iter()
+            .fold(0i128, |pot, seat| pot.saturating_add(seat.points));
+        let winners = Self::winning_slots(game, outcome);
+        let rake = if winners != 0 { pot * game.rake_bps as i128 / BPS_DENOMINATOR } else { 0 };
+
+        // Partners split a team's winnings; the odd unit goes to the lower seat
+        let mut payouts: Vec<Payout> = Vec::new(env);
+        let split = winners.count_ones().max(1) as i128;
+        let mut remainder = (pot - rake) % split;
+        for (i, seat) in game.players.iter().enumerate() {
+            let share = if winners & Self::slot_bit(i as u32 + 1) != 0 {
+                (pot - rake) / split + core::mem::take(&mut remainder)
+            } else if winners != 0 {
+                0
+            } else {
+                seat.points
+            };
+            if share > 0 {
+                payouts.push_back(Payout { player: seat.player, amount: share, claimed: false });
+            }
+        }
+        if payouts.is_empty() {
+            return;
         }
 
-        let hub_addr = Self::load_hub(env)?;
-        let hub = GameHubClient::new(env, &hub_addr);
-
-        // Game Hub only supports bool (player1_won), so on DRAW we
-        // derive a fair coin-flip from the combined seed commitments
-        // (unbiasable — neither player controls the combined hash).
-        let player1_won = match outcome {
-            OUTCOME_PLAYER1_WIN => true,
-            OUTCOME_DRAW => match (&game.seed_commit1, &game.seed_commit2) {
-                (Some(c1), Some(c2)) => {
-                    let mut tb = Bytes::from_array(env, &c1.to_array());
-                    tb.append(&Bytes::from_array(env, &c2.to_array()));
-                    let h = env.crypto().keccak256(&tb);
-                    h.to_array()[0] % 2 == 0
-                }
-                _ => session_id % 2 == 0,
-            },
-            _ => false,
+        let expires_ledger = env.ledger().sequence() + CLAIM_WINDOW_LEDGERS;
+        let key = StorageKey::PendingClaim(session_id);
+        let claim = PendingClaim {
+            payouts,
+            expires_ledger,
+            token: game.stake_token.clone(),
+            rake,
+            treasury: game.treasury.clone(),
         };
+        env.storage().persistent().set(&key, &claim);
+        // Keep the record alive past the window so expiry can be observed.
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, CLAIM_WINDOW_LEDGERS * 2, CLAIM_WINDOW_LEDGERS * 2);
 
-        // Game Hub lifecycle: end_game BEFORE finalizing state.
-        hub.end_game(&session_id, &player1_won);
-
-        EvHubEndReported {
-            session_id,
-            hub: hub_addr,
-            player1_won,
-        }.publish(&env);
-
-        EvGameEnded {
-            session_id,
-            outcome,
-        }.publish(&env);
-
-        game.outcome = outcome;
-        game.lifecycle_state = STATE_FINISHED;
-        game.deadline_nonce = None;
-        game.deadline_ledger = None;
+        EvWinningsClaimable { session_id, expires_ledger }.publish(env);
+    }
 
-        // Persist game summary to both players' history
-        Self::save_player_history(
-            env, session_id, &game.player1, &game.player2,
-            outcome, game.tricks_won1, game.tricks_won2,
-        );
-        Self::save_player_history(
-            env, session_id, &game.player2, &game.player1,
-            Self::flip_outcome(outcome), game.tricks_won2, game.tricks_won1,
-        );
+    /// Add `amount` of `token` to what `treasury` can withdraw.
+    fn credit_treasury(env: &Env, treasury: &Address, token: &Address, amount: i128) {
+        let key = StorageKey::TreasuryBalance(treasury.clone(), token.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+    }
 
-        Ok(())
+    /// Outcome code for a win by `slot`.
+    fn win_outcome(slot: u32) -> Outcome {
+        match slot {
+            1 => OUTCOME_PLAYER1_WIN,
+            2 => OUTCOME_PLAYER2_WIN,
+            3 => OUTCOME_PLAYER3_WIN,
+            _ => OUTCOME_PLAYER4_WIN,
+        }
     }
 
-    /// Flip outcome from the opponent's perspective.
-    fn flip_outcome(outcome: Outcome) -> Outcome {
+    /// Slot that won under `outcome`; `None` for a draw or no result.
+    fn outcome_winner(outcome: Outcome) -> Option<u32> {
         match outcome {
-            OUTCOME_PLAYER1_WIN => OUTCOME_PLAYER2_WIN,
-            OUTCOME_PLAYER2_WIN => OUTCOME_PLAYER1_WIN,
-            other => other, // DRAW stays the same
+            OUTCOME_PLAYER1_WIN => Some(1),
+            OUTCOME_PLAYER2_WIN => Some(2),
+            OUTCOME_PLAYER3_WIN => Some(3),
+            OUTCOME_PLAYER4_WIN => Some(4),
+            _ => None,
         }
     }
 
-    /// Append a game summary to a player's persistent history (ring buffer, max 50).
-    fn save_player_history(
-        env: &Env,
-        session_id: u32,
-        player: &Address,
-        opponent: &Address,
-        outcome: Outcome,
-        tricks_won: u32,
-        tricks_lost: u32,
-    ) {
-        let key = StorageKey::PlayerHistory(player.clone());
-        let mut history: Vec<GameSummary> = env
-            .storage()
+    /// Session ids of `player`'s active games, including any whose game
+    /// has since expired.
+    fn read_active_games(env: &Env, player: &Address) -> Vec<u32> {
+        env.storage()
             .persistent()
-            .get(&key)
-            .unwrap_or_else(|| Vec::new(env));
+            .get(&StorageKey::ActiveGames(player.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Add `session_id` to `player`'s active games, or remove it. Sessions
+    /// whose game expired unfinished are dropped along the way.
+    fn update_active_games(env: &Env, player: &Address, session_id: u32, active: bool) {
+        let mut sessions = Vec::new(env);
+        for id in Self::read_active_games(env, player).iter() {
+            if id != session_id && env.storage().temporary().has(&StorageKey::Game(id)) {
+                sessions.push_back(id);
+            }
+        }
+        if active {
+            sessions.push_back(session_id);
+        }
+
+        let key = StorageKey::ActiveGames(player.clone());
+        if sessions.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &sessions);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+        }
+    }
 
-        // Ring buffer: drop oldest if at capacity
-        while history.len() >= MAX_HISTORY_PER_PLAYER {
-            history.remove(0);
+    /// Append the game's summary, from `slot`'s side, to that player's
+    /// persistent history, `HISTORY_CHUNK_LEN` summaries per entry. Only
+    /// the last chunk is rewritten; a pre-chunking entry moves to chunk 0.
+    fn save_player_history(env: &Env, session_id: u32, game: &CangkulanGame, slot: u32) {
+        let seat = game.seat(slot);
+        let opponent = game.seat(slot % game.players.len() + 1).player;
+        // 1 = win, 2 = loss, 3 = draw
+        let winners = Self::winning_slots(game, game.outcome);
+        let outcome = match winners {
+            0 => game.outcome,
+            _ if winners & Self::slot_bit(slot) != 0 => OUTCOME_PLAYER1_WIN,
+            _ => OUTCOME_PLAYER2_WIN,
+        };
+        // A partner's tricks are not lost
+        let own_side = if game.config.teams { Self::team_slots((slot - 1) % 2 + 1) } else { Self::slot_bit(slot) };
+        let tricks_lost = game
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| own_side & Self::slot_bit(*i as u32 + 1) == 0)
+            .map(|(_, other)| other.tricks_won)
+            .sum::<u32>();
+
+        let storage = env.storage().persistent();
+        let len = Self::get_history_len(env.clone(), seat.player.clone());
+        let legacy = StorageKey::PlayerHistory(seat.player.clone());
+        if storage.has(&legacy) {
+            let entries: Vec<GameSummary> = storage.get(&legacy).unwrap_or_else(|| Vec::new(env));
+            storage.set(&StorageKey::HistoryChunk(seat.player.clone(), 0), &entries);
+            storage.remove(&legacy);
         }
 
+        // Only the chunk being appended to is rewritten
+        let chunk = len / HISTORY_CHUNK_LEN;
+        let key = StorageKey::HistoryChunk(seat.player.clone(), chunk);
+        let mut history = Self::read_history_chunk(env, &seat.player, chunk);
         history.push_back(GameSummary {
             session_id,
-            opponent: opponent.clone(),
+            opponent,
             outcome,
-            tricks_won,
+            tricks_won: seat.tricks_won,
             tricks_lost,
             ledger: env.ledger().sequence(),
         });
 
-        env.storage().persistent().set(&key, &history);
+        storage.set(&key, &history);
+        storage.extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+        let len_key = StorageKey::HistoryLen(seat.player);
+        storage.set(&len_key, &(len + 1));
+        storage.extend_ttl(&len_key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+    }
+
+    /// Keep one chunk of a player's history alive for another history TTL.
+    fn extend_history_chunk(env: &Env, player: &Address, chunk: u32) {
+        let storage = env.storage().persistent();
+        for key in [StorageKey::HistoryChunk(player.clone(), chunk), StorageKey::PlayerHistory(player.clone())] {
+            if storage.has(&key) {
+                storage.extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+            }
+            if chunk != 0 {
+                break;
+            }
+        }
+    }
+
+    /// One chunk of a player's history; chunk 0 falls back to the
+    /// pre-chunking entry. Empty once expired.
+    fn read_history_chunk(env: &Env, player: &Address, chunk: u32) -> Vec<GameSummary> {
+        let storage = env.storage().persistent();
+        storage
+            .get(&StorageKey::HistoryChunk(player.clone(), chunk))
+            .or_else(|| match chunk {
+                0 => storage.get(&StorageKey::PlayerHistory(player.clone())),
+                _ => None,
+            })
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Serialize a finished game in the `SUMMARY_VERSION` layout, or
+    /// `SUMMARY_VERSION_TABLE` for more than two seats.
+    fn summary_blob(env: &Env, session_id: u32, game: &CangkulanGame) -> Bytes {
+        let version = if game.players.len() == 2 { SUMMARY_VERSION } else { SUMMARY_VERSION_TABLE };
+        let mut blob = Bytes::from_array(env, &version.to_be_bytes());
+        blob.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        for seat in game.players.iter() {
+            blob.append(&seat.player.to_string().to_bytes());
+        }
+        blob.append(&Bytes::from_array(env, &game.outcome.to_be_bytes()));
+        for seat in game.players.iter() {
+            blob.append(&Bytes::from_array(env, &seat.tricks_won.to_be_bytes()));
+        }
+        blob.append(&Bytes::from_array(env, &game.transcript.to_array()));
+        blob
+    }
+
+    /// Record the summary blob hash; outlives the game's temporary entry.
+    fn record_summary_hash(env: &Env, session_id: u32, game: &CangkulanGame) {
+        let hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&Self::summary_blob(env, session_id, game))
+            .into();
+        let key = StorageKey::SummaryHash(session_id);
+        env.storage().persistent().set(&key, &hash);
         env.storage()
             .persistent()
             .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
     }
 
+    /// Chain `entry` into the game transcript: t' = keccak256(t || entry).
+    fn extend_transcript(env: &Env, game: &mut CangkulanGame, entry: &Bytes) {
+        game.transcript = Self::chain_entry(env, &game.transcript, entry);
+    }
+
+    fn chain_entry(env: &Env, prev: &BytesN<32>, entry: &Bytes) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &prev.to_array());
+        data.append(entry);
+        env.crypto().keccak256(&data).into()
+    }
+
+    /// Transcript entry of a trick: flipped || card per seat || winner.
+    fn trick_entry(env: &Env, record: &TrickRecord) -> Bytes {
+        let mut entry = Bytes::from_array(env, &record.flipped.to_be_bytes());
+        for card in record.cards.iter() {
+            entry.append(&Bytes::from_array(env, &card.to_be_bytes()));
+        }
+        entry.append(&Bytes::from_array(env, &record.winner.to_be_bytes()));
+        entry
+    }
+
+    /// Chain a resolved trick into the transcript and the trick log, folding
+    /// the oldest logged trick into `trick_log_base` once the log is full.
+    /// The session's full history keeps every trick.
+    fn record_trick(env: &Env, session_id: u32, game: &mut CangkulanGame, record: TrickRecord) {
+        Self::extend_transcript(env, game, &Self::trick_entry(env, &record));
+        let key = StorageKey::TrickHistory(session_id);
+        let mut history = Self::get_trick_history(env.clone(), session_id);
+        history.push_back(record.clone());
+        env.storage().temporary().set(&key, &history);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game.trick_log.push_back(record);
+        if game.trick_log.len() > TRICK_LOG_LEN {
+            let oldest = game.trick_log.pop_front_unchecked();
+            game.trick_log_base = Self::chain_entry(env, &game.trick_log_base, &Self::trick_entry(env, &oldest));
+            game.tricks_pruned += 1;
+        }
+    }
+
+    /// Keep a ZK card play's commitment for `audit_reveal`.
+    fn record_zk_play(env: &Env, session_id: u32, slot: u32, trick: u32, commit_hash: &BytesN<32>) {
+        let key = StorageKey::ZkPlayCommits(session_id, slot);
+        let mut plays: Vec<(u32, BytesN<32>)> = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        plays.push_back((trick, commit_hash.clone()));
+        env.storage().temporary().set(&key, &plays);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     //  Internal: Phase guards
     // ═══════════════════════════════════════════════════════════════════════════
 
+    /// Under `strict_reveal`, refuse a reveal once the ledger deadline has
+    /// passed, so a seat that saw the others' reveals cannot answer late.
+    fn check_reveal_deadline(env: &Env, game: &CangkulanGame) -> Result<(), CangkulanError> {
+        let passed = game
+            .deadline_ledger
+            .is_some_and(|dl| env.ledger().sequence() >= dl);
+        if game.config.strict_reveal && passed {
+            return Err(CangkulanError::RevealDeadlinePassed);
+        }
+        Ok(())
+    }
+
+    /// On a blitz clock, draw a move's overrun past the ledger deadline from
+    /// the mover's time bank. A move the bank cannot cover fails with
+    /// `TimeBankExhausted`, leaving the seat to be timed out.
+    fn charge_time_bank(env: &Env, game: &mut CangkulanGame, slot: u32) -> Result<(), CangkulanError> {
+        let Some(deadline) = game.deadline_ledger.filter(|_| !game.time_banks.is_empty()) else {
+            return Ok(());
+        };
+        let overrun = env.ledger().sequence().saturating_sub(deadline);
+        let bank = game.time_banks.get_unchecked(slot - 1);
+        if overrun > bank {
+            return Err(CangkulanError::TimeBankExhausted);
+        }
+        game.time_banks.set(slot - 1, bank - overrun);
+        Ok(())
+    }
+
+    /// Require auth for a commit or reveal by `player`: from the session
+    /// delegate if one is authorized, else from `player`.
+    fn require_play_auth(env: &Env, session_id: u32, player: &Address) {
+        Self::play_signer(env, session_id, player).require_auth();
+    }
+
+    /// Address that signs `player`'s moves: its session delegate, if any.
+    fn play_signer(env: &Env, session_id: u32, player: &Address) -> Address {
+        Self::get_delegate(env.clone(), session_id, player.clone()).unwrap_or(player.clone())
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), CangkulanError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CangkulanError::ContractPaused);
+        }
+        Ok(())
+    }
+
     fn require_active(game: &CangkulanGame) -> Result<(), CangkulanError> {
         if game.lifecycle_state == STATE_FINISHED {
             return Err(CangkulanError::GameAlreadyEnded);
@@ -1578,21 +3990,18 @@ impl CangkulanContract {
     }
 
     fn resolve_slot(game: &CangkulanGame, player: &Address) -> Result<u32, CangkulanError> {
-        if *player == game.player1 {
-            Ok(PLAYER_1)
-        } else if *player == game.player2 {
-            Ok(PLAYER_2)
-        } else {
-            Err(CangkulanError::NotAPlayer)
-        }
+        game.players
+            .iter()
+            .position(|seat| seat.player == *player)
+            .map(|i| i as u32 + 1)
+            .ok_or(CangkulanError::NotAPlayer)
     }
 
     /// Check if the player is in the commit phase of a trick.
     fn require_commit_phase(game: &CangkulanGame, slot: u32) -> Result<(), CangkulanError> {
         let ok = match game.trick_state {
             TRICK_COMMIT_WAIT_BOTH => true,
-            TRICK_COMMIT_WAIT_P1 => slot == PLAYER_1,
-            TRICK_COMMIT_WAIT_P2 => slot == PLAYER_2,
+            TRICK_COMMIT_WAIT_P1..=TRICK_COMMIT_WAIT_P4 => slot == game.trick_state - TRICK_COMMIT_WAIT_BOTH,
             _ => false,
         };
         if !ok {
@@ -1605,8 +4014,7 @@ impl CangkulanContract {
     fn require_reveal_phase(game: &CangkulanGame, slot: u32) -> Result<(), CangkulanError> {
         let ok = match game.trick_state {
             TRICK_REVEAL_WAIT_BOTH => true,
-            TRICK_REVEAL_WAIT_P1 => slot == PLAYER_1,
-            TRICK_REVEAL_WAIT_P2 => slot == PLAYER_2,
+            TRICK_REVEAL_WAIT_P1..=TRICK_REVEAL_WAIT_P4 => slot == game.trick_state - TRICK_REVEAL_WAIT_BOTH,
             _ => false,
         };
         if !ok {
@@ -1615,33 +4023,81 @@ impl CangkulanContract {
         Ok(())
     }
 
+    fn is_commit_state(trick_state: TrickState) -> bool {
+        (TRICK_COMMIT_WAIT_BOTH..=TRICK_COMMIT_WAIT_P4).contains(&trick_state)
+    }
+
+    fn is_reveal_state(trick_state: TrickState) -> bool {
+        (TRICK_REVEAL_WAIT_BOTH..=TRICK_REVEAL_WAIT_P4).contains(&trick_state)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     //  Internal: Seed verification
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Reject seeds with insufficient entropy.  A seed must contain at
-    /// least 4 distinct byte values; otherwise it is considered trivially
-    /// predictable (e.g. `[0; 32]`, `[0,1,0,1,...]`, etc.).
-    fn check_seed_entropy(seed: &BytesN<32>) -> Result<(), CangkulanError> {
-        let arr = seed.to_array();
-        // Count distinct byte values (simple linear scan, max 256)
-        let mut seen = [false; 256];
-        let mut distinct: u32 = 0;
-        let mut i = 0usize;
-        while i < 32 {
-            let idx = arr[i] as usize;
-            if !seen[idx] {
-                seen[idx] = true;
-                distinct += 1;
-            }
-            i += 1;
+    /// Verify a Noir seed proof through the ZK verifier (Mode 22). A
+    /// rejection publishes `EvNoirProofRejected` and fails with
+    /// `UltraHonkVerificationFailed` instead of trapping.
+    fn verify_noir(
+        env: &Env,
+        game: &CangkulanGame,
+        session_id: u32,
+        player: &Address,
+        seed_hash: &BytesN<32>,
+        proof: &Bytes,
+    ) -> Result<(), CangkulanError> {
+        // One 32-byte big-endian field element per seed_hash byte
+        let mut public_inputs = Bytes::new(env);
+        for byte in seed_hash.to_array() {
+            public_inputs.append(&Bytes::from_array(env, &[0u8; 31]));
+            public_inputs.push_back(byte);
         }
-        if distinct < 4 {
-            return Err(CangkulanError::WeakSeedEntropy);
+        let tagged = Self::tagged_proof(env, spec::MODE_ULTRAHONK, proof);
+        let verifier = ZkVerifierClient::new(env, &Self::mode_verifier(game, spec::MODE_ULTRAHONK));
+        if !verifier.verify_from(&env.current_contract_address(), &public_inputs, &tagged) {
+            EvNoirProofRejected { session_id, player: player.clone(), proof_len: proof.len() }.publish(env);
+            return Err(CangkulanError::UltraHonkVerificationFailed);
         }
         Ok(())
     }
 
+    /// Seed proof mode implied by a `reveal_seed` proof, using the same
+    /// length rules as `call_seed_verifier`. An empty proof consumes a
+    /// pre-verified Noir proof. `None` for lengths no mode accepts.
+    fn seed_proof_mode_of(proof: &Bytes) -> Option<SeedProofMode> {
+        match proof.len() {
+            0 => Some(SEED_PROOF_NOIR),
+            spec::NIZK_PROOF_LEN => Some(SEED_PROOF_NIZK),
+            PEDERSEN_SEED_PROOF_LEN => Some(SEED_PROOF_PEDERSEN),
+            len if len > spec::ULTRAHONK_MIN_PROOF_LEN => Some(SEED_PROOF_NOIR),
+            _ => None,
+        }
+    }
+
+    /// Reject a reveal whose proof type differs from the mode the player
+    /// declared at `commit_seed`. Unknown lengths are left to the verifier.
+    fn check_seed_proof_mode(
+        game: &CangkulanGame,
+        slot: u32,
+        mode: Option<SeedProofMode>,
+    ) -> Result<(), CangkulanError> {
+        match (game.seat(slot).seed_mode, mode) {
+            (Some(declared), Some(mode)) if declared != mode => {
+                Err(CangkulanError::SeedProofModeMismatch)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject seeds with insufficient entropy under the configured
+    /// `EntropyPolicy`. The default only requires 4 distinct byte values,
+    /// which rules out trivially predictable seeds such as `[0; 32]`.
+    fn check_seed_entropy(env: &Env, seed: &BytesN<32>) -> Result<(), CangkulanError> {
+        Self::load_entropy_policy(env)
+            .check(&seed.to_array())
+            .map_err(|_| CangkulanError::WeakSeedEntropy)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     //  Internal: BLS12-381 Pedersen commitment for ZK card plays
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1658,17 +4114,13 @@ impl CangkulanContract {
         0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
     ];
 
-    /// Pedersen H generator DST (same as ZK verifier).
-    const PEDERSEN_H_MSG: &'static [u8] = b"PEDERSEN_H";
-    const PEDERSEN_H_DST: &'static [u8] = b"SGS_CANGKULAN_V1";
-
     /// Compute Pedersen commitment: C = card_id·G + blinding·H
     fn pedersen_commit(env: &Env, card_id: u32, blinding: &BytesN<32>) -> G1Affine {
         let bls = env.crypto().bls12_381();
 
         let g = G1Affine::from_array(env, &Self::G1_GENERATOR);
-        let h_msg = Bytes::from_slice(env, Self::PEDERSEN_H_MSG);
-        let h_dst = Bytes::from_slice(env, Self::PEDERSEN_H_DST);
+        let h_msg = Bytes::from_slice(env, spec::PEDERSEN_H_MSG);
+        let h_dst = Bytes::from_slice(env, spec::PEDERSEN_H_DST);
         let h = bls.hash_to_g1(&h_msg, &h_dst);
 
         // Fr(card_id): 32-byte big-endian with card_id in the last 4 bytes
@@ -1688,8 +4140,91 @@ impl CangkulanContract {
         bls.g1_add(&card_g, &blind_h)
     }
 
-    /// Domain separator tags for the NIZK protocol
-    const NULLIFIER_TAG: [u8; 4] = [0x4E, 0x55, 0x4C, 0x4C]; // "NULL"
+    /// Mode 7 public inputs for `valid_set`, with `RING_PADDED_FLAG` in N
+    /// if `padded`:
+    /// commit_hash(32) || N(4, u32 BE) || valid_set[N](4 each, u32 BE) || session_id(4) || player(var)
+    fn ring_public_inputs(
+        env: &Env,
+        commit_hash: &BytesN<32>,
+        valid_set: &Vec<u32>,
+        padded: bool,
+        session_id: u32,
+        player: &Address,
+    ) -> Bytes {
+        let n_field = if padded { valid_set.len() | spec::RING_PADDED_FLAG } else { valid_set.len() };
+        let mut public_inputs = Bytes::from_array(env, &commit_hash.to_array());
+        public_inputs.append(&Bytes::from_array(env, &n_field.to_be_bytes()));
+        let mut wi = 0u32;
+        while wi < valid_set.len() {
+            let card = valid_set.get(wi).unwrap();
+            public_inputs.append(&Bytes::from_array(env, &card.to_be_bytes()));
+            wi += 1;
+        }
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+        public_inputs
+    }
+
+    /// `proof` behind the verifier's version-1 format tag for `mode`, so
+    /// plays keep verifying once the operator refuses untagged proofs.
+    fn tagged_proof(env: &Env, mode: u32, proof: &Bytes) -> Bytes {
+        let mut tagged = Bytes::from_array(env, &[spec::PROOF_FORMAT_V1 | mode as u8]);
+        tagged.append(proof);
+        tagged
+    }
+
+    /// Whether `zk_proof` has `cards_per_suit` ring members for a valid set
+    /// of `n < cards_per_suit` cards, i.e. is a padded ring. C may be
+    /// uncompressed or compressed.
+    fn ring_proof_padded(zk_proof: &Bytes, n: u32, cards_per_suit: u32) -> bool {
+        let members_len = cards_per_suit * spec::RING_MEMBER_PROOF_LEN;
+        n < cards_per_suit
+            && (zk_proof.len() == spec::RING_PROOF_BASE_LEN + members_len
+                || zk_proof.len() == spec::G1_COMPRESSED_LEN + members_len)
+    }
+
+    /// Check a Mode 7 or Mode 8 play proof against `public_inputs` with the
+    /// verifier pinned in `game` for `mode`.
+    ///
+    /// A non-empty `proof` is verified in this transaction. An empty one
+    /// means the player already ran it through the verifier's
+    /// `verify_and_record` in an earlier transaction (split TX flow, like
+    /// `verify_noir_seed`); the matching record is consumed instead, keyed
+    /// by `keccak256(public_inputs)` rebuilt here from game state.
+    ///
+    /// The verifier reads suits through its own `DeckParams`, so a game
+    /// whose deck differs is refused with `DeckMismatch`.
+    fn check_play_proof(
+        env: &Env,
+        session_id: u32,
+        game: &CangkulanGame,
+        player: &Address,
+        mode: u32,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> Result<bool, CangkulanError> {
+        if Self::bypass_verifier(env, session_id, player) {
+            return Ok(true);
+        }
+        let cards_per_suit = game.config.cards_per_suit;
+        let verifier = ZkVerifierClient::new(env, &Self::mode_verifier(game, mode));
+        let deck = verifier.get_deck_params();
+        if deck.cards_per_suit != cards_per_suit || deck.deck_size != 4 * cards_per_suit {
+            return Err(CangkulanError::DeckMismatch);
+        }
+        if !proof.is_empty() {
+            let proof = Self::tagged_proof(env, mode, proof);
+            return Ok(verifier.verify_from(&env.current_contract_address(), public_inputs, &proof));
+        }
+        let commitment: BytesN<32> = env.crypto().keccak256(public_inputs).into();
+        Ok(verifier.consume_verification(
+            &env.current_contract_address(),
+            &session_id,
+            player,
+            &mode,
+            &commitment,
+        ))
+    }
 
     /// Encode seed verification public inputs and call the ZK verifier.
     ///
@@ -1709,22 +4244,23 @@ impl CangkulanContract {
     ///   Split TX flow — proof was verified via `verify_noir_seed`.
     ///   Checks and consumes the stored flag.
     ///
-    /// **Noir UltraKeccakHonk (proof > 4000 bytes):**
-    ///   Routes to UltraHonk verifier contract (single-TX flow, may exceed budget).
+    /// **Noir UltraKeccakHonk (proof > `ULTRAHONK_MIN_PROOF_LEN` bytes):**
+    ///   Sent to the ZK verifier, which routes it to its UltraHonk verifier
+    ///   (Mode 22; single-TX flow, may exceed budget).
     ///
-    /// The mode is auto-detected from proof length.
+    /// The mode is auto-detected from proof length. Proofs go to the
+    /// verifier pinned in `game` at start for that mode.
+    #[allow(clippy::too_many_arguments)]
     fn call_seed_verifier(
         env: &Env,
         session_id: u32,
+        game: &CangkulanGame,
         slot: u32,
         player: &Address,
         seed_hash: &BytesN<32>,
         commit_hash: &BytesN<32>,
         proof: &Bytes,
     ) -> Result<(), CangkulanError> {
-        let verifier_addr = Self::load_verifier(env)?;
-        let verifier = ZkVerifierClient::new(env, &verifier_addr);
-
         let proof_len = proof.len();
 
         if proof_len == 0 {
@@ -1753,7 +4289,7 @@ impl CangkulanContract {
                     return Err(CangkulanError::InvalidZkProof);
                 }
             }
-        } else if proof_len == 224 {
+        } else if proof_len == PEDERSEN_SEED_PROOF_LEN {
             // ── Pedersen+Sigma Mode (Mode 4) ────────────────────────────────
             // Extract C (G1 point) from proof[0..96)
             let mut c_bytes = Bytes::new(env);
@@ -1778,19 +4314,23 @@ impl CangkulanContract {
             // Extract sigma proof: proof[96..224) = 128 bytes
             let mut sigma_proof = Bytes::new(env);
             let mut j = 96u32;
-            while j < 224 {
+            while j < PEDERSEN_SEED_PROOF_LEN {
                 sigma_proof.push_back(proof.get(j).unwrap_or(0));
                 j += 1;
             }
 
-            if !verifier.verify(&public_inputs, &sigma_proof) {
+            let sigma_proof = Self::tagged_proof(env, spec::MODE_PEDERSEN_SEED, &sigma_proof);
+            let verifier = ZkVerifierClient::new(env, &Self::mode_verifier(game, spec::MODE_PEDERSEN_SEED));
+            if !Self::bypass_verifier(env, session_id, player)
+                && !verifier.verify_from(&env.current_contract_address(), &public_inputs, &sigma_proof)
+            {
                 return Err(CangkulanError::InvalidZkProof);
             }
-        } else if proof_len == 64 {
+        } else if proof_len == spec::NIZK_PROOF_LEN {
             // ── NIZK Mode (Mode 2) — existing flow ─────────────────────────
             // Compute nullifier: keccak256(seed_hash || "NULL" || session_id_be4)
             let mut null_preimage = Bytes::from_array(env, &seed_hash.to_array());
-            null_preimage.append(&Bytes::from_array(env, &Self::NULLIFIER_TAG));
+            null_preimage.append(&Bytes::from_array(env, &spec::NULLIFIER_TAG));
             null_preimage.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
             let nullifier: BytesN<32> = env.crypto().keccak256(&null_preimage).into();
 
@@ -1802,14 +4342,29 @@ impl CangkulanContract {
             public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
             public_inputs.append(&player.to_string().to_bytes());
 
-            if !verifier.verify(&public_inputs, proof) {
+            let verifier = ZkVerifierClient::new(env, &Self::mode_verifier(game, spec::MODE_NIZK_SEED));
+            if Self::bypass_verifier(env, session_id, player) {
+                // Override: still require the hash opening
+                // keccak256(seed_hash || blinding || player) == commit_hash
+                let mut preimage = Bytes::from_array(env, &seed_hash.to_array());
+                preimage.append(&proof.slice(0..32));
+                preimage.append(&player.to_string().to_bytes());
+                let opened: BytesN<32> = env.crypto().keccak256(&preimage).into();
+                if opened != *commit_hash {
+                    return Err(CangkulanError::CommitHashMismatch);
+                }
+            } else if !verifier.verify_from(
+                &env.current_contract_address(),
+                &public_inputs,
+                &Self::tagged_proof(env, spec::MODE_NIZK_SEED, proof),
+            ) {
                 return Err(CangkulanError::InvalidZkProof);
             }
-        } else if proof_len > 4000 {
+        } else if proof_len > spec::ULTRAHONK_MIN_PROOF_LEN {
             // ── Noir UltraKeccakHonk Mode ────────────────────────────────
             // For Noir proofs, the proof is a raw UltraHonk proof blob (>4KB).
-            // Public inputs = seed_hash encoded as 32 field elements (32 BE bytes each = 1024 bytes).
-            // The UltraHonk verifier contract handles all verification internally.
+            // The ZK verifier forwards it to the UltraHonk verifier (Mode 22),
+            // which handles all verification internally.
             //
             // Commit binding: commit_hash = keccak256(seed_hash) for Noir mode.
             // This ensures the player cannot change their seed between commit and reveal.
@@ -1821,142 +4376,323 @@ impl CangkulanContract {
                 return Err(CangkulanError::CommitHashMismatch);
             }
 
-            let ultrahonk_addr = Self::load_ultrahonk_verifier(env)?;
-            let ultrahonk = UltraHonkClient::new(env, &ultrahonk_addr);
+            Self::verify_noir(env, game, session_id, player, seed_hash, proof)?;
+        } else {
+            return Err(CangkulanError::InvalidZkProof);
+        }
+
+        Ok(())
+    }
 
-            // Encode seed_hash as UltraHonk public inputs:
-            // Each u8 becomes a 32-byte big-endian field element.
-            let seed_bytes = seed_hash.to_array();
-            let mut noir_public_inputs = Bytes::new(env);
-            for byte_val in seed_bytes.iter() {
-                // 31 zero bytes + the u8 value
-                let mut k = 0u32;
-                while k < 31 {
-                    noir_public_inputs.push_back(0u8);
-                    k += 1;
-                }
-                noir_public_inputs.push_back(*byte_val);
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Internal: Shuffle & Deal
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// A game awaiting seed commits, with no stakes escrowed and no
+    /// per-mode verifiers.
+    fn new_game(
+        env: &Env,
+        players: &Vec<Address>,
+        points: &Vec<i128>,
+        config: GameConfig,
+        hand_cap: HandCapConfig,
+        verifier: Address,
+    ) -> CangkulanGame {
+        let mut seats = Vec::new(env);
+        for (player, stake) in players.iter().zip(points.iter()) {
+            seats.push_back(PlayerSlot {
+                player,
+                points: stake,
+                seed_commit: None,
+                seed_hash: None,
+                seed_revealed: false,
+                seed_mode: None,
+                hand: Vec::new(env),
+                play_commit: None,
+                zk_play: false,
+                play_revealed: false,
+                trick_card: None,
+                tricks_won: 0,
+            });
+        }
+        let mut time_banks = Vec::new(env);
+        if config.time_bank_ledgers > 0 {
+            for _ in 0..seats.len() {
+                time_banks.push_back(config.time_bank_ledgers);
+            }
+        }
+
+        CangkulanGame {
+            schema_version: GAME_SCHEMA_VERSION,
+            players: seats,
+            draw_pile: Vec::new(env),
+            trick_state: TRICK_NONE,
+            trick_suit: None,
+            flipped_card: None,
+            lifecycle_state: STATE_SEED_COMMIT,
+            outcome: OUTCOME_UNRESOLVED,
+            forfeited_by: 0,
+            vs_bot: false,
+            action_nonce: 0,
+            deadline_nonce: None,
+            deadline_ledger: None,
+            last_tick_ledger: 0,
+            updated_ledger: 0,
+            time_banks,
+            max_hand_size: hand_cap.max_hand_size,
+            overflow_rule: hand_cap.overflow_rule,
+            config,
+            verifier,
+            mode_verifiers: Map::new(env),
+            stake_token: None,
+            rake_bps: 0,
+            treasury: None,
+            transcript: BytesN::from_array(env, &[0u8; 32]),
+            trick_log: Vec::new(env),
+            trick_log_base: BytesN::from_array(env, &[0u8; 32]),
+            tricks_pruned: 0,
+        }
+    }
+
+    /// Deterministic Fisher-Yates shuffle using ZK-verified seed hashes.
+    ///
+    /// Derives PRNG seed from every seat's seed_hash and session_id (see
+    /// `deal_seed_n`), where each seed_hash = keccak256(raw_seed). Raw seeds
+    /// never touch the chain.
+    fn shuffle_and_deal(env: &Env, game: &mut CangkulanGame, session_id: u32) {
+        // Derive PRNG seed from every ZK-verified seed hash + session_id
+        let seed_hashes = Self::seed_hashes(env, game).unwrap();
+
+        let seed_hash = deal_seed_n(env, &seed_hashes, session_id);
+        Self::extend_transcript(env, game, &Bytes::from_array(env, &seed_hash.to_array()));
+        game.trick_log_base = game.transcript.clone();
+
+        let deck = Self::game_deck(env, &game.config, &seed_hash);
+        let key = StorageKey::DealtDeck(session_id);
+        env.storage().temporary().set(&key, &deck);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::deal(game, &deck);
+    }
+
+    /// Deal `hand_size` cards to each seat in order, the rest to the draw pile.
+    fn deal(game: &mut CangkulanGame, deck: &Vec<u32>) {
+        let hand_size = game.config.hand_size;
+        for slot in 1..=game.players.len() {
+            let mut seat = game.seat(slot);
+            let first = (slot - 1) * hand_size;
+            seat.hand = deck.slice(first..first + hand_size);
+            game.set_seat(slot, seat);
+        }
+        game.draw_pile = deck.slice(game.players.len() * hand_size..);
+    }
+
+    /// Fisher-Yates shuffle of the full deck ([0..36) is shared with the
+    /// verifier's Mode 18), keeping the top `deck_size / 4` values of each
+    /// suit in shuffled order.
+    fn game_deck(env: &Env, config: &GameConfig, seed: &BytesN<32>) -> Vec<u32> {
+        let cards_per_suit = config.cards_per_suit;
+        let lowest = cards_per_suit - config.deck_size / 4;
+        let mut deck = Vec::new(env);
+        for card in shuffled_deck_n(env, seed, 4 * cards_per_suit) {
+            if card % cards_per_suit >= lowest {
+                deck.push_back(card);
             }
+        }
+        deck
+    }
+
+    /// Revealed seed hashes in seat order, `None` until everyone revealed.
+    fn seed_hashes(env: &Env, game: &CangkulanGame) -> Option<Vec<BytesN<32>>> {
+        let mut seed_hashes = Vec::new(env);
+        for seat in game.players.iter() {
+            seed_hashes.push_back(seat.seed_hash?);
+        }
+        Some(seed_hashes)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Internal: Trick mechanics
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Flip the next card from the draw pile to start a new trick.
+    fn flip_next_card(_env: &Env, game: &mut CangkulanGame) {
+        if game.draw_pile.is_empty() {
+            return;
+        }
+
+        let card = game.draw_pile.get(0).unwrap();
+        game.draw_pile.remove(0);
+        let suit = card / game.config.cards_per_suit;
+
+        game.flipped_card = Some(card);
+        game.trick_suit = Some(suit);
+        Self::clear_plays(game);
+        game.trick_state = TRICK_COMMIT_WAIT_BOTH;
+    }
+
+    /// Reset every seat's commit, reveal and card for the next trick.
+    fn clear_plays(game: &mut CangkulanGame) {
+        for slot in 1..=game.players.len() {
+            let mut seat = game.seat(slot);
+            seat.trick_card = None;
+            seat.play_commit = None;
+            seat.zk_play = false;
+            seat.play_revealed = false;
+            game.set_seat(slot, seat);
+        }
+    }
+
+    /// Record `player`'s legacy play commit in a game in its playing phase.
+    fn apply_play_commit(
+        env: &Env,
+        session_id: u32,
+        game: &mut CangkulanGame,
+        player: &Address,
+        commit_hash: BytesN<32>,
+    ) -> Result<(), CangkulanError> {
+        let slot = Self::resolve_slot(game, player)?;
+        Self::require_commit_phase(game, slot)?;
+        Self::charge_time_bank(env, game, slot)?;
+        if game.config.zk_required {
+            return Err(CangkulanError::ZkPlayRequired);
+        }
+
+        let mut seat = game.seat(slot);
+        if seat.play_commit.is_some() {
+            return Err(CangkulanError::PlayCommitAlreadySubmitted);
+        }
+        seat.play_commit = Some(commit_hash);
+        game.set_seat(slot, seat);
+
+        EvPlayCommitted {
+            session_id,
+            player: player.clone(),
+        }.publish(env);
 
-            // Call the UltraHonk verifier — it panics on failure
-            ultrahonk.verify_proof(&noir_public_inputs, proof);
-        } else {
-            return Err(CangkulanError::InvalidZkProof);
-        }
+        // Advance commit state
+        Self::advance_commit_state(game);
+        Self::bump_nonce(game);
 
+        // Reset deadline on state transition
+        Self::reset_deadline(env, session_id, game);
         Ok(())
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Internal: Shuffle & Deal
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Deterministic Fisher-Yates shuffle using ZK-verified seed hashes.
-    ///
-    /// Derives PRNG seed from `seed_hash1 || seed_hash2 || session_id` where
-    /// each seed_hash = keccak256(raw_seed). Raw seeds never touch the chain.
-    fn shuffle_and_deal(env: &Env, game: &mut CangkulanGame, session_id: u32) {
-        // Derive PRNG seed from both ZK-verified seed hashes + session_id
-        let sh1 = game.seed_hash1.clone().unwrap();
-        let sh2 = game.seed_hash2.clone().unwrap();
-
-        let mut seed_data = Bytes::from_array(env, &sh1.to_array());
-        seed_data.append(&Bytes::from_array(env, &sh2.to_array()));
-        seed_data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        let seed_hash = env.crypto().keccak256(&seed_data);
-        env.prng().seed(seed_hash.into());
-
-        // Create ordered deck [0..36)
-        let mut deck: [u32; 36] = [0; 36];
-        let mut i: u32 = 0;
-        while i < DECK_SIZE {
-            deck[i as usize] = i;
-            i += 1;
+    /// Open `player`'s play commit in a game in its playing phase, and
+    /// resolve the trick once every seat has revealed.
+    fn apply_play_reveal(
+        env: &Env,
+        session_id: u32,
+        game: &mut CangkulanGame,
+        player: &Address,
+        card_id: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), CangkulanError> {
+        let slot = Self::resolve_slot(game, player)?;
+        Self::require_reveal_phase(game, slot)?;
+        Self::check_reveal_deadline(env, game)?;
+        Self::charge_time_bank(env, game, slot)?;
+        let mut seat = game.seat(slot);
+        if seat.play_revealed {
+            return Err(CangkulanError::RevealAlreadySubmitted);
         }
 
-        // Fisher-Yates shuffle
-        let mut idx = DECK_SIZE;
-        while idx > 1 {
-            idx -= 1;
-            let j = env.prng().gen_range::<u64>(0..=(idx as u64)) as u32;
-            let tmp = deck[idx as usize];
-            deck[idx as usize] = deck[j as usize];
-            deck[j as usize] = tmp;
-        }
+        // Verify commit hash matches
+        let commit = seat.play_commit.clone().ok_or(CangkulanError::PlayCommitMissing)?;
+        let is_zk = seat.zk_play;
 
-        // Deal: first 5 to P1, next 5 to P2, rest to draw pile
-        let mut hand1 = Vec::new(env);
-        let mut hand2 = Vec::new(env);
-        let mut draw_pile = Vec::new(env);
+        let is_cangkul = card_id == CANNOT_FOLLOW_SENTINEL;
 
-        let mut d: u32 = 0;
-        while d < DECK_SIZE {
-            if d < HAND_SIZE {
-                hand1.push_back(deck[d as usize]);
-            } else if d < HAND_SIZE * 2 {
-                hand2.push_back(deck[d as usize]);
-            } else {
-                draw_pile.push_back(deck[d as usize]);
+        if is_zk {
+            // Mode 8 aggregate opening: A = (Σhand_card_i)·G + r_agg·H, the
+            // player supplying r_agg as the `salt`. Mode 7 single opening:
+            // C = card_id·G + blinding·H.
+            let opened = if is_cangkul { seat.hand.iter().sum() } else { card_id };
+            if Self::compute_pedersen_commit_hash(env.clone(), opened, salt) != commit {
+                return Err(CangkulanError::ZkPlayOpeningMismatch);
+            }
+        } else {
+            // Legacy keccak256 hash opening
+            if Self::compute_play_commit(env.clone(), card_id, salt) != commit {
+                return Err(CangkulanError::PlayRevealMismatch);
             }
-            d += 1;
         }
 
-        game.hand1 = hand1;
-        game.hand2 = hand2;
-        game.draw_pile = draw_pile;
-    }
+        if is_cangkul {
+            // Validate: player truly has no matching suit cards
+            let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
+            if Self::has_suit_in_hand(&seat.hand, trick_suit, game.config.cards_per_suit) {
+                return Err(CangkulanError::HasMatchingSuit);
+            }
+            // trick_card for this slot remains None — signals cannot_follow
+        } else {
+            // Validate card_id range
+            if card_id >= 4 * game.config.cards_per_suit {
+                return Err(CangkulanError::InvalidCardId);
+            }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Internal: Trick mechanics
-    // ═══════════════════════════════════════════════════════════════════════════
+            // Validate card is in hand
+            let card_pos = Self::find_card_position(&seat.hand, card_id)?;
 
-    /// Flip the next card from the draw pile to start a new trick.
-    fn flip_next_card(_env: &Env, game: &mut CangkulanGame) {
-        if game.draw_pile.is_empty() {
-            return;
+            // Validate card matches trick suit
+            let trick_suit = game.trick_suit.ok_or(CangkulanError::NoTrickInProgress)?;
+            let card_suit = card_id / game.config.cards_per_suit;
+            if card_suit != trick_suit {
+                return Err(CangkulanError::WrongSuit);
+            }
+
+            // Remove card from hand and record it
+            seat.hand.remove(card_pos);
+            seat.trick_card = Some(card_id);
         }
+        seat.play_revealed = true;
+        game.set_seat(slot, seat);
 
-        let card = game.draw_pile.get(0).unwrap();
-        game.draw_pile.remove(0);
-        let suit = card / CARDS_PER_SUIT;
+        EvPlayRevealed {
+            session_id,
+            player: player.clone(),
+            card_id,
+            is_cangkul,
+        }.publish(env);
 
-        game.flipped_card = Some(card);
-        game.trick_suit = Some(suit);
-        game.trick_card1 = None;
-        game.trick_card2 = None;
-        game.play_commit1 = None;
-        game.play_commit2 = None;
-        game.zk_play1 = false;
-        game.zk_play2 = false;
-        game.trick_state = TRICK_COMMIT_WAIT_BOTH;
+        // Advance reveal state
+        Self::advance_reveal_state(game);
+        Self::bump_nonce(game);
+
+        // If everyone has revealed, resolve the trick
+        if game.trick_state == TRICK_NONE {
+            Self::resolve_trick(env, session_id, game)?;
+        } else if !game.config.strict_reveal {
+            // Reset deadline for reveal phase
+            Self::reset_deadline(env, session_id, game);
+        }
+        Ok(())
     }
 
     /// After a player commits, advance the commit sub-state.
-    fn advance_commit_state(game: &mut CangkulanGame, slot: u32) {
-        game.trick_state = match game.trick_state {
-            TRICK_COMMIT_WAIT_BOTH => {
-                if slot == PLAYER_1 {
-                    TRICK_COMMIT_WAIT_P2
-                } else {
-                    TRICK_COMMIT_WAIT_P1
-                }
-            }
-            _ => TRICK_REVEAL_WAIT_BOTH, // Both have committed → reveal phase
-        };
+    fn advance_commit_state(game: &mut CangkulanGame) {
+        let committed = Self::slots_where(game, |seat| seat.play_commit.is_some());
+        game.trick_state = Self::waiting_state(game, committed, TRICK_COMMIT_WAIT_BOTH, TRICK_REVEAL_WAIT_BOTH);
     }
 
     /// After a player reveals, advance the reveal sub-state.
-    fn advance_reveal_state(game: &mut CangkulanGame, slot: u32) {
-        game.trick_state = match game.trick_state {
-            TRICK_REVEAL_WAIT_BOTH => {
-                if slot == PLAYER_1 {
-                    TRICK_REVEAL_WAIT_P2
-                } else {
-                    TRICK_REVEAL_WAIT_P1
-                }
-            }
-            _ => TRICK_NONE, // Both have revealed → resolve
-        };
+    fn advance_reveal_state(game: &mut CangkulanGame) {
+        let revealed = Self::slots_where(game, |seat| seat.play_revealed);
+        game.trick_state = Self::waiting_state(game, revealed, TRICK_REVEAL_WAIT_BOTH, TRICK_NONE);
+    }
+
+    /// State waiting on the slots outside `done`: `wait_base + slot` for
+    /// one, `wait_base` for several, `next` once everyone is done.
+    fn waiting_state(game: &CangkulanGame, done: u32, wait_base: TrickState, next: TrickState) -> TrickState {
+        let pending = Self::all_slots(game) & !done;
+        match pending.count_ones() {
+            0 => next,
+            1 => wait_base + pending.trailing_zeros() + 1,
+            _ => wait_base,
+        }
     }
 
     /// Resolve a completed trick and check win conditions.
@@ -1965,100 +4701,186 @@ impl CangkulanContract {
         session_id: u32,
         game: &mut CangkulanGame,
     ) -> Result<(), CangkulanError> {
-        let p1_played = game.trick_card1.is_some();
-        let p2_played = game.trick_card2.is_some();
-
-        match (p1_played, p2_played) {
-            (true, true) => {
-                // Both followed suit — highest value wins
-                let c1 = game.trick_card1.unwrap();
-                let c2 = game.trick_card2.unwrap();
-                let v1 = c1 % CARDS_PER_SUIT;
-                let v2 = c2 % CARDS_PER_SUIT;
-                // Strictly greater wins; on tie the trick lead (P1/attacker) wins
-                if v1 >= v2 {
-                    game.tricks_won1 += 1;
-                } else {
-                    game.tricks_won2 += 1;
-                }
-            }
-            (true, false) => {
-                // P1 followed, P2 couldn't → P1 wins, P2 takes penalty
-                game.tricks_won1 += 1;
-                Self::give_penalty_card(game, PLAYER_2);
-            }
-            (false, true) => {
-                // P2 followed, P1 couldn't → P2 wins, P1 takes penalty
-                game.tricks_won2 += 1;
-                Self::give_penalty_card(game, PLAYER_1);
-            }
-            (false, false) => {
-                // Neither followed → waste trick, discard flipped card
-                // (already removed from draw pile)
-            }
+        let (trick_winner, capped, draws) = Self::settle_trick(env, game);
+        for (player, matched) in draws.iter() {
+            EvCangkulDraw { session_id, player, matched }.publish(env);
         }
 
-        // Emit trick resolved event (0 = waste, 1 = P1, 2 = P2)
-        let trick_winner: u32 = match (p1_played, p2_played) {
-            (true, true) => {
-                let c1 = game.trick_card1.unwrap();
-                let c2 = game.trick_card2.unwrap();
-                if (c1 % CARDS_PER_SUIT) >= (c2 % CARDS_PER_SUIT) { PLAYER_1 } else { PLAYER_2 }  // lead wins ties
-            }
-            (true, false) => PLAYER_1,
-            (false, true) => PLAYER_2,
-            _ => 0,
-        };
+        let mut cards = Vec::new(env);
+        let mut record_cards = Vec::new(env);
+        for seat in game.players.iter() {
+            cards.push_back(seat.trick_card);
+            record_cards.push_back(seat.trick_card.unwrap_or(CANNOT_FOLLOW_SENTINEL));
+        }
         EvTrickResolved {
             session_id,
             winner: trick_winner,
-            card1: game.trick_card1,
-            card2: game.trick_card2,
-        }.publish(&env);
+            cards,
+        }.publish(env);
+
+        let record = TrickRecord {
+            flipped: game.flipped_card.unwrap_or(CANNOT_FOLLOW_SENTINEL),
+            cards: record_cards,
+            winner: trick_winner,
+            ledger: env.ledger().sequence(),
+        };
+        Self::record_trick(env, session_id, game, record);
 
         // Clear trick state
         game.flipped_card = None;
         game.trick_suit = None;
-        game.trick_card1 = None;
-        game.trick_card2 = None;
-        game.play_commit1 = None;
-        game.play_commit2 = None;
-        game.zk_play1 = false;
-        game.zk_play2 = false;
-
-        // Check win conditions
-        if game.hand1.is_empty() || game.hand2.is_empty() {
-            let outcome = Self::determine_winner(game);
-            return Self::finalize_game(env, session_id, game, outcome);
-        }
+        Self::clear_plays(game);
 
-        // If draw pile is empty, game ends — count cards
-        if game.draw_pile.is_empty() {
-            let outcome = Self::determine_winner(game);
+        for slot in 1..=game.players.len() {
+            if capped & Self::slot_bit(slot) != 0 {
+                EvHandCapReached { session_id, player: slot, overflow_rule: game.overflow_rule }.publish(env);
+            }
+        }
+        if let Some(outcome) = Self::trick_end_outcome(game, capped) {
             return Self::finalize_game(env, session_id, game, outcome);
         }
 
         // Flip next card for new trick
         Self::flip_next_card(env, game);
-        game.deadline_nonce =
-            Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
-        game.deadline_ledger =
-            Some(env.ledger().sequence().saturating_add(TIMEOUT_LEDGERS));
+        Self::reset_deadline(env, session_id, game);
 
         Ok(())
     }
 
+    /// Award a fully revealed trick and make every seat that could not
+    /// follow draw. Returns the winner (0 for a waste trick), the slots
+    /// whose draw hit the hand-size cap, and each card drawn under
+    /// `draw_until_follow` as (slot, matched).
+    fn settle_trick(env: &Env, game: &mut CangkulanGame) -> (u32, u32, Vec<(u32, bool)>) {
+        // Highest value of the trick suit wins; on a tie the earlier seat
+        // (P1 is the attacker) wins. 0 = waste trick, nobody followed.
+        let mut trick_winner = 0u32;
+        let mut best_value = 0u32;
+        for (i, seat) in game.players.iter().enumerate() {
+            if let Some(card) = seat.trick_card {
+                if trick_winner == 0 || card % game.config.cards_per_suit > best_value {
+                    trick_winner = i as u32 + 1;
+                    best_value = card % game.config.cards_per_suit;
+                }
+            }
+        }
+
+        // Slots whose penalty draw hit the hand-size cap
+        let mut capped = 0u32;
+        let mut draws = Vec::new(env);
+        if trick_winner != 0 {
+            let mut seat = game.seat(trick_winner);
+            seat.tricks_won += 1;
+            game.set_seat(trick_winner, seat);
+
+            // Everyone who couldn't follow takes penalty_draw cards, or
+            // draws until following under draw_until_follow, in seat order
+            for slot in 1..=game.players.len() {
+                if game.seat(slot).trick_card.is_some() {
+                    continue;
+                }
+                if game.config.draw_until_follow {
+                    if Self::draw_until_follow(game, slot, &mut draws).is_some() {
+                        capped |= Self::slot_bit(slot);
+                    }
+                    continue;
+                }
+                for _ in 0..game.config.penalty_draw {
+                    if Self::give_penalty_card(game, slot).is_some() {
+                        capped |= Self::slot_bit(slot);
+                        break;
+                    }
+                }
+            }
+        }
+        // Nobody followed → waste trick, discard flipped card
+        // (already removed from draw pile)
+        (trick_winner, capped, draws)
+    }
+
+    /// Play one recorded trick of `verify_replay` on `game`, in the
+    /// `reveal_play` order of checks: `flipped` must be the card turned up,
+    /// each seat's card must be in its hand and of the trick suit, and a
+    /// seat may only pass (`CANNOT_FOLLOW_SENTINEL`) holding none of the
+    /// suit. Settles the trick like `resolve_trick` and returns the capped
+    /// slots, or `None` if any check or the recorded `winner` fails.
+    fn replay_trick(env: &Env, game: &mut CangkulanGame, flipped: u32, cards: &Vec<u32>, winner: u32) -> Option<u32> {
+        let trick_suit = game.trick_suit?;
+        if game.flipped_card != Some(flipped) {
+            return None;
+        }
+        for (slot, card) in (1..=game.players.len()).zip(cards.iter()) {
+            let mut seat = game.seat(slot);
+            if card == CANNOT_FOLLOW_SENTINEL {
+                if Self::has_suit_in_hand(&seat.hand, trick_suit, game.config.cards_per_suit) {
+                    return None;
+                }
+                continue;
+            }
+            if card / game.config.cards_per_suit != trick_suit {
+                return None;
+            }
+            let card_pos = Self::find_card_position(&seat.hand, card).ok()?;
+            seat.hand.remove(card_pos);
+            seat.trick_card = Some(card);
+            game.set_seat(slot, seat);
+        }
+
+        let (trick_winner, capped, _) = Self::settle_trick(env, game);
+        game.flipped_card = None;
+        game.trick_suit = None;
+        Self::clear_plays(game);
+        (trick_winner == winner).then_some(capped)
+    }
+
+    /// Outcome a settled trick ends the game with, `None` while play goes
+    /// on. Under `OVERFLOW_INSTANT_LOSS` the `capped` slots lose and the
+    /// best of the rest wins; otherwise an empty hand or draw pile ends it.
+    fn trick_end_outcome(game: &CangkulanGame, capped: u32) -> Option<Outcome> {
+        if capped != 0 && game.overflow_rule == OVERFLOW_INSTANT_LOSS {
+            return Some(Self::determine_winner_among(game, Self::all_slots(game) & !capped));
+        }
+        let ended = game.players.iter().any(|seat| seat.hand.is_empty()) || game.draw_pile.is_empty();
+        ended.then(|| Self::determine_winner(game))
+    }
+
     /// Give a penalty card from the draw pile to a player.
-    fn give_penalty_card(game: &mut CangkulanGame, slot: u32) {
+    ///
+    /// An empty pile means no penalty. If the hand is already at
+    /// `max_hand_size` nothing is drawn and the game's overflow rule is
+    /// returned for the caller to apply.
+    fn give_penalty_card(game: &mut CangkulanGame, slot: u32) -> Option<OverflowRule> {
         if game.draw_pile.is_empty() {
-            return;
+            return None;
+        }
+        let mut seat = game.seat(slot);
+        if seat.hand.len() >= game.max_hand_size {
+            return Some(game.overflow_rule);
         }
         let card = game.draw_pile.get(0).unwrap();
         game.draw_pile.remove(0);
-        match slot {
-            PLAYER_1 => game.hand1.push_back(card),
-            _ => game.hand2.push_back(card),
+        seat.hand.push_back(card);
+        game.set_seat(slot, seat);
+        None
+    }
+
+    /// Draw penalty cards for `slot` until one matches the trick suit or
+    /// the pile runs out, adding (slot, matched) to `draws` per card. Stops
+    /// at the hand-size cap, returning the overflow rule like
+    /// `give_penalty_card`.
+    fn draw_until_follow(game: &mut CangkulanGame, slot: u32, draws: &mut Vec<(u32, bool)>) -> Option<OverflowRule> {
+        let trick_suit = game.trick_suit?;
+        while let Some(card) = game.draw_pile.first() {
+            if let Some(rule) = Self::give_penalty_card(game, slot) {
+                return Some(rule);
+            }
+            let matched = card / game.config.cards_per_suit == trick_suit;
+            draws.push_back((slot, matched));
+            if matched {
+                break;
+            }
         }
+        None
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -2066,50 +4888,189 @@ impl CangkulanContract {
     // ═══════════════════════════════════════════════════════════════════════════
 
     fn determine_winner(game: &CangkulanGame) -> Outcome {
-        let len1 = game.hand1.len();
-        let len2 = game.hand2.len();
+        Self::determine_winner_among(game, Self::all_slots(game))
+    }
 
+    /// Winner among the slots in `candidates` (see `slot_bit`); everyone
+    /// else has already lost. A tie at the top after every rule is a draw.
+    fn determine_winner_among(game: &CangkulanGame, candidates: u32) -> Outcome {
+        if game.config.teams {
+            return Self::determine_team_winner_among(game, candidates);
+        }
         // Primary: "habis duluan" — ran out of cards first wins.
-        // If one player has 0 cards and the opponent still holds cards,
-        // the empty-handed player wins outright.
-        if len1 == 0 && len2 > 0 {
-            return OUTCOME_PLAYER1_WIN;
-        } else if len2 == 0 && len1 > 0 {
-            return OUTCOME_PLAYER2_WIN;
-        }
-
-        // Secondary: most tricks won
-        if game.tricks_won1 > game.tricks_won2 {
-            return OUTCOME_PLAYER1_WIN;
-        } else if game.tricks_won2 > game.tricks_won1 {
-            return OUTCOME_PLAYER2_WIN;
-        }
-
-        // Tie-break 1: fewer cards remaining in hand
-        if len1 < len2 {
-            return OUTCOME_PLAYER1_WIN;
-        } else if len2 < len1 {
-            return OUTCOME_PLAYER2_WIN;
-        }
-
-        // Tie-break 2: lower total card value wins
-        let sum1 = Self::hand_total_value(&game.hand1);
-        let sum2 = Self::hand_total_value(&game.hand2);
-        if sum1 < sum2 {
-            OUTCOME_PLAYER1_WIN
-        } else if sum2 < sum1 {
-            OUTCOME_PLAYER2_WIN
+        // If some players have 0 cards while others still hold cards,
+        // only the empty-handed ones remain in contention.
+        let emptied = candidates & Self::slots_where(game, |seat| seat.hand.is_empty());
+        let mut best = if emptied != 0 { emptied } else { candidates };
+
+        // Then most tricks won and fewer cards remaining in hand, in the
+        // order of the game's tie-break rule
+        let tricks = |seat: &PlayerSlot| seat.tricks_won;
+        let cards = |seat: &PlayerSlot| u32::MAX - seat.hand.len();
+        if game.config.tie_break == TIE_BREAK_CARDS {
+            best = Self::keep_best(game, best, cards);
+            best = Self::keep_best(game, best, tricks);
+        } else {
+            best = Self::keep_best(game, best, tricks);
+            best = Self::keep_best(game, best, cards);
+        }
+
+        // Last: lower total card value wins
+        best = Self::keep_best(game, best, |seat| u32::MAX - Self::hand_total_value(&seat.hand, game.config.cards_per_suit));
+
+        if best.count_ones() == 1 {
+            Self::win_outcome(best.trailing_zeros() + 1)
         } else {
             OUTCOME_DRAW
         }
     }
 
-    fn hand_total_value(hand: &Vec<u32>) -> u32 {
+    /// Team version of `determine_winner_among`. A team stays in contention
+    /// only while both partners do, and is ranked by the same rules on the
+    /// partners' combined hands and tricks. The win is recorded under the
+    /// team's lower seat, so the outcome is always P1, P2 or a draw.
+    fn determine_team_winner_among(game: &CangkulanGame, candidates: u32) -> Outcome {
+        let rank = |team: u32| {
+            let (mut emptied, mut tricks, mut cards, mut value) = (false, 0u32, 0u32, 0u32);
+            for slot in [team, team + 2] {
+                let seat = game.seat(slot);
+                emptied |= seat.hand.is_empty();
+                tricks += seat.tricks_won;
+                cards += seat.hand.len();
+                value += Self::hand_total_value(&seat.hand, game.config.cards_per_suit);
+            }
+            let (fewer_cards, fewer_value) = (u32::MAX - cards, u32::MAX - value);
+            if game.config.tie_break == TIE_BREAK_CARDS {
+                (emptied, fewer_cards, tricks, fewer_value)
+            } else {
+                (emptied, tricks, fewer_cards, fewer_value)
+            }
+        };
+        let standing = |team: u32| candidates & Self::team_slots(team) == Self::team_slots(team);
+        match (standing(1), standing(2)) {
+            (true, true) => match rank(1).cmp(&rank(2)) {
+                core::cmp::Ordering::Greater => OUTCOME_PLAYER1_WIN,
+                core::cmp::Ordering::Less => OUTCOME_PLAYER2_WIN,
+                core::cmp::Ordering::Equal => OUTCOME_DRAW,
+            },
+            (true, false) => OUTCOME_PLAYER1_WIN,
+            (false, true) => OUTCOME_PLAYER2_WIN,
+            (false, false) => OUTCOME_DRAW,
+        }
+    }
+
+    /// Seats of team 1 (seats 1 and 3) or team 2 (seats 2 and 4).
+    fn team_slots(team: u32) -> u32 {
+        Self::slot_bit(team) | Self::slot_bit(team + 2)
+    }
+
+    /// Seats that won under `outcome`: the winner, both partners of a
+    /// winning team, or none for a draw or no result.
+    fn winning_slots(game: &CangkulanGame, outcome: Outcome) -> u32 {
+        match Self::outcome_winner(outcome) {
+            Some(team) if game.config.teams => Self::team_slots(team),
+            Some(slot) => Self::slot_bit(slot),
+            None => 0,
+        }
+    }
+
+    /// Whether the Game Hub tracks the game: two seats, or two teams
+    /// reported through seats 1 and 2.
+    fn hub_tracked(seats: u32, config: &GameConfig) -> bool {
+        seats == 2 || config.teams
+    }
+
+    /// The slots of `candidates` with the highest `score`.
+    fn keep_best(game: &CangkulanGame, candidates: u32, score: impl Fn(&PlayerSlot) -> u32) -> u32 {
+        let mut best = 0u32;
+        let mut top = 0u32;
+        for (i, seat) in game.players.iter().enumerate() {
+            let bit = 1 << i;
+            if candidates & bit == 0 {
+                continue;
+            }
+            let value = score(&seat);
+            if best == 0 || value > top {
+                best = bit;
+                top = value;
+            } else if value == top {
+                best |= bit;
+            }
+        }
+        best
+    }
+
+    /// Bit of `slot` in a slot set: `1 << (slot - 1)`.
+    fn slot_bit(slot: u32) -> u32 {
+        1 << (slot - 1)
+    }
+
+    fn all_slots(game: &CangkulanGame) -> u32 {
+        (1 << game.players.len()) - 1
+    }
+
+    /// The slots whose seat satisfies `pred`.
+    fn slots_where(game: &CangkulanGame, pred: impl Fn(&PlayerSlot) -> bool) -> u32 {
+        let mut slots = 0u32;
+        for (i, seat) in game.players.iter().enumerate() {
+            if pred(&seat) {
+                slots |= 1 << i;
+            }
+        }
+        slots
+    }
+
+    /// Upper bound on the tricks still to be resolved: the one in progress
+    /// plus one per card left to flip. Penalty draws only shorten this.
+    fn max_tricks_left(game: &CangkulanGame) -> u32 {
+        game.draw_pile.len() + game.flipped_card.is_some() as u32
+    }
+
+    /// Outcome `determine_winner` must produce however the rest is played.
+    ///
+    /// A player is a forced winner when, with `r = max_tricks_left`:
+    /// - every opponent holds more than `r` cards, so can never empty their
+    ///   hand (each trick removes at most one card);
+    /// - the player leads every opponent on tricks by more than `r`, so
+    ///   none can catch up or tie before the pile runs out;
+    /// - under `OVERFLOW_INSTANT_LOSS`, `r` rounds of `penalty_draw` cards
+    ///   (the whole pile under `draw_until_follow`) cannot push the
+    ///   player's hand to the cap.
+    ///
+    /// Emptying one's own hand first only wins sooner, and an opponent
+    /// losing to the cap leaves the player ahead of the rest, so neither
+    /// is a risk.
+    fn forced_outcome(game: &CangkulanGame) -> Option<Outcome> {
+        if game.config.teams {
+            return None;
+        }
+        let r = Self::max_tricks_left(game);
+        let max_draws = if game.config.draw_until_follow {
+            game.draw_pile.len()
+        } else {
+            r.saturating_mul(game.config.penalty_draw)
+        };
+        for slot in 1..=game.players.len() {
+            let seat = game.seat(slot);
+            let safe = game.overflow_rule != OVERFLOW_INSTANT_LOSS
+                || seat.hand.len().saturating_add(max_draws) <= game.max_hand_size;
+            let ahead = game.players.iter().enumerate().all(|(i, opp)| {
+                i as u32 + 1 == slot
+                    || (opp.hand.len() > r && seat.tricks_won > opp.tricks_won.saturating_add(r))
+            });
+            if safe && ahead {
+                return Some(Self::win_outcome(slot));
+            }
+        }
+        None
+    }
+
+    fn hand_total_value(hand: &Vec<u32>, cards_per_suit: u32) -> u32 {
         let mut total: u32 = 0;
         let mut i: u32 = 0;
         while i < hand.len() {
             let card = hand.get(i).unwrap();
-            total += card % CARDS_PER_SUIT + 2; // value = id % 9 + 2
+            total += card % cards_per_suit + 2; // value = id % cards_per_suit + 2
             i += 1;
         }
         total
@@ -2119,20 +5080,6 @@ impl CangkulanContract {
     //  Internal: Hand helpers
     // ═══════════════════════════════════════════════════════════════════════════
 
-    fn get_hand(game: &CangkulanGame, slot: u32) -> Vec<u32> {
-        match slot {
-            PLAYER_1 => game.hand1.clone(),
-            _ => game.hand2.clone(),
-        }
-    }
-
-    fn set_hand(game: &mut CangkulanGame, slot: u32, hand: Vec<u32>) {
-        match slot {
-            PLAYER_1 => game.hand1 = hand,
-            _ => game.hand2 = hand,
-        }
-    }
-
     fn find_card_position(hand: &Vec<u32>, card_id: u32) -> Result<u32, CangkulanError> {
         let mut i: u32 = 0;
         while i < hand.len() {
@@ -2144,11 +5091,11 @@ impl CangkulanContract {
         Err(CangkulanError::CardNotInHand)
     }
 
-    fn has_suit_in_hand(hand: &Vec<u32>, suit: u32) -> bool {
+    fn has_suit_in_hand(hand: &Vec<u32>, suit: u32, cards_per_suit: u32) -> bool {
         let mut i: u32 = 0;
         while i < hand.len() {
             let card = hand.get(i).unwrap();
-            if card / CARDS_PER_SUIT == suit {
+            if card / cards_per_suit == suit {
                 return true;
             }
             i += 1;
@@ -2160,37 +5107,71 @@ impl CangkulanContract {
     //  Internal: Timeout logic
     // ═══════════════════════════════════════════════════════════════════════════
 
-    fn determine_timeout_outcome(game: &CangkulanGame) -> Result<Outcome, CangkulanError> {
+    /// Bitmask of the slots that have made the move the current phase
+    /// waits on; `None` outside a phase with a clock.
+    fn acted_slots(game: &CangkulanGame) -> Option<u32> {
         match game.lifecycle_state {
-            STATE_SEED_COMMIT => {
-                match (game.seed_commit1.is_some(), game.seed_commit2.is_some()) {
-                    (true, false) => Ok(OUTCOME_PLAYER1_WIN),
-                    (false, true) => Ok(OUTCOME_PLAYER2_WIN),
-                    _ => Err(CangkulanError::TimeoutNotApplicable),
-                }
+            STATE_SEED_COMMIT => Some(Self::slots_where(game, |seat| seat.seed_commit.is_some())),
+            STATE_SEED_REVEAL => Some(Self::slots_where(game, |seat| seat.seed_revealed)),
+            STATE_PLAYING if Self::is_commit_state(game.trick_state) => {
+                Some(Self::slots_where(game, |seat| seat.play_commit.is_some()))
             }
-            STATE_SEED_REVEAL => {
-                match (game.seed_revealed1, game.seed_revealed2) {
-                    (true, false) => Ok(OUTCOME_PLAYER1_WIN),
-                    (false, true) => Ok(OUTCOME_PLAYER2_WIN),
-                    (false, false) => Ok(OUTCOME_DRAW),
-                    _ => Err(CangkulanError::TimeoutNotApplicable),
-                }
+            STATE_PLAYING if Self::is_reveal_state(game.trick_state) => {
+                Some(Self::slots_where(game, |seat| seat.play_revealed))
             }
-            STATE_PLAYING => {
-                // During commit phase, whoever hasn't committed loses
-                // During reveal phase, whoever hasn't revealed loses
-                match game.trick_state {
-                    TRICK_COMMIT_WAIT_P1 | TRICK_REVEAL_WAIT_P1 => Ok(OUTCOME_PLAYER2_WIN),
-                    TRICK_COMMIT_WAIT_P2 | TRICK_REVEAL_WAIT_P2 => Ok(OUTCOME_PLAYER1_WIN),
-                    TRICK_COMMIT_WAIT_BOTH | TRICK_REVEAL_WAIT_BOTH => {
-                        // Both haven't responded — determine by cards count
-                        Ok(Self::determine_winner(game))
-                    }
-                    _ => Err(CangkulanError::TimeoutNotApplicable),
-                }
+            _ => None,
+        }
+    }
+
+    /// Whether every seat the blitz clock waits on is past the deadline and
+    /// its whole time bank.
+    fn blitz_flagged(env: &Env, game: &CangkulanGame) -> bool {
+        let (Some(deadline), Some(acted)) = (game.deadline_ledger, Self::acted_slots(game)) else {
+            return false;
+        };
+        let now = env.ledger().sequence();
+        (1..=game.players.len())
+            .filter(|slot| acted & Self::slot_bit(*slot) == 0)
+            .all(|slot| now > deadline.saturating_add(game.time_banks.get_unchecked(slot - 1)))
+    }
+
+    /// Restart the timeout clock after a state transition and announce the
+    /// new deadline with the players it waits on.
+    fn reset_deadline(env: &Env, session_id: u32, game: &mut CangkulanGame) {
+        let deadline_ledger = env.ledger().sequence().saturating_add(game.config.timeout_ledgers);
+        game.deadline_nonce = Some(game.action_nonce.saturating_add(TIMEOUT_ACTIONS));
+        game.deadline_ledger = Some(deadline_ledger);
+
+        let acted = Self::acted_slots(game).unwrap_or(Self::all_slots(game));
+        let mut waiting_on = Vec::new(env);
+        for (i, seat) in game.players.iter().enumerate() {
+            if acted & Self::slot_bit(i as u32 + 1) == 0 {
+                waiting_on.push_back(seat.player);
             }
-            _ => Err(CangkulanError::TimeoutNotApplicable),
+        }
+        EvDeadlineSet { session_id, deadline_ledger, waiting_on }.publish(env);
+    }
+
+    /// Players who did the action the clock is waiting for win against
+    /// those who did not: the best of them by `determine_winner_among`.
+    /// If nobody acted, a seed commit timeout has no winner, a seed reveal
+    /// timeout is a draw, and a trick is decided by cards count.
+    fn determine_timeout_outcome(game: &CangkulanGame) -> Result<Outcome, CangkulanError> {
+        // During commit phase, whoever hasn't committed loses
+        // During reveal phase, whoever hasn't revealed loses
+        let acted = Self::acted_slots(game).ok_or(CangkulanError::TimeoutNotApplicable)?;
+
+        if acted == Self::all_slots(game) {
+            return Err(CangkulanError::TimeoutNotApplicable);
+        }
+        if acted != 0 {
+            return Ok(Self::determine_winner_among(game, acted));
+        }
+        match game.lifecycle_state {
+            STATE_SEED_COMMIT => Err(CangkulanError::TimeoutNotApplicable),
+            STATE_SEED_REVEAL => Ok(OUTCOME_DRAW),
+            // Nobody has responded — determine by cards count
+            _ => Ok(Self::determine_winner(game)),
         }
     }
 
@@ -2202,14 +5183,107 @@ impl CangkulanContract {
         game.action_nonce = game.action_nonce.saturating_add(1);
     }
 
-    fn read_game(env: &Env, session_id: u32) -> Result<CangkulanGame, CangkulanError> {
+    /// Resolve the address the hub should account `player` to, consuming one
+    /// game of the guest allowance if `player` is a sponsored guest.
+    fn consume_guest_game(env: &Env, session_id: u32, player: &Address) -> Address {
+        let key = StorageKey::GuestSponsor(player.clone());
+        let Some(mut sponsorship) = env
+            .storage()
+            .persistent()
+            .get::<_, GuestSponsorship>(&key)
+        else {
+            return player.clone();
+        };
+
+        sponsorship.games_left -= 1;
+        if sponsorship.games_left == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &sponsorship);
+        }
+
+        EvGuestGameAttributed {
+            session_id,
+            guest: player.clone(),
+            sponsor: sponsorship.sponsor.clone(),
+            games_left: sponsorship.games_left,
+        }.publish(env);
+        sponsorship.sponsor
+    }
+
+    fn read_prefs(env: &Env, player: &Address) -> NotificationPrefs {
         env.storage()
+            .persistent()
+            .get(&StorageKey::NotificationPrefs(player.clone()))
+            .unwrap_or_default()
+    }
+
+    fn read_game(env: &Env, session_id: u32) -> Result<CangkulanGame, CangkulanError> {
+        let (game, _) = Self::read_stored_game(env, session_id)?;
+        Ok(game)
+    }
+
+    /// Read a game with the layout version it was stored under.
+    fn read_stored_game(env: &Env, session_id: u32) -> Result<(CangkulanGame, u32), CangkulanError> {
+        let raw: Map<Symbol, Val> = env
+            .storage()
             .temporary()
             .get(&StorageKey::Game(session_id))
-            .ok_or(CangkulanError::GameNotFound)
+            .ok_or(CangkulanError::GameNotFound)?;
+        Self::migrate_layout(env, raw)
+    }
+
+    /// Decode a stored game, first bringing an older layout up to
+    /// `GAME_SCHEMA_VERSION` one step at a time. Entries written before
+    /// versioning have no `schema_version` and count as version 0.
+    fn migrate_layout(env: &Env, mut raw: Map<Symbol, Val>) -> Result<(CangkulanGame, u32), CangkulanError> {
+        let version_key = Symbol::new(env, "schema_version");
+        let stored = match raw.get(version_key.clone()) {
+            Some(val) => u32::try_from_val(env, &val).map_err(|_| CangkulanError::UnsupportedGameSchema)?,
+            None => 0,
+        };
+        if stored > GAME_SCHEMA_VERSION {
+            return Err(CangkulanError::UnsupportedGameSchema);
+        }
+        // 0 → 1 only adds `schema_version` itself
+        // 1 → 2 adds the blitz clock, off: `time_banks` and
+        // `config.time_bank_ledgers`
+        if stored < 2 {
+            Self::set_config_field(env, &mut raw, "time_bank_ledgers", 0u32.into_val(env))?;
+            raw.set(Symbol::new(env, "time_banks"), Vec::<u32>::new(env).into_val(env));
+        }
+        // 2 → 3 adds `forfeited_by`, none
+        if stored < 3 {
+            raw.set(Symbol::new(env, "forfeited_by"), 0u32.into_val(env));
+        }
+        // 3 → 4 adds `config.teams`, off
+        if stored < 4 {
+            Self::set_config_field(env, &mut raw, "teams", false.into_val(env))?;
+        }
+        // 4 → 5 adds `vs_bot`, off
+        if stored < 5 {
+            raw.set(Symbol::new(env, "vs_bot"), false.into_val(env));
+        }
+        raw.set(version_key, GAME_SCHEMA_VERSION.into_val(env));
+        let game = CangkulanGame::try_from_val(env, &raw.to_val())
+            .map_err(|_| CangkulanError::UnsupportedGameSchema)?;
+        Ok((game, stored))
+    }
+
+    /// Set `field` of the `config` nested in a raw stored game.
+    fn set_config_field(env: &Env, raw: &mut Map<Symbol, Val>, field: &str, value: Val) -> Result<(), CangkulanError> {
+        let config_key = Symbol::new(env, "config");
+        let mut config: Map<Symbol, Val> = raw
+            .get(config_key.clone())
+            .and_then(|val| Map::try_from_val(env, &val).ok())
+            .ok_or(CangkulanError::UnsupportedGameSchema)?;
+        config.set(Symbol::new(env, field), value);
+        raw.set(config_key, config.into_val(env));
+        Ok(())
     }
 
-    fn write_game(env: &Env, session_id: u32, game: &CangkulanGame) {
+    fn write_game(env: &Env, session_id: u32, game: &mut CangkulanGame) {
+        game.updated_ledger = env.ledger().sequence();
         let key = StorageKey::Game(session_id);
         env.storage().temporary().set(&key, game);
         env.storage()
@@ -2228,6 +5302,42 @@ impl CangkulanContract {
             .ok_or(CangkulanError::AdminNotSet)
     }
 
+    fn load_hand_cap(env: &Env) -> HandCapConfig {
+        env.storage()
+            .instance()
+            .get(&StorageKey::HandCap)
+            .unwrap_or(HandCapConfig {
+                max_hand_size: DEFAULT_MAX_HAND_SIZE,
+                overflow_rule: OVERFLOW_SKIP_PENALTY,
+            })
+    }
+
+    fn active_verifier_override(env: &Env) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::VerifierOverride)
+            .filter(|&expires: &u32| env.ledger().sequence() < expires)
+    }
+
+    /// Whether to skip the ZK verifier for this proof; emits
+    /// `EvVerifierBypassed` when it does.
+    fn bypass_verifier(env: &Env, session_id: u32, player: &Address) -> bool {
+        match Self::active_verifier_override(env) {
+            Some(expires_ledger) => {
+                EvVerifierBypassed { session_id, player: player.clone(), expires_ledger }.publish(env);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn load_entropy_policy(env: &Env) -> EntropyPolicy {
+        env.storage()
+            .instance()
+            .get(&StorageKey::EntropyPolicy)
+            .unwrap_or_default()
+    }
+
     fn load_hub(env: &Env) -> Result<Address, CangkulanError> {
         env.storage()
             .instance()
@@ -2242,11 +5352,21 @@ impl CangkulanContract {
             .ok_or(CangkulanError::VerifierNotSet)
     }
 
-    fn load_ultrahonk_verifier(env: &Env) -> Result<Address, CangkulanError> {
+    /// Verifier `game` pinned for proofs of `mode`.
+    fn mode_verifier(game: &CangkulanGame, mode: u32) -> Address {
+        game.mode_verifiers.get(mode).unwrap_or(game.verifier.clone())
+    }
+
+    fn load_leaderboard(env: &Env) -> Option<Address> {
         env.storage()
             .instance()
-            .get(&StorageKey::UltraHonkVerifierAddress)
-            .ok_or(CangkulanError::UltraHonkVerifierNotSet)
+            .get(&StorageKey::LeaderboardAddress)
+    }
+
+    /// keccak256 over the XDR encoding of the rule config new games snapshot.
+    fn config_hash(env: &Env) -> BytesN<32> {
+        let config = Self::load_hand_cap(env).to_xdr(env);
+        env.crypto().keccak256(&config).into()
     }
 }
 
@@ -0,0 +1,252 @@
+//! # Lobby
+//!
+//! Open challenges, so two players need not agree off-chain and co-sign
+//! `start_game`. A creator posts a stake with `create_challenge`; any other
+//! player takes it with `accept_challenge`, which starts a two-seat game
+//! with the creator in seat 1. Each side authorizes only its own call.
+//!
+//! Under a `StakeEscrow` the creator's stake is locked when the challenge is
+//! posted and the accepter's when it is taken; the challenge keeps the
+//! escrow terms of its creation. `cancel_challenge` returns the creator's
+//! stake: the creator may cancel at any time, anyone once it has expired.
+//!
+//! At most `MAX_OPEN_CHALLENGES` challenges are open at once, and at most
+//! `MAX_CHALLENGES_PER_CREATOR` from one creator, listed by
+//! `get_open_challenges` for matchmaking. A challenge stays open for a day
+//! at most; expired ones are dropped from the listing when the next one is
+//! posted, so abandoned challenges cannot hold the lobby full.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    escrow_terms, CangkulanContract, CangkulanContractArgs, CangkulanContractClient, CangkulanError, GameConfig,
+    StorageKey,
+};
+
+/// Open challenges listed at once.
+pub const MAX_OPEN_CHALLENGES: u32 = 64;
+
+/// Open challenges listed at once from a single creator.
+pub const MAX_CHALLENGES_PER_CREATOR: u32 = 3;
+
+/// Longest a challenge may stay open: 1 day.
+pub const MAX_CHALLENGE_LEDGERS: u32 = 24 * 60 * 60 / crate::LEDGER_RATE_SECS; // 17,280 ledgers
+
+/// An open challenge awaiting an opponent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub creator: Address,
+    pub points: i128,                 // staked by each side
+    pub expires_ledger: u32,          // last ledger it can be accepted
+    // Escrow terms at creation (see `StakeEscrow`); no token when stakes
+    // are only points reported to the hub
+    pub stake_token: Option<Address>,
+    pub rake_bps: u32,
+    pub treasury: Option<Address>,
+}
+
+#[contractevent]
+pub struct EvChallengeCreated {
+    pub challenge_id: u32,
+    pub creator: Address,
+    pub points: i128,
+    pub expires_ledger: u32,
+}
+
+#[contractevent]
+pub struct EvChallengeAccepted {
+    pub challenge_id: u32,
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+pub struct EvChallengeCancelled {
+    pub challenge_id: u32,
+}
+
+#[contractimpl]
+impl CangkulanContract {
+    /// Post an open challenge staking `points`, open until `expires_ledger`
+    /// (at most `MAX_CHALLENGE_LEDGERS` ahead). Expired challenges are
+    /// delisted first; their stakes stay refundable through
+    /// `cancel_challenge`. Returns the challenge id.
+    pub fn create_challenge(
+        env: Env,
+        creator: Address,
+        points: i128,
+        expires_ledger: u32,
+    ) -> Result<u32, CangkulanError> {
+        creator.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let now = env.ledger().sequence();
+        if expires_ledger <= now || expires_ledger - now > MAX_CHALLENGE_LEDGERS {
+            return Err(CangkulanError::InvalidChallengeExpiry);
+        }
+        let mut open = Vec::new(&env);
+        let mut own = 0u32;
+        for (challenge_id, challenge) in Self::live_challenges(&env).iter() {
+            if challenge.creator == creator {
+                own += 1;
+            }
+            open.push_back(challenge_id);
+        }
+        if open.len() >= MAX_OPEN_CHALLENGES {
+            return Err(CangkulanError::LobbyFull);
+        }
+        if own >= MAX_CHALLENGES_PER_CREATOR {
+            return Err(CangkulanError::TooManyChallenges);
+        }
+
+        let escrow = Self::get_stake_escrow(env.clone());
+        if let Some(escrow) = &escrow {
+            if points < 0 {
+                return Err(CangkulanError::InvalidStakeEscrow);
+            }
+            Self::lock_stake(&env, escrow, &creator, points);
+        }
+
+        let challenge_id: u32 = env
+            .storage()
+            .instance()
+            .get(&StorageKey::NextChallengeId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&StorageKey::NextChallengeId, &(challenge_id + 1));
+
+        let challenge = Challenge {
+            creator: creator.clone(),
+            points,
+            expires_ledger,
+            stake_token: escrow.as_ref().map(|escrow| escrow.token.clone()),
+            rake_bps: escrow.as_ref().map_or(0, |escrow| escrow.rake_bps),
+            treasury: escrow.map(|escrow| escrow.treasury),
+        };
+        let key = StorageKey::Challenge(challenge_id);
+        env.storage().persistent().set(&key, &challenge);
+        // Outlive the expiry so a late cancel can still refund the stake
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MAX_CHALLENGE_LEDGERS * 2, MAX_CHALLENGE_LEDGERS * 2);
+        open.push_back(challenge_id);
+        env.storage().instance().set(&StorageKey::OpenChallenges, &open);
+
+        EvChallengeCreated { challenge_id, creator, points, expires_ledger }.publish(&env);
+        Ok(challenge_id)
+    }
+
+    /// Take an open challenge, matching its stake. Starts the game at a
+    /// contract-allocated session id with the creator in seat 1 and
+    /// returns that session id.
+    pub fn accept_challenge(
+        env: Env,
+        challenge_id: u32,
+        player: Address,
+    ) -> Result<u32, CangkulanError> {
+        player.require_auth();
+
+        let challenge = Self::load_challenge(&env, challenge_id)?;
+        if env.ledger().sequence() > challenge.expires_ledger {
+            return Err(CangkulanError::ChallengeExpired);
+        }
+        if player == challenge.creator {
+            return Err(CangkulanError::SelfPlayNotAllowed);
+        }
+
+        let escrow = escrow_terms(&challenge.stake_token, challenge.rake_bps, &challenge.treasury);
+        if let Some(escrow) = &escrow {
+            Self::lock_stake(&env, escrow, &player, challenge.points);
+        }
+        Self::close_challenge(&env, challenge_id);
+
+        let session_id = Self::allocate_session_id(&env);
+        let players = soroban_sdk::vec![&env, challenge.creator, player.clone()];
+        let points = soroban_sdk::vec![&env, challenge.points, challenge.points];
+        Self::open_session(&env, session_id, players, points, escrow, false, GameConfig::standard(), false)?;
+
+        EvChallengeAccepted { challenge_id, session_id, player }.publish(&env);
+        Ok(session_id)
+    }
+
+    /// Withdraw an open challenge and return the creator's stake. The
+    /// creator may cancel at any time; anyone may once it has expired.
+    pub fn cancel_challenge(
+        env: Env,
+        challenge_id: u32,
+        caller: Address,
+    ) -> Result<(), CangkulanError> {
+        caller.require_auth();
+
+        let challenge = Self::load_challenge(&env, challenge_id)?;
+        if caller != challenge.creator && env.ledger().sequence() <= challenge.expires_ledger {
+            return Err(CangkulanError::NotAPlayer);
+        }
+        Self::close_challenge(&env, challenge_id);
+
+        if let (Some(token), true) = (&challenge.stake_token, challenge.points > 0) {
+            soroban_sdk::token::TokenClient::new(&env, token).transfer(
+                &env.current_contract_address(),
+                &challenge.creator,
+                &challenge.points,
+            );
+        }
+        EvChallengeCancelled { challenge_id }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_challenge(env: Env, challenge_id: u32) -> Option<Challenge> {
+        env.storage().persistent().get(&StorageKey::Challenge(challenge_id))
+    }
+
+    /// Ids of the challenges still open to accept, oldest first.
+    pub fn get_open_challenges(env: Env) -> Vec<u32> {
+        let mut open = Vec::new(&env);
+        for (challenge_id, _) in Self::live_challenges(&env).iter() {
+            open.push_back(challenge_id);
+        }
+        open
+    }
+}
+
+impl CangkulanContract {
+    fn load_challenge(env: &Env, challenge_id: u32) -> Result<Challenge, CangkulanError> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::Challenge(challenge_id))
+            .ok_or(CangkulanError::ChallengeNotFound)
+    }
+
+    /// Listed challenges that have not expired, oldest first.
+    fn live_challenges(env: &Env) -> Vec<(u32, Challenge)> {
+        let now = env.ledger().sequence();
+        let mut live = Vec::new(env);
+        for challenge_id in Self::listed_challenges(env).iter() {
+            if let Some(challenge) = Self::get_challenge(env.clone(), challenge_id) {
+                if now <= challenge.expires_ledger {
+                    live.push_back((challenge_id, challenge));
+                }
+            }
+        }
+        live
+    }
+
+    fn listed_challenges(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::OpenChallenges)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Remove a challenge and its listing.
+    fn close_challenge(env: &Env, challenge_id: u32) {
+        env.storage().persistent().remove(&StorageKey::Challenge(challenge_id));
+        let mut open = Self::listed_challenges(env);
+        if let Some(index) = open.first_index_of(challenge_id) {
+            open.remove(index);
+        }
+        env.storage().instance().set(&StorageKey::OpenChallenges, &open);
+    }
+}
@@ -0,0 +1,200 @@
+//! # Match play
+//!
+//! Several deals scored to a target. `start_match` seats the players and
+//! deals the first game. Each finished deal awards every seat its tricks
+//! won less the cards left in its hand, never below zero, added to the
+//! match score. `next_deal` scores the finished deal and then either ends
+//! the match, once a seat has reached `target` with the outright highest
+//! score, or deals the next game with the same seats. A tie for the lead
+//! at the target plays on. A forfeited deal concedes the whole match to
+//! that deal's winner; a cancelled deal scores nothing.
+//!
+//! Deals are ordinary sessions at contract-allocated ids (see
+//! `CONTRACT_SESSION_BIT`), played, reported to the Game Hub and
+//! Leaderboard and timed out like any other game. Matches carry no stakes.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, vec, Address, Env, IntoVal, Vec};
+
+use crate::{
+    CangkulanContract, CangkulanContractArgs, CangkulanContractClient, CangkulanError, CangkulanGame, GameConfig,
+    StorageKey, GAME_TTL_LEDGERS, MAX_PLAYERS, MIN_PLAYERS, OUTCOME_CANCELLED, STATE_FINISHED,
+};
+
+/// Highest score a match may be played to.
+pub const MAX_MATCH_TARGET: u32 = 50;
+
+/// A match in progress or decided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchState {
+    pub players: Vec<Address>, // seat order of every deal
+    pub target: u32,
+    pub scores: Vec<u32>,      // match points per seat
+    pub round: u32,            // deals dealt so far, the current one included
+    pub session_id: u32,       // session of the current deal
+    pub winner: u32,           // seat that won the match, 0 while in play
+}
+
+#[contractevent]
+pub struct EvMatchStarted {
+    pub match_id: u32,
+    pub players: Vec<Address>,
+    pub target: u32,
+}
+
+#[contractevent]
+pub struct EvMatchDealScored {
+    pub match_id: u32,
+    pub session_id: u32,
+    pub points: Vec<u32>,
+    pub scores: Vec<u32>,
+}
+
+#[contractevent]
+pub struct EvMatchEnded {
+    pub match_id: u32,
+    pub winner: Address,
+    pub scores: Vec<u32>,
+}
+
+#[contractimpl]
+impl CangkulanContract {
+    /// Start a match to `target` points and deal its first game. Every
+    /// player authorizes `(match_id, target)`. Returns the session id of
+    /// the first deal.
+    pub fn start_match(
+        env: Env,
+        match_id: u32,
+        players: Vec<Address>,
+        target: u32,
+    ) -> Result<u32, CangkulanError> {
+        let n = players.len();
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&n) {
+            return Err(CangkulanError::InvalidPlayerCount);
+        }
+        for i in 0..n {
+            if players.slice(i + 1..).contains(players.get_unchecked(i)) {
+                return Err(CangkulanError::SelfPlayNotAllowed);
+            }
+        }
+        if target == 0 || target > MAX_MATCH_TARGET {
+            return Err(CangkulanError::InvalidMatchTarget);
+        }
+        if Self::get_match(env.clone(), match_id).is_some() {
+            return Err(CangkulanError::SessionAlreadyExists);
+        }
+        for player in players.iter() {
+            player.require_auth_for_args(vec![&env, match_id.into_val(&env), target.into_val(&env)]);
+        }
+
+        let mut scores = Vec::new(&env);
+        for _ in 0..n {
+            scores.push_back(0u32);
+        }
+        let mut state = MatchState {
+            players: players.clone(),
+            target,
+            scores,
+            round: 0,
+            session_id: 0,
+            winner: 0,
+        };
+        Self::deal_next(&env, &mut state)?;
+        Self::write_match(&env, match_id, &state);
+
+        EvMatchStarted { match_id, players, target }.publish(&env);
+        Ok(state.session_id)
+    }
+
+    /// Score the finished current deal and either end the match or deal
+    /// the next game. Needs no auth: the players agreed to every deal at
+    /// `start_match`. Returns the updated match.
+    pub fn next_deal(env: Env, match_id: u32) -> Result<MatchState, CangkulanError> {
+        let mut state = Self::get_match(env.clone(), match_id).ok_or(CangkulanError::MatchNotFound)?;
+        if state.winner != 0 {
+            return Err(CangkulanError::MatchOver);
+        }
+        let game = Self::read_game(&env, state.session_id)?;
+        if game.lifecycle_state != STATE_FINISHED {
+            return Err(CangkulanError::GameNotFinished);
+        }
+
+        let points = Self::deal_points(&env, &game);
+        for (i, won) in points.iter().enumerate() {
+            let i = i as u32;
+            state.scores.set(i, state.scores.get_unchecked(i).saturating_add(won));
+        }
+        EvMatchDealScored {
+            match_id,
+            session_id: state.session_id,
+            points,
+            scores: state.scores.clone(),
+        }.publish(&env);
+
+        state.winner = match Self::outcome_winner(game.outcome) {
+            Some(winner) if game.forfeited_by != 0 => winner,
+            _ => Self::match_leader(&state),
+        };
+        if state.winner != 0 {
+            EvMatchEnded {
+                match_id,
+                winner: state.players.get_unchecked(state.winner - 1),
+                scores: state.scores.clone(),
+            }.publish(&env);
+        } else {
+            Self::deal_next(&env, &mut state)?;
+        }
+        Self::write_match(&env, match_id, &state);
+        Ok(state)
+    }
+
+    pub fn get_match(env: Env, match_id: u32) -> Option<MatchState> {
+        env.storage().temporary().get(&StorageKey::Match(match_id))
+    }
+}
+
+impl CangkulanContract {
+    /// Open the match's next deal with no stakes.
+    fn deal_next(env: &Env, state: &mut MatchState) -> Result<(), CangkulanError> {
+        state.round += 1;
+        state.session_id = Self::allocate_session_id(env);
+        let mut points = Vec::new(env);
+        for _ in 0..state.players.len() {
+            points.push_back(0i128);
+        }
+        Self::open_session(env, state.session_id, state.players.clone(), points, None, false, GameConfig::standard(), false)
+    }
+
+    /// Tricks won less cards left in hand, per seat, floored at zero; all
+    /// zero for a cancelled deal.
+    fn deal_points(env: &Env, game: &CangkulanGame) -> Vec<u32> {
+        let mut points = Vec::new(env);
+        for seat in game.players.iter() {
+            let won = match game.outcome {
+                OUTCOME_CANCELLED => 0,
+                _ => seat.tricks_won.saturating_sub(seat.hand.len()),
+            };
+            points.push_back(won);
+        }
+        points
+    }
+
+    /// Seat holding the outright highest score once it reaches the target,
+    /// else 0.
+    fn match_leader(state: &MatchState) -> u32 {
+        let best = state.scores.iter().max().unwrap_or(0);
+        let mut leaders = state.scores.iter().enumerate().filter(|(_, score)| *score == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((i, _)), None) if best >= state.target => i as u32 + 1,
+            _ => 0,
+        }
+    }
+
+    fn write_match(env: &Env, match_id: u32, state: &MatchState) {
+        let key = StorageKey::Match(match_id);
+        env.storage().temporary().set(&key, state);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+}
@@ -3,7 +3,9 @@
 //! Unit tests for the Cangkulan Lite contract.
 //!
 //! Uses a mock Game Hub (tracks start/end counts) and a mock ZK verifier
-//! (accepts any non-empty proof) for isolated testing.
+//! (accepts any non-empty proof) for isolated testing. The integration tests
+//! register the real verifier instead and build proofs with
+//! `zk_verifier::testutils`.
 //!
 //! The playing phase uses a commit-reveal protocol:
 //! 1. Both players commit keccak256(card_id_be4 || salt)
@@ -11,15 +13,29 @@
 //! 3. Contract verifies, applies, and resolves the trick
 
 use crate::{
-    CangkulanContract, CangkulanContractClient, CangkulanError,
-    CANNOT_FOLLOW_SENTINEL, CARDS_PER_SUIT, STATE_PLAYING, STATE_SEED_COMMIT,
-    STATE_SEED_REVEAL, STATE_FINISHED, TRICK_COMMIT_WAIT_BOTH,
-    TRICK_COMMIT_WAIT_P2, TRICK_REVEAL_WAIT_BOTH, TRICK_REVEAL_WAIT_P2,
-    OUTCOME_PLAYER1_WIN, OUTCOME_PLAYER2_WIN,
+    CangkulanContract, CangkulanContractClient, CangkulanError, NotificationPrefs,
+    CANNOT_FOLLOW_SENTINEL, GAME_SCHEMA_VERSION, GAME_TTL_LEDGERS, TIMEOUT_GRACE_LEDGERS, CARDS_PER_SUIT_36, CARDS_PER_SUIT_52, STATE_PLAYING, STATE_SEED_COMMIT,
+    STATE_SEED_REVEAL, STATE_FINISHED, TRICK_NONE, TRICK_COMMIT_WAIT_BOTH,
+    TRICK_COMMIT_WAIT_P2, TRICK_COMMIT_WAIT_P3, TRICK_REVEAL_WAIT_BOTH, TRICK_REVEAL_WAIT_P2,
+    OUTCOME_CANCELLED, OUTCOME_DRAW, OUTCOME_PLAYER1_WIN, OUTCOME_UNRESOLVED, OUTCOME_PLAYER2_WIN, OVERFLOW_INSTANT_LOSS, OVERFLOW_SKIP_PENALTY, Payout,
+    StakeEscrow, GameConfig, CONTRACT_SESSION_BIT, TIE_BREAK_CARDS, TIE_BREAK_TRICKS, MAX_CHALLENGE_LEDGERS, MAX_CHALLENGES_PER_CREATOR, MAX_OPEN_CHALLENGES, MAX_RAKE_BPS,
+    EntropyPolicy, HandCapConfig, Integrations, PendingVerifierOverride, SEED_PROOF_NIZK, SEED_PROOF_NOIR, SEED_PROOF_PEDERSEN,
+    ChannelState, MatchState, MAX_HISTORY_PAGE, MAX_HISTORY_SCAN_CHUNKS, MAX_MATCH_TARGET, BotReveal, BotTurn, CangkulanBot, EvDeadlineSet, EvFeeSet, EvGameCancelled, EvGameForfeited, EvRevealAudited, EvShuffleDisputed, EvTreasuryWithdrawn, EvTimeoutResolved, TrickPayload, CHANNEL_CHALLENGE_LEDGERS,
+};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, Event as _, IntoVal, Map, Symbol, Val,
+    Vec,
 };
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, Vec};
 use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use leaderboard::{Leaderboard, LeaderboardClient, FORFEIT_PENALTY};
+use zk_verifier::DeckParams;
+use ed25519_dalek::{Signer, SigningKey};
+use zk_verifier::spec::{
+    CHALLENGE_TAG, MODE_CANGKUL_HAND, MODE_PRIVATE_HAND, PEDERSEN_CHALLENGE_TAG, PEDERSEN_H_DST, PEDERSEN_H_MSG,
+    RING_MAX_SET_SIZE, RING_MEMBER_PROOF_LEN, RING_PROOF_BASE_LEN,
+};
 
 // ════════════════════════════════════════════════════════════════════════════
 //  Mock Game Hub
@@ -30,6 +46,7 @@ use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
 enum MockKey {
     StartCount,
     EndCount,
+    LastPlayers,
 }
 
 #[contract]
@@ -41,13 +58,14 @@ impl MockGameHub {
         env: Env,
         _game_id: Address,
         _session_id: u32,
-        _player1: Address,
-        _player2: Address,
+        player1: Address,
+        player2: Address,
         _player1_points: i128,
         _player2_points: i128,
     ) {
         let count: u32 = env.storage().instance().get(&MockKey::StartCount).unwrap_or(0);
         env.storage().instance().set(&MockKey::StartCount, &(count + 1));
+        env.storage().instance().set(&MockKey::LastPlayers, &(player1, player2));
     }
 
     pub fn end_game(env: Env, _session_id: u32, _player1_won: bool) {
@@ -62,19 +80,64 @@ impl MockGameHub {
     pub fn get_end_count(env: Env) -> u32 {
         env.storage().instance().get(&MockKey::EndCount).unwrap_or(0)
     }
+
+    pub fn get_last_players(env: Env) -> (Address, Address) {
+        env.storage().instance().get(&MockKey::LastPlayers).unwrap()
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════
 //  Mock ZK Verifier (accepts any non-empty proof)
 // ════════════════════════════════════════════════════════════════════════════
 
+#[contracttype]
+#[derive(Clone)]
+enum MockVerifierKey {
+    Recorded(Address, u32, Address, u32, BytesN<32>),
+}
+
 #[contract]
 pub struct MockZkVerifier;
 
 #[contractimpl]
 impl MockZkVerifier {
-    pub fn verify(_env: Env, _public_inputs: Bytes, proof: Bytes) -> bool {
-        proof.len() > 0
+    pub fn verify_from(_env: Env, _caller: Address, _public_inputs: Bytes, proof: Bytes) -> bool {
+        !proof.is_empty()
+    }
+
+    /// Stand-in for a successful `verify_and_record`.
+    pub fn record_verification(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        mode: u32,
+        commitment: BytesN<32>,
+    ) {
+        let key = MockVerifierKey::Recorded(consumer, session_id, player, mode, commitment);
+        env.storage().temporary().set(&key, &true);
+    }
+
+    pub fn consume_verification(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        mode: u32,
+        commitment: BytesN<32>,
+    ) -> bool {
+        let key = MockVerifierKey::Recorded(consumer, session_id, player, mode, commitment);
+        let found = env.storage().temporary().has(&key);
+        env.storage().temporary().remove(&key);
+        found
+    }
+
+    pub fn set_deck_params(env: Env, params: DeckParams) {
+        env.storage().instance().set(&soroban_sdk::symbol_short!("deck"), &params);
+    }
+
+    pub fn get_deck_params(env: Env) -> DeckParams {
+        env.storage().instance().get(&soroban_sdk::symbol_short!("deck")).unwrap_or_default()
     }
 }
 
@@ -201,8 +264,8 @@ fn advance_to_playing(
     let commit1 = compute_nizk_commitment(env, &seed_hash1, &blinding1, player1);
     let commit2 = compute_nizk_commitment(env, &seed_hash2, &blinding2, player2);
 
-    client.commit_seed(&session_id, player1, &commit1);
-    client.commit_seed(&session_id, player2, &commit2);
+    client.commit_seed(&session_id, player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&session_id, player2, &commit2, &SEED_PROOF_NIZK);
 
     let proof1 = build_nizk_proof(env, &blinding1);
     let proof2 = build_nizk_proof(env, &blinding2);
@@ -240,11 +303,11 @@ fn assert_cangkulan_error<T, E>(
 }
 
 fn card_suit(card_id: u32) -> u32 {
-    card_id / CARDS_PER_SUIT
+    card_id / CARDS_PER_SUIT_36
 }
 
 fn card_value(card_id: u32) -> u32 {
-    card_id % CARDS_PER_SUIT + 2
+    card_id % CARDS_PER_SUIT_36 + 2
 }
 
 /// Helper: check if any card in hand matches the given suit.
@@ -283,8 +346,8 @@ fn play_one_trick(
     sid: u32,
 ) -> (i32, i32, i32) {
     let before = client.get_game_debug(&sid);
-    let h1_before = before.hand1.len() as i32;
-    let h2_before = before.hand2.len() as i32;
+    let h1_before = before.seat(1).hand.len() as i32;
+    let h2_before = before.seat(2).hand.len() as i32;
     let dp_before = before.draw_pile.len() as i32;
     let trick_suit = before.trick_suit.unwrap();
     let nonce = before.action_nonce;
@@ -293,11 +356,11 @@ fn play_one_trick(
     let salt2 = test_salt(env, 0x22);
 
     // Determine actions
-    let p1_action = match first_card_of_suit(&before.hand1, trick_suit) {
+    let p1_action = match first_card_of_suit(&before.seat(1).hand, trick_suit) {
         Some(c) => c,
         None => CANNOT_FOLLOW_SENTINEL,
     };
-    let p2_action = match first_card_of_suit(&before.hand2, trick_suit) {
+    let p2_action = match first_card_of_suit(&before.seat(2).hand, trick_suit) {
         Some(c) => c,
         None => CANNOT_FOLLOW_SENTINEL,
     };
@@ -316,8 +379,8 @@ fn play_one_trick(
 
     if mid2.lifecycle_state == STATE_FINISHED {
         return (
-            mid2.hand1.len() as i32 - h1_before,
-            mid2.hand2.len() as i32 - h2_before,
+            mid2.seat(1).hand.len() as i32 - h1_before,
+            mid2.seat(2).hand.len() as i32 - h2_before,
             mid2.draw_pile.len() as i32 - dp_before,
         );
     }
@@ -326,8 +389,8 @@ fn play_one_trick(
 
     let after = client.get_game_debug(&sid);
     (
-        after.hand1.len() as i32 - h1_before,
-        after.hand2.len() as i32 - h2_before,
+        after.seat(1).hand.len() as i32 - h1_before,
+        after.seat(2).hand.len() as i32 - h2_before,
         after.draw_pile.len() as i32 - dp_before,
     )
 }
@@ -344,11 +407,11 @@ fn start_game_success() {
     client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game_debug(&sid);
-    assert_eq!(game.player1, player1);
-    assert_eq!(game.player2, player2);
+    assert_eq!(game.seat(1).player, player1);
+    assert_eq!(game.seat(2).player, player2);
     assert_eq!(game.lifecycle_state, STATE_SEED_COMMIT);
-    assert!(game.hand1.is_empty());
-    assert!(game.hand2.is_empty());
+    assert!(game.seat(1).hand.is_empty());
+    assert!(game.seat(2).hand.is_empty());
     assert_eq!(hub.get_start_count(), 1);
 }
 
@@ -393,11 +456,11 @@ fn seed_commit_reveal_flow() {
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
     let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
 
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_SEED_COMMIT);
 
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_SEED_REVEAL);
 
@@ -411,8 +474,8 @@ fn seed_commit_reveal_flow() {
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_PLAYING);
 
-    assert_eq!(game.hand1.len(), 5);
-    assert_eq!(game.hand2.len(), 5);
+    assert_eq!(game.seat(1).hand.len(), 5);
+    assert_eq!(game.seat(2).hand.len(), 5);
     assert_eq!(game.draw_pile.len(), 25);
     assert!(game.flipped_card.is_some());
     assert!(game.trick_suit.is_some());
@@ -433,8 +496,8 @@ fn double_commit_rejected() {
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
 
-    client.commit_seed(&sid, &player1, &commit1);
-    let result = client.try_commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    let result = client.try_commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
     assert_cangkulan_error(&result, CangkulanError::CommitAlreadySubmitted);
 }
 
@@ -456,14 +519,92 @@ fn weak_seed_entropy_rejected() {
     let seed_hash2 = compute_seed_hash(&env, &seed2);
     let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
 
-    client.commit_seed(&sid, &player1, &commit);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
 
     let proof = build_nizk_proof(&env, &blinding);
     let result = client.try_reveal_seed(&sid, &player1, &weak_seed_hash, &proof);
     assert_cangkulan_error(&result, CangkulanError::WeakSeedEntropy);
 }
 
+#[test]
+fn entropy_policy_configurable_by_admin() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 16u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_entropy_policy(), EntropyPolicy::default());
+
+    // Period-4 hash: enough distinct bytes for the default policy.
+    let mut periodic = [0u8; 32];
+    for (i, b) in periodic.iter_mut().enumerate() {
+        *b = (i % 4) as u8 + 1;
+    }
+    let seed_hash = BytesN::<32>::from_array(&env, &periodic);
+    let blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit = compute_nizk_commitment(&env, &seed_hash, &blinding, &player1);
+    let commit2 = BytesN::<32>::from_array(&env, &[0x22u8; 32]);
+    client.commit_seed(&sid, &player1, &commit, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+
+    let invalid = EntropyPolicy { min_distinct: 0, ..EntropyPolicy::default() };
+    let result = client.try_set_entropy_policy(&invalid);
+    assert_cangkulan_error(&result, CangkulanError::InvalidEntropyPolicy);
+
+    let strict = EntropyPolicy { max_period: 4, ..EntropyPolicy::default() };
+    client.set_entropy_policy(&strict);
+    assert_eq!(client.get_entropy_policy(), strict);
+
+    let proof = build_nizk_proof(&env, &blinding);
+    let result = client.try_reveal_seed(&sid, &player1, &seed_hash, &proof);
+    assert_cangkulan_error(&result, CangkulanError::WeakSeedEntropy);
+
+    client.set_entropy_policy(&EntropyPolicy::default());
+    client.reveal_seed(&sid, &player1, &seed_hash, &proof);
+}
+
+#[test]
+fn unknown_seed_proof_mode_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 14u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let commit = BytesN::<32>::from_array(&env, &[0x11u8; 32]);
+    let result = client.try_commit_seed(&sid, &player1, &commit, &7);
+    assert_cangkulan_error(&result, CangkulanError::InvalidSeedProofMode);
+    assert_eq!(client.get_game_debug(&sid).seat(1).seed_mode, None);
+}
+
+#[test]
+fn reveal_with_undeclared_proof_mode_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 15u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed1 = BytesN::<32>::from_array(&env, &[
+        1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+        17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+    ]);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let seed_hash1 = compute_seed_hash(&env, &seed1);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    let commit2 = BytesN::<32>::from_array(&env, &[0x22u8; 32]);
+
+    // Player 1 promised a Pedersen proof but prepares the cheaper NIZK one
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_PEDERSEN);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_PEDERSEN);
+    let game = client.get_game_debug(&sid);
+    assert_eq!(game.seat(1).seed_mode, Some(SEED_PROOF_PEDERSEN));
+    assert_eq!(game.seat(2).seed_mode, Some(SEED_PROOF_PEDERSEN));
+
+    let proof = build_nizk_proof(&env, &blinding1);
+    let result = client.try_reveal_seed(&sid, &player1, &seed_hash1, &proof);
+    assert_cangkulan_error(&result, CangkulanError::SeedProofModeMismatch);
+
+    // The same goes for the split Noir flow
+    let result = client.try_verify_noir_seed(&sid, &player1, &seed_hash1, &Bytes::new(&env));
+    assert_cangkulan_error(&result, CangkulanError::SeedProofModeMismatch);
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Card dealing
 // ════════════════════════════════════════════════════════════════════════════
@@ -479,13 +620,13 @@ fn all_cards_unique_in_deal() {
 
     let mut all_cards: Vec<u32> = Vec::new(&env);
     let mut i: u32 = 0;
-    while i < game.hand1.len() {
-        all_cards.push_back(game.hand1.get(i).unwrap());
+    while i < game.seat(1).hand.len() {
+        all_cards.push_back(game.seat(1).hand.get(i).unwrap());
         i += 1;
     }
     i = 0;
-    while i < game.hand2.len() {
-        all_cards.push_back(game.hand2.get(i).unwrap());
+    while i < game.seat(2).hand.len() {
+        all_cards.push_back(game.seat(2).hand.get(i).unwrap());
         i += 1;
     }
     i = 0;
@@ -540,8 +681,8 @@ fn commit_reveal_play_card() {
     let trick_suit = game.trick_suit.unwrap();
 
     // Find a card that matches the trick suit
-    let p1_card = first_card_of_suit(&game.hand1, trick_suit);
-    let p2_card = first_card_of_suit(&game.hand2, trick_suit);
+    let p1_card = first_card_of_suit(&game.seat(1).hand, trick_suit);
+    let p2_card = first_card_of_suit(&game.seat(2).hand, trick_suit);
 
     let salt1 = test_salt(&env, 0x11);
     let salt2 = test_salt(&env, 0x22);
@@ -554,15 +695,15 @@ fn commit_reveal_play_card() {
     client.commit_play(&sid, &player1, &commit1, &game.action_nonce);
     let g1 = client.get_game_debug(&sid);
     assert_eq!(g1.trick_state, TRICK_COMMIT_WAIT_P2);
-    assert!(g1.play_commit1.is_some());
-    assert!(g1.play_commit2.is_none());
+    assert!(g1.seat(1).play_commit.is_some());
+    assert!(g1.seat(2).play_commit.is_none());
 
     let commit2 = compute_play_commit(&env, p2_action, &salt2);
     client.commit_play(&sid, &player2, &commit2, &g1.action_nonce);
     let g2 = client.get_game_debug(&sid);
     assert_eq!(g2.trick_state, TRICK_REVEAL_WAIT_BOTH);
-    assert!(g2.play_commit1.is_some());
-    assert!(g2.play_commit2.is_some());
+    assert!(g2.seat(1).play_commit.is_some());
+    assert!(g2.seat(2).play_commit.is_some());
 
     // Phase 2: Reveal
     client.reveal_play(&sid, &player1, &p1_action, &salt1);
@@ -575,6 +716,104 @@ fn commit_reveal_play_card() {
     assert!(g4.trick_state == TRICK_COMMIT_WAIT_BOTH || g4.lifecycle_state == STATE_FINISHED);
 }
 
+#[test]
+fn submit_trick_lands_both_halves_at_once() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1340u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let payload = |player: &Address, seat: u32, unique: u8| {
+        let card_id = first_card_of_suit(&game.seat(seat).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+        let salt = test_salt(&env, unique);
+        TrickPayload {
+            player: player.clone(),
+            nonce: game.action_nonce,
+            commit_hash: compute_play_commit(&env, card_id, &salt),
+            card_id,
+            salt,
+        }
+    };
+    let p1_payload = payload(&player1, 1, 0x11);
+    let p2_payload = payload(&player2, 2, 0x22);
+
+    let swapped = client.try_submit_trick(&sid, &p2_payload, &p1_payload);
+    assert_cangkulan_error(&swapped, CangkulanError::NotAPlayer);
+
+    client.submit_trick(&sid, &p1_payload, &p2_payload);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(signer, _)| signer).collect();
+    assert_eq!(signers, [player1.clone(), player2.clone()]);
+    let after = client.get_game_debug(&sid);
+    assert_eq!(after.action_nonce, game.action_nonce + 4);
+    assert!(after.trick_state == TRICK_COMMIT_WAIT_BOTH || after.lifecycle_state == STATE_FINISHED);
+
+    // A signed half is good for one trick only
+    let replay = client.try_submit_trick(&sid, &p1_payload, &p2_payload);
+    assert!(replay.is_err());
+}
+
+#[test]
+fn valid_actions_track_the_viewers_next_call() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1341u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    let early = client.try_get_valid_actions(&sid, &player1);
+    assert_cangkulan_error(&early, CangkulanError::WrongPhase);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    let outsider = client.try_get_valid_actions(&sid, &Address::generate(&env));
+    assert_cangkulan_error(&outsider, CangkulanError::NotAPlayer);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let mut expected = Vec::new(&env);
+    for card in cards_of_suit(&game.seat(1).hand, trick_suit) {
+        expected.push_back(card);
+    }
+    if expected.is_empty() {
+        expected.push_back(CANNOT_FOLLOW_SENTINEL);
+    }
+    let actions = client.get_valid_actions(&sid, &player1);
+    assert_eq!(actions.playable, expected);
+    assert_eq!(actions.next_call, Symbol::new(&env, "commit_play"));
+    assert_eq!(actions.nonce, game.action_nonce);
+
+    let action = expected.get_unchecked(0);
+    let salt = test_salt(&env, 0x11);
+    client.commit_play(&sid, &player1, &compute_play_commit(&env, action, &salt), &actions.nonce);
+    assert_eq!(client.get_valid_actions(&sid, &player1).next_call, Symbol::new(&env, "wait"));
+    let p2_actions = client.get_valid_actions(&sid, &player2);
+    assert_eq!(p2_actions.next_call, Symbol::new(&env, "commit_play"));
+    assert_eq!(p2_actions.nonce, game.action_nonce + 1);
+
+    let p2_action = p2_actions.playable.get_unchecked(0);
+    let salt2 = test_salt(&env, 0x22);
+    client.commit_play(&sid, &player2, &compute_play_commit(&env, p2_action, &salt2), &p2_actions.nonce);
+    assert_eq!(client.get_valid_actions(&sid, &player1).next_call, Symbol::new(&env, "reveal_play"));
+}
+
+#[test]
+fn commitment_views_match_what_reveals_check() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+    let salt = test_salt(&env, 0x11);
+    assert_eq!(client.compute_play_commit(&7, &salt), compute_play_commit(&env, 7, &salt));
+    assert_eq!(
+        client.compute_play_commit(&CANNOT_FOLLOW_SENTINEL, &salt),
+        compute_play_commit(&env, CANNOT_FOLLOW_SENTINEL, &salt),
+    );
+
+    let blinding = BytesN::<32>::from_array(&env, &{ let mut a = [0u8; 32]; a[31] = 99; a });
+    assert_eq!(client.compute_pedersen_commit_hash(&7, &blinding), compute_zk_play_commit(&env, 7, &blinding));
+    assert_ne!(client.compute_pedersen_commit_hash(&8, &blinding), compute_zk_play_commit(&env, 7, &blinding));
+
+    let seed_hash = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    assert_eq!(
+        client.compute_seed_commit(&seed_hash, &blinding, &player1),
+        compute_nizk_commitment(&env, &seed_hash, &blinding, &player1),
+    );
+}
+
 #[test]
 fn commit_play_wrong_reveal_rejected() {
     let (env, client, _hub, player1, player2) = setup_test();
@@ -584,8 +823,8 @@ fn commit_play_wrong_reveal_rejected() {
 
     let game = client.get_game_debug(&sid);
     let trick_suit = game.trick_suit.unwrap();
-    let p1_action = first_card_of_suit(&game.hand1, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
-    let p2_action = first_card_of_suit(&game.hand2, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p1_action = first_card_of_suit(&game.seat(1).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p2_action = first_card_of_suit(&game.seat(2).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
 
     let salt1 = test_salt(&env, 0x11);
     let salt2 = test_salt(&env, 0x22);
@@ -652,13 +891,13 @@ fn wrong_suit_reveal_rejected() {
 
     // Find a card in P1's hand that does NOT match trick suit, but P1 also
     // HAS a matching suit card (otherwise it's a valid cannot-follow)
-    if !hand_has_suit(&game.hand1, trick_suit) {
+    if !hand_has_suit(&game.seat(1).hand, trick_suit) {
         return; // Can't test wrong-suit if P1 has no matching cards
     }
     let mut wrong_card: Option<u32> = None;
     let mut i: u32 = 0;
-    while i < game.hand1.len() {
-        let c = game.hand1.get(i).unwrap();
+    while i < game.seat(1).hand.len() {
+        let c = game.seat(1).hand.get(i).unwrap();
         if card_suit(c) != trick_suit {
             wrong_card = Some(c);
             break;
@@ -669,7 +908,7 @@ fn wrong_suit_reveal_rejected() {
     if let Some(card) = wrong_card {
         let salt1 = test_salt(&env, 0x11);
         let salt2 = test_salt(&env, 0x22);
-        let p2_action = first_card_of_suit(&game.hand2, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+        let p2_action = first_card_of_suit(&game.seat(2).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
 
         // Both commit (P1 commits with the wrong card — commit succeeds, reveal will fail)
         let commit1 = compute_play_commit(&env, card, &salt1);
@@ -696,7 +935,7 @@ fn cannot_follow_when_has_suit_rejected() {
         advance_to_playing(&env, &client, sid, &player1, &player2);
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        if hand_has_suit(&game.hand1, suit) {
+        if hand_has_suit(&game.seat(1).hand, suit) {
             break;
         }
         sid += 1;
@@ -705,7 +944,7 @@ fn cannot_follow_when_has_suit_rejected() {
     let game = client.get_game_debug(&sid);
     let salt1 = test_salt(&env, 0x11);
     let salt2 = test_salt(&env, 0x22);
-    let p2_action = first_card_of_suit(&game.hand2, game.trick_suit.unwrap())
+    let p2_action = first_card_of_suit(&game.seat(2).hand, game.trick_suit.unwrap())
         .unwrap_or(CANNOT_FOLLOW_SENTINEL);
 
     // P1 commits CANNOT_FOLLOW_SENTINEL even though they have a matching suit card
@@ -770,7 +1009,7 @@ fn timeout_during_seed_commit() {
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
 
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     tick_timeout_twice(&env, &client, &sid, &player1);
     client.resolve_timeout(&sid, &player1);
@@ -781,6 +1020,27 @@ fn timeout_during_seed_commit() {
     assert_eq!(hub.get_end_count(), 1);
 }
 
+#[test]
+fn deadline_and_timeout_events_name_who_is_waited_on() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1342u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[1u8; 32]));
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &BytesN::from_array(&env, &[0xAAu8; 32]), &player1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let deadline_ledger = client.get_game_debug(&sid).deadline_ledger.unwrap();
+    let deadline_set = EvDeadlineSet { session_id: sid, deadline_ledger, waiting_on: vec![&env, player2.clone()] };
+    assert!(events.events().contains(&deadline_set.to_xdr(&env, &client.address)));
+
+    tick_timeout_twice(&env, &client, &sid, &player1);
+    client.resolve_timeout(&sid, &player1);
+    let resolved = EvTimeoutResolved { session_id: sid, outcome: OUTCOME_PLAYER1_WIN };
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert!(events.events().contains(&resolved.to_xdr(&env, &client.address)));
+}
+
 #[test]
 fn timeout_during_playing_commit_phase() {
     let (env, client, hub, player1, player2) = setup_test();
@@ -816,8 +1076,8 @@ fn timeout_during_playing_reveal_phase() {
     let trick_suit = game.trick_suit.unwrap();
     let salt1 = test_salt(&env, 0x11);
     let salt2 = test_salt(&env, 0x22);
-    let p1_action = first_card_of_suit(&game.hand1, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
-    let p2_action = first_card_of_suit(&game.hand2, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p1_action = first_card_of_suit(&game.seat(1).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p2_action = first_card_of_suit(&game.seat(2).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
 
     // Both commit
     let commit1 = compute_play_commit(&env, p1_action, &salt1);
@@ -854,7 +1114,7 @@ fn ledger_based_timeout_during_commit() {
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
 
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     let result = client.try_resolve_timeout(&sid, &player1);
     assert_cangkulan_error(&result, CangkulanError::TimeoutNotReached);
@@ -878,6 +1138,46 @@ fn ledger_based_timeout_during_commit() {
     assert_eq!(hub.get_end_count(), 1);
 }
 
+#[test]
+fn get_deadline_tracks_clock() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 53u32;
+    assert_eq!(client.get_deadline(&sid), None);
+
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    // Clock starts with the first seed commit
+    assert_eq!(client.get_deadline(&sid), None);
+
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[7u8; 32]));
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &test_salt(&env, 0xAA), &player1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+
+    let start = env.ledger().sequence();
+    let (deadline, now, waiting) = client.get_deadline(&sid).unwrap();
+    assert_eq!(now, start);
+    assert!(deadline > now);
+    assert_eq!(waiting, (STATE_SEED_COMMIT, TRICK_NONE));
+
+    advance_ledger(&env, 10);
+    let (same_deadline, later, _) = client.get_deadline(&sid).unwrap();
+    assert_eq!(same_deadline, deadline);
+    assert_eq!(later, start + 10);
+
+    client.forfeit(&sid, &player2);
+    assert_eq!(client.get_deadline(&sid), None);
+}
+
+#[test]
+fn get_deadline_reports_trick_state() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 54u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let (_, _, waiting) = client.get_deadline(&sid).unwrap();
+    assert_eq!(waiting, (STATE_PLAYING, TRICK_COMMIT_WAIT_BOTH));
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Session independence
 // ════════════════════════════════════════════════════════════════════════════
@@ -894,10 +1194,10 @@ fn different_sessions_are_independent() {
     let game1 = client.get_game_debug(&100);
     let game2 = client.get_game_debug(&101);
 
-    assert_eq!(game1.player1, player1);
-    assert_eq!(game2.player1, player3);
-    assert_eq!(game1.player1_points, 100_0000000);
-    assert_eq!(game2.player1_points, 50_0000000);
+    assert_eq!(game1.seat(1).player, player1);
+    assert_eq!(game2.seat(1).player, player3);
+    assert_eq!(game1.seat(1).points, 100_0000000);
+    assert_eq!(game2.seat(1).points, 50_0000000);
     assert_eq!(hub.get_start_count(), 2);
 }
 
@@ -919,7 +1219,7 @@ fn tick_timeout_rejected_for_non_player() {
     let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     let result = client.try_tick_timeout(&sid, &outsider);
     assert_cangkulan_error(&result, CangkulanError::NotAPlayer);
@@ -937,7 +1237,7 @@ fn tick_timeout_rate_limited() {
     let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     // First tick succeeds
     client.tick_timeout(&sid, &player1);
@@ -962,7 +1262,7 @@ fn resolve_timeout_rejected_for_non_player() {
     let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     // Advance past ledger deadline
     advance_ledger(&env, 130);
@@ -975,6 +1275,67 @@ fn resolve_timeout_rejected_for_non_player() {
     client.resolve_timeout(&sid, &player1);
 }
 
+#[test]
+fn resolve_timeout_open_to_anyone_after_grace() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+    let sid = 63u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed1 = BytesN::<32>::from_array(&env, &[1u8; 32]);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let seed_hash1 = compute_seed_hash(&env, &seed1);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+
+    // Both players walk away; the grace window is counted from the deadline
+    let deadline = client.get_game(&sid).deadline_ledger.unwrap();
+    advance_ledger(&env, deadline + TIMEOUT_GRACE_LEDGERS - 1 - env.ledger().sequence());
+    let result = client.try_resolve_timeout(&sid, &outsider);
+    assert_cangkulan_error(&result, CangkulanError::NotAPlayer);
+
+    advance_ledger(&env, 1);
+    client.resolve_timeout(&sid, &outsider);
+    let game = client.get_game(&sid);
+    assert_eq!(game.lifecycle_state, STATE_FINISHED);
+    assert_eq!(game.outcome, OUTCOME_PLAYER1_WIN);
+    assert_eq!(hub.get_end_count(), 1);
+}
+
+#[test]
+fn prune_expired_ends_abandoned_sessions() {
+    let (env, client, hub, player1, player2) = setup_test();
+    // 64: past its deadline; 65: never committed to; 66: finished; 67: missing
+    client.start_game(&64u32, &player1, &player2, &100_0000000, &100_0000000);
+    let seed1 = BytesN::<32>::from_array(&env, &[1u8; 32]);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit1 = compute_nizk_commitment(&env, &compute_seed_hash(&env, &seed1), &blinding1, &player1);
+    client.commit_seed(&64u32, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.start_game(&65u32, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&66u32, &player1, &player2, &100_0000000, &100_0000000);
+    client.forfeit(&66u32, &player2);
+    let ids = vec![&env, 64u32, 65, 66, 67];
+
+    // Nothing is overdue yet
+    assert!(client.prune_expired(&ids).is_empty());
+
+    let deadline = client.get_game(&64u32).deadline_ledger.unwrap();
+    advance_ledger(&env, deadline + TIMEOUT_GRACE_LEDGERS - env.ledger().sequence());
+    assert_eq!(client.prune_expired(&ids), vec![&env, 64u32]);
+    assert_eq!(client.get_game(&64u32).outcome, OUTCOME_PLAYER1_WIN);
+    assert_eq!(client.get_game(&65u32).lifecycle_state, STATE_SEED_COMMIT);
+
+    // The untouched session is closed as a draw before its entry expires
+    let updated = client.get_game_debug(&65u32).updated_ledger;
+    advance_ledger(&env, updated + GAME_TTL_LEDGERS - TIMEOUT_GRACE_LEDGERS - env.ledger().sequence());
+    assert_eq!(client.prune_expired(&ids), vec![&env, 65u32]);
+    let game = client.get_game(&65u32);
+    assert_eq!(game.lifecycle_state, STATE_FINISHED);
+    assert_eq!(game.outcome, OUTCOME_DRAW);
+    assert_eq!(hub.get_end_count(), 3);
+    assert!(client.get_active_games(&player1).is_empty());
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Privacy (get_game / get_game_view)
 // ════════════════════════════════════════════════════════════════════════════
@@ -988,25 +1349,51 @@ fn get_game_redacts_hands_during_active_play() {
 
     // get_game_debug (admin) still returns full state
     let full = client.get_game_debug(&sid);
-    assert_eq!(full.hand1.len(), 5);
-    assert_eq!(full.hand2.len(), 5);
-    assert!(full.draw_pile.len() > 0);
-
-    // get_game (public) redacts hands and draw pile during active game
-    let redacted = client.get_game(&sid);
-    assert_eq!(redacted.hand1.len(), 0, "get_game should redact hand1 during PLAYING");
-    assert_eq!(redacted.hand2.len(), 0, "get_game should redact hand2 during PLAYING");
-    assert_eq!(redacted.draw_pile.len(), 0, "get_game should redact draw_pile during PLAYING");
+    assert_eq!(full.seat(1).hand.len(), 5);
+    assert_eq!(full.seat(2).hand.len(), 5);
+    assert!(!full.draw_pile.is_empty());
+
+    // get_game (public) shows hands and draw pile only as counts
+    let public = client.get_game(&sid);
+    assert_eq!(public.seat(1).hand_size, 5);
+    assert_eq!(public.seat(2).hand_size, 5);
+    assert_eq!(public.draw_pile_size, full.draw_pile.len());
+    assert_eq!(public.flipped_card, full.flipped_card);
     // But metadata is still visible
-    assert_eq!(redacted.lifecycle_state, STATE_PLAYING);
-    assert_eq!(redacted.player1, player1);
-    assert_eq!(redacted.player2, player2);
+    assert_eq!(public.lifecycle_state, STATE_PLAYING);
+    assert_eq!(public.seat(1).player, player1);
+    assert_eq!(public.seat(2).player, player2);
+    assert_cangkulan_error(&client.try_get_game_record(&sid), CangkulanError::GameNotFinished);
+}
+
+#[test]
+fn get_game_hides_a_revealed_trick_card() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 692u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let suit = game.trick_suit.unwrap();
+    let action = first_card_of_suit(&game.seat(1).hand, suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let salt1 = test_salt(&env, 0x11);
+    client.commit_play(&sid, &player1, &compute_play_commit(&env, action, &salt1), &game.action_nonce);
+    let nonce = client.get_game(&sid).action_nonce;
+    let commit2 = compute_play_commit(&env, CANNOT_FOLLOW_SENTINEL, &test_salt(&env, 0x22));
+    client.commit_play(&sid, &player2, &commit2, &nonce);
+    client.reveal_play(&sid, &player1, &action, &salt1);
+
+    // Seat 1 has revealed; the public view only says so
+    let public = client.get_game(&sid);
+    assert!(public.seat(1).play_revealed && !public.seat(2).play_revealed);
+    assert!(public.seat(2).play_committed);
+    assert_eq!(public.trick_state, TRICK_REVEAL_WAIT_P2);
 }
 
 #[test]
-fn get_game_shows_hands_after_finished() {
+fn get_game_record_shows_hands_after_finished() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let sid = 69_1u32;
+    let sid = 691u32;
     client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
     advance_to_playing(&env, &client, sid, &player1, &player2);
 
@@ -1017,10 +1404,10 @@ fn get_game_shows_hands_after_finished() {
         play_one_trick(&env, &client, &player1, &player2, sid);
     }
 
-    // get_game should show full state for finished games
-    let finished_game = client.get_game(&sid);
-    assert_eq!(finished_game.lifecycle_state, STATE_FINISHED);
-    // Finished games reveal everything (hands may be empty if all cards played)
+    // get_game_record shows full state for finished games
+    let finished_game = client.get_game_record(&sid);
+    assert_eq!(finished_game, client.get_game_debug(&sid));
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_FINISHED);
 }
 
 #[test]
@@ -1031,21 +1418,21 @@ fn get_game_view_redacts_opponent_hand() {
     advance_to_playing(&env, &client, sid, &player1, &player2);
 
     let full = client.get_game_debug(&sid);
-    assert_eq!(full.hand1.len(), 5);
-    assert_eq!(full.hand2.len(), 5);
+    assert_eq!(full.seat(1).hand.len(), 5);
+    assert_eq!(full.seat(2).hand.len(), 5);
 
     let p1_view = client.get_game_view(&sid, &player1);
-    assert_eq!(p1_view.hand1.len(), 5);
-    assert_eq!(p1_view.hand2.len(), 0);
+    assert_eq!(p1_view.seat(1).hand.len(), 5);
+    assert_eq!(p1_view.seat(2).hand.len(), 0);
 
     let p2_view = client.get_game_view(&sid, &player2);
-    assert_eq!(p2_view.hand1.len(), 0);
-    assert_eq!(p2_view.hand2.len(), 5);
+    assert_eq!(p2_view.seat(1).hand.len(), 0);
+    assert_eq!(p2_view.seat(2).hand.len(), 5);
 
     let outsider = Address::generate(&env);
     let outsider_view = client.get_game_view(&sid, &outsider);
-    assert_eq!(outsider_view.hand1.len(), 0);
-    assert_eq!(outsider_view.hand2.len(), 0);
+    assert_eq!(outsider_view.seat(1).hand.len(), 0);
+    assert_eq!(outsider_view.seat(2).hand.len(), 0);
 }
 
 #[test]
@@ -1060,8 +1447,8 @@ fn get_game_view_redacts_trick_card_during_reveal() {
 
     let salt1 = test_salt(&env, 0x11);
     let salt2 = test_salt(&env, 0x22);
-    let p1_action = first_card_of_suit(&game.hand1, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
-    let p2_action = first_card_of_suit(&game.hand2, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p1_action = first_card_of_suit(&game.seat(1).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p2_action = first_card_of_suit(&game.seat(2).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
 
     // Both commit
     let commit1 = compute_play_commit(&env, p1_action, &salt1);
@@ -1078,7 +1465,7 @@ fn get_game_view_redacts_trick_card_during_reveal() {
     // P2 should NOT see P1's trick card yet (if P1 played a real card)
     if p1_action != CANNOT_FOLLOW_SENTINEL {
         let p2_view = client.get_game_view(&sid, &player2);
-        assert!(p2_view.trick_card1.is_none(), "P2 should not see P1's card during reveal");
+        assert!(p2_view.seat(1).trick_card.is_none(), "P2 should not see P1's card during reveal");
     }
 }
 
@@ -1109,12 +1496,51 @@ fn verify_shuffle_returns_full_deck() {
     let game = client.get_game_debug(&sid);
     let mut j: u32 = 0;
     while j < 5 {
-        assert_eq!(deck.get(j).unwrap(), game.hand1.get(j).unwrap());
-        assert_eq!(deck.get(j + 5).unwrap(), game.hand2.get(j).unwrap());
+        assert_eq!(deck.get(j).unwrap(), game.seat(1).hand.get(j).unwrap());
+        assert_eq!(deck.get(j + 5).unwrap(), game.seat(2).hand.get(j).unwrap());
         j += 1;
     }
 }
 
+#[test]
+fn dispute_shuffle_voids_a_deal_that_differs() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 73u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    // An honest deal cannot be disputed
+    let honest = client.try_dispute_shuffle(&sid, &player2, &1);
+    assert_cangkulan_error(&honest, CangkulanError::ShuffleDisputeRejected);
+    let past_end = client.try_dispute_shuffle(&sid, &player2, &36);
+    assert_cangkulan_error(&past_end, CangkulanError::ShuffleDisputeRejected);
+
+    // Deal the first two cards the other way round
+    let deck = client.verify_shuffle(&sid);
+    env.as_contract(&client.address, || {
+        let mut dealt = deck.clone();
+        dealt.set(0, deck.get_unchecked(1));
+        dealt.set(1, deck.get_unchecked(0));
+        env.storage().temporary().set(&crate::StorageKey::DealtDeck(sid), &dealt);
+    });
+    client.dispute_shuffle(&sid, &player2, &1);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let disputed = EvShuffleDisputed {
+        session_id: sid,
+        player: player2.clone(),
+        index: 1,
+        dealt: deck.get_unchecked(0),
+        shuffled: deck.get_unchecked(1),
+    };
+    assert!(events.events().contains(&disputed.to_xdr(&env, &client.address)));
+
+    let game = client.get_game(&sid);
+    assert_eq!((game.lifecycle_state, game.outcome), (STATE_FINISHED, OUTCOME_CANCELLED));
+    assert!(client.is_paused());
+    let again = client.try_dispute_shuffle(&sid, &player1, &1);
+    assert_cangkulan_error(&again, CangkulanError::WrongPhase);
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Edge-case trick resolution
 // ════════════════════════════════════════════════════════════════════════════
@@ -1132,15 +1558,15 @@ fn both_follow_suit_highest_value_wins() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        if hand_has_suit(&game.hand1, suit) && hand_has_suit(&game.hand2, suit) {
+        if hand_has_suit(&game.seat(1).hand, suit) && hand_has_suit(&game.seat(2).hand, suit) {
             break;
         }
         sid += 1;
     }
 
     let before = client.get_game_debug(&sid);
-    let t1_before = before.tricks_won1;
-    let t2_before = before.tricks_won2;
+    let t1_before = before.seat(1).tricks_won;
+    let t2_before = before.seat(2).tricks_won;
 
     let (d1, d2, _ddp) = play_one_trick(&env, &client, &player1, &player2, sid);
     assert_eq!(d1, -1, "P1 should have played one card");
@@ -1148,7 +1574,7 @@ fn both_follow_suit_highest_value_wins() {
 
     let after = client.get_game_debug(&sid);
     assert_eq!(
-        after.tricks_won1 + after.tricks_won2,
+        after.seat(1).tricks_won + after.seat(2).tricks_won,
         t1_before + t2_before + 1,
         "Exactly one trick should be awarded"
     );
@@ -1169,8 +1595,8 @@ fn penalty_card_on_cangkul() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        p1_has = hand_has_suit(&game.hand1, suit);
-        p2_has = hand_has_suit(&game.hand2, suit);
+        p1_has = hand_has_suit(&game.seat(1).hand, suit);
+        p2_has = hand_has_suit(&game.seat(2).hand, suit);
         if p1_has != p2_has {
             break;
         }
@@ -1184,14 +1610,14 @@ fn penalty_card_on_cangkul() {
         assert_eq!(d1, -1, "P1 played a card");
         assert_eq!(d2, 1, "P2 should have drawn a penalty card");
         let after = client.get_game_debug(&sid);
-        assert_eq!(after.tricks_won1, before.tricks_won1 + 1);
-        assert_eq!(after.tricks_won2, before.tricks_won2);
+        assert_eq!(after.seat(1).tricks_won, before.seat(1).tricks_won + 1);
+        assert_eq!(after.seat(2).tricks_won, before.seat(2).tricks_won);
     } else {
         assert_eq!(d2, -1, "P2 played a card");
         assert_eq!(d1, 1, "P1 should have drawn a penalty card");
         let after = client.get_game_debug(&sid);
-        assert_eq!(after.tricks_won2, before.tricks_won2 + 1);
-        assert_eq!(after.tricks_won1, before.tricks_won1);
+        assert_eq!(after.seat(2).tricks_won, before.seat(2).tricks_won + 1);
+        assert_eq!(after.seat(1).tricks_won, before.seat(1).tricks_won);
     }
 }
 
@@ -1208,7 +1634,7 @@ fn waste_trick_neither_follows() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        if !hand_has_suit(&game.hand1, suit) && !hand_has_suit(&game.hand2, suit) {
+        if !hand_has_suit(&game.seat(1).hand, suit) && !hand_has_suit(&game.seat(2).hand, suit) {
             break;
         }
         sid += 1;
@@ -1221,8 +1647,8 @@ fn waste_trick_neither_follows() {
     assert_eq!(d2, 0, "P2 hand should not change on waste trick");
 
     let after = client.get_game_debug(&sid);
-    assert_eq!(after.tricks_won1, before.tricks_won1);
-    assert_eq!(after.tricks_won2, before.tricks_won2);
+    assert_eq!(after.seat(1).tricks_won, before.seat(1).tricks_won);
+    assert_eq!(after.seat(2).tricks_won, before.seat(2).tricks_won);
 }
 
 // ════════════════════════════════════════════════════════════════════════════
@@ -1250,8 +1676,8 @@ fn timeout_during_seed_reveal_p1_wins() {
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
     let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
 
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
 
     let proof1 = build_nizk_proof(&env, &blinding1);
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -1297,13 +1723,101 @@ fn game_ends_when_draw_pile_exhausted() {
     );
     assert_eq!(hub.get_end_count(), 1);
     assert!(
-        final_game.hand1.is_empty()
-            || final_game.hand2.is_empty()
+        final_game.seat(1).hand.is_empty()
+            || final_game.seat(2).hand.is_empty()
             || final_game.draw_pile.is_empty(),
         "Game should end by empty hand or empty draw pile"
     );
 }
 
+/// Overwrite a game's trick score and draw pile, as if tricks had been
+/// played that way, to reach late-game positions directly.
+fn set_late_game(env: &Env, client: &CangkulanContractClient, sid: u32, pile: u32, won1: u32, won2: u32) {
+    let mut game = client.get_game_debug(&sid);
+    while game.draw_pile.len() > pile {
+        game.draw_pile.pop_back();
+    }
+    for (slot, won) in [(1, won1), (2, won2)] {
+        let mut seat = game.seat(slot);
+        seat.tricks_won = won;
+        game.set_seat(slot, seat);
+    }
+    env.as_contract(&client.address, || CangkulanContract::write_game(env, sid, &mut game));
+}
+
+#[test]
+fn claim_forced_win_finishes_decided_game() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let sid = 610u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    // Fresh deal: nothing is decided yet.
+    assert_cangkulan_error(
+        &client.try_claim_forced_win(&sid, &player1),
+        CangkulanError::OutcomeNotForced,
+    );
+
+    // One card left to flip plus the trick in progress: at most 2 more
+    // tricks, and player 2 holds more cards than that. A 2-trick lead can
+    // still be tied, a 3-trick lead cannot.
+    set_late_game(&env, &client, sid, 1, 3, 1);
+    assert_cangkulan_error(
+        &client.try_claim_forced_win(&sid, &player2),
+        CangkulanError::OutcomeNotForced,
+    );
+    set_late_game(&env, &client, sid, 1, 4, 1);
+
+    // The trailing player may concede the decided game too.
+    assert_eq!(client.claim_forced_win(&sid, &player2), OUTCOME_PLAYER1_WIN);
+    let game = client.get_game_debug(&sid);
+    assert_eq!(game.lifecycle_state, STATE_FINISHED);
+    assert_eq!(game.outcome, OUTCOME_PLAYER1_WIN);
+    assert_eq!(hub.get_end_count(), 1);
+    assert_cangkulan_error(
+        &client.try_claim_forced_win(&sid, &player1),
+        CangkulanError::GameAlreadyEnded,
+    );
+}
+
+#[test]
+fn claim_forced_win_respects_instant_loss_cap() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 611u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    set_late_game(&env, &client, sid, 1, 5, 1);
+
+    // Two penalty draws could push player 1 over a cap one card away.
+    let mut game = client.get_game_debug(&sid);
+    game.overflow_rule = OVERFLOW_INSTANT_LOSS;
+    game.max_hand_size = game.seat(1).hand.len() + 1;
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+    assert_cangkulan_error(
+        &client.try_claim_forced_win(&sid, &player1),
+        CangkulanError::OutcomeNotForced,
+    );
+
+    game.max_hand_size = game.seat(1).hand.len() + 2;
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+    assert_eq!(client.claim_forced_win(&sid, &player1), OUTCOME_PLAYER1_WIN);
+}
+
+#[test]
+fn claim_forced_win_rejected_for_non_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 612u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    set_late_game(&env, &client, sid, 1, 5, 1);
+
+    let outsider = Address::generate(&env);
+    assert_cangkulan_error(
+        &client.try_claim_forced_win(&sid, &outsider),
+        CangkulanError::NotAPlayer,
+    );
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Pedersen Mode (Mode 4)
 // ════════════════════════════════════════════════════════════════════════════
@@ -1357,8 +1871,8 @@ fn advance_to_playing_pedersen(
     let (commit1, proof1) = build_pedersen_proof(env, &seed_hash1);
     let (commit2, proof2) = build_pedersen_proof(env, &seed_hash2);
 
-    client.commit_seed(&session_id, player1, &commit1);
-    client.commit_seed(&session_id, player2, &commit2);
+    client.commit_seed(&session_id, player1, &commit1, &SEED_PROOF_PEDERSEN);
+    client.commit_seed(&session_id, player2, &commit2, &SEED_PROOF_PEDERSEN);
 
     client.reveal_seed(&session_id, player1, &seed_hash1, &proof1);
     client.reveal_seed(&session_id, player2, &seed_hash2, &proof2);
@@ -1376,10 +1890,10 @@ fn seed_commit_reveal_pedersen_flow() {
 
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_PLAYING);
-    assert_eq!(game.hand1.len(), 5);
-    assert_eq!(game.hand2.len(), 5);
-    assert!(game.seed_hash1.is_some());
-    assert!(game.seed_hash2.is_some());
+    assert_eq!(game.seat(1).hand.len(), 5);
+    assert_eq!(game.seat(2).hand.len(), 5);
+    assert!(game.seat(1).seed_hash.is_some());
+    assert!(game.seat(2).seed_hash.is_some());
 }
 
 #[test]
@@ -1430,7 +1944,7 @@ fn invalid_card_id_rejected() {
     client.commit_play(&sid, &player1, &commit1, &nonce);
 
     // P2 commits a valid action
-    let p2_action = first_card_of_suit(&game.hand2, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let p2_action = first_card_of_suit(&game.seat(2).hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
     let salt2 = test_salt(&env, 0xBB);
     let commit2 = compute_play_commit(&env, p2_action, &salt2);
     let game2 = client.get_game_debug(&sid);
@@ -1492,8 +2006,8 @@ fn non_player_reveal_play_rejected() {
 /// Empty history for a player with no games.
 #[test]
 fn history_empty_for_new_player() {
-    let (env, client, _hub, player1, _player2) = setup_test();
-    let history = client.get_player_history(&player1);
+    let (_env, client, _hub, player1, _player2) = setup_test();
+    let history = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
     assert_eq!(history.len(), 0);
 }
 
@@ -1519,8 +2033,8 @@ fn history_recorded_after_full_game() {
     let final_game = client.get_game_debug(&sid);
 
     // Both players should have 1 history entry
-    let h1 = client.get_player_history(&player1);
-    let h2 = client.get_player_history(&player2);
+    let h1 = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
+    let h2 = client.get_player_history(&player2, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
     assert_eq!(h1.len(), 1);
     assert_eq!(h2.len(), 1);
 
@@ -1549,10 +2063,10 @@ fn history_recorded_after_full_game() {
     }
 
     // Tricks are flipped
-    assert_eq!(entry1.tricks_won, final_game.tricks_won1);
-    assert_eq!(entry1.tricks_lost, final_game.tricks_won2);
-    assert_eq!(entry2.tricks_won, final_game.tricks_won2);
-    assert_eq!(entry2.tricks_lost, final_game.tricks_won1);
+    assert_eq!(entry1.tricks_won, final_game.seat(1).tricks_won);
+    assert_eq!(entry1.tricks_lost, final_game.seat(2).tricks_won);
+    assert_eq!(entry2.tricks_won, final_game.seat(2).tricks_won);
+    assert_eq!(entry2.tricks_lost, final_game.seat(1).tricks_won);
 
     // Ledger recorded
     assert!(entry1.ledger > 0);
@@ -1579,7 +2093,7 @@ fn history_accumulates_across_games() {
         }
     }
 
-    let h1 = client.get_player_history(&player1);
+    let h1 = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
     assert_eq!(h1.len(), 3);
     // Entries in chronological order
     assert_eq!(h1.get(0).unwrap().session_id, 960);
@@ -1587,6 +2101,115 @@ fn history_accumulates_across_games() {
     assert_eq!(h1.get(2).unwrap().session_id, 962);
 }
 
+/// History keeps every game past one chunk and pages through the filters.
+#[test]
+fn history_pages_past_a_chunk_with_filters() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    // Every fifth game is a loss for player1
+    for i in 0..55u32 {
+        let sid = 1000 + i;
+        client.start_game(&sid, &player1, &player2, &0, &0);
+        client.forfeit(&sid, if i % 5 == 0 { &player1 } else { &player2 });
+    }
+    client.start_game(&1100, &player3, &player1, &0, &0);
+    client.forfeit(&1100, &player3);
+    assert_eq!(client.get_history_len(&player1), 56);
+
+    let first = client.get_player_history(&player1, &0, &100, &None, &None);
+    assert_eq!(first.entries.len(), MAX_HISTORY_PAGE);
+    assert_eq!(first.entries.get(0).unwrap().session_id, 1000);
+    assert_eq!(first.entries.get(49).unwrap().session_id, 1049);
+    assert_eq!(first.next_cursor, Some(50));
+    let rest = client.get_player_history(&player1, &50, &MAX_HISTORY_PAGE, &None, &None);
+    assert_eq!(rest.entries.len(), 6);
+    assert_eq!(rest.entries.get(0).unwrap().session_id, 1050);
+    assert_eq!(rest.entries.get(5).unwrap().session_id, 1100);
+    assert_eq!(rest.next_cursor, None);
+
+    let vs3 = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &Some(player3.clone()), &None).entries;
+    assert_eq!(vs3.len(), 1);
+    assert_eq!(vs3.get(0).unwrap().outcome, OUTCOME_PLAYER1_WIN);
+
+    let losses = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &Some(OUTCOME_PLAYER2_WIN)).entries;
+    assert_eq!(losses.len(), 11);
+    assert!(losses.iter().all(|s| s.opponent == player2 && s.session_id % 5 == 0));
+    // A full filtered page resumes just past its last game
+    let some_losses = client.get_player_history(&player1, &0, &5, &Some(player2.clone()), &Some(OUTCOME_PLAYER2_WIN));
+    assert_eq!(some_losses.entries.get(4).unwrap().session_id, 1020);
+    assert_eq!(some_losses.next_cursor, Some(21));
+    let last_loss = client.get_player_history(&player1, &46, &5, &Some(player2.clone()), &Some(OUTCOME_PLAYER2_WIN)).entries;
+    assert_eq!(last_loss.len(), 1);
+    assert_eq!(last_loss.get(0).unwrap().session_id, 1050);
+    assert_eq!(client.get_player_history(&player1, &56, &MAX_HISTORY_PAGE, &None, &None).entries.len(), 0);
+}
+
+/// A filter that rarely matches reads a bounded number of chunks per call
+/// and hands back a cursor to continue from.
+#[test]
+fn filtered_history_scan_is_bounded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let scanned = MAX_HISTORY_SCAN_CHUNKS * 50;
+    for i in 0..scanned {
+        client.start_game(&(2000 + i), &player1, &player2, &0, &0);
+        client.forfeit(&(2000 + i), &player2);
+    }
+    client.start_game(&3000, &player1, &player3, &0, &0);
+    client.forfeit(&3000, &player3);
+
+    let first = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &Some(player3.clone()), &None);
+    assert_eq!(first.entries.len(), 0);
+    assert_eq!(first.next_cursor, Some(scanned));
+    let next = client.get_player_history(&player1, &scanned, &MAX_HISTORY_PAGE, &Some(player3), &None);
+    assert_eq!(next.entries.len(), 1);
+    assert_eq!(next.entries.get(0).unwrap().session_id, 3000);
+    assert_eq!(next.next_cursor, None);
+}
+
+/// A history stored as a single entry moves into chunk 0 on the next game.
+#[test]
+fn history_stored_before_chunking_is_kept() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&1110, &player1, &player2, &0, &0);
+    client.forfeit(&1110, &player2);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        let chunk = crate::StorageKey::HistoryChunk(player1.clone(), 0);
+        let entries: Vec<crate::GameSummary> = storage.get(&chunk).unwrap();
+        storage.set(&crate::StorageKey::PlayerHistory(player1.clone()), &entries);
+        storage.remove(&chunk);
+        storage.remove(&crate::StorageKey::HistoryLen(player1.clone()));
+    });
+    assert_eq!(client.get_history_len(&player1), 1);
+
+    client.start_game(&1111, &player1, &player2, &0, &0);
+    client.forfeit(&1111, &player2);
+    let history = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
+    assert_eq!(history.iter().map(|s| s.session_id).collect::<std::vec::Vec<_>>(), [1110, 1111]);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&crate::StorageKey::PlayerHistory(player1.clone())));
+    });
+}
+
+/// The active-games index follows starts and finishes.
+#[test]
+fn active_games_track_unfinished_sessions() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    assert_eq!(client.get_active_games(&player1).len(), 0);
+
+    client.start_game(&965, &player1, &player2, &0, &0);
+    client.start_game(&966, &player3, &player1, &0, &0);
+    assert_eq!(client.get_active_games(&player1), vec![&env, 965, 966]);
+    assert_eq!(client.get_active_games(&player3), vec![&env, 966]);
+
+    client.forfeit(&965, &player2);
+    assert_eq!(client.get_active_games(&player1), vec![&env, 966]);
+    assert_eq!(client.get_active_games(&player2).len(), 0);
+}
+
 /// History is recorded after a timeout win.
 #[test]
 fn history_recorded_after_timeout() {
@@ -1599,13 +2222,13 @@ fn history_recorded_after_timeout() {
     let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
     let seed_hash1 = compute_seed_hash(&env, &seed1);
     let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
-    client.commit_seed(&sid, &player1, &commit1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
 
     tick_timeout_twice(&env, &client, &sid, &player1);
     client.resolve_timeout(&sid, &player1);
 
-    let h1 = client.get_player_history(&player1);
-    let h2 = client.get_player_history(&player2);
+    let h1 = client.get_player_history(&player1, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
+    let h2 = client.get_player_history(&player2, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
     assert_eq!(h1.len(), 1);
     assert_eq!(h2.len(), 1);
 
@@ -1615,30 +2238,305 @@ fn history_recorded_after_timeout() {
 }
 
 // ════════════════════════════════════════════════════════════════════════════
-//  ZK Card Play (commit_play_zk + reveal_play Pedersen opening)
+//  Tests: Game summary export
 // ════════════════════════════════════════════════════════════════════════════
 
-/// Compute Pedersen commitment: C = card_id·G + blinding·H on BLS12-381.
-/// Returns the commitment hash (keccak256(C_bytes)) and C point bytes for reveal.
-fn compute_zk_play_commit(env: &Env, card_id: u32, blinding: &BytesN<32>) -> BytesN<32> {
-    let bls = env.crypto().bls12_381();
-
-    let g1_bytes: [u8; 96] = [
-        0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-        0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-        0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-        0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-        0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-        0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-        0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-        0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-    ];
-    let g = G1Affine::from_array(env, &g1_bytes);
+#[test]
+fn export_summary_matches_recorded_hash() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 980u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
 
-    // Same H derivation as the contract
-    let h_msg = Bytes::from_slice(env, b"PEDERSEN_H");
-    let h_dst = Bytes::from_slice(env, b"SGS_CANGKULAN_V1");
-    let h = bls.hash_to_g1(&h_msg, &h_dst);
+    // Deal seed and each trick move the transcript
+    let dealt = client.get_game_debug(&sid).transcript;
+    assert_ne!(dealt, BytesN::from_array(&env, &[0u8; 32]));
+    play_one_trick(&env, &client, &player1, &player2, sid);
+    let game = client.get_game_debug(&sid);
+    if game.lifecycle_state != STATE_FINISHED {
+        assert_ne!(game.transcript, dealt);
+    }
+
+    let mut iterations = 0u32;
+    loop {
+        let game = client.get_game_debug(&sid);
+        if game.lifecycle_state == STATE_FINISHED { break; }
+        assert!(iterations < 200, "Game did not finish after 200 iterations");
+        iterations += 1;
+        play_one_trick(&env, &client, &player1, &player2, sid);
+    }
+
+    let final_game = client.get_game_debug(&sid);
+    let blob = client.export_summary(&sid);
+    assert_eq!(blob.len(), 4 + 4 + 56 + 56 + 4 + 4 + 4 + 32);
+
+    let word = |at: u32| {
+        let mut b = [0u8; 4];
+        blob.slice(at..at + 4).copy_into_slice(&mut b);
+        u32::from_be_bytes(b)
+    };
+    assert_eq!(word(0), 1);
+    assert_eq!(word(4), sid);
+    assert_eq!(blob.slice(8..64), player1.to_string().to_bytes());
+    assert_eq!(blob.slice(64..120), player2.to_string().to_bytes());
+    assert_eq!(word(120), final_game.outcome);
+    assert_eq!(word(124), final_game.seat(1).tricks_won);
+    assert_eq!(word(128), final_game.seat(2).tricks_won);
+    assert_eq!(blob.slice(132..), Bytes::from_array(&env, &final_game.transcript.to_array()));
+
+    let expected: BytesN<32> = env.crypto().keccak256(&blob).into();
+    assert_eq!(client.get_summary_hash(&sid), Some(expected));
+}
+
+#[test]
+fn trick_log_is_bounded_and_chains_to_transcript() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 981u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    let dealt = client.get_game_debug(&sid);
+    assert_eq!(dealt.trick_log.len(), 0);
+    assert_eq!(dealt.trick_log_base, dealt.transcript);
+
+    let mut iterations = 0u32;
+    loop {
+        let game = client.get_game_debug(&sid);
+        assert!(game.trick_log.len() <= 8);
+
+        // Chaining the logged tricks onto the base reproduces the transcript
+        let mut hash = game.trick_log_base.clone();
+        for t in game.trick_log.iter() {
+            let mut data = Bytes::from_array(&env, &hash.to_array());
+            assert_eq!(t.cards.len(), 2);
+            for word in [t.flipped, t.cards.get_unchecked(0), t.cards.get_unchecked(1), t.winner] {
+                data.append(&Bytes::from_array(&env, &word.to_be_bytes()));
+            }
+            hash = env.crypto().keccak256(&data).into();
+        }
+        assert_eq!(hash, game.transcript);
+
+        if game.lifecycle_state == STATE_FINISHED { break; }
+        assert!(iterations < 200, "Game did not finish after 200 iterations");
+        iterations += 1;
+        play_one_trick(&env, &client, &player1, &player2, sid);
+    }
+
+    let final_game = client.get_game_debug(&sid);
+    // A full game runs past the log length, so older tricks were folded away
+    assert!(final_game.tricks_pruned > 0);
+    assert_eq!(final_game.trick_log.len(), 8);
+    assert_ne!(final_game.trick_log_base, dealt.transcript);
+}
+
+#[test]
+fn game_stored_before_versioning_still_loads() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 983u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    let key = crate::StorageKey::Game(sid);
+    let version_key = Symbol::new(&env, "schema_version");
+
+    // Store the layout the previous WASM wrote: no schema_version
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        raw.remove(version_key.clone());
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_eq!(client.get_game_debug(&sid).schema_version, GAME_SCHEMA_VERSION);
+    assert_eq!(client.migrate_game(&sid), 0);
+    assert_eq!(client.migrate_game(&sid), GAME_SCHEMA_VERSION);
+
+    // Version 1 had no blitz clock
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        let config_key = Symbol::new(&env, "config");
+        let mut config: Map<Symbol, Val> = raw.get(config_key.clone()).unwrap().into_val(&env);
+        config.remove(Symbol::new(&env, "time_bank_ledgers"));
+        raw.set(config_key, config.into_val(&env));
+        raw.remove(Symbol::new(&env, "time_banks"));
+        raw.set(version_key.clone(), 1u32.into_val(&env));
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_eq!(client.migrate_game(&sid), 1);
+    let game = client.get_game(&sid);
+    assert_eq!((game.config, game.time_banks), (GameConfig::standard(), Vec::new(&env)));
+
+    // Version 2 had no forfeit record
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        raw.remove(Symbol::new(&env, "forfeited_by"));
+        raw.set(version_key.clone(), 2u32.into_val(&env));
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_eq!(client.migrate_game(&sid), 2);
+    assert_eq!(client.get_game(&sid).forfeited_by, 0);
+
+    // Version 3 had no team play
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        let config_key = Symbol::new(&env, "config");
+        let mut config: Map<Symbol, Val> = raw.get(config_key.clone()).unwrap().into_val(&env);
+        config.remove(Symbol::new(&env, "teams"));
+        raw.set(config_key, config.into_val(&env));
+        raw.set(version_key.clone(), 3u32.into_val(&env));
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_eq!(client.migrate_game(&sid), 3);
+    assert_eq!(client.get_game(&sid).config, GameConfig::standard());
+
+    // Version 4 had no bot games
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        raw.remove(Symbol::new(&env, "vs_bot"));
+        raw.set(version_key.clone(), 4u32.into_val(&env));
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_eq!(client.migrate_game(&sid), 4);
+    assert!(!client.get_game(&sid).vs_bot);
+
+    // A layout from a newer WASM is refused rather than misread
+    env.as_contract(&client.address, || {
+        let mut raw: Map<Symbol, Val> = env.storage().temporary().get(&key).unwrap();
+        raw.set(version_key, (GAME_SCHEMA_VERSION + 1).into_val(&env));
+        env.storage().temporary().set(&key, &raw);
+    });
+    assert_cangkulan_error(&client.try_get_game(&sid), CangkulanError::UnsupportedGameSchema);
+}
+
+#[test]
+fn replay_export_verifies_and_catches_edits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 984u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    assert_cangkulan_error(&client.try_export_replay(&sid), CangkulanError::GameNotFinished);
+    while client.get_game_debug(&sid).lifecycle_state != STATE_FINISHED {
+        play_one_trick(&env, &client, &player1, &player2, sid);
+    }
+
+    let replay = client.export_replay(&sid);
+    let tricks = client.get_trick_history(&sid).len();
+    let tricks_at = 48 + 2 * 32 + 36 * 4 + 4;
+    let outcome_at = tricks_at + tricks * 16;
+    assert_eq!(replay.len(), outcome_at + 4 + 2 * 4 + 32);
+    assert!(client.verify_replay(&replay));
+
+    // Swapping two dealt cards breaks the shuffle
+    let mut edited = replay.clone();
+    let (first, second) = (edited.get_unchecked(112 + 3), edited.get_unchecked(116 + 3));
+    edited.set(112 + 3, second);
+    edited.set(116 + 3, first);
+    assert!(!client.verify_replay(&edited));
+
+    // Claiming another trick winner breaks the transcript
+    let mut edited = replay.clone();
+    let winner_at = tricks_at + 12 + 3;
+    edited.set(winner_at, if edited.get_unchecked(winner_at) == 1 { 2 } else { 1 });
+    assert!(!client.verify_replay(&edited));
+
+    // A consistent forgery still breaks the rules: hand the first won
+    // trick to the other seat, fix up tricks won and rechain the transcript
+    let mut edited = replay.clone();
+    let won = (0..tricks).map(|i| tricks_at + i * 16 + 15).find(|&at| edited.get_unchecked(at) != 0).unwrap();
+    let (from, to) = if edited.get_unchecked(won) == 1 { (0, 1) } else { (1, 0) };
+    edited.set(won, to as u8 + 1);
+    let won_at = |seat: u32| outcome_at + 4 + seat * 4 + 3;
+    edited.set(won_at(from), edited.get_unchecked(won_at(from)) - 1);
+    edited.set(won_at(to), edited.get_unchecked(won_at(to)) + 1);
+    let mut seed_hashes = Vec::new(&env);
+    for seat in 0..2 {
+        seed_hashes.push_back(BytesN::<32>::try_from(edited.slice(48 + seat * 32..80 + seat * 32)).unwrap());
+    }
+    let chain = |prev: BytesN<32>, entry: Bytes| -> BytesN<32> {
+        let mut data = Bytes::from_array(&env, &prev.to_array());
+        data.append(&entry);
+        env.crypto().keccak256(&data).into()
+    };
+    let mut transcript = chain(BytesN::from_array(&env, &[0u8; 32]), crate::deal_seed_n(&env, &seed_hashes, sid).into());
+    for i in 0..tricks {
+        transcript = chain(transcript, edited.slice(tricks_at + i * 16..tricks_at + (i + 1) * 16));
+    }
+    let transcript_at = outcome_at + 4 + 2 * 4;
+    for (i, byte) in transcript.to_array().iter().enumerate() {
+        edited.set(transcript_at + i as u32, *byte);
+    }
+    assert!(!client.verify_replay(&edited));
+
+    // So does recording another outcome than the last trick ends with
+    let mut edited = replay.clone();
+    let outcome = edited.get_unchecked(outcome_at + 3);
+    edited.set(outcome_at + 3, if outcome == OUTCOME_PLAYER1_WIN as u8 { OUTCOME_PLAYER2_WIN as u8 } else { OUTCOME_PLAYER1_WIN as u8 });
+    assert!(!client.verify_replay(&edited));
+
+    let truncated = replay.slice(..replay.len() - 1);
+    assert_cangkulan_error(&client.try_verify_replay(&truncated), CangkulanError::MalformedReplay);
+}
+
+#[test]
+fn trick_history_keeps_every_trick() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 982u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+    assert_eq!(client.get_trick_history(&sid).len(), 0);
+
+    play_one_trick(&env, &client, &player1, &player2, sid);
+    let first = client.get_trick_history(&sid).get(0).unwrap();
+    assert_eq!(first.ledger, env.ledger().sequence());
+
+    while client.get_game_debug(&sid).lifecycle_state != STATE_FINISHED {
+        play_one_trick(&env, &client, &player1, &player2, sid);
+    }
+    let game = client.get_game_debug(&sid);
+    let history = client.get_trick_history(&sid);
+    assert_eq!(history.len(), game.tricks_pruned + game.trick_log.len());
+    assert_eq!(history.get(0).unwrap(), first);
+    assert_eq!(history.slice(game.tricks_pruned..), game.trick_log);
+}
+
+#[test]
+fn export_summary_rejected_before_finish() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 981u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let result = client.try_export_summary(&sid);
+    assert_cangkulan_error(&result, CangkulanError::GameNotFinished);
+    assert_eq!(client.get_summary_hash(&sid), None);
+
+    // Forfeit still produces a verifiable summary
+    client.forfeit(&sid, &player1);
+    let blob = client.export_summary(&sid);
+    let expected: BytesN<32> = env.crypto().keccak256(&blob).into();
+    assert_eq!(client.get_summary_hash(&sid), Some(expected));
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  ZK Card Play (commit_play_zk + reveal_play Pedersen opening)
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Compute Pedersen commitment: C = card_id·G + blinding·H on BLS12-381.
+/// Returns the commitment hash (keccak256(C_bytes)) and C point bytes for reveal.
+fn compute_zk_play_commit(env: &Env, card_id: u32, blinding: &BytesN<32>) -> BytesN<32> {
+    let bls = env.crypto().bls12_381();
+
+    let g1_bytes: [u8; 96] = [
+        0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+        0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+        0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+        0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+        0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
+        0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
+        0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
+        0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+    ];
+    let g = G1Affine::from_array(env, &g1_bytes);
+
+    // Same H derivation as the contract
+    let h_msg = Bytes::from_slice(env, PEDERSEN_H_MSG);
+    let h_dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+    let h = bls.hash_to_g1(&h_msg, &h_dst);
 
     let mut card_fr_arr = [0u8; 32];
     card_fr_arr[28] = ((card_id >> 24) & 0xFF) as u8;
@@ -1658,16 +2556,16 @@ fn compute_zk_play_commit(env: &Env, card_id: u32, blinding: &BytesN<32>) -> Byt
 /// Helper: find which player has a card matching the trick suit and return
 /// (zk_player, zk_player_addr, other_player_addr, zk_card, is_slot1).
 /// Returns None if neither player has the trick suit.
-fn find_zk_candidate<'a>(
+fn find_zk_candidate(
     game: &crate::CangkulanGame,
     trick_suit: u32,
     player1: &Address,
     player2: &Address,
 ) -> Option<(Address, Address, u32, bool)> {
-    if let Some(card) = first_card_of_suit(&game.hand1, trick_suit) {
+    if let Some(card) = first_card_of_suit(&game.seat(1).hand, trick_suit) {
         return Some((player1.clone(), player2.clone(), card, true));
     }
-    if let Some(card) = first_card_of_suit(&game.hand2, trick_suit) {
+    if let Some(card) = first_card_of_suit(&game.seat(2).hand, trick_suit) {
         return Some((player2.clone(), player1.clone(), card, false));
     }
     None
@@ -1697,13 +2595,13 @@ fn zk_play_commit_and_reveal() {
 
     let game2 = client.get_game_debug(&sid);
     if is_slot1 {
-        assert!(game2.zk_play1, "ZK flag should be set");
+        assert!(game2.seat(1).zk_play, "ZK flag should be set");
     } else {
-        assert!(game2.zk_play2, "ZK flag should be set");
+        assert!(game2.seat(2).zk_play, "ZK flag should be set");
     }
 
     // Other player uses legacy commit
-    let other_hand = if is_slot1 { &game2.hand2 } else { &game2.hand1 };
+    let other_hand = if is_slot1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
     let other_action = match first_card_of_suit(other_hand, trick_suit) {
         Some(c) => c,
         None => CANNOT_FOLLOW_SENTINEL,
@@ -1749,7 +2647,7 @@ fn zk_play_wrong_blinding_fails() {
 
     // Other player legacy commit
     let game2 = client.get_game_debug(&sid);
-    let other_hand = if is_slot1 { &game2.hand2 } else { &game2.hand1 };
+    let other_hand = if is_slot1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
     let other_action = match first_card_of_suit(other_hand, trick_suit) {
         Some(c) => c,
         None => CANNOT_FOLLOW_SENTINEL,
@@ -1799,9 +2697,9 @@ fn zk_play_no_matching_suit_fails() {
     let trick_suit = game.trick_suit.unwrap();
 
     // Find a player who has NO cards of the trick suit
-    let no_suit_player = if !hand_has_suit(&game.hand1, trick_suit) {
+    let no_suit_player = if !hand_has_suit(&game.seat(1).hand, trick_suit) {
         Some(player1.clone())
-    } else if !hand_has_suit(&game.hand2, trick_suit) {
+    } else if !hand_has_suit(&game.seat(2).hand, trick_suit) {
         // P2 commits second, but we can test by having P1 commit first (legacy),
         // then P2 tries commit_play_zk.
         // Actually P2 can't commit first in TRICK_COMMIT_WAIT_BOTH state.
@@ -1840,8 +2738,8 @@ fn zk_play_both_players_zk() {
     let game = client.get_game_debug(&sid);
     let trick_suit = game.trick_suit.unwrap();
 
-    let p1_card = first_card_of_suit(&game.hand1, trick_suit);
-    let p2_card = first_card_of_suit(&game.hand2, trick_suit);
+    let p1_card = first_card_of_suit(&game.seat(1).hand, trick_suit);
+    let p2_card = first_card_of_suit(&game.seat(2).hand, trick_suit);
 
     if p1_card.is_none() || p2_card.is_none() {
         // Can't test both-ZK if one player lacks trick suit cards — skip
@@ -1861,12 +2759,12 @@ fn zk_play_both_players_zk() {
     // Both commit using ZK
     client.commit_play_zk(&sid, &player1, &commit_hash1, &game.action_nonce, &dummy_proof);
     let game2 = client.get_game_debug(&sid);
-    assert!(game2.zk_play1);
+    assert!(game2.seat(1).zk_play);
     client.commit_play_zk(&sid, &player2, &commit_hash2, &game2.action_nonce, &dummy_proof);
 
     let game3 = client.get_game_debug(&sid);
-    assert!(game3.zk_play1);
-    assert!(game3.zk_play2);
+    assert!(game3.seat(1).zk_play);
+    assert!(game3.seat(2).zk_play);
     assert_eq!(game3.trick_state, TRICK_REVEAL_WAIT_BOTH);
 
     // Both reveal with Pedersen opening
@@ -1879,8 +2777,82 @@ fn zk_play_both_players_zk() {
         "Trick should be resolved"
     );
     // ZK flags should be reset after trick resolution
-    assert!(!game4.zk_play1, "ZK flag should be reset after trick");
-    assert!(!game4.zk_play2, "ZK flag should be reset after trick");
+    assert!(!game4.seat(1).zk_play, "ZK flag should be reset after trick");
+    assert!(!game4.seat(2).zk_play, "ZK flag should be reset after trick");
+}
+
+/// After the game, the blindings of a ZK play reopen its commitment.
+#[test]
+fn audit_reveal_reopens_zk_plays() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 805u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let (zk_player, other_player, zk_card, _) =
+        find_zk_candidate(&game, trick_suit, &player1, &player2)
+            .expect("At least one player must have a card of the trick suit");
+
+    let blinding = BytesN::<32>::from_array(&env, &{ let mut a = [0u8; 32]; a[31] = 77; a });
+    let commit_hash = compute_zk_play_commit(&env, zk_card, &blinding);
+    let dummy_proof = Bytes::from_array(&env, &[0xFFu8; 160]);
+    client.commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &dummy_proof);
+
+    let game2 = client.get_game_debug(&sid);
+    let other_hand = if zk_player == player1 { game2.seat(2).hand } else { game2.seat(1).hand };
+    let other_action = first_card_of_suit(&other_hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let salt = test_salt(&env, 0x22);
+    client.commit_play(&sid, &other_player, &compute_play_commit(&env, other_action, &salt), &game2.action_nonce);
+    client.reveal_play(&sid, &zk_player, &zk_card, &blinding);
+    client.reveal_play(&sid, &other_player, &other_action, &salt);
+
+    let blindings = vec![&env, blinding];
+    let early = client.try_audit_reveal(&sid, &zk_player, &blindings);
+    assert_cangkulan_error(&early, CangkulanError::GameNotFinished);
+    client.forfeit(&sid, &other_player);
+
+    assert!(client.audit_reveal(&sid, &zk_player, &blindings));
+    let events = env.events().all().filter_by_contract(&client.address);
+    let audited = EvRevealAudited { session_id: sid, player: zk_player.clone(), plays: 1, valid: true };
+    assert!(events.events().contains(&audited.to_xdr(&env, &client.address)));
+
+    assert!(!client.audit_reveal(&sid, &zk_player, &vec![&env, test_salt(&env, 0x01)]));
+    let missing = client.try_audit_reveal(&sid, &zk_player, &Vec::new(&env));
+    assert_cangkulan_error(&missing, CangkulanError::AuditBlindingCount);
+    assert!(client.audit_reveal(&sid, &other_player, &Vec::new(&env)));
+}
+
+/// A ZK play whose trick never resolved is skipped, not failed.
+#[test]
+fn audit_reveal_skips_play_cut_off_by_forfeit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 806u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let (zk_player, other_player, zk_card, _) =
+        find_zk_candidate(&game, trick_suit, &player1, &player2)
+            .expect("At least one player must have a card of the trick suit");
+
+    let blinding = BytesN::<32>::from_array(&env, &{ let mut a = [0u8; 32]; a[31] = 78; a });
+    let commit_hash = compute_zk_play_commit(&env, zk_card, &blinding);
+    let dummy_proof = Bytes::from_array(&env, &[0xFFu8; 160]);
+    client.commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &dummy_proof);
+
+    // The opponent forfeits before the trick resolves
+    client.forfeit(&sid, &other_player);
+    assert!(client.get_trick_history(&sid).is_empty());
+
+    assert!(client.audit_reveal(&sid, &zk_player, &Vec::new(&env)));
+    let events = env.events().all().filter_by_contract(&client.address);
+    let audited = EvRevealAudited { session_id: sid, player: zk_player.clone(), plays: 0, valid: true };
+    assert!(events.events().contains(&audited.to_xdr(&env, &client.address)));
+    let extra = client.try_audit_reveal(&sid, &zk_player, &vec![&env, blinding]);
+    assert_cangkulan_error(&extra, CangkulanError::AuditBlindingCount);
 }
 
 // ════════════════════════════════════════════════════════════════════════════
@@ -1901,7 +2873,7 @@ fn zk_cangkul_commit_succeeds() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        if !hand_has_suit(&game.hand1, suit) || !hand_has_suit(&game.hand2, suit) {
+        if !hand_has_suit(&game.seat(1).hand, suit) || !hand_has_suit(&game.seat(2).hand, suit) {
             break;
         }
         sid += 1;
@@ -1911,7 +2883,7 @@ fn zk_cangkul_commit_succeeds() {
     let trick_suit = game.trick_suit.unwrap();
 
     // Find which player has no matching suit
-    let (cangkul_player, other_player, is_slot1) = if !hand_has_suit(&game.hand1, trick_suit) {
+    let (cangkul_player, _other_player, is_slot1) = if !hand_has_suit(&game.seat(1).hand, trick_suit) {
         (player1.clone(), player2.clone(), true)
     } else {
         (player2.clone(), player1.clone(), false)
@@ -1927,11 +2899,11 @@ fn zk_cangkul_commit_succeeds() {
 
     let game2 = client.get_game_debug(&sid);
     if is_slot1 {
-        assert!(game2.zk_play1, "ZK flag should be set for P1");
-        assert_eq!(game2.play_commit1.unwrap(), commit_hash);
+        assert!(game2.seat(1).zk_play, "ZK flag should be set for P1");
+        assert_eq!(game2.seat(1).play_commit.unwrap(), commit_hash);
     } else {
-        assert!(game2.zk_play2, "ZK flag should be set for P2");
-        assert_eq!(game2.play_commit2.unwrap(), commit_hash);
+        assert!(game2.seat(2).zk_play, "ZK flag should be set for P2");
+        assert_eq!(game2.seat(2).play_commit.unwrap(), commit_hash);
     }
 }
 
@@ -1949,7 +2921,7 @@ fn zk_cangkul_has_matching_suit_fails() {
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
         // Need a player who HAS the trick suit
-        if hand_has_suit(&game.hand1, suit) {
+        if hand_has_suit(&game.seat(1).hand, suit) {
             break;
         }
         sid += 1;
@@ -1965,7 +2937,7 @@ fn zk_cangkul_has_matching_suit_fails() {
     assert_cangkulan_error(&result, CangkulanError::HasMatchingSuit);
 }
 
-/// ZK cangkul commit with empty proof fails.
+/// ZK cangkul commit with an empty proof and no recorded verification fails.
 #[test]
 fn zk_cangkul_empty_proof_fails() {
     let (env, client, _hub, player1, player2) = setup_test();
@@ -1978,7 +2950,7 @@ fn zk_cangkul_empty_proof_fails() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        if !hand_has_suit(&game.hand1, suit) {
+        if !hand_has_suit(&game.seat(1).hand, suit) {
             break;
         }
         sid += 1;
@@ -1994,6 +2966,52 @@ fn zk_cangkul_empty_proof_fails() {
     assert_cangkulan_error(&result, CangkulanError::ZkCangkulProofInvalid);
 }
 
+/// Split TX flow: the hand proof is recorded by the verifier first, then
+/// `commit_cangkul_zk` with an empty proof consumes the record.
+#[test]
+fn zk_cangkul_consumes_recorded_verification() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let mut sid = 1060u32;
+    loop {
+        assert!(sid < 1200, "Could not find no-suit setup");
+        client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+        advance_to_playing(&env, &client, sid, &player1, &player2);
+
+        let game = client.get_game_debug(&sid);
+        if !hand_has_suit(&game.seat(1).hand, game.trick_suit.unwrap()) {
+            break;
+        }
+        sid += 1;
+    }
+
+    let game = client.get_game_debug(&sid);
+    let commit_hash = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let empty_proof = Bytes::new(&env);
+
+    // The Mode 8 statement the contract rebuilds from game state.
+    let mut public_inputs = Bytes::from_array(&env, &commit_hash.to_array());
+    public_inputs.append(&Bytes::from_array(&env, &game.trick_suit.unwrap().to_be_bytes()));
+    public_inputs.append(&Bytes::from_array(&env, &game.seat(1).hand.len().to_be_bytes()));
+    for card in game.seat(1).hand.iter() {
+        public_inputs.append(&Bytes::from_array(&env, &card.to_be_bytes()));
+    }
+    public_inputs.append(&Bytes::from_array(&env, &sid.to_be_bytes()));
+    public_inputs.append(&player1.to_string().to_bytes());
+    let commitment: BytesN<32> = env.crypto().keccak256(&public_inputs).into();
+
+    let verifier = MockZkVerifierClient::new(&env, &client.get_verifier());
+    verifier.record_verification(&client.address, &sid, &player1, &MODE_CANGKUL_HAND, &commitment);
+    client.commit_cangkul_zk(&sid, &player1, &commit_hash, &game.action_nonce, &empty_proof);
+
+    let after = client.get_game_debug(&sid);
+    assert_eq!(after.seat(1).play_commit, Some(commit_hash));
+    assert!(after.seat(1).zk_play);
+    assert!(!verifier.consume_verification(
+        &client.address, &sid, &player1, &MODE_CANGKUL_HAND, &commitment,
+    ));
+}
+
 /// Full ZK cangkul flow: commit_cangkul_zk → reveal_play with Pedersen opening.
 #[test]
 fn zk_cangkul_full_flow() {
@@ -2008,8 +3026,8 @@ fn zk_cangkul_full_flow() {
 
         let game = client.get_game_debug(&sid);
         let suit = game.trick_suit.unwrap();
-        let p1_has = hand_has_suit(&game.hand1, suit);
-        let p2_has = hand_has_suit(&game.hand2, suit);
+        let p1_has = hand_has_suit(&game.seat(1).hand, suit);
+        let p2_has = hand_has_suit(&game.seat(2).hand, suit);
         if p1_has != p2_has {
             break;
         }
@@ -2018,7 +3036,7 @@ fn zk_cangkul_full_flow() {
 
     let game = client.get_game_debug(&sid);
     let trick_suit = game.trick_suit.unwrap();
-    let p1_has = hand_has_suit(&game.hand1, trick_suit);
+    let p1_has = hand_has_suit(&game.seat(1).hand, trick_suit);
 
     let (cangkul_player, follow_player, is_cangkul_p1) = if !p1_has {
         (player1.clone(), player2.clone(), true)
@@ -2028,7 +3046,7 @@ fn zk_cangkul_full_flow() {
 
     // ZK cangkul commit for the player without matching suit
     // For aggregate opening: commit_hash = keccak256(pedersen_commit(card_sum, r_agg))
-    let cangkul_hand = if is_cangkul_p1 { &game.hand1 } else { &game.hand2 };
+    let cangkul_hand = if is_cangkul_p1 { &game.seat(1).hand } else { &game.seat(2).hand };
     let card_sum: u32 = cangkul_hand.iter().sum();
     let dummy_proof = Bytes::from_array(&env, &[0xFFu8; 228]);
     let r_agg = BytesN::<32>::from_array(&env, &{ let mut a = [0u8; 32]; a[31] = 77; a });
@@ -2039,7 +3057,7 @@ fn zk_cangkul_full_flow() {
 
     // Follow player uses legacy commit
     let game2 = client.get_game_debug(&sid);
-    let follow_hand = if is_cangkul_p1 { &game2.hand2 } else { &game2.hand1 };
+    let follow_hand = if is_cangkul_p1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
     let follow_card = first_card_of_suit(follow_hand, trick_suit).unwrap();
     let salt2 = test_salt(&env, 0x33);
     let commit2 = compute_play_commit(&env, follow_card, &salt2);
@@ -2070,10 +3088,6 @@ fn zk_cangkul_full_flow() {
 
 use zk_verifier::ZkCommitmentVerifier;
 
-/// NIZK challenge tag: "ZKV2" (must match zk-verifier constant)
-const ZK_CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x56, 0x32];
-/// Pedersen challenge tag: "ZKP4" (must match zk-verifier constant)
-const ZK_PEDERSEN_CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x50, 0x34];
 
 /// Setup with the REAL ZkCommitmentVerifier contract instead of mock.
 fn setup_test_real_verifier() -> (
@@ -2101,9 +3115,9 @@ fn setup_test_real_verifier() -> (
     let hub_client = MockGameHubClient::new(&env, &hub_addr);
 
     // Register the REAL ZK verifier instead of MockZkVerifier
-    let verifier_addr = env.register(ZkCommitmentVerifier, ());
-
     let admin = Address::generate(&env);
+    let verifier_addr = env.register(ZkCommitmentVerifier, (&admin,));
+
     let contract_id = env.register(CangkulanContract, (&admin, &hub_addr, &verifier_addr));
     let client = CangkulanContractClient::new(&env, &contract_id);
 
@@ -2132,7 +3146,7 @@ fn generate_real_nizk_proof(
     let mut challenge_pre = Bytes::from_array(env, &commitment.to_array());
     challenge_pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
     challenge_pre.append(&player.to_string().to_bytes());
-    challenge_pre.append(&Bytes::from_array(env, &ZK_CHALLENGE_TAG));
+    challenge_pre.append(&Bytes::from_array(env, &CHALLENGE_TAG));
     let challenge: BytesN<32> = env.crypto().keccak256(&challenge_pre).into();
 
     // Step 3: Compute response = keccak256(seed_hash || challenge || blinding)
@@ -2177,8 +3191,8 @@ fn generate_real_pedersen_proof(
     let g = G1Affine::from_array(env, &g1_bytes);
 
     // Pedersen H generator (hash_to_g1)
-    let msg = Bytes::from_slice(env, b"PEDERSEN_H");
-    let dst = Bytes::from_slice(env, b"SGS_CANGKULAN_V1");
+    let msg = Bytes::from_slice(env, PEDERSEN_H_MSG);
+    let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
     let h = bls.hash_to_g1(&msg, &dst);
 
     // seed_scalar = Fr from seed_hash bytes
@@ -2207,7 +3221,7 @@ fn generate_real_pedersen_proof(
     challenge_pre.append(&Bytes::from_array(env, &seed_hash_fr_bytes.to_array()));
     challenge_pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
     challenge_pre.append(&player.to_string().to_bytes());
-    challenge_pre.append(&Bytes::from_array(env, &ZK_PEDERSEN_CHALLENGE_TAG));
+    challenge_pre.append(&Bytes::from_array(env, &PEDERSEN_CHALLENGE_TAG));
     let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_pre).into();
     let e = Fr::from_bytes(e_hash);
 
@@ -2253,8 +3267,8 @@ fn test_integration_nizk_seed_commit_reveal() {
     let (proof2, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
 
     // Commit phase
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
 
     // Reveal phase — proofs go through real ZK verifier
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2263,8 +3277,8 @@ fn test_integration_nizk_seed_commit_reveal() {
     // Should be in PLAYING state now
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_PLAYING);
-    assert!(game.hand1.len() > 0, "Player 1 should have cards");
-    assert!(game.hand2.len() > 0, "Player 2 should have cards");
+    assert!(!game.seat(1).hand.is_empty(), "Player 1 should have cards");
+    assert!(!game.seat(2).hand.is_empty(), "Player 2 should have cards");
 }
 
 #[test]
@@ -2295,8 +3309,8 @@ fn test_integration_nizk_wrong_blinding_rejected() {
     let (_proof2_valid, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
 
     // Commit
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
 
     // Player 1 reveal succeeds
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2332,8 +3346,8 @@ fn test_integration_nizk_wrong_seed_hash_rejected() {
     let (proof1, commit1) = generate_real_nizk_proof(&env, &seed_hash1, &blinding1, sid, &player1);
     let (_proof2, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
 
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
 
     // Player 1 reveals normally
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2347,6 +3361,73 @@ fn test_integration_nizk_wrong_seed_hash_rejected() {
     assert!(result.is_err(), "Reveal with different seed_hash should fail");
 }
 
+#[test]
+fn test_integration_nizk_through_verifier_allowlist() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let sid = 1u32;
+
+    // Only the game contract may verify; it calls in via `verify_from`.
+    let verifier = zk_verifier::ZkCommitmentVerifierClient::new(&env, &client.get_verifier());
+    verifier.set_allowlist_enabled(&true);
+    verifier.set_caller_allowed(&client.address, &true);
+
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed1 = BytesN::<32>::from_array(&env, &[
+        1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+        17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+    ]);
+    let seed2 = BytesN::<32>::from_array(&env, &[
+        32,31,30,29,28,27,26,25,24,23,22,21,20,19,18,17,
+        16,15,14,13,12,11,10,9,8,7,6,5,4,3,2,1,
+    ]);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let seed_hash1 = compute_seed_hash(&env, &seed1);
+    let seed_hash2 = compute_seed_hash(&env, &seed2);
+    let (proof1, commit1) = generate_real_nizk_proof(&env, &seed_hash1, &blinding1, sid, &player1);
+    let (proof2, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
+
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+    client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
+
+    // Off the allowlist, the game's reveals are rejected by the verifier.
+    verifier.set_caller_allowed(&client.address, &false);
+    assert!(client.try_reveal_seed(&sid, &player2, &seed_hash2, &proof2).is_err());
+
+    verifier.set_caller_allowed(&client.address, &true);
+    client.reveal_seed(&sid, &player2, &seed_hash2, &proof2);
+    assert_eq!(client.get_game_debug(&sid).lifecycle_state, STATE_PLAYING);
+}
+
+#[test]
+fn test_integration_nizk_with_legacy_proofs_refused() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let sid = 1u32;
+
+    // The game tags what it forwards, so refusing untagged proofs is safe.
+    let verifier = zk_verifier::ZkCommitmentVerifierClient::new(&env, &client.get_verifier());
+    verifier.set_legacy_proofs_enabled(&false);
+
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed1 = BytesN::<32>::from_array(&env, &[0x5Au8; 32]);
+    let seed2 = BytesN::<32>::from_array(&env, &[0xC3u8; 32]);
+    let seed_hash1 = compute_seed_hash(&env, &seed1);
+    let seed_hash2 = compute_seed_hash(&env, &seed2);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let (proof1, commit1) = generate_real_nizk_proof(&env, &seed_hash1, &blinding1, sid, &player1);
+    let (proof2, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
+
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+    client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
+    client.reveal_seed(&sid, &player2, &seed_hash2, &proof2);
+    assert_eq!(client.get_game_debug(&sid).lifecycle_state, STATE_PLAYING);
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 //  Pedersen+Sigma Integration Tests
 // ────────────────────────────────────────────────────────────────────────────
@@ -2408,8 +3489,8 @@ fn test_integration_pedersen_seed_commit_reveal() {
     assert_eq!(proof2.len(), 224, "Pedersen proof should be 224 bytes");
 
     // Commit phase
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_PEDERSEN);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_PEDERSEN);
 
     // Reveal phase — real BLS12-381 ZK verification
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2418,8 +3499,8 @@ fn test_integration_pedersen_seed_commit_reveal() {
     // Should proceed to PLAYING state
     let game = client.get_game_debug(&sid);
     assert_eq!(game.lifecycle_state, STATE_PLAYING);
-    assert!(game.hand1.len() > 0, "Player 1 should have cards dealt");
-    assert!(game.hand2.len() > 0, "Player 2 should have cards dealt");
+    assert!(!game.seat(1).hand.is_empty(), "Player 1 should have cards dealt");
+    assert!(!game.seat(2).hand.is_empty(), "Player 2 should have cards dealt");
 }
 
 #[test]
@@ -2475,8 +3556,8 @@ fn test_integration_pedersen_tampered_sigma_rejected() {
     );
 
     // Commit both seeds
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_PEDERSEN);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_PEDERSEN);
 
     // Player 1 reveals OK
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2541,9 +3622,9 @@ fn test_integration_mixed_modes_nizk_and_pedersen() {
     );
     assert_eq!(proof2.len(), 224);
 
-    // Commit both (different modes doesn't matter for commit)
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    // Commit both, each declaring the mode it will reveal with
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_PEDERSEN);
 
     // Reveal — each uses their respective ZK mode
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
@@ -2586,8 +3667,8 @@ fn test_integration_full_game_with_real_verifier() {
     let (proof2, commit2) = generate_real_nizk_proof(&env, &seed_hash2, &blinding2, sid, &player2);
 
     // Commit → Reveal with real ZK verification
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
     client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
     client.reveal_seed(&sid, &player2, &seed_hash2, &proof2);
 
@@ -2611,11 +3692,11 @@ fn test_integration_full_game_with_real_verifier() {
         let salt2 = test_salt(&env, (tricks_played * 2 + 2) as u8);
 
         // Determine actions
-        let p1_action = match first_card_of_suit(&g.hand1, trick_suit) {
+        let p1_action = match first_card_of_suit(&g.seat(1).hand, trick_suit) {
             Some(c) => c,
             None => CANNOT_FOLLOW_SENTINEL,
         };
-        let p2_action = match first_card_of_suit(&g.hand2, trick_suit) {
+        let p2_action = match first_card_of_suit(&g.seat(2).hand, trick_suit) {
             Some(c) => c,
             None => CANNOT_FOLLOW_SENTINEL,
         };
@@ -2639,29 +3720,13 @@ fn test_integration_full_game_with_real_verifier() {
     assert!(tricks_played > 0, "Should have played at least one trick");
 }
 
-// ════════════════════════════════════════════════════════════════════════════
-//  Mock UltraHonk Verifier (accepts any proof for testing)
-// ════════════════════════════════════════════════════════════════════════════
-
-#[contract]
-pub struct MockUltraHonk;
-
-#[contractimpl]
-impl MockUltraHonk {
-    /// Accepts any proof — panics only if proof is empty.
-    pub fn verify_proof(_env: Env, _public_inputs: Bytes, proof_bytes: Bytes) {
-        if proof_bytes.len() == 0 {
-            panic!("empty proof");
-        }
-    }
-}
-
 // ════════════════════════════════════════════════════════════════════════════
 //  Tests: Split Noir Verification (verify_noir_seed → reveal_seed)
 // ════════════════════════════════════════════════════════════════════════════
 
-/// Helper: setup with UltraHonk verifier linked.
-fn setup_test_with_ultrahonk() -> (
+/// Helper: setup for the Noir flow. Noir proofs reach the (mock) ZK verifier
+/// through `verify_from`, like every other seed proof.
+fn setup_test_noir() -> (
     Env,
     CangkulanContractClient<'static>,
     Address,
@@ -2683,15 +3748,11 @@ fn setup_test_with_ultrahonk() -> (
 
     let hub_addr = env.register(MockGameHub, ());
     let verifier_addr = env.register(MockZkVerifier, ());
-    let ultrahonk_addr = env.register(MockUltraHonk, ());
 
     let admin = Address::generate(&env);
     let contract_id = env.register(CangkulanContract, (&admin, &hub_addr, &verifier_addr));
     let client = CangkulanContractClient::new(&env, &contract_id);
 
-    // Link UltraHonk verifier
-    client.set_ultrahonk_verifier(&ultrahonk_addr);
-
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
 
@@ -2705,7 +3766,7 @@ fn compute_noir_commit_hash(env: &Env, seed_hash: &BytesN<32>) -> BytesN<32> {
     env.crypto().keccak256(&Bytes::from_array(env, &seed_hash.to_array())).into()
 }
 
-/// Build a big fake Noir proof (>4000 bytes). In tests, the MockUltraHonk accepts it.
+/// Build a big fake Noir proof (> ULTRAHONK_MIN_PROOF_LEN). The mock verifier accepts it.
 fn build_fake_noir_proof(env: &Env) -> Bytes {
     let mut proof = Bytes::new(env);
     // 5000 bytes of dummy data
@@ -2719,7 +3780,7 @@ fn build_fake_noir_proof(env: &Env) -> Bytes {
 
 #[test]
 fn test_verify_noir_seed_split_flow() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 900u32;
 
     // Use seed hashes with enough entropy (≥4 distinct bytes)
@@ -2739,8 +3800,8 @@ fn test_verify_noir_seed_split_flow() {
     client.start_game(&sid, &player1, &player2, &100, &100);
 
     // Commit seeds (Noir style: commit_hash = keccak256(seed_hash))
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     assert_eq!(client.get_game_debug(&sid).lifecycle_state, STATE_SEED_REVEAL);
 
@@ -2754,8 +3815,8 @@ fn test_verify_noir_seed_split_flow() {
 
     // P1 revealed, P2 still pending
     let game = client.get_game_debug(&sid);
-    assert!(game.seed_revealed1);
-    assert!(!game.seed_revealed2);
+    assert!(game.seat(1).seed_revealed);
+    assert!(!game.seat(2).seed_revealed);
     assert_eq!(game.lifecycle_state, STATE_SEED_REVEAL);
 
     // TX 1: verify_noir_seed for P2
@@ -2766,16 +3827,16 @@ fn test_verify_noir_seed_split_flow() {
 
     // Both revealed → should be PLAYING
     let game = client.get_game_debug(&sid);
-    assert!(game.seed_revealed1);
-    assert!(game.seed_revealed2);
+    assert!(game.seat(1).seed_revealed);
+    assert!(game.seat(2).seed_revealed);
     assert_eq!(game.lifecycle_state, STATE_PLAYING);
-    assert!(game.hand1.len() > 0, "P1 should have cards dealt");
-    assert!(game.hand2.len() > 0, "P2 should have cards dealt");
+    assert!(!game.seat(1).hand.is_empty(), "P1 should have cards dealt");
+    assert!(!game.seat(2).hand.is_empty(), "P2 should have cards dealt");
 }
 
 #[test]
 fn test_verify_noir_seed_replay_prevented() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 901u32;
 
     let seed_hash1 = BytesN::<32>::from_array(&env, &[
@@ -2791,8 +3852,8 @@ fn test_verify_noir_seed_replay_prevented() {
     let commit2 = compute_noir_commit_hash(&env, &seed_hash2);
 
     client.start_game(&sid, &player1, &player2, &100, &100);
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     // Verify + reveal P1
     let noir_proof = build_fake_noir_proof(&env);
@@ -2807,7 +3868,7 @@ fn test_verify_noir_seed_replay_prevented() {
 
 #[test]
 fn test_verify_noir_seed_wrong_hash_rejected() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 902u32;
 
     let seed_hash1 = BytesN::<32>::from_array(&env, &[
@@ -2827,8 +3888,8 @@ fn test_verify_noir_seed_wrong_hash_rejected() {
     let commit2 = compute_noir_commit_hash(&env, &seed_hash2);
 
     client.start_game(&sid, &player1, &player2, &100, &100);
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     // Verify with correct hash
     let noir_proof = build_fake_noir_proof(&env);
@@ -2842,7 +3903,7 @@ fn test_verify_noir_seed_wrong_hash_rejected() {
 
 #[test]
 fn test_verify_noir_seed_without_verify_rejected() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 903u32;
 
     let seed_hash1 = BytesN::<32>::from_array(&env, &[
@@ -2858,8 +3919,8 @@ fn test_verify_noir_seed_without_verify_rejected() {
     let commit2 = compute_noir_commit_hash(&env, &seed_hash2);
 
     client.start_game(&sid, &player1, &player2, &100, &100);
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     // Try reveal with empty proof WITHOUT calling verify_noir_seed first — should fail
     let empty_proof = Bytes::new(&env);
@@ -2869,7 +3930,7 @@ fn test_verify_noir_seed_without_verify_rejected() {
 
 #[test]
 fn test_verify_noir_commit_mismatch_rejected() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 904u32;
 
     let seed_hash1 = BytesN::<32>::from_array(&env, &[
@@ -2889,8 +3950,8 @@ fn test_verify_noir_commit_mismatch_rejected() {
     let commit2 = compute_noir_commit_hash(&env, &seed_hash2);
 
     client.start_game(&sid, &player1, &player2, &100, &100);
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     // Try verify_noir_seed with WRONG hash — commit binding should fail
     let noir_proof = build_fake_noir_proof(&env);
@@ -2900,7 +3961,7 @@ fn test_verify_noir_commit_mismatch_rejected() {
 
 #[test]
 fn test_verify_noir_small_proof_rejected() {
-    let (env, client, player1, player2) = setup_test_with_ultrahonk();
+    let (env, client, player1, player2) = setup_test_noir();
     let sid = 905u32;
 
     let seed_hash1 = BytesN::<32>::from_array(&env, &[
@@ -2916,11 +3977,2274 @@ fn test_verify_noir_small_proof_rejected() {
     let commit2 = compute_noir_commit_hash(&env, &seed_hash2);
 
     client.start_game(&sid, &player1, &player2, &100, &100);
-    client.commit_seed(&sid, &player1, &commit1);
-    client.commit_seed(&sid, &player2, &commit2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NOIR);
 
     // Try verify_noir_seed with too-small proof (64 bytes) — should reject
     let small_proof = build_nizk_proof(&env, &BytesN::<32>::from_array(&env, &[0xCC; 32]));
     let result = client.try_verify_noir_seed(&sid, &player1, &seed_hash1, &small_proof);
     assert_cangkulan_error(&result, CangkulanError::InvalidZkProof);
 }
+
+/// UltraHonk verifier behind the real ZK verifier: traps unless the proof
+/// starts with 0xAB, like `build_fake_noir_proof`.
+#[contract]
+pub struct MockUltraHonk;
+
+#[contractimpl]
+impl MockUltraHonk {
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, proof_bytes: Bytes) {
+        assert_eq!(proof_bytes.get(0), Some(0xAB), "invalid proof");
+    }
+}
+
+#[test]
+fn test_noir_rejection_is_typed_error() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let verifier = zk_verifier::ZkCommitmentVerifierClient::new(&env, &client.get_verifier());
+    verifier.set_ultrahonk_verifier(&env.register(MockUltraHonk, ()));
+    let sid = 905u32;
+
+    let seed_hash1 = BytesN::<32>::from_array(&env, &[
+        10,20,30,40,5,6,7,8,9,10,11,12,13,14,15,16,
+        17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+    ]);
+    let seed_hash2 = BytesN::<32>::from_array(&env, &[
+        32,31,30,29,28,27,26,25,24,23,22,21,20,19,18,17,
+        16,15,14,13,12,11,10,9,8,7,6,5,4,3,2,1,
+    ]);
+    client.start_game(&sid, &player1, &player2, &100, &100);
+    client.commit_seed(&sid, &player1, &compute_noir_commit_hash(&env, &seed_hash1), &SEED_PROOF_NOIR);
+    client.commit_seed(&sid, &player2, &compute_noir_commit_hash(&env, &seed_hash2), &SEED_PROOF_NOIR);
+
+    // A rejected proof is an error in both flows, not a trap
+    let mut bad_proof = build_fake_noir_proof(&env);
+    bad_proof.set(0, 0);
+    let result = client.try_verify_noir_seed(&sid, &player1, &seed_hash1, &bad_proof);
+    assert_cangkulan_error(&result, CangkulanError::UltraHonkVerificationFailed);
+    let result = client.try_reveal_seed(&sid, &player1, &seed_hash1, &bad_proof);
+    assert_cangkulan_error(&result, CangkulanError::UltraHonkVerificationFailed);
+
+    // The same seed with a valid proof goes through
+    client.verify_noir_seed(&sid, &player1, &seed_hash1, &build_fake_noir_proof(&env));
+    client.reveal_seed(&sid, &player1, &seed_hash1, &Bytes::new(&env));
+    client.reveal_seed(&sid, &player2, &seed_hash2, &build_fake_noir_proof(&env));
+    assert_eq!(client.get_game_debug(&sid).lifecycle_state, STATE_PLAYING);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Integration Tests: Real ZK card plays (Modes 7 and 8)
+// ════════════════════════════════════════════════════════════════════════════
+//
+// Proofs come from `zk_verifier::testutils`, so `commit_play_zk` and
+// `commit_cangkul_zk` run against the real ring and hand verifiers.
+
+extern crate std;
+
+use zk_verifier::testutils::{blinding_sum, cangkul_hand_proof, card_ring_proof, card_ring_proof_padded};
+
+/// Like `advance_to_playing`, but with NIZK proofs the real verifier accepts.
+fn advance_to_playing_real(
+    env: &Env,
+    client: &CangkulanContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    let seed_hash1 = compute_seed_hash(env, &BytesN::from_array(env, &[0x11u8; 32]));
+    let seed_hash2 = compute_seed_hash(env, &BytesN::from_array(env, &[0x22u8; 32]));
+    let blinding1 = BytesN::<32>::from_array(env, &[0xAAu8; 32]);
+    let blinding2 = BytesN::<32>::from_array(env, &[0xBBu8; 32]);
+    let (proof1, commit1) = generate_real_nizk_proof(env, &seed_hash1, &blinding1, session_id, player1);
+    let (proof2, commit2) = generate_real_nizk_proof(env, &seed_hash2, &blinding2, session_id, player2);
+    client.commit_seed(&session_id, player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&session_id, player2, &commit2, &SEED_PROOF_NIZK);
+    client.reveal_seed(&session_id, player1, &seed_hash1, &proof1);
+    client.reveal_seed(&session_id, player2, &seed_hash2, &proof2);
+}
+
+fn cards_of_suit(hand: &Vec<u32>, suit: u32) -> std::vec::Vec<u32> {
+    hand.iter().filter(|c| c / CARDS_PER_SUIT_36 == suit).collect()
+}
+
+/// A real ring proof commits, opens in `reveal_play` and resolves the trick.
+#[test]
+fn test_integration_zk_play_real_ring_proof() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let sid = 1400u32;
+    advance_to_playing_real(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let (zk_player, other_player, zk_card, is_slot1) =
+        find_zk_candidate(&game, trick_suit, &player1, &player2)
+            .expect("At least one player must have a card of the trick suit");
+    let zk_hand = if is_slot1 { &game.seat(1).hand } else { &game.seat(2).hand };
+    let valid_set = cards_of_suit(zk_hand, trick_suit);
+
+    let mut blinding = [0u8; 32];
+    blinding[31] = 99;
+    let (commit_hash, proof) =
+        card_ring_proof(&env, zk_card, &blinding, &valid_set, sid, &zk_player).unwrap();
+    client.commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &proof);
+
+    let game2 = client.get_game_debug(&sid);
+    assert!(if is_slot1 { game2.seat(1).zk_play } else { game2.seat(2).zk_play });
+
+    let other_hand = if is_slot1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
+    let other_action = first_card_of_suit(other_hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let salt2 = test_salt(&env, 0x22);
+    client.commit_play(&sid, &other_player, &compute_play_commit(&env, other_action, &salt2), &game2.action_nonce);
+
+    client.reveal_play(&sid, &zk_player, &zk_card, &BytesN::from_array(&env, &blinding));
+    client.reveal_play(&sid, &other_player, &other_action, &salt2);
+
+    let game3 = client.get_game_debug(&sid);
+    assert!(
+        game3.trick_state == TRICK_COMMIT_WAIT_BOTH || game3.lifecycle_state == STATE_FINISHED,
+        "Trick should be resolved"
+    );
+}
+
+/// A ring padded to nine members is recognised by its length and opens
+/// like an unpadded one.
+#[test]
+fn test_integration_zk_play_real_padded_ring_proof() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let sid = 1402u32;
+    advance_to_playing_real(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let (zk_player, other_player, zk_card, is_slot1) =
+        find_zk_candidate(&game, trick_suit, &player1, &player2).unwrap();
+    let zk_hand = if is_slot1 { &game.seat(1).hand } else { &game.seat(2).hand };
+    let valid_set = cards_of_suit(zk_hand, trick_suit);
+    assert!(valid_set.len() < RING_MAX_SET_SIZE as usize);
+
+    let blinding = [5u8; 32];
+    let (commit_hash, proof) =
+        card_ring_proof_padded(&env, zk_card, &blinding, &valid_set, sid, &zk_player).unwrap();
+    assert_eq!(proof.len(), RING_PROOF_BASE_LEN + RING_MAX_SET_SIZE * RING_MEMBER_PROOF_LEN);
+    client.commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &proof);
+
+    let game2 = client.get_game_debug(&sid);
+    assert!(if is_slot1 { game2.seat(1).zk_play } else { game2.seat(2).zk_play });
+
+    let other_hand = if is_slot1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
+    let other_action = first_card_of_suit(other_hand, trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let salt2 = test_salt(&env, 0x23);
+    client.commit_play(&sid, &other_player, &compute_play_commit(&env, other_action, &salt2), &game2.action_nonce);
+    client.reveal_play(&sid, &zk_player, &zk_card, &BytesN::from_array(&env, &blinding));
+    client.reveal_play(&sid, &other_player, &other_action, &salt2);
+
+    let game3 = client.get_game_debug(&sid);
+    assert!(game3.trick_state == TRICK_COMMIT_WAIT_BOTH || game3.lifecycle_state == STATE_FINISHED);
+}
+
+/// The real verifier rejects a ring proof bound to another session or
+/// player, or made over a different valid set.
+#[test]
+fn test_integration_zk_play_real_proof_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+    let sid = 1401u32;
+    advance_to_playing_real(&env, &client, sid, &player1, &player2);
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let (zk_player, other_player, zk_card, is_slot1) =
+        find_zk_candidate(&game, trick_suit, &player1, &player2).unwrap();
+    let zk_hand = if is_slot1 { &game.seat(1).hand } else { &game.seat(2).hand };
+    let valid_set = cards_of_suit(zk_hand, trick_suit);
+    let blinding = [7u8; 32];
+
+    let (commit_hash, proof) =
+        card_ring_proof(&env, zk_card, &blinding, &valid_set, sid + 1, &zk_player).unwrap();
+    let result = client.try_commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &proof);
+    assert_cangkulan_error(&result, CangkulanError::ZkPlayProofInvalid);
+
+    let (commit_hash, proof) =
+        card_ring_proof(&env, zk_card, &blinding, &valid_set, sid, &other_player).unwrap();
+    let result = client.try_commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &proof);
+    assert_cangkulan_error(&result, CangkulanError::ZkPlayProofInvalid);
+
+    // Hiding a valid card from the set: the contract rebuilds the set from
+    // the hand, so the proof no longer matches its statement.
+    let mut padded = valid_set.clone();
+    padded.push(CANNOT_FOLLOW_SENTINEL);
+    let (commit_hash, proof) =
+        card_ring_proof(&env, zk_card, &blinding, &padded, sid, &zk_player).unwrap();
+    let result = client.try_commit_play_zk(&sid, &zk_player, &commit_hash, &game.action_nonce, &proof);
+    assert_cangkulan_error(&result, CangkulanError::ZkPlayProofInvalid);
+
+    assert_eq!(client.get_game_debug(&sid).seat(1).play_commit, None);
+    assert_eq!(client.get_game_debug(&sid).seat(2).play_commit, None);
+}
+
+/// A real hand proof commits a cangkul, and the blinding sum opens it.
+#[test]
+fn test_integration_zk_cangkul_real_hand_proof() {
+    let (env, client, _hub, player1, player2) = setup_test_real_verifier();
+
+    // Find a deal where exactly one player can follow suit
+    let mut sid = 1410u32;
+    loop {
+        assert!(sid < 1500, "Could not find one-follows setup");
+        advance_to_playing_real(&env, &client, sid, &player1, &player2);
+        let game = client.get_game_debug(&sid);
+        let suit = game.trick_suit.unwrap();
+        if hand_has_suit(&game.seat(1).hand, suit) != hand_has_suit(&game.seat(2).hand, suit) {
+            break;
+        }
+        sid += 1;
+    }
+
+    let game = client.get_game_debug(&sid);
+    let trick_suit = game.trick_suit.unwrap();
+    let is_cangkul_p1 = !hand_has_suit(&game.seat(1).hand, trick_suit);
+    let (cangkul_player, follow_player) = if is_cangkul_p1 {
+        (player1.clone(), player2.clone())
+    } else {
+        (player2.clone(), player1.clone())
+    };
+    let hand: std::vec::Vec<u32> = game.seat(if is_cangkul_p1 { 1 } else { 2 }).hand.iter().collect();
+    let blindings: std::vec::Vec<[u8; 32]> = (0..hand.len()).map(|i| [i as u8 + 1; 32]).collect();
+
+    // A hand proof for the wrong suit does not match the contract's statement
+    let (commit_hash, proof) =
+        cangkul_hand_proof(&env, &hand, &blindings, (trick_suit + 1) % 4, sid, &cangkul_player).unwrap();
+    let result = client.try_commit_cangkul_zk(&sid, &cangkul_player, &commit_hash, &game.action_nonce, &proof);
+    assert_cangkulan_error(&result, CangkulanError::ZkCangkulProofInvalid);
+
+    let (commit_hash, proof) =
+        cangkul_hand_proof(&env, &hand, &blindings, trick_suit, sid, &cangkul_player).unwrap();
+    client.commit_cangkul_zk(&sid, &cangkul_player, &commit_hash, &game.action_nonce, &proof);
+
+    let game2 = client.get_game_debug(&sid);
+    let follow_hand = if is_cangkul_p1 { &game2.seat(2).hand } else { &game2.seat(1).hand };
+    let follow_card = first_card_of_suit(follow_hand, trick_suit).unwrap();
+    let salt2 = test_salt(&env, 0x33);
+    client.commit_play(&sid, &follow_player, &compute_play_commit(&env, follow_card, &salt2), &game2.action_nonce);
+
+    let r_agg = BytesN::from_array(&env, &blinding_sum(&blindings));
+    client.reveal_play(&sid, &cangkul_player, &CANNOT_FOLLOW_SENTINEL, &r_agg);
+    client.reveal_play(&sid, &follow_player, &follow_card, &salt2);
+
+    let game3 = client.get_game_debug(&sid);
+    assert!(
+        game3.trick_state == TRICK_COMMIT_WAIT_BOTH || game3.lifecycle_state == STATE_FINISHED,
+        "Trick should resolve"
+    );
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Two-phase finalize (claim_winnings)
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_claim_winnings_pays_winner_once() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, _treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let sid = 950u32;
+    client.start_game(&sid, &player1, &player2, &100, &100);
+
+    // Nothing to claim while the game is running
+    let early = client.try_claim_winnings(&sid, &player2);
+    assert_cangkulan_error(&early, CangkulanError::NothingToClaim);
+
+    client.forfeit(&sid, &player1);
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_FINISHED);
+
+    let claim = client.get_pending_claim(&sid).expect("claim recorded at finalize");
+    assert_eq!(claim.payouts.len(), 1);
+    assert_eq!(claim.payouts.get(0).unwrap().player, player2);
+    assert_eq!(claim.payouts.get(0).unwrap().amount, 200);
+
+    // The loser has no share
+    let loser = client.try_claim_winnings(&sid, &player1);
+    assert_cangkulan_error(&loser, CangkulanError::NothingToClaim);
+
+    assert_eq!(client.claim_winnings(&sid, &player2), 200);
+    assert_eq!(token.balance(&player2), 1100);
+    assert_eq!(client.get_pending_claim(&sid), None);
+
+    let again = client.try_claim_winnings(&sid, &player2);
+    assert_cangkulan_error(&again, CangkulanError::NothingToClaim);
+}
+
+#[test]
+fn test_claim_winnings_zero_stakes_records_nothing() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let sid = 951u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    client.forfeit(&sid, &player2);
+
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_FINISHED);
+    assert_eq!(client.get_pending_claim(&sid), None);
+}
+
+#[test]
+fn test_points_only_game_records_no_claim() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 958u32;
+    client.start_game(&sid, &player1, &player2, &100, &100);
+    client.forfeit(&sid, &player2);
+
+    // Points are settled by the hub; no claim is recorded or announced
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_FINISHED);
+    assert_eq!(client.get_pending_claim(&sid), None);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&crate::StorageKey::PendingClaim(sid)));
+    });
+    let claim = client.try_claim_winnings(&sid, &player1);
+    assert_cangkulan_error(&claim, CangkulanError::NothingToClaim);
+}
+
+#[test]
+fn test_unclaimed_winnings_expire() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let sid = 952u32;
+    client.start_game(&sid, &player1, &player2, &50, &70);
+    client.forfeit(&sid, &player2);
+
+    let claim = client.get_pending_claim(&sid).unwrap();
+
+    // Cannot expire while the window is open
+    let too_early = client.try_expire_winnings(&sid);
+    assert_cangkulan_error(&too_early, CangkulanError::ClaimNotExpired);
+
+    let now = env.ledger().sequence();
+    advance_ledger(&env, claim.expires_ledger - now + 1);
+
+    let late = client.try_claim_winnings(&sid, &player1);
+    assert_cangkulan_error(&late, CangkulanError::ClaimExpired);
+
+    assert_eq!(client.expire_winnings(&sid), 120);
+    assert_eq!(client.get_pending_claim(&sid), None);
+    assert_eq!(client.get_treasury_balance(&treasury, &token.address), 120);
+}
+
+/// Helper: escrow stakes in a fresh token, funding each player with 1000.
+fn setup_escrow(
+    env: &Env,
+    client: &CangkulanContractClient,
+    players: &[&Address],
+    rake_bps: u32,
+) -> (TokenClient<'static>, Address) {
+    let issuer = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(issuer).address();
+    for player in players {
+        StellarAssetClient::new(env, &token).mint(player, &1000);
+    }
+    let treasury = Address::generate(env);
+    client.set_stake_escrow(&Some(StakeEscrow { token: token.clone(), rake_bps, treasury: treasury.clone() }));
+    (TokenClient::new(env, &token), treasury)
+}
+
+#[test]
+fn test_escrowed_stakes_pay_winner_less_rake() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2], 500);
+    let sid = 953u32;
+    client.start_game(&sid, &player1, &player2, &100, &100);
+
+    // Stakes are locked in the contract
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&client.address), 200);
+
+    client.forfeit(&sid, &player1);
+    let claim = client.get_pending_claim(&sid).unwrap();
+    assert_eq!(claim.payouts, vec![&env, Payout { player: player2.clone(), amount: 190, claimed: false }]);
+    assert_eq!(claim.rake, 10);
+
+    assert_eq!(client.claim_winnings(&sid, &player2), 190);
+    assert_eq!(token.balance(&player2), 1090);
+    assert_eq!(client.get_treasury_balance(&treasury, &token.address), 10);
+
+    // The treasury pulls its rake
+    assert_eq!(client.withdraw_treasury(&treasury, &token.address), 10);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let withdrawn = EvTreasuryWithdrawn { treasury: treasury.clone(), token: token.address.clone(), amount: 10 };
+    assert!(events.events().contains(&withdrawn.to_xdr(&env, &client.address)));
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(token.balance(&client.address), 0);
+    let empty = client.try_withdraw_treasury(&treasury, &token.address);
+    assert_cangkulan_error(&empty, CangkulanError::NothingToClaim);
+}
+
+#[test]
+fn test_escrowed_draw_refunds_without_rake() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2, &player3], 1_000);
+    let sid = 954u32;
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_multiplayer_game(&sid, &players, &vec![&env, 100, 200, 300]);
+
+    // Seats 2 and 3 are level once seat 1 forfeits
+    client.forfeit(&sid, &player1);
+    assert_eq!(client.get_game(&sid).outcome, OUTCOME_DRAW);
+    assert_eq!(client.get_pending_claim(&sid).unwrap().rake, 0);
+
+    for (player, stake) in [(&player1, 100), (&player2, 200), (&player3, 300)] {
+        assert_eq!(client.claim_winnings(&sid, player), stake);
+        assert_eq!(token.balance(player), 1000);
+    }
+    assert_eq!(client.get_pending_claim(&sid), None);
+    assert_eq!(client.get_treasury_balance(&treasury, &token.address), 0);
+}
+
+#[test]
+fn test_claims_are_per_player_and_expiry_takes_the_rest() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2, &player3], 0);
+    let sid = 957u32;
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_multiplayer_game(&sid, &players, &vec![&env, 100, 200, 300]);
+    client.forfeit(&sid, &player1);
+    assert_eq!(client.get_game(&sid).outcome, OUTCOME_DRAW);
+
+    // One seat claims without waiting on the others, and only once
+    assert_eq!(client.claim_winnings(&sid, &player2), 200);
+    assert_eq!(token.balance(&player2), 1000);
+    let again = client.try_claim_winnings(&sid, &player2);
+    assert_cangkulan_error(&again, CangkulanError::NothingToClaim);
+    let claim = client.get_pending_claim(&sid).unwrap();
+    assert_eq!(
+        claim.payouts,
+        vec![
+            &env,
+            Payout { player: player1.clone(), amount: 100, claimed: false },
+            Payout { player: player2.clone(), amount: 200, claimed: true },
+            Payout { player: player3.clone(), amount: 300, claimed: false },
+        ],
+    );
+
+    // Expiry sweeps only the shares nobody claimed
+    advance_ledger(&env, claim.expires_ledger - env.ledger().sequence() + 1);
+    assert_eq!(client.expire_winnings(&sid), 400);
+    assert_eq!(client.withdraw_treasury(&treasury, &token.address), 400);
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player3), 700);
+}
+
+#[test]
+fn test_expired_escrow_sweeps_to_treasury() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2], 250);
+    let sid = 955u32;
+    client.start_game(&sid, &player1, &player2, &200, &200);
+    client.forfeit(&sid, &player2);
+
+    // Changing the escrow does not affect a game already started
+    client.set_stake_escrow(&None);
+    assert_eq!(client.get_stake_escrow(), None);
+
+    let claim = client.get_pending_claim(&sid).unwrap();
+    advance_ledger(&env, claim.expires_ledger - env.ledger().sequence() + 1);
+    assert_eq!(client.expire_winnings(&sid), 390);
+    assert_eq!(client.withdraw_treasury(&treasury, &token.address), 400);
+    assert_eq!(token.balance(&treasury), 400);
+    assert_eq!(token.balance(&player1), 800);
+}
+
+#[test]
+fn test_stake_escrow_rejects_bad_terms() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let greedy = StakeEscrow { token: token.clone(), rake_bps: MAX_RAKE_BPS + 1, treasury: treasury.clone() };
+    let result = client.try_set_stake_escrow(&Some(greedy));
+    assert_cangkulan_error(&result, CangkulanError::InvalidStakeEscrow);
+    assert_eq!(client.get_stake_escrow(), None);
+
+    client.set_stake_escrow(&Some(StakeEscrow { token, rake_bps: MAX_RAKE_BPS, treasury }));
+    let negative = client.try_start_game(&956, &player1, &player2, &-5, &10);
+    assert_cangkulan_error(&negative, CangkulanError::InvalidStakeEscrow);
+}
+
+#[test]
+fn test_fee_applies_to_games_started_after_it() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(client.get_fee(), 0);
+    assert_cangkulan_error(&client.try_set_fee(&100), CangkulanError::InvalidStakeEscrow);
+
+    let (_token, treasury) = setup_escrow(&env, &client, &[&player1, &player2], 500);
+    client.start_game(&957, &player1, &player2, &100, &100);
+    client.set_fee(&300);
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert!(events.events().contains(&EvFeeSet { fee_bps: 300 }.to_xdr(&env, &client.address)));
+    assert_eq!(client.get_fee(), 300);
+    assert_eq!(client.get_stake_escrow().unwrap().treasury, treasury);
+    assert_cangkulan_error(&client.try_set_fee(&(MAX_RAKE_BPS + 1)), CangkulanError::InvalidStakeEscrow);
+
+    client.start_game(&958, &player1, &player2, &100, &100);
+    client.forfeit(&957, &player2);
+    client.forfeit(&958, &player2);
+    assert_eq!(client.get_pending_claim(&957).unwrap().rake, 10);
+    assert_eq!(client.get_pending_claim(&958).unwrap().rake, 6);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Match play
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn match_scores_each_deal_and_deals_again() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let players = vec![&env, player1.clone(), player2.clone()];
+    let first = client.start_match(&70, &players, &MAX_MATCH_TARGET);
+    assert_eq!(first, CONTRACT_SESSION_BIT);
+    assert_eq!(client.get_game(&first).seat(1).points, 0);
+
+    let early = client.try_next_deal(&70);
+    assert_cangkulan_error(&early, CangkulanError::GameNotFinished);
+
+    advance_to_playing(&env, &client, first, &player1, &player2);
+    while client.get_game_debug(&first).lifecycle_state != STATE_FINISHED {
+        play_one_trick(&env, &client, &player1, &player2, first);
+    }
+    let dealt = client.get_game_debug(&first);
+    let points = |slot: u32| dealt.seat(slot).tricks_won.saturating_sub(dealt.seat(slot).hand.len());
+
+    let state = client.next_deal(&70);
+    assert_eq!(
+        state,
+        MatchState {
+            players,
+            target: MAX_MATCH_TARGET,
+            scores: vec![&env, points(1), points(2)],
+            round: 2,
+            session_id: CONTRACT_SESSION_BIT | 1,
+            winner: 0,
+        },
+    );
+    assert_eq!(client.get_game(&state.session_id).lifecycle_state, STATE_SEED_COMMIT);
+    assert_eq!(hub.get_start_count(), 2);
+}
+
+#[test]
+fn forfeited_deal_concedes_the_match() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = vec![&env, player1.clone(), player2.clone()];
+    let invalid = client.try_start_match(&71, &players, &0);
+    assert_cangkulan_error(&invalid, CangkulanError::InvalidMatchTarget);
+    assert_cangkulan_error(&client.try_next_deal(&71), CangkulanError::MatchNotFound);
+
+    let deal = client.start_match(&71, &players, &5);
+    let again = client.try_start_match(&71, &players, &5);
+    assert_cangkulan_error(&again, CangkulanError::SessionAlreadyExists);
+
+    client.forfeit(&deal, &player1);
+    let state = client.next_deal(&71);
+    assert_eq!((state.winner, state.round), (2, 1));
+    assert_cangkulan_error(&client.try_next_deal(&71), CangkulanError::MatchOver);
+}
+
+#[test]
+fn cancelled_deal_scores_nothing() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = vec![&env, player1.clone(), player2.clone()];
+    let deal = client.start_match(&72, &players, &1);
+    let squat = client.try_start_game(&(CONTRACT_SESSION_BIT | 1), &player1, &player2, &0, &0);
+    assert_cangkulan_error(&squat, CangkulanError::ReservedSessionId);
+
+    // Cancelled mid-play with enough tricks to have reached the target
+    advance_to_playing(&env, &client, deal, &player1, &player2);
+    let mut game = client.get_game_debug(&deal);
+    let mut seat = game.seat(1);
+    seat.tricks_won = 9;
+    game.set_seat(1, seat);
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, deal, &mut game));
+    client.cancel_game(&deal, &symbol_short!("bad_vrf"));
+
+    let state = client.next_deal(&72);
+    assert_eq!(state.scores, vec![&env, 0, 0]);
+    assert_eq!((state.winner, state.round, state.session_id), (0, 2, CONTRACT_SESSION_BIT | 1));
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Bot opponents
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Plays the first card of the trick suit, else cangkuls.
+#[contract]
+pub struct PracticeBot;
+
+impl PracticeBot {
+    fn reply(env: &Env, turn: &BotTurn) -> BotReveal {
+        let mut preimage = Bytes::from_array(env, &turn.session_id.to_be_bytes());
+        preimage.extend_from_array(&turn.trick.to_be_bytes());
+        BotReveal {
+            card_id: first_card_of_suit(&turn.hand, turn.trick_suit).unwrap_or(CANNOT_FOLLOW_SENTINEL),
+            salt: env.crypto().keccak256(&preimage).into(),
+        }
+    }
+}
+
+#[contractimpl]
+impl CangkulanBot for PracticeBot {
+    fn choose_commit(env: Env, turn: BotTurn) -> BytesN<32> {
+        let reveal = Self::reply(&env, &turn);
+        compute_play_commit(&env, reveal.card_id, &reveal.salt)
+    }
+
+    fn choose_reveal(env: Env, turn: BotTurn) -> BotReveal {
+        Self::reply(&env, &turn)
+    }
+}
+
+/// Reveals something other than what it committed to.
+#[contract]
+pub struct BluffingBot;
+
+#[contractimpl]
+impl CangkulanBot for BluffingBot {
+    fn choose_commit(env: Env, _turn: BotTurn) -> BytesN<32> {
+        compute_play_commit(&env, CANNOT_FOLLOW_SENTINEL, &test_salt(&env, 0x42))
+    }
+
+    fn choose_reveal(env: Env, _turn: BotTurn) -> BotReveal {
+        BotReveal { card_id: CANNOT_FOLLOW_SENTINEL, salt: test_salt(&env, 0x43) }
+    }
+}
+
+/// Play seat 1 of a bot game: commit the seed and reveal it.
+fn advance_to_playing_vs_bot(env: &Env, client: &CangkulanContractClient, sid: u32, player: &Address) {
+    let seed_hash = compute_seed_hash(env, &BytesN::from_array(env, &[7u8; 32]));
+    let blinding = BytesN::<32>::from_array(env, &[0xAAu8; 32]);
+    let commit = compute_nizk_commitment(env, &seed_hash, &blinding, player);
+    client.commit_seed(&sid, player, &commit, &SEED_PROOF_NIZK);
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_SEED_REVEAL);
+    client.reveal_seed(&sid, player, &seed_hash, &build_nizk_proof(env, &blinding));
+}
+
+#[test]
+fn bot_plays_its_seat_within_the_players_calls() {
+    let (env, client, hub, player, _) = setup_test();
+    let bot = env.register(PracticeBot, ());
+    let sid = 1701u32;
+    assert_cangkulan_error(&client.try_start_game_vs_bot(&sid, &player, &player), CangkulanError::SelfPlayNotAllowed);
+    client.start_game_vs_bot(&sid, &player, &bot);
+    advance_to_playing_vs_bot(&env, &client, sid, &player);
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_PLAYING);
+
+    while client.get_game_debug(&sid).lifecycle_state != STATE_FINISHED {
+        let before = client.get_game_debug(&sid);
+        let action = first_card_of_suit(&before.seat(1).hand, before.trick_suit.unwrap()).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+        let salt = test_salt(&env, 0x11);
+        client.commit_play(&sid, &player, &compute_play_commit(&env, action, &salt), &before.action_nonce);
+        assert!(client.get_game_debug(&sid).seat(2).play_commit.is_some());
+        client.reveal_play(&sid, &player, &action, &salt);
+    }
+
+    let game = client.get_game(&sid);
+    assert!(game.vs_bot);
+    assert_ne!(game.outcome, OUTCOME_UNRESOLVED);
+    assert_eq!(game.forfeited_by, 0);
+    assert_eq!((hub.get_start_count(), hub.get_end_count()), (0, 0));
+}
+
+#[test]
+fn bot_forfeits_a_move_it_cannot_make() {
+    let (env, client, _hub, player, _) = setup_test();
+    let bot = env.register(BluffingBot, ());
+    let sid = 1702u32;
+    client.start_game_vs_bot(&sid, &player, &bot);
+    advance_to_playing_vs_bot(&env, &client, sid, &player);
+
+    let before = client.get_game_debug(&sid);
+    let action = first_card_of_suit(&before.seat(1).hand, before.trick_suit.unwrap()).unwrap_or(CANNOT_FOLLOW_SENTINEL);
+    let salt = test_salt(&env, 0x11);
+    client.commit_play(&sid, &player, &compute_play_commit(&env, action, &salt), &before.action_nonce);
+    client.reveal_play(&sid, &player, &action, &salt);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let quit = EvGameForfeited { session_id: sid, player: bot, penalized: false };
+    assert!(events.events().contains(&quit.to_xdr(&env, &client.address)));
+
+    let game = client.get_game(&sid);
+    assert_eq!((game.lifecycle_state, game.outcome, game.forfeited_by), (STATE_FINISHED, OUTCOME_PLAYER1_WIN, 2));
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Rematch
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rematch_starts_once_both_agree() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let sid = 960u32;
+    client.start_game(&sid, &player1, &player2, &30, &40);
+
+    let early = client.try_request_rematch(&sid, &player1);
+    assert_cangkulan_error(&early, CangkulanError::GameNotFinished);
+    client.forfeit(&sid, &player2);
+
+    let unrequested = client.try_accept_rematch(&sid, &player2);
+    assert_cangkulan_error(&unrequested, CangkulanError::RematchNotRequested);
+
+    let rematch_id = client.request_rematch(&sid, &player1);
+    assert_eq!(client.rematch_session_id(&sid), Some(rematch_id));
+    assert_eq!(rematch_id, CONTRACT_SESSION_BIT);
+    assert_eq!(client.get_rematch(&sid).unwrap().agreed, vec![&env, player1.clone()]);
+
+    let twice = client.try_accept_rematch(&sid, &player1);
+    assert_cangkulan_error(&twice, CangkulanError::RematchAlreadyAgreed);
+    let outsider = client.try_accept_rematch(&sid, &Address::generate(&env));
+    assert_cangkulan_error(&outsider, CangkulanError::NotAPlayer);
+
+    // The second agreement starts the new session with the same seating
+    assert_eq!(client.accept_rematch(&sid, &player2), rematch_id);
+    assert_eq!(client.get_rematch(&sid), None);
+    let game = client.get_game(&rematch_id);
+    assert_eq!(game.lifecycle_state, STATE_SEED_COMMIT);
+    assert_eq!(game.seat(1).player, player1);
+    assert_eq!(game.seat(2).points, 40);
+    assert_eq!(hub.get_start_count(), 2);
+
+    // A game is rematched only once
+    assert_eq!(client.rematch_session_id(&sid), Some(rematch_id));
+    let again = client.try_request_rematch(&sid, &player2);
+    assert_cangkulan_error(&again, CangkulanError::SessionAlreadyExists);
+}
+
+#[test]
+fn test_rematch_session_cannot_be_taken_first() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (squatter1, squatter2) = (Address::generate(&env), Address::generate(&env));
+    let sid = 962u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    client.forfeit(&sid, &player2);
+
+    let rematch_id = client.request_rematch(&sid, &player1);
+    let squat = client.try_start_game(&rematch_id, &squatter1, &squatter2, &0, &0);
+    assert_cangkulan_error(&squat, CangkulanError::ReservedSessionId);
+    assert_eq!(client.accept_rematch(&sid, &player2), rematch_id);
+    assert_eq!(client.get_game(&rematch_id).seat(1).player, player1);
+}
+
+#[test]
+fn test_escrowed_rematch_locks_stakes_per_agreement() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, _treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let sid = 961u32;
+    client.start_game(&sid, &player1, &player2, &100, &100);
+    client.forfeit(&sid, &player1);
+    client.claim_winnings(&sid, &player2);
+
+    // The rematch keeps the old terms even after escrow is switched off
+    client.set_stake_escrow(&None);
+    client.request_rematch(&sid, &player1);
+    assert_eq!(token.balance(&player1), 800);
+
+    // Cancelling refunds the stake locked by the request
+    client.cancel_rematch(&sid, &player2);
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(client.get_rematch(&sid), None);
+
+    let rematch_id = client.request_rematch(&sid, &player2);
+    client.accept_rematch(&sid, &player1);
+    assert_eq!(token.balance(&player2), 1000);
+    assert_eq!(token.balance(&client.address), 200);
+    assert!(client.get_game(&rematch_id).stake_token.is_some());
+}
+
+#[test]
+fn test_rematch_stakes_refunded_after_game_expires() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, _treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let sid = 962u32;
+    client.start_game(&sid, &player1, &player2, &100, &100);
+    client.forfeit(&sid, &player1);
+    client.claim_winnings(&sid, &player2);
+    client.request_rematch(&sid, &player2);
+    assert_eq!(token.balance(&player2), 1000);
+
+    // The finished game's temporary entry expires before the offer
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&crate::StorageKey::Game(sid));
+    });
+    assert!(client.try_get_game(&sid).is_err());
+
+    let outsider = client.try_cancel_rematch(&sid, &Address::generate(&env));
+    assert_eq!(outsider, Err(Ok(CangkulanError::NotAPlayer)));
+    client.cancel_rematch(&sid, &player1);
+    assert_eq!(token.balance(&player2), 1100);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_rematch(&sid), None);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Lobby
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_accepted_challenge_starts_game() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let expires = env.ledger().sequence() + 100;
+    let id = client.create_challenge(&player1, &25, &expires);
+    assert_eq!(client.get_open_challenges(), vec![&env, id]);
+    assert_eq!(client.get_challenge(&id).unwrap().creator, player1);
+
+    let own = client.try_accept_challenge(&id, &player1);
+    assert_cangkulan_error(&own, CangkulanError::SelfPlayNotAllowed);
+
+    let sid = client.accept_challenge(&id, &player2);
+    assert_eq!(sid, CONTRACT_SESSION_BIT);
+    let game = client.get_game(&sid);
+    assert_eq!(game.seat(1).player, player1);
+    assert_eq!(game.seat(2).player, player2);
+    assert_eq!(game.seat(2).points, 25);
+    assert_eq!(game.lifecycle_state, STATE_SEED_COMMIT);
+    assert_eq!(hub.get_start_count(), 1);
+
+    assert_eq!(client.get_open_challenges(), Vec::new(&env));
+    let taken = client.try_accept_challenge(&id, &Address::generate(&env));
+    assert_cangkulan_error(&taken, CangkulanError::ChallengeNotFound);
+}
+
+#[test]
+fn test_challenge_session_cannot_be_taken_first() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (squatter1, squatter2) = (Address::generate(&env), Address::generate(&env));
+    let expires = env.ledger().sequence() + 100;
+    let id = client.create_challenge(&player1, &25, &expires);
+
+    // Lobby games are opened only at ids start_game refuses
+    for sid in [CONTRACT_SESSION_BIT, CONTRACT_SESSION_BIT | 1, u32::MAX] {
+        let squat = client.try_start_game(&sid, &squatter1, &squatter2, &0, &0);
+        assert_cangkulan_error(&squat, CangkulanError::ReservedSessionId);
+    }
+    let bot = client.try_start_game_vs_bot(&CONTRACT_SESSION_BIT, &squatter1, &squatter2);
+    assert_cangkulan_error(&bot, CangkulanError::ReservedSessionId);
+
+    let sid = client.accept_challenge(&id, &player2);
+    assert_eq!(sid, CONTRACT_SESSION_BIT);
+    let next = client.create_challenge(&player1, &25, &expires);
+    assert_eq!(client.accept_challenge(&next, &player2), CONTRACT_SESSION_BIT | 1);
+}
+
+#[test]
+fn test_expired_challenge_refunds_creator() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, _treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let now = env.ledger().sequence();
+
+    let stale = client.try_create_challenge(&player1, &50, &now);
+    assert_cangkulan_error(&stale, CangkulanError::InvalidChallengeExpiry);
+    let distant = client.try_create_challenge(&player1, &50, &(now + MAX_CHALLENGE_LEDGERS + 1));
+    assert_cangkulan_error(&distant, CangkulanError::InvalidChallengeExpiry);
+
+    let id = client.create_challenge(&player1, &50, &(now + 10));
+    assert_eq!(token.balance(&player1), 950);
+
+    // Only the creator may cancel while the challenge is open
+    let stranger = Address::generate(&env);
+    let early = client.try_cancel_challenge(&id, &stranger);
+    assert_cangkulan_error(&early, CangkulanError::NotAPlayer);
+
+    advance_ledger(&env, 11);
+    let late = client.try_accept_challenge(&id, &player2);
+    assert_cangkulan_error(&late, CangkulanError::ChallengeExpired);
+
+    client.cancel_challenge(&id, &stranger);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(client.get_challenge(&id), None);
+    assert_eq!(client.get_open_challenges(), Vec::new(&env));
+}
+
+#[test]
+fn test_escrowed_challenge_locks_both_stakes() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, treasury) = setup_escrow(&env, &client, &[&player1, &player2], 500);
+    let id = client.create_challenge(&player1, &100, &(env.ledger().sequence() + 10));
+    let sid = client.accept_challenge(&id, &player2);
+    assert_eq!(token.balance(&client.address), 200);
+
+    client.forfeit(&sid, &player2);
+    client.claim_winnings(&sid, &player1);
+    assert_eq!(token.balance(&player1), 1090);
+    assert_eq!(client.get_treasury_balance(&treasury, &token.address), 10);
+}
+
+#[test]
+fn test_lobby_caps_open_challenges() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let expires = env.ledger().sequence() + 10;
+    for _ in 0..MAX_OPEN_CHALLENGES {
+        client.create_challenge(&Address::generate(&env), &1, &expires);
+    }
+    let full = client.try_create_challenge(&Address::generate(&env), &1, &expires);
+    assert_cangkulan_error(&full, CangkulanError::LobbyFull);
+
+    // Once they expire the lobby opens up again
+    advance_ledger(&env, 11);
+    assert_eq!(client.get_open_challenges(), Vec::new(&env));
+    let id = client.create_challenge(&Address::generate(&env), &1, &(env.ledger().sequence() + 10));
+    assert_eq!(client.get_open_challenges(), vec![&env, id]);
+}
+
+#[test]
+fn test_lobby_caps_challenges_per_creator() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let expires = env.ledger().sequence() + 10;
+    for _ in 0..MAX_CHALLENGES_PER_CREATOR {
+        client.create_challenge(&player1, &1, &expires);
+    }
+    let more = client.try_create_challenge(&player1, &1, &expires);
+    assert_cangkulan_error(&more, CangkulanError::TooManyChallenges);
+    client.create_challenge(&player2, &1, &expires);
+
+    // Taking one of them frees a slot
+    let first = client.get_open_challenges().get(0).unwrap();
+    client.accept_challenge(&first, &player2);
+    client.create_challenge(&player1, &1, &expires);
+}
+
+#[test]
+fn test_expired_challenges_are_delisted_but_refundable() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (token, _treasury) = setup_escrow(&env, &client, &[&player1, &player2], 0);
+    let now = env.ledger().sequence();
+    let stale = client.create_challenge(&player1, &50, &(now + 5));
+    let fresh = client.create_challenge(&player2, &50, &(now + 100));
+
+    advance_ledger(&env, 6);
+    assert_eq!(client.get_open_challenges(), vec![&env, fresh]);
+    // Posting prunes the expired listing; the creator's slot comes back
+    for _ in 0..MAX_CHALLENGES_PER_CREATOR {
+        client.create_challenge(&player1, &0, &(env.ledger().sequence() + 10));
+    }
+    assert_eq!(client.get_open_challenges().first_index_of(stale), None);
+
+    client.cancel_challenge(&stale, &player2);
+    assert_eq!(token.balance(&player1), 1000);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: State channel
+// ════════════════════════════════════════════════════════════════════════════
+
+fn channel_state(env: &Env, session_id: u32, move_counter: u32, tricks: [u32; 2]) -> ChannelState {
+    ChannelState {
+        session_id,
+        move_counter,
+        hands_hash: BytesN::from_array(env, &[move_counter as u8; 32]),
+        tricks: vec![env, tricks[0], tricks[1]],
+        outcome: OUTCOME_UNRESOLVED,
+    }
+}
+
+fn sign_channel_state(
+    env: &Env,
+    client: &CangkulanContractClient,
+    state: &ChannelState,
+    keys: &[&SigningKey],
+) -> Vec<BytesN<64>> {
+    let digest = client.channel_digest(state).to_array();
+    let mut signatures = Vec::new(env);
+    for key in keys {
+        signatures.push_back(BytesN::from_array(env, &key.sign(&digest).to_bytes()));
+    }
+    signatures
+}
+
+#[test]
+fn test_channel_settles_latest_cosigned_state() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let sid = 1700u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    let key1 = SigningKey::from_bytes(&[1u8; 32]);
+    let key2 = SigningKey::from_bytes(&[2u8; 32]);
+    client.open_channel(&sid, &player1, &BytesN::from_array(&env, &key1.verifying_key().to_bytes()));
+    client.open_channel(&sid, &player2, &BytesN::from_array(&env, &key2.verifying_key().to_bytes()));
+    assert_eq!(client.get_channel_keys(&sid).len(), 2);
+
+    let early = channel_state(&env, sid, 3, [1, 2]);
+    let ends = client.settle_channel(&sid, &early, &sign_channel_state(&env, &client, &early, &[&key1, &key2]));
+    assert_eq!(ends, env.ledger().sequence() + CHANNEL_CHALLENGE_LEDGERS);
+    assert_cangkulan_error(&client.try_close_channel(&sid), CangkulanError::ChallengeWindowOpen);
+
+    // Player 1 answers with a later state that turns the tally around
+    advance_ledger(&env, 10);
+    let later = channel_state(&env, sid, 5, [4, 2]);
+    client.settle_channel(&sid, &later, &sign_channel_state(&env, &client, &later, &[&key1, &key2]));
+    let stale = channel_state(&env, sid, 4, [1, 3]);
+    let replay = client.try_settle_channel(&sid, &stale, &sign_channel_state(&env, &client, &stale, &[&key1, &key2]));
+    assert_cangkulan_error(&replay, CangkulanError::StaleChannelState);
+    assert_eq!(client.get_channel_claim(&sid).unwrap().state, later);
+
+    advance_ledger(&env, CHANNEL_CHALLENGE_LEDGERS + 1);
+    assert_eq!(client.close_channel(&sid), OUTCOME_PLAYER1_WIN);
+    let game = client.get_game(&sid);
+    assert_eq!((game.lifecycle_state, game.outcome), (STATE_FINISHED, OUTCOME_PLAYER1_WIN));
+    assert_eq!(hub.get_end_count(), 1);
+    assert_eq!(client.get_channel_claim(&sid), None);
+    assert_cangkulan_error(&client.try_close_channel(&sid), CangkulanError::NoChannelClaim);
+}
+
+#[test]
+fn test_channel_rejects_unsigned_states() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1701u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    let key1 = SigningKey::from_bytes(&[1u8; 32]);
+    let key2 = SigningKey::from_bytes(&[2u8; 32]);
+    let state = channel_state(&env, sid, 1, [0, 0]);
+    let signatures = sign_channel_state(&env, &client, &state, &[&key1, &key2]);
+
+    client.open_channel(&sid, &player1, &BytesN::from_array(&env, &key1.verifying_key().to_bytes()));
+    let half_open = client.try_settle_channel(&sid, &state, &signatures);
+    assert_cangkulan_error(&half_open, CangkulanError::ChannelNotOpen);
+    client.open_channel(&sid, &player2, &BytesN::from_array(&env, &key2.verifying_key().to_bytes()));
+
+    let elsewhere = channel_state(&env, sid + 1, 1, [0, 0]);
+    let moved = client.try_settle_channel(&sid, &elsewhere, &signatures);
+    assert_cangkulan_error(&moved, CangkulanError::InvalidChannelState);
+    let one_signer = sign_channel_state(&env, &client, &state, &[&key1, &key1]);
+    assert!(client.try_settle_channel(&sid, &state, &one_signer).is_err());
+    assert_cangkulan_error(&client.try_close_channel(&sid), CangkulanError::NoChannelClaim);
+
+    // A tied tally settles as a draw
+    client.settle_channel(&sid, &state, &signatures);
+    advance_ledger(&env, CHANNEL_CHALLENGE_LEDGERS + 1);
+    assert_eq!(client.close_channel(&sid), OUTCOME_DRAW);
+}
+
+#[test]
+fn test_channel_keys_lock_before_a_challenge() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1702u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    let key1 = SigningKey::from_bytes(&[1u8; 32]);
+    let key2 = SigningKey::from_bytes(&[2u8; 32]);
+    let fresh = SigningKey::from_bytes(&[3u8; 32]);
+    client.open_channel(&sid, &player1, &BytesN::from_array(&env, &key1.verifying_key().to_bytes()));
+    client.open_channel(&sid, &player2, &BytesN::from_array(&env, &key2.verifying_key().to_bytes()));
+
+    // Player 2 settles an old state in their favour, then tries to re-key
+    let early = channel_state(&env, sid, 3, [1, 2]);
+    client.settle_channel(&sid, &early, &sign_channel_state(&env, &client, &early, &[&key1, &key2]));
+    let rekey = client.try_open_channel(&sid, &player2, &BytesN::from_array(&env, &fresh.verifying_key().to_bytes()));
+    assert_cangkulan_error(&rekey, CangkulanError::ChannelKeysLocked);
+
+    // Player 1's later state still verifies under the locked keys
+    let later = channel_state(&env, sid, 5, [4, 2]);
+    client.settle_channel(&sid, &later, &sign_channel_state(&env, &client, &later, &[&key1, &key2]));
+    advance_ledger(&env, CHANNEL_CHALLENGE_LEDGERS + 1);
+    assert_eq!(client.close_channel(&sid), OUTCOME_PLAYER1_WIN);
+}
+
+#[test]
+fn test_channel_superseded_by_on_chain_play() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1703u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    let key1 = SigningKey::from_bytes(&[1u8; 32]);
+    let key2 = SigningKey::from_bytes(&[2u8; 32]);
+    client.open_channel(&sid, &player1, &BytesN::from_array(&env, &key1.verifying_key().to_bytes()));
+    client.open_channel(&sid, &player2, &BytesN::from_array(&env, &key2.verifying_key().to_bytes()));
+    let state = channel_state(&env, sid, 1, [2, 0]);
+    client.settle_channel(&sid, &state, &sign_channel_state(&env, &client, &state, &[&key1, &key2]));
+
+    // Play carries on on-chain during the challenge window
+    let seed_hash = compute_seed_hash(&env, &BytesN::from_array(&env, &[1u8; 32]));
+    let commit = compute_nizk_commitment(&env, &seed_hash, &BytesN::from_array(&env, &[0xAAu8; 32]), &player1);
+    client.commit_seed(&sid, &player1, &commit, &SEED_PROOF_NIZK);
+
+    advance_ledger(&env, CHANNEL_CHALLENGE_LEDGERS + 1);
+    assert_cangkulan_error(&client.try_close_channel(&sid), CangkulanError::ChannelSuperseded);
+    let later = channel_state(&env, sid, 2, [3, 0]);
+    let settle = client.try_settle_channel(&sid, &later, &sign_channel_state(&env, &client, &later, &[&key1, &key2]));
+    assert_cangkulan_error(&settle, CangkulanError::ChannelSuperseded);
+    assert_eq!(client.get_game(&sid).lifecycle_state, STATE_SEED_COMMIT);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Notification preferences
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_notification_prefs_default_and_update() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(client.get_notification_prefs(&player1), NotificationPrefs::default());
+
+    let prefs = NotificationPrefs {
+        deadline_warnings: true,
+        mute_emotes: true,
+        rematch_offers: false,
+    };
+    client.set_notification_prefs(&player1, &prefs);
+    assert_eq!(client.get_notification_prefs(&player1), prefs);
+    assert_eq!(client.get_notification_prefs(&player2), NotificationPrefs::default());
+}
+
+#[test]
+fn test_emote_suppressed_for_muted_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+    let sid = 960u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert!(client.send_emote(&sid, &player1, &3));
+    assert_eq!(env.events().all().events().len(), 1);
+
+    client.set_notification_prefs(&player2, &NotificationPrefs {
+        mute_emotes: true,
+        ..NotificationPrefs::default()
+    });
+    assert!(!client.send_emote(&sid, &player1, &3));
+    assert_eq!(env.events().all().events().len(), 0);
+
+    // Muting is per recipient — player2 can still emote at player1
+    assert!(client.send_emote(&sid, &player2, &0));
+
+    let bad = client.try_send_emote(&sid, &player1, &16);
+    assert_cangkulan_error(&bad, CangkulanError::InvalidEmote);
+    let outsider_emote = client.try_send_emote(&sid, &outsider, &0);
+    assert_cangkulan_error(&outsider_emote, CangkulanError::NotAPlayer);
+}
+
+#[test]
+fn test_deadline_warning_only_when_opted_in() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 961u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed1 = BytesN::<32>::from_array(&env, &[7u8; 32]);
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let seed_hash1 = compute_seed_hash(&env, &seed1);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+
+    client.tick_timeout(&sid, &player1);
+    assert_eq!(env.events().all().events().len(), 0);
+
+    client.set_notification_prefs(&player2, &NotificationPrefs {
+        deadline_warnings: true,
+        ..NotificationPrefs::default()
+    });
+    advance_ledger(&env, 61);
+    client.tick_timeout(&sid, &player1);
+    assert_eq!(env.events().all().events().len(), 1);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Guest accounts
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_guest_games_attributed_to_sponsor() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let guest = Address::generate(&env);
+
+    client.sponsor_guest(&player1, &guest, &2);
+
+    client.start_game(&970, &guest, &player2, &10, &10);
+    assert_eq!(hub.get_last_players(), (player1.clone(), player2.clone()));
+    assert_eq!(client.get_game_debug(&970).seat(1).player, guest);
+    assert_eq!(client.get_guest_sponsor(&guest).unwrap().games_left, 1);
+
+    client.start_game(&971, &player2, &guest, &10, &10);
+    assert_eq!(hub.get_last_players(), (player2.clone(), player1.clone()));
+    assert_eq!(client.get_guest_sponsor(&guest), None);
+
+    // Allowance used up — the guest now plays as itself
+    client.start_game(&972, &guest, &player2, &0, &0);
+    assert_eq!(hub.get_last_players(), (guest, player2));
+}
+
+#[test]
+fn test_guest_cannot_play_against_sponsor() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+    let guest = Address::generate(&env);
+    client.sponsor_guest(&player1, &guest, &1);
+
+    let result = client.try_start_game(&973, &guest, &player1, &0, &0);
+    assert_cangkulan_error(&result, CangkulanError::SelfPlayNotAllowed);
+    assert_eq!(client.get_guest_sponsor(&guest).unwrap().games_left, 1);
+}
+
+#[test]
+fn test_guest_sponsorship_validation_and_revoke() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let guest = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let zero = client.try_sponsor_guest(&player1, &guest, &0);
+    assert_cangkulan_error(&zero, CangkulanError::InvalidGuestSponsorship);
+    let own = client.try_sponsor_guest(&player1, &player1, &1);
+    assert_cangkulan_error(&own, CangkulanError::InvalidGuestSponsorship);
+
+    client.sponsor_guest(&player1, &guest, &3);
+    let chained = client.try_sponsor_guest(&guest, &other, &1);
+    assert_cangkulan_error(&chained, CangkulanError::InvalidGuestSponsorship);
+
+    let wrong_sponsor = client.try_revoke_guest(&player2, &guest);
+    assert_cangkulan_error(&wrong_sponsor, CangkulanError::GuestNotSponsored);
+
+    client.revoke_guest(&player1, &guest);
+    assert_eq!(client.get_guest_sponsor(&guest), None);
+}
+
+#[test]
+fn test_session_delegate_signs_commits_and_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let delegate = Address::generate(&env);
+    let sid = 1330u32;
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let own = client.try_authorize_delegate(&sid, &player1, &player1);
+    assert_cangkulan_error(&own, CangkulanError::InvalidDelegate);
+    let outsider = client.try_authorize_delegate(&sid, &delegate, &player1);
+    assert_cangkulan_error(&outsider, CangkulanError::NotAPlayer);
+
+    client.authorize_delegate(&sid, &player1, &delegate);
+    assert_eq!(client.get_delegate(&sid, &player1), Some(delegate.clone()));
+    let seed_hash = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    let blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit = compute_nizk_commitment(&env, &seed_hash, &blinding, &player1);
+    client.commit_seed(&sid, &player1, &commit, &SEED_PROOF_NIZK);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, delegate);
+
+    // Without a delegate the player signs again
+    client.revoke_delegate(&sid, &player1);
+    assert_eq!(client.get_delegate(&sid, &player1), None);
+    assert_cangkulan_error(&client.try_revoke_delegate(&sid, &player1), CangkulanError::InvalidDelegate);
+    let seed_hash2 = compute_seed_hash(&env, &BytesN::from_array(&env, &[4u8; 32]));
+    let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding, &player2);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+    client.reveal_seed(&sid, &player1, &seed_hash, &build_nizk_proof(&env, &blinding));
+    assert_eq!(env.auths()[0].0, player1);
+}
+
+#[test]
+fn test_session_delegates_cleared_when_game_ends() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1331u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    for player in [&player1, &player2] {
+        client.authorize_delegate(&sid, player, &Address::generate(&env));
+    }
+
+    client.forfeit(&sid, &player2);
+    assert_eq!(client.get_delegate(&sid, &player1), None);
+    assert_eq!(client.get_delegate(&sid, &player2), None);
+
+    // A finished game takes no new delegate either
+    let late = client.try_authorize_delegate(&sid, &player1, &Address::generate(&env));
+    assert_cangkulan_error(&late, CangkulanError::GameAlreadyEnded);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Hand-size cap
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_hand_cap_config_snapshotted_at_start() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(
+        client.get_hand_cap(),
+        HandCapConfig { max_hand_size: 15, overflow_rule: OVERFLOW_SKIP_PENALTY },
+    );
+
+    client.start_game(&980, &player1, &player2, &0, &0);
+    client.set_hand_cap(&7, &OVERFLOW_INSTANT_LOSS);
+    client.start_game(&981, &player1, &Address::generate(&env), &0, &0);
+
+    let old = client.get_game_debug(&980);
+    assert_eq!((old.max_hand_size, old.overflow_rule), (15, OVERFLOW_SKIP_PENALTY));
+    let new = client.get_game_debug(&981);
+    assert_eq!((new.max_hand_size, new.overflow_rule), (7, OVERFLOW_INSTANT_LOSS));
+
+    let below_deal = client.try_set_hand_cap(&4, &OVERFLOW_SKIP_PENALTY);
+    assert_cangkulan_error(&below_deal, CangkulanError::InvalidHandCap);
+    let bad_rule = client.try_set_hand_cap(&10, &2);
+    assert_cangkulan_error(&bad_rule, CangkulanError::InvalidHandCap);
+}
+
+#[test]
+fn test_hand_cap_skip_leaves_card_on_pile() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 982u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let mut game = client.get_game_debug(&sid);
+    game.max_hand_size = game.seat(1).hand.len();
+    let pile_before = game.draw_pile.len();
+
+    let rule = CangkulanContract::give_penalty_card(&mut game, 1);
+    assert_eq!(rule, Some(OVERFLOW_SKIP_PENALTY));
+    assert_eq!(game.seat(1).hand.len(), game.max_hand_size);
+    assert_eq!(game.draw_pile.len(), pile_before);
+
+    // Below the cap the penalty is drawn as usual
+    game.max_hand_size += 1;
+    assert_eq!(CangkulanContract::give_penalty_card(&mut game, 1), None);
+    assert_eq!(game.seat(1).hand.len(), game.max_hand_size);
+    assert_eq!(game.draw_pile.len(), pile_before - 1);
+}
+
+#[test]
+fn test_hand_cap_exhausted_pile_never_overflows() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 983u32;
+    client.start_game(&sid, &player1, &player2, &0, &0);
+    advance_to_playing(&env, &client, sid, &player1, &player2);
+
+    let mut game = client.get_game_debug(&sid);
+    game.max_hand_size = game.seat(2).hand.len();
+    game.overflow_rule = OVERFLOW_INSTANT_LOSS;
+    game.draw_pile = Vec::new(&env);
+
+    // No card to draw → no penalty, so the cap cannot trigger a loss
+    assert_eq!(CangkulanContract::give_penalty_card(&mut game, 2), None);
+    assert_eq!(game.seat(2).hand.len(), game.max_hand_size);
+}
+
+#[test]
+fn test_hand_cap_instant_loss_ends_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_hand_cap(&HAND_CAP_AT_DEAL, &OVERFLOW_INSTANT_LOSS);
+
+    // The shuffle depends on session_id; scan sessions until one deals a
+    // trick where a player with a full hand cannot follow.
+    for sid in 984u32..1084 {
+        client.start_game(&sid, &player1, &player2, &0, &0);
+        advance_to_playing(&env, &client, sid, &player1, &player2);
+
+        while client.get_game_debug(&sid).lifecycle_state == STATE_PLAYING {
+            let before = client.get_game_debug(&sid);
+            let suit = before.trick_suit.unwrap();
+            let p1_follows = hand_has_suit(&before.seat(1).hand, suit);
+            let p2_follows = hand_has_suit(&before.seat(2).hand, suit);
+            let penalized_len = if p1_follows { before.seat(2).hand.len() } else { before.seat(1).hand.len() };
+            let overflows = p1_follows != p2_follows
+                && !before.draw_pile.is_empty()
+                && penalized_len >= HAND_CAP_AT_DEAL;
+
+            play_one_trick(&env, &client, &player1, &player2, sid);
+            if !overflows {
+                continue;
+            }
+
+            let after = client.get_game_debug(&sid);
+            let expected = if p1_follows { OUTCOME_PLAYER1_WIN } else { OUTCOME_PLAYER2_WIN };
+            assert_eq!(after.lifecycle_state, STATE_FINISHED);
+            assert_eq!(after.outcome, expected);
+            assert_eq!(after.draw_pile.len(), before.draw_pile.len(), "overflowing card is not drawn");
+            return;
+        }
+    }
+    panic!("no session reached an overflowing penalty");
+}
+
+const HAND_CAP_AT_DEAL: u32 = 5;
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Integrations
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_integrations_matches_getters() {
+    let (env, client, hub, _player1, _player2) = setup_test();
+
+    let integrations = client.get_integrations();
+    assert_eq!(
+        integrations,
+        Integrations {
+            hub: hub.address.clone(),
+            verifier: client.get_verifier(),
+            mode_verifiers: Map::new(&env),
+            leaderboard: None,
+            config_hash: integrations.config_hash.clone(),
+        },
+    );
+
+    let leaderboard = Address::generate(&env);
+    client.set_leaderboard(&leaderboard);
+
+    let updated = client.get_integrations();
+    assert_eq!(updated.leaderboard, Some(leaderboard.clone()));
+    assert_eq!(client.get_leaderboard(), Some(leaderboard));
+    assert_eq!(updated.config_hash, integrations.config_hash);
+}
+
+#[test]
+fn test_finished_game_reported_to_leaderboard() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+    let board = LeaderboardClient::new(&env, &env.register(Leaderboard, (&admin,)));
+    board.authorize_game(&admin, &client.address);
+    client.set_leaderboard(&board.address);
+
+    client.start_game(&1600, &player1, &player2, &0, &0);
+    client.forfeit(&1600, &player2);
+    assert_eq!(board.get_player(&player1).unwrap().wins, 1);
+    assert_eq!(board.get_player(&player2).unwrap().losses, 1);
+}
+
+#[test]
+fn rage_quit_after_deal_costs_more_than_a_loss() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+    let board = LeaderboardClient::new(&env, &env.register(Leaderboard, (&admin,)));
+    board.authorize_game(&admin, &client.address);
+    client.set_leaderboard(&board.address);
+
+    // Leaving before the deal is an ordinary loss
+    client.start_game(&1602, &player1, &player2, &0, &0);
+    client.forfeit(&1602, &player2);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let quit = EvGameForfeited { session_id: 1602, player: player2.clone(), penalized: false };
+    assert!(events.events().contains(&quit.to_xdr(&env, &client.address)));
+    assert_eq!(client.get_game(&1602).forfeited_by, 2);
+    let plain_loss = board.get_player(&player2).unwrap().elo;
+
+    // Leaving mid-game is penalized on top
+    let (player3, player4) = (Address::generate(&env), Address::generate(&env));
+    client.start_game(&1603, &player3, &player4, &0, &0);
+    advance_to_playing(&env, &client, 1603, &player3, &player4);
+    client.forfeit(&1603, &player4);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let quit = EvGameForfeited { session_id: 1603, player: player4.clone(), penalized: true };
+    assert!(events.events().contains(&quit.to_xdr(&env, &client.address)));
+
+    let game = client.get_game(&1603);
+    assert_eq!((game.outcome, game.forfeited_by), (OUTCOME_PLAYER1_WIN, 2));
+    let stats = board.get_player(&player4).unwrap();
+    assert_eq!((stats.losses, stats.elo), (1, plain_loss - FORFEIT_PENALTY));
+    assert_eq!(board.get_player(&player3).unwrap().elo, board.get_player(&player1).unwrap().elo);
+}
+
+#[test]
+fn test_leaderboard_refusal_does_not_block_finish() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+    let board = LeaderboardClient::new(&env, &env.register(Leaderboard, (&admin,)));
+    client.set_leaderboard(&board.address); // never authorized
+
+    client.start_game(&1601, &player1, &player2, &0, &0);
+    client.forfeit(&1601, &player2);
+    assert_eq!(client.get_game(&1601).lifecycle_state, STATE_FINISHED);
+    assert_eq!(board.get_player(&player1), None);
+}
+
+#[test]
+fn test_config_hash_tracks_rule_changes() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let before = client.get_integrations().config_hash;
+    client.set_hand_cap(&10, &OVERFLOW_SKIP_PENALTY);
+    let after = client.get_integrations().config_hash;
+    assert_ne!(before, after);
+
+    client.set_hand_cap(&15, &OVERFLOW_SKIP_PENALTY);
+    assert_eq!(client.get_integrations().config_hash, before, "Hash depends only on the config");
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Emergency verifier override
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A verifier that has broken and rejects everything.
+#[contract]
+pub struct BrokenZkVerifier;
+
+#[contractimpl]
+impl BrokenZkVerifier {
+    pub fn verify_from(_env: Env, _caller: Address, _public_inputs: Bytes, _proof: Bytes) -> bool {
+        false
+    }
+
+    pub fn get_deck_params(_env: Env) -> DeckParams {
+        DeckParams::default()
+    }
+}
+
+#[test]
+fn pause_blocks_new_games_and_commits_only() {
+    let (env, client, hub, player1, player2) = setup_test();
+    client.start_game(&1310u32, &player1, &player2, &100_0000000, &100_0000000);
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    let seed_hash2 = compute_seed_hash(&env, &BytesN::from_array(&env, &[4u8; 32]));
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
+    client.commit_seed(&1310u32, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&1310u32, &player2, &commit2, &SEED_PROOF_NIZK);
+    client.start_game(&1311u32, &player1, &player2, &100_0000000, &100_0000000);
+
+    client.set_paused(&true);
+    assert!(client.is_paused());
+    let result = client.try_start_game(&1312u32, &player1, &player2, &100_0000000, &100_0000000);
+    assert_cangkulan_error(&result, CangkulanError::ContractPaused);
+    let result = client.try_create_challenge(&player1, &10, &(env.ledger().sequence() + 100));
+    assert_cangkulan_error(&result, CangkulanError::ContractPaused);
+    let result = client.try_commit_seed(&1311u32, &player1, &commit1, &SEED_PROOF_NIZK);
+    assert_cangkulan_error(&result, CangkulanError::ContractPaused);
+
+    // Games in flight can still be wound down
+    client.reveal_seed(&1310u32, &player1, &seed_hash1, &build_nizk_proof(&env, &blinding1));
+    client.forfeit(&1311u32, &player2);
+    assert_eq!(client.get_game(&1311u32).outcome, OUTCOME_PLAYER1_WIN);
+
+    client.set_paused(&false);
+    client.start_game(&1312u32, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(hub.get_start_count(), 3);
+}
+
+#[test]
+fn cancel_game_refunds_and_reports_no_winner() {
+    let (env, client, hub, player1, player2) = setup_test();
+    setup_escrow(&env, &client, &[&player1, &player2], 0);
+    client.start_game(&1320u32, &player1, &player2, &100, &50);
+    advance_to_playing(&env, &client, 1320, &player1, &player2);
+    client.start_game(&1321u32, &player1, &player2, &100, &50);
+
+    client.cancel_game(&1320u32, &symbol_short!("bad_vrf"));
+    let events = env.events().all().filter_by_contract(&client.address);
+    let cancelled = EvGameCancelled { session_id: 1320, reason: symbol_short!("bad_vrf") };
+    assert!(events.events().contains(&cancelled.to_xdr(&env, &client.address)));
+    let game = client.get_game(&1320u32);
+    assert_eq!((game.lifecycle_state, game.outcome), (STATE_FINISHED, OUTCOME_CANCELLED));
+    // The hub records no winner
+    assert_eq!(hub.get_end_count(), 0);
+    let payouts = client.get_pending_claim(&1320u32).unwrap().payouts;
+    assert_eq!(payouts.get_unchecked(0).amount, 100);
+    assert_eq!(payouts.get_unchecked(1).amount, 50);
+    assert_cangkulan_error(
+        &client.try_cancel_game(&1320u32, &symbol_short!("again")),
+        CangkulanError::GameAlreadyEnded,
+    );
+
+    // A broken hub does not strand the session
+    client.set_hub(&Address::generate(&env));
+    client.cancel_game(&1321u32, &symbol_short!("bad_hub"));
+    assert_eq!(client.get_game(&1321u32).outcome, OUTCOME_CANCELLED);
+    assert_eq!(hub.get_end_count(), 0);
+}
+
+#[test]
+fn test_verifier_override_timelock() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    assert_cangkulan_error(&client.try_execute_after_timelock(), CangkulanError::OverrideNotScheduled);
+    assert_cangkulan_error(
+        &client.try_schedule_verifier_override(&0),
+        CangkulanError::InvalidOverrideDuration,
+    );
+
+    let executable = client.schedule_verifier_override(&1_000);
+    assert_eq!(
+        client.get_pending_verifier_override(),
+        Some(PendingVerifierOverride { executable_ledger: executable, duration_ledgers: 1_000 }),
+    );
+    advance_ledger(&env, executable - env.ledger().sequence() - 1);
+    assert_cangkulan_error(&client.try_execute_after_timelock(), CangkulanError::TimelockNotElapsed);
+    assert_eq!(client.get_verifier_override(), None);
+
+    advance_ledger(&env, 1);
+    let expires = client.execute_after_timelock();
+    assert_eq!(client.get_verifier_override(), Some(expires));
+    assert_eq!(client.get_pending_verifier_override(), None);
+
+    // Lapses on its own.
+    advance_ledger(&env, 1_000);
+    assert_eq!(client.get_verifier_override(), None);
+
+    // And can be ended early.
+    let executable = client.schedule_verifier_override(&1_000);
+    advance_ledger(&env, executable - env.ledger().sequence());
+    client.execute_after_timelock();
+    client.cancel_verifier_override();
+    assert_eq!(client.get_verifier_override(), None);
+}
+
+#[test]
+fn test_verifier_override_unsticks_seed_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sid = 1300u32;
+    client.set_verifier(&env.register(BrokenZkVerifier, ()));
+    client.start_game(&sid, &player1, &player2, &100_0000000, &100_0000000);
+
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    let seed_hash2 = compute_seed_hash(&env, &BytesN::from_array(&env, &[4u8; 32]));
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
+    client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+    client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+
+    let proof1 = build_nizk_proof(&env, &blinding1);
+    assert_cangkulan_error(
+        &client.try_reveal_seed(&sid, &player1, &seed_hash1, &proof1),
+        CangkulanError::InvalidZkProof,
+    );
+
+    let executable = client.schedule_verifier_override(&1_000);
+    advance_ledger(&env, executable - env.ledger().sequence());
+    client.execute_after_timelock();
+
+    // The hash commitment must still open.
+    let wrong = build_nizk_proof(&env, &blinding2);
+    assert_cangkulan_error(
+        &client.try_reveal_seed(&sid, &player1, &seed_hash1, &wrong),
+        CangkulanError::CommitHashMismatch,
+    );
+    client.reveal_seed(&sid, &player1, &seed_hash1, &proof1);
+    client.reveal_seed(&sid, &player2, &seed_hash2, &build_nizk_proof(&env, &blinding2));
+    assert_eq!(client.get_game_debug(&sid).lifecycle_state, STATE_PLAYING);
+}
+
+#[test]
+fn game_keeps_verifier_pinned_at_start() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let original = client.get_verifier();
+    client.start_game(&1301u32, &player1, &player2, &100_0000000, &100_0000000);
+    let broken = env.register(BrokenZkVerifier, ());
+    client.set_verifier(&broken);
+    client.start_game(&1302u32, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game_debug(&1301u32).verifier, original);
+    assert_eq!(client.get_game_debug(&1302u32).verifier, broken);
+
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    let seed_hash2 = compute_seed_hash(&env, &BytesN::from_array(&env, &[4u8; 32]));
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
+    for sid in [1301u32, 1302] {
+        client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+        client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+    }
+
+    // The game in flight still trusts the verifier it started with
+    client.reveal_seed(&1301u32, &player1, &seed_hash1, &build_nizk_proof(&env, &blinding1));
+    assert!(client.get_game_debug(&1301u32).seat(1).seed_revealed);
+    assert_cangkulan_error(
+        &client.try_reveal_seed(&1302u32, &player1, &seed_hash1, &build_nizk_proof(&env, &blinding1)),
+        CangkulanError::InvalidZkProof,
+    );
+}
+
+#[test]
+fn seed_proofs_follow_mode_route() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let broken = env.register(BrokenZkVerifier, ());
+    assert_cangkulan_error(
+        &client.try_set_verifier_for_mode(&MODE_PRIVATE_HAND, &broken),
+        CangkulanError::UnroutableProofMode,
+    );
+    client.set_verifier_for_mode(&SEED_PROOF_NIZK, &broken);
+    assert_eq!(client.get_mode_verifiers().get(SEED_PROOF_NIZK), Some(broken.clone()));
+    client.start_game(&1303u32, &player1, &player2, &100_0000000, &100_0000000);
+    client.clear_verifier_for_mode(&SEED_PROOF_NIZK);
+    client.start_game(&1304u32, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(client.get_game_debug(&1304u32).mode_verifiers.is_empty());
+
+    let seed_hash1 = compute_seed_hash(&env, &BytesN::from_array(&env, &[3u8; 32]));
+    let blinding1 = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let commit1 = compute_nizk_commitment(&env, &seed_hash1, &blinding1, &player1);
+    let seed_hash2 = compute_seed_hash(&env, &BytesN::from_array(&env, &[4u8; 32]));
+    let blinding2 = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+    let commit2 = compute_nizk_commitment(&env, &seed_hash2, &blinding2, &player2);
+    for sid in [1303u32, 1304] {
+        client.commit_seed(&sid, &player1, &commit1, &SEED_PROOF_NIZK);
+        client.commit_seed(&sid, &player2, &commit2, &SEED_PROOF_NIZK);
+    }
+
+    // NIZK seed proofs of the game started under the route go to it
+    assert_cangkulan_error(
+        &client.try_reveal_seed(&1303u32, &player1, &seed_hash1, &build_nizk_proof(&env, &blinding1)),
+        CangkulanError::InvalidZkProof,
+    );
+    client.reveal_seed(&1304u32, &player1, &seed_hash1, &build_nizk_proof(&env, &blinding1));
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: Three- and four-player games
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Helper: start a game for `players` and take it to the PLAYING state.
+fn start_table_game(env: &Env, client: &CangkulanContractClient, sid: u32, players: &[Address], stake: i128) {
+    start_configured_game(env, client, sid, players, stake, None);
+}
+
+/// Helper: as `start_table_game`, under `config`.
+fn start_configured_game(
+    env: &Env,
+    client: &CangkulanContractClient,
+    sid: u32,
+    players: &[Address],
+    stake: i128,
+    config: Option<GameConfig>,
+) {
+    let mut addresses = Vec::new(env);
+    let mut points = Vec::new(env);
+    for player in players {
+        addresses.push_back(player.clone());
+        points.push_back(stake);
+    }
+    client.start_game_with_config(&sid, &addresses, &points, &config);
+
+    let mut reveals = std::vec::Vec::new();
+    for (i, player) in players.iter().enumerate() {
+        let seed_hash = compute_seed_hash(env, &BytesN::from_array(env, &[i as u8 + 1; 32]));
+        let blinding = BytesN::<32>::from_array(env, &[0xA0 + i as u8; 32]);
+        let commit = compute_nizk_commitment(env, &seed_hash, &blinding, player);
+        client.commit_seed(&sid, player, &commit, &SEED_PROOF_NIZK);
+        reveals.push((seed_hash, build_nizk_proof(env, &blinding)));
+    }
+    for (player, (seed_hash, proof)) in players.iter().zip(reveals) {
+        client.reveal_seed(&sid, player, &seed_hash, &proof);
+    }
+}
+
+/// Helper: every seat commits its action in seat order, then reveals it.
+fn play_trick_as(env: &Env, client: &CangkulanContractClient, sid: u32, players: &[Address], actions: &[u32]) {
+    for (i, (player, action)) in players.iter().zip(actions).enumerate() {
+        let nonce = client.get_game_debug(&sid).action_nonce;
+        let commit = compute_play_commit(env, *action, &test_salt(env, 0x30 + i as u8));
+        client.commit_play(&sid, player, &commit, &nonce);
+    }
+    for (i, (player, action)) in players.iter().zip(actions).enumerate() {
+        client.reveal_play(&sid, player, action, &test_salt(env, 0x30 + i as u8));
+    }
+}
+
+/// Follow suit with the first matching card, or declare cangkul.
+fn follow_actions(game: &crate::CangkulanGame) -> std::vec::Vec<u32> {
+    let suit = game.trick_suit.unwrap();
+    (1..=game.players.len())
+        .map(|slot| first_card_of_suit(&game.seat(slot).hand, suit).unwrap_or(CANNOT_FOLLOW_SENTINEL))
+        .collect()
+}
+
+#[test]
+fn multiplayer_game_rejects_bad_seating() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let five: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&env)).collect();
+
+    let lone = client.try_start_multiplayer_game(&1400, &vec![&env, player1.clone()], &vec![&env, 10]);
+    assert_cangkulan_error(&lone, CangkulanError::InvalidPlayerCount);
+
+    let crowd = Vec::from_slice(&env, &five);
+    let result = client.try_start_multiplayer_game(&1400, &crowd, &vec![&env, 10, 10, 10, 10, 10]);
+    assert_cangkulan_error(&result, CangkulanError::InvalidPlayerCount);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3];
+    let short = client.try_start_multiplayer_game(&1400, &players, &vec![&env, 10, 10]);
+    assert_cangkulan_error(&short, CangkulanError::InvalidPlayerCount);
+
+    let twice = vec![&env, player1.clone(), player2, player1];
+    let result = client.try_start_multiplayer_game(&1400, &twice, &vec![&env, 10, 10, 10]);
+    assert_cangkulan_error(&result, CangkulanError::SelfPlayNotAllowed);
+}
+
+#[test]
+fn three_player_trick_waits_on_each_seat() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let players = [player1.clone(), player2.clone(), player3.clone()];
+    let sid = 1401u32;
+    start_table_game(&env, &client, sid, &players, 100);
+
+    // Dealt 5 each; the pile is the rest minus the flipped card
+    let game = client.get_game_debug(&sid);
+    assert_eq!(game.lifecycle_state, STATE_PLAYING);
+    assert_eq!(game.players.len(), 3);
+    for slot in 1..=3 {
+        assert_eq!(game.seat(slot).hand.len(), 5);
+    }
+    assert_eq!(game.draw_pile.len(), 36 - 15 - 1);
+    assert_eq!(client.verify_shuffle(&sid).len(), 36);
+    assert_eq!(hub.get_start_count(), 0, "Only two-seat games reach the hub");
+
+    // Commits: several outstanding, then only P3
+    let actions = follow_actions(&game);
+    let salts: std::vec::Vec<BytesN<32>> = (0..3).map(|i| test_salt(&env, 0x40 + i)).collect();
+    for (i, expected) in [TRICK_COMMIT_WAIT_BOTH, TRICK_COMMIT_WAIT_P3, TRICK_REVEAL_WAIT_BOTH].iter().enumerate() {
+        let nonce = client.get_game_debug(&sid).action_nonce;
+        let commit = compute_play_commit(&env, actions[i], &salts[i]);
+        client.commit_play(&sid, &players[i], &commit, &nonce);
+        assert_eq!(client.get_game_debug(&sid).trick_state, *expected);
+    }
+
+    // Reveals out of seat order: P3, then P1, leaves only P2
+    client.reveal_play(&sid, &player3, &actions[2], &salts[2]);
+    assert_eq!(client.get_game_debug(&sid).trick_state, TRICK_REVEAL_WAIT_BOTH);
+    let again = client.try_reveal_play(&sid, &player3, &actions[2], &salts[2]);
+    assert_cangkulan_error(&again, CangkulanError::RevealAlreadySubmitted);
+    client.reveal_play(&sid, &player1, &actions[0], &salts[0]);
+    assert_eq!(client.get_game_debug(&sid).trick_state, TRICK_REVEAL_WAIT_P2);
+
+    // P2 cannot see the revealed cards until revealing too
+    let p2_view = client.get_game_view(&sid, &player2);
+    assert!(p2_view.seat(2).trick_card.is_none() && p2_view.seat(1).trick_card.is_none());
+    assert!(p2_view.seat(1).hand.is_empty() && p2_view.seat(3).hand.is_empty());
+    assert_eq!(p2_view.seat(2).hand.len(), game.seat(2).hand.len());
+    let p1_view = client.get_game_view(&sid, &player1);
+    assert_eq!(p1_view.seat(1).trick_card, client.get_game_debug(&sid).seat(1).trick_card);
+
+    client.reveal_play(&sid, &player2, &actions[1], &salts[1]);
+    let after = client.get_game_debug(&sid);
+    let trick = after.trick_log.get(0).unwrap();
+    assert_eq!(trick.cards, Vec::from_slice(&env, &actions));
+    assert!(after.trick_state == TRICK_COMMIT_WAIT_BOTH || after.lifecycle_state == STATE_FINISHED);
+}
+
+#[test]
+fn four_player_trick_penalizes_every_non_follower() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1, player2, Address::generate(&env), Address::generate(&env)];
+    let sid = 1402u32;
+    start_table_game(&env, &client, sid, &players, 100);
+
+    // Suit 0 trick: P1 and P2 follow, P3 and P4 hold no suit-0 card
+    let mut game = client.get_game_debug(&sid);
+    for (slot, hand) in [(1, [1u32, 20]), (2, [5, 21]), (3, [22, 23]), (4, [30, 31])] {
+        let mut seat = game.seat(slot);
+        seat.hand = Vec::from_slice(&env, &hand);
+        game.set_seat(slot, seat);
+    }
+    game.flipped_card = Some(0);
+    game.trick_suit = Some(0);
+    game.draw_pile = vec![&env, 9, 10, 11, 12];
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+
+    play_trick_as(&env, &client, sid, &players, &[1, 5, CANNOT_FOLLOW_SENTINEL, CANNOT_FOLLOW_SENTINEL]);
+
+    let game = client.get_game_debug(&sid);
+    let trick = game.trick_log.last().unwrap();
+    assert_eq!(trick.winner, 2, "Highest card of the suit wins");
+    assert_eq!(game.seat(2).tricks_won, 1);
+    assert_eq!(game.seat(1).tricks_won, 0);
+    // Penalty cards are drawn in seat order, then the next card is flipped
+    assert_eq!(game.seat(3).hand, vec![&env, 22, 23, 9]);
+    assert_eq!(game.seat(4).hand, vec![&env, 30, 31, 10]);
+    assert_eq!(game.flipped_card, Some(11));
+    assert_eq!(game.draw_pile, vec![&env, 12]);
+}
+
+#[test]
+fn three_player_timeout_attributes_idle_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let players = [player1.clone(), player2.clone(), player3.clone()];
+    setup_escrow(&env, &client, &[&player1, &player2, &player3], 0);
+    let sid = 1403u32;
+    start_table_game(&env, &client, sid, &players, 100);
+
+    // P2 leads on tricks; P3 never commits
+    let mut game = client.get_game_debug(&sid);
+    let mut seat = game.seat(2);
+    seat.tricks_won = 2;
+    game.set_seat(2, seat);
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+
+    let actions = follow_actions(&game);
+    for (i, player) in players[..2].iter().enumerate() {
+        let nonce = client.get_game_debug(&sid).action_nonce;
+        client.commit_play(&sid, player, &compute_play_commit(&env, actions[i], &test_salt(&env, 0x50)), &nonce);
+    }
+    assert_eq!(client.get_game_debug(&sid).trick_state, TRICK_COMMIT_WAIT_P3);
+
+    tick_timeout_twice(&env, &client, &sid, &player1);
+    client.resolve_timeout(&sid, &player1);
+
+    // The idle player loses; the best of the others wins every stake
+    let game = client.get_game(&sid);
+    assert_eq!(game.outcome, OUTCOME_PLAYER2_WIN);
+    let claim = client.get_pending_claim(&sid).unwrap();
+    assert_eq!(claim.payouts, vec![&env, Payout { player: player2.clone(), amount: 300, claimed: false }]);
+
+    let h3 = client.get_player_history(&player3, &0, &MAX_HISTORY_PAGE, &None, &None).entries;
+    assert_eq!(h3.get(0).unwrap().outcome, OUTCOME_PLAYER2_WIN, "Loss from P3's side");
+    assert_eq!(h3.get(0).unwrap().opponent, player1, "Opponent is the next seat");
+    assert_eq!(h3.get(0).unwrap().tricks_lost, 2);
+    assert_eq!(client.get_player_history(&player2, &0, &MAX_HISTORY_PAGE, &None, &None).entries.get(0).unwrap().outcome, OUTCOME_PLAYER1_WIN);
+}
+
+#[test]
+fn four_player_game_plays_to_finish() {
+    let (env, client, hub, player1, player2) = setup_test();
+    env.cost_estimate().budget().reset_unlimited();
+    let players = [player1, player2, Address::generate(&env), Address::generate(&env)];
+    setup_escrow(&env, &client, &players.each_ref(), 0);
+    let sid = 1404u32;
+    start_table_game(&env, &client, sid, &players, 100);
+
+    let mut iterations = 0;
+    while client.get_game_debug(&sid).lifecycle_state != STATE_FINISHED {
+        let actions = follow_actions(&client.get_game_debug(&sid));
+        play_trick_as(&env, &client, sid, &players, &actions);
+        iterations += 1;
+        assert!(iterations < 40, "Game did not finish");
+    }
+    assert_eq!(hub.get_end_count(), 0);
+
+    let game = client.get_game_record(&sid);
+    let won: u32 = game.players.iter().map(|seat| seat.tricks_won).sum();
+    assert!(won <= game.trick_log.len() + game.tricks_pruned);
+
+    // Summary blob: version 2, four players and four trick counts
+    let blob = client.export_summary(&sid);
+    assert_eq!(blob.slice(0..4), Bytes::from_array(&env, &2u32.to_be_bytes()));
+    assert_eq!(blob.len(), 4 + 4 + 4 * 56 + 4 + 4 * 4 + 32);
+    let expected: BytesN<32> = env.crypto().keccak256(&blob).into();
+    assert_eq!(client.get_summary_hash(&sid), Some(expected));
+
+    // Winner takes all four stakes; a draw refunds each
+    let claim = client.get_pending_claim(&sid).unwrap();
+    if game.outcome == OUTCOME_DRAW {
+        assert_eq!(claim.payouts.len(), 4);
+    } else {
+        assert_eq!(claim.payouts.len(), 1);
+        assert_eq!(claim.payouts.get(0).unwrap().amount, 400);
+    }
+    for player in &players {
+        assert_eq!(client.get_player_history(player, &0, &MAX_HISTORY_PAGE, &None, &None).entries.len(), 1);
+    }
+}
+
+#[test]
+fn team_game_credits_partners_and_reports_two_sides() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let players = [player1.clone(), player2.clone(), Address::generate(&env), Address::generate(&env)];
+    setup_escrow(&env, &client, &players.each_ref(), 0);
+    let sid = 1405u32;
+    start_configured_game(&env, &client, sid, &players, 100, Some(GameConfig::teams()));
+    assert_eq!(hub.get_start_count(), 1, "The two teams reach the hub");
+    assert_eq!(hub.get_last_players(), (player1.clone(), player2.clone()));
+
+    // P3 empties their hand on a trick P2's partnership leads on tricks
+    let mut game = client.get_game_debug(&sid);
+    for (slot, hand) in [(1, &[1u32, 20][..]), (2, &[5, 21]), (3, &[7]), (4, &[30, 31])] {
+        let mut seat = game.seat(slot);
+        seat.hand = Vec::from_slice(&env, hand);
+        seat.tricks_won = if slot == 2 { 3 } else { 0 };
+        game.set_seat(slot, seat);
+    }
+    game.flipped_card = Some(0);
+    game.trick_suit = Some(0);
+    game.draw_pile = vec![&env, 9, 10];
+    env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+    play_trick_as(&env, &client, sid, &players, &[1, 5, 7, CANNOT_FOLLOW_SENTINEL]);
+
+    // Going out first wins for the whole team, recorded as a P1 win
+    let game = client.get_game(&sid);
+    assert_eq!(game.outcome, OUTCOME_PLAYER1_WIN);
+    assert_eq!(hub.get_end_count(), 1);
+    let claim = client.get_pending_claim(&sid).unwrap();
+    assert_eq!(
+        claim.payouts,
+        vec![
+            &env,
+            Payout { player: players[0].clone(), amount: 200, claimed: false },
+            Payout { player: players[2].clone(), amount: 200, claimed: false },
+        ],
+    );
+    let h3 = client.get_player_history(&players[2], &0, &MAX_HISTORY_PAGE, &None, &None).entries.get(0).unwrap();
+    assert_eq!((h3.outcome, h3.tricks_lost), (OUTCOME_PLAYER1_WIN, 3));
+    let h4 = client.get_player_history(&players[3], &0, &MAX_HISTORY_PAGE, &None, &None).entries.get(0).unwrap();
+    assert_eq!((h4.outcome, h4.tricks_lost), (OUTCOME_PLAYER2_WIN, 1));
+}
+
+#[test]
+fn team_loses_for_an_idle_or_quitting_partner() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1.clone(), player2, Address::generate(&env), Address::generate(&env)];
+    start_configured_game(&env, &client, 1406, &players, 0, Some(GameConfig::teams()));
+    start_configured_game(&env, &client, 1407, &players, 0, Some(GameConfig::teams()));
+
+    // P4 never commits, so P2's team loses however the tricks stand
+    let actions = follow_actions(&client.get_game_debug(&1406));
+    for (i, player) in players[..3].iter().enumerate() {
+        let nonce = client.get_game_debug(&1406).action_nonce;
+        client.commit_play(&1406, player, &compute_play_commit(&env, actions[i], &test_salt(&env, 0x60)), &nonce);
+    }
+    tick_timeout_twice(&env, &client, &1406, &player1);
+    client.resolve_timeout(&1406, &player1);
+    assert_eq!(client.get_game(&1406).outcome, OUTCOME_PLAYER1_WIN);
+
+    // P3 quits, conceding for P1 too
+    client.forfeit(&1407, &players[2]);
+    assert_eq!(client.get_game(&1407).outcome, OUTCOME_PLAYER2_WIN);
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+//  Tests: House rules (GameConfig)
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn game_config_rejects_unplayable_rules() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.start_game(&1500, &player1, &player2, &0, &0);
+    assert_eq!(client.get_game(&1500).config, GameConfig::standard());
+
+    let players = vec![&env, player1, player2];
+    let points = vec![&env, 0, 0];
+    let bad = [
+        GameConfig { deck_size: 30, ..GameConfig::standard() },
+        GameConfig { deck_size: 20, ..GameConfig::standard() },
+        GameConfig { hand_size: 0, ..GameConfig::standard() },
+        GameConfig { hand_size: 12, deck_size: 24, ..GameConfig::standard() },
+        GameConfig { hand_size: 16, ..GameConfig::standard() },
+        GameConfig { timeout_ledgers: 5, ..GameConfig::standard() },
+        GameConfig { tie_break: 2, ..GameConfig::standard() },
+        GameConfig { penalty_draw: 0, ..GameConfig::standard() },
+        GameConfig { penalty_draw: 4, ..GameConfig::standard() },
+        GameConfig { cards_per_suit: 10, deck_size: 40, ..GameConfig::standard() },
+        GameConfig { deck_size: 52, ..GameConfig::standard() },
+        GameConfig { timeout_ledgers: 1, ..GameConfig::blitz() },
+        GameConfig { time_bank_ledgers: 721, ..GameConfig::blitz() },
+        GameConfig::teams(),
+    ];
+    for config in bad {
+        let result = client.try_start_game_with_config(&1501, &players, &points, &Some(config));
+        assert_cangkulan_error(&result, CangkulanError::InvalidGameConfig);
+    }
+}
+
+#[test]
+fn small_deck_deals_configured_hands() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = GameConfig { hand_size: 7, deck_size: 24, timeout_ledgers: 40, ..GameConfig::standard() };
+    let sid = 1502u32;
+    start_configured_game(&env, &client, sid, &[player1.clone(), player2], 10, Some(config));
+
+    let game = client.get_game_debug(&sid);
+    assert_eq!(game.seat(1).hand.len(), 7);
+    assert_eq!(game.seat(2).hand.len(), 7);
+    assert_eq!(game.draw_pile.len(), 24 - 14 - 1);
+    assert_eq!(game.deadline_ledger, Some(env.ledger().sequence() + 40));
+
+    // Only values 5-10 of each suit are in play
+    let deck = client.verify_shuffle(&sid);
+    assert_eq!(deck.len(), 24);
+    assert!(deck.iter().all(|card| card % CARDS_PER_SUIT_36 >= 3));
+    assert_eq!(deck.slice(14..15), vec![&env, game.flipped_card.unwrap()]);
+
+    // The tick gap follows the configured timeout
+    advance_ledger(&env, 20);
+    client.tick_timeout(&sid, &player1);
+    advance_ledger(&env, 19);
+    let early = client.try_tick_timeout(&sid, &player1);
+    assert_cangkulan_error(&early, CangkulanError::TickTooSoon);
+}
+
+/// Helper: a two-seat game about to resolve a suit-0 trick with `hands`.
+fn start_rigged_trick(
+    env: &Env,
+    client: &CangkulanContractClient,
+    sid: u32,
+    players: &[Address],
+    config: GameConfig,
+    hands: [&[u32]; 2],
+    pile: &[u32],
+) {
+    start_configured_game(env, client, sid, players, 0, Some(config));
+    let mut game = client.get_game_debug(&sid);
+    for (slot, hand) in [(1, hands[0]), (2, hands[1])] {
+        let mut seat = game.seat(slot);
+        seat.hand = Vec::from_slice(env, hand);
+        game.set_seat(slot, seat);
+    }
+    game.flipped_card = Some(0);
+    game.trick_suit = Some(0);
+    game.draw_pile = Vec::from_slice(env, pile);
+    env.as_contract(&client.address, || CangkulanContract::write_game(env, sid, &mut game));
+}
+
+#[test]
+fn penalty_draw_takes_configured_cards() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1, player2];
+    let config = GameConfig { penalty_draw: 2, ..GameConfig::standard() };
+    start_rigged_trick(&env, &client, 1503, &players, config, [&[1, 20], &[21, 22]], &[9, 10, 11]);
+
+    play_trick_as(&env, &client, 1503, &players, &[1, CANNOT_FOLLOW_SENTINEL]);
+    let game = client.get_game_debug(&1503);
+    assert_eq!(game.seat(2).hand, vec![&env, 21, 22, 9, 10]);
+    assert_eq!(game.flipped_card, Some(11));
+}
+
+#[test]
+fn tie_break_rule_orders_tricks_and_cards() {
+    // After the trick P1 leads on tricks (3 to 2) but holds more cards (3 to 1)
+    for (rule, sid, expected) in [(TIE_BREAK_TRICKS, 1504u32, OUTCOME_PLAYER1_WIN), (TIE_BREAK_CARDS, 1505, OUTCOME_PLAYER2_WIN)] {
+        let (env, client, _hub, player1, player2) = setup_test();
+        let players = [player1, player2];
+        let config = GameConfig { tie_break: rule, ..GameConfig::standard() };
+        start_rigged_trick(&env, &client, sid, &players, config, [&[1, 20, 21, 22], &[5, 23]], &[]);
+        let mut game = client.get_game_debug(&sid);
+        for (slot, won) in [(1, 3), (2, 1)] {
+            let mut seat = game.seat(slot);
+            seat.tricks_won = won;
+            game.set_seat(slot, seat);
+        }
+        env.as_contract(&client.address, || CangkulanContract::write_game(&env, sid, &mut game));
+
+        play_trick_as(&env, &client, sid, &players, &[1, 5]);
+        let game = client.get_game(&sid);
+        assert_eq!(game.lifecycle_state, STATE_FINISHED);
+        assert_eq!(game.outcome, expected);
+    }
+}
+
+#[test]
+fn zk_required_rejects_plain_commits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = GameConfig { zk_required: true, ..GameConfig::standard() };
+    start_configured_game(&env, &client, 1506, &[player1.clone(), player2], 0, Some(config));
+
+    let nonce = client.get_game_debug(&1506).action_nonce;
+    let commit = compute_play_commit(&env, 0, &test_salt(&env, 1));
+    let result = client.try_commit_play(&1506, &player1, &commit, &nonce);
+    assert_cangkulan_error(&result, CangkulanError::ZkPlayRequired);
+}
+
+#[test]
+fn standard_deck_deals_from_52_cards() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let config = GameConfig { cards_per_suit: CARDS_PER_SUIT_52, deck_size: 52, ..GameConfig::standard() };
+    let sid = 1507u32;
+    start_configured_game(&env, &client, sid, &[player1, player2], 0, Some(config));
+
+    let deck = client.verify_shuffle(&sid);
+    assert_eq!(deck.len(), 52);
+    let mut seen = [false; 52];
+    for card in deck.iter() {
+        assert!(!seen[card as usize], "card {card} dealt twice");
+        seen[card as usize] = true;
+    }
+
+    let game = client.get_game_debug(&sid);
+    assert_eq!(game.draw_pile.len(), 52 - 10 - 1);
+    assert_eq!(game.trick_suit, Some(game.flipped_card.unwrap() / CARDS_PER_SUIT_52));
+}
+
+#[test]
+fn standard_deck_ranks_ace_high() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1, player2];
+    let config = GameConfig { cards_per_suit: CARDS_PER_SUIT_52, deck_size: 52, ..GameConfig::standard() };
+    // 11 and 12 are the king and ace of suit 0 (in the 36-card deck 12 is suit 1)
+    start_rigged_trick(&env, &client, 1508, &players, config, [&[11, 30], &[12, 40]], &[20, 21]);
+
+    play_trick_as(&env, &client, 1508, &players, &[11, 12]);
+    let game = client.get_game_debug(&1508);
+    assert_eq!((game.seat(1).tricks_won, game.seat(2).tricks_won), (0, 1));
+    assert_eq!(game.trick_suit, Some(20 / CARDS_PER_SUIT_52));
+}
+
+#[test]
+fn zk_play_requires_matching_verifier_deck() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1.clone(), player2];
+    let config = GameConfig { cards_per_suit: CARDS_PER_SUIT_52, deck_size: 52, ..GameConfig::standard() };
+    start_rigged_trick(&env, &client, 1509, &players, config, [&[11, 30], &[12, 40]], &[20, 21]);
+
+    let nonce = client.get_game_debug(&1509).action_nonce;
+    let commit_hash = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+    let dummy_proof = Bytes::from_array(&env, &[1u8; 4]);
+    let result = client.try_commit_play_zk(&1509, &player1, &commit_hash, &nonce, &dummy_proof);
+    assert_cangkulan_error(&result, CangkulanError::DeckMismatch);
+
+    MockZkVerifierClient::new(&env, &client.get_verifier()).set_deck_params(&DeckParams::standard());
+    client.commit_play_zk(&1509, &player1, &commit_hash, &nonce, &dummy_proof);
+    assert!(client.get_game_debug(&1509).seat(1).zk_play);
+}
+
+#[test]
+fn draw_until_follow_stops_on_trick_suit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1, player2];
+    let config = GameConfig { draw_until_follow: true, ..GameConfig::standard() };
+    start_rigged_trick(&env, &client, 1510, &players, config, [&[1, 20], &[21, 22]], &[10, 19, 2, 11, 12]);
+
+    play_trick_as(&env, &client, 1510, &players, &[1, CANNOT_FOLLOW_SENTINEL]);
+    let game = client.get_game_debug(&1510);
+    assert_eq!(game.seat(2).hand, vec![&env, 21, 22, 10, 19, 2]);
+    assert_eq!(game.flipped_card, Some(11));
+    assert_eq!(game.draw_pile, vec![&env, 12]);
+}
+
+#[test]
+fn draw_until_follow_ends_game_on_empty_pile() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1, player2];
+    let config = GameConfig { draw_until_follow: true, ..GameConfig::standard() };
+    start_rigged_trick(&env, &client, 1511, &players, config, [&[1, 20], &[21, 22]], &[10, 19]);
+
+    play_trick_as(&env, &client, 1511, &players, &[1, CANNOT_FOLLOW_SENTINEL]);
+    let game = client.get_game_debug(&1511);
+    assert_eq!(game.seat(2).hand, vec![&env, 21, 22, 10, 19]);
+    assert_eq!(game.lifecycle_state, STATE_FINISHED);
+    assert_eq!(game.outcome, OUTCOME_PLAYER1_WIN);
+}
+
+#[test]
+fn blitz_clock_draws_on_time_bank_then_flags() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1.clone(), player2.clone()];
+    let config = GameConfig::blitz();
+    start_rigged_trick(&env, &client, 1513, &players, config.clone(), [&[1, 20], &[21, 22]], &[10, 19]);
+    let bank = config.time_bank_ledgers;
+    assert_eq!(client.get_game_debug(&1513).time_banks, vec![&env, bank, bank]);
+
+    // Three ledgers late: paid from player 1's bank
+    let game = client.get_game_debug(&1513);
+    advance_ledger(&env, game.deadline_ledger.unwrap() + 3 - env.ledger().sequence());
+    let commit = compute_play_commit(&env, 1, &test_salt(&env, 0));
+    client.commit_play(&1513, &player1, &commit, &game.action_nonce);
+    let game = client.get_game_debug(&1513);
+    assert_eq!(game.time_banks, vec![&env, bank - 3, bank]);
+
+    // Player 2 is flagged once the deadline and the whole bank have passed
+    let outsider = Address::generate(&env);
+    advance_ledger(&env, game.deadline_ledger.unwrap() + bank - env.ledger().sequence());
+    assert_cangkulan_error(&client.try_resolve_timeout(&1513, &outsider), CangkulanError::TimeoutNotReached);
+    advance_ledger(&env, 1);
+    let commit = compute_play_commit(&env, CANNOT_FOLLOW_SENTINEL, &test_salt(&env, 1));
+    let late = client.try_commit_play(&1513, &player2, &commit, &game.action_nonce);
+    assert_cangkulan_error(&late, CangkulanError::TimeBankExhausted);
+    client.resolve_timeout(&1513, &outsider);
+    assert_eq!(client.get_game_debug(&1513).outcome, OUTCOME_PLAYER1_WIN);
+}
+
+#[test]
+fn strict_reveal_shares_one_deadline() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let players = [player1.clone(), player2.clone()];
+    let config = GameConfig { strict_reveal: true, ..GameConfig::standard() };
+    start_rigged_trick(&env, &client, 1512, &players, config, [&[1, 20], &[21, 22]], &[10, 19]);
+
+    let actions = [1, CANNOT_FOLLOW_SENTINEL];
+    for (i, (player, action)) in players.iter().zip(actions).enumerate() {
+        let nonce = client.get_game_debug(&1512).action_nonce;
+        client.commit_play(&1512, player, &compute_play_commit(&env, action, &test_salt(&env, i as u8)), &nonce);
+    }
+    let deadline = client.get_game_debug(&1512).deadline_ledger;
+    client.reveal_play(&1512, &player1, &1, &test_salt(&env, 0));
+    // The first reveal does not restart the clock for the second
+    assert_eq!(client.get_game_debug(&1512).deadline_ledger, deadline);
+
+    advance_ledger(&env, deadline.unwrap() - env.ledger().sequence());
+    let result = client.try_reveal_play(&1512, &player2, &CANNOT_FOLLOW_SENTINEL, &test_salt(&env, 1));
+    assert_cangkulan_error(&result, CangkulanError::RevealDeadlinePassed);
+    client.resolve_timeout(&1512, &player1);
+    assert_eq!(client.get_game_debug(&1512).outcome, OUTCOME_PLAYER1_WIN);
+}
@@ -0,0 +1,285 @@
+#![no_std]
+
+//! # Cangkulan Common
+//!
+//! Types, checks and constants shared by the Cangkulan game contract and
+//! the ZK verifier. This crate is a plain library: it exports no contract
+//! functions of its own.
+//!
+//! ## Seed entropy policy
+//!
+//! Both contracts reject seed hashes that look trivially predictable before
+//! they are used to shuffle the deck. What counts as "trivially predictable"
+//! is an [`EntropyPolicy`] that each contract keeps in instance storage, so
+//! the admin can tighten or relax it with one call instead of a wasm upgrade
+//! of both contracts.
+//!
+//! | Check            | Field            | Rejects                                              |
+//! |------------------|------------------|------------------------------------------------------|
+//! | Distinct bytes   | `min_distinct`   | fewer than `min_distinct` distinct byte values        |
+//! | Repeated runs    | `max_run`        | a run of identical bytes longer than `max_run`        |
+//! | Short period     | `max_period`     | a seed that repeats with period `<= max_period`       |
+//! | Nibble chi-square| `max_chi_square` | nibble histogram with chi-square above the threshold |
+//!
+//! A zero `max_run`, `max_period` or `max_chi_square` disables that check.
+//! The default policy only requires 4 distinct bytes, which is the rule both
+//! contracts hardcoded before the policy existed.
+//!
+//! ## Deck parameters
+//!
+//! Modes 7 and 8 check card ids against a [`DeckParams`]: the deck size,
+//! cards per suit (card `c` has suit `c / cards_per_suit`), and the largest
+//! ring and hand a proof may cover. The default is this game's 36-card
+//! deck; [`DeckParams::standard`] is the 52-card deck. The verifier keeps
+//! one in instance storage, so a 52-card variant of the game reuses it.
+//!
+//! ## Verifier specification
+//!
+//! [`spec`] holds the verifier's mode numbers, Fiat-Shamir tags and byte
+//! layouts, re-exported as `zk_verifier::spec`, so Rust provers never
+//! hardcode magic bytes such as `"ZKP7"`.
+//!
+//! ## Deterministic deal
+//!
+//! [`deal_seed`] and [`shuffled_deck`] are the game's shuffle, shared so the
+//! verifier can re-derive the deck a hand commitment must open to (Mode 18)
+//! without trusting the client. [`deal_seed_n`] is the same seed for games
+//! with three or four seats.
+//!
+//! ## Poseidon transcripts (`poseidon-fs` feature)
+//!
+//! [`poseidon`] holds the Poseidon2 sponge that replaces keccak256 in the
+//! verifier's field-valued challenges, so the verifier and the prover build
+//! it from one copy.
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Vec};
+
+pub mod spec;
+#[cfg(feature = "poseidon-fs")]
+pub mod poseidon;
+
+/// Distinct-byte floor of the default policy.
+pub const DEFAULT_MIN_DISTINCT: u32 = 4;
+
+/// Longest period `max_period` may ask to reject. A period above half the
+/// seed length only says the two halves overlap, not that the seed is weak.
+pub const MAX_PERIOD_LIMIT: u32 = 16;
+
+/// Seed entropy rules, stored in instance storage by each contract that
+/// checks seeds. See the crate docs for what each field rejects.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntropyPolicy {
+    /// Minimum number of distinct byte values (1..=32).
+    pub min_distinct: u32,
+    /// Longest allowed run of one repeated byte; 0 disables the check.
+    pub max_run: u32,
+    /// Reject seeds periodic with any period up to this (0..=16); 0 disables.
+    pub max_period: u32,
+    /// Upper bound on the chi-square statistic of the 64 nibbles over 16
+    /// bins; 0 disables. Uniform seeds average 15 (15 degrees of freedom).
+    pub max_chi_square: u32,
+}
+
+/// Which policy rule a seed broke.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EntropyViolation {
+    TooFewDistinct,
+    RunTooLong,
+    Periodic,
+    ChiSquareTooHigh,
+}
+
+impl Default for EntropyPolicy {
+    fn default() -> Self {
+        EntropyPolicy {
+            min_distinct: DEFAULT_MIN_DISTINCT,
+            max_run: 0,
+            max_period: 0,
+            max_chi_square: 0,
+        }
+    }
+}
+
+impl EntropyPolicy {
+    /// Whether the policy can be stored: every bound is in range, so no
+    /// policy can silently reject every possible seed.
+    pub fn is_valid(&self) -> bool {
+        (1..=32).contains(&self.min_distinct)
+            && self.max_run <= 32
+            && self.max_period <= MAX_PERIOD_LIMIT
+    }
+
+    /// Check a 32-byte seed hash against every enabled rule.
+    pub fn check(&self, seed: &[u8; 32]) -> Result<(), EntropyViolation> {
+        if distinct_bytes(seed) < self.min_distinct {
+            return Err(EntropyViolation::TooFewDistinct);
+        }
+        if self.max_run > 0 && longest_run(seed) > self.max_run {
+            return Err(EntropyViolation::RunTooLong);
+        }
+        if self.max_period > 0 && shortest_period(seed) <= self.max_period {
+            return Err(EntropyViolation::Periodic);
+        }
+        if self.max_chi_square > 0 && nibble_chi_square_x4(seed) > self.max_chi_square.saturating_mul(4) {
+            return Err(EntropyViolation::ChiSquareTooHigh);
+        }
+        Ok(())
+    }
+}
+
+/// Card encoding and size bounds for the Mode 7 and 8 checks. See the
+/// crate docs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeckParams {
+    /// Cards in the deck; ids run `0..deck_size`.
+    pub deck_size: u32,
+    /// Cards per suit; `deck_size` is a whole number of suits.
+    pub cards_per_suit: u32,
+    /// Largest Mode 7/12 ring, and the member count of a padded ring.
+    pub max_ring_size: u32,
+    /// Largest Mode 8 hand.
+    pub max_hand_size: u32,
+}
+
+impl Default for DeckParams {
+    /// The 36-card Cangkulan deck: 4 suits of 9, hands of up to 18.
+    fn default() -> Self {
+        DeckParams {
+            deck_size: spec::SHUFFLE_DECK_SIZE,
+            cards_per_suit: spec::RING_MAX_SET_SIZE,
+            max_ring_size: spec::RING_MAX_SET_SIZE,
+            max_hand_size: 18,
+        }
+    }
+}
+
+impl DeckParams {
+    /// The standard 52-card deck: 4 suits of 13, hands of up to 26.
+    pub const fn standard() -> Self {
+        DeckParams { deck_size: 52, cards_per_suit: 13, max_ring_size: 13, max_hand_size: 26 }
+    }
+
+    /// Whether the parameters can be stored: a non-empty deck of whole suits
+    /// up to `DECK_MAX_SIZE`, rings no larger than a suit or
+    /// `RING_SIZE_LIMIT`, and hands no larger than the deck.
+    pub fn is_valid(&self) -> bool {
+        (1..=spec::DECK_MAX_SIZE).contains(&self.deck_size)
+            && self.cards_per_suit > 0
+            && self.deck_size.is_multiple_of(self.cards_per_suit)
+            && (1..=self.cards_per_suit.min(spec::RING_SIZE_LIMIT)).contains(&self.max_ring_size)
+            && (1..=self.deck_size).contains(&self.max_hand_size)
+    }
+
+    pub fn suits(&self) -> u32 {
+        self.deck_size / self.cards_per_suit
+    }
+
+    /// Suit of a card id below `deck_size`.
+    pub fn suit_of(&self, card: u32) -> u32 {
+        card / self.cards_per_suit
+    }
+}
+
+fn distinct_bytes(seed: &[u8; 32]) -> u32 {
+    let mut seen = [false; 256];
+    let mut distinct = 0;
+    for &b in seed {
+        if !seen[b as usize] {
+            seen[b as usize] = true;
+            distinct += 1;
+        }
+    }
+    distinct
+}
+
+fn longest_run(seed: &[u8; 32]) -> u32 {
+    let mut longest = 1;
+    let mut run = 1;
+    for i in 1..32 {
+        run = if seed[i] == seed[i - 1] { run + 1 } else { 1 };
+        longest = longest.max(run);
+    }
+    longest
+}
+
+/// Smallest `p` with `seed[i] == seed[i + p]` for every `i`; 32 if none.
+fn shortest_period(seed: &[u8; 32]) -> u32 {
+    for p in 1..32 {
+        if (0..32 - p).all(|i| seed[i] == seed[i + p]) {
+            return p as u32;
+        }
+    }
+    32
+}
+
+/// Four times the nibble chi-square statistic, which keeps it integral:
+/// 64 nibbles over 16 bins expect 4 per bin, so `chi2 = sum((o - 4)^2) / 4`.
+fn nibble_chi_square_x4(seed: &[u8; 32]) -> u32 {
+    let mut bins = [0i32; 16];
+    for &b in seed {
+        bins[(b >> 4) as usize] += 1;
+        bins[(b & 0x0f) as usize] += 1;
+    }
+    bins.iter().map(|&o| ((o - 4) * (o - 4)) as u32).sum()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Deterministic deal
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Shuffle seed of a session: keccak256(seed_hash1 || seed_hash2 || session_id).
+pub fn deal_seed(env: &Env, seed_hash1: &BytesN<32>, seed_hash2: &BytesN<32>, session_id: u32) -> BytesN<32> {
+    let mut seed_data = Bytes::from_array(env, &seed_hash1.to_array());
+    seed_data.append(&Bytes::from_array(env, &seed_hash2.to_array()));
+    seed_data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    env.crypto().keccak256(&seed_data).into()
+}
+
+/// Shuffle seed of a session with any number of seats:
+/// keccak256(seed_hash_1 || … || seed_hash_n || session_id). Equal to
+/// [`deal_seed`] for two seats.
+pub fn deal_seed_n(env: &Env, seed_hashes: &Vec<BytesN<32>>, session_id: u32) -> BytesN<32> {
+    let mut seed_data = Bytes::new(env);
+    for seed_hash in seed_hashes.iter() {
+        seed_data.append(&Bytes::from_array(env, &seed_hash.to_array()));
+    }
+    seed_data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    env.crypto().keccak256(&seed_data).into()
+}
+
+/// Deck order for a shuffle seed: Fisher-Yates over cards 0..36, drawing
+/// from the PRNG reseeded with `seed`. Reseeds the calling contract's PRNG.
+pub fn shuffled_deck(env: &Env, seed: &BytesN<32>) -> [u32; spec::SHUFFLE_DECK_SIZE as usize] {
+    let mut deck = [0u32; spec::SHUFFLE_DECK_SIZE as usize];
+    shuffle_into(env, seed, &mut deck);
+    deck
+}
+
+/// [`shuffled_deck`] over cards `0..deck_size`, for decks other than the
+/// 36 cards of Mode 18; the same for `deck_size == 36`. Panics above
+/// `DECK_MAX_SIZE`.
+pub fn shuffled_deck_n(env: &Env, seed: &BytesN<32>, deck_size: u32) -> Vec<u32> {
+    let mut cards = [0u32; spec::DECK_MAX_SIZE as usize];
+    let deck = &mut cards[..deck_size as usize];
+    shuffle_into(env, seed, deck);
+    Vec::from_slice(env, deck)
+}
+
+fn shuffle_into(env: &Env, seed: &BytesN<32>, deck: &mut [u32]) {
+    env.prng().seed(seed.clone().into());
+
+    for (i, card) in deck.iter_mut().enumerate() {
+        *card = i as u32;
+    }
+    let mut idx = deck.len() as u32;
+    while idx > 1 {
+        idx -= 1;
+        let j = env.prng().gen_range::<u64>(0..=(idx as u64)) as u32;
+        deck.swap(idx as usize, j as usize);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,96 @@
+//! Poseidon2 over BLS12-381 Fr (t = 3, x^5, 8 full + 56 partial rounds) and
+//! the sponge behind the verifier's `poseidon-fs` transcripts. Shared so the
+//! verifier and `cangkulan-prover` hash a transcript the same way.
+
+use soroban_sdk::crypto::bls12_381::Fr;
+use soroban_sdk::{Bytes, BytesN, Env, Symbol, Vec, U256};
+
+/// Full and partial round counts.
+pub const ROUNDS_F: u32 = 8;
+pub const ROUNDS_P: u32 = 56;
+
+/// Internal matrix diagonal minus one.
+const DIAG_M_1: [u32; 3] = [1, 1, 2];
+
+/// Round constants, 64 rows × 3 big-endian Fr elements. These are the
+/// HorizenLabs reference constants for the t = 3 BLS12-381 instance, the same
+/// set the Soroban host tests against; the verifier's
+/// `test_poseidon2_known_answer` pins them.
+const RC: &[u8; 64 * 3 * 32] = include_bytes!("poseidon2_bls12_381_t3.bin");
+
+/// Transcript bytes absorbed per rate element (31 bytes always fit below r).
+const CHUNK: u32 = 31;
+
+/// Poseidon2 sponge, rate 2, capacity 1 (state[0], seeded with the
+/// preimage length in bytes). The preimage is split into 31-byte
+/// big-endian chunks, absorbed two per permutation with a zero chunk
+/// padding the last pair; the digest is state[1] after the final
+/// permutation.
+pub fn sponge(env: &Env, preimage: &Bytes) -> BytesN<32> {
+    let hazmat = env.crypto_hazmat();
+    let field = Symbol::new(env, "BLS12_381");
+    let (diag, rc) = params(env);
+
+    let len = preimage.len();
+    let mut state: Vec<U256> = Vec::new(env);
+    state.push_back(U256::from_u32(env, len));
+    state.push_back(U256::from_u32(env, 0));
+    state.push_back(U256::from_u32(env, 0));
+
+    let mut offset = 0u32;
+    loop {
+        let mut lane = 1u32;
+        while lane < 3 {
+            let end = (offset + CHUNK).min(len);
+            let mut arr = [0u8; 32];
+            preimage
+                .slice(offset..end)
+                .copy_into_slice(&mut arr[(32 - (end - offset)) as usize..]);
+            let chunk = Fr::from_bytes(BytesN::from_array(env, &arr));
+            let absorbed = Fr::from_u256(state.get_unchecked(lane)) + chunk;
+            state.set(lane, absorbed.to_u256());
+            offset = end;
+            lane += 1;
+        }
+        state = hazmat.poseidon2_permutation(
+            &state,
+            field.clone(),
+            3,
+            5,
+            ROUNDS_F,
+            ROUNDS_P,
+            &diag,
+            &rc,
+        );
+        if offset >= len {
+            break;
+        }
+    }
+
+    Fr::from_u256(state.get_unchecked(1)).to_bytes()
+}
+
+/// Poseidon2 internal diagonal (minus one) and round constants in the
+/// shape `poseidon2_permutation` expects.
+pub fn params(env: &Env) -> (Vec<U256>, Vec<Vec<U256>>) {
+    let mut diag: Vec<U256> = Vec::new(env);
+    for d in DIAG_M_1 {
+        diag.push_back(U256::from_u32(env, d));
+    }
+    let mut rc: Vec<Vec<U256>> = Vec::new(env);
+    let mut row = 0usize;
+    while row < (ROUNDS_F + ROUNDS_P) as usize {
+        let mut constants: Vec<U256> = Vec::new(env);
+        let mut col = 0usize;
+        while col < 3 {
+            let start = (row * 3 + col) * 32;
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&RC[start..start + 32]);
+            constants.push_back(U256::from_be_bytes(env, &Bytes::from_array(env, &arr)));
+            col += 1;
+        }
+        rc.push_back(constants);
+        row += 1;
+    }
+    (diag, rc)
+}
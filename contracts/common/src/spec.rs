@@ -0,0 +1,433 @@
+//! # Verifier wire specification
+//!
+//! Mode numbers, Fiat-Shamir domain tags and byte layouts of the ZK
+//! verifier, for provers and tooling that build proofs in Rust. The verifier
+//! re-exports this module as `zk_verifier::spec` and uses these items
+//! directly, so a value here can never drift from what is checked on-chain.
+//!
+//! G1 points are 96 bytes and G2 points 192 bytes (uncompressed), Fr scalars
+//! are 32 bytes big-endian and must be canonical, u32s are 4 bytes
+//! big-endian. Modes 4, 7, 8 and 12 also accept 48-byte compressed G1
+//! points wherever their layouts below show one. Any layout ending in
+//! `session_id(4) || player(var)` may carry a ledger window after the player.
+//! Proofs for the auto-detecting entrypoints are prefixed with a one-byte
+//! format tag (see `PROOF_FORMAT_V1`) naming their mode.
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Mode numbers (as reported by `EvVerifySuccess`)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub const MODE_NIZK_SEED: u32 = 2;
+pub const MODE_PEDERSEN_SEED: u32 = 4;
+pub const MODE_CARD_RING: u32 = 7;
+pub const MODE_CANGKUL_HAND: u32 = 8;
+pub const MODE_PLONK: u32 = 9;
+pub const MODE_RANGE: u32 = 10;
+pub const MODE_MERKLE: u32 = 11;
+pub const MODE_HIDDEN_RING: u32 = 12;
+pub const MODE_PRIVATE_HAND: u32 = 13;
+pub const MODE_SHUFFLE: u32 = 14;
+pub const MODE_ELGAMAL: u32 = 15;
+pub const MODE_CARD_COMPARE: u32 = 16;
+pub const MODE_COMMIT_EQ: u32 = 17;
+pub const MODE_DEAL: u32 = 18;
+pub const MODE_THRESHOLD_SEED: u32 = 19;
+pub const MODE_BLS_ATTEST: u32 = 20;
+pub const MODE_HAND_COUNT: u32 = 21;
+pub const MODE_ULTRAHONK: u32 = 22;
+pub const MODE_VECTOR_COMMIT: u32 = 23;
+pub const MODE_LINKED_PLAY: u32 = 24;
+pub const MODE_ED25519_SEED: u32 = 25;
+pub const MODE_TRICK_BUNDLE: u32 = 26;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Proof format tag
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// A tagged proof is `tag(1) || layout`, where `layout` is the mode's
+/// untagged proof below and `tag = PROOF_FORMAT_V1 | mode`. The top two bits
+/// of the tag carry the format version, the low six the mode number.
+pub const PROOF_FORMAT_V1: u8 = 0x80;
+
+/// Version bits of a proof tag.
+pub const PROOF_FORMAT_VERSION_MASK: u8 = 0xC0;
+
+/// Mode bits of a proof tag.
+pub const PROOF_FORMAT_MODE_MASK: u8 = 0x3F;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Domain Separators (prevent cross-protocol attacks)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Domain separator for nullifier derivation: ASCII "NULL" = 0x4E554C4C
+pub const NULLIFIER_TAG: [u8; 4] = *b"NULL";
+
+/// Domain separator for Fiat-Shamir challenge: ASCII "ZKV2" = 0x5A4B5632
+pub const CHALLENGE_TAG: [u8; 4] = *b"ZKV2";
+
+/// Domain separator for Pedersen+Sigma Fiat-Shamir: ASCII "ZKP4" = 0x5A4B5034
+pub const PEDERSEN_CHALLENGE_TAG: [u8; 4] = *b"ZKP4";
+
+/// Domain separator for Card Play Ring Sigma Fiat-Shamir: ASCII "ZKP7" = 0x5A4B5037
+pub const RING_CHALLENGE_TAG: [u8; 4] = *b"ZKP7";
+
+/// Domain separator for Cangkul Hand Proof Fiat-Shamir: ASCII "ZKP8" = 0x5A4B5038
+pub const CANGKUL_CHALLENGE_TAG: [u8; 4] = *b"ZKP8";
+
+/// Domain separator for the PLONK transcript: ASCII "ZKP9" = 0x5A4B5039
+pub const PLONK_CHALLENGE_TAG: [u8; 4] = *b"ZKP9";
+
+/// Domain separator for Range Proof Fiat-Shamir: ASCII "ZKPA" = 0x5A4B5041
+pub const RANGE_CHALLENGE_TAG: [u8; 4] = *b"ZKPA";
+
+/// Domain separator for Hidden-Set Ring Fiat-Shamir: ASCII "ZKPB" = 0x5A4B5042
+pub const HIDDEN_RING_CHALLENGE_TAG: [u8; 4] = *b"ZKPB";
+
+/// Domain separator for private cangkul hand challenges (Mode 13): ASCII "ZKPC"
+pub const PRIVATE_HAND_CHALLENGE_TAG: [u8; 4] = *b"ZKPC";
+
+/// Domain separator for shuffle permutation challenges (Mode 14): ASCII "ZKPD"
+pub const SHUFFLE_CHALLENGE_TAG: [u8; 4] = *b"ZKPD";
+
+/// Domain separator for ElGamal share challenges (Mode 15): ASCII "ZKPE"
+pub const ELGAMAL_CHALLENGE_TAG: [u8; 4] = *b"ZKPE";
+
+/// Domain separator for card comparison challenges (Mode 16): ASCII "ZKPF"
+pub const CARD_COMPARE_CHALLENGE_TAG: [u8; 4] = *b"ZKPF";
+
+/// Domain separator for commitment equality challenges (Mode 17): ASCII "ZKPG"
+pub const COMMIT_EQ_CHALLENGE_TAG: [u8; 4] = *b"ZKPG";
+
+/// Domain separator for deal consistency challenges (Mode 18): ASCII "ZKPH"
+pub const DEAL_CHALLENGE_TAG: [u8; 4] = *b"ZKPH";
+
+/// Domain separator for threshold seed challenges (Mode 19): ASCII "ZKPI"
+pub const THRESHOLD_CHALLENGE_TAG: [u8; 4] = *b"ZKPI";
+
+/// Domain separator for hand count challenges (Mode 21): ASCII "ZKPJ"
+pub const HAND_COUNT_CHALLENGE_TAG: [u8; 4] = *b"ZKPJ";
+
+/// Domain separator for vector commitment openings (Mode 23): ASCII "ZKPK"
+pub const VECTOR_CHALLENGE_TAG: [u8; 4] = *b"ZKPK";
+
+/// Domain separator for linked card plays (Mode 24): ASCII "ZKPL"
+pub const LINKED_CHALLENGE_TAG: [u8; 4] = *b"ZKPL";
+
+/// Domain separator for Ed25519 seed signatures (Mode 25): ASCII "ZKPM"
+pub const ED25519_CHALLENGE_TAG: [u8; 4] = *b"ZKPM";
+
+/// Domain separator for the second part of a trick bundle (Mode 26), in
+/// place of its own mode's tag: ASCII "ZKPN"
+pub const TRICK_CHALLENGE_TAG: [u8; 4] = *b"ZKPN";
+
+/// Bit 31 of the Mode 7 `N` field selects the hidden-set ring (Mode 12).
+pub const HIDDEN_RING_FLAG: u32 = 0x8000_0000;
+
+/// Bit 30 of the Mode 7/12 `N` field pads the ring to `RING_MAX_SET_SIZE`
+/// members, so the proof length no longer shows how many cards are listed.
+pub const RING_PADDED_FLAG: u32 = 0x4000_0000;
+
+/// Domain separation tag (DST) for hash_to_g1 to derive the Pedersen H generator.
+/// H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1")
+/// This is a nothing-up-my-sleeve construction: anyone can reproduce H.
+pub const PEDERSEN_H_MSG: &[u8] = b"PEDERSEN_H";
+pub const PEDERSEN_H_DST: &[u8] = b"SGS_CANGKULAN_V1";
+
+/// Dummy member of a padded ring: P_pad = hash_to_g1("RING_PAD", "SGS_CANGKULAN_V1").
+/// Its representation over G and H is unknown, so no card commitment opens to it.
+pub const RING_PAD_MSG: &[u8] = b"RING_PAD";
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Sigma-protocol layouts (Modes 2, 4, 7, 8)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Mode 2 proof: blinding(32) || response(32).
+pub const NIZK_PROOF_LEN: u32 = 64;
+
+/// Mode 2 public inputs before the player address:
+/// seed_hash(32) || commitment(32) || nullifier(32) || session_id(4).
+pub const NIZK_INPUTS_FIXED_LEN: u32 = 100;
+
+/// Mode 4 proof: R(96, G1) || z_r(32, Fr).
+pub const PEDERSEN_PROOF_LEN: u32 = 96 + 32;
+
+/// Mode 7 proof: C(96, G1) followed by e_i(32) || z_i(32) per ring member.
+pub const RING_PROOF_BASE_LEN: u32 = 96;
+pub const RING_MEMBER_PROOF_LEN: u32 = 64;
+
+/// Largest Mode 7 valid set (cards per suit) of the 36-card deck, and the
+/// member count of a padded ring under the default `DeckParams`.
+pub const RING_MAX_SET_SIZE: u32 = 9;
+
+/// Largest ring any `DeckParams` may allow. A 15-member Mode 7 proof is as
+/// long as a Mode 9 proof, so rings stay at a standard suit of 13.
+pub const RING_SIZE_LIMIT: u32 = 13;
+
+/// Largest deck any `DeckParams` may describe (the standard 52 cards).
+pub const DECK_MAX_SIZE: u32 = 52;
+
+/// Mode 8 proof: k(4) || A(96, G1) || R(96, G1) || z(32, Fr).
+pub const CANGKUL_HAND_PROOF_LEN: u32 = 4 + 96 + 96 + 32;
+
+/// Compressed G1 point: x(48, big-endian) with flags in the top three bits
+/// of the first byte (0x80 compressed, 0x40 infinity, 0x20 larger y).
+pub const G1_COMPRESSED_LEN: u32 = 48;
+
+/// Set in the first byte of every compressed G1 point, never in an
+/// uncompressed one.
+pub const G1_COMPRESSION_FLAG: u8 = 0x80;
+
+/// Optional ledger window after the player address of every mode with a
+/// `session_id || player` tail: `LEDGER_WINDOW_TAG(4) || valid_until_ledger(4)`.
+/// The leading zero byte never occurs in a strkey, so the trailer cannot be
+/// mistaken for part of the address.
+pub const LEDGER_WINDOW_TAG: [u8; 4] = *b"\0VUL";
+pub const LEDGER_WINDOW_LEN: u32 = 8;
+
+/// Optional transcript hash selector for Modes 2, 4, 7, 8 and 12, after the
+/// player address and before any ledger window: `TRANSCRIPT_HASH_TAG(4) ||
+/// hash_id(1)`. Without it the challenges use keccak256 (`fs_digest`).
+pub const TRANSCRIPT_HASH_TAG: [u8; 4] = *b"\0FSH";
+pub const TRANSCRIPT_HASH_LEN: u32 = 5;
+
+/// `hash_id` selecting sha256 challenges, which Soroban meters well below
+/// keccak256. No other id is defined.
+pub const TRANSCRIPT_HASH_SHA256: u8 = 1;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  PLONK layouts (Mode 9)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// PLONK proof: 9 G1 commitments (A, B, C, Z, T1, T2, T3, Wξ, Wξω) followed by
+/// 6 Fr evaluations (ā, b̄, c̄, s̄1, s̄2, z̄ω) = 9×96 + 6×32 bytes.
+pub const PLONK_PROOF_LEN: u32 = 9 * 96 + 6 * 32;
+
+/// PLONK verification key:
+/// `n(4) || n_public(4) || k1(32) || k2(32) || ω(32) ||
+///  Qm || Ql || Qr || Qo || Qc || S1 || S2 || S3 (8×96, G1) || X_2(192, G2)`
+pub const PLONK_VK_LEN: u32 = 4 + 4 + 3 * 32 + 8 * 96 + 192;
+
+pub const PLONK_VK_K1_OFFSET: u32 = 8;
+pub const PLONK_VK_K2_OFFSET: u32 = 40;
+pub const PLONK_VK_OMEGA_OFFSET: u32 = 72;
+pub const PLONK_VK_POINTS_OFFSET: u32 = 104;
+pub const PLONK_VK_X2_OFFSET: u32 = PLONK_VK_POINTS_OFFSET + 8 * 96;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Range proof layouts (Mode 10)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One bit of a range proof: B || R0 || R1 (3×96, G1) || e0 || z0 || z1 (3×32, Fr).
+pub const RANGE_BIT_PROOF_LEN: u32 = 3 * 96 + 3 * 32;
+
+/// Largest supported hi − lo (8-bit decompositions).
+pub const RANGE_MAX_WIDTH: u32 = 255;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Merkle membership layouts (Mode 11)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Public inputs: root(32) || suit(4) || C(96, G1).
+pub const MERKLE_INPUTS_LEN: u32 = 32 + 4 + 96;
+
+/// Deepest supported hand tree: 2^6 = 64 ≥ 36 leaves (the whole deck).
+pub const MERKLE_MAX_DEPTH: u32 = 6;
+
+/// Domain separation prefixes (RFC 6962 style) so a leaf can never be
+/// reinterpreted as an internal node.
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Private cangkul hand layouts (Mode 13)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One card: S || A || 9 first moves (11×96, G1) || (e_0..2 || z_0..2) × 3 (18×32, Fr).
+pub const PRIVATE_HAND_CARD_PROOF_LEN: u32 = 11 * 96 + 18 * 32;
+
+/// Offset of the scalars within one card's proof.
+pub const PRIVATE_HAND_SCALARS_OFFSET: u32 = 11 * 96;
+
+/// Most cards one Mode 13 proof covers within the default CPU budget.
+pub const PRIVATE_HAND_MAX_CHUNK: u32 = 4;
+
+/// Largest hand a Mode 13 proof can be bound to (same limit as Mode 8).
+pub const PRIVATE_HAND_MAX_CARDS: u32 = 18;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Shuffle permutation layouts (Mode 14)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Deck size proven by Mode 14.
+pub const SHUFFLE_DECK_SIZE: u32 = 36;
+
+/// Proof: c_d || c_δ || c_Δ (3×96, G1) || ã_1..36 || b̃_1..36 || r̃ || s̃ (74×32, Fr).
+pub const SHUFFLE_PROOF_LEN: u32 = 3 * 96 + (2 * SHUFFLE_DECK_SIZE + 2) * 32;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Mental-poker ElGamal layouts (Mode 15)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Proof: output point || A1 || A2 (3×96, G1) || z (32, Fr).
+pub const ELGAMAL_PROOF_LEN: u32 = 3 * 96 + 32;
+
+/// Statement kinds carried in the first 4 bytes of the public inputs.
+pub const ELGAMAL_KIND_DECRYPT: u32 = 0;
+pub const ELGAMAL_KIND_REENCRYPT: u32 = 1;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Card comparison layouts (Mode 16)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Public inputs before the session id: trick_suit(4) || winner(4) || C_0 || C_1 (2×96, G1).
+pub const CARD_COMPARE_INPUTS_FIXED_LEN: u32 = 4 + 4 + 2 * 96;
+
+/// Proof: R × 27 (27×96, G1) || (e_0..8 || z_0..8) × 3 (54×32, Fr).
+pub const CARD_COMPARE_PROOF_LEN: u32 = 27 * 96 + 54 * 32;
+
+/// Offset of the scalars within the proof.
+pub const CARD_COMPARE_SCALARS_OFFSET: u32 = 27 * 96;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Commitment equality layouts (Mode 17)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One pair: R || R' (2×96, G1) || z_v || z || z' (3×32, Fr). The proof is
+/// n(4) followed by n pairs.
+pub const COMMIT_EQ_PAIR_PROOF_LEN: u32 = 2 * 96 + 3 * 32;
+
+/// Most commitment pairs one Mode 17 proof covers.
+pub const COMMIT_EQ_MAX_PAIRS: u32 = 9;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Deal consistency layouts (Mode 18)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Cards dealt to each player; slot s holds deck positions 5s..5s+4.
+pub const DEAL_HAND_SIZE: u32 = 5;
+
+/// Public inputs before the session id:
+/// seed_hash1(32) || seed_hash2(32) || slot(4) || P_0..P_4 (5×96, G1).
+pub const DEAL_INPUTS_FIXED_LEN: u32 = 32 + 32 + 4 + DEAL_HAND_SIZE * 96;
+
+/// One card: R(96, G1) || z(32, Fr).
+pub const DEAL_CARD_PROOF_LEN: u32 = 96 + 32;
+
+/// Proof: one `DEAL_CARD_PROOF_LEN` entry per dealt card.
+pub const DEAL_PROOF_LEN: u32 = DEAL_HAND_SIZE * DEAL_CARD_PROOF_LEN;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Threshold seed layouts (Mode 19)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Public inputs header: n(4) || t(4) || opened mask(4).
+pub const THRESHOLD_INPUTS_HEADER_LEN: u32 = 12;
+
+/// One opened party: R(96, G1) || z(32, Fr). The proof is the opened mask(4)
+/// followed by one entry per opened party, in party order.
+pub const THRESHOLD_PARTY_PROOF_LEN: u32 = 96 + 32;
+
+/// Party count bounds; the threshold t is at least `THRESHOLD_MIN_PARTIES` too.
+pub const THRESHOLD_MIN_PARTIES: u32 = 2;
+pub const THRESHOLD_MAX_PARTIES: u32 = 8;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  BLS attestation layouts (Mode 20)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// hash_to_g2 domain separation tag for attestation messages.
+pub const BLS_ATTEST_DST: &[u8] = b"SGS_CANGKULAN_V1_BLS_ATTEST";
+
+/// Proof: the (aggregate) signature, one uncompressed G2 point.
+pub const BLS_SIGNATURE_LEN: u32 = 192;
+
+/// Most signers one aggregate signature may cover.
+pub const BLS_MAX_SIGNERS: u32 = 4;
+
+/// Longest attested message.
+pub const BLS_MAX_MESSAGE_LEN: u32 = 256;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Hand count layouts (Mode 21)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Message for the count generator K = hash_to_g1("HAND_COUNT_K", PEDERSEN_H_DST).
+/// Every card adds one K to the aggregate hand commitment.
+pub const HAND_COUNT_K_MSG: &[u8] = b"HAND_COUNT_K";
+
+/// Public inputs before the player address: A(96, G1) || k(4) || session_id(4).
+pub const HAND_COUNT_INPUTS_FIXED_LEN: u32 = 96 + 4 + 4;
+
+/// Proof: e(32, Fr) || z_s(32, Fr) || z_r(32, Fr).
+pub const HAND_COUNT_PROOF_LEN: u32 = 3 * 32;
+
+/// Largest provable count (the whole deck).
+pub const HAND_COUNT_MAX: u32 = 36;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  UltraHonk routing (Mode 22)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Proofs longer than this that match no native mode are Noir
+/// UltraKeccakHonk proofs, forwarded as-is to the registered UltraHonk
+/// verifier. Native modes above it have fixed lengths of at most 6528 bytes;
+/// UltraKeccakHonk proofs are ~14 KB.
+pub const ULTRAHONK_MIN_PROOF_LEN: u32 = 4096;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Vector commitment layouts (Mode 23)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Position generators H_i = hash_to_g1("VECTOR_H", VECTOR_H_DST_PREFIX || i(4, BE))
+/// for positions i = 0..k. The blinding generator is the Pedersen H.
+pub const VECTOR_H_MSG: &[u8] = b"VECTOR_H";
+pub const VECTOR_H_DST_PREFIX: &[u8] = b"SGS_CANGKULAN_V1_VECTOR_";
+
+/// Public inputs before the opened positions: V(96, G1) || k(4) || m(4).
+pub const VECTOR_INPUTS_HEADER_LEN: u32 = 96 + 4 + 4;
+
+/// Each opened position in the public inputs: position(4) || card(4).
+pub const VECTOR_OPENING_LEN: u32 = 8;
+
+/// Proof header: k(4) || m(4) || e(32, Fr) || z_r(32, Fr). One z_i(32, Fr)
+/// follows for each of the k − m unopened positions.
+pub const VECTOR_PROOF_HEADER_LEN: u32 = 4 + 4 + 32 + 32;
+
+/// Longest committable vector (half the deck, as for Mode 8 hands).
+pub const VECTOR_MAX_LEN: u32 = 18;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Linked card play layouts (Mode 24)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Proof header: N(4) || k(4) || C(48, compressed G1). Then e_i(32) || z_i(32)
+/// per valid card and f_j(32) || y_j(32) per hand commitment. C is always
+/// compressed, which keeps the length (≡ 24 mod 32) off every other mode.
+pub const LINKED_PROOF_HEADER_LEN: u32 = 4 + 4 + G1_COMPRESSED_LEN;
+
+/// Most ring members over both rings together, N + k, within the default budget.
+pub const LINKED_MAX_MEMBERS: u32 = 12;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Ed25519 seed layouts (Mode 25)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// First byte of every Mode 25 proof. It makes the length odd, which no
+/// other mode's proof is.
+pub const ED25519_PROOF_TAG: u8 = 0xED;
+
+/// Mode 25 proof: tag(1) || pk(32, Ed25519 public key) || signature(64).
+/// The public inputs are Mode 2's, with `pk` in place of the blinding.
+pub const ED25519_PROOF_LEN: u32 = 1 + 32 + 64;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Trick bundle layouts (Mode 26)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Mode 26 public inputs: mode_a(4) || len_a(4) || inputs_a || mode_b(4) ||
+/// inputs_b, each part a Mode 7 or Mode 8 statement.
+pub const TRICK_INPUTS_HEADER_LEN: u32 = 4 + 4;
+
+/// Mode 26 proof: len_a(4) || proof_a || proof_b. Mode 26 has no length
+/// rule, so it is only accepted behind its format tag.
+pub const TRICK_PROOF_HEADER_LEN: u32 = 4;
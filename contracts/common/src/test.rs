@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use crate::spec::*;
+use crate::{deal_seed, deal_seed_n, shuffled_deck, shuffled_deck_n, DeckParams, EntropyPolicy, EntropyViolation};
+use soroban_sdk::{contract, vec, BytesN, Env};
+
+/// Context for PRNG calls, which only work inside a contract.
+#[contract]
+struct Host;
+
+/// Every nibble appears exactly 4 times and no two bytes repeat in order,
+/// so this seed passes every rule at its strictest useful setting.
+fn balanced_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    for (i, b) in seed.iter_mut().enumerate() {
+        let hi = i % 16;
+        let lo = (i * 7 + 3 + i / 16) % 16;
+        *b = ((hi << 4) | lo) as u8;
+    }
+    seed
+}
+
+fn strict() -> EntropyPolicy {
+    EntropyPolicy { min_distinct: 16, max_run: 2, max_period: 16, max_chi_square: 40 }
+}
+
+#[test]
+fn test_default_policy_matches_legacy_rule() {
+    let policy = EntropyPolicy::default();
+    assert!(policy.is_valid());
+    assert_eq!(policy.check(&[0u8; 32]), Err(EntropyViolation::TooFewDistinct));
+
+    let mut three = [0u8; 32];
+    three[1] = 1;
+    three[2] = 2;
+    assert_eq!(policy.check(&three), Err(EntropyViolation::TooFewDistinct));
+    three[3] = 3;
+    assert_eq!(policy.check(&three), Ok(()), "4 distinct bytes is enough by default");
+}
+
+#[test]
+fn test_strict_policy_accepts_balanced_seed() {
+    assert_eq!(strict().check(&balanced_seed()), Ok(()));
+}
+
+#[test]
+fn test_run_and_period_rules() {
+    let mut run = balanced_seed();
+    run[5] = run[4];
+    run[6] = run[4];
+    assert_eq!(strict().check(&run), Err(EntropyViolation::RunTooLong));
+
+    // 0,1,2,3 repeated: enough distinct bytes for the default, but period 4.
+    let mut periodic = [0u8; 32];
+    for (i, b) in periodic.iter_mut().enumerate() {
+        *b = (i % 4) as u8;
+    }
+    assert_eq!(EntropyPolicy::default().check(&periodic), Ok(()));
+    let policy = EntropyPolicy { max_period: 4, ..EntropyPolicy::default() };
+    assert_eq!(policy.check(&periodic), Err(EntropyViolation::Periodic));
+    let policy = EntropyPolicy { max_period: 3, ..EntropyPolicy::default() };
+    assert_eq!(policy.check(&periodic), Ok(()));
+}
+
+#[test]
+fn test_chi_square_rule() {
+    // 0..32 has 32 distinct bytes but nibbles 0 and 1 dominate (chi2 = 112).
+    let mut counting = [0u8; 32];
+    for (i, b) in counting.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let policy = EntropyPolicy { max_chi_square: 112, ..EntropyPolicy::default() };
+    assert_eq!(policy.check(&counting), Ok(()));
+    let policy = EntropyPolicy { max_chi_square: 111, ..EntropyPolicy::default() };
+    assert_eq!(policy.check(&counting), Err(EntropyViolation::ChiSquareTooHigh));
+}
+
+#[test]
+fn test_policy_bounds() {
+    assert!(strict().is_valid());
+    assert!(!EntropyPolicy { min_distinct: 0, ..EntropyPolicy::default() }.is_valid());
+    assert!(!EntropyPolicy { min_distinct: 33, ..EntropyPolicy::default() }.is_valid());
+    assert!(!EntropyPolicy { max_run: 33, ..EntropyPolicy::default() }.is_valid());
+    assert!(!EntropyPolicy { max_period: 17, ..EntropyPolicy::default() }.is_valid());
+}
+
+#[test]
+fn test_deck_params_bounds() {
+    let default = DeckParams::default();
+    assert!(default.is_valid() && DeckParams::standard().is_valid());
+    assert_eq!((default.suits(), default.suit_of(35)), (4, 3));
+    assert_eq!((DeckParams::standard().suits(), DeckParams::standard().suit_of(12)), (4, 0));
+    assert!(!DeckParams { deck_size: 0, ..default.clone() }.is_valid());
+    assert!(!DeckParams { deck_size: 56, cards_per_suit: 14, max_ring_size: 13, ..default.clone() }.is_valid());
+    assert!(!DeckParams { cards_per_suit: 0, ..default.clone() }.is_valid());
+    assert!(!DeckParams { cards_per_suit: 10, ..default.clone() }.is_valid());
+    assert!(!DeckParams { max_ring_size: 10, ..default.clone() }.is_valid());
+    assert!(!DeckParams { max_ring_size: 14, ..DeckParams::standard() }.is_valid());
+    assert!(!DeckParams { max_hand_size: 0, ..default.clone() }.is_valid());
+    assert!(!DeckParams { max_hand_size: 37, ..default }.is_valid());
+}
+
+#[test]
+fn test_spec_challenge_tags_are_distinct() {
+    let tags = [
+        NULLIFIER_TAG,
+        CHALLENGE_TAG,
+        PEDERSEN_CHALLENGE_TAG,
+        RING_CHALLENGE_TAG,
+        CANGKUL_CHALLENGE_TAG,
+        PLONK_CHALLENGE_TAG,
+        RANGE_CHALLENGE_TAG,
+        HIDDEN_RING_CHALLENGE_TAG,
+        PRIVATE_HAND_CHALLENGE_TAG,
+        SHUFFLE_CHALLENGE_TAG,
+        ELGAMAL_CHALLENGE_TAG,
+        CARD_COMPARE_CHALLENGE_TAG,
+        COMMIT_EQ_CHALLENGE_TAG,
+        DEAL_CHALLENGE_TAG,
+        THRESHOLD_CHALLENGE_TAG,
+        HAND_COUNT_CHALLENGE_TAG,
+        VECTOR_CHALLENGE_TAG,
+        LINKED_CHALLENGE_TAG,
+        ED25519_CHALLENGE_TAG,
+        TRICK_CHALLENGE_TAG,
+    ];
+    for (i, a) in tags.iter().enumerate() {
+        for b in &tags[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    assert_eq!(RING_CHALLENGE_TAG, [0x5A, 0x4B, 0x50, 0x37]);
+    assert_eq!(PLONK_PROOF_LEN, 1056);
+    assert_eq!(SHUFFLE_PROOF_LEN, 2656);
+    assert_eq!(CARD_COMPARE_PROOF_LEN, 4320);
+    assert_eq!(COMMIT_EQ_PAIR_PROOF_LEN, 288);
+    assert_eq!((DEAL_INPUTS_FIXED_LEN, DEAL_PROOF_LEN), (548, 640));
+}
+
+#[test]
+fn test_shuffled_deck_is_deterministic_permutation() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let sh1 = BytesN::from_array(&env, &[1u8; 32]);
+    let sh2 = BytesN::from_array(&env, &[2u8; 32]);
+    let deal = |sid: u32| env.as_contract(&host, || shuffled_deck(&env, &deal_seed(&env, &sh1, &sh2, sid)));
+
+    let deck = deal(7);
+    let mut seen = [false; SHUFFLE_DECK_SIZE as usize];
+    for &card in &deck {
+        assert!(!seen[card as usize], "card {card} dealt twice");
+        seen[card as usize] = true;
+    }
+    assert_eq!(deck, deal(7));
+    assert_ne!(deck, deal(8));
+}
+
+#[test]
+fn test_deal_seed_n_matches_two_seat_seed() {
+    let env = Env::default();
+    let sh1 = BytesN::from_array(&env, &[1u8; 32]);
+    let sh2 = BytesN::from_array(&env, &[2u8; 32]);
+    let sh3 = BytesN::from_array(&env, &[3u8; 32]);
+
+    let two = deal_seed_n(&env, &vec![&env, sh1.clone(), sh2.clone()], 7);
+    assert_eq!(two, deal_seed(&env, &sh1, &sh2, 7));
+    let three = deal_seed_n(&env, &vec![&env, sh1, sh2, sh3], 7);
+    assert_ne!(three, two);
+}
+
+#[test]
+fn test_shuffled_deck_n_matches_mode_18_deck() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let seed = BytesN::from_array(&env, &[5u8; 32]);
+
+    let (deck, same, full) = env.as_contract(&host, || {
+        (shuffled_deck(&env, &seed), shuffled_deck_n(&env, &seed, SHUFFLE_DECK_SIZE), shuffled_deck_n(&env, &seed, 52))
+    });
+    assert_eq!(same, soroban_sdk::Vec::from_slice(&env, &deck));
+    let mut seen = [false; DECK_MAX_SIZE as usize];
+    for card in full.iter() {
+        assert!(!seen[card as usize], "card {card} dealt twice");
+        seen[card as usize] = true;
+    }
+    assert_eq!(full.len(), 52);
+}
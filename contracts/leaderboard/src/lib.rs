@@ -8,9 +8,11 @@
 //! ## Features
 //! - ELO rating system (K=32 for new players, K=16 for established)
 //! - Win/loss/draw counters
-//! - Top-N leaderboard query
+//! - Extra rating penalty for forfeiting a game in progress
+//! - Top-N leaderboard query with a stable, total ranking order
 //! - Per-player stats query
 //! - Event emission for indexing
+//! - Sandbagging heuristics that flag players for moderator review
 
 use soroban_sdk::{
     contract, contracterror, contractevent, contractimpl, contracttype,
@@ -33,6 +35,28 @@ pub struct PlayerStats {
     pub win_streak: u32,
     pub best_streak: u32,
     pub last_updated: u64,
+    /// Registration order (0 = first player ever seen); final tiebreak.
+    pub registration: u32,
+    /// Bitmask of sandbagging heuristics (`FLAG_*`) that have fired.
+    /// Informational only: flags never change ratings or rankings.
+    pub flags: u32,
+    /// Number of times any heuristic fired for this player.
+    pub flag_count: u32,
+}
+
+/// One row of the ranking index. Carries the full sort key so ordering
+/// never depends on reading other players' stats.
+///
+/// Rank order: higher `elo`, then more `games_played`, then lower
+/// `registration` (earlier sign-up). `registration` is unique, so the
+/// order is total and repeated updates cannot make equal players swap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RankEntry {
+    pub address: Address,
+    pub elo: u32,
+    pub games_played: u32,
+    pub registration: u32,
 }
 
 #[contracttype]
@@ -43,8 +67,13 @@ pub enum DataKey {
     AuthorizedGames,
     /// Player stats: DataKey::Player(address) → PlayerStats
     Player(Address),
-    /// Sorted leaderboard index (top players by ELO)
-    TopPlayers,
+    /// Sorted leaderboard index: Vec<RankEntry> in rank order
+    TopIndex,
+    /// Number of players registered so far (next `registration` value)
+    PlayerCount,
+    /// Recent results between two players, keyed with the lower address
+    /// first: Vec<u32> of outcomes from that player's side, oldest first
+    Pair(Address, Address),
 }
 
 #[contracterror]
@@ -65,7 +94,7 @@ pub enum LeaderboardError {
 pub struct EvMatchRecorded {
     pub player1: Address,
     pub player2: Address,
-    pub outcome: u32, // 1 = P1 win, 2 = P2 win, 3 = draw
+    pub outcome: u32, // 1 = P1 win, 2 = P2 win, 3 = draw; may carry OUTCOME_FORFEIT
     pub p1_new_elo: u32,
     pub p2_new_elo: u32,
 }
@@ -76,6 +105,21 @@ pub struct EvPlayerRegistered {
     pub initial_elo: u32,
 }
 
+/// A sandbagging heuristic fired for `player`. Raised for moderator
+/// review; ratings are left untouched.
+#[contractevent]
+pub struct EvSandbagFlagged {
+    pub player: Address,
+    pub opponent: Address,
+    pub flag: u32,
+    pub elo: u32,
+}
+
+#[contractevent]
+pub struct EvFlagsCleared {
+    pub player: Address,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Constants
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -85,19 +129,45 @@ const K_NEW: u32 = 32;      // K-factor for players with < 30 games
 const K_ESTABLISHED: u32 = 16; // K-factor for players with >= 30 games
 const MAX_TOP_PLAYERS: u32 = 50;
 
+/// Flag OR-ed into a `record_match` win outcome when the loser forfeited a
+/// game in progress: 5 = P1 wins by forfeit, 6 = P2 wins by forfeit.
+pub const OUTCOME_FORFEIT: u32 = 4;
+
+/// Rating a forfeiting player loses on top of the loss itself.
+pub const FORFEIT_PENALTY: u32 = 16;
+
 // Ledger rate is approximately 5 seconds per ledger on Stellar
 const LEDGER_RATE_SECS: u32 = 5;
 
 // TTL expressed in human-readable time units (120 days)
 const TTL_SECONDS: u32 = 120 * 24 * 60 * 60;    // 10,368,000 seconds
-const TTL_MINUTES: u32 = TTL_SECONDS / 60;      // 172,800 minutes
-const TTL_HOURS: u32 = TTL_MINUTES / 60;        // 2,880 hours
-const TTL_DAYS: u32 = TTL_HOURS / 24;           // 120 days
-const TTL_WEEKS: u32 = TTL_DAYS / 7;            // 17 weeks + 1 day
 
 /// TTL for player data in ledgers: 120 * 24 * 60 * 60 / 5 = 2,073,600 ledgers
 const TTL_LEDGERS: u32 = TTL_SECONDS / LEDGER_RATE_SECS;
 
+/// Tier boundaries shown by the frontend (Beginner, Intermediate, Expert,
+/// Grandmaster).
+const TIER_BOUNDARIES: [u32; 4] = [1000, 1200, 1400, 1600];
+
+/// Within this many points of a boundary a rating counts as "near" it
+/// (one K_NEW swing).
+const TIER_MARGIN: u32 = 32;
+
+/// Results kept per pair for the alternation check.
+const PAIR_WINDOW: u32 = 4;
+
+/// Minimum rating gap for a boundary-crossing loss to look deliberate
+/// (the loser's expected score is 76% or more).
+const UPSET_GAP: u32 = 200;
+
+/// The last `PAIR_WINDOW` results between two players alternate wins and
+/// losses while one of them sits near a tier boundary (rating trading).
+pub const FLAG_ALTERNATING: u32 = 1 << 0;
+
+/// A player dropped below a tier boundary by losing to an opponent rated
+/// `UPSET_GAP` or more below them.
+pub const FLAG_TIER_DROP: u32 = 1 << 1;
+
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Contract
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -112,8 +182,8 @@ impl Leaderboard {
         env.storage().instance().set(&DataKey::Admin, &admin);
         let empty_games: Vec<Address> = Vec::new(&env);
         env.storage().instance().set(&DataKey::AuthorizedGames, &empty_games);
-        let empty_top: Vec<Address> = Vec::new(&env);
-        env.storage().instance().set(&DataKey::TopPlayers, &empty_top);
+        let empty_top: Vec<RankEntry> = Vec::new(&env);
+        env.storage().instance().set(&DataKey::TopIndex, &empty_top);
     }
 
     /// Add a game contract address that's allowed to report results
@@ -135,7 +205,9 @@ impl Leaderboard {
     }
 
     /// Record a match result. Called by an authorized game contract or admin.
-    /// outcome: 1 = player1 wins, 2 = player2 wins, 3 = draw
+    /// outcome: 1 = player1 wins, 2 = player2 wins, 3 = draw, with
+    /// `OUTCOME_FORFEIT` set when the loser quit; they then lose another
+    /// `FORFEIT_PENALTY` points. The flag is ignored on a draw.
     pub fn record_match(
         env: Env,
         caller: Address,
@@ -162,20 +234,28 @@ impl Leaderboard {
             panic_with_error!(&env, LeaderboardError::SelfPlay);
         }
 
+        let forfeit = outcome & OUTCOME_FORFEIT != 0;
+        let result = outcome & !OUTCOME_FORFEIT;
         let now = env.ledger().timestamp();
 
         // Get or create stats for both players
         let mut stats1 = Self::get_or_create_stats(&env, &player1, now);
         let mut stats2 = Self::get_or_create_stats(&env, &player2, now);
+        let (old_elo1, old_elo2) = (stats1.elo, stats2.elo);
 
         // Calculate ELO changes
-        let (new_elo1, new_elo2) = Self::calculate_elo(
+        let (mut new_elo1, mut new_elo2) = Self::calculate_elo(
             stats1.elo,
             stats2.elo,
-            outcome,
+            result,
             if stats1.games_played < 30 { K_NEW } else { K_ESTABLISHED },
             if stats2.games_played < 30 { K_NEW } else { K_ESTABLISHED },
         );
+        match result {
+            1 if forfeit => new_elo2 = new_elo2.saturating_sub(FORFEIT_PENALTY).max(100),
+            2 if forfeit => new_elo1 = new_elo1.saturating_sub(FORFEIT_PENALTY).max(100),
+            _ => {}
+        }
 
         // Update stats
         stats1.elo = new_elo1;
@@ -185,7 +265,7 @@ impl Leaderboard {
         stats1.last_updated = now;
         stats2.last_updated = now;
 
-        match outcome {
+        match result {
             1 => {
                 stats1.wins += 1;
                 stats1.win_streak += 1;
@@ -213,13 +293,30 @@ impl Leaderboard {
             }
         }
 
+        // Sandbagging heuristics (flag only, never penalize)
+        if Self::record_pair_result(&env, &player1, &player2, result)
+            && (Self::near_tier_boundary(new_elo1) || Self::near_tier_boundary(new_elo2))
+        {
+            Self::flag(&env, &mut stats1, &player2, FLAG_ALTERNATING);
+            Self::flag(&env, &mut stats2, &player1, FLAG_ALTERNATING);
+        }
+        match result {
+            1 if Self::is_tier_drop(old_elo2, new_elo2, old_elo1) => {
+                Self::flag(&env, &mut stats2, &player1, FLAG_TIER_DROP);
+            }
+            2 if Self::is_tier_drop(old_elo1, new_elo1, old_elo2) => {
+                Self::flag(&env, &mut stats1, &player2, FLAG_TIER_DROP);
+            }
+            _ => {}
+        }
+
         // Persist
         Self::save_stats(&env, &stats1);
         Self::save_stats(&env, &stats2);
 
         // Update top players index
-        Self::update_top_players(&env, &player1, new_elo1);
-        Self::update_top_players(&env, &player2, new_elo2);
+        Self::update_top_players(&env, &stats1);
+        Self::update_top_players(&env, &stats2);
 
         // Emit event
         EvMatchRecorded {
@@ -238,21 +335,43 @@ impl Leaderboard {
             .get(&DataKey::Player(player))
     }
 
-    /// Get top N players by ELO rating
+    /// Clear a player's sandbagging flags after moderator review. Admin only.
+    pub fn clear_flags(env: Env, caller: Address, player: Address) {
+        caller.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            panic_with_error!(&env, LeaderboardError::NotAdmin);
+        }
+        let mut stats: PlayerStats = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Player(player.clone()))
+        {
+            Some(stats) => stats,
+            None => panic_with_error!(&env, LeaderboardError::PlayerNotFound),
+        };
+        stats.flags = 0;
+        stats.flag_count = 0;
+        Self::save_stats(&env, &stats);
+        EvFlagsCleared { player }.publish(&env);
+    }
+
+    /// Get top N players by ELO rating. Ties are broken by games played
+    /// (more first), then registration order (earlier first).
     pub fn get_top_players(env: Env, limit: u32) -> Vec<PlayerStats> {
-        let top: Vec<Address> = env
+        let top: Vec<RankEntry> = env
             .storage()
             .instance()
-            .get(&DataKey::TopPlayers)
+            .get(&DataKey::TopIndex)
             .unwrap_or(Vec::new(&env));
 
         let n = if limit < top.len() { limit } else { top.len() };
         let mut result = Vec::new(&env);
 
         for i in 0..n {
-            let addr = top.get(i).unwrap();
+            let entry = top.get(i).unwrap();
             if let Some(stats) = env.storage().persistent().get::<DataKey, PlayerStats>(
-                &DataKey::Player(addr.clone()),
+                &DataKey::Player(entry.address),
             ) {
                 result.push_back(stats);
             }
@@ -262,10 +381,10 @@ impl Leaderboard {
 
     /// Get total number of registered players (from top index length)
     pub fn total_players(env: Env) -> u32 {
-        let top: Vec<Address> = env
+        let top: Vec<RankEntry> = env
             .storage()
             .instance()
-            .get(&DataKey::TopPlayers)
+            .get(&DataKey::TopIndex)
             .unwrap_or(Vec::new(&env));
         top.len()
     }
@@ -284,6 +403,14 @@ impl Leaderboard {
                     player: player.clone(),
                     initial_elo: DEFAULT_ELO,
                 }.publish(env);
+                let registration: u32 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::PlayerCount)
+                    .unwrap_or(0);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PlayerCount, &(registration + 1));
                 PlayerStats {
                     address: player.clone(),
                     elo: DEFAULT_ELO,
@@ -294,6 +421,9 @@ impl Leaderboard {
                     win_streak: 0,
                     best_streak: 0,
                     last_updated: now,
+                    registration,
+                    flags: 0,
+                    flag_count: 0,
                 }
             }
         }
@@ -364,18 +494,82 @@ impl Leaderboard {
         }
     }
 
-    /// Keep the top players index sorted by ELO (descending).
-    fn update_top_players(env: &Env, player: &Address, new_elo: u32) {
-        let mut top: Vec<Address> = env
+    /// Append `outcome` to the pair's recent results. Returns true once the
+    /// window is full and every result in it is decisive and alternates.
+    fn record_pair_result(env: &Env, player1: &Address, player2: &Address, outcome: u32) -> bool {
+        // Store results from the lower address's side so both orders share a key
+        let (key, result) = if player1 < player2 {
+            (DataKey::Pair(player1.clone(), player2.clone()), outcome)
+        } else {
+            let flipped = match outcome {
+                1 => 2,
+                2 => 1,
+                other => other,
+            };
+            (DataKey::Pair(player2.clone(), player1.clone()), flipped)
+        };
+
+        let mut results: Vec<u32> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        results.push_back(result);
+        while results.len() > PAIR_WINDOW {
+            results.remove(0);
+        }
+        env.storage().persistent().set(&key, &results);
+        env.storage().persistent().extend_ttl(&key, TTL_LEDGERS, TTL_LEDGERS);
+
+        if results.len() < PAIR_WINDOW {
+            return false;
+        }
+        let mut prev = 0u32;
+        for r in results.iter() {
+            if (r != 1 && r != 2) || r == prev {
+                return false;
+            }
+            prev = r;
+        }
+        true
+    }
+
+    fn near_tier_boundary(elo: u32) -> bool {
+        TIER_BOUNDARIES.iter().any(|&b| elo.abs_diff(b) <= TIER_MARGIN)
+    }
+
+    /// True if a loss took the loser from `old_elo` to below a tier boundary
+    /// it was at or above, against an opponent at least `UPSET_GAP` lower.
+    fn is_tier_drop(old_elo: u32, new_elo: u32, winner_elo: u32) -> bool {
+        winner_elo + UPSET_GAP <= old_elo
+            && TIER_BOUNDARIES.iter().any(|&b| old_elo >= b && new_elo < b)
+    }
+
+    /// Record a heuristic hit on `stats` and emit it for moderators.
+    fn flag(env: &Env, stats: &mut PlayerStats, opponent: &Address, flag: u32) {
+        stats.flags |= flag;
+        stats.flag_count += 1;
+        EvSandbagFlagged {
+            player: stats.address.clone(),
+            opponent: opponent.clone(),
+            flag,
+            elo: stats.elo,
+        }.publish(env);
+    }
+
+    /// True if `a` ranks strictly above `b` (see `RankEntry`).
+    fn ranks_above(a: &RankEntry, b: &RankEntry) -> bool {
+        (a.elo, a.games_played, b.registration) > (b.elo, b.games_played, a.registration)
+    }
+
+    /// Keep the top players index sorted by rank (descending).
+    fn update_top_players(env: &Env, stats: &PlayerStats) {
+        let mut top: Vec<RankEntry> = env
             .storage()
             .instance()
-            .get(&DataKey::TopPlayers)
+            .get(&DataKey::TopIndex)
             .unwrap_or(Vec::new(env));
 
         // Remove player if already present
         let mut found_idx: Option<u32> = None;
         for i in 0..top.len() {
-            if top.get(i).unwrap() == *player {
+            if top.get(i).unwrap().address == stats.address {
                 found_idx = Some(i);
                 break;
             }
@@ -384,30 +578,29 @@ impl Leaderboard {
             top.remove(idx);
         }
 
-        // Find insertion point (sorted descending by ELO)
+        // Find insertion point (sorted descending by rank key)
+        let entry = RankEntry {
+            address: stats.address.clone(),
+            elo: stats.elo,
+            games_played: stats.games_played,
+            registration: stats.registration,
+        };
         let mut insert_at = top.len();
         for i in 0..top.len() {
-            let addr = top.get(i).unwrap();
-            if let Some(stats) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, PlayerStats>(&DataKey::Player(addr))
-            {
-                if new_elo > stats.elo {
-                    insert_at = i;
-                    break;
-                }
+            if Self::ranks_above(&entry, &top.get(i).unwrap()) {
+                insert_at = i;
+                break;
             }
         }
 
-        top.insert(insert_at, player.clone());
+        top.insert(insert_at, entry);
 
         // Trim to MAX_TOP_PLAYERS
         while top.len() > MAX_TOP_PLAYERS {
             top.pop_back();
         }
 
-        env.storage().instance().set(&DataKey::TopPlayers, &top);
+        env.storage().instance().set(&DataKey::TopIndex, &top);
     }
 }
 
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{Leaderboard, LeaderboardClient};
+use crate::{Leaderboard, LeaderboardClient, FLAG_ALTERNATING, FLAG_TIER_DROP, FORFEIT_PENALTY, OUTCOME_FORFEIT};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{Address, Env};
 
@@ -160,6 +160,42 @@ fn test_elo_minimum_floor() {
     assert!(s1.elo >= 100, "ELO should never drop below 100");
 }
 
+// ════════════════════════════════════════════════════════════════════════════
+//  Forfeits
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_forfeit_costs_the_quitter_extra() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+    let (p3, p4) = players(&env);
+
+    client.record_match(&admin, &p1, &p2, &1);
+    client.record_match(&admin, &p3, &p4, &(2 | OUTCOME_FORFEIT));
+
+    let (loser, quitter) = (client.get_player(&p2).unwrap(), client.get_player(&p3).unwrap());
+    assert_eq!(quitter.elo, loser.elo - FORFEIT_PENALTY);
+    assert_eq!((quitter.losses, quitter.win_streak), (1, 0));
+    // The winner gains only what a normal win is worth
+    assert_eq!(client.get_player(&p4).unwrap().elo, client.get_player(&p1).unwrap().elo);
+}
+
+#[test]
+fn test_forfeit_penalty_respects_floor_and_ignores_draws() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+
+    for _ in 0..80 {
+        client.record_match(&admin, &p1, &p2, &(2 | OUTCOME_FORFEIT));
+    }
+    assert_eq!(client.get_player(&p1).unwrap().elo, 100);
+
+    let (p3, p4) = players(&env);
+    client.record_match(&admin, &p3, &p4, &(3 | OUTCOME_FORFEIT));
+    let s3 = client.get_player(&p3).unwrap();
+    assert_eq!((s3.draws, s3.elo), (1, 1200));
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Win Streak
 // ════════════════════════════════════════════════════════════════════════════
@@ -234,6 +270,54 @@ fn test_top_players_sorted_by_elo() {
     }
 }
 
+#[test]
+fn test_equal_elo_ranked_by_games_played() {
+    let (env, client, admin) = setup();
+    let (a, c) = players(&env);
+    let (b, d) = players(&env);
+
+    // Draws between equal players leave ELO at 1200
+    client.record_match(&admin, &a, &c, &3);
+    client.record_match(&admin, &b, &d, &3);
+    client.record_match(&admin, &a, &c, &3);
+
+    let top = client.get_top_players(&10);
+    assert_eq!(top.len(), 4);
+    for i in 0..4 {
+        assert_eq!(top.get(i).unwrap().elo, 1200);
+    }
+    // Two games before one; within each group, earlier registration first
+    assert_eq!(top.get(0).unwrap().address, a);
+    assert_eq!(top.get(1).unwrap().address, c);
+    assert_eq!(top.get(2).unwrap().address, b);
+    assert_eq!(top.get(3).unwrap().address, d);
+}
+
+#[test]
+fn test_tie_order_stable_under_repeated_updates() {
+    let (env, client, admin) = setup();
+    let (p0, p1) = players(&env);
+    let (p2, p3) = players(&env);
+
+    // Register in order p0, p1, p2, p3
+    client.record_match(&admin, &p0, &p1, &3);
+    client.record_match(&admin, &p2, &p3, &3);
+    assert_eq!(client.get_player(&p0).unwrap().registration, 0);
+    assert_eq!(client.get_player(&p3).unwrap().registration, 3);
+
+    // Re-insert the later players first, in reverse order, many times
+    for _ in 0..3 {
+        client.record_match(&admin, &p3, &p2, &3);
+        client.record_match(&admin, &p1, &p0, &3);
+
+        let top = client.get_top_players(&10);
+        assert_eq!(top.get(0).unwrap().address, p0);
+        assert_eq!(top.get(1).unwrap().address, p1);
+        assert_eq!(top.get(2).unwrap().address, p2);
+        assert_eq!(top.get(3).unwrap().address, p3);
+    }
+}
+
 #[test]
 fn test_top_players_limit() {
     let (env, client, admin) = setup();
@@ -254,6 +338,91 @@ fn test_top_players_limit() {
     assert!(all.len() <= 6); // 5 players + loser = 6
 }
 
+// ════════════════════════════════════════════════════════════════════════════
+//  Sandbagging Flags
+// ════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_alternating_results_near_boundary_flag_both() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+
+    // Both start at 1200, a tier boundary; trade wins four times
+    for (i, outcome) in [1u32, 2, 1, 2].iter().enumerate() {
+        // Alternate the argument order too: the pair history is order-independent
+        if i % 2 == 0 {
+            client.record_match(&admin, &p1, &p2, outcome);
+        } else {
+            client.record_match(&admin, &p2, &p1, &(3 - outcome));
+        }
+        let flagged = client.get_player(&p1).unwrap().flags;
+        assert_eq!(flagged != 0, i == 3);
+    }
+    let s1 = client.get_player(&p1).unwrap();
+    let s2 = client.get_player(&p2).unwrap();
+    assert_eq!(s1.flags, FLAG_ALTERNATING);
+    assert_eq!(s2.flags, FLAG_ALTERNATING);
+    assert_eq!(s1.flag_count, 1);
+    // Flags leave the match record alone
+    assert_eq!((s1.wins, s1.losses), (2, 2));
+}
+
+#[test]
+fn test_streaks_and_draws_not_flagged() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+
+    for outcome in [1u32, 1, 2, 1, 3, 2, 3, 1] {
+        client.record_match(&admin, &p1, &p2, &outcome);
+    }
+    assert_eq!(client.get_player(&p1).unwrap().flags, 0);
+    assert_eq!(client.get_player(&p2).unwrap().flags, 0);
+}
+
+#[test]
+fn test_tier_drop_against_much_weaker_opponent_flagged() {
+    let (env, client, admin) = setup();
+
+    // Sink a player to 1000 or below against fresh opponents
+    let weak = Address::generate(&env);
+    while client.get_player(&weak).map_or(1200, |s| s.elo) > 1000 {
+        client.record_match(&admin, &Address::generate(&env), &weak, &1);
+    }
+
+    // A fresh 1200 player losing to them drops below the 1200 boundary
+    let strong = Address::generate(&env);
+    client.record_match(&admin, &weak, &strong, &1);
+
+    let s = client.get_player(&strong).unwrap();
+    assert!(s.elo < 1200);
+    assert_eq!(s.flags, FLAG_TIER_DROP);
+    assert_eq!(client.get_player(&weak).unwrap().flags, 0);
+}
+
+#[test]
+fn test_admin_clears_flags() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+    for outcome in [1u32, 2, 1, 2] {
+        client.record_match(&admin, &p1, &p2, &outcome);
+    }
+    assert_ne!(client.get_player(&p1).unwrap().flags, 0);
+
+    client.clear_flags(&admin, &p1);
+    let s1 = client.get_player(&p1).unwrap();
+    assert_eq!((s1.flags, s1.flag_count), (0, 0));
+    assert_eq!(s1.games_played, 4);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")] // NotAdmin
+fn test_non_admin_cannot_clear_flags() {
+    let (env, client, admin) = setup();
+    let (p1, p2) = players(&env);
+    client.record_match(&admin, &p1, &p2, &1);
+    client.clear_flags(&p2, &p1);
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 //  Player Not Found
 // ════════════════════════════════════════════════════════════════════════════
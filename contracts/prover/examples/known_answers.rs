@@ -0,0 +1,12 @@
+//! Print the known-answer vectors as JSON.
+//!
+//! cargo run -p cangkulan-prover --example known_answers --features testutils > vectors.json
+
+use cangkulan_prover::vectors;
+use soroban_sdk::Env;
+
+fn main() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    print!("{}", vectors::to_json(&env));
+}
@@ -0,0 +1,94 @@
+//! BLS12-381 scalar arithmetic on 32-byte big-endian values.
+//!
+//! Pure Rust, no host calls, so responses can be combined anywhere. `add`,
+//! `sub` and `mul` expect canonical operands (`< r`) and return canonical
+//! results; `reduce` brings any 32-byte value (a raw hash) into range.
+
+use soroban_sdk::crypto::bls12_381::Fr;
+use soroban_sdk::{BytesN, Env};
+
+/// BLS12-381 scalar field modulus r, big-endian.
+pub const MODULUS: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48,
+    0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+fn to_limbs(a: &[u8; 32]) -> (u128, u128) {
+    let mut hi = [0u8; 16];
+    let mut lo = [0u8; 16];
+    hi.copy_from_slice(&a[..16]);
+    lo.copy_from_slice(&a[16..]);
+    (u128::from_be_bytes(hi), u128::from_be_bytes(lo))
+}
+
+fn from_limbs(hi: u128, lo: u128) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[..16].copy_from_slice(&hi.to_be_bytes());
+    out[16..].copy_from_slice(&lo.to_be_bytes());
+    out
+}
+
+/// `a - b` over 256 bits, ignoring the final borrow.
+fn wrapping_sub(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (ah, al) = to_limbs(a);
+    let (bh, bl) = to_limbs(b);
+    let (lo, borrow) = al.overflowing_sub(bl);
+    from_limbs(ah.wrapping_sub(bh).wrapping_sub(borrow as u128), lo)
+}
+
+/// Whether `a < r`.
+pub fn is_canonical(a: &[u8; 32]) -> bool {
+    *a < MODULUS
+}
+
+/// `a mod r` for any 32-byte value. 2^256 < 3r, so two subtractions suffice.
+pub fn reduce(a: &[u8; 32]) -> [u8; 32] {
+    let mut v = *a;
+    while !is_canonical(&v) {
+        v = wrapping_sub(&v, &MODULUS);
+    }
+    v
+}
+
+/// `a + b mod r`.
+pub fn add(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (ah, al) = to_limbs(a);
+    let (bh, bl) = to_limbs(b);
+    let (lo, carry) = al.overflowing_add(bl);
+    // Both operands are below r < 2^255, so the sum fits in 256 bits.
+    reduce(&from_limbs(ah.wrapping_add(bh).wrapping_add(carry as u128), lo))
+}
+
+/// `-a mod r`.
+pub fn neg(a: &[u8; 32]) -> [u8; 32] {
+    if *a == [0u8; 32] {
+        return *a;
+    }
+    wrapping_sub(&MODULUS, a)
+}
+
+/// `a - b mod r`.
+pub fn sub(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    add(a, &neg(b))
+}
+
+/// `a · b mod r` by double-and-add over the bits of `b`.
+pub fn mul(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut acc = [0u8; 32];
+    for byte in b {
+        for bit in (0..8).rev() {
+            acc = add(&acc, &acc);
+            if (byte >> bit) & 1 == 1 {
+                acc = add(&acc, a);
+            }
+        }
+    }
+    acc
+}
+
+/// The host scalar for a canonical value.
+pub fn to_fr(env: &Env, a: &[u8; 32]) -> Fr {
+    Fr::from_bytes(BytesN::from_array(env, a))
+}
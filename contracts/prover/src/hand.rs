@@ -0,0 +1,93 @@
+//! Mode 8: proof that a hand holds no card of the trick suit (cangkul).
+//!
+//! ```text
+//! A = Σ (card_i·G + b_i·H),  commit_hash = keccak256(A)
+//! R = k·H
+//! e = Fr(H(A || R || trick_suit || k || session_id || tail || [network_id] || "ZKP8"))
+//! z = nonce + e·Σ b_i
+//!
+//! public_inputs = commit_hash(32) || trick_suit(4) || k(4) || card_0..card_{k-1}(4 each) || session_id(4) || tail
+//! proof         = k(4) || A(96) || R(96) || z(32)
+//! ```
+//!
+//! The verifier checks the suits of the listed cards itself; this builder
+//! proves the opening for whatever hand it is given.
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::spec::CANGKUL_CHALLENGE_TAG;
+use crate::{challenge, fr, g1_generator, pedersen_h, scalar_u32, PlayerTail};
+
+/// `(commit_hash, proof)` for `hand` with one blinding per card, or `None`
+/// if the hand is empty or the counts differ.
+pub fn prove(
+    env: &Env,
+    hand: &[u32],
+    blindings: &[[u8; 32]],
+    trick_suit: u32,
+    nonce: &[u8; 32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Option<(BytesN<32>, Bytes)> {
+    if hand.is_empty() || hand.len() != blindings.len() {
+        return None;
+    }
+    let bls = env.crypto().bls12_381();
+    let g = g1_generator(env);
+    let h = pedersen_h(env);
+    let k = hand.len() as u32;
+
+    let mut aggregate = None;
+    let mut blinding_sum = [0u8; 32];
+    for (card, blinding) in hand.iter().zip(blindings) {
+        let blinding = fr::reduce(blinding);
+        let point = bls.g1_add(
+            &bls.g1_mul(&g, &fr::to_fr(env, &scalar_u32(*card))),
+            &bls.g1_mul(&h, &fr::to_fr(env, &blinding)),
+        );
+        aggregate = Some(match aggregate {
+            Some(sum) => bls.g1_add(&sum, &point),
+            None => point,
+        });
+        blinding_sum = fr::add(&blinding_sum, &blinding);
+    }
+    let a_bytes = Bytes::from_array(env, &aggregate?.to_bytes().to_array());
+    let commit_hash: BytesN<32> = env.crypto().keccak256(&a_bytes).into();
+
+    let nonce = fr::reduce(nonce);
+    let r_bytes = Bytes::from_array(env, &bls.g1_mul(&h, &fr::to_fr(env, &nonce)).to_bytes().to_array());
+
+    let mut pre = a_bytes.clone();
+    pre.append(&r_bytes);
+    pre.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
+    pre.append(&Bytes::from_array(env, &k.to_be_bytes()));
+    pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    pre.append(&tail.to_bytes(env));
+    let e = challenge(env, tail, pre, &CANGKUL_CHALLENGE_TAG);
+    let z = fr::add(&nonce, &fr::mul(&e, &blinding_sum));
+
+    let mut proof = Bytes::from_array(env, &k.to_be_bytes());
+    proof.append(&a_bytes);
+    proof.append(&r_bytes);
+    proof.append(&Bytes::from_array(env, &z));
+    Some((commit_hash, proof))
+}
+
+pub fn public_inputs(
+    env: &Env,
+    commit_hash: &BytesN<32>,
+    trick_suit: u32,
+    hand: &[u32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Bytes {
+    let mut pi = Bytes::from_array(env, &commit_hash.to_array());
+    pi.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
+    pi.append(&Bytes::from_array(env, &(hand.len() as u32).to_be_bytes()));
+    for card in hand {
+        pi.append(&Bytes::from_array(env, &card.to_be_bytes()));
+    }
+    pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    pi.append(&tail.to_bytes(env));
+    pi
+}
@@ -0,0 +1,234 @@
+#![no_std]
+
+//! # Cangkulan Prover
+//!
+//! Proof builders for the ZK verifier's sigma-protocol modes, so Rust
+//! clients and bots produce byte-exact public inputs and proofs without
+//! re-implementing the transcripts. These are the builders the verifier's
+//! own tests use.
+//!
+//! | Module       | Mode | Proves                                          |
+//! |--------------|------|-------------------------------------------------|
+//! | [`nizk`]     | 2    | knowledge of a seed behind a blinded commitment |
+//! | [`pedersen`] | 4    | opening of a Pedersen seed commitment           |
+//! | [`ring`]     | 7    | a committed card is in the valid set            |
+//! | [`hand`]     | 8    | a hand holds no card of the trick suit          |
+//! | [`trick`]    | 26   | both players' Mode 7/8 proofs for one trick     |
+//! | [`fr`]       | —    | BLS12-381 scalar arithmetic on 32-byte values   |
+//!
+//! Every builder takes an `Env` for keccak256/sha256 and BLS12-381 host
+//! functions. Inside a contract (wasm) that is the contract's own `Env`;
+//! off-chain, enable the `testutils` feature and use `Env::default()`.
+//! The same feature adds [`vectors`], fixed known-answer vectors for
+//! checking other provers byte for byte.
+//!
+//! ## Randomness
+//!
+//! The crate never draws randomness itself. Blindings and nonces are
+//! arguments, and a reused nonce leaks the blinding it protects, so callers
+//! must pass fresh secret values for every proof.
+//!
+//! ## Format tag
+//!
+//! Builders return untagged proofs, which the typed entrypoints take as
+//! they are. [`tag_proof`] prefixes the one-byte format tag that `verify`,
+//! `verify_from` and `verify_and_record` need once the operator refuses
+//! untagged proofs.
+//!
+//! ## Public-input tail
+//!
+//! Every mode here ends its public inputs with `session_id || player`, which
+//! [`PlayerTail`] extends with the verifier's optional trailers: the sha256
+//! transcript selector and the ledger window. The builders hash exactly the
+//! bytes `PlayerTail::to_bytes` appends, so the trailers need no extra work.
+//! For a verifier with network binding on, `PlayerTail::network` adds the
+//! network id to every challenge.
+//!
+//! Field-valued challenges use keccak256, or the Poseidon2 sponge with the
+//! `poseidon-fs` feature; build the prover to match the verifier. sha256,
+//! when selected, overrides both.
+
+use soroban_sdk::crypto::bls12_381::G1Affine;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+pub use cangkulan_common::spec;
+
+use spec::*;
+
+pub mod fr;
+pub mod hand;
+pub mod nizk;
+pub mod pedersen;
+pub mod ring;
+pub mod trick;
+#[cfg(feature = "testutils")]
+pub mod vectors;
+
+/// BLS12-381 G1 generator, uncompressed.
+pub const G1_GENERATOR: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+    0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+    0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+    0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
+    0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
+    0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
+    0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+];
+
+/// The generator G of every Pedersen commitment.
+pub fn g1_generator(env: &Env) -> G1Affine {
+    G1Affine::from_array(env, &G1_GENERATOR)
+}
+
+/// The Pedersen generator H, derived exactly as the verifier derives it.
+pub fn pedersen_h(env: &Env) -> G1Affine {
+    let msg = Bytes::from_slice(env, PEDERSEN_H_MSG);
+    let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+    env.crypto().bls12_381().hash_to_g1(&msg, &dst)
+}
+
+/// The dummy member `P_pad` of a padded Mode 7 ring.
+pub fn ring_pad_point(env: &Env) -> G1Affine {
+    let msg = Bytes::from_slice(env, RING_PAD_MSG);
+    let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+    env.crypto().bls12_381().hash_to_g1(&msg, &dst)
+}
+
+/// `proof` behind the version-1 format tag for `mode`, the form the
+/// verifier's auto-detecting entrypoints need once legacy proofs are off.
+pub fn tag_proof(env: &Env, mode: u32, proof: &Bytes) -> Bytes {
+    let mut out = Bytes::from_array(env, &[PROOF_FORMAT_V1 | (mode as u8 & PROOF_FORMAT_MODE_MASK)]);
+    out.append(proof);
+    out
+}
+
+/// The public inputs after `session_id`: the player's strkey, then the
+/// optional transcript hash selector and ledger window.
+#[derive(Clone, Debug)]
+pub struct PlayerTail {
+    player: Address,
+    sha256: bool,
+    valid_until_ledger: Option<u32>,
+    network_id: Option<BytesN<32>>,
+    link: Option<BytesN<32>>,
+}
+
+impl PlayerTail {
+    pub fn new(player: &Address) -> Self {
+        PlayerTail { player: player.clone(), sha256: false, valid_until_ledger: None, network_id: None, link: None }
+    }
+
+    /// Select sha256 challenges (`TRANSCRIPT_HASH_SHA256`).
+    pub fn sha256(mut self) -> Self {
+        self.sha256 = true;
+        self
+    }
+
+    /// Let the proof expire after ledger `ledger`.
+    pub fn valid_until(mut self, ledger: u32) -> Self {
+        self.valid_until_ledger = Some(ledger);
+        self
+    }
+
+    /// Bind challenges to `network_id` (sha256 of the network passphrase),
+    /// for a verifier with `set_network_binding` on.
+    pub fn network(mut self, network_id: &BytesN<32>) -> Self {
+        self.network_id = Some(network_id.clone());
+        self
+    }
+
+    /// Chain the challenge onto the first part of a Mode 26 trick bundle,
+    /// with `link` from [`trick::link`]. For Mode 7 and 8 proofs only.
+    pub fn linked(mut self, link: &BytesN<32>) -> Self {
+        self.link = Some(link.clone());
+        self
+    }
+
+    /// The player's strkey bytes alone, as Mode 2 commitments cover them.
+    pub fn player_bytes(&self) -> Bytes {
+        self.player.to_string().to_bytes()
+    }
+
+    /// Every byte this tail adds to the public inputs.
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut tail = self.player_bytes();
+        if self.sha256 {
+            tail.append(&Bytes::from_array(env, &TRANSCRIPT_HASH_TAG));
+            tail.push_back(TRANSCRIPT_HASH_SHA256);
+        }
+        if let Some(until) = self.valid_until_ledger {
+            tail.append(&Bytes::from_array(env, &LEDGER_WINDOW_TAG));
+            tail.append(&Bytes::from_array(env, &until.to_be_bytes()));
+        }
+        tail
+    }
+
+    /// The hash the verifier uses for these inputs where it compares bytes
+    /// (the Mode 2 challenge and response): keccak256, or sha256 if selected.
+    pub fn hash(&self, env: &Env, data: &Bytes) -> BytesN<32> {
+        if self.sha256 {
+            env.crypto().sha256(data).into()
+        } else {
+            env.crypto().keccak256(data).into()
+        }
+    }
+}
+
+/// `preimage || [network_id] || tag`, with `preimage` ending in the tail.
+/// A linked tail appends its link and `TRICK_CHALLENGE_TAG` instead of `tag`.
+fn tagged(env: &Env, tail: &PlayerTail, mut preimage: Bytes, tag: &[u8; 4]) -> Bytes {
+    if let Some(network_id) = &tail.network_id {
+        preimage.append(&Bytes::from_array(env, &network_id.to_array()));
+    }
+    match &tail.link {
+        Some(link) => {
+            preimage.append(&Bytes::from_array(env, &link.to_array()));
+            preimage.append(&Bytes::from_array(env, &TRICK_CHALLENGE_TAG));
+        }
+        None => preimage.append(&Bytes::from_array(env, tag)),
+    }
+    preimage
+}
+
+/// Byte-valued challenge `H(preimage || [network_id] || tag)` (Mode 2).
+pub(crate) fn challenge_digest(env: &Env, tail: &PlayerTail, preimage: Bytes, tag: &[u8; 4]) -> BytesN<32> {
+    tail.hash(env, &tagged(env, tail, preimage, tag))
+}
+
+/// Field-valued challenge `Fr(fs_digest(preimage || [network_id] || tag))`,
+/// with sha256 in place of `fs_digest` if selected.
+pub(crate) fn challenge(env: &Env, tail: &PlayerTail, preimage: Bytes, tag: &[u8; 4]) -> [u8; 32] {
+    let data = tagged(env, tail, preimage, tag);
+    let digest = if tail.sha256 { tail.hash(env, &data) } else { fs_digest(env, &data) };
+    fr::reduce(&digest.to_array())
+}
+
+#[cfg(not(feature = "poseidon-fs"))]
+fn fs_digest(env: &Env, preimage: &Bytes) -> BytesN<32> {
+    env.crypto().keccak256(preimage).into()
+}
+
+#[cfg(feature = "poseidon-fs")]
+fn fs_digest(env: &Env, preimage: &Bytes) -> BytesN<32> {
+    cangkulan_common::poseidon::sponge(env, preimage)
+}
+
+/// Independent per-index scalar from one caller nonce:
+/// `keccak256(nonce || label || index) mod r`.
+pub(crate) fn expand_nonce(env: &Env, nonce: &[u8; 32], label: u8, index: u32) -> [u8; 32] {
+    let mut pre = Bytes::from_array(env, nonce);
+    pre.push_back(label);
+    pre.append(&Bytes::from_array(env, &index.to_be_bytes()));
+    fr::reduce(&env.crypto().keccak256(&pre).to_array())
+}
+
+/// Big-endian scalar for a small integer (card ids, values).
+pub(crate) fn scalar_u32(v: u32) -> [u8; 32] {
+    let mut arr = [0u8; 32];
+    arr[28..].copy_from_slice(&v.to_be_bytes());
+    arr
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,101 @@
+//! Mode 2: hash-based NIZK seed proof.
+//!
+//! ```text
+//! public_inputs = seed_hash(32) || commitment(32) || nullifier(32) || session_id(4) || tail
+//! proof         = blinding(32) || response(32)
+//! ```
+//!
+//! The commitment is published at commit time, before any proof, so it only
+//! covers the player's strkey. Commitment and nullifier are always keccak256;
+//! the challenge and response follow the tail's transcript hash.
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::spec::{CHALLENGE_TAG, NULLIFIER_TAG};
+use crate::{challenge_digest, PlayerTail};
+
+/// `seed_hash = keccak256(seed)`.
+pub fn seed_hash(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from_array(env, &seed.to_array())).into()
+}
+
+/// `commitment = keccak256(seed_hash || blinding || player)`.
+pub fn commitment(env: &Env, seed_hash: &BytesN<32>, blinding: &BytesN<32>, tail: &PlayerTail) -> BytesN<32> {
+    let mut pre = Bytes::from_array(env, &seed_hash.to_array());
+    pre.append(&Bytes::from_array(env, &blinding.to_array()));
+    pre.append(&tail.player_bytes());
+    env.crypto().keccak256(&pre).into()
+}
+
+/// `nullifier = keccak256(seed_hash || "NULL" || session_id)`.
+pub fn nullifier(env: &Env, seed_hash: &BytesN<32>, session_id: u32) -> BytesN<32> {
+    let mut pre = Bytes::from_array(env, &seed_hash.to_array());
+    pre.append(&Bytes::from_array(env, &NULLIFIER_TAG));
+    pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    env.crypto().keccak256(&pre).into()
+}
+
+/// `e = H(commitment || session_id || tail || [network_id] || "ZKV2")`.
+pub fn challenge(env: &Env, commitment: &BytesN<32>, session_id: u32, tail: &PlayerTail) -> BytesN<32> {
+    let mut pre = Bytes::from_array(env, &commitment.to_array());
+    pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    pre.append(&tail.to_bytes(env));
+    challenge_digest(env, tail, pre, &CHALLENGE_TAG)
+}
+
+/// `response = H(seed_hash || e || blinding)`.
+pub fn response(
+    env: &Env,
+    seed_hash: &BytesN<32>,
+    challenge: &BytesN<32>,
+    blinding: &BytesN<32>,
+    tail: &PlayerTail,
+) -> BytesN<32> {
+    let mut pre = Bytes::from_array(env, &seed_hash.to_array());
+    pre.append(&Bytes::from_array(env, &challenge.to_array()));
+    pre.append(&Bytes::from_array(env, &blinding.to_array()));
+    tail.hash(env, &pre)
+}
+
+pub fn public_inputs(
+    env: &Env,
+    seed_hash: &BytesN<32>,
+    commitment: &BytesN<32>,
+    nullifier: &BytesN<32>,
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Bytes {
+    let mut buf = Bytes::from_array(env, &seed_hash.to_array());
+    buf.append(&Bytes::from_array(env, &commitment.to_array()));
+    buf.append(&Bytes::from_array(env, &nullifier.to_array()));
+    buf.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    buf.append(&tail.to_bytes(env));
+    buf
+}
+
+pub fn proof(env: &Env, blinding: &BytesN<32>, response: &BytesN<32>) -> Bytes {
+    let mut buf = Bytes::from_array(env, &blinding.to_array());
+    buf.append(&Bytes::from_array(env, &response.to_array()));
+    buf
+}
+
+/// Full Mode 2 proof for `seed`: `(public_inputs, proof, commitment)`.
+/// The commitment is what the player commits to before revealing.
+pub fn prove(
+    env: &Env,
+    seed: &BytesN<32>,
+    blinding: &BytesN<32>,
+    session_id: u32,
+    tail: &PlayerTail,
+) -> (Bytes, Bytes, BytesN<32>) {
+    let seed_hash = seed_hash(env, seed);
+    let commitment = commitment(env, &seed_hash, blinding, tail);
+    let nullifier = nullifier(env, &seed_hash, session_id);
+    let e = challenge(env, &commitment, session_id, tail);
+    let response = response(env, &seed_hash, &e, blinding, tail);
+    (
+        public_inputs(env, &seed_hash, &commitment, &nullifier, session_id, tail),
+        proof(env, blinding, &response),
+        commitment,
+    )
+}
@@ -0,0 +1,61 @@
+//! Mode 4: Pedersen commitment to a seed hash with a Schnorr proof.
+//!
+//! ```text
+//! C = s·G + b·H   with s = seed_hash mod r
+//! R = k·H
+//! e = Fr(H(C || R || seed_hash || session_id || tail || [network_id] || "ZKP4"))
+//! z = k + e·b
+//!
+//! public_inputs = C(96) || seed_hash(32) || session_id(4) || tail
+//! proof         = R(96) || z(32)
+//! ```
+
+use soroban_sdk::crypto::bls12_381::G1Affine;
+use soroban_sdk::{vec, Bytes, BytesN, Env};
+
+use crate::spec::PEDERSEN_CHALLENGE_TAG;
+use crate::{challenge, fr, g1_generator, pedersen_h, PlayerTail};
+
+/// `C = value·G + blinding·H`, both scalars reduced mod r.
+pub fn commitment(env: &Env, value: &[u8; 32], blinding: &[u8; 32]) -> G1Affine {
+    let bls = env.crypto().bls12_381();
+    bls.g1_msm(
+        vec![env, g1_generator(env), pedersen_h(env)],
+        vec![env, fr::to_fr(env, &fr::reduce(value)), fr::to_fr(env, &fr::reduce(blinding))],
+    )
+}
+
+/// Mode 4 `(public_inputs, proof)` opening the commitment to `seed_hash`
+/// under `blinding`, with Schnorr nonce `nonce`.
+pub fn prove(
+    env: &Env,
+    seed_hash: &BytesN<32>,
+    blinding: &[u8; 32],
+    nonce: &[u8; 32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> (Bytes, Bytes) {
+    let bls = env.crypto().bls12_381();
+    let seed_hash = seed_hash.to_array();
+    let blinding = fr::reduce(blinding);
+    let nonce = fr::reduce(nonce);
+
+    let c_bytes = commitment(env, &seed_hash, &blinding).to_bytes();
+    let r_bytes = bls.g1_mul(&pedersen_h(env), &fr::to_fr(env, &nonce)).to_bytes();
+
+    let mut public_inputs = Bytes::from_array(env, &c_bytes.to_array());
+    public_inputs.append(&Bytes::from_array(env, &seed_hash));
+    public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    public_inputs.append(&tail.to_bytes(env));
+
+    // The preimage is C || R || seed_hash || session_id || tail
+    let mut pre = Bytes::from_array(env, &c_bytes.to_array());
+    pre.append(&Bytes::from_array(env, &r_bytes.to_array()));
+    pre.append(&public_inputs.slice(96..));
+    let e = challenge(env, tail, pre, &PEDERSEN_CHALLENGE_TAG);
+    let z = fr::add(&nonce, &fr::mul(&e, &blinding));
+
+    let mut proof = Bytes::from_array(env, &r_bytes.to_array());
+    proof.append(&Bytes::from_array(env, &z));
+    (public_inputs, proof)
+}
@@ -0,0 +1,164 @@
+//! Mode 7: ring sigma proof that a committed card is in the valid set.
+//!
+//! ```text
+//! C   = card·G + b·H,  commit_hash = keccak256(C)
+//! D_i = C − v_i·G                        (D_j = b·H for the real card j)
+//! R_i = z_i·H − e_i·D_i                  (i ≠ j, e_i and z_i simulated)
+//! R_j = k·H
+//! e   = Fr(H(C || R_0..R_{N-1} || session_id || tail || [network_id] || "ZKP7"))
+//! e_j = e − Σ_{i≠j} e_i,  z_j = k + e_j·b
+//!
+//! public_inputs = commit_hash(32) || N(4) || v_0..v_{N-1}(4 each) || session_id(4) || tail
+//! proof         = C(96) || [e_i(32) || z_i(32)] × N
+//! ```
+//!
+//! A padded ring sets `RING_PADDED_FLAG` in N and always proves 9 members,
+//! with `D_i = C − P_pad` for the slots past the valid set.
+
+use soroban_sdk::crypto::bls12_381::G1Affine;
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::spec::{RING_CHALLENGE_TAG, RING_MAX_SET_SIZE, RING_PADDED_FLAG, RING_SIZE_LIMIT};
+use crate::{challenge, expand_nonce, fr, g1_generator, pedersen_h, ring_pad_point, scalar_u32, PlayerTail};
+
+/// Labels separating the scalars drawn from one nonce.
+const NONCE_K: u8 = 0;
+const NONCE_E: u8 = 1;
+const NONCE_Z: u8 = 2;
+
+/// `(commit_hash, proof)` for `card_id` committed under `blinding`, or
+/// `None` if the card is not in `valid_set` or the set holds more than
+/// `RING_SIZE_LIMIT` cards (a 52-card suit; the verifier's `DeckParams`
+/// may allow fewer). `nonce` seeds the Schnorr nonce and the
+/// simulated branches.
+pub fn prove(
+    env: &Env,
+    card_id: u32,
+    blinding: &[u8; 32],
+    valid_set: &[u32],
+    nonce: &[u8; 32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Option<(BytesN<32>, Bytes)> {
+    prove_ring(env, card_id, blinding, valid_set, false, nonce, session_id, tail)
+}
+
+/// As [`prove`], but padded to `RING_MAX_SET_SIZE` members whatever the
+/// size of `valid_set`, as the default 36-card `DeckParams` expects. Pair
+/// with [`public_inputs_padded`].
+pub fn prove_padded(
+    env: &Env,
+    card_id: u32,
+    blinding: &[u8; 32],
+    valid_set: &[u32],
+    nonce: &[u8; 32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Option<(BytesN<32>, Bytes)> {
+    prove_ring(env, card_id, blinding, valid_set, true, nonce, session_id, tail)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prove_ring(
+    env: &Env,
+    card_id: u32,
+    blinding: &[u8; 32],
+    valid_set: &[u32],
+    padded: bool,
+    nonce: &[u8; 32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Option<(BytesN<32>, Bytes)> {
+    let limit = if padded { RING_MAX_SET_SIZE } else { RING_SIZE_LIMIT };
+    if valid_set.len() > limit as usize {
+        return None;
+    }
+    let real = valid_set.iter().position(|&c| c == card_id)?;
+    let bls = env.crypto().bls12_381();
+    let g = g1_generator(env);
+    let h = pedersen_h(env);
+    let blinding = fr::reduce(blinding);
+
+    let commitment = bls.g1_add(
+        &bls.g1_mul(&g, &fr::to_fr(env, &scalar_u32(card_id))),
+        &bls.g1_mul(&h, &fr::to_fr(env, &blinding)),
+    );
+    let c_bytes = Bytes::from_array(env, &commitment.to_bytes().to_array());
+    let commit_hash: BytesN<32> = env.crypto().keccak256(&c_bytes).into();
+
+    let members = if padded { RING_MAX_SET_SIZE as usize } else { valid_set.len() };
+    let pad = if padded { Some(ring_pad_point(env)) } else { None };
+
+    let k = expand_nonce(env, nonce, NONCE_K, 0);
+    let mut e_parts = [[0u8; 32]; RING_SIZE_LIMIT as usize];
+    let mut z_parts = [[0u8; 32]; RING_SIZE_LIMIT as usize];
+    let mut r_points: Vec<G1Affine> = Vec::new(env);
+    let mut e_others = [0u8; 32];
+    for i in 0..members {
+        if i == real {
+            r_points.push_back(bls.g1_mul(&h, &fr::to_fr(env, &k)));
+            continue;
+        }
+        let member = match valid_set.get(i) {
+            Some(&value) => bls.g1_mul(&g, &fr::to_fr(env, &scalar_u32(value))),
+            None => pad.clone()?,
+        };
+        let d = bls.g1_add(&commitment, &-member);
+        e_parts[i] = expand_nonce(env, nonce, NONCE_E, i as u32);
+        z_parts[i] = expand_nonce(env, nonce, NONCE_Z, i as u32);
+        let z_h = bls.g1_mul(&h, &fr::to_fr(env, &z_parts[i]));
+        let e_d = bls.g1_mul(&d, &fr::to_fr(env, &e_parts[i]));
+        r_points.push_back(bls.g1_add(&z_h, &-e_d));
+        e_others = fr::add(&e_others, &e_parts[i]);
+    }
+
+    let mut pre = c_bytes.clone();
+    for r in r_points.iter() {
+        pre.append(&Bytes::from_array(env, &r.to_bytes().to_array()));
+    }
+    pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    pre.append(&tail.to_bytes(env));
+    let e = challenge(env, tail, pre, &RING_CHALLENGE_TAG);
+    e_parts[real] = fr::sub(&e, &e_others);
+    z_parts[real] = fr::add(&k, &fr::mul(&e_parts[real], &blinding));
+
+    let mut proof = c_bytes;
+    for i in 0..members {
+        proof.append(&Bytes::from_array(env, &e_parts[i]));
+        proof.append(&Bytes::from_array(env, &z_parts[i]));
+    }
+    Some((commit_hash, proof))
+}
+
+pub fn public_inputs(env: &Env, commit_hash: &BytesN<32>, valid_set: &[u32], session_id: u32, tail: &PlayerTail) -> Bytes {
+    encode_inputs(env, commit_hash, valid_set, 0, session_id, tail)
+}
+
+/// Public inputs of a padded ring: N carries `RING_PADDED_FLAG`.
+pub fn public_inputs_padded(
+    env: &Env,
+    commit_hash: &BytesN<32>,
+    valid_set: &[u32],
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Bytes {
+    encode_inputs(env, commit_hash, valid_set, RING_PADDED_FLAG, session_id, tail)
+}
+
+fn encode_inputs(
+    env: &Env,
+    commit_hash: &BytesN<32>,
+    valid_set: &[u32],
+    flags: u32,
+    session_id: u32,
+    tail: &PlayerTail,
+) -> Bytes {
+    let mut pi = Bytes::from_array(env, &commit_hash.to_array());
+    pi.append(&Bytes::from_array(env, &(valid_set.len() as u32 | flags).to_be_bytes()));
+    for value in valid_set {
+        pi.append(&Bytes::from_array(env, &value.to_be_bytes()));
+    }
+    pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    pi.append(&tail.to_bytes(env));
+    pi
+}
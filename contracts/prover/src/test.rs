@@ -0,0 +1,51 @@
+#![cfg(test)]
+
+use crate::spec::*;
+use crate::{fr, PlayerTail};
+use soroban_sdk::crypto::bls12_381::Fr;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+fn host(env: &Env, a: &[u8; 32]) -> Fr {
+    Fr::from_bytes(BytesN::from_array(env, a))
+}
+
+#[test]
+fn fr_arithmetic_matches_host() {
+    let env = Env::default();
+    let a = fr::reduce(&env.crypto().keccak256(&Bytes::from_array(&env, b"a")).to_array());
+    let b = fr::reduce(&env.crypto().keccak256(&Bytes::from_array(&env, b"b")).to_array());
+    assert!(fr::is_canonical(&a) && fr::is_canonical(&b));
+
+    assert_eq!(fr::add(&a, &b), (host(&env, &a) + host(&env, &b)).to_bytes().to_array());
+    assert_eq!(fr::sub(&a, &b), (host(&env, &a) - host(&env, &b)).to_bytes().to_array());
+    assert_eq!(fr::mul(&a, &b), (host(&env, &a) * host(&env, &b)).to_bytes().to_array());
+    assert_eq!(fr::sub(&a, &a), [0u8; 32]);
+    assert_eq!(fr::neg(&[0u8; 32]), [0u8; 32]);
+}
+
+#[test]
+fn fr_reduce_matches_host() {
+    let env = Env::default();
+    let top = [0xFF; 32];
+    assert_eq!(fr::reduce(&top), host(&env, &top).to_bytes().to_array());
+    assert_eq!(fr::reduce(&fr::MODULUS), [0u8; 32]);
+    assert!(!fr::is_canonical(&fr::MODULUS));
+}
+
+#[test]
+fn player_tail_layout() {
+    let env = Env::default();
+    let player = Address::generate(&env);
+    let strkey = player.to_string().to_bytes();
+    assert_eq!(PlayerTail::new(&player).to_bytes(&env), strkey);
+
+    let tail = PlayerTail::new(&player).sha256().valid_until(77).to_bytes(&env);
+    let mut expected = strkey.clone();
+    expected.append(&Bytes::from_array(&env, &TRANSCRIPT_HASH_TAG));
+    expected.push_back(TRANSCRIPT_HASH_SHA256);
+    expected.append(&Bytes::from_array(&env, &LEDGER_WINDOW_TAG));
+    expected.append(&Bytes::from_array(&env, &77u32.to_be_bytes()));
+    assert_eq!(tail, expected);
+    assert_eq!(tail.len(), strkey.len() + TRANSCRIPT_HASH_LEN + LEDGER_WINDOW_LEN);
+}
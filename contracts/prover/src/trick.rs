@@ -0,0 +1,48 @@
+//! Mode 26: two players' Mode 7/8 proofs for one trick, verified together.
+//!
+//! ```text
+//! link          = keccak256(mode_a(4) || len_a(4) || inputs_a || proof_a)
+//! public_inputs = mode_a(4) || len_a(4) || inputs_a || mode_b(4) || inputs_b
+//! proof         = tag || len_a(4) || proof_a || proof_b
+//! ```
+//!
+//! Part A is an ordinary [`ring`](crate::ring) or [`hand`](crate::hand)
+//! proof. Part B is built the same way with `PlayerTail::linked(&link)`,
+//! which chains its challenge onto part A.
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::spec::MODE_TRICK_BUNDLE;
+use crate::tag_proof;
+
+/// One part of a bundle: its mode (7 or 8), public inputs and proof.
+pub type Part<'a> = (u32, &'a Bytes, &'a Bytes);
+
+/// The digest part B's challenge chains onto, from part A.
+pub fn link(env: &Env, (mode, public_inputs, proof): Part) -> BytesN<32> {
+    let mut data = header(env, mode, public_inputs);
+    data.append(public_inputs);
+    data.append(proof);
+    env.crypto().keccak256(&data).into()
+}
+
+/// `(public_inputs, proof)` of the bundle, the proof already tagged: Mode
+/// 26 has no length rule, so the verifier only accepts it tagged.
+pub fn bundle(env: &Env, (mode_a, inputs_a, proof_a): Part, (mode_b, inputs_b, proof_b): Part) -> (Bytes, Bytes) {
+    let mut public_inputs = header(env, mode_a, inputs_a);
+    public_inputs.append(inputs_a);
+    public_inputs.append(&Bytes::from_array(env, &mode_b.to_be_bytes()));
+    public_inputs.append(inputs_b);
+
+    let mut proof = Bytes::from_array(env, &proof_a.len().to_be_bytes());
+    proof.append(proof_a);
+    proof.append(proof_b);
+    (public_inputs, tag_proof(env, MODE_TRICK_BUNDLE, &proof))
+}
+
+/// `mode(4) || len(4)` ahead of part A's inputs.
+fn header(env: &Env, mode: u32, public_inputs: &Bytes) -> Bytes {
+    let mut out = Bytes::from_array(env, &mode.to_be_bytes());
+    out.append(&Bytes::from_array(env, &public_inputs.len().to_be_bytes()));
+    out
+}
@@ -0,0 +1,264 @@
+//! Known-answer vectors (`testutils` feature): fixed witnesses, the public
+//! inputs and proofs this crate builds from them, and whether the verifier
+//! accepts each one.
+//!
+//! Every witness is a constant and the players are fixed strkeys, so the
+//! output is byte-identical on every run. Other provers (the TypeScript
+//! frontend, bots) replay the `args` of each vector and compare their bytes
+//! with `public_inputs` and `proof`. The verifier's tests check each
+//! vector's `valid` against the contract, so a vector never drifts from
+//! what the chain accepts.
+//!
+//! `examples/known_answers.rs` prints [`to_json`]:
+//!
+//! ```text
+//! cargo run -p cangkulan-prover --example known_answers --features testutils > vectors.json
+//! ```
+//!
+//! The witnesses are published, so they must never be reused for a real
+//! game.
+
+extern crate std;
+
+use std::format;
+use std::string::String;
+use std::vec::Vec;
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::spec::*;
+use crate::{hand, nizk, pedersen, ring, tag_proof, trick, PlayerTail};
+
+/// The two players of every vector.
+pub const PLAYER_A: &str = "GCQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DJX7";
+pub const PLAYER_B: &str = "GCZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFF6T";
+
+/// One named argument a prover needs to rebuild a vector.
+#[derive(Clone, Debug)]
+pub enum Arg {
+    Bytes(Bytes),
+    U32(u32),
+    U32s(Vec<u32>),
+    Text(&'static str),
+}
+
+/// A vector: the prover arguments, what they build, and the verdict.
+/// `proof` is untagged; `verify` takes it behind [`tag_proof`].
+#[derive(Clone, Debug)]
+pub struct KnownAnswer {
+    pub name: String,
+    pub mode: u32,
+    pub args: Vec<(&'static str, Arg)>,
+    pub public_inputs: Bytes,
+    pub proof: Bytes,
+    pub valid: bool,
+}
+
+impl KnownAnswer {
+    /// The proof as `verify` takes it, behind the format tag.
+    pub fn tagged_proof(&self, env: &Env) -> Bytes {
+        tag_proof(env, self.mode, &self.proof)
+    }
+}
+
+/// Every vector: one valid proof per layout the crate builds, and for each
+/// mode a copy with the proof's last byte flipped, which must fail.
+pub fn all(env: &Env) -> Vec<KnownAnswer> {
+    let player_a = Address::from_str(env, PLAYER_A);
+    let player_b = Address::from_str(env, PLAYER_B);
+    let tail_a = PlayerTail::new(&player_a);
+    let mut out = Vec::new();
+
+    // ── Mode 2: NIZK seed ───────────────────────────────────────────────────
+    let seed = BytesN::from_array(env, &[0x5E; 32]);
+    let blinding = BytesN::from_array(env, &[0xB1; 32]);
+    let (public_inputs, proof, _) = nizk::prove(env, &seed, &blinding, 1001, &tail_a);
+    push_with_tampered(&mut out, KnownAnswer {
+        name: "nizk_seed".into(),
+        mode: MODE_NIZK_SEED,
+        args: std::vec![
+            ("seed", Arg::Bytes(seed.into())),
+            ("blinding", Arg::Bytes(blinding.into())),
+            ("session_id", Arg::U32(1001)),
+            ("player", Arg::Text(PLAYER_A)),
+        ],
+        public_inputs,
+        proof,
+        valid: true,
+    });
+
+    // ── Mode 4: Pedersen seed ───────────────────────────────────────────────
+    let seed_hash = nizk::seed_hash(env, &BytesN::from_array(env, &[0x4E; 32]));
+    let (blinding, nonce) = ([0xB4; 32], [0xC4; 32]);
+    let (public_inputs, proof) = pedersen::prove(env, &seed_hash, &blinding, &nonce, 1004, &tail_a);
+    push_with_tampered(&mut out, KnownAnswer {
+        name: "pedersen_seed".into(),
+        mode: MODE_PEDERSEN_SEED,
+        args: std::vec![
+            ("seed_hash", Arg::Bytes(seed_hash.into())),
+            ("blinding", Arg::Bytes(Bytes::from_array(env, &blinding))),
+            ("nonce", Arg::Bytes(Bytes::from_array(env, &nonce))),
+            ("session_id", Arg::U32(1004)),
+            ("player", Arg::Text(PLAYER_A)),
+        ],
+        public_inputs,
+        proof,
+        valid: true,
+    });
+
+    // ── Mode 7: card play ring, plain, sha256 and padded ────────────────────
+    let (blinding, ring_nonce, valid_set) = ([0xB7; 32], [0xC7; 32], [9u32, 10, 11]);
+    let ring_args = |session_id: u32| {
+        std::vec![
+            ("card_id", Arg::U32(10)),
+            ("blinding", Arg::Bytes(Bytes::from_array(env, &blinding))),
+            ("valid_set", Arg::U32s(valid_set.to_vec())),
+            ("nonce", Arg::Bytes(Bytes::from_array(env, &ring_nonce))),
+            ("session_id", Arg::U32(session_id)),
+            ("player", Arg::Text(PLAYER_A)),
+        ]
+    };
+    if let Some((commit_hash, proof)) = ring::prove(env, 10, &blinding, &valid_set, &ring_nonce, 1007, &tail_a) {
+        push_with_tampered(&mut out, KnownAnswer {
+            name: "card_ring".into(),
+            mode: MODE_CARD_RING,
+            args: ring_args(1007),
+            public_inputs: ring::public_inputs(env, &commit_hash, &valid_set, 1007, &tail_a),
+            proof,
+            valid: true,
+        });
+    }
+    let tail_sha = tail_a.clone().sha256();
+    if let Some((commit_hash, proof)) = ring::prove(env, 10, &blinding, &valid_set, &ring_nonce, 1008, &tail_sha) {
+        let mut args = ring_args(1008);
+        args.push(("transcript", Arg::Text("sha256")));
+        out.push(KnownAnswer {
+            name: "card_ring_sha256".into(),
+            mode: MODE_CARD_RING,
+            args,
+            public_inputs: ring::public_inputs(env, &commit_hash, &valid_set, 1008, &tail_sha),
+            proof,
+            valid: true,
+        });
+    }
+    if let Some((commit_hash, proof)) = ring::prove_padded(env, 10, &blinding, &valid_set, &ring_nonce, 1009, &tail_a) {
+        out.push(KnownAnswer {
+            name: "card_ring_padded".into(),
+            mode: MODE_CARD_RING,
+            args: ring_args(1009),
+            public_inputs: ring::public_inputs_padded(env, &commit_hash, &valid_set, 1009, &tail_a),
+            proof,
+            valid: true,
+        });
+    }
+
+    // ── Mode 8: cangkul hand ────────────────────────────────────────────────
+    let (hand_cards, blindings, hand_nonce) = ([0u32, 2, 20], [[0xB8; 32], [0xB9; 32], [0xBA; 32]], [0xC8; 32]);
+    let tail_b = PlayerTail::new(&player_b);
+    if let Some((commit_hash, proof)) = hand::prove(env, &hand_cards, &blindings, 1, &hand_nonce, 1010, &tail_b) {
+        push_with_tampered(&mut out, KnownAnswer {
+            name: "cangkul_hand".into(),
+            mode: MODE_CANGKUL_HAND,
+            args: std::vec![
+                ("hand", Arg::U32s(hand_cards.to_vec())),
+                ("blindings", Arg::Bytes(concat(env, &blindings))),
+                ("trick_suit", Arg::U32(1)),
+                ("nonce", Arg::Bytes(Bytes::from_array(env, &hand_nonce))),
+                ("session_id", Arg::U32(1010)),
+                ("player", Arg::Text(PLAYER_B)),
+            ],
+            public_inputs: hand::public_inputs(env, &commit_hash, 1, &hand_cards, 1010, &tail_b),
+            proof,
+            valid: true,
+        });
+    }
+
+    // ── Mode 26: trick bundle, A leads card 10, B cangkuls ─────────────────
+    let lead = ring::prove(env, 10, &blinding, &valid_set, &ring_nonce, 1026, &tail_a);
+    if let Some((lead_hash, lead_proof)) = lead {
+        let lead_inputs = ring::public_inputs(env, &lead_hash, &valid_set, 1026, &tail_a);
+        let link = trick::link(env, (MODE_CARD_RING, &lead_inputs, &lead_proof));
+        let tail_linked = tail_b.clone().linked(&link);
+        if let Some((follow_hash, follow_proof)) =
+            hand::prove(env, &hand_cards, &blindings, 1, &hand_nonce, 1026, &tail_linked)
+        {
+            let follow_inputs = hand::public_inputs(env, &follow_hash, 1, &hand_cards, 1026, &tail_linked);
+            let (public_inputs, proof) = trick::bundle(
+                env,
+                (MODE_CARD_RING, &lead_inputs, &lead_proof),
+                (MODE_CANGKUL_HAND, &follow_inputs, &follow_proof),
+            );
+            let mut args = ring_args(1026);
+            args.extend([
+                ("follow_hand", Arg::U32s(hand_cards.to_vec())),
+                ("follow_blindings", Arg::Bytes(concat(env, &blindings))),
+                ("follow_trick_suit", Arg::U32(1)),
+                ("follow_nonce", Arg::Bytes(Bytes::from_array(env, &hand_nonce))),
+                ("follow_player", Arg::Text(PLAYER_B)),
+            ]);
+            args.push(("link", Arg::Bytes(link.into())));
+            push_with_tampered(&mut out, KnownAnswer {
+                name: "trick_bundle".into(),
+                mode: MODE_TRICK_BUNDLE,
+                args,
+                public_inputs,
+                proof: proof.slice(1..),
+                valid: true,
+            });
+        }
+    }
+    out
+}
+
+/// [`all`] as a JSON array, bytes in lowercase hex.
+pub fn to_json(env: &Env) -> String {
+    let entries: Vec<String> = all(env)
+        .iter()
+        .map(|v| {
+            let args: Vec<String> = v.args.iter().map(|(name, arg)| format!("\"{}\": {}", name, arg_json(arg))).collect();
+            format!(
+                "  {{\"name\": \"{}\", \"mode\": {}, \"args\": {{{}}}, \"public_inputs\": \"{}\", \"proof\": \"{}\", \"tagged_proof\": \"{}\", \"valid\": {}}}",
+                v.name,
+                v.mode,
+                args.join(", "),
+                hex(&v.public_inputs),
+                hex(&v.proof),
+                hex(&v.tagged_proof(env)),
+                v.valid,
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+/// Push `vector` and a copy with the low bit of its last proof byte flipped.
+fn push_with_tampered(out: &mut Vec<KnownAnswer>, vector: KnownAnswer) {
+    let mut tampered = vector.clone();
+    let last = tampered.proof.len() - 1;
+    tampered.proof.set(last, tampered.proof.get_unchecked(last) ^ 1);
+    tampered.name = format!("{}_tampered", vector.name);
+    tampered.valid = false;
+    out.push(vector);
+    out.push(tampered);
+}
+
+fn concat(env: &Env, parts: &[[u8; 32]]) -> Bytes {
+    let mut out = Bytes::new(env);
+    for part in parts {
+        out.append(&Bytes::from_array(env, part));
+    }
+    out
+}
+
+fn arg_json(arg: &Arg) -> String {
+    match arg {
+        Arg::Bytes(bytes) => format!("\"{}\"", hex(bytes)),
+        Arg::U32(n) => format!("{}", n),
+        Arg::U32s(ns) => format!("[{}]", ns.iter().map(|n| format!("{}", n)).collect::<Vec<_>>().join(", ")),
+        Arg::Text(text) => format!("\"{}\"", text),
+    }
+}
+
+fn hex(bytes: &Bytes) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,218 @@
+//! # Compressed G1 points
+//!
+//! The host only takes uncompressed (96-byte) G1 points, so the 48-byte
+//! compressed encoding is expanded here. The encoding is the usual
+//! ZCash / IETF one: x big-endian with the top three bits of the first byte
+//! used as flags.
+//!
+//! | Bit  | Flag        | Meaning                                      |
+//! |------|-------------|----------------------------------------------|
+//! | 0x80 | compression | always set in a 48-byte encoding             |
+//! | 0x40 | infinity    | the identity; every other bit must be zero   |
+//! | 0x20 | sort        | y is the larger of y and p − y               |
+//!
+//! Recovering y takes one square root in Fp. p ≡ 3 (mod 4), so
+//! `√a = a^((p+1)/4)`, checked by squaring. Elements are six little-endian
+//! u64 limbs in Montgomery form. Every input is public, so nothing here
+//! needs to run in constant time.
+
+/// Base field element, little-endian limbs.
+type Fp = [u64; 6];
+
+/// BLS12-381 base field modulus p.
+const P: Fp = [
+    0xb9fe_ffff_ffff_aaab,
+    0x1eab_fffe_b153_ffff,
+    0x6730_d2a0_f6b0_f624,
+    0x6477_4b84_f385_12bf,
+    0x4b1b_a7b6_434b_acd7,
+    0x1a01_11ea_397f_e69a,
+];
+
+/// −p⁻¹ mod 2^64.
+const INV: u64 = 0x89f3_fffc_fffc_fffd;
+
+/// 2^768 mod p, to move into Montgomery form.
+const R2: Fp = [
+    0xf4df_1f34_1c34_1746,
+    0x0a76_e6a6_09d1_04f1,
+    0x8de5_476c_4c95_b6d5,
+    0x67eb_88a9_939d_83c0,
+    0x9a79_3e85_b519_952d,
+    0x1198_8fe5_92ca_e3aa,
+];
+
+/// (p + 1) / 4, the square root exponent.
+const SQRT_EXP: Fp = [
+    0xee7f_bfff_ffff_eaab,
+    0x07aa_ffff_ac54_ffff,
+    0xd9cc_34a8_3dac_3d89,
+    0xd91d_d2e1_3ce1_44af,
+    0x92c6_e9ed_90d2_eb35,
+    0x0680_447a_8e5f_f9a6,
+];
+
+/// Curve constant b of y² = x³ + 4.
+const B: Fp = [4, 0, 0, 0, 0, 0];
+
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SORT_FLAG: u8 = 0x20;
+
+/// Expand a compressed G1 point to the 96-byte uncompressed encoding.
+///
+/// Returns `None` unless the flags are consistent, x is canonical (< p) and
+/// x³ + 4 is a square. The result is on the curve but may be outside the
+/// prime-order subgroup, so callers still run their subgroup checks.
+pub(crate) fn decompress_g1(compressed: &[u8; 48]) -> Option<[u8; 96]> {
+    let flags = compressed[0] & (COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+    if flags & COMPRESSION_FLAG == 0 {
+        return None;
+    }
+    let mut x_bytes = *compressed;
+    x_bytes[0] &= !flags;
+
+    let mut out = [0u8; 96];
+    if flags & INFINITY_FLAG != 0 {
+        if flags & SORT_FLAG != 0 || x_bytes.iter().any(|b| *b != 0) {
+            return None;
+        }
+        out[0] = INFINITY_FLAG;
+        return Some(out);
+    }
+
+    // y² = x³ + 4
+    let x = to_montgomery(&from_be_bytes(&x_bytes)?);
+    let rhs = add(&mul(&mul(&x, &x), &x), &to_montgomery(&B));
+    let root = pow(&rhs, &SQRT_EXP);
+    if mul(&root, &root) != rhs {
+        return None;
+    }
+
+    // y = 0 has no larger root, so the sort flag must be clear
+    let y = from_montgomery(&root);
+    if y == [0; 6] && flags & SORT_FLAG != 0 {
+        return None;
+    }
+    let neg_y = if y == [0; 6] { y } else { sub(&P, &y) };
+    let y = if (flags & SORT_FLAG != 0) == less_than(&neg_y, &y) { y } else { neg_y };
+
+    out[..48].copy_from_slice(&x_bytes);
+    out[48..].copy_from_slice(&to_be_bytes(&y));
+    Some(out)
+}
+
+fn from_be_bytes(bytes: &[u8; 48]) -> Option<Fp> {
+    let mut limbs = [0u64; 6];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let start = 48 - 8 * (i + 1);
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[start..start + 8]);
+        *limb = u64::from_be_bytes(word);
+    }
+    less_than(&limbs, &P).then_some(limbs)
+}
+
+fn to_be_bytes(a: &Fp) -> [u8; 48] {
+    let mut bytes = [0u8; 48];
+    for (i, limb) in a.iter().enumerate() {
+        let start = 48 - 8 * (i + 1);
+        bytes[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+fn less_than(a: &Fp, b: &Fp) -> bool {
+    for i in (0..6).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// a − b for a ≥ b.
+fn sub(a: &Fp, b: &Fp) -> Fp {
+    let mut out = [0u64; 6];
+    let mut borrow = false;
+    for i in 0..6 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 || b2;
+    }
+    out
+}
+
+fn add(a: &Fp, b: &Fp) -> Fp {
+    let mut out = [0u64; 6];
+    let mut carry = false;
+    for i in 0..6 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    // a, b < p < 2^381, so the sum never carries out of 384 bits
+    if less_than(&out, &P) {
+        out
+    } else {
+        sub(&out, &P)
+    }
+}
+
+/// Montgomery product a·b·2^−384 mod p (CIOS).
+fn mul(a: &Fp, b: &Fp) -> Fp {
+    let mut t = [0u64; 8];
+    for &bi in b.iter() {
+        let mut carry = 0u64;
+        for j in 0..6 {
+            let v = t[j] as u128 + a[j] as u128 * bi as u128 + carry as u128;
+            t[j] = v as u64;
+            carry = (v >> 64) as u64;
+        }
+        let v = t[6] as u128 + carry as u128;
+        t[6] = v as u64;
+        t[7] = (v >> 64) as u64;
+
+        let m = t[0].wrapping_mul(INV);
+        let v = t[0] as u128 + m as u128 * P[0] as u128;
+        let mut carry = (v >> 64) as u64;
+        for j in 1..6 {
+            let v = t[j] as u128 + m as u128 * P[j] as u128 + carry as u128;
+            t[j - 1] = v as u64;
+            carry = (v >> 64) as u64;
+        }
+        let v = t[6] as u128 + carry as u128;
+        t[5] = v as u64;
+        t[6] = t[7] + (v >> 64) as u64;
+    }
+
+    let out = [t[0], t[1], t[2], t[3], t[4], t[5]];
+    if t[6] == 0 && less_than(&out, &P) {
+        out
+    } else {
+        sub(&out, &P)
+    }
+}
+
+fn pow(base: &Fp, exp: &Fp) -> Fp {
+    let mut acc = to_montgomery(&[1, 0, 0, 0, 0, 0]);
+    for i in (0..6).rev() {
+        for bit in (0..64).rev() {
+            acc = mul(&acc, &acc);
+            if (exp[i] >> bit) & 1 == 1 {
+                acc = mul(&acc, base);
+            }
+        }
+    }
+    acc
+}
+
+fn to_montgomery(a: &Fp) -> Fp {
+    mul(a, &R2)
+}
+
+fn from_montgomery(a: &Fp) -> Fp {
+    mul(a, &[1, 0, 0, 0, 0, 0])
+}
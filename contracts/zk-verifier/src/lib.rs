@@ -1,8 +1,8 @@
 #![no_std]
 
-//! # ZK Commitment Verifier (Multi-Mode: NIZK, Pedersen, Ring Sigma, Hand Proof)
+//! # ZK Commitment Verifier (Multi-Mode: NIZK, Pedersen, Ring Sigma, Hand Proof, PLONK)
 //!
-//! On-chain verifier contract supporting four verification modes:
+//! On-chain verifier contract supporting five verification modes:
 //!
 //! | Mode | Name                | Curve      | Use Case                    |
 //! |------|---------------------|------------|-----------------------------|
@@ -10,6 +10,24 @@
 //! | 4    | Pedersen+Sigma      | BLS12-381  | EC seed commitment          |
 //! | 7    | Card Play Ring Sigma| BLS12-381  | ZK card play compliance     |
 //! | 8    | Cangkul Hand Proof  | BLS12-381  | ZK suit exclusion (cangkul) |
+//! | 9    | PLONK (KZG)         | BLS12-381  | Registered circuit proofs   |
+//! | 10   | Range Proof         | BLS12-381  | Committed card value bounds |
+//! | 11   | Merkle Membership   | keccak256  | Card in committed hand tree |
+//! | 12   | Hidden-Set Ring     | BLS12-381  | Mode 7 over hand commitments|
+//! | 13   | Private Cangkul Hand| BLS12-381  | Mode 8 without card ids     |
+//! | 14   | Shuffle Permutation | BLS12-381  | Deck is a permutation       |
+//! | 15   | ElGamal Share       | BLS12-381  | Mental-poker deck shares    |
+//! | 16   | Card Comparison     | BLS12-381  | Trick winner without reveal |
+//! | 17   | Commitment Equality | BLS12-381  | Re-commit a card unopened   |
+//! | 18   | Deal Consistency    | BLS12-381  | Hand commitments match deal |
+//! | 19   | Threshold Seed      | BLS12-381  | t-of-n seed commitments     |
+//! | 20   | BLS Attestation     | BLS12-381  | Signed off-chain agreements |
+//! | 21   | Hand Count          | BLS12-381  | Committed hand has k cards  |
+//! | 22   | UltraHonk (routed)  | BN254      | Noir proofs, external VK    |
+//! | 23   | Vector Commitment   | BLS12-381  | Open one position of a hand |
+//! | 24   | Linked Card Play    | BLS12-381  | Ring play tied to the deal  |
+//! | 25   | Ed25519 Seed        | Ed25519    | Cheap signed seed reveal    |
+//! | 26   | Trick Bundle        | BLS12-381  | Both plays of a trick       |
 //!
 //! ## Mode 2 — NIZK Seed Proof of Knowledge (cangkulan, enhanced ZK)
 //!
@@ -74,7 +92,8 @@
 //! 4. Verify `N' == nullifier` (session-binding check)
 //! 5. Recompute challenge: `e = keccak256(commitment || session_id || player || "ZKV2")`
 //! 6. Verify response: `keccak256(seed_hash || e || blinding) == response`
-//! 7. Entropy check: `seed_hash` must have >= 4 distinct byte values
+//! 7. Entropy check: `seed_hash` must satisfy the stored `EntropyPolicy`
+//!    (default: >= 4 distinct byte values)
 //!
 //!
 //! ## Mode 4 — Pedersen+Sigma Protocol (BLS12-381) — NEW!
@@ -91,6 +110,9 @@
 //! H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1")  (nothing-up-my-sleeve)
 //! ```
 //!
+//! The constructor caches H in instance storage; every Pedersen-based mode
+//! loads it from there and derives it only if the entry is missing.
+//!
 //! **Commitment:**
 //! ```text
 //! C = seed_scalar · G + blinding_scalar · H     (Pedersen commitment)
@@ -122,10 +144,412 @@
 //! - **NIZK seed mode**: Proof is exactly 64 bytes (blinding + response)
 //! - **Pedersen+Sigma mode**: Proof is exactly 128 bytes (R + z_r)
 //! - **Card Play Ring Sigma mode**: Proof is 96 + N×64 bytes where N ∈ [1, 9]
+//!   (up to 13 under a 52-card `DeckParams`)
 //! - **Cangkul Hand Proof mode**: Proof is exactly 228 bytes (k + A + R + z)
+//! - **PLONK mode**: Proof is exactly 1056 bytes (9 G1 commitments + 6 Fr evaluations)
+//!
+//! Modes 4, 7, 8 and 12 also take any of their G1 points compressed (48
+//! bytes, decompressed in `fp`), shrinking those proofs by 48 bytes per
+//! point: 80 bytes for Mode 4, 48 + N×64 for Mode 7, 180 or 132 for Mode 8.
+//!
+//! ## Embedding (`lib` feature)
+//!
+//! With the `lib` feature the contract exports are compiled out and the
+//! per-mode routines (`verify_nizk_seed`, `verify_pedersen_sigma`,
+//! `verify_card_play_ring`, `verify_cangkul_hand`) become a plain library
+//! that another Soroban game can call in-process:
+//!
+//! ```toml
+//! zk-verifier = { path = "../zk-verifier", features = ["lib"] }
+//! ```
+//!
+//! ```ignore
+//! let ok = ZkCommitmentVerifier::verify_card_play_ring(&env, &public_inputs, &proof);
+//! ```
+//!
+//! This saves the cross-contract call per proof at the cost of the caller's
+//! own CPU budget. The standalone contract (default build) is unchanged.
+//!
+//! ## Proof builders (`testutils` feature)
+//!
+//! `testutils` adds the [`testutils`] module: Mode 7 and Mode 8 builders
+//! (`card_ring_proof`, `cangkul_hand_proof`) producing the `commit_hash`
+//! and proof a game submits in `commit_play_zk` and `commit_cangkul_zk`,
+//! so its tests can run against this contract instead of a mock. The
+//! `cangkulan-prover` crate behind them is re-exported for other modes.
+//!
+//! ## Typed entrypoints
+//!
+//! `verify_nizk`, `verify_ring` and `verify_hand` accept `NizkInputs`,
+//! `RingInputs` and `HandInputs` instead of raw public-input bytes. The
+//! contract encodes the struct into the Mode 2, 7 or 8 layout itself and
+//! requires the exact proof length, so inputs can no longer be truncated.
+//!
+//! ## Debug reports
+//!
+//! `verify_debug` runs a Mode 2, 4, 7 or 8 proof through the same routine
+//! as `verify` but returns a `VerifyReport`: each sub-check in order
+//! (binding, nullifier, challenge, response, entropy, subgroup, schnorr)
+//! with keccak256 of the value the verifier recomputed, and the failing
+//! check's number and error. A prover author compares the digests against
+//! their own intermediates instead of guessing at a bare `false`. It is
+//! admin-gated, which still lets anyone simulate it.
+//!
+//! ## Verification log
+//!
+//! Each success in a mode that binds a session bumps a per-(player, mode)
+//! counter for that session, which `get_verification_log` returns. An
+//! auditor checks a finished game against it instead of replaying events.
+//! The log is temporary, capped at 32 pairs, and absent from the `lib` build.
+//!
+//! ## Entropy policy
+//!
+//! The Mode 2 entropy rule is an `EntropyPolicy` from the shared
+//! `cangkulan-common` crate, kept in instance storage and changed with
+//! `set_entropy_policy`. The game contract stores its own copy and checks
+//! it on every reveal. The `lib` build always uses the default policy.
+//!
+//! ## Deck parameters
+//!
+//! Modes 7, 8 and 12 read their card encoding from a `DeckParams` (also
+//! from `cangkulan-common`) in instance storage: deck size, cards per suit,
+//! and the largest ring and hand. The default is the 36-card deck;
+//! `set_deck_params(DeckParams::standard())` switches to 52 cards with
+//! rings of up to 13. The `lib` build always uses the default.
+//!
+//! ## Operator controls
+//!
+//! The admin can disable individual modes with `set_mode_enabled` (e.g. the
+//! legacy Mode 2 once Mode 4 is the default) and turn on a caller allowlist.
+//! With the allowlist on, only `verify_from` called by an allowlisted
+//! contract verifies anything. Neither control exists in the `lib` build.
+//!
+//! `set_network_binding` binds the Mode 2, 4, 7 and 8 challenges to the
+//! network: the 32-byte `network_id` (sha256 of the network passphrase) is
+//! appended to the preimage just before the domain tag. A testnet proof then
+//! never verifies on a mainnet deployment, even for a colliding session id.
+//! Binding is off by default, so existing provers keep working until the
+//! operator switches it on. The `lib` build is always unbound.
+//!
+//! ## Proof format tag
+//!
+//! `verify`, `verify_from`, `verify_and_record` and `verify_debug` take a
+//! proof prefixed with a one-byte tag, `PROOF_FORMAT_V1 | mode`, and pick
+//! the mode from it instead of from the proof length. Later layouts (more
+//! compressed points, new modes) can then reuse any length. Untagged proofs
+//! are still detected by length while `is_legacy_proofs_enabled`, which is
+//! the default; `set_legacy_proofs_enabled(false)` refuses them with
+//! `UnknownProofVersion`. The game contract tags every proof it forwards.
+//! The typed entrypoints and the `lib` routines take untagged proofs.
+//!
+//! ## Ledger window
+//!
+//! Any public inputs ending in `session_id(4) || player(var)` may append
+//! `LEDGER_WINDOW_TAG || valid_until_ledger(4)`. The proof then fails with
+//! `ProofExpired` once `env.ledger().sequence()` passes `valid_until_ledger`.
+//! The trailer sits inside the bytes every mode hashes into its challenge,
+//! so stripping or extending it invalidates the proof. Mode 20 is the
+//! exception: signers never sign the player, so an attestation that needs a
+//! deadline must put it in the signed message.
+//!
+//! ## sha256 transcripts
+//!
+//! Modes 2, 4, 7, 8 and 12 also accept `TRANSCRIPT_HASH_TAG || hash_id(1)`
+//! between the player address and any ledger window. `hash_id` 1
+//! (`TRANSCRIPT_HASH_SHA256`) finishes the challenge with sha256 instead of
+//! `fs_digest`, and Mode 2's response hash follows it; any other id fails
+//! with `UnknownTranscriptHash`. Soroban meters sha256 well below keccak256,
+//! which adds up over the many Mode 7 plays of a game. Commitments, binding
+//! hashes and the Mode 2 nullifier stay keccak256, so a seed's nullifier does
+//! not depend on the prover's choice. The selector is hashed into the
+//! challenge like the ledger window, so it cannot be stripped or swapped.
+//!
+//! ## Recorded verifications
+//!
+//! `verify_and_record` verifies a proof in its own transaction and keeps
+//! `(consumer, session_id, player, mode, keccak256(public_inputs))` in
+//! temporary storage; the consumer contract takes it exactly once with
+//! `consume_verification`. This is the game's `verify_noir_seed` split-TX
+//! pattern for any mode.
+//!
+//! A proof too large for one transaction can be uploaded in pieces:
+//! `begin_verify` declares its length and takes the first chunk,
+//! `continue_verify` appends the rest, and `finish_verify` verifies the
+//! assembled proof in a transaction of its own and records it the same way.
+//! Only the upload is split. Every mode, Mode 22 included, still verifies in
+//! one call, because the UltraHonk verifier has no incremental interface.
+//!
+//! ## Wire specification
+//!
+//! Mode numbers, challenge tags and byte layouts are public constants in
+//! [`spec`] (defined in `cangkulan-common` and re-exported here), so Rust
+//! provers and tooling can build proofs without copying magic bytes.
+//!
+//! ## Proof encoding
+//!
+//! Byte layouts and challenge preimages are handled by the `transcript`
+//! module: typed readers (`ProofReader`, `InputsReader`, `VkReader`) decode
+//! G1/G2 points, Fr scalars, u32s and digests, and the `FiatShamir` builder
+//! assembles each tagged challenge. Every mode below goes through them.
+//! Proof scalars must be canonical (`< r`) and, outside PLONK, proof points
+//! must not be the G1 identity, so no proof has a second valid encoding.
+//!
+//! ## Constant-time comparisons (`ct-compare` feature)
+//!
+//! Digest checks (commitments, nullifiers, responses, Merkle roots) go
+//! through `digest_eq`. By default that is a host object comparison, which
+//! Soroban meters by length rather than by the position of the first
+//! mismatch. With `ct-compare` the bytes are folded with XOR/OR in the
+//! contract itself, so the guest code has no data-dependent early exit
+//! either. All compared values are public, so neither build leaks a secret.
+//!
+//! ## Poseidon Fiat-Shamir (`poseidon-fs` feature)
+//!
+//! Every field-valued challenge (e, ρ, x and the PLONK transcript) is read
+//! from `fs_digest`. By default that is keccak256. With `poseidon-fs` it is a
+//! Poseidon2 sponge over BLS12-381 Fr (t = 3, x^5, 8 + 56 rounds, HorizenLabs
+//! constants), so a prover running inside a SNARK circuit can rebuild the
+//! transcript cheaply. Preimages and domain tags are identical in both builds;
+//! Mode 2 and the binding hashes (`commit_hash`, Merkle nodes) stay keccak256.
+//! Each permutation absorbs 62 transcript bytes for ~0.7M CPU, so the long
+//! Mode 10, 13, 14, 16 and 17 transcripts need more than the default budget.
+//!
+//! ## Mode 9 — PLONK with KZG Commitments (BLS12-381)
+//!
+//! Generic circuit proofs, so circuit-based proofs are not tied to
+//! UltraKeccakHonk. Verification keys live in the admin-managed registry
+//! (`register_vk` / `get_vk`); a proof names its circuit in the public inputs:
+//!
+//! ```text
+//! public_inputs = circuit_id(4) || n_public(4) || public_1(32) || ... || public_n(32)
+//! proof         = A || B || C || Z || T1 || T2 || T3 || Wξ || Wξω || ā || b̄ || c̄ || s̄1 || s̄2 || z̄ω
+//! ```
+//!
+//! The final check is the standard KZG batch opening at ξ and ξω, a single
+//! two-pair `pairing_check`.
+//!
+//! ## Mode 10 — Range Proof for Committed Card Values (BLS12-381)
+//!
+//! Shows that a Pedersen commitment C = v·G + r·H hides v ∈ [lo, hi] (card
+//! ids 0..35, face values 2..10) without opening it. v − lo and
+//! v − lo + (2^m − 1 − (hi − lo)) are both split into m bit commitments, each
+//! with a CDS OR-proof that it commits to 0 or 1:
+//!
+//! ```text
+//! public_inputs = C(96) || lo(4) || hi(4) || session_id(4) || player(var)
+//! proof         = [B_i || R0_i || R1_i || e0_i || z0_i || z1_i] × 2m   (384 bytes per bit)
+//! ```
+//!
+//! All bit equations and both recompositions are folded into one `g1_msm`.
+//!
+//! ## Mode 11 — Merkle Membership for Hand Commitments (keccak256)
+//!
+//! Shows a played card commitment C is a leaf of the player's hand tree under
+//! the trick suit, so card plays no longer need a plaintext valid set:
+//!
+//! ```text
+//! public_inputs = root(32) || suit(4) || C(96)
+//! proof         = index(4) || sibling_0(32) || ... || sibling_{d-1}(32)   (d ≤ 6)
+//! ```
+//!
+//! ## Mode 13 — Private Cangkul Hand Proof (BLS12-381)
+//!
+//! Mode 8 without the plaintext hand: every card commitment C_i carries
+//! three 3-branch OR-proofs (suit, and the two base-3 digits of the rank)
+//! showing it is one of the 27 ids outside the trick suit. One proof covers
+//! up to 4 cards; larger hands are proven in chunks:
+//!
+//! ```text
+//! public_inputs = commit_hash(32) || trick_suit(4) || n(4) || C_1..C_n(96 each) || first(4) || session_id(4) || player(var)
+//! proof         = [S || A || R × 9 || (e_0..2 || z_0..2) × 3] × k   (1632 bytes per card)
+//! ```
+//!
+//! ## Mode 14 — Shuffle Permutation (BLS12-381)
+//!
+//! Shows 36 position commitments E_i = a_i·G_i + r_i·H hide a permutation
+//! of the canonical deck, via a Bayer–Groth single value product argument
+//! on ∏(a_i − x) == ∏(i − x). The 36 generators are precomputed
+//! (`shuffle_generators.bin`):
+//!
+//! ```text
+//! public_inputs = E_1(96) || ... || E_36(96) || session_id(4) || player(var)
+//! proof         = c_d || c_δ || c_Δ || ã_1..36 || b̃_1..36 || r̃ || s̃   (2656 bytes)
+//! ```
+//!
+//! ## Mode 15 — Mental-Poker ElGamal Shares (BLS12-381)
+//!
+//! The players jointly encrypt and shuffle the deck off-chain under
+//! PK = pk_A + pk_B. Each re-encryption and each partial decryption share
+//! comes with a Chaum–Pedersen proof that log_G(P) == log_B(Q):
+//!
+//! ```text
+//! public_inputs = kind(4) || statement points || session_id(4) || player(var)
+//! proof         = output(96) || A1(96) || A2(96) || z(32)   (320 bytes)
+//! ```
+//!
+//! ## Mode 16 — Card Comparison (BLS12-381)
+//!
+//! Resolves a trick without opening either card: for two card commitments
+//! and a claimed winner, three 9-branch OR-proofs show both cards are in the
+//! trick suit and the winner's value (id mod 9) is at least the loser's:
+//!
+//! ```text
+//! public_inputs = trick_suit(4) || winner(4) || C_0(96) || C_1(96) || session_id(4) || player(var)
+//! proof         = R × 27 || (e_0..8 || z_0..8) × 3   (4320 bytes)
+//! ```
+//!
+//! ## Mode 17 — Commitment Equality (BLS12-381)
+//!
+//! Shows pairs of Pedersen commitments C_i, C'_i open to the same card id
+//! under different blindings, so a card committed at the deal can be
+//! re-committed for play without opening it. Each pair is a Chaum–Pedersen
+//! style sigma sharing one response for the card id:
+//!
+//! ```text
+//! public_inputs = n(4) || (C_i(96) || C'_i(96)) × n || session_id(4) || player(var)
+//! proof         = n(4) || (R_i || R'_i || z_v,i || z_i || z'_i) × n   (4 + 288n bytes)
+//! ```
+//!
+//! ## Mode 18 — Deal Consistency (BLS12-381)
+//!
+//! Ties a player's 5 hand commitments P_i = c_i·G + r_i·H to the shuffle:
+//! the verifier re-derives the deck from both seed hashes and the session id
+//! and checks each P_i opens to deck position 5·slot + i, given a Schnorr
+//! proof of the blinding over H:
+//!
+//! ```text
+//! public_inputs = seed_hash1(32) || seed_hash2(32) || slot(4) || P_0..P_4 (5×96) || session_id(4) || player(var)
+//! proof         = (R_i(96) || z_i(32)) × 5   (640 bytes)
+//! ```
+//!
+//! ## Mode 19 — Threshold Seed Commitment (BLS12-381)
+//!
+//! One call for the seed commitments of an n-party game (n ∈ [2, 8]): at
+//! least t parties open their Mode 4 commitment C_i to seed_hash_i, each
+//! with its own Schnorr proof of the blinding, batched into one MSM:
+//!
+//! ```text
+//! public_inputs = n(4) || t(4) || mask(4) || C_i(96) × n || seed_hash_i(32) × |mask| || session_id(4) || player(var)
+//! proof         = mask(4) || (R_i(96) || z_i(32)) × |mask|   (4 + 128k bytes)
+//! ```
+//!
+//! ## Mode 20 — BLS Attestation (BLS12-381)
+//!
+//! A BLS signature (G1 keys, G2 signatures) from up to 4 signers over a
+//! session-bound message, so relayers or a matchmaking service can attest
+//! to off-chain agreements. Each signer signs `pk_i || session_id || message`
+//! and the signatures are summed; one pairing check verifies the aggregate:
+//!
+//! ```text
+//! public_inputs = k(4) || pk_i(96) × k || msg_len(4) || message || session_id(4) || player(var)
+//! proof         = σ(192, G2)
+//! ```
+//!
+//! ## Mode 21 — Hand Count (BLS12-381)
+//!
+//! Proves an aggregate hand commitment A = s·G + r·H + k·K holds exactly k
+//! cards, where every card adds one copy of the count generator K. The
+//! prover opens A − k·K over G and H without revealing s or r; k = 0 shows
+//! the hand is empty:
+//!
+//! ```text
+//! public_inputs = A(96) || k(4) || session_id(4) || player(var)
+//! proof         = e(32) || z_s(32) || z_r(32)   (96 bytes)
+//! ```
+//!
+//! ## Mode 22 — UltraHonk routing
+//!
+//! Noir UltraKeccakHonk proofs are verified by a separate contract with the
+//! circuit's key baked in. Once the admin registers it with
+//! `set_ultrahonk_verifier`, any proof longer than `ULTRAHONK_MIN_PROOF_LEN`
+//! that matches no native mode is forwarded to its `verify_proof` unchanged,
+//! so callers talk to one verifier for every proof system. Inputs led by a
+//! `circuit_id(4)` go instead to the verifier registered for that circuit
+//! with `register_ultrahonk_circuit`, so circuits rotate like Mode 9 keys. The UltraHonk
+//! contract traps on an invalid proof; the call is made with `try_`, so the
+//! trap becomes an ordinary `false` with `UltraHonkProofRejected` instead of
+//! aborting the caller's transaction. Without a registered address (and
+//! always in the `lib` build) Mode 22 fails with `UltraHonkVerifierNotSet`.
+//!
+//! ## Mode 23 — Vector Commitment (BLS12-381)
+//!
+//! Commits a whole hand positionally in one point, V = Σ c_i·H_i + r·H, with
+//! an independent generator H_i per position (hash_to_g1 under an indexed
+//! DST). Unlike the Mode 8 aggregate, which only binds Σ c_i, V binds every
+//! card to its slot. A proof opens any subset of positions and shows
+//! knowledge of the rest without revealing them:
+//!
+//! ```text
+//! public_inputs = V(96) || k(4) || m(4) || [position(4) || card(4)] × m || session_id(4) || player(var)
+//! proof         = k(4) || m(4) || e(32) || z_r(32) || z_i(32) × (k − m)
+//! ```
+//!
+//! ## Mode 24 — Linked Card Play (BLS12-381)
+//!
+//! A Mode 7 ring only shows that C hides *some* card of the valid set. Mode
+//! 24 adds a second ring, under the same challenge, showing C hides the same
+//! card as one of the player's hand commitments P_j from the deal (Mode 18),
+//! so the played card is tied to the cards actually dealt:
+//!
+//! ```text
+//! public_inputs = commit_hash(32) || N(4) || valid_set[N](4 each) || k(4) || P[k](96 each) || session_id(4) || player(var)
+//! proof         = N(4) || k(4) || C(48, compressed) || [e_i(32) || z_i(32)] × N || [f_j(32) || y_j(32)] × k
+//! ```
+//!
+//! ## Mode 25 — Ed25519 Seed (native host crypto)
+//!
+//! A seed proof for clients too slow for BLS12-381 scalar multiplications.
+//! The public inputs are Mode 2's; the player's Ed25519 public key takes
+//! the place of the blinding in the commitment, and the proof is a
+//! signature over the whole statement, checked with `ed25519_verify`:
+//!
+//! ```text
+//! commitment    = keccak256(seed_hash || pk || player)
+//! proof         = 0xED || pk(32) || sig(64)          (97 bytes)
+//! sig           = Ed25519_sign(sk, public_inputs || "ZKPM")
+//! ```
+//!
+//! The host traps on a bad signature instead of returning an error, so an
+//! invalid Mode 25 signature aborts the call rather than returning `false`.
+//! Every other check fails normally first.
+//!
+//! ## Mode 26 — Trick Bundle (BLS12-381)
+//!
+//! Two Mode 7 or Mode 8 proofs for one trick, by two players, verified in
+//! one call. The second proof's challenge absorbs a digest of the first, so
+//! the follower proves on their own after seeing the lead, and neither half
+//! verifies next to any other:
+//!
+//! ```text
+//! public_inputs = mode_a(4) || len_a(4) || inputs_a || mode_b(4) || inputs_b
+//! proof         = len_a(4) || proof_a || proof_b                    (tagged only)
+//! link          = keccak256(mode_a || len_a || inputs_a || proof_a)
+//! e_b           = H(preimage_b || [network_id] || link || "ZKPN")
+//! ```
+//!
+//! The bundle has no length rule, so it is only accepted with the format
+//! tag. Each play counts in the verification log under its own mode.
+
+#[cfg(not(feature = "lib"))]
+use soroban_sdk::{contract, contractimpl};
+use core::cell::RefCell;
 
-use soroban_sdk::{contract, contracterror, contractevent, contractimpl, Bytes, BytesN, Env, Vec};
-use soroban_sdk::crypto::bls12_381::{Bls12_381, Fr, G1Affine};
+use soroban_sdk::{
+    contractclient, contracterror, contractevent, contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec,
+    U256,
+};
+use soroban_sdk::crypto::bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+
+pub use cangkulan_common::{spec, DeckParams, EntropyPolicy};
+use cangkulan_common::{deal_seed, shuffled_deck};
+
+use spec::*;
+
+mod fp;
+#[cfg(feature = "testutils")]
+pub mod testutils;
+mod transcript;
+use transcript::{FiatShamir, InputsReader, ProofReader, Reader, TranscriptHash, VkReader};
 
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -159,2319 +583,10409 @@ pub enum ZkVerifyError {
     HandSuitViolation = 22,
     HandCardCountMismatch = 23,
     HandSchnorrCheckFailed = 24,
+    // Verification key registry / PLONK errors (Mode 9)
+    VkNotFound = 25,
+    PlonkInvalidVk = 27,
+    PlonkPairingFailed = 28,
+    PlonkInputCountMismatch = 29,
+    PlonkPointNotInSubgroup = 30,
+    AdminNotSet = 31,
+    // Range proof errors (Mode 10)
+    RangeInvalidBounds = 32,
+    RangeCheckFailed = 33,
+    RangePointNotInSubgroup = 34,
+    // Merkle membership errors (Mode 11)
+    MerkleInvalidDepth = 35,
+    MerkleRootMismatch = 36,
+    // Private cangkul hand errors (Mode 13)
+    PrivateHandCheckFailed = 37,
+    PrivateHandPointNotInSubgroup = 38,
+    // Shuffle permutation errors (Mode 14)
+    ShuffleCheckFailed = 39,
+    ShufflePointNotInSubgroup = 40,
+    // Mental-poker ElGamal share errors (Mode 15)
+    ElGamalCheckFailed = 41,
+    ElGamalPointNotInSubgroup = 42,
+    ElGamalUnknownKind = 43,
+    // Admin configuration
+    InvalidEntropyPolicy = 44,
+    // Canonical encodings (every BLS12-381 mode)
+    NonCanonicalScalar = 45,
+    IdentityPoint = 46,
+    // Operator controls
+    ModeDisabled = 47,
+    CallerNotAllowed = 48,
+    UnknownMode = 49,
+    // Card comparison errors (Mode 16)
+    CompareInvalidStatement = 50,
+    ComparePointNotInSubgroup = 51,
+    CompareCheckFailed = 52,
+    // Commitment equality errors (Mode 17)
+    CommitEqInvalidStatement = 53,
+    CommitEqPointNotInSubgroup = 54,
+    CommitEqCheckFailed = 55,
+    // Deal consistency errors (Mode 18)
+    DealInvalidStatement = 56,
+    DealPointNotInSubgroup = 57,
+    DealCheckFailed = 58,
+    // Threshold seed errors (Mode 19)
+    ThresholdInvalidStatement = 59,
+    ThresholdPointNotInSubgroup = 60,
+    ThresholdCheckFailed = 61,
+    // BLS attestation errors (Mode 20)
+    BlsInvalidStatement = 62,
+    BlsPointNotInSubgroup = 63,
+    BlsSignatureInvalid = 64,
+    // Ledger window (every mode with a session)
+    ProofExpired = 65,
+    // Hand count errors (Mode 21)
+    HandCountInvalidStatement = 66,
+    HandCountPointNotInSubgroup = 67,
+    HandCountCheckFailed = 68,
+    // UltraHonk routing (Mode 22)
+    UltraHonkVerifierNotSet = 69,
+    UltraHonkProofRejected = 70,
+    // Chunked upload (begin_verify / continue_verify / finish_verify)
+    ChunkedProofTooLong = 71,
+    ChunkedUploadMissing = 72,
+    ChunkedUploadIncomplete = 73,
+    // Transcript hash selector (Modes 2, 4, 7, 8, 12)
+    UnknownTranscriptHash = 74,
+    // Debug verification (verify_debug)
+    NoDebugReport = 75,
+    // Vector commitment errors (Mode 23)
+    VectorInvalidStatement = 76,
+    VectorPointNotInSubgroup = 77,
+    VectorCheckFailed = 78,
+    // Linked card play errors (Mode 24)
+    LinkedHandCheckFailed = 79,
+    // Deck parameters (set_deck_params)
+    InvalidDeckParams = 80,
+    // Proof format tag
+    UnknownProofVersion = 81,
+    // Trick bundle errors (Mode 26)
+    TrickInvalidStatement = 82,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
 //  Events
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// Published by `verify`, `verify_from`, `verify_and_record` and the typed
+/// entrypoints before any check runs. `mode` is 0 when no mode matches.
+#[contractevent]
+pub struct EvVerifyAttempt {
+    pub mode: u32,
+    pub session_id: Option<u32>,
+    pub inputs_len: u32,
+    pub proof_len: u32,
+}
+
+/// `check` is the 1-based position of the failing check within the mode's
+/// routine, or 0 when the call was rejected before the routine ran.
 #[contractevent]
 pub struct EvVerifyFailed {
     pub reason: u32,
+    pub mode: u32,
+    pub session_id: Option<u32>,
+    pub inputs_len: u32,
+    pub proof_len: u32,
+    pub check: u32,
 }
 
 #[contractevent]
 pub struct EvVerifySuccess {
-    pub mode: u32, // 2 = NIZK seed, 4 = Pedersen+Sigma, 7 = Card Play Ring Sigma, 8 = Cangkul Hand Proof
+    pub mode: u32, // 2 = NIZK seed, 4 = Pedersen+Sigma, 7 = Card Play Ring Sigma, 8 = Cangkul Hand Proof, 9 = PLONK, 10 = Range, 11 = Merkle membership, 12 = Hidden-set ring, 13 = Private cangkul hand, 14 = Shuffle permutation, 15 = ElGamal share, 16 = Card comparison
 }
 
-// ═══════════════════════════════════════════════════════════════════════════════
-//  Domain Separators (prevent cross-protocol attacks)
-// ═══════════════════════════════════════════════════════════════════════════════
+#[contractevent]
+pub struct EvVkRegistered {
+    pub circuit_id: u32,
+    pub len: u32,
+}
+
+#[contractevent]
+pub struct EvModeToggled {
+    pub mode: u32,
+    pub enabled: bool,
+}
+
+#[contractevent]
+pub struct EvAllowlistToggled {
+    pub enabled: bool,
+}
 
-/// Domain separator for nullifier derivation: ASCII "NULL" = 0x4E554C4C
-const NULLIFIER_TAG: [u8; 4] = [0x4E, 0x55, 0x4C, 0x4C];
+#[contractevent]
+pub struct EvNetworkBindingToggled {
+    pub enabled: bool,
+}
+
+#[contractevent]
+pub struct EvLegacyProofsToggled {
+    pub enabled: bool,
+}
 
-/// Domain separator for Fiat-Shamir challenge: ASCII "ZKV2" = 0x5A4B5632
-const CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x56, 0x32];
+#[contractevent]
+pub struct EvUltraHonkVerifierSet {
+    pub verifier: Address,
+}
 
-/// Domain separator for Pedersen+Sigma Fiat-Shamir: ASCII "ZKP4" = 0x5A4B5034
-const PEDERSEN_CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x50, 0x34];
+#[contractevent]
+pub struct EvUltraHonkCircuitSet {
+    pub circuit_id: u32,
+    pub verifier: Address,
+}
 
-/// Domain separator for Card Play Ring Sigma Fiat-Shamir: ASCII "ZKP7" = 0x5A4B5037
-const RING_CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x50, 0x37];
+#[contractevent]
+pub struct EvCallerAllowed {
+    pub caller: Address,
+    pub allowed: bool,
+}
 
-/// Domain separator for Cangkul Hand Proof Fiat-Shamir: ASCII "ZKP8" = 0x5A4B5038
-const CANGKUL_CHALLENGE_TAG: [u8; 4] = [0x5A, 0x4B, 0x50, 0x38];
+#[contractevent]
+pub struct EvVerificationRecorded {
+    pub consumer: Address,
+    pub session_id: u32,
+    pub player: Address,
+    pub mode: u32,
+}
 
-/// Domain separation tag (DST) for hash_to_g1 to derive the Pedersen H generator.
-/// H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1")
-/// This is a nothing-up-my-sleeve construction: anyone can reproduce H.
-const PEDERSEN_H_MSG: &[u8] = b"PEDERSEN_H";
-const PEDERSEN_H_DST: &[u8] = b"SGS_CANGKULAN_V1";
+#[contractevent]
+pub struct EvVerificationConsumed {
+    pub consumer: Address,
+    pub session_id: u32,
+    pub player: Address,
+    pub mode: u32,
+}
 
 // ═══════════════════════════════════════════════════════════════════════════════
-//  Contract
+//  Cost estimates
 // ═══════════════════════════════════════════════════════════════════════════════
 
-#[contract]
-pub struct ZkCommitmentVerifier;
-
-#[contractimpl]
-impl ZkCommitmentVerifier {
-    /// Verify a ZK commitment proof (auto-detects mode by proof length).
-    ///
-    /// Returns `true` if the proof is valid, `false` otherwise.
-    /// Emits diagnostic events on success or failure.
-    pub fn verify(env: Env, public_inputs: Bytes, proof: Bytes) -> bool {
-        // ── Proof length determines the mode ────────────────────────────────
-        let proof_len = proof.len();
+/// Approximate resources of one `verify` call, returned by `estimate_cost`.
+/// `verify_and_record` writes one more (temporary) entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CostEstimate {
+    pub cpu_instructions: u64,
+    pub read_entries: u32,
+    pub write_entries: u32,
+}
 
-        if proof_len == 0 {
-            EvVerifyFailed { reason: ZkVerifyError::ProofWrongLength as u32 }.publish(&env);
-            return false;
-        }
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Verification log
+// ═══════════════════════════════════════════════════════════════════════════════
 
+/// Successful verifications of `mode` for one player in a session, as
+/// `get_verification_log` lists them. `player` is the strkey bytes the
+/// public inputs carry, i.e. `Address::to_string` of the player.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationCount {
+    pub player: Bytes,
+    pub mode: u32,
+    pub count: u32,
+}
 
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Debug reports
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // Mode 4: Pedersen+Sigma — proof is exactly 128 bytes (R_G1 + z_r)
-        if proof_len == 128 {
-            return Self::verify_pedersen_sigma(&env, &public_inputs, &proof);
-        }
+/// One sub-check of a `verify_debug` run: its name (`binding`, `nullifier`,
+/// `challenge`, `response`, `entropy`, `subgroup`, `schnorr`), whether it
+/// held, and keccak256 of the value the verifier recomputed for it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyCheck {
+    pub name: Symbol,
+    pub passed: bool,
+    pub digest: BytesN<32>,
+}
 
-        // Mode 8: Cangkul Hand Proof — proof is exactly 228 bytes (k(4) + A(96) + R(96) + z(32))
-        if proof_len == 228 {
-            return Self::verify_cangkul_hand(&env, &public_inputs, &proof);
-        }
+/// Outcome of `verify_debug`. `checks` lists the sub-checks in the order
+/// they ran, up to the first failure; `failed_check` and `reason` are the
+/// `check` and error code that `EvVerifyFailed` would carry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyReport {
+    pub mode: u32,
+    pub valid: bool,
+    pub failed_check: Option<u32>,
+    pub reason: Option<u32>,
+    pub checks: Vec<VerifyCheck>,
+}
 
-        // Mode 7: Card Play Ring Sigma — proof is 96 + N×64 bytes where N ∈ [1, 9]
-        // Mode 7 max valid N = 9 (max cards per suit),
-        // so proof_len ≤ 96 + 9*64 = 672.
-        if proof_len >= 160 && (proof_len - 96) % 64 == 0 {
-            return Self::verify_card_play_ring(&env, &public_inputs, &proof);
-        }
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Structured public inputs (typed entrypoints)
+// ═══════════════════════════════════════════════════════════════════════════════
 
+/// Mode 2 public inputs, accepted by `verify_nizk`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NizkInputs {
+    pub seed_hash: BytesN<32>,
+    pub commitment: BytesN<32>,
+    pub nullifier: BytesN<32>,
+    pub session_id: u32,
+    pub player: Address,
+}
 
-        // Mode 2: NIZK seed — proof is exactly 64 bytes (blinding + response)
-        if proof_len == 64 && public_inputs.len() >= 101 {
-            return Self::verify_nizk_seed(&env, &public_inputs, &proof);
-        }
+/// Mode 7 public inputs, accepted by `verify_ring`. `valid_set` holds
+/// 1..=9 card ids; N is its length.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RingInputs {
+    pub commit_hash: BytesN<32>,
+    pub valid_set: Vec<u32>,
+    pub session_id: u32,
+    pub player: Address,
+}
 
+/// Mode 8 public inputs, accepted by `verify_hand`. k is `cards.len()`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandInputs {
+    pub commit_hash: BytesN<32>,
+    pub trick_suit: u32,
+    pub cards: Vec<u32>,
+    pub session_id: u32,
+    pub player: Address,
+}
 
-        // No matching mode — reject
-        EvVerifyFailed { reason: ZkVerifyError::ProofWrongLength as u32 }.publish(&env);
-        false
+impl NizkInputs {
+    /// Byte layout read by `verify_nizk_seed`:
+    /// `seed_hash || commitment || nullifier || session_id || player`.
+    pub fn encode(&self, env: &Env) -> Bytes {
+        let mut buf = Bytes::from_array(env, &self.seed_hash.to_array());
+        buf.append(&Bytes::from_array(env, &self.commitment.to_array()));
+        buf.append(&Bytes::from_array(env, &self.nullifier.to_array()));
+        buf.append(&Bytes::from_array(env, &self.session_id.to_be_bytes()));
+        buf.append(&self.player.to_string().to_bytes());
+        buf
     }
+}
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Mode 2: NIZK Seed Proof of Knowledge (enhanced ZK — new!)
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Non-Interactive Zero-Knowledge Proof of Knowledge for seed commitment.
-    ///
-    /// The prover demonstrates knowledge of `seed` such that:
-    ///   `seed_hash = keccak256(seed)` AND
-    ///   `commitment = keccak256(seed_hash || blinding || player_address)`
-    /// WITHOUT revealing the raw `seed` on-chain.
-    ///
-    /// **Public inputs:** `seed_hash(32) || commitment(32) || nullifier(32) || session_id(4) || player(var)`
-    /// **Proof:** `blinding(32) || response(32)`
-    ///
-    /// Verification:
-    /// 1. Commitment binding: `keccak256(seed_hash || blinding || player) == commitment`
-    /// 2. Nullifier check: `keccak256(seed_hash || "NULL" || session_id) == nullifier`
-    /// 3. Fiat-Shamir challenge: `e = keccak256(commitment || session_id || player || "ZKV2")`
-    /// 4. Response check: `keccak256(seed_hash || e || blinding) == response`
-    /// 5. Entropy: `seed_hash` must have >= 4 distinct byte values
-    fn verify_nizk_seed(
-        env: &Env,
-        public_inputs: &Bytes,
-        proof: &Bytes,
-    ) -> bool {
-        // ── Extract public inputs ───────────────────────────────────────────
-        // seed_hash: [0..32)
-        let mut seed_hash_arr = [0u8; 32];
-        let mut i = 0usize;
-        while i < 32 {
-            seed_hash_arr[i] = public_inputs.get(i as u32).unwrap_or(0);
-            i += 1;
+impl RingInputs {
+    /// Byte layout read by `verify_card_play_ring`:
+    /// `commit_hash || N || valid_set[N] || session_id || player`.
+    pub fn encode(&self, env: &Env) -> Bytes {
+        let mut buf = Bytes::from_array(env, &self.commit_hash.to_array());
+        buf.append(&Bytes::from_array(env, &self.valid_set.len().to_be_bytes()));
+        for card in self.valid_set.iter() {
+            buf.append(&Bytes::from_array(env, &card.to_be_bytes()));
         }
-        let seed_hash = BytesN::<32>::from_array(env, &seed_hash_arr);
+        buf.append(&Bytes::from_array(env, &self.session_id.to_be_bytes()));
+        buf.append(&self.player.to_string().to_bytes());
+        buf
+    }
+}
 
-        // commitment: [32..64)
-        let mut commit_arr = [0u8; 32];
-        i = 0;
-        while i < 32 {
-            commit_arr[i] = public_inputs.get((32 + i) as u32).unwrap_or(0);
-            i += 1;
+impl HandInputs {
+    /// Byte layout read by `verify_cangkul_hand`:
+    /// `commit_hash || trick_suit || k || cards[k] || session_id || player`.
+    pub fn encode(&self, env: &Env) -> Bytes {
+        let mut buf = Bytes::from_array(env, &self.commit_hash.to_array());
+        buf.append(&Bytes::from_array(env, &self.trick_suit.to_be_bytes()));
+        buf.append(&Bytes::from_array(env, &self.cards.len().to_be_bytes()));
+        for card in self.cards.iter() {
+            buf.append(&Bytes::from_array(env, &card.to_be_bytes()));
         }
-        let commitment = BytesN::<32>::from_array(env, &commit_arr);
+        buf.append(&Bytes::from_array(env, &self.session_id.to_be_bytes()));
+        buf.append(&self.player.to_string().to_bytes());
+        buf
+    }
+}
 
-        // nullifier: [64..96)
-        let mut null_arr = [0u8; 32];
-        i = 0;
-        while i < 32 {
-            null_arr[i] = public_inputs.get((64 + i) as u32).unwrap_or(0);
-            i += 1;
-        }
-        let nullifier = BytesN::<32>::from_array(env, &null_arr);
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Storage
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // session_id: [96..100)
-        let mut sid_arr = [0u8; 4];
-        i = 0;
-        while i < 4 {
-            sid_arr[i] = public_inputs.get((96 + i) as u32).unwrap_or(0);
-            i += 1;
-        }
+#[contracttype]
+#[derive(Clone)]
+enum StorageKey {
+    Admin,
+    /// Verification key for a circuit-based mode, keyed by circuit_id.
+    /// Stored in instance storage; the admin may rotate it at any time.
+    Vk(u32),
+    /// Pedersen H generator (uncompressed G1), derived once by the constructor.
+    PedersenH,
+    /// Seed entropy policy for Mode 2; the default applies while unset.
+    EntropyPolicy,
+    /// Deck encoding and size bounds for Modes 7, 8 and 12; the 36-card
+    /// default applies while unset.
+    DeckParams,
+    /// Present for every mode the admin has switched off. Absent = enabled.
+    ModeDisabled(u32),
+    /// Whether only allowlisted callers may verify (via `verify_from`).
+    AllowlistEnabled,
+    /// Present for every contract on the caller allowlist.
+    AllowedCaller(Address),
+    /// Present while Modes 2, 4, 7 and 8 bind their challenges to the
+    /// network id. Absent = unbound.
+    NetworkBound,
+    /// Present once untagged (legacy) proofs are refused. Absent = accepted.
+    LegacyProofsDisabled,
+    /// UltraHonk verifier contract that Mode 22 forwards Noir proofs to.
+    UltraHonkVerifier,
+    /// UltraHonk verifier contract for one circuit, keyed by circuit_id;
+    /// its key is baked in, so the contract stands in for the VK.
+    UltraHonkCircuit(u32),
+    /// A proof being uploaded in chunks, keyed by (session_id, player).
+    /// Temporary storage; removed by `finish_verify`.
+    Upload(u32, Address),
+    /// A proof verified by `verify_and_record`, keyed by
+    /// (consumer, session_id, player, mode, keccak256(public_inputs)).
+    /// Temporary storage; removed by `consume_verification`.
+    Verified(Address, u32, Address, u32, BytesN<32>),
+    /// Per-(player, mode) counts of successful verifications in a session,
+    /// keyed by session_id. Temporary storage, refreshed on every count.
+    VerificationLog(u32),
+}
 
-        // player_address: [100..)
-        let player_len = public_inputs.len() - 100;
-        if player_len == 0 {
-            EvVerifyFailed { reason: ZkVerifyError::EmptyPlayerAddress as u32 }.publish(env);
-            return false;
-        }
-        let mut player_bytes = Bytes::new(env);
-        let mut j = 0u32;
-        while j < player_len {
-            player_bytes.push_back(public_inputs.get(100 + j).unwrap_or(0));
-            j += 1;
-        }
+/// A proof being uploaded with `begin_verify` / `continue_verify`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ChunkedUpload {
+    consumer: Address,
+    public_inputs: Bytes,
+    proof_len: u32,
+    proof: Bytes,
+}
 
-        // ── Extract proof (witness) ─────────────────────────────────────────
-        // blinding: [0..32)
-        let mut blind_arr = [0u8; 32];
-        i = 0;
-        while i < 32 {
-            blind_arr[i] = proof.get(i as u32).unwrap_or(0);
-            i += 1;
-        }
-        let blinding = BytesN::<32>::from_array(env, &blind_arr);
+/// Every mode `set_mode_enabled` accepts.
+const MODES: [u32; 22] = [
+    MODE_NIZK_SEED,
+    MODE_PEDERSEN_SEED,
+    MODE_CARD_RING,
+    MODE_CANGKUL_HAND,
+    MODE_PLONK,
+    MODE_RANGE,
+    MODE_MERKLE,
+    MODE_HIDDEN_RING,
+    MODE_PRIVATE_HAND,
+    MODE_SHUFFLE,
+    MODE_ELGAMAL,
+    MODE_CARD_COMPARE,
+    MODE_COMMIT_EQ,
+    MODE_DEAL,
+    MODE_THRESHOLD_SEED,
+    MODE_BLS_ATTEST,
+    MODE_HAND_COUNT,
+    MODE_ULTRAHONK,
+    MODE_VECTOR_COMMIT,
+    MODE_LINKED_PLAY,
+    MODE_ED25519_SEED,
+    MODE_TRICK_BUNDLE,
+];
+
+/// CPU cost per mode as (mode, base, per unit of n), in thousands of
+/// instructions, fitted to native test runs. n is the ring size (Modes 7
+/// and 12), hand size (8), bit count (10), tree depth (11), cards per chunk
+/// (13), pairs (17), parties (19), signers (20), vector length (23), ring
+/// members N + k (24) or ring members across both plays (26, a cangkul hand
+/// counting as two); other modes ignore it.
+const MODE_COSTS: [(u32, u64, u64); 22] = [
+    (MODE_NIZK_SEED, 150, 0),
+    (MODE_PEDERSEN_SEED, 11_700, 0),
+    (MODE_CARD_RING, 900, 7_050),
+    (MODE_CANGKUL_HAND, 10_900, 1_500),
+    (MODE_PLONK, 66_600, 0),
+    (MODE_RANGE, 7_950, 9_350),
+    (MODE_MERKLE, 70, 15),
+    (MODE_HIDDEN_RING, 850, 6_450),
+    (MODE_PRIVATE_HAND, 5_700, 19_700),
+    (MODE_SHUFFLE, 72_000, 0),
+    (MODE_ELGAMAL, 15_400, 0),
+    (MODE_CARD_COMPARE, 53_200, 0),
+    (MODE_COMMIT_EQ, 5_650, 7_650),
+    (MODE_DEAL, 24_900, 0),
+    (MODE_THRESHOLD_SEED, 5_650, 3_900),
+    (MODE_BLS_ATTEST, 18_500, 14_600),
+    (MODE_HAND_COUNT, 12_600, 0),
+    (MODE_ULTRAHONK, 215_000, 0),
+    (MODE_VECTOR_COMMIT, 6_350, 4_700),
+    (MODE_LINKED_PLAY, 850, 7_400),
+    (MODE_ED25519_SEED, 550, 0),
+    (MODE_TRICK_BUNDLE, 1_800, 7_050),
+];
+
+// Ledger rate is approximately 5 seconds per ledger on Stellar
+const LEDGER_RATE_SECS: u32 = 5;
+
+// Instance TTL: 120 days — admin and verification keys live in instance storage
+const INSTANCE_TTL_SECONDS: u32 = 120 * 24 * 60 * 60; // 10,368,000 seconds
+const INSTANCE_TTL_LEDGERS: u32 = INSTANCE_TTL_SECONDS / LEDGER_RATE_SECS; // 2,073,600 ledgers
+
+// Verification record TTL: 1 day — long enough to span a game's split transactions
+const RECORD_TTL_SECONDS: u32 = 24 * 60 * 60; // 86,400 seconds
+const RECORD_TTL_LEDGERS: u32 = RECORD_TTL_SECONDS / LEDGER_RATE_SECS; // 17,280 ledgers
+
+/// (player, mode) pairs one session's verification log holds. Anyone can
+/// submit valid proofs for any player string, so the log is capped to keep
+/// its entry small; pairs past the cap are not counted.
+#[cfg(not(feature = "lib"))]
+const VERIFICATION_LOG_MAX_ENTRIES: u32 = 32;
+
+// Largest proof `begin_verify` accepts — 64 KB, over four UltraKeccakHonk proofs
+const CHUNKED_MAX_PROOF_LEN: u32 = 64 * 1024;
 
-        // response: [32..64)
-        let mut resp_arr = [0u8; 32];
-        i = 0;
-        while i < 32 {
-            resp_arr[i] = proof.get((32 + i) as u32).unwrap_or(0);
-            i += 1;
-        }
-        let response = BytesN::<32>::from_array(env, &resp_arr);
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Curve generators
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // ── Step 1: Verify commitment binding ───────────────────────────────
-        // C' = keccak256(seed_hash || blinding || player_address)
-        let mut commit_preimage = Bytes::from_array(env, &seed_hash.to_array());
-        commit_preimage.append(&Bytes::from_array(env, &blinding.to_array()));
-        commit_preimage.append(&player_bytes.clone());
-        let computed_commitment: BytesN<32> = env.crypto().keccak256(&commit_preimage).into();
-        if computed_commitment != commitment {
-            EvVerifyFailed { reason: ZkVerifyError::CommitmentMismatch as u32 }.publish(env);
-            return false;
-        }
+/// BLS12-381 G1 standard generator (x || y).
+const G1_GENERATOR: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+    0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+    0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+    0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
+    0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
+    0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
+    0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+];
+
+/// BLS12-381 G2 standard generator (x.c1 || x.c0 || y.c1 || y.c0).
+const G2_GENERATOR: [u8; 192] = [
+    0x13, 0xe0, 0x2b, 0x60, 0x52, 0x71, 0x9f, 0x60, 0x7d, 0xac, 0xd3, 0xa0,
+    0x88, 0x27, 0x4f, 0x65, 0x59, 0x6b, 0xd0, 0xd0, 0x99, 0x20, 0xb6, 0x1a,
+    0xb5, 0xda, 0x61, 0xbb, 0xdc, 0x7f, 0x50, 0x49, 0x33, 0x4c, 0xf1, 0x12,
+    0x13, 0x94, 0x5d, 0x57, 0xe5, 0xac, 0x7d, 0x05, 0x5d, 0x04, 0x2b, 0x7e,
+    0x02, 0x4a, 0xa2, 0xb2, 0xf0, 0x8f, 0x0a, 0x91, 0x26, 0x08, 0x05, 0x27,
+    0x2d, 0xc5, 0x10, 0x51, 0xc6, 0xe4, 0x7a, 0xd4, 0xfa, 0x40, 0x3b, 0x02,
+    0xb4, 0x51, 0x0b, 0x64, 0x7a, 0xe3, 0xd1, 0x77, 0x0b, 0xac, 0x03, 0x26,
+    0xa8, 0x05, 0xbb, 0xef, 0xd4, 0x80, 0x56, 0xc8, 0xc1, 0x21, 0xbd, 0xb8,
+    0x06, 0x06, 0xc4, 0xa0, 0x2e, 0xa7, 0x34, 0xcc, 0x32, 0xac, 0xd2, 0xb0,
+    0x2b, 0xc2, 0x8b, 0x99, 0xcb, 0x3e, 0x28, 0x7e, 0x85, 0xa7, 0x63, 0xaf,
+    0x26, 0x74, 0x92, 0xab, 0x57, 0x2e, 0x99, 0xab, 0x3f, 0x37, 0x0d, 0x27,
+    0x5c, 0xec, 0x1d, 0xa1, 0xaa, 0xa9, 0x07, 0x5f, 0xf0, 0x5f, 0x79, 0xbe,
+    0x0c, 0xe5, 0xd5, 0x27, 0x72, 0x7d, 0x6e, 0x11, 0x8c, 0xc9, 0xcd, 0xc6,
+    0xda, 0x2e, 0x35, 0x1a, 0xad, 0xfd, 0x9b, 0xaa, 0x8c, 0xbd, 0xd3, 0xa7,
+    0x6d, 0x42, 0x9a, 0x69, 0x51, 0x60, 0xd1, 0x2c, 0x92, 0x3a, 0xc9, 0xcc,
+    0x3b, 0xac, 0xa2, 0x89, 0xe1, 0x93, 0x54, 0x86, 0x08, 0xb8, 0x28, 0x01,
+];
 
-        // ── Step 2: Verify nullifier (session-binding) ──────────────────────
-        // N' = keccak256(seed_hash || "NULL" || session_id_be4)
-        let mut null_preimage = Bytes::from_array(env, &seed_hash.to_array());
-        null_preimage.append(&Bytes::from_array(env, &NULLIFIER_TAG));
-        null_preimage.append(&Bytes::from_array(env, &sid_arr));
-        let computed_nullifier: BytesN<32> = env.crypto().keccak256(&null_preimage).into();
-        if computed_nullifier != nullifier {
-            EvVerifyFailed { reason: ZkVerifyError::NullifierMismatch as u32 }.publish(env);
-            return false;
-        }
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Shuffle permutation generators (Mode 14)
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // ── Step 3: Recompute Fiat-Shamir challenge ─────────────────────────
-        // e = keccak256(commitment || session_id_be4 || player_address || "ZKV2")
-        let mut challenge_preimage = Bytes::from_array(env, &commitment.to_array());
-        challenge_preimage.append(&Bytes::from_array(env, &sid_arr));
-        challenge_preimage.append(&player_bytes);
-        challenge_preimage.append(&Bytes::from_array(env, &CHALLENGE_TAG));
-        let challenge: BytesN<32> = env.crypto().keccak256(&challenge_preimage).into();
+/// Position generators G_i = hash_to_g1("SHUFFLE_G" || i_be4, "SGS_CANGKULAN_V1"),
+/// i = 0..35, uncompressed. Precomputed because deriving 36 points costs
+/// ~116M CPU; `test_shuffle_generators_match_derivation` re-derives them.
+const SHUFFLE_GENERATORS: &[u8; 36 * 96] = include_bytes!("shuffle_generators.bin");
 
-        // ── Step 4: Verify response ─────────────────────────────────────────
-        // expected_response = keccak256(seed_hash || challenge || blinding)
-        let mut resp_preimage = Bytes::from_array(env, &seed_hash.to_array());
-        resp_preimage.append(&Bytes::from_array(env, &challenge.to_array()));
-        resp_preimage.append(&Bytes::from_array(env, &blinding.to_array()));
-        let expected_response: BytesN<32> = env.crypto().keccak256(&resp_preimage).into();
-        if expected_response != response {
-            EvVerifyFailed { reason: ZkVerifyError::ResponseMismatch as u32 }.publish(env);
-            return false;
-        }
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Diagnostics
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // ── Step 5: Entropy check on seed_hash ──────────────────────────────
-        // seed_hash must contain >= 4 distinct byte values
-        let mut seen = [false; 256];
-        let mut distinct: u32 = 0;
-        i = 0;
-        while i < 32 {
-            let idx = seed_hash_arr[i] as usize;
-            if !seen[idx] {
-                seen[idx] = true;
-                distinct += 1;
-            }
-            i += 1;
-        }
-        if distinct < 4 {
-            EvVerifyFailed { reason: ZkVerifyError::WeakSeedEntropy as u32 }.publish(env);
-            return false;
-        }
+/// One verification call, carrying the context that `EvVerifyAttempt` and
+/// `EvVerifyFailed` report alongside the mode.
+struct Attempt<'a> {
+    env: &'a Env,
+    mode: u32,
+    public_inputs: &'a Bytes,
+    proof: &'a Bytes,
+    trace: Option<&'a RefCell<VerifyReport>>,
+    setup: Option<&'a SharedSetup>,
+    link: Option<BytesN<32>>,
+}
 
-        // ── All checks passed ───────────────────────────────────────────────
-        EvVerifySuccess { mode: 2 }.publish(env);
-        true
+/// Generators the two parts of a Mode 26 bundle derive once between them.
+struct SharedSetup {
+    h: G1Affine,
+    pad: RefCell<Option<G1Affine>>,
+}
+
+impl<'a> Attempt<'a> {
+    fn new(env: &'a Env, mode: u32, public_inputs: &'a Bytes, proof: &'a Bytes) -> Self {
+        Attempt { env, mode, public_inputs, proof, trace: None, setup: None, link: None }
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Mode 4: Pedersen+Sigma Protocol (BLS12-381) — NEW!
-    // ═══════════════════════════════════════════════════════════════════════════
+    /// Record sub-checks and the first failure into `report` (`verify_debug`).
+    fn traced(mut self, report: &'a RefCell<VerifyReport>) -> Self {
+        self.trace = Some(report);
+        self
+    }
 
-    /// Derive the Pedersen H generator using hash_to_g1 with a fixed DST.
-    /// This is deterministic and reproducible by anyone.
-    fn pedersen_h(bls: &Bls12_381, env: &Env) -> G1Affine {
-        let msg = Bytes::from_slice(env, PEDERSEN_H_MSG);
-        let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
-        bls.hash_to_g1(&msg, &dst)
+    /// Take H and P_pad from `setup` instead of deriving them (Mode 26).
+    fn shared(mut self, setup: &'a SharedSetup) -> Self {
+        self.setup = Some(setup);
+        self
     }
 
-    /// Extract a G1Affine point from a byte slice at a given offset.
-    fn extract_g1(env: &Env, data: &Bytes, offset: u32) -> G1Affine {
-        let mut arr = [0u8; 96];
-        let mut i = 0usize;
-        while i < 96 {
-            arr[i] = data.get(offset + i as u32).unwrap_or(0);
-            i += 1;
-        }
-        G1Affine::from_array(env, &arr)
+    /// Chain the challenge onto `previous`, the digest of the bundle part
+    /// before this one (Mode 26).
+    fn linked(mut self, previous: BytesN<32>) -> Self {
+        self.link = Some(previous);
+        self
     }
 
-    /// Extract an Fr scalar from a byte slice at a given offset (32 bytes, big-endian).
-    fn extract_fr(env: &Env, data: &Bytes, offset: u32) -> Fr {
-        let mut arr = [0u8; 32];
-        let mut i = 0usize;
-        while i < 32 {
-            arr[i] = data.get(offset + i as u32).unwrap_or(0);
-            i += 1;
+    /// The Pedersen H generator, from the shared setup if there is one.
+    fn pedersen_h(&self, bls: &Bls12_381) -> G1Affine {
+        match self.setup {
+            Some(setup) => setup.h.clone(),
+            None => ZkCommitmentVerifier::pedersen_h(bls, self.env),
         }
-        Fr::from_bytes(BytesN::<32>::from_array(env, &arr))
     }
 
-    /// Pedersen+Sigma verification (Mode 4).
-    ///
-    /// **Public inputs:** `C(96, G1) || seed_hash(32, Fr) || session_id(4) || player(var)`
-    /// **Proof:** `R(96, G1) || z_r(32, Fr)` = 128 bytes
-    ///
-    /// Verification:
-    /// 1. Derive H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1")
-    /// 2. Extract C, R, z_r from proof; seed_hash from public_inputs
-    /// 3. Check C and R are in the G1 subgroup
-    /// 4. Compute D = C − seed_hash·G (strips public component)
-    /// 5. Compute Fiat-Shamir challenge: e = Fr(keccak256(C || R || seed_hash || session_id || player || "ZKP4"))
-    /// 6. Schnorr check: z_r·H == R + e·D (proves knowledge of blinding r)
-    /// 7. Accept iff LHS == RHS
-    fn verify_pedersen_sigma(
-        env: &Env,
-        public_inputs: &Bytes,
-        proof: &Bytes,
-    ) -> bool {
-        let bls = env.crypto().bls12_381();
+    /// P_pad, derived at most once per shared setup.
+    fn ring_pad_point(&self, bls: &Bls12_381) -> G1Affine {
+        let Some(setup) = self.setup else {
+            return ZkCommitmentVerifier::ring_pad_point(bls, self.env);
+        };
+        setup.pad.borrow_mut().get_or_insert_with(|| ZkCommitmentVerifier::ring_pad_point(bls, self.env)).clone()
+    }
 
-        // ── Minimum public_inputs length: C(96) + seed_hash(32) + session_id(4) + player(>=1) = 133
-        if public_inputs.len() < 133 {
-            EvVerifyFailed { reason: ZkVerifyError::InputsTooShort as u32 }.publish(env);
-            return false;
+    /// The challenge of `transcript` under `tag`, after the network id if
+    /// bound. A linked part appends the previous part's digest and finishes
+    /// with `TRICK_CHALLENGE_TAG` instead.
+    fn challenge(&self, transcript: &mut FiatShamir, tag: &[u8; 4]) -> Fr {
+        let transcript = ZkCommitmentVerifier::bind_network(self.env, transcript);
+        match &self.link {
+            Some(previous) => transcript.digest(previous).challenge(&TRICK_CHALLENGE_TAG),
+            None => transcript.challenge(tag),
         }
+    }
 
-        // ── Extract public inputs ───────────────────────────────────────────
-        let commitment = Self::extract_g1(env, public_inputs, 0); // C: [0..96)
-        let seed_hash_fr = Self::extract_fr(env, public_inputs, 96); // seed_hash as Fr: [96..128)
+    /// Note sub-check `name` on `value` and return `passed`. Only hashes
+    /// `value` when tracing, so plain verification pays nothing for it.
+    fn check<const N: usize>(&self, name: Symbol, passed: bool, value: &BytesN<N>) -> bool {
+        if let Some(trace) = self.trace {
+            let digest = self.env.crypto().keccak256(&value.clone().into()).into();
+            trace.borrow_mut().checks.push_back(VerifyCheck { name, passed, digest });
+        }
+        passed
+    }
 
-        // seed_hash raw bytes for challenge
-        let mut seed_hash_bytes = [0u8; 32];
-        {
-            let mut i = 0usize;
-            while i < 32 {
-                seed_hash_bytes[i] = public_inputs.get((96 + i) as u32).unwrap_or(0);
-                i += 1;
-            }
+    /// `new`, publishing `EvVerifyAttempt`. Used by the public entrypoints.
+    fn start(env: &'a Env, mode: u32, public_inputs: &'a Bytes, proof: &'a Bytes) -> Self {
+        let at = Self::new(env, mode, public_inputs, proof);
+        EvVerifyAttempt {
+            mode,
+            session_id: at.session_id(),
+            inputs_len: public_inputs.len(),
+            proof_len: proof.len(),
         }
+        .publish(env);
+        at
+    }
 
-        // session_id: [128..132)
-        let mut sid_arr = [0u8; 4];
-        {
-            let mut i = 0usize;
-            while i < 4 {
-                sid_arr[i] = public_inputs.get((128 + i) as u32).unwrap_or(0);
-                i += 1;
+    /// Publish `EvVerifyFailed` for check `check` and return `false`.
+    fn fail(&self, check: u32, reason: ZkVerifyError) -> bool {
+        if let Some(trace) = self.trace {
+            let mut report = trace.borrow_mut();
+            if report.reason.is_none() {
+                report.failed_check = Some(check);
+                report.reason = Some(reason as u32);
             }
         }
+        EvVerifyFailed {
+            reason: reason as u32,
+            mode: self.mode,
+            session_id: self.session_id(),
+            inputs_len: self.public_inputs.len(),
+            proof_len: self.proof.len(),
+            check,
+        }
+        .publish(self.env);
+        false
+    }
 
-        // player: [132..)
-        let player_len = public_inputs.len() - 132;
-        if player_len == 0 {
-            EvVerifyFailed { reason: ZkVerifyError::EmptyPlayerAddress as u32 }.publish(env);
-            return false;
+    /// The session id field of the public inputs, if the mode has one and
+    /// the inputs are long enough to hold it. Modes 9 and 11 bind no session.
+    fn session_id(&self) -> Option<u32> {
+        let offset = self.session_offset()?;
+        (offset.checked_add(4)? <= self.public_inputs.len())
+            .then(|| InputsReader::new(self.env, self.public_inputs).u32_at(offset))
+    }
+
+    /// The player's strkey bytes between the session id and the trailers,
+    /// if the mode binds a session and the player keeps at least one byte.
+    fn player(&self) -> Option<Bytes> {
+        let start = self.session_offset()?.checked_add(4)?;
+        let end = self.public_inputs.len().checked_sub(self.trailer_len())?;
+        (start < end).then(|| self.public_inputs.slice(start..end))
+    }
+
+    /// `valid_until_ledger` from the trailer after the player address, if
+    /// the inputs carry one. The player must keep at least one byte.
+    fn ledger_window(&self) -> Option<u32> {
+        let player = self.session_offset()?.checked_add(4)?;
+        let len = self.public_inputs.len();
+        if len < player.checked_add(1 + LEDGER_WINDOW_LEN)? {
+            return None;
         }
-        let mut player_bytes = Bytes::new(env);
-        let mut j = 0u32;
-        while j < player_len {
-            player_bytes.push_back(public_inputs.get(132 + j).unwrap_or(0));
-            j += 1;
+        let inputs = InputsReader::new(self.env, self.public_inputs);
+        (inputs.slice(len - LEDGER_WINDOW_LEN..len - 4) == Bytes::from_array(self.env, &LEDGER_WINDOW_TAG))
+            .then(|| inputs.u32_at(len - 4))
+    }
+
+    /// `hash_id` from the transcript hash selector, which sits between the
+    /// player address and any ledger window.
+    fn hash_selector(&self) -> Option<u8> {
+        let player = self.session_offset()?.checked_add(4)?;
+        let end = self.public_inputs.len() - self.ledger_window().map_or(0, |_| LEDGER_WINDOW_LEN);
+        if end < player.checked_add(1 + TRANSCRIPT_HASH_LEN)? {
+            return None;
         }
+        let inputs = InputsReader::new(self.env, self.public_inputs);
+        (inputs.slice(end - TRANSCRIPT_HASH_LEN..end - 1) == Bytes::from_array(self.env, &TRANSCRIPT_HASH_TAG))
+            .then(|| self.public_inputs.get(end - 1).unwrap_or(0))
+    }
 
-        // ── Extract proof: R(96) || z_r(32) ────────────────────────────────
-        let r_point = Self::extract_g1(env, proof, 0);     // R: [0..96)
-        let z_r = Self::extract_fr(env, proof, 96);         // z_r: [96..128)
+    /// Bytes the optional trailers add after the player address.
+    fn trailer_len(&self) -> u32 {
+        self.ledger_window().map_or(0, |_| LEDGER_WINDOW_LEN) + self.hash_selector().map_or(0, |_| TRANSCRIPT_HASH_LEN)
+    }
 
-        // ── Subgroup checks ─────────────────────────────────────────────────
-        if !bls.g1_is_in_subgroup(&commitment) {
-            EvVerifyFailed { reason: ZkVerifyError::PedersenPointNotOnCurve as u32 }.publish(env);
-            return false;
-        }
-        if !bls.g1_is_in_subgroup(&r_point) {
-            EvVerifyFailed { reason: ZkVerifyError::PedersenPointNotOnCurve as u32 }.publish(env);
-            return false;
+    /// The hash the inputs select for the challenge, or `None` after failing
+    /// with `UnknownTranscriptHash`. Inputs without a selector use the default.
+    fn transcript_hash(&self) -> Option<TranscriptHash> {
+        match self.hash_selector() {
+            None => Some(TranscriptHash::Default),
+            Some(TRANSCRIPT_HASH_SHA256) => Some(TranscriptHash::Sha256),
+            Some(_) => {
+                self.fail(0, ZkVerifyError::UnknownTranscriptHash);
+                None
+            }
         }
+    }
 
-        // ── Derive H generator and G1 generator ────────────────────────────
-        let h = Self::pedersen_h(&bls, env);
+    /// Fail with `ProofExpired` once the ledger is past the inputs' window.
+    /// Inputs without a window never expire.
+    fn fresh(&self) -> bool {
+        match self.ledger_window() {
+            Some(until) if self.env.ledger().sequence() > until => self.fail(0, ZkVerifyError::ProofExpired),
+            _ => true,
+        }
+    }
 
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        let g = G1Affine::from_array(env, &g1_bytes);
+    /// Offset of the session id field, without checking it is in bounds.
+    fn session_offset(&self) -> Option<u32> {
+        let inputs = InputsReader::new(self.env, self.public_inputs);
+        let len = inputs.len();
+        let u32_at = |offset: u32| (offset.checked_add(4)? <= len).then(|| inputs.u32_at(offset));
+        let offset = match self.mode {
+            MODE_NIZK_SEED | MODE_ED25519_SEED => 96,
+            MODE_PEDERSEN_SEED => 128,
+            MODE_CARD_RING => 36u32.checked_add((u32_at(32)? & !RING_PADDED_FLAG).checked_mul(4)?)?,
+            MODE_HIDDEN_RING => {
+                36u32.checked_add((u32_at(32)? & !(HIDDEN_RING_FLAG | RING_PADDED_FLAG)).checked_mul(96)?)?
+            }
+            MODE_CANGKUL_HAND => 40u32.checked_add(u32_at(36)?.checked_mul(4)?)?,
+            MODE_RANGE => 104,
+            MODE_PRIVATE_HAND => 44u32.checked_add(u32_at(36)?.checked_mul(96)?)?,
+            MODE_SHUFFLE => SHUFFLE_DECK_SIZE * 96,
+            MODE_CARD_COMPARE => CARD_COMPARE_INPUTS_FIXED_LEN,
+            MODE_COMMIT_EQ => 4u32.checked_add(u32_at(0)?.checked_mul(192)?)?,
+            MODE_DEAL => DEAL_INPUTS_FIXED_LEN,
+            MODE_HAND_COUNT => 100,
+            MODE_VECTOR_COMMIT => VECTOR_INPUTS_HEADER_LEN.checked_add(u32_at(100)?.checked_mul(VECTOR_OPENING_LEN)?)?,
+            MODE_LINKED_PLAY => {
+                let k_offset = 36u32.checked_add(u32_at(32)?.checked_mul(4)?)?;
+                k_offset.checked_add(4)?.checked_add(u32_at(k_offset)?.checked_mul(96)?)?
+            }
+            MODE_THRESHOLD_SEED => THRESHOLD_INPUTS_HEADER_LEN
+                .checked_add(u32_at(0)?.checked_mul(96)?)?
+                .checked_add(u32_at(8)?.count_ones() * 32)?,
+            MODE_BLS_ATTEST => {
+                let msg_offset = 4u32.checked_add(u32_at(0)?.checked_mul(96)?)?;
+                msg_offset.checked_add(4)?.checked_add(u32_at(msg_offset)?)?
+            }
+            MODE_ELGAMAL => match u32_at(0)? {
+                ELGAMAL_KIND_DECRYPT => 4 + 2 * 96,
+                ELGAMAL_KIND_REENCRYPT => 4 + 4 * 96,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(offset)
+    }
+}
 
-        // ── Compute D = C − seed_hash·G ─────────────────────────────────────
-        // If honest: D = blinding·H
-        let s_times_g = bls.g1_mul(&g, &seed_hash_fr);
-        let neg_s_times_g = -s_times_g;
-        let d = bls.g1_add(&commitment, &neg_s_times_g);
+// ═══════════════════════════════════════════════════════════════════════════════
+//  UltraHonk verifier interface (Mode 22)
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // ── Compute Fiat-Shamir challenge ───────────────────────────────────
-        // e = Fr(keccak256(C || R || seed_hash || session_id || player || "ZKP4"))
-        let c_bytes = commitment.to_bytes();
-        let r_bytes = r_point.to_bytes();
-        let mut challenge_preimage = Bytes::from_array(env, &c_bytes.to_array());
-        challenge_preimage.append(&Bytes::from_array(env, &r_bytes.to_array()));
-        challenge_preimage.append(&Bytes::from_array(env, &seed_hash_bytes));
-        challenge_preimage.append(&Bytes::from_array(env, &sid_arr));
-        challenge_preimage.append(&player_bytes);
-        challenge_preimage.append(&Bytes::from_array(env, &PEDERSEN_CHALLENGE_TAG));
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_preimage).into();
-        let e = Fr::from_bytes(e_hash);
+/// Noir UltraKeccakHonk verifier (rs-soroban-ultrahonk), deployed with its
+/// verification key. `verify_proof` traps on an invalid proof.
+#[contractclient(name = "UltraHonkClient")]
+pub trait UltraHonkVerifier {
+    fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes);
+}
 
-        // ── Schnorr verification on D ───────────────────────────────────────
-        // Prove knowledge of r such that D = r·H
-        // LHS = z_r · H
-        // RHS = R + e · D
-        let lhs = bls.g1_mul(&h, &z_r);
-        let e_times_d = bls.g1_mul(&d, &e);
-        let rhs = bls.g1_add(&r_point, &e_times_d);
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Contract
+// ═══════════════════════════════════════════════════════════════════════════════
 
-        // ── Final equality check ────────────────────────────────────────────
-        if lhs.to_bytes() != rhs.to_bytes() {
-            EvVerifyFailed { reason: ZkVerifyError::PedersenSigmaCheckFailed as u32 }.publish(env);
-            return false;
-        }
+#[cfg_attr(not(feature = "lib"), contract)]
+pub struct ZkCommitmentVerifier;
 
-        EvVerifySuccess { mode: 4 }.publish(env);
-        true
+#[cfg_attr(not(feature = "lib"), contractimpl)]
+impl ZkCommitmentVerifier {
+    /// Verify a ZK commitment proof (auto-detects mode by proof length).
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    /// Emits diagnostic events on success or failure. Rejected while the
+    /// caller allowlist is enabled; allowlisted contracts use `verify_from`.
+    pub fn verify(env: Env, public_inputs: Bytes, proof: Bytes) -> bool {
+        Self::dispatch(&env, None, &public_inputs, &proof).is_some()
     }
 
-    /// Extract a u32 (big-endian) from bytes at a given offset.
-    fn extract_u32(data: &Bytes, offset: u32) -> u32 {
-        let b0 = data.get(offset).unwrap_or(0) as u32;
-        let b1 = data.get(offset + 1).unwrap_or(0) as u32;
-        let b2 = data.get(offset + 2).unwrap_or(0) as u32;
-        let b3 = data.get(offset + 3).unwrap_or(0) as u32;
-        (b0 << 24) | (b1 << 16) | (b2 << 8) | b3
+    /// `verify` on behalf of `caller`, which must authorize the call. A
+    /// contract calling directly authorizes itself, so game contracts pass
+    /// `env.current_contract_address()`. Checked against the allowlist.
+    pub fn verify_from(env: Env, caller: Address, public_inputs: Bytes, proof: Bytes) -> bool {
+        caller.require_auth();
+        Self::dispatch(&env, Some(&caller), &public_inputs, &proof).is_some()
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Mode 7: Card Play Ring Sigma (1-of-N Schnorr on Pedersen / BLS12-381)
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Ring Sigma verification for card plays (Mode 7).
-    ///
-    /// Proves that a Pedersen commitment C hides a card_id belonging to a
-    /// known valid set, WITHOUT revealing which card was committed.
-    ///
-    /// **Public inputs layout:**
-    /// ```text
-    /// commit_hash(32) || N(4, u32 BE) || valid_set[N](4 each, u32 BE) ||
-    /// session_id(4) || player(var)
-    /// ```
-    ///
-    /// **Proof layout (96 + N×64 bytes):**
-    /// ```text
-    /// C(96, G1) || [e_i(32, Fr) || z_i(32, Fr)] × N
-    /// ```
+    /// Verify a proof in its own transaction and record the fact for
+    /// `consumer` to pick up later with `consume_verification`.
     ///
-    /// **Verification:**
-    /// 1. Derive H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1")
-    /// 2. Verify keccak256(C) == commit_hash (binding check)
-    /// 3. For each i: D_i = C − valid_set[i]·G, R_i = z_i·H − e_i·D_i
-    /// 4. e = Fr(keccak256(C || R_0 || ... || R_{N-1} || session_id || player || "ZKP7"))
-    /// 5. Accept iff Σe_i == e (checked at group level: Σ(e_i·G) == e·G)
-    fn verify_card_play_ring(
-        env: &Env,
-        public_inputs: &Bytes,
-        proof: &Bytes,
+    /// On success stores `(consumer, session_id, player, mode,
+    /// keccak256(public_inputs))` in temporary storage, so an expensive
+    /// proof (ring, hand, shuffle) does not have to fit in the budget of
+    /// the game transaction that relies on it. `player` must authorize the
+    /// call, and `consumer` must be allowlisted while the allowlist is on.
+    pub fn verify_and_record(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        proof: Bytes,
     ) -> bool {
-        let bls = env.crypto().bls12_381();
-        let proof_len = proof.len();
-
-        // N = number of valid cards in the ring
-        let n = (proof_len - 96) / 64;
-        if n == 0 || n > 9 {
-            EvVerifyFailed { reason: ZkVerifyError::RingInvalidSetSize as u32 }.publish(env);
+        player.require_auth();
+        let Some(mode) = Self::dispatch(&env, Some(&consumer), &public_inputs, &proof) else {
             return false;
-        }
+        };
+        Self::record(&env, consumer, session_id, player, mode, &public_inputs);
+        true
+    }
 
-        // Minimum public_inputs: commit_hash(32) + N(4) + valid_set(4*N) + session_id(4) + player(>=1)
-        let min_inputs = 32 + 4 + 4 * n + 4 + 1;
-        if public_inputs.len() < min_inputs {
-            EvVerifyFailed { reason: ZkVerifyError::InputsTooShort as u32 }.publish(env);
-            return false;
+    /// Start uploading a `proof_len`-byte proof for `verify_and_record` in
+    /// chunks, with `chunk` as its first bytes. Replaces any unfinished
+    /// upload by `player` for `session_id`. Returns the bytes still missing.
+    pub fn begin_verify(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        proof_len: u32,
+        chunk: Bytes,
+    ) -> Result<u32, ZkVerifyError> {
+        player.require_auth();
+        if proof_len > CHUNKED_MAX_PROOF_LEN || chunk.len() > proof_len {
+            return Err(ZkVerifyError::ChunkedProofTooLong);
         }
+        let upload = ChunkedUpload { consumer, public_inputs, proof_len, proof: chunk };
+        Ok(Self::save_upload(&env, session_id, player, &upload))
+    }
 
-        // ── Extract commit_hash [0..32) ─────────────────────────────────────
-        let mut commit_arr = [0u8; 32];
-        let mut i = 0usize;
-        while i < 32 {
-            commit_arr[i] = public_inputs.get(i as u32).unwrap_or(0);
-            i += 1;
+    /// Append the next chunk to an upload started with `begin_verify`.
+    /// Returns the bytes still missing.
+    pub fn continue_verify(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        chunk: Bytes,
+    ) -> Result<u32, ZkVerifyError> {
+        player.require_auth();
+        let mut upload = Self::load_upload(&env, session_id, &player)?;
+        if chunk.len() > upload.proof_len - upload.proof.len() {
+            return Err(ZkVerifyError::ChunkedProofTooLong);
         }
-        let commit_hash = BytesN::<32>::from_array(env, &commit_arr);
+        upload.proof.append(&chunk);
+        Ok(Self::save_upload(&env, session_id, player, &upload))
+    }
 
-        // ── Extract N from public_inputs [32..36) and cross-check ───────────
-        let pi_n = Self::extract_u32(public_inputs, 32);
-        if pi_n != n {
-            EvVerifyFailed { reason: ZkVerifyError::RingInvalidSetSize as u32 }.publish(env);
-            return false;
+    /// Verify a fully uploaded proof and, on success, record it exactly like
+    /// `verify_and_record`. The upload is removed either way.
+    pub fn finish_verify(env: Env, session_id: u32, player: Address) -> Result<bool, ZkVerifyError> {
+        player.require_auth();
+        let upload = Self::load_upload(&env, session_id, &player)?;
+        if upload.proof.len() != upload.proof_len {
+            return Err(ZkVerifyError::ChunkedUploadIncomplete);
         }
+        env.storage().temporary().remove(&StorageKey::Upload(session_id, player.clone()));
 
-        let valid_set_offset = 36u32;
+        let Some(mode) = Self::dispatch(&env, Some(&upload.consumer), &upload.public_inputs, &upload.proof) else {
+            return Ok(false);
+        };
+        Self::record(&env, upload.consumer, session_id, player, mode, &upload.public_inputs);
+        Ok(true)
+    }
 
-        // ── Extract session_id [36+4*N..40+4*N) ────────────────────────────
-        let sid_offset = 36 + 4 * n;
-        let mut sid_arr = [0u8; 4];
-        i = 0;
-        while i < 4 {
-            sid_arr[i] = public_inputs.get(sid_offset + i as u32).unwrap_or(0);
-            i += 1;
+    /// Remove a record left by `verify_and_record`. Returns `true` exactly
+    /// once per record, so each verified proof is used at most once.
+    /// `commitment` is `keccak256(public_inputs)` of the verified statement,
+    /// which the consumer rebuilds from its own state.
+    pub fn consume_verification(
+        env: Env,
+        consumer: Address,
+        session_id: u32,
+        player: Address,
+        mode: u32,
+        commitment: BytesN<32>,
+    ) -> bool {
+        consumer.require_auth();
+        let key = StorageKey::Verified(consumer.clone(), session_id, player.clone(), mode, commitment);
+        if !env.storage().temporary().has(&key) {
+            return false;
         }
+        env.storage().temporary().remove(&key);
+        EvVerificationConsumed { consumer, session_id, player, mode }.publish(&env);
+        true
+    }
 
-        // ── Extract player [40+4*N..) ───────────────────────────────────────
-        let player_offset = 40 + 4 * n;
-        let player_len = public_inputs.len() - player_offset;
-        if player_len == 0 {
-            EvVerifyFailed { reason: ZkVerifyError::EmptyPlayerAddress as u32 }.publish(env);
+    /// Mode 2 with structured public inputs.
+    ///
+    /// Same check as `verify`, but the inputs arrive as a `NizkInputs` value
+    /// instead of a hand-built byte string, so they cannot be truncated or
+    /// misaligned. The proof must be exactly 64 bytes.
+    pub fn verify_nizk(env: Env, inputs: NizkInputs, proof: Bytes) -> bool {
+        let public_inputs = inputs.encode(&env);
+        let at = Attempt::start(&env, MODE_NIZK_SEED, &public_inputs, &proof);
+        if !Self::caller_allowed(&at, None) || !Self::mode_enabled(&at) {
             return false;
         }
-        let mut player_bytes = Bytes::new(env);
-        let mut j = 0u32;
-        while j < player_len {
-            player_bytes.push_back(public_inputs.get(player_offset + j).unwrap_or(0));
-            j += 1;
+        if proof.len() != NIZK_PROOF_LEN {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
         }
+        let ok = Self::verify_nizk_seed(&env, &public_inputs, &proof);
+        if ok {
+            Self::log_verification(&at);
+        }
+        ok
+    }
 
-        // ── Extract C from proof [0..96) ────────────────────────────────────
-        let commitment = Self::extract_g1(env, proof, 0);
-
-        // Subgroup check
-        if !bls.g1_is_in_subgroup(&commitment) {
-            EvVerifyFailed { reason: ZkVerifyError::RingPointNotOnCurve as u32 }.publish(env);
+    /// Mode 7 with structured public inputs. The proof must be exactly
+    /// |C| + 64·N bytes for N = `valid_set.len()` ∈ [1, `max_ring_size`],
+    /// where |C| is 96, or 48 if C is compressed.
+    pub fn verify_ring(env: Env, inputs: RingInputs, proof: Bytes) -> bool {
+        let public_inputs = inputs.encode(&env);
+        let at = Attempt::start(&env, MODE_CARD_RING, &public_inputs, &proof);
+        if !Self::caller_allowed(&at, None) || !Self::mode_enabled(&at) {
             return false;
         }
+        let n = inputs.valid_set.len();
+        if n == 0 || n > Self::deck_params(&env).max_ring_size {
+            return at.fail(0, ZkVerifyError::RingInvalidSetSize);
+        }
+        let c_len = ProofReader::new(&env, &proof).g1_len_at(0);
+        if proof.len() != c_len + RING_MEMBER_PROOF_LEN * n {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let ok = Self::verify_card_play_ring(&env, &public_inputs, &proof);
+        if ok {
+            Self::log_verification(&at);
+        }
+        ok
+    }
 
-        // Binding check: keccak256(C) == commit_hash
-        let c_raw = commitment.to_bytes();
-        let c_bytes_for_hash = Bytes::from_array(env, &c_raw.to_array());
-        let computed_commit: BytesN<32> = env.crypto().keccak256(&c_bytes_for_hash).into();
-        if computed_commit != commit_hash {
-            EvVerifyFailed { reason: ZkVerifyError::CommitmentMismatch as u32 }.publish(env);
+    /// Mode 8 with structured public inputs. The proof must be exactly
+    /// 228 bytes (less 48 for each compressed point) and its k must equal
+    /// `cards.len()`.
+    pub fn verify_hand(env: Env, inputs: HandInputs, proof: Bytes) -> bool {
+        let public_inputs = inputs.encode(&env);
+        let at = Attempt::start(&env, MODE_CANGKUL_HAND, &public_inputs, &proof);
+        if !Self::caller_allowed(&at, None) || !Self::mode_enabled(&at) {
             return false;
         }
+        if proof.len() != Self::cangkul_hand_proof_len(&ProofReader::new(&env, &proof)) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let ok = Self::verify_cangkul_hand(&env, &public_inputs, &proof);
+        if ok {
+            Self::log_verification(&at);
+        }
+        ok
+    }
 
-        // ── G1 generator (same as Mode 4) ──────────────────────────────────
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        let g = G1Affine::from_array(env, &g1_bytes);
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&StorageKey::Admin, &admin);
+        let h = Self::derive_pedersen_h(&env.crypto().bls12_381(), &env);
+        env.storage().instance().set(&StorageKey::PedersenH, &h.to_bytes());
+    }
 
-        // ── H generator (same nothing-up-my-sleeve derivation) ─────────────
-        let h = Self::pedersen_h(&bls, env);
+    pub fn get_admin(env: Env) -> Result<Address, ZkVerifyError> {
+        Self::load_admin(&env)
+    }
 
-        // ── Process each ring member: compute D_i and R_i ──────────────────
-        // Also build Fiat-Shamir challenge preimage: C || R_0 || ... || R_{N-1} || session_id || player || "ZKP7"
-        let mut challenge_preimage = Bytes::from_array(env, &c_raw.to_array());
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.storage().instance().set(&StorageKey::Admin, &new_admin);
+        Ok(())
+    }
 
-        // Vectors for the Σ e_i group-level check via MSM
-        let mut g_vec: Vec<G1Affine> = Vec::new(env);
-        let mut e_vec: Vec<Fr> = Vec::new(env);
+    /// Register (or rotate) the PLONK verification key for `circuit_id`.
+    ///
+    /// Mode 9 resolves its key by id at verification time, so rotating a
+    /// circuit is a single admin call instead of a redeploy. The key must
+    /// pass the structural checks of `PLONK_VK_LEN` keys, else
+    /// `PlonkInvalidVk`. UltraHonk circuits register their verifier with
+    /// `register_ultrahonk_circuit` instead.
+    pub fn register_vk(env: Env, circuit_id: u32, vk_bytes: Bytes) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if !Self::is_valid_plonk_vk(&env, &vk_bytes) {
+            return Err(ZkVerifyError::PlonkInvalidVk);
+        }
 
-        let mut idx = 0u32;
-        while idx < n {
-            // Extract e_i from proof [96 + idx*64 .. 96 + idx*64 + 32)
-            let e_i = Self::extract_fr(env, proof, 96 + idx * 64);
-            // Extract z_i from proof [96 + idx*64 + 32 .. 96 + idx*64 + 64)
-            let z_i = Self::extract_fr(env, proof, 96 + idx * 64 + 32);
-
-            // Extract valid_set[idx] and convert to Fr
-            let card_val = Self::extract_u32(public_inputs, valid_set_offset + idx * 4);
-            let mut card_fr_arr = [0u8; 32];
-            card_fr_arr[28] = ((card_val >> 24) & 0xFF) as u8;
-            card_fr_arr[29] = ((card_val >> 16) & 0xFF) as u8;
-            card_fr_arr[30] = ((card_val >> 8) & 0xFF) as u8;
-            card_fr_arr[31] = (card_val & 0xFF) as u8;
-            let card_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &card_fr_arr));
-
-            // D_i = C − card_i·G
-            let card_times_g = bls.g1_mul(&g, &card_fr);
-            let neg_card_g = -card_times_g;
-            let d_i = bls.g1_add(&commitment, &neg_card_g);
-
-            // R_i = z_i·H − e_i·D_i
-            let z_h = bls.g1_mul(&h, &z_i);
-            let e_d = bls.g1_mul(&d_i, &e_i);
-            let neg_e_d = -e_d;
-            let r_i = bls.g1_add(&z_h, &neg_e_d);
+        env.storage()
+            .instance()
+            .set(&StorageKey::Vk(circuit_id), &vk_bytes);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_LEDGERS, INSTANCE_TTL_LEDGERS);
 
-            // Append R_i to challenge preimage
-            let r_i_raw = r_i.to_bytes();
-            challenge_preimage.append(&Bytes::from_array(env, &r_i_raw.to_array()));
+        EvVkRegistered { circuit_id, len: vk_bytes.len() }.publish(&env);
+        Ok(())
+    }
 
-            // Accumulate for Σ e_i · G check
-            g_vec.push_back(g.clone());
-            e_vec.push_back(e_i);
+    /// Read the verification key registered for `circuit_id`, if any.
+    pub fn get_vk(env: Env, circuit_id: u32) -> Option<Bytes> {
+        env.storage().instance().get(&StorageKey::Vk(circuit_id))
+    }
 
-            idx += 1;
-        }
+    /// Register (or rotate) the UltraHonk verifier, its key baked in, that
+    /// Mode 22 forwards proofs naming `circuit_id` to.
+    pub fn register_ultrahonk_circuit(env: Env, circuit_id: u32, verifier: Address) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&StorageKey::UltraHonkCircuit(circuit_id), &verifier);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_LEDGERS, INSTANCE_TTL_LEDGERS);
+        EvUltraHonkCircuitSet { circuit_id, verifier }.publish(&env);
+        Ok(())
+    }
 
-        // ── Fiat-Shamir challenge ───────────────────────────────────────────
-        challenge_preimage.append(&Bytes::from_array(env, &sid_arr));
-        challenge_preimage.append(&player_bytes);
-        challenge_preimage.append(&Bytes::from_array(env, &RING_CHALLENGE_TAG));
+    /// Read the UltraHonk verifier registered for `circuit_id`, if any.
+    pub fn get_ultrahonk_circuit(env: Env, circuit_id: u32) -> Option<Address> {
+        env.storage().instance().get(&StorageKey::UltraHonkCircuit(circuit_id))
+    }
 
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_preimage).into();
-        let expected_e = Fr::from_bytes(e_hash);
+    /// Set the entropy policy Mode 2 applies to `seed_hash`.
+    pub fn set_entropy_policy(env: Env, policy: EntropyPolicy) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if !policy.is_valid() {
+            return Err(ZkVerifyError::InvalidEntropyPolicy);
+        }
+        env.storage().instance().set(&StorageKey::EntropyPolicy, &policy);
+        Ok(())
+    }
 
-        // ── Check Σ e_i == expected_e via group-level comparison ────────────
-        // MSM computes Σ (e_i · G) which equals (Σ e_i) · G
-        let sum_point = bls.g1_msm(g_vec, e_vec);
-        let expected_point = bls.g1_mul(&g, &expected_e);
+    pub fn get_entropy_policy(env: Env) -> EntropyPolicy {
+        Self::entropy_policy(&env)
+    }
 
-        if sum_point.to_bytes() != expected_point.to_bytes() {
-            EvVerifyFailed { reason: ZkVerifyError::RingChallengeCheckFailed as u32 }.publish(env);
-            return false;
+    /// Set the deck Modes 7, 8 and 12 check card ids and set sizes against,
+    /// e.g. `DeckParams::standard()` for a 52-card game.
+    pub fn set_deck_params(env: Env, params: DeckParams) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if !params.is_valid() {
+            return Err(ZkVerifyError::InvalidDeckParams);
         }
+        env.storage().instance().set(&StorageKey::DeckParams, &params);
+        Ok(())
+    }
 
-        EvVerifySuccess { mode: 7 }.publish(env);
-        true
+    pub fn get_deck_params(env: Env) -> DeckParams {
+        Self::deck_params(&env)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    //  Mode 8: Cangkul Hand Proof (Aggregate Pedersen + Schnorr + Suit Exclusion)
-    // ═══════════════════════════════════════════════════════════════════════════
+    /// Switch a mode on or off. A disabled mode fails with `ModeDisabled`
+    /// before any proof bytes are parsed.
+    pub fn set_mode_enabled(env: Env, mode: u32, enabled: bool) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if !MODES.contains(&mode) {
+            return Err(ZkVerifyError::UnknownMode);
+        }
+        let key = StorageKey::ModeDisabled(mode);
+        if enabled {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &true);
+        }
+        EvModeToggled { mode, enabled }.publish(&env);
+        Ok(())
+    }
 
-    /// Cangkul hand binding verification (Mode 8).
+    pub fn is_mode_enabled(env: Env, mode: u32) -> bool {
+        MODES.contains(&mode) && !env.storage().instance().has(&StorageKey::ModeDisabled(mode))
+    }
+
+    /// Restrict verification to allowlisted contracts calling `verify_from`.
+    /// While enabled, plain `verify` and the typed entrypoints always fail.
+    pub fn set_allowlist_enabled(env: Env, enabled: bool) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.storage().instance().set(&StorageKey::AllowlistEnabled, &enabled);
+        EvAllowlistToggled { enabled }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_allowlist_enabled(env: Env) -> bool {
+        env.storage().instance().get(&StorageKey::AllowlistEnabled).unwrap_or(false)
+    }
+
+    /// Add `caller` to, or remove it from, the caller allowlist.
+    pub fn set_caller_allowed(env: Env, caller: Address, allowed: bool) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        let key = StorageKey::AllowedCaller(caller.clone());
+        if allowed {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        EvCallerAllowed { caller, allowed }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_caller_allowed(env: Env, caller: Address) -> bool {
+        env.storage().instance().has(&StorageKey::AllowedCaller(caller))
+    }
+
+    /// Bind the Mode 2, 4, 7 and 8 challenges to this network's id, so a
+    /// proof made for one network never verifies on another. Provers must
+    /// append the 32-byte network id just before the domain tag.
+    pub fn set_network_binding(env: Env, enabled: bool) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if enabled {
+            env.storage().instance().set(&StorageKey::NetworkBound, &true);
+        } else {
+            env.storage().instance().remove(&StorageKey::NetworkBound);
+        }
+        EvNetworkBindingToggled { enabled }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_network_binding_enabled(env: Env) -> bool {
+        env.storage().instance().has(&StorageKey::NetworkBound)
+    }
+
+    /// Accept or refuse untagged proofs (the layouts before the format tag)
+    /// in the auto-detecting entrypoints. Refusing them frees every length
+    /// for future layouts; tagged proofs verify either way.
+    pub fn set_legacy_proofs_enabled(env: Env, enabled: bool) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        if enabled {
+            env.storage().instance().remove(&StorageKey::LegacyProofsDisabled);
+        } else {
+            env.storage().instance().set(&StorageKey::LegacyProofsDisabled, &true);
+        }
+        EvLegacyProofsToggled { enabled }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_legacy_proofs_enabled(env: Env) -> bool {
+        !env.storage().instance().has(&StorageKey::LegacyProofsDisabled)
+    }
+
+    /// Approximate cost of verifying one `mode` proof of size `n` (see
+    /// `MODE_COSTS`), so clients can pick a proof type and set resource fees
+    /// before building anything. Native estimates; on-chain WASM runs add
+    /// guest-instruction overhead. Reads are this contract's instance and
+    /// code, plus the UltraHonk verifier's for Mode 22, and the session's
+    /// verification log, which a success writes back; Modes 9, 11 and 22
+    /// bind no session and keep no log.
+    pub fn estimate_cost(mode: u32, n: u32) -> Result<CostEstimate, ZkVerifyError> {
+        let &(_, base, per_n) = MODE_COSTS
+            .iter()
+            .find(|(m, _, _)| *m == mode)
+            .ok_or(ZkVerifyError::UnknownMode)?;
+        let logged = !matches!(mode, MODE_PLONK | MODE_MERKLE | MODE_ULTRAHONK);
+        Ok(CostEstimate {
+            cpu_instructions: (base + per_n * n as u64) * 1000,
+            read_entries: if mode == MODE_ULTRAHONK { 4 } else { 2 + logged as u32 },
+            write_entries: logged as u32,
+        })
+    }
+
+    /// Admin only: verify a Mode 2, 4, 7 or 8 proof (decoded as in
+    /// `verify`) and report every sub-check instead of a bare `bool`, for
+    /// debugging client-side provers. Simulation records the admin auth
+    /// without a signature, so anyone can run it off-chain against a
+    /// deployed verifier. Skips the allowlist and mode switches; hidden-set
+    /// ring proofs report only `valid`.
+    pub fn verify_debug(env: Env, public_inputs: Bytes, proof: Bytes) -> Result<VerifyReport, ZkVerifyError> {
+        Self::load_admin(&env)?.require_auth();
+        let (mode, proof) = Self::decode(&env, &public_inputs, &proof)?;
+        let report = RefCell::new(VerifyReport {
+            mode,
+            valid: false,
+            failed_check: None,
+            reason: None,
+            checks: Vec::new(&env),
+        });
+        let at = Attempt::new(&env, mode, &public_inputs, &proof).traced(&report);
+        let valid = match mode {
+            MODE_NIZK_SEED => Self::nizk_seed(&at),
+            MODE_PEDERSEN_SEED => Self::pedersen_sigma(&at),
+            MODE_CARD_RING => Self::card_play_ring(&at),
+            MODE_CANGKUL_HAND => Self::cangkul_hand(&at),
+            _ => return Err(ZkVerifyError::NoDebugReport),
+        };
+        let mut report = report.into_inner();
+        report.valid = valid;
+        Ok(report)
+    }
+
+    /// Register the UltraHonk verifier that Mode 22 forwards Noir proofs to.
+    pub fn set_ultrahonk_verifier(env: Env, verifier: Address) -> Result<(), ZkVerifyError> {
+        let admin = Self::load_admin(&env)?;
+        admin.require_auth();
+        env.storage().instance().set(&StorageKey::UltraHonkVerifier, &verifier);
+        EvUltraHonkVerifierSet { verifier }.publish(&env);
+        Ok(())
+    }
+
+    /// Successful verifications in `session_id`, one entry per (player,
+    /// mode) in the order each pair first verified, so a finished game can
+    /// be checked for the proofs every trick needed without replaying
+    /// events. Counts cover every entrypoint and caller, so they are a
+    /// floor for the game's own proofs. Empty once the log expires, a day
+    /// after its last count.
+    pub fn get_verification_log(env: Env, session_id: u32) -> Vec<VerificationCount> {
+        env.storage()
+            .temporary()
+            .get(&StorageKey::VerificationLog(session_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_ultrahonk_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&StorageKey::UltraHonkVerifier)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Verification routines (embeddable)
+// ═══════════════════════════════════════════════════════════════════════════════
+//
+// The per-mode routines live outside `#[contractimpl]` so they are never
+// exported as contract functions. Other game contracts can build this crate
+// with the `lib` feature and call them in-process instead of paying for a
+// cross-contract `verify` call. Events are published under the caller's
+// contract address in that case.
+
+impl ZkCommitmentVerifier {
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 2: NIZK Seed Proof of Knowledge (enhanced ZK — new!)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Non-Interactive Zero-Knowledge Proof of Knowledge for seed commitment.
     ///
-    /// Proves that a player's hand contains NO cards matching the trick suit,
-    /// using an aggregate Pedersen commitment over the entire hand with a
-    /// Schnorr proof of knowledge of the aggregate blinding factor.
+    /// The prover demonstrates knowledge of `seed` such that:
+    ///   `seed_hash = keccak256(seed)` AND
+    ///   `commitment = keccak256(seed_hash || blinding || player_address)`
+    /// WITHOUT revealing the raw `seed` on-chain.
     ///
-    /// **Security properties:**
-    /// - **Binding**: keccak256(A) == commit_hash ties the proof to the commit.
-    /// - **Knowledge**: Schnorr sigma proves the prover knows r_agg such that
-    ///   A = (Σ card_i)·G + r_agg·H.
-    /// - **Suit exclusion**: Every card in the public set is checked against
-    ///   trick_suit on-chain — no card with matching suit passes.
-    /// - **Session-bound**: Fiat-Shamir challenge includes session_id and player,
-    ///   preventing cross-session replay.
+    /// **Public inputs:** `seed_hash(32) || commitment(32) || nullifier(32) || session_id(4) || player(var)`
+    /// **Proof:** `blinding(32) || response(32)`
     ///
-    /// **Public inputs layout:**
-    /// ```text
-    /// commit_hash(32) || trick_suit(4, u32 BE) || k(4, u32 BE) ||
-    /// card_1(4) || ... || card_k(4) || session_id(4) || player(var)
-    /// ```
+    /// Verification:
+    /// 1. Commitment binding: `keccak256(seed_hash || blinding || player) == commitment`
+    /// 2. Nullifier check: `keccak256(seed_hash || "NULL" || session_id) == nullifier`
+    /// 3. Fiat-Shamir challenge: `e = keccak256(commitment || session_id || player || "ZKV2")`
+    /// 4. Response check: `keccak256(seed_hash || e || blinding) == response`
+    /// 5. Entropy: `seed_hash` must satisfy the stored `EntropyPolicy`
     ///
-    /// **Proof layout (228 bytes):**
-    /// ```text
-    /// k(4, u32 BE) || A(96, G1) || R(96, G1) || z(32, Fr)
-    /// ```
+    /// A ledger window after the player is part of `player` in step 3 only;
+    /// the commitment was made before the proof and never covers it.
+    pub fn verify_nizk_seed(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        Self::nizk_seed(&Attempt::new(env, MODE_NIZK_SEED, public_inputs, proof))
+    }
+
+    fn nizk_seed(at: &Attempt) -> bool {
+        let (env, public_inputs, proof) = (at.env, at.public_inputs, at.proof);
+        if !at.fresh() {
+            return false;
+        }
+        let Some(hash) = at.transcript_hash() else {
+            return false;
+        };
+        // ── Extract public inputs ───────────────────────────────────────────
+        // seed_hash(32) || commitment(32) || nullifier(32) || session_id(4) || player(var)
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let seed_hash_arr: [u8; 32] = inputs.array();
+        let seed_hash = BytesN::<32>::from_array(env, &seed_hash_arr);
+        let commitment = inputs.digest();
+        let nullifier = inputs.digest();
+        let sid_arr: [u8; 4] = inputs.array();
+
+        let player_bytes = inputs.rest();
+        if player_bytes.is_empty() {
+            return at.fail(1, ZkVerifyError::EmptyPlayerAddress);
+        }
+
+        // ── Extract proof (witness): blinding(32) || response(32) ──────────
+        let mut witness = ProofReader::new(env, proof);
+        let blinding = witness.digest();
+        let response = witness.digest();
+
+        // ── Step 1: Verify commitment binding ───────────────────────────────
+        // C' = keccak256(seed_hash || blinding || player_address)
+        // The commitment predates the proof, so it never covers a trailer and
+        // stays keccak256 whichever hash the transcript selects.
+        let address_len = player_bytes.len() - at.trailer_len();
+        let mut commit_preimage = Bytes::from_array(env, &seed_hash.to_array());
+        commit_preimage.append(&Bytes::from_array(env, &blinding.to_array()));
+        commit_preimage.append(&player_bytes.slice(..address_len));
+        let computed_commitment: BytesN<32> = env.crypto().keccak256(&commit_preimage).into();
+        if !at.check(symbol_short!("binding"), Self::digest_eq(&computed_commitment, &commitment), &computed_commitment) {
+            return at.fail(2, ZkVerifyError::CommitmentMismatch);
+        }
+
+        // ── Step 2: Verify nullifier (session-binding) ──────────────────────
+        // N' = keccak256(seed_hash || "NULL" || session_id_be4)
+        // Always keccak256, so one seed has one nullifier under either hash.
+        let mut null_preimage = Bytes::from_array(env, &seed_hash.to_array());
+        null_preimage.append(&Bytes::from_array(env, &NULLIFIER_TAG));
+        null_preimage.append(&Bytes::from_array(env, &sid_arr));
+        let computed_nullifier: BytesN<32> = env.crypto().keccak256(&null_preimage).into();
+        if !at.check(symbol_short!("nullifier"), Self::digest_eq(&computed_nullifier, &nullifier), &computed_nullifier) {
+            return at.fail(3, ZkVerifyError::NullifierMismatch);
+        }
+
+        // ── Step 3: Recompute Fiat-Shamir challenge ─────────────────────────
+        // e = keccak256(commitment || session_id_be4 || player_address || [network_id] || "ZKV2")
+        let mut transcript = FiatShamir::using(env, hash);
+        transcript.digest(&commitment).array(&sid_arr).bytes(&player_bytes);
+        let challenge = Self::bind_network(env, &mut transcript).hash_challenge(&CHALLENGE_TAG);
+        at.check(symbol_short!("challenge"), true, &challenge);
+
+        // ── Step 4: Verify response ─────────────────────────────────────────
+        // expected_response = H(seed_hash || challenge || blinding), H as for e
+        let mut resp_preimage = Bytes::from_array(env, &seed_hash.to_array());
+        resp_preimage.append(&Bytes::from_array(env, &challenge.to_array()));
+        resp_preimage.append(&Bytes::from_array(env, &blinding.to_array()));
+        let expected_response = hash.plain(env, &resp_preimage);
+        if !at.check(symbol_short!("response"), Self::digest_eq(&expected_response, &response), &expected_response) {
+            return at.fail(4, ZkVerifyError::ResponseMismatch);
+        }
+
+        // ── Step 5: Entropy check on seed_hash ──────────────────────────────
+        let entropy_ok = Self::entropy_policy(env).check(&seed_hash_arr).is_ok();
+        if !at.check(symbol_short!("entropy"), entropy_ok, &seed_hash) {
+            return at.fail(5, ZkVerifyError::WeakSeedEntropy);
+        }
+
+        // ── All checks passed ───────────────────────────────────────────────
+        EvVerifySuccess { mode: MODE_NIZK_SEED }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 4: Pedersen+Sigma Protocol (BLS12-381) — NEW!
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Seed entropy policy for Mode 2. Like `pedersen_h`, the `lib` build
+    /// never reads the embedder's storage and uses the default policy.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn entropy_policy(env: &Env) -> EntropyPolicy {
+        #[cfg(not(feature = "lib"))]
+        if let Some(policy) = env.storage().instance().get(&StorageKey::EntropyPolicy) {
+            return policy;
+        }
+        EntropyPolicy::default()
+    }
+
+    /// Deck parameters for Modes 7, 8 and 12. The `lib` build uses the
+    /// 36-card default.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn deck_params(env: &Env) -> DeckParams {
+        #[cfg(not(feature = "lib"))]
+        if let Some(params) = env.storage().instance().get(&StorageKey::DeckParams) {
+            return params;
+        }
+        DeckParams::default()
+    }
+
+    /// Pedersen H generator for Modes 4, 7, 8, 10, 12, 13 and 14.
     ///
-    /// **Verification:**
-    /// 1. Cross-check k in proof vs public_inputs
-    /// 2. Binding: keccak256(A) == commit_hash
-    /// 3. Suit exclusion: for each card_i, verify card_i / 9 ≠ trick_suit
-    /// 4. Compute expected_sum = Σ(card_i · G) via g1_msm
-    /// 5. delta = A − expected_sum (should be r_agg · H)
-    /// 6. e = Fr(keccak256(A || R || trick_suit || k || session_id || player || "ZKP8"))
-    /// 7. Schnorr check: z · H == R + e · delta
-    fn verify_cangkul_hand(
+    /// The standalone contract reads the copy its constructor cached in
+    /// instance storage and only falls back to `hash_to_g1` when it is absent.
+    /// The `lib` build never touches the embedder's storage and always derives.
+    fn pedersen_h(bls: &Bls12_381, env: &Env) -> G1Affine {
+        #[cfg(not(feature = "lib"))]
+        if let Some(h) = env.storage().instance().get::<_, BytesN<96>>(&StorageKey::PedersenH) {
+            return G1Affine::from_bytes(h);
+        }
+        Self::derive_pedersen_h(bls, env)
+    }
+
+    /// Standard G1 generator G.
+    fn g1_generator(env: &Env) -> G1Affine {
+        G1Affine::from_array(env, &G1_GENERATOR)
+    }
+
+    /// Count generator K for Mode 21, `hash_to_g1(HAND_COUNT_K_MSG, PEDERSEN_H_DST)`.
+    fn hand_count_k(bls: &Bls12_381, env: &Env) -> G1Affine {
+        let msg = Bytes::from_slice(env, HAND_COUNT_K_MSG);
+        let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+        bls.hash_to_g1(&msg, &dst)
+    }
+
+    /// Dummy member of a padded ring (Modes 7 and 12),
+    /// `hash_to_g1(RING_PAD_MSG, PEDERSEN_H_DST)`.
+    fn ring_pad_point(bls: &Bls12_381, env: &Env) -> G1Affine {
+        let msg = Bytes::from_slice(env, RING_PAD_MSG);
+        let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+        bls.hash_to_g1(&msg, &dst)
+    }
+
+    /// Position generator H_i for Mode 23,
+    /// `hash_to_g1(VECTOR_H_MSG, VECTOR_H_DST_PREFIX || i)`.
+    fn vector_generator(bls: &Bls12_381, env: &Env, index: u32) -> G1Affine {
+        let msg = Bytes::from_slice(env, VECTOR_H_MSG);
+        let mut dst = Bytes::from_slice(env, VECTOR_H_DST_PREFIX);
+        dst.append(&Bytes::from_array(env, &index.to_be_bytes()));
+        bls.hash_to_g1(&msg, &dst)
+    }
+
+    /// Derive the Pedersen H generator using hash_to_g1 with a fixed DST.
+    /// This is deterministic and reproducible by anyone.
+    fn derive_pedersen_h(bls: &Bls12_381, env: &Env) -> G1Affine {
+        let msg = Bytes::from_slice(env, PEDERSEN_H_MSG);
+        let dst = Bytes::from_slice(env, PEDERSEN_H_DST);
+        bls.hash_to_g1(&msg, &dst)
+    }
+
+    /// Pedersen+Sigma verification (Mode 4).
+    ///
+    /// **Public inputs:** `C(96, G1) || seed_hash(32, Fr) || session_id(4) || player(var)`
+    /// **Proof:** `R(96, G1) || z_r(32, Fr)` = 128 bytes
+    ///
+    /// C and R may each be sent compressed (48 bytes), so the proof is 80
+    /// bytes with a compressed R. The challenge always hashes the
+    /// uncompressed points, so both encodings verify the same proof.
+    ///
+    /// Verification:
+    /// 1. Load H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1") (cached at construction)
+    /// 2. Extract C, R, z_r from proof; seed_hash from public_inputs
+    /// 3. Check C and R are in the G1 subgroup
+    /// 4. Compute D = C − seed_hash·G (strips public component)
+    /// 5. Compute Fiat-Shamir challenge: e = Fr(keccak256(C || R || seed_hash || session_id || player || "ZKP4"))
+    /// 6. Schnorr check: z_r·H == R + e·D (proves knowledge of blinding r)
+    /// 7. Accept iff LHS == RHS
+    pub fn verify_pedersen_sigma(
         env: &Env,
         public_inputs: &Bytes,
         proof: &Bytes,
     ) -> bool {
+        Self::pedersen_sigma(&Attempt::new(env, MODE_PEDERSEN_SEED, public_inputs, proof))
+    }
+
+    fn pedersen_sigma(at: &Attempt) -> bool {
+        let (env, public_inputs, proof) = (at.env, at.public_inputs, at.proof);
+        if !at.fresh() {
+            return false;
+        }
+        let Some(hash) = at.transcript_hash() else {
+            return false;
+        };
         let bls = env.crypto().bls12_381();
 
-        // ── Extract k from proof [0..4) ─────────────────────────────────────
-        let k = Self::extract_u32(proof, 0);
-        if k == 0 || k > 18 {
-            EvVerifyFailed { reason: ZkVerifyError::HandCardCountMismatch as u32 }.publish(env);
+        // ── Minimum public_inputs length: C(96 or 48) + seed_hash(32) + session_id(4) + player(>=1)
+        let mut inputs = InputsReader::new(env, public_inputs);
+        if public_inputs.len() < inputs.g1_len_at(0) + 37 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Proof: R(96 or 48) || z_r(32) ──────────────────────────────────
+        let mut witness = ProofReader::new(env, proof);
+        if proof.len() != witness.g1_len_at(0) + 32 {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+
+        // ── Extract public inputs ───────────────────────────────────────────
+        let Some(commitment) = inputs.g1_any() else {
+            return at.fail(3, ZkVerifyError::PedersenPointNotOnCurve);
+        };
+        let seed_hash_bytes: [u8; 32] = inputs.array();
+        let seed_hash_fr = Fr::from_bytes(BytesN::from_array(env, &seed_hash_bytes));
+        let sid_arr: [u8; 4] = inputs.array();
+
+        let player_bytes = inputs.rest();
+        if player_bytes.is_empty() {
+            return at.fail(2, ZkVerifyError::EmptyPlayerAddress);
+        }
+
+        // ── Extract proof ───────────────────────────────────────────────────
+        let Some(r_point) = witness.g1_any() else {
+            return at.fail(4, ZkVerifyError::PedersenPointNotOnCurve);
+        };
+        let z_r = witness.fr();
+
+        // ── Subgroup checks ─────────────────────────────────────────────────
+        if !at.check(symbol_short!("subgroup"), bls.g1_is_in_subgroup(&commitment), &commitment.to_bytes()) {
+            return at.fail(3, ZkVerifyError::PedersenPointNotOnCurve);
+        }
+        if !at.check(symbol_short!("subgroup"), bls.g1_is_in_subgroup(&r_point), &r_point.to_bytes()) {
+            return at.fail(4, ZkVerifyError::PedersenPointNotOnCurve);
+        }
+
+        // ── Derive H generator and G1 generator ────────────────────────────
+        let h = Self::pedersen_h(&bls, env);
+
+        let g = Self::g1_generator(env);
+
+        // ── Compute D = C − seed_hash·G ─────────────────────────────────────
+        // If honest: D = blinding·H
+        let s_times_g = bls.g1_mul(&g, &seed_hash_fr);
+        let neg_s_times_g = -s_times_g;
+        let d = bls.g1_add(&commitment, &neg_s_times_g);
+
+        // ── Compute Fiat-Shamir challenge ───────────────────────────────────
+        // e = Fr(keccak256(C || R || seed_hash || session_id || player || [network_id] || "ZKP4"))
+        let mut transcript = FiatShamir::using(env, hash);
+        transcript
+            .g1(&commitment)
+            .g1(&r_point)
+            .array(&seed_hash_bytes)
+            .array(&sid_arr)
+            .bytes(&player_bytes);
+        let e = Self::bind_network(env, &mut transcript).challenge(&PEDERSEN_CHALLENGE_TAG);
+        at.check(symbol_short!("challenge"), true, &e.to_bytes());
+
+        // ── Schnorr verification on D ───────────────────────────────────────
+        // Prove knowledge of r such that D = r·H
+        // LHS = z_r · H
+        // RHS = R + e · D
+        let lhs = bls.g1_mul(&h, &z_r);
+        let e_times_d = bls.g1_mul(&d, &e);
+        let rhs = bls.g1_add(&r_point, &e_times_d);
+
+        // ── Final equality check ────────────────────────────────────────────
+        if !at.check(symbol_short!("schnorr"), lhs.to_bytes() == rhs.to_bytes(), &lhs.to_bytes()) {
+            return at.fail(5, ZkVerifyError::PedersenSigmaCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(at, 6, &inputs, false) || !Self::canonical(at, 7, &witness, false) {
             return false;
         }
 
-        // Minimum public_inputs: commit_hash(32) + trick_suit(4) + k(4) + cards(4*k) + session_id(4) + player(>=1)
-        let min_inputs = 32 + 4 + 4 + 4 * k + 4 + 1;
-        if public_inputs.len() < min_inputs {
-            EvVerifyFailed { reason: ZkVerifyError::InputsTooShort as u32 }.publish(env);
-            return false;
-        }
+        EvVerifySuccess { mode: MODE_PEDERSEN_SEED }.publish(env);
+        true
+    }
+
+    /// Mode that `verify` dispatches `proof` to, chosen by proof length.
+    fn mode_of(public_inputs: &Bytes, proof: &Bytes) -> Option<u32> {
+        let proof_len = proof.len();
+
+        if proof_len == 0 {
+            return None;
+        }
+
+        // Mode 4: Pedersen+Sigma — proof is exactly 128 bytes (R_G1 + z_r), or 80 with R compressed.
+        // 80 is not 4 + 32·d, 96 + 64·N or 48 + 64·N, so no other mode matches.
+        if proof_len == PEDERSEN_PROOF_LEN || proof_len == PEDERSEN_PROOF_LEN - 48 {
+            return Some(MODE_PEDERSEN_SEED);
+        }
+
+        // Mode 8: Cangkul Hand Proof — proof is exactly 228 bytes (k(4) + A(96) + R(96) + z(32)),
+        // or 180 with one of A and R compressed. 180 matches no other mode's pattern.
+        if proof_len == CANGKUL_HAND_PROOF_LEN || proof_len == CANGKUL_HAND_PROOF_LEN - 48 {
+            return Some(MODE_CANGKUL_HAND);
+        }
+
+        // Mode 8 with both points compressed is 132 bytes, which is also a depth-4 Mode 11
+        // proof. Mode 8 repeats k ∈ [1, 18] at public_inputs[36..40) and sets the compression
+        // flag on A; Mode 11 has the top of an uncompressed C's x coordinate there, which
+        // would have to be ground to equal the index it proves.
+        if proof_len == CANGKUL_HAND_PROOF_LEN - 96
+            && proof.get_unchecked(4) & G1_COMPRESSION_FLAG != 0
+            && public_inputs.len() >= 40
+            && public_inputs.slice(36..40) == proof.slice(0..4)
+        {
+            return Some(MODE_CANGKUL_HAND);
+        }
+
+        // Mode 9: PLONK/KZG — proof is exactly 1056 bytes (9 G1 + 6 Fr).
+        // Checked before Mode 7: 1056 also fits the 96 + N×64 pattern (N = 15).
+        if proof_len == PLONK_PROOF_LEN {
+            return Some(MODE_PLONK);
+        }
+
+        // Mode 10: Range proof — proof is 2 × m × 384 bytes where m ∈ [1, 8].
+        // 768·m − 96 ≡ 32 (mod 64), so these lengths never collide with Mode 7.
+        if proof_len.is_multiple_of(2 * RANGE_BIT_PROOF_LEN)
+            && proof_len <= 2 * 8 * RANGE_BIT_PROOF_LEN
+        {
+            return Some(MODE_RANGE);
+        }
+
+        // Mode 11: Merkle membership — proof is index(4) + d×32 bytes where d ∈ [1, 6].
+        // 4 + 32·d is never 64, 128, 228 or 96 + k·64; d = 4 is shared only with the
+        // compressed Mode 8 proof ruled out above.
+        if proof_len >= 36
+            && (proof_len - 4).is_multiple_of(32)
+            && (proof_len - 4) / 32 <= MERKLE_MAX_DEPTH
+        {
+            return Some(MODE_MERKLE);
+        }
+
+        // Mode 13: Private cangkul hand — proof is k × 1632 bytes where k ∈ [1, 4].
+        // Never a multiple of 768 for k ≤ 4 and always above Mode 7's 928-byte maximum.
+        if proof_len.is_multiple_of(PRIVATE_HAND_CARD_PROOF_LEN)
+            && proof_len <= PRIVATE_HAND_MAX_CHUNK * PRIVATE_HAND_CARD_PROOF_LEN
+        {
+            return Some(MODE_PRIVATE_HAND);
+        }
+
+        // Mode 14: Shuffle permutation — proof is exactly 2656 bytes.
+        // Checked before Mode 7: 2656 also fits the 96 + N×64 pattern (N = 40).
+        if proof_len == SHUFFLE_PROOF_LEN {
+            return Some(MODE_SHUFFLE);
+        }
+
+        // Mode 15: ElGamal share — proof is exactly 320 bytes.
+        // 320 ≡ 0 (mod 64) and 316 is not a multiple of 32, so no other mode matches.
+        if proof_len == ELGAMAL_PROOF_LEN {
+            return Some(MODE_ELGAMAL);
+        }
+
+        // Mode 16: Card comparison — proof is exactly 4320 bytes.
+        // Checked before Mode 7: 4320 also fits the 96 + N×64 pattern (N = 66).
+        if proof_len == CARD_COMPARE_PROOF_LEN {
+            return Some(MODE_CARD_COMPARE);
+        }
+
+        // Mode 17: Commitment equality — proof is 4 + n×288 bytes where n ∈ [1, 9].
+        // 4 + 288·n ≡ 4 or 36 (mod 64), is never 228 and exceeds Mode 11's 196.
+        if proof_len > 4
+            && (proof_len - 4).is_multiple_of(COMMIT_EQ_PAIR_PROOF_LEN)
+            && (proof_len - 4) / COMMIT_EQ_PAIR_PROOF_LEN <= COMMIT_EQ_MAX_PAIRS
+        {
+            return Some(MODE_COMMIT_EQ);
+        }
+
+        // Mode 18: Deal consistency — proof is exactly 640 bytes.
+        // 640 − 96 ≡ 32 (mod 64) and 636 is not a multiple of 32 or 288, so no other mode matches.
+        if proof_len == DEAL_PROOF_LEN {
+            return Some(MODE_DEAL);
+        }
+
+        // Mode 19: Threshold seed — proof is 4 + k×128 bytes where k ∈ [2, 8].
+        // 4 + 128·k ≡ 36 (mod 64) and exceeds Mode 11's 196; 128·k is never a multiple of 288.
+        if proof_len > 4
+            && (proof_len - 4).is_multiple_of(THRESHOLD_PARTY_PROOF_LEN)
+            && (THRESHOLD_MIN_PARTIES..=THRESHOLD_MAX_PARTIES).contains(&((proof_len - 4) / THRESHOLD_PARTY_PROOF_LEN))
+        {
+            return Some(MODE_THRESHOLD_SEED);
+        }
+
+        // Mode 20: BLS attestation — proof is exactly 192 bytes (one G2 signature).
+        // 192 − 96 ≡ 32 (mod 64) and 188 is not a multiple of 32, so no other mode matches.
+        if proof_len == BLS_SIGNATURE_LEN {
+            return Some(MODE_BLS_ATTEST);
+        }
+
+        // Mode 21: Hand count — proof is exactly 96 bytes (e, z_s, z_r).
+        // Below Mode 7's 160-byte minimum, 96 − 48 is not a multiple of 64 and 92 is not a
+        // multiple of 32, so no other mode matches.
+        if proof_len == HAND_COUNT_PROOF_LEN {
+            return Some(MODE_HAND_COUNT);
+        }
+
+        // Mode 23: Vector opening — proof is 72 + 32·u bytes for u ≤ 18 unopened positions.
+        // 72 + 32·u ≡ 8 (mod 32), a residue no other mode's lengths reach.
+        if proof_len >= VECTOR_PROOF_HEADER_LEN
+            && (proof_len - VECTOR_PROOF_HEADER_LEN).is_multiple_of(32)
+            && (proof_len - VECTOR_PROOF_HEADER_LEN) / 32 <= VECTOR_MAX_LEN
+        {
+            return Some(MODE_VECTOR_COMMIT);
+        }
+
+        // Mode 24: Linked card play — 56 + 64·(N + k) bytes, C always compressed.
+        // ≡ 24 (mod 32), again a residue of its own.
+        if proof_len >= LINKED_PROOF_HEADER_LEN + 2 * 64
+            && (proof_len - LINKED_PROOF_HEADER_LEN).is_multiple_of(64)
+            && (proof_len - LINKED_PROOF_HEADER_LEN) / 64 <= LINKED_MAX_MEMBERS
+        {
+            return Some(MODE_LINKED_PLAY);
+        }
+
+        // Mode 25: Ed25519 seed — proof is exactly 97 bytes starting with 0xED.
+        // Every other mode's proof length is even.
+        if proof_len == ED25519_PROOF_LEN && proof.get_unchecked(0) == ED25519_PROOF_TAG {
+            return Some(MODE_ED25519_SEED);
+        }
+
+        // Mode 22: UltraHonk — anything above 4096 bytes not claimed by Modes 10, 13 or 16.
+        // Checked before Mode 7, whose length pattern has no upper bound here.
+        if proof_len > ULTRAHONK_MIN_PROOF_LEN {
+            return Some(MODE_ULTRAHONK);
+        }
+
+        // Mode 7: Card Play Ring Sigma — proof is 96 + N×64 bytes where N ∈ [1, 13]
+        // Mode 7 max valid N = RING_SIZE_LIMIT (a 52-card suit; 9 for the default deck),
+        // so proof_len ≤ 96 + 13*64 = 928. Bit 31 of N selects Mode 12.
+        // With C compressed it is 48 + N×64 bytes; ≡ 48 (mod 64) is unique to Mode 7.
+        if (proof_len >= 160 && (proof_len - 96).is_multiple_of(64))
+            || (proof_len >= 112 && (proof_len - 48).is_multiple_of(64))
+        {
+            return Some(Self::ring_mode(public_inputs));
+        }
+
+        // Mode 2: NIZK seed — proof is exactly 64 bytes (blinding + response)
+        if proof_len == NIZK_PROOF_LEN && public_inputs.len() > NIZK_INPUTS_FIXED_LEN {
+            return Some(MODE_NIZK_SEED);
+        }
+
+        None
+    }
+
+    /// Mode 7 or 12, by the hidden-set flag: the top bit of the big-endian
+    /// N at offset 32.
+    fn ring_mode(public_inputs: &Bytes) -> u32 {
+        let hidden = public_inputs.len() >= 36
+            && public_inputs.get_unchecked(32) & (HIDDEN_RING_FLAG >> 24) as u8 != 0;
+        if hidden { MODE_HIDDEN_RING } else { MODE_CARD_RING }
+    }
+
+    /// The mode of `proof` and its untagged layout. A version-1 tag names
+    /// the mode outright (the ring flag still tells 7 from 12); untagged
+    /// proofs go through `mode_of` while legacy proofs are accepted.
+    ///
+    /// With legacy proofs on, a leading tag byte only counts if the rest is
+    /// a layout of the mode it names. Untagged layouts are even-length
+    /// except Mode 25's, which starts with 0xED (no tag), so stripping one
+    /// byte from a legacy proof leaves no layout below Mode 22's lengths,
+    /// and Mode 22 proofs are BN254 field elements, whose first byte is
+    /// never a tag.
+    fn decode(env: &Env, public_inputs: &Bytes, proof: &Bytes) -> Result<(u32, Bytes), ZkVerifyError> {
+        let legacy = Self::legacy_proofs(env);
+        if let Some(tag) = proof.first() {
+            if tag & PROOF_FORMAT_VERSION_MASK == PROOF_FORMAT_V1 {
+                let layout = proof.slice(1..);
+                let mode = match u32::from(tag & PROOF_FORMAT_MODE_MASK) {
+                    MODE_CARD_RING | MODE_HIDDEN_RING => Self::ring_mode(public_inputs),
+                    mode => mode,
+                };
+                // Mode 26 has no length rule; its tag counts unless the whole
+                // proof is an untagged layout, which a bundle's odd length
+                // below Mode 22's never is.
+                let matches = match mode {
+                    MODE_TRICK_BUNDLE => Self::mode_of(public_inputs, proof).is_none(),
+                    _ => Self::mode_of(public_inputs, &layout) == Some(mode),
+                };
+                if MODES.contains(&mode) && (!legacy || matches) {
+                    return Ok((mode, layout));
+                }
+                if !legacy {
+                    return Err(ZkVerifyError::UnknownMode);
+                }
+            } else if !legacy {
+                return Err(ZkVerifyError::UnknownProofVersion);
+            }
+        }
+        let mode = Self::mode_of(public_inputs, proof).ok_or(ZkVerifyError::ProofWrongLength)?;
+        Ok((mode, proof.clone()))
+    }
+
+    /// Whether untagged proofs are still accepted. Always in the `lib` build.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn legacy_proofs(env: &Env) -> bool {
+        #[cfg(not(feature = "lib"))]
+        if env.storage().instance().has(&StorageKey::LegacyProofsDisabled) {
+            return false;
+        }
+        true
+    }
+
+    /// Run the mode `decode` picks, unless `caller` fails the allowlist or
+    /// the admin has disabled the mode. Returns the mode if the proof verified.
+    fn dispatch(env: &Env, caller: Option<&Address>, public_inputs: &Bytes, proof: &Bytes) -> Option<u32> {
+        let decoded = Self::decode(env, public_inputs, proof);
+        let (mode, proof) = match &decoded {
+            Ok((mode, layout)) => (*mode, layout),
+            Err(_) => (0, proof),
+        };
+        let at = Attempt::start(env, mode, public_inputs, proof);
+        if !Self::caller_allowed(&at, caller) {
+            return None;
+        }
+        if let Err(reason) = decoded {
+            at.fail(0, reason);
+            return None;
+        }
+        if !Self::mode_enabled(&at) {
+            return None;
+        }
+        let ok = match mode {
+            MODE_NIZK_SEED => Self::verify_nizk_seed(env, public_inputs, proof),
+            MODE_PEDERSEN_SEED => Self::verify_pedersen_sigma(env, public_inputs, proof),
+            MODE_CARD_RING | MODE_HIDDEN_RING => Self::verify_card_play_ring(env, public_inputs, proof),
+            MODE_CANGKUL_HAND => Self::verify_cangkul_hand(env, public_inputs, proof),
+            MODE_PLONK => Self::verify_plonk(env, public_inputs, proof),
+            MODE_RANGE => Self::verify_range(env, public_inputs, proof),
+            MODE_MERKLE => Self::verify_merkle_membership(env, public_inputs, proof),
+            MODE_PRIVATE_HAND => Self::verify_cangkul_hand_private(env, public_inputs, proof),
+            MODE_SHUFFLE => Self::verify_shuffle_permutation(env, public_inputs, proof),
+            MODE_ELGAMAL => Self::verify_elgamal_share(env, public_inputs, proof),
+            MODE_CARD_COMPARE => Self::verify_card_compare(env, public_inputs, proof),
+            MODE_COMMIT_EQ => Self::verify_commitment_equality(env, public_inputs, proof),
+            MODE_DEAL => Self::verify_deal_consistency(env, public_inputs, proof),
+            MODE_THRESHOLD_SEED => Self::verify_threshold_seed(env, public_inputs, proof),
+            MODE_BLS_ATTEST => Self::verify_bls_attestation(env, public_inputs, proof),
+            MODE_HAND_COUNT => Self::verify_hand_count(env, public_inputs, proof),
+            MODE_VECTOR_COMMIT => Self::verify_vector_opening(env, public_inputs, proof),
+            MODE_LINKED_PLAY => Self::verify_linked_play(env, public_inputs, proof),
+            MODE_ED25519_SEED => Self::verify_ed25519_seed(env, public_inputs, proof),
+            MODE_TRICK_BUNDLE => Self::verify_trick_bundle(env, public_inputs, proof),
+            _ => Self::verify_ultrahonk(env, public_inputs, proof),
+        };
+        if ok {
+            Self::log_verification(&at);
+        }
+        ok.then_some(mode)
+    }
+
+    /// Count a successful verification in its session's log. Modes without
+    /// a session go uncounted, and the `lib` build keeps no log.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn log_verification(at: &Attempt) {
+        #[cfg(not(feature = "lib"))]
+        if let (Some(session_id), Some(player)) = (at.session_id(), at.player()) {
+            let key = StorageKey::VerificationLog(session_id);
+            let storage = at.env.storage().temporary();
+            let mut log: Vec<VerificationCount> = storage.get(&key).unwrap_or_else(|| Vec::new(at.env));
+            match log.iter().position(|entry| entry.player == player && entry.mode == at.mode) {
+                Some(i) => {
+                    let mut entry = log.get_unchecked(i as u32);
+                    entry.count = entry.count.saturating_add(1);
+                    log.set(i as u32, entry);
+                }
+                None if log.len() < VERIFICATION_LOG_MAX_ENTRIES => {
+                    log.push_back(VerificationCount { player, mode: at.mode, count: 1 });
+                }
+                None => return,
+            }
+            storage.set(&key, &log);
+            storage.extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        }
+    }
+
+    /// Whether `mode` is switched on, emitting `ModeDisabled` if not. The
+    /// `lib` build has no operator and treats every mode as enabled.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn mode_enabled(at: &Attempt) -> bool {
+        #[cfg(not(feature = "lib"))]
+        if at.env.storage().instance().has(&StorageKey::ModeDisabled(at.mode)) {
+            return at.fail(0, ZkVerifyError::ModeDisabled);
+        }
+        true
+    }
+
+    /// Allowlist gate shared by every public entrypoint. `caller` is the
+    /// authenticated contract for `verify_from` and `None` otherwise, which
+    /// never passes while the allowlist is enabled.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn caller_allowed(at: &Attempt, caller: Option<&Address>) -> bool {
+        #[cfg(not(feature = "lib"))]
+        if at.env.storage().instance().get(&StorageKey::AllowlistEnabled).unwrap_or(false)
+            && !caller.is_some_and(|c| at.env.storage().instance().has(&StorageKey::AllowedCaller(c.clone())))
+        {
+            return at.fail(0, ZkVerifyError::CallerNotAllowed);
+        }
+        true
+    }
+
+    /// Append the network id to `transcript` while network binding is on.
+    /// The `lib` build leaves transcripts unbound.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn bind_network<'a, 'e>(env: &Env, transcript: &'a mut FiatShamir<'e>) -> &'a mut FiatShamir<'e> {
+        #[cfg(not(feature = "lib"))]
+        if env.storage().instance().has(&StorageKey::NetworkBound) {
+            return transcript.digest(&env.ledger().network_id());
+        }
+        transcript
+    }
+
+    /// Store a verification for `consume_verification` to take.
+    fn record(env: &Env, consumer: Address, session_id: u32, player: Address, mode: u32, public_inputs: &Bytes) {
+        let commitment: BytesN<32> = env.crypto().keccak256(public_inputs).into();
+        let key = StorageKey::Verified(consumer.clone(), session_id, player.clone(), mode, commitment);
+        env.storage().temporary().set(&key, &true);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        EvVerificationRecorded { consumer, session_id, player, mode }.publish(env);
+    }
+
+    fn load_upload(env: &Env, session_id: u32, player: &Address) -> Result<ChunkedUpload, ZkVerifyError> {
+        env.storage()
+            .temporary()
+            .get(&StorageKey::Upload(session_id, player.clone()))
+            .ok_or(ZkVerifyError::ChunkedUploadMissing)
+    }
+
+    /// Store `upload` and return the bytes it still lacks.
+    fn save_upload(env: &Env, session_id: u32, player: Address, upload: &ChunkedUpload) -> u32 {
+        let key = StorageKey::Upload(session_id, player);
+        env.storage().temporary().set(&key, upload);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        upload.proof_len - upload.proof.len()
+    }
+
+    /// The UltraHonk verifier registered for `circuit_id`, or the default
+    /// one without a circuit. The `lib` build has none.
+    #[cfg_attr(feature = "lib", allow(unused_variables))]
+    fn ultrahonk_verifier(env: &Env, circuit_id: Option<u32>) -> Option<Address> {
+        #[cfg(not(feature = "lib"))]
+        return match circuit_id {
+            Some(circuit_id) => env.storage().instance().get(&StorageKey::UltraHonkCircuit(circuit_id)),
+            None => env.storage().instance().get(&StorageKey::UltraHonkVerifier),
+        };
+        #[cfg(feature = "lib")]
+        None
+    }
+
+    fn load_admin(env: &Env) -> Result<Address, ZkVerifyError> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(ZkVerifyError::AdminNotSet)
+    }
+
+    /// Equality for 32-byte digests (commitments, nullifiers, responses,
+    /// Merkle roots).
+    ///
+    /// The default build compares the host objects directly. With the
+    /// `ct-compare` feature the bytes are copied out and folded with XOR/OR,
+    /// so the comparison never exits early on the first differing byte.
+    #[cfg(not(feature = "ct-compare"))]
+    fn digest_eq(a: &BytesN<32>, b: &BytesN<32>) -> bool {
+        a == b
+    }
+
+    #[cfg(feature = "ct-compare")]
+    fn digest_eq(a: &BytesN<32>, b: &BytesN<32>) -> bool {
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut diff = 0u8;
+        let mut i = 0usize;
+        while i < 32 {
+            diff |= a[i] ^ b[i];
+            i += 1;
+        }
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Reject the second encodings a proof could otherwise have: a scalar
+    /// `>= r`, which `Fr::from_bytes` would silently reduce, and, unless
+    /// `allow_identity`, the G1 identity. Covers everything `reader` has
+    /// decoded so far, so modes call it after their last read. A failure is
+    /// reported as check `check` of the routine.
+    fn canonical<K>(at: &Attempt, check: u32, reader: &Reader<K>, allow_identity: bool) -> bool {
+        if !reader.scalars_canonical() {
+            return at.fail(check, ZkVerifyError::NonCanonicalScalar);
+        }
+        if !allow_identity && reader.saw_identity() {
+            return at.fail(check, ZkVerifyError::IdentityPoint);
+        }
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 7: Card Play Ring Sigma (1-of-N Schnorr on Pedersen / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Ring Sigma verification for card plays (Mode 7).
+    ///
+    /// Proves that a Pedersen commitment C hides a card_id belonging to a
+    /// known valid set, WITHOUT revealing which card was committed.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// commit_hash(32) || N(4, u32 BE) || valid_set[N](4 each, u32 BE) ||
+    /// session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (96 + N×64 bytes):**
+    /// ```text
+    /// C(96, G1) || [e_i(32, Fr) || z_i(32, Fr)] × N
+    /// ```
+    ///
+    /// C may be compressed, giving 48 + N×64 bytes. The binding hash and the
+    /// challenge are over the uncompressed C either way.
+    ///
+    /// **Verification:**
+    /// 1. Load H = hash_to_g1("PEDERSEN_H", "SGS_CANGKULAN_V1") (cached at construction)
+    /// 2. Verify keccak256(C) == commit_hash (binding check)
+    /// 3. For each i: R_i = z_i·H − e_i·(C − valid_set[i]·G), as one 3-point MSM
+    /// 4. e = Fr(keccak256(C || R_0 || ... || R_{N-1} || session_id || player || "ZKP7"))
+    /// 5. Accept iff Σe_i == e (in Fr)
+    ///
+    /// If bit 31 of `N` is set the proof is routed to the hidden-set ring
+    /// (Mode 12), which shares the proof layout.
+    ///
+    /// If bit 30 (`RING_PADDED_FLAG`) is set the proof always has
+    /// `max_ring_size` members (9 for the default deck), so its length says
+    /// nothing about N. Only the first N are listed; the other slots use the dummy member P_pad in place of card·G, i.e.
+    /// D = C − P_pad. Proving a dummy branch needs C = P_pad + b·H, which
+    /// opens to no card, so the later reveal still fails.
+    pub fn verify_card_play_ring(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        Self::card_play_ring(&Attempt::new(env, MODE_CARD_RING, public_inputs, proof))
+    }
+
+    fn card_play_ring(at: &Attempt) -> bool {
+        let (env, public_inputs, proof) = (at.env, at.public_inputs, at.proof);
+        let inputs = InputsReader::new(env, public_inputs);
+        if inputs.len() >= 36 && inputs.u32_at(32) & HIDDEN_RING_FLAG != 0 {
+            return Self::verify_card_play_ring_hidden(env, public_inputs, proof);
+        }
+        if !at.fresh() {
+            return false;
+        }
+        let Some(hash) = at.transcript_hash() else {
+            return false;
+        };
+
+        let bls = env.crypto().bls12_381();
+        let mut witness = ProofReader::new(env, proof);
+
+        // N = number of valid cards in the ring; C is 96 bytes, or 48 compressed
+        let c_len = witness.g1_len_at(0);
+        if proof.len() < c_len || !(proof.len() - c_len).is_multiple_of(64) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let n = (proof.len() - c_len) / 64;
+        let deck = Self::deck_params(env);
+        if n == 0 || n > deck.max_ring_size {
+            return at.fail(1, ZkVerifyError::RingInvalidSetSize);
+        }
+
+        // A padded ring lists only the first `listed` of its `max_ring_size` members
+        let padded = inputs.len() >= 36 && inputs.u32_at(32) & RING_PADDED_FLAG != 0;
+        let listed = if padded { inputs.u32_at(32) & !RING_PADDED_FLAG } else { n };
+        if padded && (n != deck.max_ring_size || listed == 0 || listed > n) {
+            return at.fail(1, ZkVerifyError::RingInvalidSetSize);
+        }
+
+        // Minimum public_inputs: commit_hash(32) + N(4) + valid_set(4*N) + session_id(4) + player(>=1)
+        let min_inputs = 32 + 4 + 4 * listed + 4 + 1;
+        if public_inputs.len() < min_inputs {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Extract commit_hash [0..32) ─────────────────────────────────────
+        let commit_hash = inputs.digest_at(0);
+
+        // ── Extract N from public_inputs [32..36) and cross-check ───────────
+        let pi_n = inputs.u32_at(32) & !RING_PADDED_FLAG;
+        if pi_n != listed {
+            return at.fail(3, ZkVerifyError::RingInvalidSetSize);
+        }
+
+        let valid_set_offset = 36u32;
+
+        // ── Extract session_id [36+4*N..40+4*N) and player [40+4*N..) ──────
+        let mut tail = InputsReader::at(env, public_inputs, 36 + 4 * listed);
+        let sid_arr: [u8; 4] = tail.array();
+        let player_bytes = tail.rest();
+        if player_bytes.is_empty() {
+            return at.fail(4, ZkVerifyError::EmptyPlayerAddress);
+        }
+
+        // ── Extract C from the front of the proof ───────────────────────────
+        let Some(commitment) = witness.g1_any() else {
+            return at.fail(5, ZkVerifyError::RingPointNotOnCurve);
+        };
+
+        // Subgroup check
+        if !at.check(symbol_short!("subgroup"), bls.g1_is_in_subgroup(&commitment), &commitment.to_bytes()) {
+            return at.fail(5, ZkVerifyError::RingPointNotOnCurve);
+        }
+
+        // Binding check: keccak256(C) == commit_hash
+        let c_raw = commitment.to_bytes();
+        let c_bytes_for_hash = Bytes::from_array(env, &c_raw.to_array());
+        let computed_commit: BytesN<32> = env.crypto().keccak256(&c_bytes_for_hash).into();
+        if !at.check(symbol_short!("binding"), Self::digest_eq(&computed_commit, &commit_hash), &computed_commit) {
+            return at.fail(6, ZkVerifyError::CommitmentMismatch);
+        }
+
+        // ── G1 generator (same as Mode 4) ──────────────────────────────────
+        let g = Self::g1_generator(env);
+
+        // ── H generator (same nothing-up-my-sleeve derivation) ─────────────
+        let h = at.pedersen_h(&bls);
+        let pad = if padded { Some(at.ring_pad_point(&bls)) } else { None };
+
+        // ── Process each ring member: reconstruct R_i ──────────────────────
+        // Also build Fiat-Shamir challenge preimage: C || R_0 || ... || R_{N-1} || session_id || player || [network_id] || "ZKP7"
+        let mut transcript = FiatShamir::using(env, hash);
+        transcript.g1(&commitment);
+
+        // R_i = z_i·H − e_i·D_i with D_i = C − card_i·G expands to
+        // z_i·H + e_i·(−C) + (e_i·card_i)·G: one 3-point MSM per member.
+        // Each R_i is hashed into the challenge, so they cannot be merged further.
+        let neg_c = -commitment.clone();
+        let mut e_sum = Self::fr_from_u32(env, 0);
+
+        let mut idx = 0u32;
+        while idx < n {
+            // e_i || z_i from proof [|C| + idx*64 .. |C| + idx*64 + 64)
+            let e_i = witness.fr();
+            let z_i = witness.fr();
+
+            // Extract valid_set[idx] and convert to Fr; a padding slot
+            // swaps card·G for P_pad
+            let (member, weight) = match &pad {
+                Some(p_pad) if idx >= listed => (p_pad.clone(), e_i.clone()),
+                _ => {
+                    let card_val = inputs.u32_at(valid_set_offset + idx * 4);
+                    (g.clone(), e_i.clone() * Self::fr_from_u32(env, card_val))
+                }
+            };
+
+            let r_i = bls.g1_msm(
+                Vec::from_array(env, [h.clone(), neg_c.clone(), member]),
+                Vec::from_array(env, [z_i, e_i.clone(), weight]),
+            );
+
+            // Append R_i to challenge preimage
+            transcript.g1(&r_i);
+            e_sum = e_sum + e_i;
+
+            idx += 1;
+        }
+
+        // ── Fiat-Shamir challenge ───────────────────────────────────────────
+        transcript.array(&sid_arr).bytes(&player_bytes);
+        let expected_e = at.challenge(&mut transcript, &RING_CHALLENGE_TAG);
+
+        // ── Check Σ e_i == expected_e in Fr ─────────────────────────────────
+        if !at.check(symbol_short!("challenge"), e_sum == expected_e, &expected_e.to_bytes()) {
+            return at.fail(7, ZkVerifyError::RingChallengeCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(at, 8, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_CARD_RING }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 12: Hidden-Set Card Play Ring (1-of-N over hand commitments)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Hidden-set ring verification for card plays (Mode 12).
+    ///
+    /// Mode 7 publishes the valid set as plaintext card ids, so the opponent
+    /// learns every suit-matching card in the hand. Here the ring is built
+    /// over the player's own hand commitments P_i = card_i·G + r_i·H instead:
+    /// the proof shows C commits to the same card as one of the P_i, i.e.
+    /// C − P_i = (r − r_i)·H for some i. The caller (the game contract)
+    /// selects the P_i whose suit matches the trick; only N is revealed.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// commit_hash(32) || (N | 0x80000000)(4, u32 BE) || P[N](96 each, G1) ||
+    /// session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (96 + N×64 bytes, same as Mode 7):**
+    /// ```text
+    /// C(96, G1) || [e_i(32, Fr) || z_i(32, Fr)] × N
+    /// ```
+    ///
+    /// As in Mode 7, C may be compressed (48 + N×64 bytes).
+    ///
+    /// **Verification:**
+    /// 1. keccak256(C) == commit_hash; C and every P_i in the G1 subgroup
+    /// 2. For each i: D_i = C − P_i, R_i = z_i·H − e_i·D_i
+    /// 3. e = Fr(keccak256(C || P_0..P_{N-1} || R_0..R_{N-1} || session_id || player || "ZKPB"))
+    /// 4. Accept iff Σe_i == e
+    ///
+    /// With `RING_PADDED_FLAG` the proof has `max_ring_size` members and the
+    /// unlisted slots take P_pad as their P_i, exactly as in Mode 7.
+    pub fn verify_card_play_ring_hidden(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_HIDDEN_RING, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let Some(hash) = at.transcript_hash() else {
+            return false;
+        };
+        let bls = env.crypto().bls12_381();
+
+        let inputs = InputsReader::new(env, public_inputs);
+        let mut witness = ProofReader::new(env, proof);
+        let c_len = witness.g1_len_at(0);
+        if proof.len() < c_len || !(proof.len() - c_len).is_multiple_of(64) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let n = (proof.len() - c_len) / 64;
+        let padded = inputs.u32_at(32) & RING_PADDED_FLAG != 0;
+        let listed = inputs.u32_at(32) & !(HIDDEN_RING_FLAG | RING_PADDED_FLAG);
+        let max_ring_size = Self::deck_params(env).max_ring_size;
+        let members = if padded { max_ring_size } else { listed };
+        if n == 0 || n > max_ring_size || n != members || listed == 0 || listed > n {
+            return at.fail(1, ZkVerifyError::RingInvalidSetSize);
+        }
+
+        // commit_hash(32) + N(4) + P(96*N) + session_id(4) + player(>=1)
+        let sid_offset = 36 + 96 * listed;
+        if public_inputs.len() < sid_offset + 4 + 1 {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Commitment and binding check ────────────────────────────────────
+        let Some(commitment) = witness.g1_any() else {
+            return at.fail(3, ZkVerifyError::RingPointNotOnCurve);
+        };
+        if !bls.g1_is_in_subgroup(&commitment) {
+            return at.fail(3, ZkVerifyError::RingPointNotOnCurve);
+        }
+        let c_bytes = Bytes::from_array(env, &commitment.to_bytes().to_array());
+        let computed_commit: BytesN<32> = env.crypto().keccak256(&c_bytes).into();
+        if !Self::digest_eq(&computed_commit, &inputs.digest_at(0)) {
+            return at.fail(4, ZkVerifyError::CommitmentMismatch);
+        }
+
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Ring over D_i = C − P_i ─────────────────────────────────────────
+        let mut transcript = FiatShamir::using(env, hash);
+        transcript.bytes(&c_bytes).bytes(&inputs.slice(36..sid_offset));
+        let mut e_sum = zero.clone();
+
+        let pad = if padded { Some(Self::ring_pad_point(&bls, env)) } else { None };
+        let mut idx = 0u32;
+        while idx < n {
+            let p_i = match &pad {
+                Some(p_pad) if idx >= listed => p_pad.clone(),
+                _ => {
+                    let p_i = inputs.g1_at(36 + idx * 96);
+                    if !bls.g1_is_in_subgroup(&p_i) {
+                        return at.fail(5, ZkVerifyError::RingPointNotOnCurve);
+                    }
+                    p_i
+                }
+            };
+            let e_i = witness.fr();
+            let z_i = witness.fr();
+
+            let d_i = bls.g1_add(&commitment, &-p_i);
+            let r_i = bls.g1_msm(
+                Vec::from_array(env, [h.clone(), d_i]),
+                Vec::from_array(env, [z_i, zero.clone() - e_i.clone()]),
+            );
+            transcript.g1(&r_i);
+            e_sum = e_sum + e_i;
+            idx += 1;
+        }
+
+        // ── Fiat-Shamir challenge ───────────────────────────────────────────
+        let e = transcript
+            .bytes(&inputs.slice(sid_offset..inputs.len()))
+            .challenge(&HIDDEN_RING_CHALLENGE_TAG);
+
+        if e_sum != e {
+            return at.fail(6, ZkVerifyError::RingChallengeCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 7, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_HIDDEN_RING }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 8: Cangkul Hand Proof (Aggregate Pedersen + Schnorr + Suit Exclusion)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Length a Mode 8 proof must have, given how its A and R are encoded.
+    fn cangkul_hand_proof_len(witness: &ProofReader) -> u32 {
+        let a_len = witness.g1_len_at(4);
+        4 + a_len + witness.g1_len_at(4 + a_len) + 32
+    }
+
+    /// Cangkul hand binding verification (Mode 8).
+    ///
+    /// Proves that a player's hand contains NO cards matching the trick suit,
+    /// using an aggregate Pedersen commitment over the entire hand with a
+    /// Schnorr proof of knowledge of the aggregate blinding factor.
+    ///
+    /// **Security properties:**
+    /// - **Binding**: keccak256(A) == commit_hash ties the proof to the commit.
+    /// - **Knowledge**: Schnorr sigma proves the prover knows r_agg such that
+    ///   A = (Σ card_i)·G + r_agg·H.
+    /// - **Suit exclusion**: Every card in the public set is checked against
+    ///   trick_suit on-chain — no card with matching suit passes.
+    /// - **Session-bound**: Fiat-Shamir challenge includes session_id and player,
+    ///   preventing cross-session replay.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// commit_hash(32) || trick_suit(4, u32 BE) || k(4, u32 BE) ||
+    /// card_1(4) || ... || card_k(4) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (228 bytes):**
+    /// ```text
+    /// k(4, u32 BE) || A(96, G1) || R(96, G1) || z(32, Fr)
+    /// ```
+    ///
+    /// A and R may each be compressed, for 180 or 132 bytes. The binding
+    /// hash and the challenge are over the uncompressed points either way.
+    ///
+    /// **Verification:**
+    /// 1. Cross-check k in proof vs public_inputs
+    /// 2. Binding: keccak256(A) == commit_hash
+    /// 3. Suit exclusion: for each card_i, verify card_i / cards_per_suit ≠ trick_suit
+    /// 4. Compute expected_sum = Σ(card_i · G) via g1_msm
+    /// 5. delta = A − expected_sum (should be r_agg · H)
+    /// 6. e = Fr(keccak256(A || R || trick_suit || k || session_id || player || "ZKP8"))
+    /// 7. Schnorr check: z · H == R + e · delta
+    pub fn verify_cangkul_hand(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        Self::cangkul_hand(&Attempt::new(env, MODE_CANGKUL_HAND, public_inputs, proof))
+    }
+
+    fn cangkul_hand(at: &Attempt) -> bool {
+        let (env, public_inputs, proof) = (at.env, at.public_inputs, at.proof);
+        if !at.fresh() {
+            return false;
+        }
+        let Some(hash) = at.transcript_hash() else {
+            return false;
+        };
+        let bls = env.crypto().bls12_381();
+
+        // ── Extract k from proof [0..4) ─────────────────────────────────────
+        let mut witness = ProofReader::new(env, proof);
+        if proof.len() != Self::cangkul_hand_proof_len(&witness) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let k = witness.u32();
+        let deck = Self::deck_params(env);
+        if k == 0 || k > deck.max_hand_size {
+            return at.fail(1, ZkVerifyError::HandCardCountMismatch);
+        }
+
+        // Minimum public_inputs: commit_hash(32) + trick_suit(4) + k(4) + cards(4*k) + session_id(4) + player(>=1)
+        let min_inputs = 32 + 4 + 4 + 4 * k + 4 + 1;
+        if public_inputs.len() < min_inputs {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Extract commit_hash [0..32) ─────────────────────────────────────
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let commit_hash = inputs.digest();
+
+        // ── Extract trick_suit [32..36) ─────────────────────────────────────
+        let trick_suit = inputs.u32();
+        if trick_suit >= deck.suits() {
+            return at.fail(3, ZkVerifyError::HandSuitViolation);
+        }
+
+        // ── Cross-check k from public_inputs [36..40) ──────────────────────
+        let k_pi = inputs.u32();
+        if k_pi != k {
+            return at.fail(4, ZkVerifyError::HandCardCountMismatch);
+        }
+
+        // ── Extract card values, verify suit exclusion, build MSM vectors ──
+        let g = Self::g1_generator(env);
+
+        let mut g_vec: Vec<G1Affine> = Vec::new(env);
+        let mut fr_vec: Vec<Fr> = Vec::new(env);
+
+        let mut idx = 0u32;
+        while idx < k {
+            let card_val = inputs.u32();
+
+            // Card range check
+            if card_val >= deck.deck_size {
+                return at.fail(5, ZkVerifyError::HandSuitViolation);
+            }
+
+            // Suit exclusion: floor(card / cards_per_suit) must NOT equal trick_suit
+            if deck.suit_of(card_val) == trick_suit {
+                return at.fail(6, ZkVerifyError::HandSuitViolation);
+            }
+
+            g_vec.push_back(g.clone());
+            fr_vec.push_back(Self::fr_from_u32(env, card_val));
+            idx += 1;
+        }
+
+        // ── Extract session_id [40+4k..44+4k) and player [44+4k..) ────────
+        let sid_arr: [u8; 4] = inputs.array();
+        let player_bytes = inputs.rest();
+        if player_bytes.is_empty() {
+            return at.fail(7, ZkVerifyError::EmptyPlayerAddress);
+        }
+
+        // ── Extract proof fields ────────────────────────────────────────────
+        // A (aggregate commitment) follows k
+        let Some(agg_commit) = witness.g1_any() else {
+            return at.fail(8, ZkVerifyError::RingPointNotOnCurve);
+        };
+        if !at.check(symbol_short!("subgroup"), bls.g1_is_in_subgroup(&agg_commit), &agg_commit.to_bytes()) {
+            return at.fail(8, ZkVerifyError::RingPointNotOnCurve);
+        }
+
+        // R (Schnorr nonce) follows A
+        let Some(nonce_r) = witness.g1_any() else {
+            return at.fail(9, ZkVerifyError::RingPointNotOnCurve);
+        };
+        if !at.check(symbol_short!("subgroup"), bls.g1_is_in_subgroup(&nonce_r), &nonce_r.to_bytes()) {
+            return at.fail(9, ZkVerifyError::RingPointNotOnCurve);
+        }
+
+        // z (Schnorr response) closes the proof
+        let z = witness.fr();
+
+        // ── Binding check: keccak256(A) == commit_hash ──────────────────────
+        let a_raw = agg_commit.to_bytes();
+        let a_bytes_for_hash = Bytes::from_array(env, &a_raw.to_array());
+        let computed_commit: BytesN<32> = env.crypto().keccak256(&a_bytes_for_hash).into();
+        if !at.check(symbol_short!("binding"), Self::digest_eq(&computed_commit, &commit_hash), &computed_commit) {
+            return at.fail(10, ZkVerifyError::CommitmentMismatch);
+        }
+
+        // ── Compute expected_sum = Σ(card_i · G) via MSM ────────────────────
+        let expected_sum = bls.g1_msm(g_vec, fr_vec);
+
+        // ── delta = A − expected_sum (should be r_agg · H) ─────────────────
+        let neg_expected = -expected_sum;
+        let delta = bls.g1_add(&agg_commit, &neg_expected);
+
+        // ── H generator ─────────────────────────────────────────────────────
+        let h = at.pedersen_h(&bls);
+
+        // ── Fiat-Shamir challenge ───────────────────────────────────────────
+        // e = Fr(keccak256(A || R || trick_suit(4) || k(4) || session_id(4) || player || [network_id] || "ZKP8"))
+        let mut transcript = FiatShamir::using(env, hash);
+        transcript
+            .g1(&agg_commit)
+            .g1(&nonce_r)
+            .u32(trick_suit)
+            .u32(k)
+            .array(&sid_arr)
+            .bytes(&player_bytes);
+        let e = at.challenge(&mut transcript, &CANGKUL_CHALLENGE_TAG);
+        at.check(symbol_short!("challenge"), true, &e.to_bytes());
+
+        // ── Schnorr verification: z · H == R + e · delta ────────────────────
+        let z_h = bls.g1_mul(&h, &z);
+        let e_delta = bls.g1_mul(&delta, &e);
+        let r_plus_e_delta = bls.g1_add(&nonce_r, &e_delta);
+
+        if !at.check(symbol_short!("schnorr"), z_h.to_bytes() == r_plus_e_delta.to_bytes(), &z_h.to_bytes()) {
+            return at.fail(11, ZkVerifyError::HandSchnorrCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(at, 12, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_CANGKUL_HAND }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 9: PLONK (KZG polynomial commitments on BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Structural checks on a PLONK verification key:
+    /// - length is exactly `PLONK_VK_LEN`
+    /// - `n` is a power of two ≥ 2 and `n_public ≤ n`
+    /// - `ω` is a primitive n-th root of unity
+    ///
+    /// Curve points are validated by the host when they enter the MSM and
+    /// pairing, so a corrupt key traps instead of verifying anything.
+    fn is_valid_plonk_vk(env: &Env, vk: &Bytes) -> bool {
+        if vk.len() != PLONK_VK_LEN {
+            return false;
+        }
+        let key = VkReader::new(env, vk);
+        let n = key.u32_at(0);
+        let n_public = key.u32_at(4);
+        if n < 2 || !n.is_power_of_two() || n_public > n {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+        let one = Self::fr_from_u32(env, 1);
+        let omega = key.fr_at(PLONK_VK_OMEGA_OFFSET);
+        bls.fr_pow(&omega, n as u64) == one && bls.fr_pow(&omega, (n / 2) as u64) != one
+    }
+
+    /// Lift a small integer into Fr.
+    fn fr_from_u32(env: &Env, value: u32) -> Fr {
+        Fr::from_u256(U256::from_u32(env, value))
+    }
+
+    /// PLONK verification with KZG commitments (Mode 9).
+    ///
+    /// Checks a proof for a circuit whose verification key was registered with
+    /// `register_vk`. The verifier follows the standard (linearised)
+    /// PLONK verifier; the transcript is `fs_digest` (keccak256 by default) with the "ZKP9" tag.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// circuit_id(4, u32 BE) || n_public(4, u32 BE) || public_1(32) || ... || public_n(32)
+    /// ```
+    ///
+    /// **Proof layout (1056 bytes):**
+    /// ```text
+    /// A || B || C || Z || T1 || T2 || T3 || Wξ || Wξω  (9×96, G1)
+    /// ā || b̄ || c̄ || s̄1 || s̄2 || z̄ω                   (6×32, Fr)
+    /// ```
+    ///
+    /// **Transcript:**
+    /// ```text
+    /// β = H(Qm..S3 || publics || A || B || C)
+    /// γ = H(β)
+    /// α = H(β || γ || Z)
+    /// ξ = H(α || T1 || T2 || T3)
+    /// v = H(ξ || ā || b̄ || c̄ || s̄1 || s̄2 || z̄ω)
+    /// u = H(ξ || v || Wξ || Wξω)
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. Z_H(ξ) = ξⁿ − 1, L_i(ξ) = ωⁱ(ξⁿ − 1) / (n(ξ − ωⁱ)), PI(ξ) = −Σ pub_i·L_i(ξ)
+    /// 2. r0 = PI(ξ) − L_0(ξ)α² − α(ā + βs̄1 + γ)(b̄ + βs̄2 + γ)(c̄ + γ)z̄ω
+    /// 3. D = āb̄[Qm] + ā[Ql] + b̄[Qr] + c̄[Qo] + [Qc]
+    ///      + ((ā + βξ + γ)(b̄ + βk1ξ + γ)(c̄ + βk2ξ + γ)α + L_0(ξ)α² + u)[Z]
+    ///      − (ā + βs̄1 + γ)(b̄ + βs̄2 + γ)αβz̄ω[S3]
+    ///      − Z_H(ξ)([T1] + ξⁿ[T2] + ξ²ⁿ[T3])
+    /// 4. F = D + v[A] + v²[B] + v³[C] + v⁴[S1] + v⁵[S2]
+    /// 5. E = (−r0 + vā + v²b̄ + v³c̄ + v⁴s̄1 + v⁵s̄2 + uz̄ω)[1]
+    /// 6. Accept iff e(−(Wξ + uWξω), X_2) · e(ξWξ + uξωWξω + F − E, [1]₂) == 1
+    /// ```
+    fn verify_plonk(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_PLONK, public_inputs, proof);
+        let bls = env.crypto().bls12_381();
+
+        // ── Minimum public_inputs: circuit_id(4) + n_public(4) ──────────────
+        if public_inputs.len() < 8 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Resolve the verification key ────────────────────────────────────
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let circuit_id = inputs.u32();
+        let vk: Bytes = match env.storage().instance().get(&StorageKey::Vk(circuit_id)) {
+            Some(vk) => vk,
+            None => {
+                return at.fail(2, ZkVerifyError::VkNotFound);
+            }
+        };
+        if !Self::is_valid_plonk_vk(env, &vk) {
+            return at.fail(3, ZkVerifyError::PlonkInvalidVk);
+        }
+
+        let mut key = VkReader::new(env, &vk);
+        let n = key.u32_at(0);
+        let n_public = key.u32_at(4);
+
+        // ── Public input count must match the circuit ───────────────────────
+        let pi_count = inputs.u32();
+        if pi_count != n_public || public_inputs.len() != 8 + 32 * n_public {
+            return at.fail(4, ZkVerifyError::PlonkInputCountMismatch);
+        }
+
+        // ── Extract proof commitments and check subgroup membership ─────────
+        let mut witness = ProofReader::new(env, proof);
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut idx = 0u32;
+        while idx < 9 {
+            let point = witness.g1();
+            if !bls.g1_is_in_subgroup(&point) {
+                return at.fail(5, ZkVerifyError::PlonkPointNotInSubgroup);
+            }
+            points.push_back(point);
+            idx += 1;
+        }
+        let a_cm = points.get_unchecked(0);
+        let b_cm = points.get_unchecked(1);
+        let c_cm = points.get_unchecked(2);
+        let z_cm = points.get_unchecked(3);
+        let t1_cm = points.get_unchecked(4);
+        let t2_cm = points.get_unchecked(5);
+        let t3_cm = points.get_unchecked(6);
+        let w_xi = points.get_unchecked(7);
+        let w_xiw = points.get_unchecked(8);
+
+        // ── Extract evaluations ─────────────────────────────────────────────
+        let evals_offset = 9 * 96;
+        let eval_a = witness.fr();
+        let eval_b = witness.fr();
+        let eval_c = witness.fr();
+        let eval_s1 = witness.fr();
+        let eval_s2 = witness.fr();
+        let eval_zw = witness.fr();
+
+        // ── Verification key fields ─────────────────────────────────────────
+        let k1 = key.fr_at(PLONK_VK_K1_OFFSET);
+        let k2 = key.fr_at(PLONK_VK_K2_OFFSET);
+        let omega = key.fr_at(PLONK_VK_OMEGA_OFFSET);
+        key.seek(PLONK_VK_POINTS_OFFSET); // Qm || Ql || Qr || Qo || Qc || S1 || S2 || S3 || X2
+        let qm = key.g1();
+        let ql = key.g1();
+        let qr = key.g1();
+        let qo = key.g1();
+        let qc = key.g1();
+        let s1 = key.g1();
+        let s2 = key.g1();
+        let s3 = key.g1();
+        let x2 = key.g2();
+
+        // ── Fiat-Shamir transcript ──────────────────────────────────────────
+        let beta = FiatShamir::new(env)
+            .bytes(&key.slice(PLONK_VK_POINTS_OFFSET..PLONK_VK_X2_OFFSET))
+            .bytes(&inputs.rest())
+            .bytes(&witness.slice(0..3 * 96))
+            .challenge(&PLONK_CHALLENGE_TAG);
+        let gamma = FiatShamir::new(env).fr(&beta).challenge(&PLONK_CHALLENGE_TAG);
+        let alpha = FiatShamir::new(env)
+            .fr(&beta)
+            .fr(&gamma)
+            .bytes(&witness.slice(3 * 96..4 * 96))
+            .challenge(&PLONK_CHALLENGE_TAG);
+        let xi = FiatShamir::new(env)
+            .fr(&alpha)
+            .bytes(&witness.slice(4 * 96..7 * 96))
+            .challenge(&PLONK_CHALLENGE_TAG);
+        let v = FiatShamir::new(env)
+            .fr(&xi)
+            .bytes(&witness.slice(evals_offset..PLONK_PROOF_LEN))
+            .challenge(&PLONK_CHALLENGE_TAG);
+        let u = FiatShamir::new(env)
+            .fr(&xi)
+            .fr(&v)
+            .bytes(&witness.slice(7 * 96..9 * 96))
+            .challenge(&PLONK_CHALLENGE_TAG);
+
+        // ── Vanishing polynomial and Lagrange evaluations ───────────────────
+        let zero = Self::fr_from_u32(env, 0);
+        let one = Self::fr_from_u32(env, 1);
+        let xi_n = bls.fr_pow(&xi, n as u64);
+        let zh = xi_n.clone() - one.clone();
+        if zh == zero {
+            // ξ landed on the evaluation domain; Lagrange bases are undefined.
+            return at.fail(6, ZkVerifyError::PlonkPairingFailed);
+        }
+        let n_fr = Self::fr_from_u32(env, n);
+
+        // L_i(ξ) = ωⁱ·Z_H(ξ) / (n·(ξ − ωⁱ)); L_0 is always needed, the rest for PI.
+        let l_count = if n_public == 0 { 1 } else { n_public };
+        let mut lagrange: Vec<Fr> = Vec::new(env);
+        let mut w_i = one.clone();
+        idx = 0;
+        while idx < l_count {
+            let denom = n_fr.clone() * (xi.clone() - w_i.clone());
+            let l_i = w_i.clone() * zh.clone() * bls.fr_inv(&denom);
+            lagrange.push_back(l_i);
+            w_i = w_i * omega.clone();
+            idx += 1;
+        }
+        let l0 = lagrange.get_unchecked(0);
+
+        // PI(ξ) = −Σ pub_i·L_i(ξ)
+        let mut pi = zero.clone();
+        idx = 0;
+        while idx < n_public {
+            let pub_i = inputs.fr_at(8 + idx * 32);
+            pi = pi - pub_i * lagrange.get_unchecked(idx);
+            idx += 1;
+        }
+
+        // ── r0 (constant part of the linearisation polynomial) ──────────────
+        let alpha2 = alpha.clone() * alpha.clone();
+        let e1 = eval_a.clone() + beta.clone() * eval_s1.clone() + gamma.clone();
+        let e2 = eval_b.clone() + beta.clone() * eval_s2.clone() + gamma.clone();
+        let e3 = eval_c.clone() + gamma.clone();
+        let r0 = pi
+            - l0.clone() * alpha2.clone()
+            - alpha.clone() * e1.clone() * e2.clone() * e3 * eval_zw.clone();
+
+        // ── D (linearisation commitment) ────────────────────────────────────
+        let beta_xi = beta.clone() * xi.clone();
+        let z_coeff = (eval_a.clone() + beta_xi.clone() + gamma.clone())
+            * (eval_b.clone() + beta_xi.clone() * k1 + gamma.clone())
+            * (eval_c.clone() + beta_xi * k2 + gamma.clone())
+            * alpha.clone()
+            + l0 * alpha2
+            + u.clone();
+        let s3_coeff = e1 * e2 * alpha * beta * eval_zw.clone();
+        let xi_2n = xi_n.clone() * xi_n.clone();
+
+        // ── F = D + v[A] + v²[B] + v³[C] + v⁴[S1] + v⁵[S2] ─────────────────
+        let v2 = v.clone() * v.clone();
+        let v3 = v2.clone() * v.clone();
+        let v4 = v3.clone() * v.clone();
+        let v5 = v4.clone() * v.clone();
+
+        // ── E = (−r0 + vā + v²b̄ + v³c̄ + v⁴s̄1 + v⁵s̄2 + uz̄ω)·[1] ────────────
+        let e_scalar = zero.clone() - r0
+            + v.clone() * eval_a.clone()
+            + v2.clone() * eval_b.clone()
+            + v3.clone() * eval_c.clone()
+            + v4.clone() * eval_s1
+            + v5.clone() * eval_s2
+            + u.clone() * eval_zw;
+
+        // ── Pairing operands ────────────────────────────────────────────────
+        // P1 = Wξ + u·Wξω
+        // P2 = ξ·Wξ + u·ξ·ω·Wξω + F − E
+        let g = Self::g1_generator(env);
+
+        let p1 = bls.g1_msm(
+            Vec::from_array(env, [w_xi.clone(), w_xiw.clone()]),
+            Vec::from_array(env, [one, u.clone()]),
+        );
+
+        let ab = eval_a.clone() * eval_b.clone();
+        let neg_zh = zero.clone() - zh;
+        let p2 = bls.g1_msm(
+            Vec::from_array(env, [
+                w_xi, w_xiw,
+                qm, ql, qr, qo, qc,
+                z_cm, s3,
+                t1_cm, t2_cm, t3_cm,
+                a_cm, b_cm, c_cm, s1, s2,
+                g,
+            ]),
+            Vec::from_array(env, [
+                xi.clone(),
+                u * xi * omega,
+                ab,
+                eval_a,
+                eval_b,
+                eval_c,
+                Self::fr_from_u32(env, 1),
+                z_coeff,
+                zero.clone() - s3_coeff,
+                neg_zh.clone(),
+                neg_zh.clone() * xi_n,
+                neg_zh * xi_2n,
+                v,
+                v2,
+                v3,
+                v4,
+                v5,
+                zero - e_scalar,
+            ]),
+        );
+
+        let g2 = G2Affine::from_array(env, &G2_GENERATOR);
+        let valid = bls.pairing_check(
+            Vec::from_array(env, [-p1, p2]),
+            Vec::from_array(env, [x2, g2]),
+        );
+        if !valid {
+            return at.fail(7, ZkVerifyError::PlonkPairingFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 8, &inputs, true) || !Self::canonical(&at, 9, &witness, true) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_PLONK }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 10: Range Proof (bit decomposition + OR-proofs on Pedersen / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Range proof verification for committed card values (Mode 10).
+    ///
+    /// Proves that a Pedersen commitment C = v·G + r·H hides a value
+    /// v ∈ [lo, hi] WITHOUT revealing v. With w = hi − lo and m the bit length
+    /// of w, the prover shows two values are m-bit numbers:
+    ///
+    /// ```text
+    /// x1 = v − lo                    (C1 = C − lo·G)
+    /// x2 = v − lo + (2^m − 1 − w)    (C2 = C1 + (2^m − 1 − w)·G)
+    /// ```
+    ///
+    /// x1 ≥ 0 gives the lower bound and x2 < 2^m gives the upper bound.
+    /// Each is decomposed into bit commitments B_i = b_i·G + s_i·H with
+    /// Σ 2^i·B_i == C_j, and every B_i carries a CDS OR-proof that it
+    /// commits to 0 or 1.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// C(96, G1) || lo(4, u32 BE) || hi(4, u32 BE) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (2 × m × 384 bytes, m ∈ [1, 8]):**
+    /// ```text
+    /// [B_i || R0_i || R1_i (3×96, G1) || e0_i || z0_i || z1_i (3×32, Fr)] × m   (x1 bits, LSB first)
+    /// [B_i || R0_i || R1_i (3×96, G1) || e0_i || z0_i || z1_i (3×32, Fr)] × m   (x2 bits, LSB first)
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. Bounds: lo ≤ hi, hi − lo < 256, m matches the proof length
+    /// 2. e   = Fr(keccak256(C || lo || hi || [B_i || R0_i || R1_i]... || session_id || player || "ZKPA"))
+    ///    e1_i = e − e0_i
+    /// 3. ρ   = Fr(keccak256(e || proof || "ZKPA")), one power of ρ per equation
+    /// 4. One g1_msm over [H, G, C, B.., R0.., R1..] must be the identity:
+    ///      z0_i·H == R0_i + e0_i·B_i              (bit is 0)
+    ///      z1_i·H == R1_i + e1_i·(B_i − G)        (bit is 1)
+    ///      Σ 2^i·B_i == C_j                        (per decomposition)
+    /// ```
+    ///
+    /// Only C is subgroup-checked. Every other point enters solely through the
+    /// batched equation, so an accepted proof also holds for the projections
+    /// onto the order-r subgroup, which is all the statement about C needs.
+    /// Batching keeps a 6-bit proof (card ids 0..35) inside the default budget.
+    pub fn verify_range(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_RANGE, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        // ── Minimum public_inputs: C(96) + lo(4) + hi(4) + session_id(4) + player(>=1)
+        if public_inputs.len() < 109 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Bounds and bit length ───────────────────────────────────────────
+        let inputs = InputsReader::new(env, public_inputs);
+        let lo = inputs.u32_at(96);
+        let hi = inputs.u32_at(100);
+        if hi < lo || hi - lo > RANGE_MAX_WIDTH {
+            return at.fail(2, ZkVerifyError::RangeInvalidBounds);
+        }
+        let width = hi - lo;
+        let m = Self::range_bits(width);
+        if proof.len() != 2 * m * RANGE_BIT_PROOF_LEN {
+            return at.fail(3, ZkVerifyError::RangeInvalidBounds);
+        }
+        let shift = (1u32 << m) - 1 - width;
+
+        // ── Commitment ──────────────────────────────────────────────────────
+        let commitment = inputs.g1_at(0);
+        if !bls.g1_is_in_subgroup(&commitment) {
+            return at.fail(4, ZkVerifyError::RangePointNotInSubgroup);
+        }
+
+        // ── Fiat-Shamir challenge over C, bounds and every first move ───────
+        let witness = ProofReader::new(env, proof);
+        let mut transcript = FiatShamir::new(env);
+        transcript.bytes(&inputs.slice(0..104));
+        let mut bit = 0u32;
+        while bit < 2 * m {
+            let offset = bit * RANGE_BIT_PROOF_LEN;
+            transcript.bytes(&witness.slice(offset..offset + 288));
+            bit += 1;
+        }
+        transcript.bytes(&inputs.slice(104..inputs.len()));
+        let e_hash = transcript.challenge_digest(&RANGE_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        // ── Batching weight ρ, bound to the whole proof ─────────────────────
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&RANGE_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Accumulate the batched equation ─────────────────────────────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut coef_c = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        let mut j = 0u32;
+        while j < 2 {
+            // −ρ_j·C_j = −ρ_j·C + ρ_j·lo·G              (j = 0)
+            //          = −ρ_j·C + ρ_j·(lo − shift)·G     (j = 1)
+            let rho_j = weight.clone();
+            weight = weight * rho.clone();
+            coef_c = coef_c - rho_j.clone();
+            let g_offset = if j == 0 {
+                Self::fr_from_u32(env, lo)
+            } else {
+                Self::fr_from_u32(env, lo) - Self::fr_from_u32(env, shift)
+            };
+            coef_g = coef_g + rho_j.clone() * g_offset;
+
+            let mut i = 0u32;
+            while i < m {
+                let mut bit_proof = ProofReader::at(env, proof, (j * m + i) * RANGE_BIT_PROOF_LEN);
+                let b_i = bit_proof.g1();
+                let r0 = bit_proof.g1();
+                let r1 = bit_proof.g1();
+                let e0 = bit_proof.fr();
+                let z0 = bit_proof.fr();
+                let z1 = bit_proof.fr();
+                if !Self::canonical(&at, 5, &bit_proof, false) {
+                    return false;
+                }
+                let e1 = e.clone() - e0.clone();
+
+                let t0 = weight.clone();
+                let t1 = t0.clone() * rho.clone();
+                weight = t1.clone() * rho.clone();
+
+                // t0·(z0·H − R0 − e0·B) + t1·(z1·H − R1 − e1·B + e1·G) + ρ_j·2^i·B
+                coef_h = coef_h + t0.clone() * z0 + t1.clone() * z1;
+                coef_g = coef_g + t1.clone() * e1.clone();
+                let coef_b = rho_j.clone() * Self::fr_from_u32(env, 1u32 << i)
+                    - t0.clone() * e0
+                    - t1.clone() * e1;
+
+                points.push_back(b_i);
+                scalars.push_back(coef_b);
+                points.push_back(r0);
+                scalars.push_back(zero.clone() - t0);
+                points.push_back(r1);
+                scalars.push_back(zero.clone() - t1);
+                i += 1;
+            }
+            j += 1;
+        }
+
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+        points.push_back(commitment);
+        scalars.push_back(coef_c);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(6, ZkVerifyError::RangeCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 7, &inputs, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_RANGE }.publish(env);
+        true
+    }
+
+    /// Number of bits m needed to represent `width` (at least 1).
+    fn range_bits(width: u32) -> u32 {
+        if width == 0 {
+            1
+        } else {
+            32 - width.leading_zeros()
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 11: Merkle Membership (hand commitment tree, keccak256)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Merkle membership verification for hand commitments (Mode 11).
+    ///
+    /// At deal time a player publishes the root of a tree whose leaves are
+    /// their hand's Pedersen commitments, each tagged with the card's suit.
+    /// To play, the player shows the committed card C is one of those leaves
+    /// under the trick suit. The opponent learns the suit and the leaf
+    /// position, never the other cards — unlike Mode 7, no plaintext valid
+    /// set is published.
+    ///
+    /// **Public inputs layout (132 bytes):**
+    /// ```text
+    /// root(32) || suit(4, u32 BE) || C(96, G1)
+    /// ```
+    ///
+    /// **Proof layout (4 + d × 32 bytes, d ∈ [1, 6]):**
+    /// ```text
+    /// index(4, u32 BE) || sibling_0(32) || ... || sibling_{d-1}(32)   (leaf level first)
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// leaf = keccak256(0x00 || suit || C)
+    /// node = keccak256(0x01 || left || right), left/right chosen by bit i of index
+    /// accept iff index < 2^d and the final node == root
+    /// ```
+    pub fn verify_merkle_membership(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_MERKLE, public_inputs, proof);
+        if public_inputs.len() != MERKLE_INPUTS_LEN {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        let mut witness = ProofReader::new(env, proof);
+        let depth = (proof.len() - 4) / 32;
+        let index = witness.u32();
+        if depth == 0 || depth > MERKLE_MAX_DEPTH || index >> depth != 0 {
+            return at.fail(2, ZkVerifyError::MerkleInvalidDepth);
+        }
+
+        // ── Leaf: suit-tagged commitment ────────────────────────────────────
+        let mut leaf_preimage = Bytes::from_array(env, &[MERKLE_LEAF_PREFIX]);
+        leaf_preimage.append(&public_inputs.slice(32..MERKLE_INPUTS_LEN));
+        let mut node: BytesN<32> = env.crypto().keccak256(&leaf_preimage).into();
+
+        // ── Walk up to the root ─────────────────────────────────────────────
+        let mut level = 0u32;
+        while level < depth {
+            let sibling = Bytes::from_array(env, &witness.array::<32>());
+            let mut preimage = Bytes::from_array(env, &[MERKLE_NODE_PREFIX]);
+            if (index >> level) & 1 == 0 {
+                preimage.append(&Bytes::from_array(env, &node.to_array()));
+                preimage.append(&sibling);
+            } else {
+                preimage.append(&sibling);
+                preimage.append(&Bytes::from_array(env, &node.to_array()));
+            }
+            node = env.crypto().keccak256(&preimage).into();
+            level += 1;
+        }
+
+        let root = InputsReader::new(env, public_inputs).digest();
+        if !Self::digest_eq(&node, &root) {
+            return at.fail(3, ZkVerifyError::MerkleRootMismatch);
+        }
+
+        EvVerifySuccess { mode: MODE_MERKLE }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 13: Private Cangkul Hand Proof (per-card OR-proofs on Pedersen / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Private cangkul hand verification (Mode 13).
+    ///
+    /// Mode 8 publishes every card id in the hand so the verifier can check
+    /// the suits. Here the hand stays committed: each card is a Pedersen
+    /// commitment C_i = card_i·G + r_i·H, and the prover shows every card is
+    /// one of the 27 ids outside the trick suit without opening it.
+    ///
+    /// Writing card = 9·suit + 3·a + b with a, b ∈ {0, 1, 2}, the prover adds
+    /// commitments S_i (to the suit) and A_i (to a), so
+    /// C_i − 9·S_i − 3·A_i commits to b. Three 3-branch CDS OR-proofs then
+    /// cover the 27 ids with 9 branches instead of 27:
+    ///
+    /// ```text
+    /// suit: S_i                 − u·G = x·H   for one of the 3 suits u ≠ trick_suit
+    /// a:    A_i                 − j·G = y·H   for one of j ∈ {0, 1, 2}
+    /// b:    C_i − 9·S_i − 3·A_i − j·G = z·H   for one of j ∈ {0, 1, 2}
+    /// ```
+    ///
+    /// Each card costs 12 MSM points, so one proof covers at most 4 cards.
+    /// Larger hands are proven in chunks: the public inputs always carry the
+    /// whole hand (n commitments), and the proof covers cards
+    /// [first, first + k). The caller accepts the declaration once every
+    /// chunk has verified.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// commit_hash(32) || trick_suit(4, u32 BE) || n(4, u32 BE) ||
+    /// C_1(96, G1) || ... || C_n(96, G1) || first(4, u32 BE) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (k × 1632 bytes, k ∈ [1, 4]):**
+    /// ```text
+    /// [S || A || R_suit,0..2 || R_a,0..2 || R_b,0..2 (11×96, G1) ||
+    ///  (e_0..2 || z_0..2) for suit, a, b (18×32, Fr)] × k
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. Binding: keccak256(C_1 || ... || C_n) == commit_hash, first + k ≤ n ≤ 18
+    /// 2. e = Fr(keccak256(trick_suit || n || C_i.. || first || [S || A || R..]... || session_id || player || "ZKPC"))
+    /// 3. Per card and OR-proof: e_0 + e_1 + e_2 == e
+    /// 4. ρ = Fr(keccak256(e || proof || "ZKPC")), one power of ρ per branch
+    /// 5. One g1_msm over [R.., S.., A.., C.., H, G] must be the identity:
+    ///      z_j·H == R_j + e_j·D_j   for every branch of every card
+    /// ```
+    ///
+    /// Only the C_i are subgroup-checked; as in Mode 10 every other point
+    /// enters solely through the batched equation.
+    pub fn verify_cangkul_hand_private(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_PRIVATE_HAND, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        let k = proof.len() / PRIVATE_HAND_CARD_PROOF_LEN;
+        if k == 0 || k > PRIVATE_HAND_MAX_CHUNK {
+            return at.fail(1, ZkVerifyError::HandCardCountMismatch);
+        }
+
+        // Minimum public_inputs: commit_hash(32) + trick_suit(4) + n(4) + C(96*n) + first(4) + session_id(4) + player(>=1)
+        if public_inputs.len() < 40 {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        let n = inputs.u32_at(36);
+        if n > PRIVATE_HAND_MAX_CARDS {
+            return at.fail(3, ZkVerifyError::HandCardCountMismatch);
+        }
+        let first_offset = 40 + 96 * n;
+        let sid_offset = first_offset + 4;
+        if public_inputs.len() < sid_offset + 5 {
+            return at.fail(4, ZkVerifyError::InputsTooShort);
+        }
+        let first = inputs.u32_at(first_offset);
+        if first >= n || n - first < k {
+            return at.fail(5, ZkVerifyError::HandCardCountMismatch);
+        }
+
+        let trick_suit = inputs.u32_at(32);
+        if trick_suit > 3 {
+            return at.fail(6, ZkVerifyError::HandSuitViolation);
+        }
+
+        // ── Binding check: keccak256(C_1 || ... || C_n) == commit_hash ──────
+        let hand_bytes = inputs.slice(40..first_offset);
+        let computed_commit: BytesN<32> = env.crypto().keccak256(&hand_bytes).into();
+        if !Self::digest_eq(&computed_commit, &inputs.digest_at(0)) {
+            return at.fail(7, ZkVerifyError::CommitmentMismatch);
+        }
+
+        // ── Fiat-Shamir challenge over the hand and every first move ───────
+        let witness = ProofReader::new(env, proof);
+        let mut transcript = FiatShamir::new(env);
+        transcript.bytes(&inputs.slice(32..sid_offset));
+        let mut card = 0u32;
+        while card < k {
+            let offset = card * PRIVATE_HAND_CARD_PROOF_LEN;
+            transcript.bytes(&witness.slice(offset..offset + PRIVATE_HAND_SCALARS_OFFSET));
+            card += 1;
+        }
+        transcript.bytes(&inputs.slice(sid_offset..inputs.len()));
+        let e_hash = transcript.challenge_digest(&PRIVATE_HAND_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        // ── Batching weight ρ, bound to the whole proof ─────────────────────
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&PRIVATE_HAND_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+        let three = Self::fr_from_u32(env, 3);
+        let nine = Self::fr_from_u32(env, 9);
+
+        // ── Accumulate the batched equation ─────────────────────────────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        card = 0;
+        while card < k {
+            let c_i = inputs.g1_at(40 + 96 * (first + card));
+            if !bls.g1_is_in_subgroup(&c_i) {
+                return at.fail(8, ZkVerifyError::PrivateHandPointNotInSubgroup);
+            }
+            let base = card * PRIVATE_HAND_CARD_PROOF_LEN;
+            let mut coef_s = zero.clone();
+            let mut coef_a = zero.clone();
+            let mut coef_c = zero.clone();
+
+            // OR-proof 0: suit, 1: a, 2: b — w·(z·H − R − e·D_j), D_j = P − value_j·G
+            let mut or_proof = 0u32;
+            while or_proof < 3 {
+                let scalars_base = base + PRIVATE_HAND_SCALARS_OFFSET + 192 * or_proof;
+                let mut e_sum = zero.clone();
+                let mut j = 0u32;
+                while j < 3 {
+                    let e_j = witness.fr_at(scalars_base + 32 * j);
+                    let z_j = witness.fr_at(scalars_base + 96 + 32 * j);
+                    let value = if or_proof == 0 && j >= trick_suit { j + 1 } else { j };
+                    let w = weight.clone();
+                    weight = weight * rho.clone();
+                    e_sum = e_sum + e_j.clone();
+
+                    let w_e = w.clone() * e_j;
+                    coef_h = coef_h + w.clone() * z_j;
+                    coef_g = coef_g + w_e.clone() * Self::fr_from_u32(env, value);
+                    match or_proof {
+                        0 => coef_s = coef_s - w_e,
+                        1 => coef_a = coef_a - w_e,
+                        _ => {
+                            coef_c = coef_c - w_e.clone();
+                            coef_s = coef_s + w_e.clone() * nine.clone();
+                            coef_a = coef_a + w_e * three.clone();
+                        }
+                    }
+                    points.push_back(witness.g1_at(base + 192 + 96 * (3 * or_proof + j)));
+                    scalars.push_back(zero.clone() - w);
+                    j += 1;
+                }
+                if e_sum != e {
+                    return at.fail(9, ZkVerifyError::PrivateHandCheckFailed);
+                }
+                or_proof += 1;
+            }
+
+            points.push_back(witness.g1_at(base));
+            scalars.push_back(coef_s);
+            points.push_back(witness.g1_at(base + 96));
+            scalars.push_back(coef_a);
+            points.push_back(c_i);
+            scalars.push_back(coef_c);
+            card += 1;
+        }
+
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(10, ZkVerifyError::PrivateHandCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 11, &inputs, false) || !Self::canonical(&at, 12, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_PRIVATE_HAND }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 14: Shuffle Permutation (Bayer–Groth product argument / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Shuffle-correctness verification (Mode 14).
+    ///
+    /// Proves a shuffled deck of 36 position commitments
+    /// E_i = a_i·G_i + r_i·H hides a permutation of the canonical deck
+    /// 0..35, so fairness no longer rests on the on-chain PRNG alone. The
+    /// blindings r_i are the committed shuffle randomness.
+    ///
+    /// Because every position has its own generator, C = Σ E_i is a vector
+    /// commitment to (a_1, ..., a_36). For a challenge x fixed by the E_i,
+    /// {a_i} is a permutation of {0..35} iff (w.h.p.)
+    ///
+    /// ```text
+    /// ∏ (a_i − x) == ∏ (i − x)
+    /// ```
+    ///
+    /// and C − x·Σ G_i commits to (a_i − x) with the same blinding. The
+    /// product is shown with the Bayer–Groth single value product argument
+    /// (EUROCRYPT 2012, §5.3).
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// E_1(96, G1) || ... || E_36(96, G1) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (2656 bytes):**
+    /// ```text
+    /// c_d || c_δ || c_Δ (3×96, G1) || ã_1..36 || b̃_1..36 || r̃ || s̃ (74×32, Fr)
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. C = Σ E_i (g1_add), b = ∏ (i − x)
+    /// 2. x = Fr(keccak256(E_1 || ... || E_36 || session_id || player || "ZKPD"))
+    ///    e = Fr(keccak256(x || c_d || c_δ || c_Δ || "ZKPD"))
+    /// 3. b̃_1 == ã_1 and b̃_36 == e·b
+    /// 4. ρ = Fr(keccak256(e || proof || "ZKPD")); one g1_msm must be the identity:
+    ///      e·(C − x·Σ G_i) + c_d == Σ ã_i·G_i + r̃·H
+    ///      e·c_Δ + c_δ        == Σ (e·b̃_{i+1} − b̃_i·ã_{i+1})·G_i + s̃·H   (i < 36)
+    /// ```
+    ///
+    /// The MSM covers the 36 generators plus 5 points; C is subgroup-checked.
+    pub fn verify_shuffle_permutation(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_SHUFFLE, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+        let n = SHUFFLE_DECK_SIZE;
+
+        // Minimum public_inputs: E(96*36) + session_id(4) + player(>=1)
+        if public_inputs.len() < 96 * n + 5 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Vector commitment C = Σ E_i ─────────────────────────────────────
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let mut commitment = inputs.g1();
+        let mut i = 1u32;
+        while i < n {
+            commitment = bls.g1_add(&commitment, &inputs.g1());
+            i += 1;
+        }
+        if !bls.g1_is_in_subgroup(&commitment) {
+            return at.fail(2, ZkVerifyError::ShufflePointNotInSubgroup);
+        }
+
+        // ── Challenges ──────────────────────────────────────────────────────
+        let witness = ProofReader::new(env, proof);
+        let x_hash = FiatShamir::new(env)
+            .bytes(public_inputs)
+            .challenge_digest(&SHUFFLE_CHALLENGE_TAG);
+        let x = Fr::from_bytes(x_hash.clone());
+
+        let e_hash = FiatShamir::chained(env, &x_hash)
+            .bytes(&witness.slice(0..288))
+            .challenge_digest(&SHUFFLE_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&SHUFFLE_CHALLENGE_TAG);
+
+        // ── Product of the canonical deck shifted by x ──────────────────────
+        let zero = Self::fr_from_u32(env, 0);
+        let mut b = Self::fr_from_u32(env, 1);
+        i = 0;
+        while i < n {
+            b = b * (Self::fr_from_u32(env, i) - x.clone());
+            i += 1;
+        }
+
+        let a_offset = 288u32;
+        let b_offset = a_offset + 32 * n;
+        let a_tilde = |k: u32| witness.fr_at(a_offset + 32 * k);
+        let b_tilde = |k: u32| witness.fr_at(b_offset + 32 * k);
+        let r_tilde = witness.fr_at(b_offset + 32 * n);
+        let s_tilde = witness.fr_at(b_offset + 32 * n + 32);
+
+        if b_tilde(0) != a_tilde(0) || b_tilde(n - 1) != e.clone() * b {
+            return at.fail(3, ZkVerifyError::ShuffleCheckFailed);
+        }
+
+        // ── Batched equation ────────────────────────────────────────────────
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let e_x = e.clone() * x;
+        let mut a_next = a_tilde(0);
+        let mut b_cur = b_tilde(0);
+        i = 0;
+        while i < n {
+            let a_i = a_next.clone();
+            let mut scalar = zero.clone() - e_x.clone() - a_i;
+            if i + 1 < n {
+                a_next = a_tilde(i + 1);
+                let b_next = b_tilde(i + 1);
+                scalar = scalar
+                    - rho.clone() * (e.clone() * b_next.clone() - b_cur.clone() * a_next.clone());
+                b_cur = b_next;
+            }
+            let start = (96 * i) as usize;
+            let mut g_i = [0u8; 96];
+            g_i.copy_from_slice(&SHUFFLE_GENERATORS[start..start + 96]);
+            points.push_back(G1Affine::from_array(env, &g_i));
+            scalars.push_back(scalar);
+            i += 1;
+        }
+
+        points.push_back(Self::pedersen_h(&bls, env));
+        scalars.push_back(zero.clone() - r_tilde - rho.clone() * s_tilde);
+        points.push_back(commitment);
+        scalars.push_back(e.clone());
+        points.push_back(witness.g1_at(0));
+        scalars.push_back(Self::fr_from_u32(env, 1));
+        points.push_back(witness.g1_at(96));
+        scalars.push_back(rho.clone());
+        points.push_back(witness.g1_at(192));
+        scalars.push_back(rho * e);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(4, ZkVerifyError::ShuffleCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 5, &inputs, false) || !Self::canonical(&at, 6, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_SHUFFLE }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 15: Mental-poker ElGamal shares (Chaum–Pedersen DLEQ / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// ElGamal re-encryption / partial-decryption verification (Mode 15).
+    ///
+    /// Cards are encrypted off-chain under the joint key PK = pk_A + pk_B as
+    /// `(c1, c2) = (r·G, card·G + r·PK)`. Both statements reduce to a
+    /// Chaum–Pedersen equality of discrete logs, log_G(P) == log_B(Q):
+    ///
+    /// ```text
+    /// kind 0 — partial decryption by one player:
+    ///   public_inputs = 0(4) || pk_i(96) || c1(96) || session_id(4) || player(var)
+    ///   proof         = D_i || A1 || A2 || z            (D_i = sk_i·c1)
+    ///   P = pk_i, B = c1, Q = D_i
+    ///
+    /// kind 1 — re-encryption of one card:
+    ///   public_inputs = 1(4) || PK(96) || c1(96) || c2(96) || c1'(96) || session_id(4) || player(var)
+    ///   proof         = c2' || A1 || A2 || z            (c1' = c1 + s·G, c2' = c2 + s·PK)
+    ///   P = c1' − c1, B = PK, Q = c2' − c2
+    /// ```
+    ///
+    /// The first proof point is the prover's output (the share or the new
+    /// `c2`), so the game contract reads it from the verified proof.
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. Subgroup-check every point of the statement and A1, A2
+    /// 2. e = Fr(keccak256(public_inputs || proof[0..288) || "ZKPE"))
+    /// 3. ρ = Fr(keccak256(e || proof || "ZKPE")); one g1_msm must be the identity:
+    ///      z·G − A1 − e·P + ρ·(z·B − A2 − e·Q) == O
+    /// ```
+    ///
+    /// Once both shares are verified, `c2 − D_A − D_B = card·G` and the
+    /// card is recovered by comparing against the 36 multiples of G.
+    pub fn verify_elgamal_share(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_ELGAMAL, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        if public_inputs.len() < 4 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let kind = inputs.u32();
+        let statement_len = match kind {
+            ELGAMAL_KIND_DECRYPT => 4 + 2 * 96,
+            ELGAMAL_KIND_REENCRYPT => 4 + 4 * 96,
+            _ => return at.fail(2, ZkVerifyError::ElGamalUnknownKind),
+        };
+        // statement + session_id(4) + player(>=1)
+        if public_inputs.len() < statement_len + 5 {
+            return at.fail(3, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Statement points ────────────────────────────────────────────────
+        let mut witness = ProofReader::new(env, proof);
+        let output = witness.g1();
+        let (p, b, q) = if kind == ELGAMAL_KIND_DECRYPT {
+            let pk_i = inputs.g1();
+            let c1 = inputs.g1();
+            if !bls.g1_is_in_subgroup(&pk_i) || !bls.g1_is_in_subgroup(&c1) {
+                return at.fail(4, ZkVerifyError::ElGamalPointNotInSubgroup);
+            }
+            (pk_i, c1, output.clone())
+        } else {
+            let pk = inputs.g1();
+            let c1 = inputs.g1();
+            let c2 = inputs.g1();
+            let c1_new = inputs.g1();
+            if !bls.g1_is_in_subgroup(&pk)
+                || !bls.g1_is_in_subgroup(&c1)
+                || !bls.g1_is_in_subgroup(&c2)
+                || !bls.g1_is_in_subgroup(&c1_new)
+            {
+                return at.fail(5, ZkVerifyError::ElGamalPointNotInSubgroup);
+            }
+            (bls.g1_add(&c1_new, &-c1), pk, bls.g1_add(&output, &-c2))
+        };
+
+        let a1 = witness.g1();
+        let a2 = witness.g1();
+        let z = witness.fr();
+        if !bls.g1_is_in_subgroup(&output)
+            || !bls.g1_is_in_subgroup(&a1)
+            || !bls.g1_is_in_subgroup(&a2)
+        {
+            return at.fail(6, ZkVerifyError::ElGamalPointNotInSubgroup);
+        }
+
+        // ── Challenges ──────────────────────────────────────────────────────
+        let e_hash = FiatShamir::new(env)
+            .bytes(public_inputs)
+            .bytes(&witness.slice(0..288))
+            .challenge_digest(&ELGAMAL_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&ELGAMAL_CHALLENGE_TAG);
+
+        // ── Batched DLEQ equation ───────────────────────────────────────────
+        let g = Self::g1_generator(env);
+
+        let zero = Self::fr_from_u32(env, 0);
+        let minus_one = zero.clone() - Self::fr_from_u32(env, 1);
+        let points = Vec::from_array(env, [g, a1, p, b, a2, q]);
+        let scalars = Vec::from_array(
+            env,
+            [
+                z.clone(),
+                minus_one,
+                zero.clone() - e.clone(),
+                rho.clone() * z,
+                zero.clone() - rho.clone(),
+                zero - rho * e,
+            ],
+        );
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        if bls.g1_msm(points, scalars).to_bytes().to_array() != identity {
+            return at.fail(7, ZkVerifyError::ElGamalCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 8, &inputs, false) || !Self::canonical(&at, 9, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_ELGAMAL }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 16: Card Comparison (CDS OR-proofs on Pedersen / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Card comparison verification (Mode 16).
+    ///
+    /// Resolves a trick without revealing either card. The statement is two
+    /// card commitments C_b = card_b·G + r_b·H (the Mode 7 form) and a
+    /// claimed `winner` ∈ {0, 1}. With W = C_winner, L = C_{1−winner} and s
+    /// the trick suit, three 9-branch CDS OR-proofs show
+    ///
+    /// ```text
+    /// winner: W     − (9·s + j)·G = x·H         for one of j ∈ {0..8}
+    /// loser:  L     − (9·s + j)·G = y·H         for one of j ∈ {0..8}
+    /// gap:    W − L −        j ·G = (x − y)·H   for one of j ∈ {0..8}
+    /// ```
+    ///
+    /// The first two put both cards in the trick suit, so the gap is the
+    /// difference of their values (id mod 9), and a gap in {0..8} means the
+    /// winner's value is at least the loser's. The prover needs both
+    /// openings; the verifier learns neither card.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// trick_suit(4, u32 BE) || winner(4, u32 BE) || C_0(96, G1) || C_1(96, G1) ||
+    /// session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (4320 bytes):**
+    /// ```text
+    /// R_winner,0..8 || R_loser,0..8 || R_gap,0..8 (27×96, G1) ||
+    /// (e_0..8 || z_0..8) for winner, loser, gap (54×32, Fr)
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. trick_suit ≤ 3, winner ≤ 1, C_0 and C_1 in the G1 subgroup
+    /// 2. e = Fr(keccak256(trick_suit || winner || C_0 || C_1 || R.. || session_id || player || "ZKPF"))
+    /// 3. Per OR-proof: e_0 + ... + e_8 == e
+    /// 4. ρ = Fr(keccak256(e || proof || "ZKPF")), one power of ρ per branch
+    /// 5. One g1_msm over [R.., W, L, H, G] must be the identity:
+    ///      z_j·H == R_j + e_j·D_j   for every branch
+    /// ```
+    ///
+    /// The caller checks C_0 and C_1 against the plays it stored for the
+    /// trick, e.g. `keccak256(C)` from `commit_play_zk`.
+    pub fn verify_card_compare(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_CARD_COMPARE, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        // Minimum public_inputs: trick_suit(4) + winner(4) + C_0(96) + C_1(96) + session_id(4) + player(>=1)
+        if public_inputs.len() < CARD_COMPARE_INPUTS_FIXED_LEN + 5 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let trick_suit = inputs.u32();
+        let winner = inputs.u32();
+        if trick_suit > 3 || winner > 1 {
+            return at.fail(2, ZkVerifyError::CompareInvalidStatement);
+        }
+        let c_0 = inputs.g1();
+        let c_1 = inputs.g1();
+        if !bls.g1_is_in_subgroup(&c_0) || !bls.g1_is_in_subgroup(&c_1) {
+            return at.fail(3, ZkVerifyError::ComparePointNotInSubgroup);
+        }
+        let (win, lose) = if winner == 0 { (c_0, c_1) } else { (c_1, c_0) };
+
+        // ── Fiat-Shamir challenge over the statement and every first move ──
+        let witness = ProofReader::new(env, proof);
+        let e_hash = FiatShamir::new(env)
+            .bytes(&inputs.slice(0..CARD_COMPARE_INPUTS_FIXED_LEN))
+            .bytes(&witness.slice(0..CARD_COMPARE_SCALARS_OFFSET))
+            .bytes(&inputs.slice(CARD_COMPARE_INPUTS_FIXED_LEN..inputs.len()))
+            .challenge_digest(&CARD_COMPARE_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        // ── Batching weight ρ, bound to the whole proof ─────────────────────
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&CARD_COMPARE_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Accumulate the batched equation ─────────────────────────────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut coef_win = zero.clone();
+        let mut coef_lose = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        // OR-proof 0: winner, 1: loser, 2: gap — w·(z·H − R − e·D_j), D_j = P − value_j·G
+        let mut or_proof = 0u32;
+        while or_proof < 3 {
+            let scalars_base = CARD_COMPARE_SCALARS_OFFSET + 18 * 32 * or_proof;
+            let mut e_sum = zero.clone();
+            let mut j = 0u32;
+            while j < 9 {
+                let e_j = witness.fr_at(scalars_base + 32 * j);
+                let z_j = witness.fr_at(scalars_base + 9 * 32 + 32 * j);
+                let value = if or_proof < 2 { 9 * trick_suit + j } else { j };
+                let w = weight.clone();
+                weight = weight * rho.clone();
+                e_sum = e_sum + e_j.clone();
+
+                let w_e = w.clone() * e_j;
+                coef_h = coef_h + w.clone() * z_j;
+                coef_g = coef_g + w_e.clone() * Self::fr_from_u32(env, value);
+                match or_proof {
+                    0 => coef_win = coef_win - w_e,
+                    1 => coef_lose = coef_lose - w_e,
+                    _ => {
+                        coef_win = coef_win - w_e.clone();
+                        coef_lose = coef_lose + w_e;
+                    }
+                }
+                points.push_back(witness.g1_at(96 * (9 * or_proof + j)));
+                scalars.push_back(zero.clone() - w);
+                j += 1;
+            }
+            if e_sum != e {
+                return at.fail(4, ZkVerifyError::CompareCheckFailed);
+            }
+            or_proof += 1;
+        }
+
+        points.push_back(win);
+        scalars.push_back(coef_win);
+        points.push_back(lose);
+        scalars.push_back(coef_lose);
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(5, ZkVerifyError::CompareCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 6, &inputs, false) || !Self::canonical(&at, 7, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_CARD_COMPARE }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 17: Commitment Equality (Chaum–Pedersen style sigma / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Commitment equality verification (Mode 17).
+    ///
+    /// Proves that each pair of card commitments C_i = v_i·G + r_i·H and
+    /// C'_i = v_i·G + r'_i·H (the Mode 7 form) hides the same card id v_i,
+    /// without revealing v_i or either blinding. The prover knows both
+    /// openings and answers one challenge for all three witnesses:
+    ///
+    /// ```text
+    /// R  = a·G + b·H,   R' = a·G + b'·H
+    /// z_v = a + e·v,    z = b + e·r,    z' = b' + e·r'
+    /// ```
+    ///
+    /// Because z_v appears in both equations, the two commitments must share
+    /// their G component. C_i == C'_i is rejected: a re-commitment has to
+    /// use a fresh blinding, or it would link the play to the deal.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// n(4, u32 BE) || (C_i(96, G1) || C'_i(96, G1)) × n || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (4 + n×288 bytes, n ∈ [1, 9]):**
+    /// ```text
+    /// n(4, u32 BE) || [R_i || R'_i (2×96, G1) || z_v,i || z_i || z'_i (3×32, Fr)] × n
+    /// ```
+    ///
+    /// The leading n keeps the proof length off Mode 7's pattern (a single
+    /// 288-byte pair would read as a 3-member ring) and is cross-checked
+    /// against the public inputs, as in Mode 8.
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. n in proof == n in public_inputs, C_i != C'_i, every C in the G1 subgroup
+    /// 2. e = Fr(keccak256(n || C_i || C'_i.. || [R_i || R'_i].. || session_id || player || "ZKPG"))
+    /// 3. ρ = Fr(keccak256(e || proof || "ZKPG")), one power of ρ per equation
+    /// 4. One g1_msm over [R.., C.., H, G] must be the identity:
+    ///      z_v·G + z·H  == R  + e·C
+    ///      z_v·G + z'·H == R' + e·C'   for every pair
+    /// ```
+    ///
+    /// Only the commitments are subgroup-checked; the R points enter solely
+    /// through the batched equation.
+    pub fn verify_commitment_equality(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_COMMIT_EQ, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        // ── Extract n from proof [0..4) ─────────────────────────────────────
+        let mut witness = ProofReader::new(env, proof);
+        let n = witness.u32();
+        if n == 0 || n > COMMIT_EQ_MAX_PAIRS || proof.len() != 4 + n * COMMIT_EQ_PAIR_PROOF_LEN {
+            return at.fail(1, ZkVerifyError::CommitEqInvalidStatement);
+        }
+
+        // Minimum public_inputs: n(4) + (C || C')(192*n) + session_id(4) + player(>=1)
+        let sid_offset = 4 + 192 * n;
+        if public_inputs.len() < sid_offset + 5 {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        if inputs.u32_at(0) != n {
+            return at.fail(3, ZkVerifyError::CommitEqInvalidStatement);
+        }
+
+        // ── Fiat-Shamir challenge over the statement and every first move ──
+        let mut transcript = FiatShamir::new(env);
+        transcript.bytes(&inputs.slice(0..sid_offset));
+        let mut pair = 0u32;
+        while pair < n {
+            let offset = 4 + pair * COMMIT_EQ_PAIR_PROOF_LEN;
+            transcript.bytes(&witness.slice(offset..offset + 192));
+            pair += 1;
+        }
+        transcript.bytes(&inputs.slice(sid_offset..inputs.len()));
+        let e_hash = transcript.challenge_digest(&COMMIT_EQ_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        // ── Batching weight ρ, bound to the whole proof ─────────────────────
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&COMMIT_EQ_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Accumulate the batched equation ─────────────────────────────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        pair = 0;
+        while pair < n {
+            let c = inputs.g1_at(4 + 192 * pair);
+            let c_prime = inputs.g1_at(4 + 192 * pair + 96);
+            if !bls.g1_is_in_subgroup(&c) || !bls.g1_is_in_subgroup(&c_prime) {
+                return at.fail(4, ZkVerifyError::CommitEqPointNotInSubgroup);
+            }
+            if c.to_bytes() == c_prime.to_bytes() {
+                return at.fail(5, ZkVerifyError::CommitEqInvalidStatement);
+            }
+
+            // w·(z_v·G + z·H − R − e·C) for C, then for C'
+            let base = 4 + pair * COMMIT_EQ_PAIR_PROOF_LEN;
+            let z_v = witness.fr_at(base + 192);
+            let mut side = 0u32;
+            while side < 2 {
+                let w = weight.clone();
+                weight = weight * rho.clone();
+                let z = witness.fr_at(base + 224 + 32 * side);
+                coef_g = coef_g + w.clone() * z_v.clone();
+                coef_h = coef_h + w.clone() * z;
+                points.push_back(witness.g1_at(base + 96 * side));
+                scalars.push_back(zero.clone() - w.clone());
+                points.push_back(if side == 0 { c.clone() } else { c_prime.clone() });
+                scalars.push_back(zero.clone() - w * e.clone());
+                side += 1;
+            }
+            pair += 1;
+        }
+
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(6, ZkVerifyError::CommitEqCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 7, &inputs, false) || !Self::canonical(&at, 8, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_COMMIT_EQ }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 18: Deal Consistency (Schnorr over H / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Deal consistency verification (Mode 18).
+    ///
+    /// Proves that the 5 hand commitments P_i = c_i·G + r_i·H a player
+    /// publishes at the deal commit to the cards the shuffle actually dealt
+    /// them: c_i is deck position 5·slot + i of the deck the game derives from
+    /// `seed_hash1 || seed_hash2 || session_id`. The verifier re-runs the
+    /// shuffle (`cangkulan_common::shuffled_deck`), so the card ids never come
+    /// from the client. What remains is knowledge of each blinding:
+    ///
+    /// ```text
+    /// D_i = P_i − c_i·G = r_i·H
+    /// R_i = k_i·H,   z_i = k_i + e·r_i
+    /// ```
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// seed_hash1(32) || seed_hash2(32) || slot(4, 0 = player1, 1 = player2) ||
+    /// P_0..P_4 (5×96, G1) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (640 bytes):**
+    /// ```text
+    /// [R_i(96, G1) || z_i(32, Fr)] × 5
+    /// ```
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. slot ∈ {0, 1}, every P_i in the G1 subgroup
+    /// 2. c_i = shuffled_deck(deal_seed(seed_hash1, seed_hash2, session_id))[5·slot + i]
+    /// 3. e = Fr(keccak256(seed_hash1 || seed_hash2 || slot || P_0..P_4 || R_0..R_4 || session_id || player || "ZKPH"))
+    /// 4. ρ = Fr(keccak256(e || proof || "ZKPH")), one power of ρ per card
+    /// 5. One g1_msm over [R.., P.., H, G] must be the identity:
+    ///      z_i·H == R_i + e·(P_i − c_i·G)   for every card
+    /// ```
+    ///
+    /// The seed hashes are public once both seeds are revealed, so this hides
+    /// nothing the chain does not already know; it binds the commitments later
+    /// modes (11, 12, 17) build on to the verified shuffle. Re-running the
+    /// shuffle reseeds the PRNG of the calling contract.
+    pub fn verify_deal_consistency(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_DEAL, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        if proof.len() != DEAL_PROOF_LEN {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        // Minimum public_inputs: fixed part + session_id(4) + player(>=1)
+        if public_inputs.len() < DEAL_INPUTS_FIXED_LEN + 5 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        let slot = inputs.u32_at(64);
+        if slot > 1 {
+            return at.fail(2, ZkVerifyError::DealInvalidStatement);
+        }
+
+        // ── Re-derive the dealt cards ───────────────────────────────────────
+        let session_id = inputs.u32_at(DEAL_INPUTS_FIXED_LEN);
+        let seed = deal_seed(env, &inputs.digest_at(0), &inputs.digest_at(32), session_id);
+        let deck = shuffled_deck(env, &seed);
+
+        // ── Fiat-Shamir challenge over the statement and every first move ──
+        let witness = ProofReader::new(env, proof);
+        let mut transcript = FiatShamir::new(env);
+        transcript.bytes(&inputs.slice(0..DEAL_INPUTS_FIXED_LEN));
+        let mut card = 0u32;
+        while card < DEAL_HAND_SIZE {
+            let offset = card * DEAL_CARD_PROOF_LEN;
+            transcript.bytes(&witness.slice(offset..offset + 96));
+            card += 1;
+        }
+        transcript.bytes(&inputs.slice(DEAL_INPUTS_FIXED_LEN..inputs.len()));
+        let e_hash = transcript.challenge_digest(&DEAL_CHALLENGE_TAG);
+        let e = Fr::from_bytes(e_hash.clone());
+
+        // ── Batching weight ρ, bound to the whole proof ─────────────────────
+        let rho = FiatShamir::chained(env, &e_hash)
+            .bytes(proof)
+            .challenge(&DEAL_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Accumulate w·(z·H − R − e·P + e·c·G) per card ──────────────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        card = 0;
+        while card < DEAL_HAND_SIZE {
+            let p = inputs.g1_at(68 + 96 * card);
+            if !bls.g1_is_in_subgroup(&p) {
+                return at.fail(3, ZkVerifyError::DealPointNotInSubgroup);
+            }
+            let dealt = deck[(DEAL_HAND_SIZE * slot + card) as usize];
+            let base = card * DEAL_CARD_PROOF_LEN;
+            let w = weight.clone();
+            weight = weight * rho.clone();
+
+            coef_h = coef_h + w.clone() * witness.fr_at(base + 96);
+            coef_g = coef_g + w.clone() * e.clone() * Self::fr_from_u32(env, dealt);
+            points.push_back(witness.g1_at(base));
+            scalars.push_back(zero.clone() - w.clone());
+            points.push_back(p);
+            scalars.push_back(zero.clone() - w * e.clone());
+            card += 1;
+        }
+
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(4, ZkVerifyError::DealCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 5, &inputs, false) || !Self::canonical(&at, 6, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_DEAL }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 19: Threshold Seed Commitment (batched Mode 4 / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Threshold seed commitment verification (Mode 19).
+    ///
+    /// Verifies the seed commitments of an n-party game in one call. Each
+    /// party i holds a Mode 4 commitment C_i = s_i·G + r_i·H; the parties in
+    /// the opened mask (at least t of them) publish s_i = seed_hash_i and
+    /// prove knowledge of their blinding, exactly as in Mode 4:
+    ///
+    /// ```text
+    /// D_i = C_i − s_i·G = r_i·H
+    /// R_i = k_i·H,   z_i = k_i + e_i·r_i
+    /// ```
+    ///
+    /// Together the opened proofs open the aggregate commitment
+    /// Σ C_i = (Σ s_i)·G + (Σ r_i)·H over the opened set. Each party has its
+    /// own challenge, so parties prove independently and the submitter only
+    /// concatenates their proofs. The shuffle seed is derived from the opened
+    /// seed hashes by the game; with t < n the last party to open can still
+    /// choose whether to, which the game must weigh against liveness.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// n(4) || t(4) || mask(4) || C_0..C_{n−1} (n×96, G1) ||
+    /// seed_hash_i(32) for each i in mask, ascending || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (4 + k×128 bytes, k = |mask| ∈ [2, 8]):**
+    /// ```text
+    /// mask(4, u32 BE) || [R_i(96, G1) || z_i(32, Fr)] for each i in mask, ascending
+    /// ```
+    ///
+    /// As in Mode 8, the leading mask keeps the proof length off other
+    /// modes' patterns and is cross-checked against the public inputs.
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. 2 ≤ t ≤ n ≤ 8, mask ⊆ [0, n), |mask| ≥ t, same mask in proof and inputs
+    /// 2. every C_i in the G1 subgroup, no two C_i equal
+    /// 3. e_i = Fr(keccak256(i || C_i || R_i || seed_hash_i || session_id || player || "ZKPI"))
+    /// 4. ρ = Fr(keccak256(public_inputs || proof || "ZKPI")), one power of ρ per party
+    /// 5. One g1_msm over [R.., C.., H, G] must be the identity:
+    ///      z_i·H == R_i + e_i·(C_i − s_i·G)   for every opened party
+    /// ```
+    pub fn verify_threshold_seed(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_THRESHOLD_SEED, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        // ── Extract the opened mask from proof [0..4) ──────────────────────
+        let witness = ProofReader::new(env, proof);
+        let mask = witness.u32_at(0);
+        let opened = mask.count_ones();
+        if !(THRESHOLD_MIN_PARTIES..=THRESHOLD_MAX_PARTIES).contains(&opened)
+            || proof.len() != 4 + opened * THRESHOLD_PARTY_PROOF_LEN
+        {
+            return at.fail(1, ZkVerifyError::ThresholdInvalidStatement);
+        }
+
+        // ── Statement header: n || t || mask ────────────────────────────────
+        if public_inputs.len() < THRESHOLD_INPUTS_HEADER_LEN {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        let n = inputs.u32_at(0);
+        let t = inputs.u32_at(4);
+        if !(THRESHOLD_MIN_PARTIES..=THRESHOLD_MAX_PARTIES).contains(&n)
+            || t < THRESHOLD_MIN_PARTIES
+            || t > n
+            || inputs.u32_at(8) != mask
+            || mask >> n != 0
+            || opened < t
+        {
+            return at.fail(3, ZkVerifyError::ThresholdInvalidStatement);
+        }
+
+        // Minimum public_inputs: header + C(96·n) + seed_hash(32·k) + session_id(4) + player(>=1)
+        let seeds_offset = THRESHOLD_INPUTS_HEADER_LEN + 96 * n;
+        let sid_offset = seeds_offset + 32 * opened;
+        if public_inputs.len() < sid_offset + 5 {
+            return at.fail(4, ZkVerifyError::InputsTooShort);
+        }
+        let tail = inputs.slice(sid_offset..inputs.len());
+
+        // ── Every commitment is a distinct subgroup point ───────────────────
+        let mut commitments: Vec<G1Affine> = Vec::new(env);
+        let mut party = 0u32;
+        while party < n {
+            let c = inputs.g1_at(THRESHOLD_INPUTS_HEADER_LEN + 96 * party);
+            if !bls.g1_is_in_subgroup(&c) {
+                return at.fail(5, ZkVerifyError::ThresholdPointNotInSubgroup);
+            }
+            if commitments.iter().any(|prev| prev.to_bytes() == c.to_bytes()) {
+                return at.fail(6, ZkVerifyError::ThresholdInvalidStatement);
+            }
+            commitments.push_back(c);
+            party += 1;
+        }
+
+        // ── Batching weight ρ, bound to the whole statement and proof ──────
+        let rho = FiatShamir::new(env)
+            .bytes(public_inputs)
+            .bytes(proof)
+            .challenge(&THRESHOLD_CHALLENGE_TAG);
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Accumulate w·(z·H − R − e·C + e·s·G) per opened party ──────────
+        let mut coef_h = zero.clone();
+        let mut coef_g = zero.clone();
+        let mut points: Vec<G1Affine> = Vec::new(env);
+        let mut scalars: Vec<Fr> = Vec::new(env);
+        let mut weight = rho.clone();
+
+        let mut slot = 0u32;
+        party = 0;
+        while party < n {
+            if mask & (1 << party) != 0 {
+                let c = commitments.get_unchecked(party);
+                let seed_hash = inputs.digest_at(seeds_offset + 32 * slot);
+                let base = 4 + slot * THRESHOLD_PARTY_PROOF_LEN;
+                let r_point = witness.g1_at(base);
+                let z = witness.fr_at(base + 96);
+
+                let e = FiatShamir::new(env)
+                    .u32(party)
+                    .g1(&c)
+                    .g1(&r_point)
+                    .digest(&seed_hash)
+                    .bytes(&tail)
+                    .challenge(&THRESHOLD_CHALLENGE_TAG);
+
+                let w = weight.clone();
+                weight = weight * rho.clone();
+                coef_h = coef_h + w.clone() * z;
+                coef_g = coef_g + w.clone() * e.clone() * Fr::from_bytes(seed_hash);
+                points.push_back(r_point);
+                scalars.push_back(zero.clone() - w.clone());
+                points.push_back(c);
+                scalars.push_back(zero.clone() - w * e);
+                slot += 1;
+            }
+            party += 1;
+        }
+
+        points.push_back(h);
+        scalars.push_back(coef_h);
+        points.push_back(g);
+        scalars.push_back(coef_g);
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        let combined = bls.g1_msm(points, scalars);
+        if combined.to_bytes().to_array() != identity {
+            return at.fail(7, ZkVerifyError::ThresholdCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 8, &inputs, false) || !Self::canonical(&at, 9, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_THRESHOLD_SEED }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 20: BLS Attestation (min-pk BLS signatures / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// BLS attestation verification (Mode 20).
+    ///
+    /// Checks a BLS signature from one or more signers (relayers, a
+    /// matchmaking service) over a session-bound message, e.g. agreed stakes
+    /// or rules. Public keys are G1 points pk_i = sk_i·G, signatures G2
+    /// points. Each signer signs its own key, the session and the message,
+    /// and the signatures are summed into one:
+    ///
+    /// ```text
+    /// m_i = pk_i(96) || session_id(4) || message
+    /// σ   = Σ sk_i·hash_to_g2(m_i, "SGS_CANGKULAN_V1_BLS_ATTEST")
+    /// ```
+    ///
+    /// Prefixing the key makes every signer's message distinct, so a rogue
+    /// key chosen to cancel another signer's key cannot forge an aggregate
+    /// and no proof of possession is needed.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// k(4) || pk_1..pk_k (k×96, G1) || msg_len(4) || message(msg_len) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (192 bytes):** `σ (G2)`
+    ///
+    /// **Verification:**
+    /// ```text
+    /// 1. 1 ≤ k ≤ 4, msg_len ≤ 256, distinct non-identity pk_i in the G1 subgroup, σ in the G2 subgroup
+    /// 2. pairing_check([pk_1, .., pk_k, −G], [H(m_1), .., H(m_k), σ])
+    /// ```
+    ///
+    /// Signers do not sign `player`; it only names who submitted the
+    /// attestation. The consumer decides which keys it trusts.
+    pub fn verify_bls_attestation(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_BLS_ATTEST, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        if proof.len() != BLS_SIGNATURE_LEN {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        if public_inputs.len() < 4 {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        let k = inputs.u32_at(0);
+        if k == 0 || k > BLS_MAX_SIGNERS {
+            return at.fail(2, ZkVerifyError::BlsInvalidStatement);
+        }
+
+        // ── Message and session ─────────────────────────────────────────────
+        let msg_offset = 4 + 96 * k;
+        if public_inputs.len() < msg_offset + 4 {
+            return at.fail(3, ZkVerifyError::InputsTooShort);
+        }
+        let msg_len = inputs.u32_at(msg_offset);
+        if msg_len > BLS_MAX_MESSAGE_LEN {
+            return at.fail(4, ZkVerifyError::BlsInvalidStatement);
+        }
+        // Minimum public_inputs: ... + message + session_id(4) + player(>=1)
+        let sid_offset = msg_offset + 4 + msg_len;
+        if public_inputs.len() < sid_offset + 5 {
+            return at.fail(5, ZkVerifyError::InputsTooShort);
+        }
+        let mut signed = inputs.slice(sid_offset..sid_offset + 4);
+        signed.append(&inputs.slice(msg_offset + 4..sid_offset));
+
+        let mut witness = ProofReader::new(env, proof);
+        let signature = witness.g2();
+        if !bls.g2_is_in_subgroup(&signature) {
+            return at.fail(6, ZkVerifyError::BlsPointNotInSubgroup);
+        }
+
+        // ── One pairing per signer, plus e(−G, σ) ──────────────────────────
+        let dst = Bytes::from_slice(env, BLS_ATTEST_DST);
+        let mut keys: Vec<G1Affine> = Vec::new(env);
+        let mut hashes: Vec<G2Affine> = Vec::new(env);
+        let mut signer = 0u32;
+        while signer < k {
+            let offset = 4 + 96 * signer;
+            let pk = inputs.g1_at(offset);
+            if !bls.g1_is_in_subgroup(&pk) {
+                return at.fail(7, ZkVerifyError::BlsPointNotInSubgroup);
+            }
+            if keys.iter().any(|prev| prev.to_bytes() == pk.to_bytes()) {
+                return at.fail(8, ZkVerifyError::BlsInvalidStatement);
+            }
+            let mut message = inputs.slice(offset..offset + 96);
+            message.append(&signed);
+            hashes.push_back(bls.hash_to_g2(&message, &dst));
+            keys.push_back(pk);
+            signer += 1;
+        }
+
+        keys.push_back(-Self::g1_generator(env));
+        hashes.push_back(signature);
+
+        if !bls.pairing_check(keys, hashes) {
+            return at.fail(9, ZkVerifyError::BlsSignatureInvalid);
+        }
+
+        // ── Canonical encodings (an identity key would sign nothing) ────────
+        if !Self::canonical(&at, 10, &inputs, false) || !Self::canonical(&at, 11, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_BLS_ATTEST }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    //  Mode 21: Hand Count (Okamoto proof over G and H / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Hand count verification (Mode 21).
+    ///
+    /// Proves that an aggregate hand commitment holds exactly k cards without
+    /// opening any of them. Each card enters the aggregate as its Mode 7
+    /// commitment plus one copy of the count generator K:
+    ///
+    /// ```text
+    /// A = Σ (c_i·G + r_i·H + K) = s·G + r·H + k·K
+    /// ```
+    ///
+    /// so the K coefficient is the card count. The prover shows it knows
+    /// (s, r) with A − k·K = s·G + r·H. A second opening with another count
+    /// would give a discrete-log relation between G, H and K. k = 0 proves
+    /// an empty hand.
+    ///
+    /// The proof says nothing about which cards are in A. Whoever maintains A
+    /// must only fold in commitments proven to be of the c·G + r·H form (for
+    /// instance by Mode 7, 12 or 13), or a card could carry a −K term.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// A(96, G1) || k(4, u32 BE) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (96 bytes):**
+    /// ```text
+    /// e(32, Fr) || z_s(32, Fr) || z_r(32, Fr)
+    /// ```
+    ///
+    /// **Verification:**
+    /// 1. k ≤ 36, A in the G1 subgroup
+    /// 2. R = z_s·G + z_r·H − e·A + (e·k)·K   (one g1_msm)
+    /// 3. e == Fr(keccak256(A || R || k || session_id || player || "ZKPJ"))
+    pub fn verify_hand_count(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_HAND_COUNT, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        if proof.len() != HAND_COUNT_PROOF_LEN {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        // Minimum public_inputs: A(96) + k(4) + session_id(4) + player(>=1)
+        if public_inputs.len() <= HAND_COUNT_INPUTS_FIXED_LEN {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let agg_commit = inputs.g1();
+        let k = inputs.u32();
+        if k > HAND_COUNT_MAX {
+            return at.fail(2, ZkVerifyError::HandCountInvalidStatement);
+        }
+        if !bls.g1_is_in_subgroup(&agg_commit) {
+            return at.fail(3, ZkVerifyError::HandCountPointNotInSubgroup);
+        }
+
+        let mut witness = ProofReader::new(env, proof);
+        let e = witness.fr();
+        let z_s = witness.fr();
+        let z_r = witness.fr();
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let count_k = Self::hand_count_k(&bls, env);
+
+        // ── Reconstruct the first move: R = z_s·G + z_r·H − e·(A − k·K) ────
+        let zero = Self::fr_from_u32(env, 0);
+        let e_k = e.clone() * Self::fr_from_u32(env, k);
+        let r_point = bls.g1_msm(
+            Vec::from_array(env, [g, h, agg_commit.clone(), count_k]),
+            Vec::from_array(env, [z_s, z_r, zero - e.clone(), e_k]),
+        );
+
+        // ── Fiat-Shamir challenge ───────────────────────────────────────────
+        let expected_e = FiatShamir::new(env)
+            .g1(&agg_commit)
+            .g1(&r_point)
+            .u32(k)
+            .bytes(&inputs.rest())
+            .challenge(&HAND_COUNT_CHALLENGE_TAG);
+        if expected_e != e {
+            return at.fail(4, ZkVerifyError::HandCountCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 5, &inputs, false) || !Self::canonical(&at, 6, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_HAND_COUNT }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    //  Mode 22: UltraHonk (forwarded to the registered Noir verifier)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Forward a Noir UltraKeccakHonk proof to the registered verifier (Mode 22).
+    ///
+    /// The inputs are the circuit's public inputs as 32-byte big-endian
+    /// field elements, optionally led by a `circuit_id(4)`. With a circuit
+    /// id the proof goes to that circuit's verifier from
+    /// `register_ultrahonk_circuit` (`VkNotFound` if none), otherwise to the
+    /// default one from `set_ultrahonk_verifier`. The field elements and
+    /// `proof` are passed through unchanged. The UltraHonk contract traps
+    /// on an invalid proof; the trap is caught and reported as
+    /// `UltraHonkProofRejected`.
+    pub fn verify_ultrahonk(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_ULTRAHONK, public_inputs, proof);
+        if proof.len() <= ULTRAHONK_MIN_PROOF_LEN {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        // Field elements fill whole 32-byte words; 4 bytes over is a circuit id
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let circuit_id = (public_inputs.len() % 32 == 4).then(|| inputs.u32());
+        let fields = inputs.rest();
+        let Some(verifier) = Self::ultrahonk_verifier(env, circuit_id) else {
+            let reason = match circuit_id {
+                Some(_) => ZkVerifyError::VkNotFound,
+                None => ZkVerifyError::UltraHonkVerifierNotSet,
+            };
+            return at.fail(0, reason);
+        };
+
+        if !matches!(
+            UltraHonkClient::new(env, &verifier).try_verify_proof(&fields, proof),
+            Ok(Ok(()))
+        ) {
+            return at.fail(1, ZkVerifyError::UltraHonkProofRejected);
+        }
+
+        EvVerifySuccess { mode: MODE_ULTRAHONK }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    //  Mode 23: Vector Commitment opening (Okamoto proof / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Vector commitment opening (Mode 23).
+    ///
+    /// A hand of k cards is committed positionally in one point,
+    ///
+    /// ```text
+    /// V = c_0·H_0 + … + c_{k-1}·H_{k-1} + r·H
+    /// ```
+    ///
+    /// with an independent generator per position. The Mode 8 aggregate
+    /// Σ c_i·G + r·H only binds the sum of the cards, so any permutation or
+    /// sum-preserving swap opens it equally well; here a second opening of a
+    /// position would give a discrete-log relation between the H_i.
+    ///
+    /// The proof opens m of the k positions to public cards and shows
+    /// knowledge of the other coefficients and r, i.e. of an opening of
+    /// V − Σ_opened c_j·H_{p_j} over the unopened generators and H. m = 0
+    /// proves V is a well-formed k-card vector; m = k opens all of it.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// V(96, G1) || k(4) || m(4) || [position(4) || card(4)] × m ||
+    /// session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (72 + 32·(k − m) bytes):**
+    /// ```text
+    /// k(4) || m(4) || e(32, Fr) || z_r(32, Fr) || z_i(32, Fr) × (k − m)
+    /// ```
+    ///
+    /// The z_i follow the unopened positions in increasing order.
+    ///
+    /// **Verification:**
+    /// 1. 1 ≤ k ≤ 18, m ≤ k, both repeated in the inputs; V in the G1 subgroup
+    /// 2. Opened positions strictly increasing and below k, cards below 36
+    /// 3. R = z_r·H + Σ_unopened z_i·H_i − e·V + Σ_opened (e·c_j)·H_{p_j}   (one g1_msm)
+    /// 4. e == Fr(keccak256(V || R || k || m || openings || session_id || player || "ZKPK"))
+    pub fn verify_vector_opening(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_VECTOR_COMMIT, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        let mut witness = ProofReader::new(env, proof);
+        let k = witness.u32();
+        let m = witness.u32();
+        if k == 0 || k > VECTOR_MAX_LEN || m > k {
+            return at.fail(1, ZkVerifyError::VectorInvalidStatement);
+        }
+        if proof.len() != VECTOR_PROOF_HEADER_LEN + 32 * (k - m) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        // Minimum public_inputs: V(96) + k(4) + m(4) + openings(8·m) + session_id(4) + player(>=1)
+        if public_inputs.len() <= VECTOR_INPUTS_HEADER_LEN + VECTOR_OPENING_LEN * m + 4 {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let vector = inputs.g1();
+        if inputs.u32() != k || inputs.u32() != m {
+            return at.fail(3, ZkVerifyError::VectorInvalidStatement);
+        }
+        if !bls.g1_is_in_subgroup(&vector) {
+            return at.fail(4, ZkVerifyError::VectorPointNotInSubgroup);
+        }
+
+        let e = witness.fr();
+        let z_r = witness.fr();
+
+        // ── Reconstruct R = z_r·H + Σ z_i·H_i − e·(V − Σ c_j·H_{p_j}) ─────
+        // Walk the positions in order: an opened one contributes e·c_j, an
+        // unopened one the next z_i from the proof.
+        let zero = Self::fr_from_u32(env, 0);
+        let mut points = Vec::from_array(env, [Self::pedersen_h(&bls, env), vector.clone()]);
+        let mut scalars = Vec::from_array(env, [z_r, zero - e.clone()]);
+        let mut next = 0u32;
+        for _ in 0..m {
+            let position = inputs.u32();
+            let card = inputs.u32();
+            if position < next || position >= k || card >= 36 {
+                return at.fail(5, ZkVerifyError::VectorInvalidStatement);
+            }
+            while next < position {
+                points.push_back(Self::vector_generator(&bls, env, next));
+                scalars.push_back(witness.fr());
+                next += 1;
+            }
+            points.push_back(Self::vector_generator(&bls, env, position));
+            scalars.push_back(e.clone() * Self::fr_from_u32(env, card));
+            next = position + 1;
+        }
+        while next < k {
+            points.push_back(Self::vector_generator(&bls, env, next));
+            scalars.push_back(witness.fr());
+            next += 1;
+        }
+        let r_point = bls.g1_msm(points, scalars);
+
+        // ── Fiat-Shamir challenge over V, R and every input after V ─────────
+        let expected_e = FiatShamir::new(env)
+            .g1(&vector)
+            .g1(&r_point)
+            .bytes(&inputs.slice(96..public_inputs.len()))
+            .challenge(&VECTOR_CHALLENGE_TAG);
+        if expected_e != e {
+            return at.fail(6, ZkVerifyError::VectorCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 7, &inputs, false) || !Self::canonical(&at, 8, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_VECTOR_COMMIT }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    //  Mode 24: Linked Card Play (two rings, one challenge / BLS12-381)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Linked card play verification (Mode 24).
+    ///
+    /// Mode 7 proves C = card·G + r·H for some card of the valid set, but
+    /// says nothing about where that card came from. This mode proves, for
+    /// the same C, two OR-statements under one Fiat-Shamir challenge:
+    ///
+    /// ```text
+    /// ∃ i: C − v_i·G = r·H           (the card is in the valid set, as Mode 7)
+    /// ∃ j: C − P_j   = (r − r_j)·H   (the card is hand commitment P_j, as Mode 12)
+    /// ```
+    ///
+    /// where P_j are the hand commitments from the deal (Mode 18). Since P_j
+    /// commits to a dealt card and C − P_j has no G component, the played
+    /// card is one that was dealt to the player and is in the valid set.
+    ///
+    /// **Public inputs layout:**
+    /// ```text
+    /// commit_hash(32) || N(4) || valid_set[N](4 each) || k(4) ||
+    /// P[k](96 each, G1) || session_id(4) || player(var)
+    /// ```
+    ///
+    /// **Proof layout (56 + 64·(N + k) bytes):**
+    /// ```text
+    /// N(4) || k(4) || C(48, compressed G1) ||
+    /// [e_i(32) || z_i(32)] × N || [f_j(32) || y_j(32)] × k
+    /// ```
+    ///
+    /// **Verification:**
+    /// 1. 1 ≤ N, k ≤ 9, N + k ≤ 12, both repeated in the inputs
+    /// 2. keccak256(C) == commit_hash; C and every P_j in the G1 subgroup
+    /// 3. R_i = z_i·H − e_i·(C − v_i·G) and R'_j = y_j·H − f_j·(C − P_j), one 3-point MSM each
+    /// 4. e = Fr(keccak256(C || R_0.. || R'_0.. || N || valid_set || k || P || session_id || player || "ZKPL"))
+    /// 5. Accept iff Σe_i == e and Σf_j == e
+    pub fn verify_linked_play(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_LINKED_PLAY, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        let bls = env.crypto().bls12_381();
+
+        let mut witness = ProofReader::new(env, proof);
+        let n = witness.u32();
+        let k = witness.u32();
+        if n == 0 || n > RING_MAX_SET_SIZE || k == 0 || k > RING_MAX_SET_SIZE || n + k > LINKED_MAX_MEMBERS {
+            return at.fail(1, ZkVerifyError::RingInvalidSetSize);
+        }
+        if witness.g1_len_at(8) != G1_COMPRESSED_LEN || proof.len() != LINKED_PROOF_HEADER_LEN + 64 * (n + k) {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+
+        // commit_hash(32) + N(4) + valid_set(4·N) + k(4) + P(96·k) + session_id(4) + player(>=1)
+        let k_offset = 36 + 4 * n;
+        let sid_offset = k_offset + 4 + 96 * k;
+        if public_inputs.len() <= sid_offset + 4 {
+            return at.fail(2, ZkVerifyError::InputsTooShort);
+        }
+        let inputs = InputsReader::new(env, public_inputs);
+        if inputs.u32_at(32) != n || inputs.u32_at(k_offset) != k {
+            return at.fail(3, ZkVerifyError::RingInvalidSetSize);
+        }
+
+        // ── Commitment and binding check ────────────────────────────────────
+        let Some(commitment) = witness.g1_any() else {
+            return at.fail(4, ZkVerifyError::RingPointNotOnCurve);
+        };
+        if !bls.g1_is_in_subgroup(&commitment) {
+            return at.fail(4, ZkVerifyError::RingPointNotOnCurve);
+        }
+        let c_bytes = Bytes::from_array(env, &commitment.to_bytes().to_array());
+        let computed_commit: BytesN<32> = env.crypto().keccak256(&c_bytes).into();
+        if !Self::digest_eq(&computed_commit, &inputs.digest_at(0)) {
+            return at.fail(5, ZkVerifyError::CommitmentMismatch);
+        }
+
+        let g = Self::g1_generator(env);
+        let h = Self::pedersen_h(&bls, env);
+        let neg_c = -commitment.clone();
+        let zero = Self::fr_from_u32(env, 0);
+
+        // ── Card ring: R_i = z_i·H + e_i·(−C) + (e_i·v_i)·G ────────────────
+        let mut transcript = FiatShamir::new(env);
+        transcript.bytes(&c_bytes);
+        let mut e_sum = zero.clone();
+        for idx in 0..n {
+            let e_i = witness.fr();
+            let z_i = witness.fr();
+            let card_fr = Self::fr_from_u32(env, inputs.u32_at(36 + idx * 4));
+            let r_i = bls.g1_msm(
+                Vec::from_array(env, [h.clone(), neg_c.clone(), g.clone()]),
+                Vec::from_array(env, [z_i, e_i.clone(), e_i.clone() * card_fr]),
+            );
+            transcript.g1(&r_i);
+            e_sum = e_sum + e_i;
+        }
+
+        // ── Hand ring: R'_j = y_j·H + f_j·(−C) + f_j·P_j ───────────────────
+        let mut f_sum = zero;
+        for idx in 0..k {
+            let p_j = inputs.g1_at(k_offset + 4 + idx * 96);
+            if !bls.g1_is_in_subgroup(&p_j) {
+                return at.fail(6, ZkVerifyError::RingPointNotOnCurve);
+            }
+            let f_j = witness.fr();
+            let y_j = witness.fr();
+            let r_j = bls.g1_msm(
+                Vec::from_array(env, [h.clone(), neg_c.clone(), p_j]),
+                Vec::from_array(env, [y_j, f_j.clone(), f_j.clone()]),
+            );
+            transcript.g1(&r_j);
+            f_sum = f_sum + f_j;
+        }
+
+        // ── One challenge for both rings ────────────────────────────────────
+        let e = transcript
+            .bytes(&inputs.slice(32..public_inputs.len()))
+            .challenge(&LINKED_CHALLENGE_TAG);
+        if e_sum != e {
+            return at.fail(7, ZkVerifyError::RingChallengeCheckFailed);
+        }
+        if f_sum != e {
+            return at.fail(8, ZkVerifyError::LinkedHandCheckFailed);
+        }
+
+        // ── Canonical encodings ─────────────────────────────────────────────
+        if !Self::canonical(&at, 9, &witness, false) {
+            return false;
+        }
+
+        EvVerifySuccess { mode: MODE_LINKED_PLAY }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    //  Mode 25: Ed25519 Seed (native host signature check)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Ed25519 seed verification (Mode 25).
+    ///
+    /// Mode 2's statement with a signature in place of the hash response:
+    /// the player commits with their Ed25519 public key as the blinding and
+    /// later signs the full public inputs with the matching secret key. One
+    /// `ed25519_verify` host call replaces every curve operation, so a
+    /// low-end client only has to produce an ordinary Ed25519 signature.
+    ///
+    /// **Public inputs:** `seed_hash(32) || commitment(32) || nullifier(32) || session_id(4) || player(var)`
+    /// **Proof:** `0xED || pk(32) || sig(64)`
+    ///
+    /// Verification:
+    /// 1. Commitment binding: `keccak256(seed_hash || pk || player) == commitment`
+    /// 2. Nullifier check: `keccak256(seed_hash || "NULL" || session_id) == nullifier`
+    /// 3. Entropy: `seed_hash` must satisfy the stored `EntropyPolicy`
+    /// 4. Signature: `sig` is `pk`'s Ed25519 signature of `public_inputs || "ZKPM"`
+    ///
+    /// The signed message covers the session id, the player and any
+    /// trailers, so a signature is never valid for another statement. Step 4
+    /// traps on an invalid signature or key; the host offers no fallible
+    /// check.
+    pub fn verify_ed25519_seed(
+        env: &Env,
+        public_inputs: &Bytes,
+        proof: &Bytes,
+    ) -> bool {
+        let at = Attempt::new(env, MODE_ED25519_SEED, public_inputs, proof);
+        if !at.fresh() {
+            return false;
+        }
+        if proof.len() != ED25519_PROOF_LEN || proof.get_unchecked(0) != ED25519_PROOF_TAG {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        if public_inputs.len() <= NIZK_INPUTS_FIXED_LEN {
+            return at.fail(1, ZkVerifyError::InputsTooShort);
+        }
+
+        // ── Extract public inputs ───────────────────────────────────────────
+        let mut inputs = InputsReader::new(env, public_inputs);
+        let seed_hash: [u8; 32] = inputs.array();
+        let commitment = inputs.digest();
+        let nullifier = inputs.digest();
+        let sid_arr: [u8; 4] = inputs.array();
+        let player_bytes = inputs.rest();
+        let address_len = player_bytes.len() - at.trailer_len();
+        if address_len == 0 {
+            return at.fail(1, ZkVerifyError::EmptyPlayerAddress);
+        }
+
+        // ── Extract proof: tag(1) || pk(32) || sig(64) ─────────────────────
+        let mut witness = ProofReader::at(env, proof, 1);
+        let pk = witness.digest();
+        let sig = BytesN::<64>::from_array(env, &witness.array());
+
+        // ── Step 1: Commitment binding, over the strkey alone as in Mode 2 ──
+        let mut commit_preimage = Bytes::from_array(env, &seed_hash);
+        commit_preimage.append(&pk.clone().into());
+        commit_preimage.append(&player_bytes.slice(..address_len));
+        let computed_commitment: BytesN<32> = env.crypto().keccak256(&commit_preimage).into();
+        if !Self::digest_eq(&computed_commitment, &commitment) {
+            return at.fail(2, ZkVerifyError::CommitmentMismatch);
+        }
+
+        // ── Step 2: Nullifier (session binding) ─────────────────────────────
+        let mut null_preimage = Bytes::from_array(env, &seed_hash);
+        null_preimage.append(&Bytes::from_array(env, &NULLIFIER_TAG));
+        null_preimage.append(&Bytes::from_array(env, &sid_arr));
+        let computed_nullifier: BytesN<32> = env.crypto().keccak256(&null_preimage).into();
+        if !Self::digest_eq(&computed_nullifier, &nullifier) {
+            return at.fail(3, ZkVerifyError::NullifierMismatch);
+        }
+
+        // ── Step 3: Entropy check on seed_hash ──────────────────────────────
+        if Self::entropy_policy(env).check(&seed_hash).is_err() {
+            return at.fail(4, ZkVerifyError::WeakSeedEntropy);
+        }
+
+        // ── Step 4: Signature over the whole statement (traps if invalid) ───
+        let mut message = public_inputs.clone();
+        message.append(&Bytes::from_array(env, &ED25519_CHALLENGE_TAG));
+        env.crypto().ed25519_verify(&pk, &message, &sig);
+
+        EvVerifySuccess { mode: MODE_ED25519_SEED }.publish(env);
+        true
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    //  Mode 26: Trick bundle (both players' plays in one call)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Trick bundle verification (Mode 26).
+    ///
+    /// Verifies two Mode 7 or Mode 8 proofs for one trick, normally the lead
+    /// and the follow, in a single call, so a relayer submits one
+    /// transaction per trick. Part A is an ordinary Mode 7/8 proof. Part B
+    /// extends the shared transcript: its challenge absorbs the digest of
+    /// part A and finishes with "ZKPN", so it only verifies next to that
+    /// exact A. The follower already sees the lead before playing, so it
+    /// proves alone, with no extra round.
+    ///
+    /// **Public inputs:** `mode_a(4) || len_a(4) || inputs_a || mode_b(4) || inputs_b`
+    /// **Proof:** `len_a(4) || proof_a || proof_b`, only accepted tagged
+    ///
+    /// Verification:
+    /// 1. Both parts are Mode 7 (not hidden-set) or Mode 8, for one session
+    ///    and two different players
+    /// 2. Part A verifies as its mode does alone
+    /// 3. Part B verifies with `link = keccak256(mode_a || len_a || inputs_a || proof_a)`
+    ///    appended to its preimage after any network id, and "ZKPN" in
+    ///    place of its domain tag
+    ///
+    /// H and P_pad are derived once for both parts. A failing part reports
+    /// its own `EvVerifyFailed`; each part counts in the verification log
+    /// under its own mode.
+    pub fn verify_trick_bundle(env: &Env, public_inputs: &Bytes, proof: &Bytes) -> bool {
+        Self::trick_bundle(&Attempt::new(env, MODE_TRICK_BUNDLE, public_inputs, proof))
+    }
+
+    fn trick_bundle(at: &Attempt) -> bool {
+        let (env, public_inputs, proof) = (at.env, at.public_inputs, at.proof);
+
+        // ── Split the proof: len_a(4) || proof_a || proof_b ────────────────
+        let witness = ProofReader::new(env, proof);
+        let Some(split) = TRICK_PROOF_HEADER_LEN.checked_add(witness.u32_at(0)) else {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        };
+        if proof.len() < TRICK_PROOF_HEADER_LEN || split > proof.len() {
+            return at.fail(0, ZkVerifyError::ProofWrongLength);
+        }
+        let proof_a = proof.slice(TRICK_PROOF_HEADER_LEN..split);
+        let proof_b = proof.slice(split..);
+
+        // ── Split the inputs: mode_a(4) || len_a(4) || inputs_a || mode_b(4) || inputs_b
+        let inputs = InputsReader::new(env, public_inputs);
+        if inputs.len() < TRICK_INPUTS_HEADER_LEN {
+            return at.fail(1, ZkVerifyError::TrickInvalidStatement);
+        }
+        let end_a = match TRICK_INPUTS_HEADER_LEN.checked_add(inputs.u32_at(4)) {
+            Some(end) if end.checked_add(4).is_some_and(|b| b <= inputs.len()) => end,
+            _ => return at.fail(1, ZkVerifyError::TrickInvalidStatement),
+        };
+        let (mode_a, mode_b) = (inputs.u32_at(0), inputs.u32_at(end_a));
+        let inputs_a = inputs.slice(TRICK_INPUTS_HEADER_LEN..end_a);
+        let inputs_b = inputs.slice(end_a + 4..inputs.len());
+        let part_mode_ok = |mode: u32, part: &Bytes| match mode {
+            MODE_CARD_RING => Self::ring_mode(part) == MODE_CARD_RING,
+            MODE_CANGKUL_HAND => true,
+            _ => false,
+        };
+        if !part_mode_ok(mode_a, &inputs_a) || !part_mode_ok(mode_b, &inputs_b) {
+            return at.fail(1, ZkVerifyError::TrickInvalidStatement);
+        }
+
+        // ── One session, two players ────────────────────────────────────────
+        let bls = env.crypto().bls12_381();
+        let setup = SharedSetup { h: Self::pedersen_h(&bls, env), pad: RefCell::new(None) };
+        let link = {
+            let mut data = inputs.slice(0..end_a);
+            data.append(&proof_a);
+            env.crypto().keccak256(&data).into()
+        };
+        let part_a = Attempt::new(env, mode_a, &inputs_a, &proof_a).shared(&setup);
+        let part_b = Attempt::new(env, mode_b, &inputs_b, &proof_b).shared(&setup).linked(link);
+        let (session_a, session_b) = (part_a.session_id(), part_b.session_id());
+        let (player_a, player_b) = (part_a.player(), part_b.player());
+        if session_a.is_none() || session_a != session_b || player_a.is_none() || player_b.is_none() || player_a == player_b {
+            return at.fail(2, ZkVerifyError::TrickInvalidStatement);
+        }
+
+        // ── Both parts, B chained onto A ────────────────────────────────────
+        for part in [&part_a, &part_b] {
+            let ok = match part.mode {
+                MODE_CARD_RING => Self::card_play_ring(part),
+                _ => Self::cangkul_hand(part),
+            };
+            if !ok {
+                return false;
+            }
+        }
+        Self::log_verification(&part_a);
+        Self::log_verification(&part_b);
+
+        EvVerifySuccess { mode: MODE_TRICK_BUNDLE }.publish(env);
+        true
+    }
+}
+
+/// Tests through the contract client; the `lib` build has no exports.
+#[cfg(all(test, not(feature = "lib")))]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, testutils::Events as _, testutils::Ledger as _, vec, Address, Env, Event as _};
+    use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
+    use cangkulan_prover::{fr, g1_generator, hand, nizk, pedersen, pedersen_h, ring, tag_proof, trick, vectors, PlayerTail};
+
+
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  NIZK seed proof helpers (new!)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Compute seed_hash = keccak256(seed)
+    fn compute_seed_hash(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+        nizk::seed_hash(env, seed)
+    }
+
+    /// Compute blinded commitment = keccak256(seed_hash || blinding || player_address)
+    fn compute_nizk_commitment(env: &Env, seed_hash: &BytesN<32>, blinding: &BytesN<32>, player: &Address) -> BytesN<32> {
+        nizk::commitment(env, seed_hash, blinding, &PlayerTail::new(player))
+    }
+
+    /// Compute nullifier = keccak256(seed_hash || "NULL" || session_id_be4)
+    fn compute_nullifier(env: &Env, seed_hash: &BytesN<32>, session_id: u32) -> BytesN<32> {
+        nizk::nullifier(env, seed_hash, session_id)
+    }
+
+    /// Compute Fiat-Shamir challenge = keccak256(commitment || session_id_be4 || player || "ZKV2")
+    fn compute_fs_challenge(env: &Env, commitment: &BytesN<32>, session_id: u32, player: &Address) -> BytesN<32> {
+        nizk::challenge(env, commitment, session_id, &PlayerTail::new(player))
+    }
+
+    /// Compute response = keccak256(seed_hash || challenge || blinding)
+    fn compute_response(
+        env: &Env,
+        seed_hash: &BytesN<32>,
+        challenge: &BytesN<32>,
+        blinding: &BytesN<32>,
+        player: &Address,
+    ) -> BytesN<32> {
+        nizk::response(env, seed_hash, challenge, blinding, &PlayerTail::new(player))
+    }
+
+    /// Build NIZK public inputs
+    fn encode_nizk_public_inputs(
+        env: &Env,
+        seed_hash: &BytesN<32>,
+        commitment: &BytesN<32>,
+        nullifier: &BytesN<32>,
+        session_id: u32,
+        player: &Address,
+    ) -> Bytes {
+        nizk::public_inputs(env, seed_hash, commitment, nullifier, session_id, &PlayerTail::new(player))
+    }
+
+    /// Build NIZK proof (blinding || response)
+    fn encode_nizk_proof(env: &Env, blinding: &BytesN<32>, response: &BytesN<32>) -> Bytes {
+        nizk::proof(env, blinding, response)
+    }
+
+    /// Full NIZK proof generation helper (mirrors client-side logic)
+    fn generate_nizk_proof(
+        env: &Env,
+        seed: &BytesN<32>,
+        blinding: &BytesN<32>,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes, BytesN<32>) {
+        nizk::prove(env, seed, blinding, session_id, &PlayerTail::new(player))
+    }
+
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  NIZK seed proof tests (new!)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_nizk_seed_valid_proof() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[
+            0xAA,0xBB,0xCC,0xDD,0x11,0x22,0x33,0x44,
+            0x55,0x66,0x77,0x88,0x99,0x00,0xEE,0xFF,
+            0x12,0x34,0x56,0x78,0x9A,0xBC,0xDE,0xF0,
+            0x13,0x57,0x9B,0xDF,0x24,0x68,0xAC,0xE0,
+        ]);
+        let session_id: u32 = 12345;
+
+        let (public_inputs, proof, _commitment) = generate_nizk_proof(
+            &env, &seed, &blinding, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_nizk_seed_wrong_blinding_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let real_blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+        let wrong_blinding = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
+        let session_id: u32 = 42;
+
+        // Generate proof with real blinding
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &real_blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+
+        // But put wrong blinding in the proof
+        let challenge = compute_fs_challenge(&env, &commitment, session_id, &player);
+        let wrong_response = compute_response(&env, &seed_hash, &challenge, &wrong_blinding, &player);
+
+        let public_inputs = encode_nizk_public_inputs(
+            &env, &seed_hash, &commitment, &nullifier, session_id, &player,
+        );
+        let proof = encode_nizk_proof(&env, &wrong_blinding, &wrong_response);
+
+        // Commitment check will fail (wrong blinding doesn't match commitment)
+        assert!(!client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_nizk_seed_wrong_session_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xCCu8; 32]);
+        let real_session = 100u32;
+        let fake_session = 999u32;
+
+        // Generate proof for real session
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, real_session);
+
+        // Tamper: replace session_id with fake_session but keep old nullifier
+        let mut buf = Bytes::from_array(&env, &seed_hash.to_array());
+        buf.append(&Bytes::from_array(&env, &commitment.to_array()));
+        buf.append(&Bytes::from_array(&env, &nullifier.to_array()));
+        buf.append(&Bytes::from_array(&env, &fake_session.to_be_bytes())); // tampered!
+        buf.append(&player.to_string().to_bytes());
+
+        let challenge = compute_fs_challenge(&env, &commitment, real_session, &player);
+        let response = compute_response(&env, &seed_hash, &challenge, &blinding, &player);
+        let proof = encode_nizk_proof(&env, &blinding, &response);
+
+        // Nullifier check will fail
+        assert!(!client.verify(&buf, &proof));
+    }
+
+    #[test]
+    fn test_verify_failure_events_carry_context() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[7u8; 32]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xCCu8; 32]);
+        let (inputs, proof, _) = generate_nizk_proof(&env, &seed, &blinding, 41, &player);
+        let mut tampered = inputs.slice(0..96);
+        tampered.append(&Bytes::from_array(&env, &42u32.to_be_bytes()));
+        tampered.append(&inputs.slice(100..));
+        assert!(!client.verify(&tampered, &proof));
+
+        // Nullifier mismatch is the third check of Mode 2
+        let attempt = EvVerifyAttempt {
+            mode: MODE_NIZK_SEED,
+            session_id: Some(42),
+            inputs_len: tampered.len(),
+            proof_len: NIZK_PROOF_LEN,
+        };
+        let failed = EvVerifyFailed {
+            reason: ZkVerifyError::NullifierMismatch as u32,
+            mode: MODE_NIZK_SEED,
+            session_id: Some(42),
+            inputs_len: tampered.len(),
+            proof_len: NIZK_PROOF_LEN,
+            check: 3,
+        };
+        assert_eq!(
+            env.events().all().filter_by_contract(&verifier_id),
+            vec![
+                &env,
+                (verifier_id.clone(), attempt.topics(&env), attempt.data(&env)),
+                (verifier_id.clone(), failed.topics(&env), failed.data(&env)),
+            ]
+        );
+
+        // Unmatched lengths fail before any mode is chosen
+        assert!(!client.verify(&tampered, &Bytes::from_array(&env, &[0u8; 3])));
+        let failed = EvVerifyFailed {
+            reason: ZkVerifyError::ProofWrongLength as u32,
+            mode: 0,
+            session_id: None,
+            inputs_len: tampered.len(),
+            proof_len: 3,
+            check: 0,
+        };
+        let attempt = EvVerifyAttempt { mode: 0, session_id: None, inputs_len: tampered.len(), proof_len: 3 };
+        assert_eq!(
+            env.events().all().filter_by_contract(&verifier_id),
+            vec![
+                &env,
+                (verifier_id.clone(), attempt.topics(&env), attempt.data(&env)),
+                (verifier_id.clone(), failed.topics(&env), failed.data(&env)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nizk_seed_different_player_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xDDu8; 32]);
+        let session_id: u32 = 50;
+
+        // Generate valid proof for player1
+        let (_public_inputs, proof, _) = generate_nizk_proof(
+            &env, &seed, &blinding, session_id, &player1,
+        );
+
+        // Replace player address with player2
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player1);
+        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+
+        let mut tampered = Bytes::from_array(&env, &seed_hash.to_array());
+        tampered.append(&Bytes::from_array(&env, &commitment.to_array()));
+        tampered.append(&Bytes::from_array(&env, &nullifier.to_array()));
+        tampered.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
+        tampered.append(&player2.to_string().to_bytes()); // wrong player!
+
+        // Commitment check will fail (commitment is bound to player1)
+        assert!(!client.verify(&tampered, &proof));
+    }
+
+    #[test]
+    fn test_nizk_seed_tampered_response_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xEEu8; 32]);
+        let session_id: u32 = 77;
+
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+
+        let public_inputs = encode_nizk_public_inputs(
+            &env, &seed_hash, &commitment, &nullifier, session_id, &player,
+        );
+
+        // Use correct blinding but fake response
+        let fake_response = BytesN::<32>::from_array(&env, &[0x42u8; 32]);
+        let proof = encode_nizk_proof(&env, &blinding, &fake_response);
+
+        // Response check will fail (but commitment passes, so response check catches it)
+        assert!(!client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_nizk_response_mismatch_cost_independent_of_position() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xEEu8; 32]);
+        let (public_inputs, proof, _commitment) = generate_nizk_proof(
+            &env, &seed, &blinding, 78, &player,
+        );
+
+        // Metered cost of rejecting a response that differs in its first
+        // vs. its last byte must be identical in every build.
+        let mut first = proof.clone();
+        first.set(32, proof.get(32).unwrap() ^ 1);
+        let mut last = proof.clone();
+        last.set(63, proof.get(63).unwrap() ^ 1);
+
+        env.cost_estimate().budget().reset_default();
+        assert!(!client.verify(&public_inputs, &first));
+        let cost_first = env.cost_estimate().budget().cpu_instruction_cost();
+
+        env.cost_estimate().budget().reset_default();
+        assert!(!client.verify(&public_inputs, &last));
+        let cost_last = env.cost_estimate().budget().cpu_instruction_cost();
+
+        assert_eq!(cost_first, cost_last);
+    }
+
+    #[test]
+    fn test_nizk_multiple_sessions_same_seed() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[
+            10,20,30,40,50,60,70,80,90,100,110,120,130,140,150,160,
+            170,180,190,200,210,220,230,240,250,5,15,25,35,45,55,65,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0x99u8; 32]);
+
+        // Same seed, different sessions — all should verify independently
+        for sid in [1u32, 2, 100, 999] {
+            let (public_inputs, proof, _) = generate_nizk_proof(
+                &env, &seed, &blinding, sid, &player,
+            );
+            assert!(client.verify(&public_inputs, &proof), "Session {} should verify", sid);
+        }
+    }
+
+    #[test]
+    fn test_nizk_entropy_policy_is_configurable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        assert_eq!(client.get_entropy_policy(), EntropyPolicy::default());
+
+        // Period-4 seed hash: passes the default 4-distinct-bytes rule.
+        let mut periodic = [0u8; 32];
+        for (i, b) in periodic.iter_mut().enumerate() {
+            *b = (i % 4) as u8 + 1;
+        }
+        let seed_hash = BytesN::<32>::from_array(&env, &periodic);
+        let blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+        let player = Address::generate(&env);
+        let session_id = 7u32;
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+        let challenge = compute_fs_challenge(&env, &commitment, session_id, &player);
+        let response = compute_response(&env, &seed_hash, &challenge, &blinding, &player);
+        let public_inputs = encode_nizk_public_inputs(
+            &env, &seed_hash, &commitment, &nullifier, session_id, &player,
+        );
+        let proof = encode_nizk_proof(&env, &blinding, &response);
+        assert!(client.verify(&public_inputs, &proof));
+
+        let invalid = EntropyPolicy { max_period: 17, ..EntropyPolicy::default() };
+        assert_eq!(
+            client.try_set_entropy_policy(&invalid),
+            Err(Ok(ZkVerifyError::InvalidEntropyPolicy))
+        );
+
+        let strict = EntropyPolicy { max_period: 4, ..EntropyPolicy::default() };
+        client.set_entropy_policy(&strict);
+        assert_eq!(client.get_entropy_policy(), strict);
+        assert!(!client.verify(&public_inputs, &proof));
+    }
+
+    fn sample_nizk_proof(env: &Env) -> (Bytes, Bytes) {
+        let seed = BytesN::<32>::from_array(env, &[
+            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
+            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
+        ]);
+        let blinding = BytesN::<32>::from_array(env, &[0xAAu8; 32]);
+        let (public_inputs, proof, _) =
+            generate_nizk_proof(env, &seed, &blinding, 99, &Address::generate(env));
+        (public_inputs, proof)
+    }
+
+    #[test]
+    fn test_mode_can_be_disabled_by_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let (public_inputs, proof) = sample_nizk_proof(&env);
+        assert!(client.is_mode_enabled(&MODE_NIZK_SEED));
+        assert!(client.verify(&public_inputs, &proof));
+
+        client.set_mode_enabled(&MODE_NIZK_SEED, &false);
+        assert!(!client.is_mode_enabled(&MODE_NIZK_SEED));
+        assert!(client.is_mode_enabled(&MODE_PEDERSEN_SEED));
+        assert!(!client.verify(&public_inputs, &proof));
+
+        client.set_mode_enabled(&MODE_NIZK_SEED, &true);
+        assert!(client.verify(&public_inputs, &proof));
+
+        assert_eq!(client.try_set_mode_enabled(&3, &false), Err(Ok(ZkVerifyError::UnknownMode)));
+        assert!(!client.is_mode_enabled(&3));
+    }
+
+    #[test]
+    fn test_verify_debug_reports_sub_checks() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let names = |report: &VerifyReport| -> Vec<Symbol> {
+            let mut names = Vec::new(&env);
+            for check in report.checks.iter() {
+                names.push_back(check.name);
+            }
+            names
+        };
+
+        let (public_inputs, proof) = sample_nizk_proof(&env);
+        let report = client.verify_debug(&public_inputs, &proof);
+        assert!(report.valid);
+        assert_eq!(report.mode, MODE_NIZK_SEED);
+        assert_eq!((report.failed_check, report.reason), (None, None));
+        assert_eq!(names(&report), vec![
+            &env,
+            symbol_short!("binding"), symbol_short!("nullifier"), symbol_short!("challenge"),
+            symbol_short!("response"), symbol_short!("entropy"),
+        ]);
+        assert!(report.checks.iter().all(|c| c.passed));
+        // The binding digest is keccak256 of the recomputed commitment
+        let commitment = public_inputs.slice(32..64);
+        assert_eq!(report.checks.get(0).unwrap().digest, env.crypto().keccak256(&commitment).to_bytes());
+
+        // A bad response stops at the response check
+        let mut bad = proof.clone();
+        bad.set(63, bad.get(63).unwrap() ^ 1);
+        let report = client.verify_debug(&public_inputs, &bad);
+        assert!(!report.valid);
+        assert_eq!(report.failed_check, Some(4));
+        assert_eq!(report.reason, Some(ZkVerifyError::ResponseMismatch as u32));
+        let last = report.checks.last().unwrap();
+        assert_eq!((last.name, last.passed), (symbol_short!("response"), false));
+        assert_eq!(report.checks.len(), 4);
+
+        // Mode 7 with a commitment that does not match commit_hash
+        let player = Address::generate(&env);
+        let blinding = BytesN::<32>::from_array(&env, &[7u8; 32]);
+        let (commit_hash, proof) = build_ring_sigma_proof(&env, 3, &blinding, &[1, 3, 5], 12, &player);
+        let inputs = build_ring_public_inputs(&env, &commit_hash, &[1, 3, 5], 12, &player);
+        let report = client.verify_debug(&inputs, &proof);
+        assert!(report.valid);
+        assert_eq!(names(&report), vec![&env, symbol_short!("subgroup"), symbol_short!("binding"), symbol_short!("challenge")]);
+        let wrong = build_ring_public_inputs(&env, &BytesN::from_array(&env, &[0u8; 32]), &[1, 3, 5], 12, &player);
+        let report = client.verify_debug(&wrong, &proof);
+        assert_eq!(report.reason, Some(ZkVerifyError::CommitmentMismatch as u32));
+        assert_eq!(names(&report), vec![&env, symbol_short!("subgroup"), symbol_short!("binding")]);
+
+        // Only the sigma modes report
+        let noir = Bytes::from_array(&env, &[0u8; ULTRAHONK_MIN_PROOF_LEN as usize + 32]);
+        assert_eq!(client.try_verify_debug(&public_inputs, &noir), Err(Ok(ZkVerifyError::NoDebugReport)));
+    }
+
+    #[test]
+    fn test_caller_allowlist_gates_verification() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let (public_inputs, proof) = sample_nizk_proof(&env);
+        let game = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        // Disabled by default: anyone may verify, with or without a caller.
+        assert!(!client.is_allowlist_enabled());
+        assert!(client.verify(&public_inputs, &proof));
+        assert!(client.verify_from(&stranger, &public_inputs, &proof));
+
+        client.set_allowlist_enabled(&true);
+        client.set_caller_allowed(&game, &true);
+        assert!(client.is_caller_allowed(&game));
+        assert!(!client.is_caller_allowed(&stranger));
+        assert!(!client.verify(&public_inputs, &proof));
+        assert!(!client.verify_from(&stranger, &public_inputs, &proof));
+        assert!(client.verify_from(&game, &public_inputs, &proof));
+
+        client.set_caller_allowed(&game, &false);
+        assert!(!client.verify_from(&game, &public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_network_binding_rejects_other_networks() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_network_id([0x7E; 32]);
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let player = Address::generate(&env);
+
+        // Unbound provers for Modes 2, 4, 7 and 8
+        let (nizk_inputs, nizk_proof) = sample_nizk_proof(&env);
+        let pedersen = generate_pedersen_sigma_proof(
+            &env, &fr_u32(&env, 11), &fr_u32(&env, 12), &fr_u32(&env, 13), 5, &player,
+        );
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 7; arr
+        });
+        let (commit_hash, ring_proof) = build_ring_sigma_proof(&env, 10, &blinding, &[9, 10, 11], 5, &player);
+        let ring = (build_ring_public_inputs(&env, &commit_hash, &[9, 10, 11], 5, &player), ring_proof);
+        let hand = [9u32, 10, 27];
+        let (commit_hash, hand_proof) =
+            build_cangkul_hand_proof(&env, &hand, &[[1u8; 32], [2u8; 32], [3u8; 32]], 0, 5, &player);
+        let hand = (build_cangkul_public_inputs(&env, &commit_hash, 0, &hand, 5, &player), hand_proof);
+        let unbound = [(nizk_inputs, nizk_proof), pedersen, ring, hand];
+
+        assert!(!client.is_network_binding_enabled());
+        for (inputs, proof) in unbound.iter() {
+            assert!(client.verify(inputs, proof));
+        }
+
+        client.set_network_binding(&true);
+        assert!(client.is_network_binding_enabled());
+        for (inputs, proof) in unbound.iter() {
+            assert!(!client.verify(inputs, proof), "unbound proof must fail once bound");
+        }
+
+        // A Mode 2 prover that appends the network id before "ZKV2"
+        let seed = BytesN::<32>::from_array(&env, &[0x42; 32]);
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, 5);
+        let mut pre = Bytes::from_array(&env, &commitment.to_array());
+        pre.append(&Bytes::from_array(&env, &5u32.to_be_bytes()));
+        pre.append(&player.to_string().to_bytes());
+        pre.append(&Bytes::from_array(&env, &[0x7E; 32]));
+        pre.append(&Bytes::from_array(&env, &CHALLENGE_TAG));
+        let challenge: BytesN<32> = env.crypto().keccak256(&pre).into();
+        let response = compute_response(&env, &seed_hash, &challenge, &blinding, &player);
+        let inputs = encode_nizk_public_inputs(&env, &seed_hash, &commitment, &nullifier, 5, &player);
+        let proof = encode_nizk_proof(&env, &blinding, &response);
+        assert!(client.verify(&inputs, &proof), "bound proof should verify on its network");
+
+        // The same proof replayed on a network with a different passphrase
+        env.ledger().set_network_id([0x5A; 32]);
+        assert!(!client.verify(&inputs, &proof));
+
+        env.ledger().set_network_id([0x7E; 32]);
+        client.set_network_binding(&false);
+        assert!(!client.verify(&inputs, &proof));
+        assert!(client.verify(&unbound[0].0, &unbound[0].1));
+    }
+
+    #[test]
+    fn test_ledger_window_expires_proofs() {
+        let env = Env::default();
+        env.ledger().set_sequence_number(1_000);
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let player = Address::generate(&env);
+
+        // Mode 2 prover with a window: the challenge covers player || window,
+        // the commitment only the player
+        let window = |until: u32| {
+            let mut trailer = Bytes::from_array(&env, &LEDGER_WINDOW_TAG);
+            trailer.append(&Bytes::from_array(&env, &until.to_be_bytes()));
+            trailer
+        };
+        let seed_hash = compute_seed_hash(&env, &BytesN::from_array(&env, &[0x42; 32]));
+        let blinding = BytesN::<32>::from_array(&env, &[0xAB; 32]);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, 8);
+        let mut pre = Bytes::from_array(&env, &commitment.to_array());
+        pre.append(&Bytes::from_array(&env, &8u32.to_be_bytes()));
+        pre.append(&player.to_string().to_bytes());
+        pre.append(&window(1_010));
+        pre.append(&Bytes::from_array(&env, &CHALLENGE_TAG));
+        let challenge: BytesN<32> = env.crypto().keccak256(&pre).into();
+        let response = compute_response(&env, &seed_hash, &challenge, &blinding, &player);
+        let unwindowed = encode_nizk_public_inputs(&env, &seed_hash, &commitment, &nullifier, 8, &player);
+        let mut inputs = unwindowed.clone();
+        inputs.append(&window(1_010));
+        let proof = encode_nizk_proof(&env, &blinding, &response);
+
+        assert!(client.verify(&inputs, &proof));
+        env.ledger().set_sequence_number(1_010);
+        assert!(client.verify(&inputs, &proof), "valid through valid_until_ledger");
+
+        env.ledger().set_sequence_number(1_011);
+        assert!(!client.verify(&inputs, &proof));
+        let failed = EvVerifyFailed {
+            reason: ZkVerifyError::ProofExpired as u32,
+            mode: MODE_NIZK_SEED,
+            session_id: Some(8),
+            inputs_len: inputs.len(),
+            proof_len: NIZK_PROOF_LEN,
+            check: 0,
+        };
+        let events = env.events().all().filter_by_contract(&verifier_id);
+        assert_eq!(events.events().last().unwrap(), &failed.to_xdr(&env, &verifier_id));
+
+        // Stripping or extending the window changes the challenge
+        assert!(!client.verify(&unwindowed, &proof));
+        let mut extended = unwindowed.clone();
+        extended.append(&window(2_000));
+        assert!(!client.verify(&extended, &proof));
+
+        // Appending a window to an existing Mode 4 proof breaks it as well
+        let (ped_inputs, ped_proof) = generate_pedersen_sigma_proof(
+            &env, &fr_u32(&env, 21), &fr_u32(&env, 22), &fr_u32(&env, 23), 8, &player,
+        );
+        assert!(client.verify(&ped_inputs, &ped_proof));
+        let mut ped_windowed = ped_inputs.clone();
+        ped_windowed.append(&window(5_000));
+        assert!(!client.verify(&ped_windowed, &ped_proof));
+    }
+
+    #[test]
+    fn test_sha256_transcript_selected_by_tag() {
+        let env = Env::default();
+        env.ledger().set_sequence_number(1_000);
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let player = Address::generate(&env);
+        let selector = |hash_id: u8| {
+            let mut trailer = Bytes::from_array(&env, &TRANSCRIPT_HASH_TAG);
+            trailer.push_back(hash_id);
+            trailer
+        };
+
+        // Mode 7 with a sha256 challenge, followed by a ledger window
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [9u32, 11, 14];
+        let tail = PlayerTail::new(&player).sha256().valid_until(1_010);
+        let (commit_hash, proof) =
+            ring::prove(&env, 11, &blinding.to_array(), &valid_set, &TEST_NONCE, 5, &tail).unwrap();
+        let inputs = ring::public_inputs(&env, &commit_hash, &valid_set, 5, &tail);
+        assert!(client.verify(&inputs, &proof));
+        env.ledger().set_sequence_number(1_011);
+        assert!(!client.verify(&inputs, &proof), "the window still applies");
+        env.ledger().set_sequence_number(1_000);
+
+        // Dropping the selector falls back to keccak256 and breaks the proof
+        let (_, keccak_proof) = build_ring_sigma_proof(&env, 11, &blinding, &valid_set, 5, &player);
+        let plain = build_ring_public_inputs(&env, &commit_hash, &valid_set, 5, &player);
+        assert!(client.verify(&plain, &keccak_proof));
+        assert!(!client.verify(&plain, &proof));
+
+        // Mode 2: sha256 challenge and response; commitment and nullifier
+        // stay keccak256
+        let seed_hash = compute_seed_hash(&env, &BytesN::from_array(&env, &[0x42; 32]));
+        let nizk_blinding = BytesN::<32>::from_array(&env, &[0xAB; 32]);
+        let commitment = compute_nizk_commitment(&env, &seed_hash, &nizk_blinding, &player);
+        let nullifier = compute_nullifier(&env, &seed_hash, 8);
+        let mut pre = Bytes::from_array(&env, &commitment.to_array());
+        pre.append(&Bytes::from_array(&env, &8u32.to_be_bytes()));
+        pre.append(&player.to_string().to_bytes());
+        pre.append(&selector(TRANSCRIPT_HASH_SHA256));
+        pre.append(&Bytes::from_array(&env, &CHALLENGE_TAG));
+        let challenge: BytesN<32> = env.crypto().sha256(&pre).into();
+        let mut resp_pre = Bytes::from_array(&env, &seed_hash.to_array());
+        resp_pre.append(&Bytes::from_array(&env, &challenge.to_array()));
+        resp_pre.append(&Bytes::from_array(&env, &nizk_blinding.to_array()));
+        let response: BytesN<32> = env.crypto().sha256(&resp_pre).into();
+        let mut nizk_inputs = encode_nizk_public_inputs(&env, &seed_hash, &commitment, &nullifier, 8, &player);
+        nizk_inputs.append(&selector(TRANSCRIPT_HASH_SHA256));
+        let nizk_proof = encode_nizk_proof(&env, &nizk_blinding, &response);
+        assert!(client.verify(&nizk_inputs, &nizk_proof));
+
+        // An undefined hash id is rejected before any hashing
+        let mut unknown = encode_nizk_public_inputs(&env, &seed_hash, &commitment, &nullifier, 8, &player);
+        unknown.append(&selector(7));
+        assert!(!client.verify(&unknown, &nizk_proof));
+        let failed = EvVerifyFailed {
+            reason: ZkVerifyError::UnknownTranscriptHash as u32,
+            mode: MODE_NIZK_SEED,
+            session_id: Some(8),
+            inputs_len: unknown.len(),
+            proof_len: NIZK_PROOF_LEN,
+            check: 0,
+        };
+        let events = env.events().all().filter_by_contract(&verifier_id);
+        assert_eq!(events.events().last().unwrap(), &failed.to_xdr(&env, &verifier_id));
+    }
+
+    #[test]
+    fn test_verify_and_record_consumed_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let (public_inputs, proof) = sample_nizk_proof(&env);
+        let game = Address::generate(&env);
+        let player = Address::generate(&env);
+        let commitment: BytesN<32> = env.crypto().keccak256(&public_inputs).into();
+
+        // A failed proof records nothing.
+        let mut bad_proof = proof.clone();
+        bad_proof.set(0, bad_proof.get(0).unwrap() ^ 1);
+        assert!(!client.verify_and_record(&game, &5, &player, &public_inputs, &bad_proof));
+        assert!(!client.consume_verification(&game, &5, &player, &MODE_NIZK_SEED, &commitment));
+
+        assert!(client.verify_and_record(&game, &5, &player, &public_inputs, &proof));
+
+        // Bound to the consumer, session, player, mode and statement.
+        let other = Address::generate(&env);
+        let wrong: BytesN<32> = BytesN::from_array(&env, &[7u8; 32]);
+        assert!(!client.consume_verification(&other, &5, &player, &MODE_NIZK_SEED, &commitment));
+        assert!(!client.consume_verification(&game, &6, &player, &MODE_NIZK_SEED, &commitment));
+        assert!(!client.consume_verification(&game, &5, &other, &MODE_NIZK_SEED, &commitment));
+        assert!(!client.consume_verification(&game, &5, &player, &MODE_PEDERSEN_SEED, &commitment));
+        assert!(!client.consume_verification(&game, &5, &player, &MODE_NIZK_SEED, &wrong));
+
+        assert!(client.consume_verification(&game, &5, &player, &MODE_NIZK_SEED, &commitment));
+        assert!(!client.consume_verification(&game, &5, &player, &MODE_NIZK_SEED, &commitment));
+
+        // With the allowlist on, only allowlisted consumers can get records.
+        client.set_allowlist_enabled(&true);
+        assert!(!client.verify_and_record(&game, &5, &player, &public_inputs, &proof));
+        client.set_caller_allowed(&game, &true);
+        assert!(client.verify_and_record(&game, &5, &player, &public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_verification_log_counts_per_player_and_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &[0x42u8; 32]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
+        let (alice_inputs, alice_proof, _) = generate_nizk_proof(&env, &seed, &blinding, 7, &alice);
+        let (bob_inputs, bob_proof, _) = generate_nizk_proof(&env, &seed, &blinding, 7, &bob);
+        let (other_inputs, other_proof, _) = generate_nizk_proof(&env, &seed, &blinding, 8, &alice);
+        assert_eq!(client.get_verification_log(&7).len(), 0);
+
+        // Failures are not counted.
+        let mut bad_proof = alice_proof.clone();
+        bad_proof.set(0, bad_proof.get(0).unwrap() ^ 1);
+        assert!(!client.verify(&alice_inputs, &bad_proof));
+        assert_eq!(client.get_verification_log(&7).len(), 0);
+
+        // Every entrypoint counts, per (player, mode) within the session.
+        assert!(client.verify(&alice_inputs, &alice_proof));
+        assert!(client.verify_and_record(&Address::generate(&env), &7, &alice, &alice_inputs, &alice_proof));
+        assert!(client.verify(&bob_inputs, &bob_proof));
+        assert!(client.verify(&other_inputs, &other_proof));
+        let valid_set = [3u32, 5, 9];
+        let card_blinding = BytesN::<32>::from_array(&env, &[0x11u8; 32]);
+        let (commit_hash, ring_proof) = build_ring_sigma_proof(&env, 5, &card_blinding, &valid_set, 7, &alice);
+        let ring_inputs = RingInputs {
+            commit_hash,
+            valid_set: Vec::from_slice(&env, &valid_set),
+            session_id: 7,
+            player: alice.clone(),
+        };
+        assert!(client.verify_ring(&ring_inputs, &ring_proof));
+
+        let entry = |player: &Address, mode: u32, count: u32| VerificationCount {
+            player: player.to_string().to_bytes(),
+            mode,
+            count,
+        };
+        assert_eq!(
+            client.get_verification_log(&7),
+            vec![
+                &env,
+                entry(&alice, MODE_NIZK_SEED, 2),
+                entry(&bob, MODE_NIZK_SEED, 1),
+                entry(&alice, MODE_CARD_RING, 1),
+            ]
+        );
+        assert_eq!(client.get_verification_log(&8), vec![&env, entry(&alice, MODE_NIZK_SEED, 1)]);
+    }
+
+    #[test]
+    fn test_chunked_upload_verifies_and_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        client.set_ultrahonk_verifier(&env.register(MockUltraHonk, ()));
+        let game = Address::generate(&env);
+        let player = Address::generate(&env);
+        let public_inputs = Bytes::from_array(&env, &[0u8; 32 * 32]);
+        let commitment: BytesN<32> = env.crypto().keccak256(&public_inputs).into();
+        let proof = noir_proof(&env, 1);
+        let len = proof.len();
+
+        // Nothing to continue or finish before begin_verify
+        assert_eq!(
+            client.try_continue_verify(&5, &player, &proof.slice(0..10)),
+            Err(Ok(ZkVerifyError::ChunkedUploadMissing))
+        );
+        assert_eq!(client.try_finish_verify(&5, &player), Err(Ok(ZkVerifyError::ChunkedUploadMissing)));
+        assert_eq!(
+            client.try_begin_verify(&game, &5, &player, &public_inputs, &(CHUNKED_MAX_PROOF_LEN + 1), &Bytes::new(&env)),
+            Err(Ok(ZkVerifyError::ChunkedProofTooLong))
+        );
+
+        assert_eq!(client.begin_verify(&game, &5, &player, &public_inputs, &len, &proof.slice(0..6000)), len - 6000);
+        assert_eq!(client.try_finish_verify(&5, &player), Err(Ok(ZkVerifyError::ChunkedUploadIncomplete)));
+        let mut overlong = proof.slice(6000..len);
+        overlong.push_back(0);
+        assert_eq!(
+            client.try_continue_verify(&5, &player, &overlong),
+            Err(Ok(ZkVerifyError::ChunkedProofTooLong))
+        );
+        assert_eq!(client.continue_verify(&5, &player, &proof.slice(6000..12000)), len - 12000);
+        assert_eq!(client.continue_verify(&5, &player, &proof.slice(12000..len)), 0);
+
+        assert!(client.finish_verify(&5, &player));
+        assert!(client.consume_verification(&game, &5, &player, &MODE_ULTRAHONK, &commitment));
+
+        // The upload is gone once finished, and a rejected proof records nothing
+        assert_eq!(client.try_finish_verify(&5, &player), Err(Ok(ZkVerifyError::ChunkedUploadMissing)));
+        let rejected = noir_proof(&env, 0);
+        client.begin_verify(&game, &6, &player, &public_inputs, &len, &rejected);
+        assert!(!client.finish_verify(&6, &player));
+        assert!(!client.consume_verification(&game, &6, &player, &MODE_ULTRAHONK, &commitment));
+    }
+
+    #[test]
+    fn test_nizk_raw_seed_never_on_chain() {
+        // Verify that the raw seed bytes do NOT appear in public_inputs or proof
+        let env = Env::default();
+        let seed = BytesN::<32>::from_array(&env, &[
+            0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02, 0x03, 0x04,
+            0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+            0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14,
+            0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C,
+        ]);
+        let blinding = BytesN::<32>::from_array(&env, &[0xFFu8; 32]);
+        let player = Address::generate(&env);
+        let session_id = 42u32;
+
+        let (public_inputs, proof, _) = generate_nizk_proof(
+            &env, &seed, &blinding, session_id, &player,
+        );
+
+        // Check that the raw seed bytes don't appear as a contiguous
+        // 32-byte substring in either public_inputs or proof
+        let seed_arr = seed.to_array();
+        let pi_len = public_inputs.len();
+        let proof_len = proof.len();
+
+        // Check public inputs
+        let mut found_in_pi = false;
+        if pi_len >= 32 {
+            let mut offset = 0u32;
+            while offset <= pi_len - 32 {
+                let mut matches = true;
+                let mut j = 0usize;
+                while j < 32 {
+                    if public_inputs.get(offset + j as u32).unwrap_or(0) != seed_arr[j] {
+                        matches = false;
+                        break;
+                    }
+                    j += 1;
+                }
+                if matches {
+                    found_in_pi = true;
+                    break;
+                }
+                offset += 1;
+            }
+        }
+        assert!(!found_in_pi, "Raw seed must NOT appear in public inputs");
+
+        // Check proof
+        let mut found_in_proof = false;
+        if proof_len >= 32 {
+            let mut offset = 0u32;
+            while offset <= proof_len - 32 {
+                let mut matches = true;
+                let mut j = 0usize;
+                while j < 32 {
+                    if proof.get(offset + j as u32).unwrap_or(0) != seed_arr[j] {
+                        matches = false;
+                        break;
+                    }
+                    j += 1;
+                }
+                if matches {
+                    found_in_proof = true;
+                    break;
+                }
+                offset += 1;
+            }
+        }
+        assert!(!found_in_proof, "Raw seed must NOT appear in proof");
+    }
+
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Pedersen+Sigma helpers (Mode 4)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Compute Pedersen commitment: C = seed_scalar·G + blinding_scalar·H
+    fn compute_pedersen_commitment(env: &Env, seed_scalar: &Fr, blinding_scalar: &Fr) -> G1Affine {
+        pedersen::commitment(env, &seed_scalar.to_bytes().to_array(), &blinding_scalar.to_bytes().to_array())
+    }
+
+    /// Generate a Pedersen+Sigma proof (revised: Schnorr on D = C - s·G)
+    fn generate_pedersen_sigma_proof(
+        env: &Env,
+        seed_scalar: &Fr,
+        blinding_scalar: &Fr,
+        nonce_r: &Fr,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        pedersen::prove(
+            env,
+            &seed_scalar.to_bytes(),
+            &blinding_scalar.to_bytes().to_array(),
+            &nonce_r.to_bytes().to_array(),
+            session_id,
+            &PlayerTail::new(player),
+        )
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Pedersen+Sigma tests (Mode 4)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_pedersen_sigma_valid_proof() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let session_id = 12345u32;
+
+        // Scalars (deterministic for test)
+        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xDE, 0xAD, 0xBE, 0xEF,
+        ]));
+        let blinding_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
+        ]));
+        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
+        ]));
+
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding_scalar, &nonce_r,
+            session_id, &player,
+        );
+
+        assert_eq!(proof.len(), 128);
+        assert!(client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_pedersen_sigma_rejects_non_canonical_encodings() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let player = Address::generate(&env);
+        let seed_scalar = Fr::from_u256(U256::from_u32(&env, 0xDEAD_BEEF));
+        let blinding_scalar = Fr::from_u256(U256::from_u32(&env, 0xCAFE_BABE));
+
+        // z_r + r decodes to the same scalar as z_r.
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding_scalar,
+            &Fr::from_u256(U256::from_u32(&env, 0x0506_0708)), 7, &player,
+        );
+        assert!(client.verify(&public_inputs, &proof));
+        let mut z_r = [0u8; 32];
+        proof.slice(96..128).copy_into_slice(&mut z_r);
+        let zh = u128::from_be_bytes(z_r[..16].try_into().unwrap());
+        let zl = u128::from_be_bytes(z_r[16..].try_into().unwrap());
+        let rh = u128::from_be_bytes(fr::MODULUS[..16].try_into().unwrap());
+        let rl = u128::from_be_bytes(fr::MODULUS[16..].try_into().unwrap());
+        let (lo, carry) = zl.overflowing_add(rl);
+        let mut shifted = [0u8; 32];
+        shifted[0..16].copy_from_slice(&(zh + rh + carry as u128).to_be_bytes());
+        shifted[16..32].copy_from_slice(&lo.to_be_bytes());
+        let mut malleated = proof.slice(0..96);
+        malleated.append(&Bytes::from_array(&env, &shifted));
+        assert!(!client.verify(&public_inputs, &malleated));
+
+        // A zero nonce gives R = identity; the Schnorr equation still holds.
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding_scalar,
+            &Fr::from_u256(U256::from_u32(&env, 0)), 7, &player,
+        );
+        assert_eq!(proof.get(0).unwrap() & 0x40, 0x40);
+        assert!(!client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_pedersen_sigma_wrong_z_r_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let session_id = 42u32;
+
+        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xDE, 0xAD, 0xBE, 0xEF,
+        ]));
+        let blinding_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
+        ]));
+        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
+        ]));
+
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding_scalar, &nonce_r,
+            session_id, &player,
+        );
+
+        // Tamper with z_r: replace bytes [96..128) with garbage
+        let tampered_z_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[0x42u8; 32]));
+        let r_bytes = {
+            let mut arr = [0u8; 96];
+            let mut i = 0usize;
+            while i < 96 { arr[i] = proof.get(i as u32).unwrap_or(0); i += 1; }
+            arr
+        };
+
+        let mut tampered_proof = Bytes::from_array(&env, &r_bytes);
+        tampered_proof.append(&Bytes::from_array(&env, &tampered_z_r.to_bytes().to_array()));
+
+        assert!(!client.verify(&public_inputs, &tampered_proof));
+    }
+
+    #[test]
+    fn test_pedersen_sigma_different_player_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        let session_id = 77u32;
+
+        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xAB, 0xCD, 0xEF, 0x01,
+        ]));
+        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x56, 0x78,
+        ]));
+        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x0E, 0x0F, 0x10, 0x11,
+        ]));
+
+        // Generate proof for player1
+        let (_public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding, &nonce_r,
+            session_id, &player1,
+        );
+
+        // Create public inputs with player2 but same commitment and seed_hash
+        let commitment = compute_pedersen_commitment(&env, &seed_scalar, &blinding);
+        let c_bytes = commitment.to_bytes();
+        let seed_hash_bytes = seed_scalar.to_bytes();
+        let mut tampered_inputs = Bytes::from_array(&env, &c_bytes.to_array());
+        tampered_inputs.append(&Bytes::from_array(&env, &seed_hash_bytes.to_array()));
+        tampered_inputs.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
+        tampered_inputs.append(&player2.to_string().to_bytes()); // wrong player!
+
+        // Challenge will be different → sigma check fails
+        assert!(!client.verify(&tampered_inputs, &proof));
+    }
+
+    #[test]
+    fn test_pedersen_sigma_multiple_sessions() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let seed = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
+        ]));
+        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x06, 0x07, 0x08, 0x09, 0x0A,
+        ]));
+        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x11, 0x12, 0x13, 0x14,
+        ]));
+
+        for sid in [1u32, 100, 999, 65535] {
+            let (public_inputs, proof) = generate_pedersen_sigma_proof(
+                &env, &seed, &blinding, &nonce_r, sid, &player,
+            );
+            assert!(client.verify(&public_inputs, &proof), "Session {} should verify", sid);
+        }
+    }
+
+    #[test]
+    fn test_pedersen_sigma_wrong_seed_hash_fails() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+
+        let player = Address::generate(&env);
+        let session_id = 55u32;
+
+        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC, 0xDD,
+        ]));
+        let wrong_seed = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44,
+        ]));
+        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
+        ]));
+        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
+        ]));
+
+        // Generate valid proof for seed_scalar
+        let (_, proof) = generate_pedersen_sigma_proof(
+            &env, &seed_scalar, &blinding, &nonce_r,
+            session_id, &player,
+        );
+
+        // Build public inputs with WRONG seed_hash (wrong_seed instead of seed_scalar)
+        let commitment = compute_pedersen_commitment(&env, &seed_scalar, &blinding);
+        let c_bytes = commitment.to_bytes();
+        let mut tampered_inputs = Bytes::from_array(&env, &c_bytes.to_array());
+        tampered_inputs.append(&Bytes::from_array(&env, &wrong_seed.to_bytes().to_array()));
+        tampered_inputs.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
+        tampered_inputs.append(&player.to_string().to_bytes());
+
+        // D = C - wrong_seed·G ≠ blinding·H → Schnorr check fails
+        assert!(!client.verify(&tampered_inputs, &proof));
+    }
+
+    #[test]
+    fn test_constructor_caches_pedersen_h() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+
+        let cached: Option<BytesN<96>> = env.as_contract(&verifier_id, || {
+            env.storage().instance().get(&StorageKey::PedersenH)
+        });
+        assert_eq!(cached, Some(pedersen_h(&env).to_bytes()));
+    }
+
+    #[test]
+    fn test_pedersen_h_falls_back_to_derivation() {
+        let env = Env::default();
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let player = Address::generate(&env);
+
+        let seed = test_scalar(&env, b"h-cache-seed", 0, 0);
+        let blinding = test_scalar(&env, b"h-cache-blind", 0, 0);
+        let nonce = test_scalar(&env, b"h-cache-nonce", 0, 0);
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &seed, &blinding, &nonce, 808, &player,
+        );
+
+        env.cost_estimate().budget().reset_default();
+        assert!(client.verify(&public_inputs, &proof));
+        let cached_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+        // Instances without the cached point still verify, via hash_to_g1
+        env.as_contract(&verifier_id, || {
+            env.storage().instance().remove(&StorageKey::PedersenH);
+        });
+        env.cost_estimate().budget().reset_default();
+        assert!(client.verify(&public_inputs, &proof));
+        let derived_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+        assert!(cached_cost < derived_cost, "cached H should skip hash_to_g1");
+    }
+
+
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Mode 7: Card Play Ring Sigma tests
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Fixed prover nonce, so the ring and hand proofs are reproducible.
+    const TEST_NONCE: [u8; 32] = [0x5A; 32];
+
+    /// Build a valid Ring Sigma proof for testing (mirrors frontend buildCardPlayRingProof).
+    fn build_ring_sigma_proof(
+        env: &Env,
+        card_id: u32,
+        blinding: &BytesN<32>,
+        valid_set: &[u32],
+        session_id: u32,
+        player: &Address,
+    ) -> (BytesN<32>, Bytes) {
+        let tail = PlayerTail::new(player);
+        ring::prove(env, card_id, &blinding.to_array(), valid_set, &TEST_NONCE, session_id, &tail).unwrap()
+    }
+
+    fn build_ring_public_inputs(
+        env: &Env,
+        commit_hash: &BytesN<32>,
+        valid_set: &[u32],
+        session_id: u32,
+        player: &Address,
+    ) -> Bytes {
+        ring::public_inputs(env, commit_hash, valid_set, session_id, &PlayerTail::new(player))
+    }
+
+    #[test]
+    fn test_ring_sigma_valid_proof_n1() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let card_id: u32 = 5;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [5u32];
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let public_inputs = build_ring_public_inputs(
+            &env, &commit_hash, &valid_set, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof), "Ring sigma N=1 should verify");
+    }
+
+    #[test]
+    fn test_ring_sigma_valid_proof_n3() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let card_id: u32 = 10;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 7; arr
+        });
+        let valid_set = [9u32, 10, 11];
+        let session_id = 200u32;
+
+        let (commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let public_inputs = build_ring_public_inputs(
+            &env, &commit_hash, &valid_set, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof), "Ring sigma N=3 should verify");
+    }
+
+    #[test]
+    fn test_ring_sigma_wrong_commit_hash_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let card_id: u32 = 5;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [5u32];
+        let session_id = 100u32;
+
+        let (_commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let wrong_hash = BytesN::<32>::from_array(&env, &[0xAA; 32]);
+        let public_inputs = build_ring_public_inputs(
+            &env, &wrong_hash, &valid_set, session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Wrong commit_hash should fail");
+    }
+
+    #[test]
+    fn test_ring_sigma_wrong_valid_set_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let card_id: u32 = 5;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [5u32];
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let wrong_set = [6u32];
+        let public_inputs = build_ring_public_inputs(
+            &env, &commit_hash, &wrong_set, session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Wrong valid_set should fail");
+    }
+
+    #[test]
+    fn test_ring_sigma_different_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let card_id: u32 = 5;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [5u32];
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let public_inputs = build_ring_public_inputs(
+            &env, &commit_hash, &valid_set, 999, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Different session should fail");
+    }
+
+    #[test]
+    fn test_ring_sigma_different_player_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+        let other_player = Address::generate(&env);
+
+        let card_id: u32 = 5;
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 42; arr
+        });
+        let valid_set = [5u32];
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_ring_sigma_proof(
+            &env, card_id, &blinding, &valid_set, session_id, &player,
+        );
+        let public_inputs = build_ring_public_inputs(
+            &env, &commit_hash, &valid_set, session_id, &other_player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Different player should fail");
+    }
+
+    #[test]
+    fn test_ring_sigma_full_suit_within_budget() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // All nine hearts (9..=17) — the largest ring Mode 7 accepts
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 9; arr
+        });
+        let valid_set = [9u32, 10, 11, 12, 13, 14, 15, 16, 17];
+        env.cost_estimate().budget().reset_unlimited();
+        let (commit_hash, proof) =
+            build_ring_sigma_proof(&env, 13, &blinding, &valid_set, 405, &player);
+        let public_inputs = build_ring_public_inputs(&env, &commit_hash, &valid_set, 405, &player);
+
+        env.cost_estimate().budget().reset_default();
+        assert!(client.verify(&public_inputs, &proof), "N = 9 ring should fit the default budget");
+    }
+
+    #[test]
+    fn test_ring_sigma_padded_hides_set_size() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let tail = PlayerTail::new(&Address::generate(&env));
+        let mut blinding = [0u8; 32];
+        blinding[31] = 9;
+
+        // Three hearts in hand, proven as a nine-member ring
+        let valid_set = [9u32, 10, 11];
+        env.cost_estimate().budget().reset_unlimited();
+        let (commit_hash, proof) = ring::prove_padded(&env, 10, &blinding, &valid_set, &TEST_NONCE, 407, &tail).unwrap();
+        let public_inputs = ring::public_inputs_padded(&env, &commit_hash, &valid_set, 407, &tail);
+        let plain = ring::public_inputs(&env, &commit_hash, &valid_set, 407, &tail);
+        let fewer = ring::public_inputs_padded(&env, &commit_hash, &valid_set[..1], 407, &tail);
+        let (short_hash, short) = ring::prove(&env, 10, &blinding, &valid_set, &TEST_NONCE, 408, &tail).unwrap();
+        let short_inputs = ring::public_inputs_padded(&env, &short_hash, &valid_set, 408, &tail);
+        env.cost_estimate().budget().reset_default();
+
+        assert_eq!(proof.len(), RING_PROOF_BASE_LEN + RING_MAX_SET_SIZE * RING_MEMBER_PROOF_LEN);
+        assert!(client.verify(&public_inputs, &proof), "Padded ring should fit the default budget");
+
+        // Without the flag N must match the nine members
+        assert!(!client.verify(&plain, &proof));
+        // Listing fewer cards turns the played one into padding
+        assert!(!client.verify(&fewer, &proof));
+        // The flag needs a full nine-member proof
+        assert!(!client.verify(&short_inputs, &short));
+    }
+
+    #[test]
+    fn test_ring_sigma_52_card_suit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+        assert_eq!(client.get_deck_params(), DeckParams::default());
+        let too_big = DeckParams { max_ring_size: 14, ..DeckParams::standard() };
+        assert_eq!(client.try_set_deck_params(&too_big), Err(Ok(ZkVerifyError::InvalidDeckParams)));
+
+        // All thirteen diamonds (13..=25) of a standard deck
+        let blinding = BytesN::<32>::from_array(&env, &[0x13u8; 32]);
+        let valid_set: [u32; 13] = core::array::from_fn(|i| 13 + i as u32);
+        env.cost_estimate().budget().reset_unlimited();
+        let (commit_hash, proof) = build_ring_sigma_proof(&env, 20, &blinding, &valid_set, 409, &player);
+        let public_inputs = build_ring_public_inputs(&env, &commit_hash, &valid_set, 409, &player);
+        env.cost_estimate().budget().reset_unlimited();
+
+        // The 36-card default caps rings at nine
+        assert!(!client.verify(&public_inputs, &proof));
+
+        client.set_deck_params(&DeckParams::standard());
+        assert_eq!(client.get_deck_params(), DeckParams::standard());
+        restore_verify_budget(&env);
+        assert!(client.verify(&public_inputs, &proof), "N = 13 ring should fit the default budget");
+    }
+
+    #[test]
+    fn test_estimate_cost_tracks_measured_cost() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        for mode in MODES {
+            let estimate = client.estimate_cost(&mode, &1);
+            let logged = !matches!(mode, MODE_PLONK | MODE_MERKLE | MODE_ULTRAHONK);
+            assert!(estimate.cpu_instructions > 0, "mode {}", mode);
+            assert_eq!(estimate.write_entries, logged as u32, "mode {}", mode);
+        }
+        assert_eq!(client.try_estimate_cost(&3, &0), Err(Ok(ZkVerifyError::UnknownMode)));
+        assert_eq!(client.estimate_cost(&MODE_ULTRAHONK, &0).read_entries, 4);
+
+        // A full-suit ring lands within 10% of the estimate. The estimates
+        // are for keccak256 transcripts, so `poseidon-fs` only checks validity.
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 9; arr
+        });
+        let valid_set = [9u32, 10, 11, 12, 13, 14, 15, 16, 17];
+        env.cost_estimate().budget().reset_unlimited();
+        let (commit_hash, proof) = build_ring_sigma_proof(&env, 13, &blinding, &valid_set, 406, &player);
+        let public_inputs = build_ring_public_inputs(&env, &commit_hash, &valid_set, 406, &player);
+        env.cost_estimate().budget().reset_unlimited();
+        assert!(client.verify(&public_inputs, &proof));
+        let measured = env.cost_estimate().budget().cpu_instruction_cost();
+        let estimated = client.estimate_cost(&MODE_CARD_RING, &9).cpu_instructions;
+        if cfg!(not(feature = "poseidon-fs")) {
+            assert!(measured.abs_diff(estimated) < estimated / 10, "measured {} vs {}", measured, estimated);
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Mode 8: Cangkul Hand Proof tests
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Build a valid Mode 8 (Cangkul Hand) proof for testing.
+    fn build_cangkul_hand_proof(
+        env: &Env,
+        hand: &[u32],
+        blindings: &[[u8; 32]],
+        trick_suit: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (BytesN<32>, Bytes) {
+        let tail = PlayerTail::new(player);
+        hand::prove(env, hand, blindings, trick_suit, &TEST_NONCE, session_id, &tail).unwrap()
+    }
+
+    fn build_cangkul_public_inputs(
+        env: &Env,
+        commit_hash: &BytesN<32>,
+        trick_suit: u32,
+        hand: &[u32],
+        session_id: u32,
+        player: &Address,
+    ) -> Bytes {
+        hand::public_inputs(env, commit_hash, trick_suit, hand, session_id, &PlayerTail::new(player))
+    }
+
+    #[test]
+    fn test_cangkul_hand_valid_proof_single_card() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [10u32];
+        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof), "Single card cangkul proof should pass");
+    }
+
+    #[test]
+    fn test_cangkul_hand_52_card_deck() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // All thirteen spades and seven clubs of a standard deck, void in
+        // diamonds: too many cards, and ids past 35, for the 36-card default
+        let hand: [u32; 20] = core::array::from_fn(|i| if i < 13 { i as u32 } else { 27 + i as u32 });
+        let blindings: [[u8; 32]; 20] = core::array::from_fn(|i| [i as u8 + 1; 32]);
+        env.cost_estimate().budget().reset_unlimited();
+        let (commit_hash, proof) = build_cangkul_hand_proof(&env, &hand, &blindings, 1, 110, &player);
+        let public_inputs = build_cangkul_public_inputs(&env, &commit_hash, 1, &hand, 110, &player);
+        let (spade_hash, spade_proof) = build_cangkul_hand_proof(&env, &hand[12..13], &blindings[..1], 1, 111, &player);
+        let spade_inputs = build_cangkul_public_inputs(&env, &spade_hash, 1, &hand[12..13], 111, &player);
+        let (wrong_hash, wrong_proof) = build_cangkul_hand_proof(&env, &hand[12..13], &blindings[..1], 0, 112, &player);
+        let wrong_inputs = build_cangkul_public_inputs(&env, &wrong_hash, 0, &hand[12..13], 112, &player);
+        env.cost_estimate().budget().reset_default();
+
+        // Under 9 cards per suit, card 12 is a heart (suit 1)
+        assert!(!client.verify(&public_inputs, &proof));
+        assert!(!client.verify(&spade_inputs, &spade_proof));
+
+        // Under 13 per suit it is a spade (suit 0)
+        client.set_deck_params(&DeckParams::standard());
+        assert!(client.verify(&public_inputs, &proof), "20-card hand should verify");
+        assert!(client.verify(&spade_inputs, &spade_proof));
+        assert!(!client.verify(&wrong_inputs, &wrong_proof));
+    }
+
+    #[test]
+    fn test_cangkul_hand_valid_proof_multi_card() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [9u32, 10, 11, 27, 28];
+        let blindings = [
+            {let mut a = [0u8; 32]; a[31] = 10; a},
+            {let mut a = [0u8; 32]; a[31] = 20; a},
+            {let mut a = [0u8; 32]; a[31] = 30; a},
+            {let mut a = [0u8; 32]; a[31] = 40; a},
+            {let mut a = [0u8; 32]; a[31] = 50; a},
+        ];
+        let trick_suit = 0u32;
+        let session_id = 200u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof), "Multi-card cangkul proof should pass");
+    }
+
+    #[test]
+    fn test_cangkul_hand_valid_proof_suit2_excluded() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [0u32, 1, 27, 28];
+        let blindings = [
+            {let mut a = [0u8; 32]; a[31] = 5; a},
+            {let mut a = [0u8; 32]; a[31] = 15; a},
+            {let mut a = [0u8; 32]; a[31] = 25; a},
+            {let mut a = [0u8; 32]; a[31] = 35; a},
+        ];
+        let trick_suit = 2u32;
+        let session_id = 300u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(client.verify(&public_inputs, &proof));
+    }
+
+    #[test]
+    fn test_cangkul_hand_suit_violation_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [5u32, 10];
+        let blindings = [
+            {let mut a = [0u8; 32]; a[31] = 42; a},
+            {let mut a = [0u8; 32]; a[31] = 43; a},
+        ];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Card matching trick suit should fail");
+    }
+
+    #[test]
+    fn test_cangkul_hand_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [10u32];
+        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, 999, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Wrong session should fail");
+    }
+
+    #[test]
+    fn test_cangkul_hand_wrong_player_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let hand = [10u32];
+        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &other,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Wrong player should fail");
+    }
+
+    #[test]
+    fn test_cangkul_hand_k_mismatch_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [10u32, 11];
+        let blindings = [
+            {let mut a = [0u8; 32]; a[31] = 42; a},
+            {let mut a = [0u8; 32]; a[31] = 43; a},
+        ];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &[10u32], session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "k mismatch should fail");
+    }
+
+    #[test]
+    fn test_cangkul_hand_invalid_trick_suit_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [10u32];
+        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
+        let trick_suit = 5u32;
+        let session_id = 100u32;
+
+        let (commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Invalid trick suit should fail");
+    }
+
+    #[test]
+    fn test_cangkul_hand_commit_mismatch_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let hand = [10u32];
+        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
+        let trick_suit = 0u32;
+        let session_id = 100u32;
+
+        let (_commit_hash, proof) = build_cangkul_hand_proof(
+            &env, &hand, &blindings, trick_suit, session_id, &player,
+        );
+        let fake_hash = BytesN::<32>::from_array(&env, &[0xFFu8; 32]);
+        let public_inputs = build_cangkul_public_inputs(
+            &env, &fake_hash, trick_suit, &hand, session_id, &player,
+        );
+
+        assert!(!client.verify(&public_inputs, &proof), "Wrong commit hash should fail");
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  PLONK / KZG helpers (Mode 9)
+    // ════════════════════════════════════════════════════════════════════════
+    //
+    // A tiny honest PLONK prover over an n = 4 domain, used to produce real
+    // proofs for the on-chain verifier. The circuit proves knowledge of `w`
+    // such that `w·w = x` for a public `x`:
+    //
+    //   row 0: ql = 1                a = x            (public input row)
+    //   row 1: qm = 1, qo = −1       a = w, b = w, c = x
+    //   row 2, 3: empty
+    //
+    // Copy constraints: a₀ = c₁ (x) and a₁ = b₁ (w).
+    // The SRS uses a known τ — fine for tests, never for production keys.
+
+    /// Primitive 4th root of unity in Fr: 7^((r − 1)/4).
+    const PLONK_OMEGA_4: [u8; 32] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8d, 0x51, 0xcc, 0xce,
+        0x76, 0x03, 0x04, 0xd0, 0xec, 0x03, 0x00, 0x02, 0x76, 0x03, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    const PLONK_N: u32 = 4;
+    const PLONK_K1: u32 = 2;
+    const PLONK_K2: u32 = 3;
+    const PLONK_TAU: u32 = 0x1234_5678;
+
+    fn fr_u32(env: &Env, v: u32) -> Fr {
+        Fr::from_u256(soroban_sdk::U256::from_u32(env, v))
+    }
+
+    fn fr_neg(env: &Env, a: &Fr) -> Fr {
+        fr_u32(env, 0) - a.clone()
+    }
+
+    fn plonk_omega(env: &Env) -> Fr {
+        Fr::from_bytes(BytesN::from_array(env, &PLONK_OMEGA_4))
+    }
+
+    /// Evaluate a coefficient-form polynomial at x (Horner).
+    fn poly_eval(env: &Env, p: &Vec<Fr>, x: &Fr) -> Fr {
+        let mut acc = fr_u32(env, 0);
+        let mut i = p.len();
+        while i > 0 {
+            i -= 1;
+            acc = acc * x.clone() + p.get_unchecked(i);
+        }
+        acc
+    }
+
+    fn poly_add(env: &Env, a: &Vec<Fr>, b: &Vec<Fr>) -> Vec<Fr> {
+        let len = if a.len() > b.len() { a.len() } else { b.len() };
+        let mut out = Vec::new(env);
+        let mut i = 0u32;
+        while i < len {
+            let x = a.get(i).unwrap_or(fr_u32(env, 0));
+            let y = b.get(i).unwrap_or(fr_u32(env, 0));
+            out.push_back(x + y);
+            i += 1;
+        }
+        out
+    }
+
+    fn poly_scale(env: &Env, a: &Vec<Fr>, s: &Fr) -> Vec<Fr> {
+        let mut out = Vec::new(env);
+        for c in a.iter() {
+            out.push_back(c * s.clone());
+        }
+        out
+    }
+
+    fn poly_mul(env: &Env, a: &Vec<Fr>, b: &Vec<Fr>) -> Vec<Fr> {
+        let mut out = Vec::new(env);
+        let mut i = 0u32;
+        while i < a.len() + b.len() - 1 {
+            out.push_back(fr_u32(env, 0));
+            i += 1;
+        }
+        let mut i = 0u32;
+        while i < a.len() {
+            let mut j = 0u32;
+            while j < b.len() {
+                let cur = out.get_unchecked(i + j);
+                out.set(i + j, cur + a.get_unchecked(i) * b.get_unchecked(j));
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }
+
+    /// c₀ + c₁·X
+    fn poly_linear(env: &Env, c0: &Fr, c1: &Fr) -> Vec<Fr> {
+        vec![env, c0.clone(), c1.clone()]
+    }
+
+    /// p(ω·X): scales coefficient j by ωʲ.
+    fn poly_shift(env: &Env, p: &Vec<Fr>, omega: &Fr) -> Vec<Fr> {
+        let mut out = Vec::new(env);
+        let mut w = fr_u32(env, 1);
+        for c in p.iter() {
+            out.push_back(c * w.clone());
+            w = w * omega.clone();
+        }
+        out
+    }
+
+    /// Interpolate evaluations over {ωⁱ} into coefficients (inverse DFT).
+    fn poly_interpolate(env: &Env, evals: &[Fr]) -> Vec<Fr> {
+        let bls = env.crypto().bls12_381();
+        let n = evals.len() as u32;
+        let omega_inv = bls.fr_inv(&plonk_omega(env));
+        let n_inv = bls.fr_inv(&fr_u32(env, n));
+        let mut out = Vec::new(env);
+        let mut j = 0u32;
+        while j < n {
+            let step = bls.fr_pow(&omega_inv, j as u64);
+            let mut w = fr_u32(env, 1);
+            let mut acc = fr_u32(env, 0);
+            let mut i = 0usize;
+            while i < evals.len() {
+                acc = acc + evals[i].clone() * w.clone();
+                w = w * step.clone();
+                i += 1;
+            }
+            out.push_back(acc * n_inv.clone());
+            j += 1;
+        }
+        out
+    }
+
+    /// Divide by Xⁿ − 1, asserting the remainder is zero.
+    fn poly_div_vanishing(env: &Env, p: &Vec<Fr>, n: u32) -> Vec<Fr> {
+        let mut rem = p.clone();
+        let mut q = Vec::new(env);
+        let mut i = 0u32;
+        while i + n < rem.len() {
+            q.push_back(fr_u32(env, 0));
+            i += 1;
+        }
+        let mut d = rem.len();
+        while d > n {
+            d -= 1;
+            let lead = rem.get_unchecked(d);
+            q.set(d - n, lead.clone());
+            let low = rem.get_unchecked(d - n);
+            rem.set(d - n, low + lead);
+            rem.set(d, fr_u32(env, 0));
+        }
+        for c in rem.iter() {
+            assert_eq!(c, fr_u32(env, 0), "constraint numerator must vanish on the domain");
+        }
+        q
+    }
+
+    fn poly_slice(env: &Env, p: &Vec<Fr>, from: u32, to: u32) -> Vec<Fr> {
+        let mut out = Vec::new(env);
+        let mut i = from;
+        while i < to {
+            out.push_back(p.get(i).unwrap_or(fr_u32(env, 0)));
+            i += 1;
+        }
+        out
+    }
+
+    /// Mirror of the on-chain transcript hash: Fr(fs_digest(preimage || "ZKP9")).
+    fn plonk_transcript(env: &Env, preimage: &Bytes) -> Fr {
+        let mut data = preimage.clone();
+        data.append(&Bytes::from_array(env, &PLONK_CHALLENGE_TAG));
+        let hash: BytesN<32> = transcript::fs_digest(env, &data);
+        Fr::from_bytes(hash)
+    }
+
+    fn fr_bytes(env: &Env, x: &Fr) -> Bytes {
+        Bytes::from_array(env, &x.to_bytes().to_array())
+    }
+
+    /// Hand the budget back to the verifier after proving. The Mode 10, 13,
+    /// 14, 16 and 17 transcripts outgrow the default budget under `poseidon-fs`, so
+    /// that build keeps it unlimited and the tests still check correctness.
+    fn restore_verify_budget(env: &Env) {
+        #[cfg(not(feature = "poseidon-fs"))]
+        env.cost_estimate().budget().reset_default();
+        #[cfg(feature = "poseidon-fs")]
+        env.cost_estimate().budget().reset_unlimited();
+    }
+
+    /// KZG commitment with the known-τ SRS: [p(τ)]₁.
+    fn kzg_commit(env: &Env, p: &Vec<Fr>) -> Bytes {
+        let bls = env.crypto().bls12_381();
+        let tau = fr_u32(env, PLONK_TAU);
+        let point = bls.g1_mul(&g1_generator(env), &poly_eval(env, p, &tau));
+        Bytes::from_array(env, &point.to_bytes().to_array())
+    }
+
+    /// Selector and permutation polynomials of the `w·w = x` circuit:
+    /// (Qm, Ql, Qr, Qo, Qc, S1, S2, S3).
+    fn plonk_circuit_polys(env: &Env) -> [Vec<Fr>; 8] {
+        let zero = fr_u32(env, 0);
+        let one = fr_u32(env, 1);
+        let omega = plonk_omega(env);
+        let w: [Fr; 4] = [
+            one.clone(),
+            omega.clone(),
+            omega.clone() * omega.clone(),
+            omega.clone() * omega.clone() * omega.clone(),
+        ];
+        let k1 = fr_u32(env, PLONK_K1);
+        let k2 = fr_u32(env, PLONK_K2);
+
+        let qm = poly_interpolate(env, &[zero.clone(), one.clone(), zero.clone(), zero.clone()]);
+        let ql = poly_interpolate(env, &[one.clone(), zero.clone(), zero.clone(), zero.clone()]);
+        let qr = poly_interpolate(env, &[zero.clone(), zero.clone(), zero.clone(), zero.clone()]);
+        let qo = poly_interpolate(env, &[zero.clone(), fr_neg(env, &one), zero.clone(), zero.clone()]);
+        let qc = poly_interpolate(env, &[zero.clone(), zero.clone(), zero.clone(), zero.clone()]);
+
+        // σ: a₀ ↔ c₁, a₁ ↔ b₁; every other slot maps to itself.
+        let s1 = poly_interpolate(env, &[
+            k2.clone() * w[1].clone(),
+            k1.clone() * w[1].clone(),
+            w[2].clone(),
+            w[3].clone(),
+        ]);
+        let s2 = poly_interpolate(env, &[
+            k1.clone() * w[0].clone(),
+            w[1].clone(),
+            k1.clone() * w[2].clone(),
+            k1.clone() * w[3].clone(),
+        ]);
+        let s3 = poly_interpolate(env, &[
+            k2.clone() * w[0].clone(),
+            w[0].clone(),
+            k2.clone() * w[2].clone(),
+            k2.clone() * w[3].clone(),
+        ]);
+        [qm, ql, qr, qo, qc, s1, s2, s3]
+    }
+
+    /// Serialize the verification key for the `w·w = x` circuit.
+    fn build_plonk_vk(env: &Env) -> Bytes {
+        let bls = env.crypto().bls12_381();
+        let polys = plonk_circuit_polys(env);
+        let mut vk = Bytes::from_array(env, &PLONK_N.to_be_bytes());
+        vk.append(&Bytes::from_array(env, &1u32.to_be_bytes()));
+        vk.append(&fr_bytes(env, &fr_u32(env, PLONK_K1)));
+        vk.append(&fr_bytes(env, &fr_u32(env, PLONK_K2)));
+        vk.append(&fr_bytes(env, &plonk_omega(env)));
+        for p in polys.iter() {
+            vk.append(&kzg_commit(env, p));
+        }
+        let g2 = G2Affine::from_array(env, &G2_GENERATOR);
+        let x2 = bls.g2_mul(&g2, &fr_u32(env, PLONK_TAU));
+        vk.append(&Bytes::from_array(env, &x2.to_bytes().to_array()));
+        vk
+    }
+
+    fn build_plonk_public_inputs(env: &Env, circuit_id: u32, x: u32) -> Bytes {
+        let mut pi = Bytes::from_array(env, &circuit_id.to_be_bytes());
+        pi.append(&Bytes::from_array(env, &1u32.to_be_bytes()));
+        pi.append(&fr_bytes(env, &fr_u32(env, x)));
+        pi
+    }
+
+    /// Honest PLONK prover for `w·w = x`. Returns the 1056-byte proof.
+    fn build_plonk_proof(env: &Env, vk: &Bytes, x: u32, w_val: u32) -> Bytes {
+        let bls = env.crypto().bls12_381();
+        let zero = fr_u32(env, 0);
+        let one = fr_u32(env, 1);
+        let omega = plonk_omega(env);
+        let k1 = fr_u32(env, PLONK_K1);
+        let k2 = fr_u32(env, PLONK_K2);
+        let tau = fr_u32(env, PLONK_TAU);
+        let x_fr = fr_u32(env, x);
+        let w_fr = fr_u32(env, w_val);
+        let [qm, ql, qr, qo, qc, s1, s2, s3] = plonk_circuit_polys(env);
+
+        // ── Round 1: wire polynomials ───────────────────────────────────────
+        let a_vals = [x_fr.clone(), w_fr.clone(), zero.clone(), zero.clone()];
+        let b_vals = [zero.clone(), w_fr.clone(), zero.clone(), zero.clone()];
+        let c_vals = [zero.clone(), x_fr.clone(), zero.clone(), zero.clone()];
+        let a = poly_interpolate(env, &a_vals);
+        let b = poly_interpolate(env, &b_vals);
+        let c = poly_interpolate(env, &c_vals);
+        let a_cm = kzg_commit(env, &a);
+        let b_cm = kzg_commit(env, &b);
+        let c_cm = kzg_commit(env, &c);
+
+        let mut preimage = vk.slice(PLONK_VK_POINTS_OFFSET..PLONK_VK_X2_OFFSET);
+        preimage.append(&fr_bytes(env, &x_fr));
+        preimage.append(&a_cm);
+        preimage.append(&b_cm);
+        preimage.append(&c_cm);
+        let beta = plonk_transcript(env, &preimage);
+        let gamma = plonk_transcript(env, &fr_bytes(env, &beta));
+
+        // ── Round 2: permutation accumulator Z ──────────────────────────────
+        let mut z_vals = [one.clone(), zero.clone(), zero.clone(), zero.clone()];
+        let mut acc = one.clone();
+        let mut wi = one.clone();
+        let mut i = 0usize;
+        while i < 4 {
+            let num = (a_vals[i].clone() + beta.clone() * wi.clone() + gamma.clone())
+                * (b_vals[i].clone() + beta.clone() * k1.clone() * wi.clone() + gamma.clone())
+                * (c_vals[i].clone() + beta.clone() * k2.clone() * wi.clone() + gamma.clone());
+            let den = (a_vals[i].clone() + beta.clone() * poly_eval(env, &s1, &wi) + gamma.clone())
+                * (b_vals[i].clone() + beta.clone() * poly_eval(env, &s2, &wi) + gamma.clone())
+                * (c_vals[i].clone() + beta.clone() * poly_eval(env, &s3, &wi) + gamma.clone());
+            acc = acc * num * bls.fr_inv(&den);
+            if i < 3 {
+                z_vals[i + 1] = acc.clone();
+            }
+            wi = wi * omega.clone();
+            i += 1;
+        }
+        assert_eq!(acc, one, "copy constraints must close the grand product");
+        let z = poly_interpolate(env, &z_vals);
+        let z_cm = kzg_commit(env, &z);
+
+        let mut preimage = fr_bytes(env, &beta);
+        preimage.append(&fr_bytes(env, &gamma));
+        preimage.append(&z_cm);
+        let alpha = plonk_transcript(env, &preimage);
+
+        // ── Round 3: quotient T ─────────────────────────────────────────────
+        let pi_poly = poly_interpolate(env, &[fr_neg(env, &x_fr), zero.clone(), zero.clone(), zero.clone()]);
+        let l0_poly = poly_interpolate(env, &[one.clone(), zero.clone(), zero.clone(), zero.clone()]);
+
+        let mut gate = poly_mul(env, &poly_mul(env, &qm, &a), &b);
+        gate = poly_add(env, &gate, &poly_mul(env, &ql, &a));
+        gate = poly_add(env, &gate, &poly_mul(env, &qr, &b));
+        gate = poly_add(env, &gate, &poly_mul(env, &qo, &c));
+        gate = poly_add(env, &gate, &qc);
+        gate = poly_add(env, &gate, &pi_poly);
+
+        let perm_id = poly_mul(
+            env,
+            &poly_mul(
+                env,
+                &poly_add(env, &a, &poly_linear(env, &gamma, &beta)),
+                &poly_add(env, &b, &poly_linear(env, &gamma, &(beta.clone() * k1.clone()))),
+            ),
+            &poly_mul(
+                env,
+                &poly_add(env, &c, &poly_linear(env, &gamma, &(beta.clone() * k2.clone()))),
+                &z,
+            ),
+        );
+        let gamma_poly = vec![env, gamma.clone()];
+        let perm_sigma = poly_mul(
+            env,
+            &poly_mul(
+                env,
+                &poly_add(env, &a, &poly_add(env, &poly_scale(env, &s1, &beta), &gamma_poly)),
+                &poly_add(env, &b, &poly_add(env, &poly_scale(env, &s2, &beta), &gamma_poly)),
+            ),
+            &poly_mul(
+                env,
+                &poly_add(env, &c, &poly_add(env, &poly_scale(env, &s3, &beta), &gamma_poly)),
+                &poly_shift(env, &z, &omega),
+            ),
+        );
+        let perm = poly_add(env, &perm_id, &poly_scale(env, &perm_sigma, &fr_neg(env, &one)));
+        let z_minus_one = poly_add(env, &z, &vec![env, fr_neg(env, &one)]);
+        let boundary = poly_mul(env, &z_minus_one, &l0_poly);
+
+        let alpha2 = alpha.clone() * alpha.clone();
+        let mut numerator = poly_add(env, &gate, &poly_scale(env, &perm, &alpha));
+        numerator = poly_add(env, &numerator, &poly_scale(env, &boundary, &alpha2));
+        let t = poly_div_vanishing(env, &numerator, PLONK_N);
+        let t1 = poly_slice(env, &t, 0, PLONK_N);
+        let t2 = poly_slice(env, &t, PLONK_N, 2 * PLONK_N);
+        let t3 = poly_slice(env, &t, 2 * PLONK_N, 3 * PLONK_N);
+        assert!(t.len() <= 3 * PLONK_N, "quotient degree must fit in three chunks");
+        let t1_cm = kzg_commit(env, &t1);
+        let t2_cm = kzg_commit(env, &t2);
+        let t3_cm = kzg_commit(env, &t3);
+
+        let mut preimage = fr_bytes(env, &alpha);
+        preimage.append(&t1_cm);
+        preimage.append(&t2_cm);
+        preimage.append(&t3_cm);
+        let xi = plonk_transcript(env, &preimage);
+
+        // ── Round 4: evaluations at ξ ───────────────────────────────────────
+        let eval_a = poly_eval(env, &a, &xi);
+        let eval_b = poly_eval(env, &b, &xi);
+        let eval_c = poly_eval(env, &c, &xi);
+        let eval_s1 = poly_eval(env, &s1, &xi);
+        let eval_s2 = poly_eval(env, &s2, &xi);
+        let eval_zw = poly_eval(env, &z, &(xi.clone() * omega.clone()));
+
+        let mut evals = fr_bytes(env, &eval_a);
+        evals.append(&fr_bytes(env, &eval_b));
+        evals.append(&fr_bytes(env, &eval_c));
+        evals.append(&fr_bytes(env, &eval_s1));
+        evals.append(&fr_bytes(env, &eval_s2));
+        evals.append(&fr_bytes(env, &eval_zw));
+        let mut preimage = fr_bytes(env, &xi);
+        preimage.append(&evals);
+        let v = plonk_transcript(env, &preimage);
+
+        // ── Round 5: linearisation and opening proofs ───────────────────────
+        let xi_n = bls.fr_pow(&xi, PLONK_N as u64);
+        let zh = xi_n.clone() - one.clone();
+        let l0 = poly_eval(env, &l0_poly, &xi);
+        let pi = poly_eval(env, &pi_poly, &xi);
+        let e1 = eval_a.clone() + beta.clone() * eval_s1.clone() + gamma.clone();
+        let e2 = eval_b.clone() + beta.clone() * eval_s2.clone() + gamma.clone();
+        let r0 = pi
+            - l0.clone() * alpha2.clone()
+            - alpha.clone() * e1.clone() * e2.clone() * (eval_c.clone() + gamma.clone()) * eval_zw.clone();
+
+        let beta_xi = beta.clone() * xi.clone();
+        let z_coeff = (eval_a.clone() + beta_xi.clone() + gamma.clone())
+            * (eval_b.clone() + beta_xi.clone() * k1.clone() + gamma.clone())
+            * (eval_c.clone() + beta_xi * k2.clone() + gamma.clone())
+            * alpha.clone()
+            + l0 * alpha2;
+        let s3_coeff = e1 * e2 * alpha.clone() * beta.clone() * eval_zw.clone();
+
+        let mut r = poly_scale(env, &qm, &(eval_a.clone() * eval_b.clone()));
+        r = poly_add(env, &r, &poly_scale(env, &ql, &eval_a));
+        r = poly_add(env, &r, &poly_scale(env, &qr, &eval_b));
+        r = poly_add(env, &r, &poly_scale(env, &qo, &eval_c));
+        r = poly_add(env, &r, &qc);
+        r = poly_add(env, &r, &poly_scale(env, &z, &z_coeff));
+        r = poly_add(env, &r, &poly_scale(env, &s3, &fr_neg(env, &s3_coeff)));
+        let mut t_lin = t1.clone();
+        t_lin = poly_add(env, &t_lin, &poly_scale(env, &t2, &xi_n));
+        t_lin = poly_add(env, &t_lin, &poly_scale(env, &t3, &(xi_n.clone() * xi_n.clone())));
+        r = poly_add(env, &r, &poly_scale(env, &t_lin, &fr_neg(env, &zh)));
+        assert_eq!(
+            poly_eval(env, &r, &xi) + r0.clone(),
+            zero,
+            "linearisation polynomial must open to −r0 at ξ"
+        );
+
+        // Wξ = (r(τ) + r0 + Σ vᵏ(pₖ(τ) − p̄ₖ)) / (τ − ξ)
+        let mut w_num = poly_eval(env, &r, &tau) + r0;
+        let mut vk_pow = v.clone();
+        for (p, e) in [(&a, &eval_a), (&b, &eval_b), (&c, &eval_c), (&s1, &eval_s1), (&s2, &eval_s2)] {
+            w_num = w_num + vk_pow.clone() * (poly_eval(env, p, &tau) - e.clone());
+            vk_pow = vk_pow * v.clone();
+        }
+        let w_xi_scalar = w_num * bls.fr_inv(&(tau.clone() - xi.clone()));
+        // Wξω = (z(τ) − z̄ω) / (τ − ξω)
+        let w_xiw_scalar = (poly_eval(env, &z, &tau) - eval_zw)
+            * bls.fr_inv(&(tau - xi.clone() * omega));
+
+        let g = g1_generator(env);
+        let w_xi = Bytes::from_array(env, &bls.g1_mul(&g, &w_xi_scalar).to_bytes().to_array());
+        let w_xiw = Bytes::from_array(env, &bls.g1_mul(&g, &w_xiw_scalar).to_bytes().to_array());
+
+        let mut proof = a_cm;
+        for part in [b_cm, c_cm, z_cm, t1_cm, t2_cm, t3_cm, w_xi, w_xiw, evals] {
+            proof.append(&part);
+        }
+        assert_eq!(proof.len(), PLONK_PROOF_LEN);
+        proof
+    }
+
+    /// Register the `w·w = x` VK under `circuit_id` and return (client, vk).
+    fn setup_plonk(env: &Env, circuit_id: u32) -> (ZkCommitmentVerifierClient<'_>, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(env),));
+        let client = ZkCommitmentVerifierClient::new(env, &contract_id);
+        let vk = build_plonk_vk(env);
+        client.register_vk(&circuit_id, &vk);
+        (client, vk)
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  PLONK / KZG tests (Mode 9)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_plonk_valid_proof() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let public_inputs = build_plonk_public_inputs(&env, 1, 49);
+
+        env.cost_estimate().budget().reset_default();
+        assert!(client.verify(&public_inputs, &proof), "Honest PLONK proof should pass");
+    }
+
+    #[test]
+    fn test_plonk_wrong_public_input_fails() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let public_inputs = build_plonk_public_inputs(&env, 1, 50);
+
+        assert!(!client.verify(&public_inputs, &proof), "Proof must not verify for another x");
+    }
+
+    #[test]
+    fn test_plonk_tampered_evaluation_fails() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let public_inputs = build_plonk_public_inputs(&env, 1, 49);
+
+        // Flip the last byte of ā
+        let idx = 9 * 96 + 31;
+        let mut tampered = proof.clone();
+        tampered.set(idx, proof.get(idx).unwrap() ^ 0x01);
+
+        assert!(!client.verify(&public_inputs, &tampered), "Tampered evaluation must fail");
+    }
+
+    #[test]
+    fn test_plonk_unknown_vk_fails() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let public_inputs = build_plonk_public_inputs(&env, 2, 49);
+
+        assert!(!client.verify(&public_inputs, &proof), "Unregistered circuit_id must fail");
+    }
+
+    #[test]
+    fn test_plonk_input_count_mismatch_fails() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let mut public_inputs = build_plonk_public_inputs(&env, 1, 49);
+        public_inputs.append(&fr_bytes(&env, &fr_u32(&env, 1)));
+
+        assert!(!client.verify(&public_inputs, &proof), "Extra public input must fail");
+    }
+
+    #[test]
+    fn test_vk_registry_rotation() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+        assert_eq!(client.get_vk(&1), Some(vk.clone()));
+        assert_eq!(client.get_vk(&2), None);
+
+        // Rotate circuit 1 to a key with a different (wrong) K1: old proofs stop verifying
+        let proof = build_plonk_proof(&env, &vk, 49, 7);
+        let public_inputs = build_plonk_public_inputs(&env, 1, 49);
+        assert!(client.verify(&public_inputs, &proof));
+
+        let mut rotated = vk.clone();
+        rotated.set(PLONK_VK_K1_OFFSET + 31, 5);
+        client.register_vk(&1, &rotated);
+        assert_eq!(client.get_vk(&1), Some(rotated));
+        assert!(!client.verify(&public_inputs, &proof), "Proof against the old key must fail");
+    }
+
+    #[test]
+    fn test_register_vk_requires_admin() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(ZkCommitmentVerifier, (&admin,));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        assert_eq!(client.get_admin(), admin);
+
+        // No auth mocked: the admin signature is missing
+        let vk = Bytes::from_array(&env, &[1u8; 8]);
+        assert!(client.try_register_vk(&1, &vk).is_err());
+        assert_eq!(client.get_vk(&1), None);
+    }
+
+    #[test]
+    fn test_plonk_vk_bad_omega_rejected() {
+        let env = Env::default();
+        let (client, vk) = setup_plonk(&env, 1);
+
+        // ω = 1 is not a primitive root of unity
+        let mut bad_vk = vk.clone();
+        let mut i = 0u32;
+        while i < 32 {
+            bad_vk.set(PLONK_VK_OMEGA_OFFSET + i, if i == 31 { 1 } else { 0 });
+            i += 1;
+        }
+        assert_eq!(client.try_register_vk(&5, &bad_vk), Err(Ok(ZkVerifyError::PlonkInvalidVk)));
+        assert_eq!(client.get_vk(&5), None);
+
+        // Nor can a registered circuit be rotated to a malformed key
+        let short = vk.slice(..PLONK_VK_LEN - 1);
+        assert_eq!(client.try_register_vk(&1, &short), Err(Ok(ZkVerifyError::PlonkInvalidVk)));
+        assert_eq!(client.get_vk(&1), Some(vk));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Range proof helpers (Mode 10)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Deterministic "random" scalar for test provers: Fr(keccak256(label || a || b)).
+    fn test_scalar(env: &Env, label: &[u8], a: u32, b: u32) -> Fr {
+        let mut pre = Bytes::from_slice(env, label);
+        pre.append(&Bytes::from_array(env, &a.to_be_bytes()));
+        pre.append(&Bytes::from_array(env, &b.to_be_bytes()));
+        let hash: BytesN<32> = env.crypto().keccak256(&pre).into();
+        Fr::from_bytes(hash)
+    }
+
+    /// Build a Mode 10 range proof that C = value·G + blinding·H lies in [lo, hi].
+    /// Returns (public_inputs, proof). Values outside the range still produce
+    /// a well-formed proof, which the verifier must reject. Proving runs with an
+    /// unlimited budget; the budget is reset to default before returning so the
+    /// verify call is metered as on-chain.
+    fn build_range_proof(
+        env: &Env,
+        value: u32,
+        blinding: &Fr,
+        lo: u32,
+        hi: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let neg_g = -g.clone();
+
+        let commitment = bls.g1_msm(
+            vec![env, g.clone(), h.clone()],
+            vec![env, fr_u32(env, value), blinding.clone()],
+        );
+
+        let width = hi - lo;
+        let m = if width == 0 { 1 } else { 32 - width.leading_zeros() };
+        let shift = (1u32 << m) - 1 - width;
+        let x1 = value.wrapping_sub(lo);
+        let values = [x1, x1.wrapping_add(shift)];
+
+        let mut public_inputs = Bytes::from_array(env, &commitment.to_bytes().to_array());
+        public_inputs.append(&Bytes::from_array(env, &lo.to_be_bytes()));
+        public_inputs.append(&Bytes::from_array(env, &hi.to_be_bytes()));
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+
+        // ── Bit commitments and first OR-proof move ─────────────────────────
+        let mut preimage = public_inputs.slice(0..104);
+        let mut bit_points: soroban_sdk::Vec<G1Affine> = soroban_sdk::Vec::new(env);
+        let mut first_moves: soroban_sdk::Vec<G1Affine> = soroban_sdk::Vec::new(env);
+        let mut bit_blindings: soroban_sdk::Vec<Fr> = soroban_sdk::Vec::new(env);
+        let mut bit_values: soroban_sdk::Vec<u32> = soroban_sdk::Vec::new(env);
+
+        let mut j = 0u32;
+        while j < 2 {
+            let x = values[j as usize];
+            // s_i random for i < m−1; the top blinding closes Σ 2^i·s_i = r
+            let mut acc = fr_u32(env, 0);
+            let mut i = 0u32;
+            while i < m {
+                let s_i = if i + 1 < m {
+                    let s = test_scalar(env, b"range-s", j, i);
+                    acc = acc + s.clone() * fr_u32(env, 1u32 << i);
+                    s
+                } else {
+                    (blinding.clone() - acc.clone()) * bls.fr_inv(&fr_u32(env, 1u32 << i))
+                };
+                let bit = (x >> i) & 1;
+                let b_i = bls.g1_msm(
+                    vec![env, g.clone(), h.clone()],
+                    vec![env, fr_u32(env, bit), s_i.clone()],
+                );
+
+                let k = test_scalar(env, b"range-k", j, i);
+                let e_sim = test_scalar(env, b"range-e", j, i);
+                let z_sim = test_scalar(env, b"range-z", j, i);
+                let real_r = bls.g1_mul(&h, &k);
+                let sim_base = if bit == 0 { bls.g1_add(&b_i, &neg_g) } else { b_i.clone() };
+                let sim_r = bls.g1_msm(
+                    vec![env, h.clone(), sim_base],
+                    vec![env, z_sim, fr_neg(env, &e_sim)],
+                );
+                let (r0, r1) = if bit == 0 { (real_r, sim_r) } else { (sim_r, real_r) };
+
+                preimage.append(&Bytes::from_array(env, &b_i.to_bytes().to_array()));
+                preimage.append(&Bytes::from_array(env, &r0.to_bytes().to_array()));
+                preimage.append(&Bytes::from_array(env, &r1.to_bytes().to_array()));
+
+                bit_points.push_back(b_i);
+                first_moves.push_back(r0);
+                first_moves.push_back(r1);
+                bit_blindings.push_back(s_i);
+                bit_values.push_back(bit);
+                i += 1;
+            }
+            j += 1;
+        }
+
+        preimage.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        preimage.append(&player.to_string().to_bytes());
+        preimage.append(&Bytes::from_array(env, &RANGE_CHALLENGE_TAG));
+        let e_hash: BytesN<32> = transcript::fs_digest(env, &preimage);
+        let e = Fr::from_bytes(e_hash);
+
+        // ── Responses ───────────────────────────────────────────────────────
+        let mut proof = Bytes::new(env);
+        let mut idx = 0u32;
+        while idx < 2 * m {
+            let (j, i) = (idx / m, idx % m);
+            let bit = bit_values.get_unchecked(idx);
+            let k = test_scalar(env, b"range-k", j, i);
+            let e_sim = test_scalar(env, b"range-e", j, i);
+            let z_sim = test_scalar(env, b"range-z", j, i);
+            let e_real = e.clone() - e_sim.clone();
+            let z_real = k + e_real.clone() * bit_blindings.get_unchecked(idx);
+            let (e0, z0, z1) = if bit == 0 {
+                (e_real, z_real, z_sim)
+            } else {
+                (e_sim, z_sim, z_real)
+            };
+
+            proof.append(&Bytes::from_array(env, &bit_points.get_unchecked(idx).to_bytes().to_array()));
+            proof.append(&Bytes::from_array(env, &first_moves.get_unchecked(2 * idx).to_bytes().to_array()));
+            proof.append(&Bytes::from_array(env, &first_moves.get_unchecked(2 * idx + 1).to_bytes().to_array()));
+            proof.append(&fr_bytes(env, &e0));
+            proof.append(&fr_bytes(env, &z0));
+            proof.append(&fr_bytes(env, &z1));
+            idx += 1;
+        }
+
+        restore_verify_budget(env);
+        (public_inputs, proof)
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Range proof tests (Mode 10)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_range_card_id_valid() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let blinding = test_scalar(&env, b"blinding", 0, 0);
+        let (public_inputs, proof) = build_range_proof(&env, 27, &blinding, 0, 35, 300, &player);
+        assert_eq!(proof.len(), 2 * 6 * RANGE_BIT_PROOF_LEN);
+
+        assert!(client.verify(&public_inputs, &proof), "Card id 27 ∈ [0, 35] should pass");
+    }
+
+    #[test]
+    fn test_range_card_value_bounds_valid() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let blinding = test_scalar(&env, b"blinding", 0, 1);
+        for value in [2u32, 10] {
+            let (public_inputs, proof) = build_range_proof(&env, value, &blinding, 2, 10, 301, &player);
+            assert!(client.verify(&public_inputs, &proof), "Edge value should pass");
+        }
+    }
+
+    #[test]
+    fn test_range_value_above_range_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let blinding = test_scalar(&env, b"blinding", 0, 2);
+        let (public_inputs, proof) = build_range_proof(&env, 11, &blinding, 2, 10, 302, &player);
+        assert!(!client.verify(&public_inputs, &proof), "11 ∉ [2, 10] must fail");
+    }
+
+    #[test]
+    fn test_range_value_below_range_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let blinding = test_scalar(&env, b"blinding", 0, 3);
+        let (public_inputs, proof) = build_range_proof(&env, 1, &blinding, 2, 10, 303, &player);
+        assert!(!client.verify(&public_inputs, &proof), "1 ∉ [2, 10] must fail");
+    }
+
+    #[test]
+    fn test_range_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let blinding = test_scalar(&env, b"blinding", 0, 4);
+        let (mut public_inputs, proof) = build_range_proof(&env, 5, &blinding, 0, 35, 304, &player);
+        public_inputs.set(107, 0xFF); // session_id low byte
+
+        assert!(!client.verify(&public_inputs, &proof), "Replay under another session must fail");
+    }
+
+    #[test]
+    fn test_range_bounds_mismatch_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Proof for [0, 35] (m = 6) presented as [0, 15] (m = 4)
+        let blinding = test_scalar(&env, b"blinding", 0, 5);
+        let (mut public_inputs, proof) = build_range_proof(&env, 5, &blinding, 0, 35, 305, &player);
+        public_inputs.set(103, 15);
+
+        assert!(!client.verify(&public_inputs, &proof), "Bit length must match the bounds");
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Merkle membership helpers (Mode 11)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Pedersen commitment C = card·G + r·H with a label-derived blinding.
+    fn card_commitment(env: &Env, card_id: u32) -> G1Affine {
+        let bls = env.crypto().bls12_381();
+        bls.g1_msm(
+            vec![env, g1_generator(env), pedersen_h(env)],
+            vec![env, fr_u32(env, card_id), test_scalar(env, b"hand-r", card_id, 0)],
+        )
+    }
+
+    fn merkle_leaf(env: &Env, suit: u32, commitment: &G1Affine) -> BytesN<32> {
+        let mut pre = Bytes::from_array(env, &[MERKLE_LEAF_PREFIX]);
+        pre.append(&Bytes::from_array(env, &suit.to_be_bytes()));
+        pre.append(&Bytes::from_array(env, &commitment.to_bytes().to_array()));
+        env.crypto().keccak256(&pre).into()
+    }
+
+    fn merkle_node(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let mut pre = Bytes::from_array(env, &[MERKLE_NODE_PREFIX]);
+        pre.append(&Bytes::from_array(env, &left.to_array()));
+        pre.append(&Bytes::from_array(env, &right.to_array()));
+        env.crypto().keccak256(&pre).into()
+    }
+
+    /// Build a hand tree (padded with zero leaves to 2^depth) and return
+    /// (root, proof) for the card at `index`.
+    fn build_merkle_proof(env: &Env, hand: &[u32], depth: u32, index: u32) -> (BytesN<32>, Bytes) {
+        let mut level: soroban_sdk::Vec<BytesN<32>> = soroban_sdk::Vec::new(env);
+        for i in 0..(1u32 << depth) {
+            let leaf = match hand.get(i as usize) {
+                Some(&card) => merkle_leaf(env, card / 9, &card_commitment(env, card)),
+                None => BytesN::from_array(env, &[0u8; 32]),
+            };
+            level.push_back(leaf);
+        }
+
+        let mut proof = Bytes::from_array(env, &index.to_be_bytes());
+        let mut pos = index;
+        while level.len() > 1 {
+            proof.append(&Bytes::from_array(env, &level.get_unchecked(pos ^ 1).to_array()));
+            let mut next: soroban_sdk::Vec<BytesN<32>> = soroban_sdk::Vec::new(env);
+            let mut i = 0;
+            while i < level.len() {
+                next.push_back(merkle_node(env, &level.get_unchecked(i), &level.get_unchecked(i + 1)));
+                i += 2;
+            }
+            level = next;
+            pos /= 2;
+        }
+        (level.get_unchecked(0), proof)
+    }
+
+    fn merkle_public_inputs(env: &Env, root: &BytesN<32>, suit: u32, commitment: &G1Affine) -> Bytes {
+        let mut pi = Bytes::from_array(env, &root.to_array());
+        pi.append(&Bytes::from_array(env, &suit.to_be_bytes()));
+        pi.append(&Bytes::from_array(env, &commitment.to_bytes().to_array()));
+        pi
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Merkle membership tests (Mode 11)
+    // ════════════════════════════════════════════════════════════════════════
+
+    // Hand: 3♠(1), 7♥(14), 9♥(16), 2♣(18), 10♦(35)
+    const MERKLE_HAND: [u32; 5] = [1, 14, 16, 18, 35];
+
+    #[test]
+    fn test_merkle_membership_valid() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+
+        let (root, proof) = build_merkle_proof(&env, &MERKLE_HAND, 3, 2);
+        assert_eq!(proof.len(), 4 + 3 * 32);
+        let pi = merkle_public_inputs(&env, &root, 1, &card_commitment(&env, 16));
+
+        assert!(client.verify(&pi, &proof), "9♥ is in the hand under suit 1");
+    }
+
+    #[test]
+    fn test_merkle_wrong_suit_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+
+        let (root, proof) = build_merkle_proof(&env, &MERKLE_HAND, 3, 2);
+        let pi = merkle_public_inputs(&env, &root, 0, &card_commitment(&env, 16));
+
+        assert!(!client.verify(&pi, &proof), "Leaf is tagged with suit 1, not 0");
+    }
+
+    #[test]
+    fn test_merkle_card_not_in_hand_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+
+        // 8♥ (15) is not in the hand; reuse 9♥'s path
+        let (root, proof) = build_merkle_proof(&env, &MERKLE_HAND, 3, 2);
+        let pi = merkle_public_inputs(&env, &root, 1, &card_commitment(&env, 15));
+
+        assert!(!client.verify(&pi, &proof), "Commitment outside the tree must fail");
+    }
+
+    #[test]
+    fn test_merkle_tampered_path_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+
+        let (root, mut proof) = build_merkle_proof(&env, &MERKLE_HAND, 3, 2);
+        let pi = merkle_public_inputs(&env, &root, 1, &card_commitment(&env, 16));
+
+        // Flip the index to the sibling position
+        proof.set(3, 3);
+        assert!(!client.verify(&pi, &proof), "Wrong leaf position must fail");
+
+        // Index beyond the tree width
+        proof.set(3, 8);
+        assert!(!client.verify(&pi, &proof), "Index ≥ 2^depth must fail");
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Hidden-set ring helpers (Mode 12)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Hand commitment P = card·G + r_card·H (same blinding as `card_commitment`).
+    fn hand_commitments(env: &Env, cards: &[u32]) -> soroban_sdk::Vec<G1Affine> {
+        let mut out = soroban_sdk::Vec::new(env);
+        for &card in cards {
+            out.push_back(card_commitment(env, card));
+        }
+        out
+    }
+
+    /// Build a Mode 12 proof that a fresh commitment to `card_id` matches
+    /// `ring[real]`. Returns (public_inputs, proof).
+    fn build_hidden_ring_proof(
+        env: &Env,
+        card_id: u32,
+        ring: &soroban_sdk::Vec<G1Affine>,
+        real: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        build_hidden_ring_proof_listing(env, card_id, ring, ring.len(), real, session_id, player)
+    }
+
+    /// As `build_hidden_ring_proof`, but only `ring[..listed]` goes into the
+    /// public inputs; with `listed < ring.len()` the N field carries
+    /// `RING_PADDED_FLAG` and the rest of `ring` should be P_pad.
+    fn build_hidden_ring_proof_listing(
+        env: &Env,
+        card_id: u32,
+        ring: &soroban_sdk::Vec<G1Affine>,
+        listed: u32,
+        real: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        let bls = env.crypto().bls12_381();
+        let h = pedersen_h(env);
+        let n = ring.len();
+        let flags = if listed < n { HIDDEN_RING_FLAG | RING_PADDED_FLAG } else { HIDDEN_RING_FLAG };
+
+        // Fresh commitment C = card·G + r·H; witness w = r − r_card
+        let r = test_scalar(env, b"play-r", card_id, session_id);
+        let commitment = bls.g1_msm(
+            vec![env, g1_generator(env), h.clone()],
+            vec![env, fr_u32(env, card_id), r.clone()],
+        );
+        let w = r - test_scalar(env, b"hand-r", card_id, 0);
+        let c_bytes = Bytes::from_array(env, &commitment.to_bytes().to_array());
+
+        let mut public_inputs = Bytes::new(env);
+        let commit_hash: BytesN<32> = env.crypto().keccak256(&c_bytes).into();
+        public_inputs.append(&Bytes::from_array(env, &commit_hash.to_array()));
+        public_inputs.append(&Bytes::from_array(env, &(listed | flags).to_be_bytes()));
+        for p in ring.iter().take(listed as usize) {
+            public_inputs.append(&Bytes::from_array(env, &p.to_bytes().to_array()));
+        }
+        let sid_offset = public_inputs.len();
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+
+        // First moves: simulated for i ≠ real, honest for i = real
+        let mut preimage = c_bytes;
+        preimage.append(&public_inputs.slice(36..sid_offset));
+        let mut e_sim_sum = fr_u32(env, 0);
+        let mut i = 0u32;
+        while i < n {
+            let r_i = if i == real {
+                bls.g1_mul(&h, &test_scalar(env, b"ring-t", i, 0))
+            } else {
+                let d_i = bls.g1_add(&commitment, &-ring.get_unchecked(i));
+                let e_i = test_scalar(env, b"ring-e", i, 0);
+                e_sim_sum = e_sim_sum + e_i.clone();
+                bls.g1_msm(
+                    vec![env, h.clone(), d_i],
+                    vec![env, test_scalar(env, b"ring-z", i, 0), fr_neg(env, &e_i)],
+                )
+            };
+            preimage.append(&Bytes::from_array(env, &r_i.to_bytes().to_array()));
+            i += 1;
+        }
+        preimage.append(&public_inputs.slice(sid_offset..));
+        preimage.append(&Bytes::from_array(env, &HIDDEN_RING_CHALLENGE_TAG));
+        let e_hash: BytesN<32> = transcript::fs_digest(env, &preimage);
+        let e = Fr::from_bytes(e_hash);
+
+        let mut proof = Bytes::from_array(env, &commitment.to_bytes().to_array());
+        let mut i = 0u32;
+        while i < n {
+            let (e_i, z_i) = if i == real {
+                let e_real = e.clone() - e_sim_sum.clone();
+                let z_real = test_scalar(env, b"ring-t", i, 0) + e_real.clone() * w.clone();
+                (e_real, z_real)
+            } else {
+                (test_scalar(env, b"ring-e", i, 0), test_scalar(env, b"ring-z", i, 0))
+            };
+            proof.append(&fr_bytes(env, &e_i));
+            proof.append(&fr_bytes(env, &z_i));
+            i += 1;
+        }
+        (public_inputs, proof)
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Hidden-set ring tests (Mode 12)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_hidden_ring_valid_proof() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Hearts in hand: 7♥(14), 9♥(16) — ring hides which one is played
+        let ring = hand_commitments(&env, &[14, 16]);
+        let (pi, proof) = build_hidden_ring_proof(&env, 16, &ring, 1, 400, &player);
+
+        assert!(client.verify(&pi, &proof), "Played card matches a hand commitment");
+    }
+
+    #[test]
+    fn test_hidden_ring_card_not_in_ring_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Claims 8♥ (15) matches ring[0] = 7♥ — witness is wrong
+        let ring = hand_commitments(&env, &[14, 16]);
+        let (pi, proof) = build_hidden_ring_proof(&env, 15, &ring, 0, 401, &player);
+
+        assert!(!client.verify(&pi, &proof), "Card outside the ring must fail");
+    }
+
+    #[test]
+    fn test_hidden_ring_swapped_commitment_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let ring = hand_commitments(&env, &[14, 16]);
+        let (mut pi, proof) = build_hidden_ring_proof(&env, 16, &ring, 1, 402, &player);
+
+        // Replace ring[1] with a commitment to another card
+        let other = card_commitment(&env, 17).to_bytes().to_array();
+        let mut k = 0u32;
+        while k < 96 {
+            pi.set(36 + 96 + k, other[k as usize]);
+            k += 1;
+        }
+        assert!(!client.verify(&pi, &proof), "Ring members are bound into the challenge");
+    }
+
+    #[test]
+    fn test_hidden_ring_flag_mismatch_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let ring = hand_commitments(&env, &[14, 16]);
+        let (mut pi, proof) = build_hidden_ring_proof(&env, 16, &ring, 1, 403, &player);
+        pi.set(35, 3); // N = 3 with the flag set, but the proof has 2 members
+
+        assert!(!client.verify(&pi, &proof), "N must match the proof length");
+    }
+
+    #[test]
+    fn test_hidden_ring_full_suit_within_budget() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // All nine hearts (9..=17) — the largest ring Mode 12 accepts
+        env.cost_estimate().budget().reset_unlimited();
+        let ring = hand_commitments(&env, &[9, 10, 11, 12, 13, 14, 15, 16, 17]);
+        let (pi, proof) = build_hidden_ring_proof(&env, 13, &ring, 4, 404, &player);
+
+        env.cost_estimate().budget().reset_default();
+        assert!(client.verify(&pi, &proof), "N = 9 ring should fit the default budget");
+    }
+
+    #[test]
+    fn test_hidden_ring_padded() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Two hearts in hand, padded to nine members with P_pad
+        env.cost_estimate().budget().reset_unlimited();
+        let mut ring = hand_commitments(&env, &[14, 16]);
+        let pad = cangkulan_prover::ring_pad_point(&env);
+        while ring.len() < RING_MAX_SET_SIZE {
+            ring.push_back(pad.clone());
+        }
+        let (pi, proof) = build_hidden_ring_proof_listing(&env, 16, &ring, 2, 1, 405, &player);
+        // The same statement without padding, for the length comparison
+        let (_, unpadded) = build_hidden_ring_proof(&env, 16, &hand_commitments(&env, &[14, 16]), 1, 405, &player);
+        env.cost_estimate().budget().reset_default();
+
+        assert_eq!(proof.len(), RING_PROOF_BASE_LEN + RING_MAX_SET_SIZE * RING_MEMBER_PROOF_LEN);
+        assert!(proof.len() > unpadded.len());
+        assert!(client.verify(&pi, &proof), "Padded hidden ring should fit the default budget");
+
+        // Dropping the padding flag makes N disagree with the proof
+        let mut unflagged = pi.clone();
+        unflagged.set(32, (HIDDEN_RING_FLAG >> 24) as u8);
+        assert!(!client.verify(&unflagged, &proof));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Private cangkul hand helpers (Mode 13)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Build a Mode 13 proof that `hand[first..first + count]` (committed with
+    /// `card_commitment`) has no card in `trick_suit`. A card in the trick
+    /// suit still yields a well-formed proof, which the verifier must reject.
+    /// Returns (public_inputs, proof) with the budget reset to default.
+    fn build_private_hand_proof(
+        env: &Env,
+        hand: &[u32],
+        first: u32,
+        count: u32,
+        trick_suit: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let cards = &hand[first as usize..(first + count) as usize];
+
+        let mut commitments = Bytes::new(env);
+        for &card in hand {
+            commitments.append(&Bytes::from_array(env, &card_commitment(env, card).to_bytes().to_array()));
+        }
+        let commit_hash: BytesN<32> = env.crypto().keccak256(&commitments).into();
+        let mut public_inputs = Bytes::from_array(env, &commit_hash.to_array());
+        public_inputs.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
+        public_inputs.append(&Bytes::from_array(env, &(hand.len() as u32).to_be_bytes()));
+        public_inputs.append(&commitments);
+        public_inputs.append(&Bytes::from_array(env, &first.to_be_bytes()));
+        let sid_offset = public_inputs.len();
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+
+        // card = 9·suit + 3·a + b; per OR-proof the statement point P, its
+        // witness, and the real branch (a trick-suit card falls back to branch 0).
+        let statements = |card: u32| {
+            let (suit, a, b) = (card / 9, (card % 9) / 3, card % 3);
+            let alpha = test_scalar(env, b"suit-a", card, session_id);
+            let beta = test_scalar(env, b"trit-a", card, session_id);
+            let s_point = bls.g1_msm(vec![env, g.clone(), h.clone()], vec![env, fr_u32(env, suit), alpha.clone()]);
+            let a_point = bls.g1_msm(vec![env, g.clone(), h.clone()], vec![env, fr_u32(env, a), beta.clone()]);
+            let b_point = bls.g1_msm(
+                vec![env, card_commitment(env, card), s_point.clone(), a_point.clone()],
+                vec![env, fr_u32(env, 1), fr_neg(env, &fr_u32(env, 9)), fr_neg(env, &fr_u32(env, 3))],
+            );
+            let b_witness = test_scalar(env, b"hand-r", card, 0)
+                - alpha.clone() * fr_u32(env, 9)
+                - beta.clone() * fr_u32(env, 3);
+            let suit_branch = match suit.cmp(&trick_suit) {
+                core::cmp::Ordering::Less => suit,
+                core::cmp::Ordering::Greater => suit - 1,
+                core::cmp::Ordering::Equal => 0,
+            };
+            [(s_point, alpha, suit_branch), (a_point, beta, a), (b_point, b_witness, b)]
+        };
+        let branch_value = |or_proof: usize, j: u32| {
+            if or_proof == 0 && j >= trick_suit { j + 1 } else { j }
+        };
+
+        let mut first_moves = Bytes::new(env);
+        for (c, &card) in cards.iter().enumerate() {
+            let c = c as u32;
+            let st = statements(card);
+            first_moves.append(&Bytes::from_array(env, &st[0].0.to_bytes().to_array()));
+            first_moves.append(&Bytes::from_array(env, &st[1].0.to_bytes().to_array()));
+            for (o, (p, _, real)) in st.iter().enumerate() {
+                for j in 0..3u32 {
+                    let label = 3 * o as u32 + j;
+                    let r_j = if j == *real {
+                        bls.g1_mul(&h, &test_scalar(env, b"hand-t", c, label))
+                    } else {
+                        let e_j = test_scalar(env, b"hand-e", c, label);
+                        bls.g1_msm(
+                            vec![env, h.clone(), p.clone(), g.clone()],
+                            vec![
+                                env,
+                                test_scalar(env, b"hand-z", c, label),
+                                fr_neg(env, &e_j),
+                                e_j.clone() * fr_u32(env, branch_value(o, j)),
+                            ],
+                        )
+                    };
+                    first_moves.append(&Bytes::from_array(env, &r_j.to_bytes().to_array()));
+                }
+            }
+        }
+
+        let mut preimage = public_inputs.slice(32..sid_offset);
+        preimage.append(&first_moves);
+        preimage.append(&public_inputs.slice(sid_offset..));
+        preimage.append(&Bytes::from_array(env, &PRIVATE_HAND_CHALLENGE_TAG));
+        let e_hash: BytesN<32> = transcript::fs_digest(env, &preimage);
+        let e = Fr::from_bytes(e_hash);
+
+        let mut proof = Bytes::new(env);
+        for (c, &card) in cards.iter().enumerate() {
+            let c = c as u32;
+            let base = c * PRIVATE_HAND_SCALARS_OFFSET;
+            proof.append(&first_moves.slice(base..base + PRIVATE_HAND_SCALARS_OFFSET));
+            for (o, (_, witness, real)) in statements(card).iter().enumerate() {
+                let label = |j: u32| 3 * o as u32 + j;
+                let mut es: [Fr; 3] = core::array::from_fn(|j| test_scalar(env, b"hand-e", c, label(j as u32)));
+                let mut zs: [Fr; 3] = core::array::from_fn(|j| test_scalar(env, b"hand-z", c, label(j as u32)));
+                let mut e_real = e.clone();
+                for j in 0..3u32 {
+                    if j != *real {
+                        e_real = e_real - es[j as usize].clone();
+                    }
+                }
+                zs[*real as usize] = test_scalar(env, b"hand-t", c, label(*real)) + e_real.clone() * witness.clone();
+                es[*real as usize] = e_real;
+                for e_j in &es {
+                    proof.append(&fr_bytes(env, e_j));
+                }
+                for z_j in &zs {
+                    proof.append(&fr_bytes(env, z_j));
+                }
+            }
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Private cangkul hand tests (Mode 13)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_private_hand_valid_proof() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Trick suit hearts (1): 2♠(0), 10♠(8), 3♦(19), 10♣(35)
+        let (pi, proof) = build_private_hand_proof(&env, &[0, 8, 19, 35], 0, 4, 1, 500, &player);
+
+        assert!(client.verify(&pi, &proof), "Hand without hearts should verify");
+    }
+
+    #[test]
+    fn test_private_hand_chunks_cover_dealt_hand() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // A dealt hand of five is proven as [0, 4) and [4, 5), each within the default budget
+        let hand = [0, 8, 19, 27, 35];
+        let (pi, proof) = build_private_hand_proof(&env, &hand, 0, 4, 1, 501, &player);
+        assert!(client.verify(&pi, &proof), "First chunk should verify");
+        let (pi, proof) = build_private_hand_proof(&env, &hand, 4, 1, 1, 501, &player);
+        assert!(client.verify(&pi, &proof), "Second chunk should verify");
+    }
+
+    #[test]
+    fn test_private_hand_matching_suit_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // 5♥ (12) is in the trick suit
+        let (pi, proof) = build_private_hand_proof(&env, &[0, 12, 27], 0, 3, 1, 502, &player);
+
+        assert!(!client.verify(&pi, &proof), "A hidden trick-suit card must fail");
+    }
+
+    #[test]
+    fn test_private_hand_trick_suit_swapped_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (mut pi, proof) = build_private_hand_proof(&env, &[0, 19, 27], 0, 3, 1, 503, &player);
+        pi.set(35, 0); // claim the proof excludes spades instead
+
+        assert!(!client.verify(&pi, &proof), "Trick suit is bound into the proof");
+    }
+
+    #[test]
+    fn test_private_hand_commit_mismatch_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (mut pi, proof) = build_private_hand_proof(&env, &[0, 19, 27], 0, 3, 1, 504, &player);
+        pi.set(0, pi.get(0).unwrap() ^ 0x01);
 
-        // ── Extract commit_hash [0..32) ─────────────────────────────────────
-        let mut commit_arr = [0u8; 32];
-        let mut i = 0usize;
-        while i < 32 {
-            commit_arr[i] = public_inputs.get(i as u32).unwrap_or(0);
-            i += 1;
-        }
-        let commit_hash = BytesN::<32>::from_array(env, &commit_arr);
+        assert!(!client.verify(&pi, &proof), "Commit hash must cover the hand commitments");
+    }
 
-        // ── Extract trick_suit [32..36) ─────────────────────────────────────
-        let trick_suit = Self::extract_u32(public_inputs, 32);
-        if trick_suit > 3 {
-            EvVerifyFailed { reason: ZkVerifyError::HandSuitViolation as u32 }.publish(env);
-            return false;
-        }
+    #[test]
+    fn test_private_hand_shifted_chunk_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // ── Cross-check k from public_inputs [36..40) ──────────────────────
-        let k_pi = Self::extract_u32(public_inputs, 36);
-        if k_pi != k {
-            EvVerifyFailed { reason: ZkVerifyError::HandCardCountMismatch as u32 }.publish(env);
-            return false;
-        }
+        // Proof covers card 0; pointing it at card 1 (5♥) must not pass
+        let (mut pi, proof) = build_private_hand_proof(&env, &[0, 12], 0, 1, 1, 505, &player);
+        pi.set(40 + 2 * 96 + 3, 1);
+        assert!(!client.verify(&pi, &proof), "Chunk offset is bound into the challenge");
 
-        // ── Extract card values, verify suit exclusion, build MSM vectors ──
-        let cards_offset = 40u32;
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        let g = G1Affine::from_array(env, &g1_bytes);
+        pi.set(40 + 2 * 96 + 3, 2);
+        assert!(!client.verify(&pi, &proof), "Chunk must lie inside the hand");
+    }
 
-        let mut g_vec: Vec<G1Affine> = Vec::new(env);
-        let mut fr_vec: Vec<Fr> = Vec::new(env);
+    #[test]
+    fn test_private_hand_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        let mut idx = 0u32;
-        while idx < k {
-            let card_val = Self::extract_u32(public_inputs, cards_offset + idx * 4);
+        let (mut pi, proof) = build_private_hand_proof(&env, &[0, 19, 27], 0, 3, 1, 506, &player);
+        pi.set(40 + 3 * 96 + 4 + 3, 0xFF);
 
-            // Card range check
-            if card_val >= 36 {
-                EvVerifyFailed { reason: ZkVerifyError::HandSuitViolation as u32 }.publish(env);
-                return false;
-            }
+        assert!(!client.verify(&pi, &proof), "Session id is bound into the challenge");
+    }
 
-            // Suit exclusion: floor(card / 9) must NOT equal trick_suit
-            if card_val / 9 == trick_suit {
-                EvVerifyFailed { reason: ZkVerifyError::HandSuitViolation as u32 }.publish(env);
-                return false;
-            }
+    // ════════════════════════════════════════════════════════════════════════
+    //  Shuffle permutation helpers (Mode 14)
+    // ════════════════════════════════════════════════════════════════════════
 
-            // Card → Fr scalar (u32 → 32-byte big-endian)
-            let mut card_fr_arr = [0u8; 32];
-            card_fr_arr[28] = ((card_val >> 24) & 0xFF) as u8;
-            card_fr_arr[29] = ((card_val >> 16) & 0xFF) as u8;
-            card_fr_arr[30] = ((card_val >> 8) & 0xFF) as u8;
-            card_fr_arr[31] = (card_val & 0xFF) as u8;
-            let card_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &card_fr_arr));
+    fn shuffle_generator(env: &Env, i: u32) -> G1Affine {
+        let start = (96 * i) as usize;
+        let mut g_i = [0u8; 96];
+        g_i.copy_from_slice(&SHUFFLE_GENERATORS[start..start + 96]);
+        G1Affine::from_array(env, &g_i)
+    }
 
-            g_vec.push_back(g.clone());
-            fr_vec.push_back(card_fr);
-            idx += 1;
+    /// Σ values_i·G_i + blinding·H over the first `values.len()` generators.
+    fn shuffle_vector_commit(env: &Env, values: &[Fr], blinding: &Fr) -> G1Affine {
+        let mut points = vec![env, pedersen_h(env)];
+        let mut scalars = vec![env, blinding.clone()];
+        for (i, v) in values.iter().enumerate() {
+            points.push_back(shuffle_generator(env, i as u32));
+            scalars.push_back(v.clone());
         }
+        env.crypto().bls12_381().g1_msm(points, scalars)
+    }
 
-        // ── Extract session_id [40+4k..44+4k) ──────────────────────────────
-        let sid_offset = 40 + 4 * k;
-        let mut sid_arr = [0u8; 4];
-        i = 0;
-        while i < 4 {
-            sid_arr[i] = public_inputs.get(sid_offset + i as u32).unwrap_or(0);
-            i += 1;
+    /// Build a Mode 14 proof for the deck `cards` (position i holds
+    /// `cards[i]`). A deck that is not a permutation still yields a
+    /// well-formed proof, which the verifier must reject. Returns
+    /// (public_inputs, proof) with the budget reset to default.
+    fn build_shuffle_proof(env: &Env, cards: &[u32; 36], session_id: u32, player: &Address) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let h = pedersen_h(env);
+        let n = cards.len();
+
+        // Position commitments E_i = a_i·G_i + r_i·H
+        let mut public_inputs = Bytes::new(env);
+        let mut r = fr_u32(env, 0);
+        for (i, &card) in cards.iter().enumerate() {
+            let r_i = test_scalar(env, b"deck-r", i as u32, session_id);
+            let e_i = bls.g1_msm(
+                vec![env, shuffle_generator(env, i as u32), h.clone()],
+                vec![env, fr_u32(env, card), r_i.clone()],
+            );
+            public_inputs.append(&Bytes::from_array(env, &e_i.to_bytes().to_array()));
+            r = r + r_i;
         }
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
 
-        // ── Extract player [44+4k..) ────────────────────────────────────────
-        let player_offset = 44 + 4 * k;
-        let player_len = public_inputs.len() - player_offset;
-        if player_len == 0 {
-            EvVerifyFailed { reason: ZkVerifyError::EmptyPlayerAddress as u32 }.publish(env);
-            return false;
-        }
-        let mut player_bytes = Bytes::new(env);
-        let mut j = 0u32;
-        while j < player_len {
-            player_bytes.push_back(public_inputs.get(player_offset + j).unwrap_or(0));
-            j += 1;
+        let mut x_preimage = public_inputs.clone();
+        x_preimage.append(&Bytes::from_array(env, &SHUFFLE_CHALLENGE_TAG));
+        let x_hash: BytesN<32> = transcript::fs_digest(env, &x_preimage);
+        let x = Fr::from_bytes(x_hash.clone());
+
+        // Single value product argument over a_i − x
+        let a: [Fr; 36] = core::array::from_fn(|i| fr_u32(env, cards[i]) - x.clone());
+        let mut b: [Fr; 36] = core::array::from_fn(|_| fr_u32(env, 0));
+        b[0] = a[0].clone();
+        for i in 1..n {
+            b[i] = b[i - 1].clone() * a[i].clone();
         }
+        let d: [Fr; 36] = core::array::from_fn(|i| test_scalar(env, b"svp-d", i as u32, session_id));
+        let mut delta: [Fr; 36] = core::array::from_fn(|i| test_scalar(env, b"svp-delta", i as u32, session_id));
+        delta[0] = d[0].clone();
+        delta[n - 1] = fr_u32(env, 0);
+        let r_d = test_scalar(env, b"svp-rd", 0, session_id);
+        let s_1 = test_scalar(env, b"svp-s1", 0, session_id);
+        let s_x = test_scalar(env, b"svp-sx", 0, session_id);
+
+        let c_d = shuffle_vector_commit(env, &d, &r_d);
+        let small_delta: [Fr; 35] = core::array::from_fn(|i| fr_neg(env, &(delta[i].clone() * d[i + 1].clone())));
+        let c_delta = shuffle_vector_commit(env, &small_delta, &s_1);
+        let big_delta: [Fr; 35] = core::array::from_fn(|i| {
+            delta[i + 1].clone() - a[i + 1].clone() * delta[i].clone() - b[i].clone() * d[i + 1].clone()
+        });
+        let c_big_delta = shuffle_vector_commit(env, &big_delta, &s_x);
 
-        // ── Extract proof fields ────────────────────────────────────────────
-        // A (aggregate commitment) from proof [4..100)
-        let agg_commit = Self::extract_g1(env, proof, 4);
-        if !bls.g1_is_in_subgroup(&agg_commit) {
-            EvVerifyFailed { reason: ZkVerifyError::RingPointNotOnCurve as u32 }.publish(env);
-            return false;
-        }
+        let mut proof = Bytes::from_array(env, &c_d.to_bytes().to_array());
+        proof.append(&Bytes::from_array(env, &c_delta.to_bytes().to_array()));
+        proof.append(&Bytes::from_array(env, &c_big_delta.to_bytes().to_array()));
 
-        // R (Schnorr nonce) from proof [100..196)
-        let nonce_r = Self::extract_g1(env, proof, 100);
-        if !bls.g1_is_in_subgroup(&nonce_r) {
-            EvVerifyFailed { reason: ZkVerifyError::RingPointNotOnCurve as u32 }.publish(env);
-            return false;
+        let mut e_preimage = Bytes::from_array(env, &x_hash.to_array());
+        e_preimage.append(&proof);
+        e_preimage.append(&Bytes::from_array(env, &SHUFFLE_CHALLENGE_TAG));
+        let e_hash: BytesN<32> = transcript::fs_digest(env, &e_preimage);
+        let e = Fr::from_bytes(e_hash);
+
+        for i in 0..n {
+            proof.append(&fr_bytes(env, &(e.clone() * a[i].clone() + d[i].clone())));
         }
+        for i in 0..n {
+            proof.append(&fr_bytes(env, &(e.clone() * b[i].clone() + delta[i].clone())));
+        }
+        proof.append(&fr_bytes(env, &(e.clone() * r + r_d)));
+        proof.append(&fr_bytes(env, &(e * s_x + s_1)));
+        restore_verify_budget(env);
+        (public_inputs, proof)
+    }
 
-        // z (Schnorr response) from proof [196..228)
-        let z = Self::extract_fr(env, proof, 196);
+    /// Deck order used by the shuffle tests: position i holds (7·i + 3) mod 36.
+    fn shuffled_deck() -> [u32; 36] {
+        core::array::from_fn(|i| (7 * i as u32 + 3) % 36)
+    }
 
-        // ── Binding check: keccak256(A) == commit_hash ──────────────────────
-        let a_raw = agg_commit.to_bytes();
-        let a_bytes_for_hash = Bytes::from_array(env, &a_raw.to_array());
-        let computed_commit: BytesN<32> = env.crypto().keccak256(&a_bytes_for_hash).into();
-        if computed_commit != commit_hash {
-            EvVerifyFailed { reason: ZkVerifyError::CommitmentMismatch as u32 }.publish(env);
-            return false;
-        }
+    // ════════════════════════════════════════════════════════════════════════
+    //  Shuffle permutation tests (Mode 14)
+    // ════════════════════════════════════════════════════════════════════════
 
-        // ── Compute expected_sum = Σ(card_i · G) via MSM ────────────────────
-        let expected_sum = bls.g1_msm(g_vec, fr_vec);
+    #[test]
+    fn test_shuffle_valid_permutation() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // ── delta = A − expected_sum (should be r_agg · H) ─────────────────
-        let neg_expected = -expected_sum;
-        let delta = bls.g1_add(&agg_commit, &neg_expected);
+        let (pi, proof) = build_shuffle_proof(&env, &shuffled_deck(), 600, &player);
 
-        // ── H generator ─────────────────────────────────────────────────────
-        let h = Self::pedersen_h(&bls, env);
+        assert!(client.verify(&pi, &proof), "Permutation of the deck should verify within the default budget");
+    }
 
-        // ── Fiat-Shamir challenge ───────────────────────────────────────────
-        // e = Fr(keccak256(A || R || trick_suit(4) || k(4) || session_id(4) || player || "ZKP8"))
-        let mut challenge_preimage = Bytes::from_array(env, &a_raw.to_array());
-        let r_raw = nonce_r.to_bytes();
-        challenge_preimage.append(&Bytes::from_array(env, &r_raw.to_array()));
-        challenge_preimage.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
-        challenge_preimage.append(&Bytes::from_array(env, &k.to_be_bytes()));
-        challenge_preimage.append(&Bytes::from_array(env, &sid_arr));
-        challenge_preimage.append(&player_bytes);
-        challenge_preimage.append(&Bytes::from_array(env, &CANGKUL_CHALLENGE_TAG));
-
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_preimage).into();
-        let e = Fr::from_bytes(e_hash);
+    #[test]
+    fn test_shuffle_duplicate_card_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // ── Schnorr verification: z · H == R + e · delta ────────────────────
-        let z_h = bls.g1_mul(&h, &z);
-        let e_delta = bls.g1_mul(&delta, &e);
-        let r_plus_e_delta = bls.g1_add(&nonce_r, &e_delta);
+        // deck[6] appears twice and deck[5] is missing
+        let mut deck = shuffled_deck();
+        deck[5] = deck[6];
+        let (pi, proof) = build_shuffle_proof(&env, &deck, 601, &player);
 
-        if z_h.to_bytes() != r_plus_e_delta.to_bytes() {
-            EvVerifyFailed { reason: ZkVerifyError::HandSchnorrCheckFailed as u32 }.publish(env);
-            return false;
-        }
+        assert!(!client.verify(&pi, &proof), "Deck with a duplicate must fail");
+    }
 
-        EvVerifySuccess { mode: 8 }.publish(env);
-        true
+    #[test]
+    fn test_shuffle_same_sum_different_values_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // Keeps Σ a_i unchanged but is not a permutation
+        let mut deck = shuffled_deck();
+        deck[0] += 1;
+        deck[1] -= 1;
+        let (pi, proof) = build_shuffle_proof(&env, &deck, 602, &player);
+
+        assert!(!client.verify(&pi, &proof), "Only a true permutation passes the product check");
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, vec, Address, Env};
-    use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
+    #[test]
+    fn test_shuffle_reordered_commitments_fail() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (pi, proof) = build_shuffle_proof(&env, &shuffled_deck(), 603, &player);
 
+        // Swap E_0 and E_1: same vector sum, different transcript
+        let mut swapped = pi.slice(96..192);
+        swapped.append(&pi.slice(0..96));
+        swapped.append(&pi.slice(192..));
+        assert!(!client.verify(&swapped, &proof), "Commitment order is bound into the challenge");
+    }
+
+    #[test]
+    fn test_shuffle_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
+        let (mut pi, proof) = build_shuffle_proof(&env, &shuffled_deck(), 604, &player);
+        pi.set(96 * 36 + 3, 0xFF);
+
+        assert!(!client.verify(&pi, &proof), "Session id is bound into the challenge");
+    }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  NIZK seed proof helpers (new!)
+    //  Mental-poker ElGamal helpers (Mode 15)
     // ════════════════════════════════════════════════════════════════════════
 
-    /// Compute seed_hash = keccak256(seed)
-    fn compute_seed_hash(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
-        env.crypto().keccak256(&Bytes::from_array(env, &seed.to_array())).into()
+    fn g1_bytes(env: &Env, p: &G1Affine) -> Bytes {
+        Bytes::from_array(env, &p.to_bytes().to_array())
     }
 
-    /// Compute blinded commitment = keccak256(seed_hash || blinding || player_address)
-    fn compute_nizk_commitment(
-        env: &Env,
-        seed_hash: &BytesN<32>,
-        blinding: &BytesN<32>,
-        player: &Address,
-    ) -> BytesN<32> {
-        let mut pre = Bytes::from_array(env, &seed_hash.to_array());
-        pre.append(&Bytes::from_array(env, &blinding.to_array()));
-        pre.append(&player.to_string().to_bytes());
-        env.crypto().keccak256(&pre).into()
+    /// Encrypt `card` under `pk` with randomness `r`: (r·G, card·G + r·PK).
+    fn elgamal_encrypt(env: &Env, card: u32, pk: &G1Affine, r: &Fr) -> (G1Affine, G1Affine) {
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let c1 = bls.g1_mul(&g, r);
+        let c2 = bls.g1_msm(vec![env, g, pk.clone()], vec![env, fr_u32(env, card), r.clone()]);
+        (c1, c2)
     }
 
-    /// Compute nullifier = keccak256(seed_hash || "NULL" || session_id_be4)
-    fn compute_nullifier(env: &Env, seed_hash: &BytesN<32>, session_id: u32) -> BytesN<32> {
-        let mut pre = Bytes::from_array(env, &seed_hash.to_array());
-        pre.append(&Bytes::from_array(env, &NULLIFIER_TAG));
-        pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        env.crypto().keccak256(&pre).into()
+    /// Chaum–Pedersen proof that log_G(P) == log_B(Q) == x, with `output`
+    /// as the first proof point. Returns the 320-byte proof.
+    fn build_dleq_proof(
+        env: &Env,
+        public_inputs: &Bytes,
+        output: &G1Affine,
+        base: &G1Affine,
+        x: &Fr,
+        nonce_label: &[u8],
+    ) -> Bytes {
+        let bls = env.crypto().bls12_381();
+        let k = test_scalar(env, nonce_label, 0, 0);
+        let a1 = bls.g1_mul(&g1_generator(env), &k);
+        let a2 = bls.g1_mul(base, &k);
+
+        let mut proof = g1_bytes(env, output);
+        proof.append(&g1_bytes(env, &a1));
+        proof.append(&g1_bytes(env, &a2));
+
+        let mut pre = public_inputs.clone();
+        pre.append(&proof);
+        pre.append(&Bytes::from_array(env, &ELGAMAL_CHALLENGE_TAG));
+        let e_hash: BytesN<32> = transcript::fs_digest(env, &pre);
+        let e = Fr::from_bytes(e_hash);
+        proof.append(&fr_bytes(env, &(k + e * x.clone())));
+        proof
     }
 
-    /// Compute Fiat-Shamir challenge = keccak256(commitment || session_id_be4 || player || "ZKV2")
-    fn compute_fs_challenge(
+    /// Kind 0: player with secret `sk` publishes D = sk·c1.
+    /// Returns (public_inputs, proof, D).
+    fn build_decrypt_share(
         env: &Env,
-        commitment: &BytesN<32>,
+        sk: &Fr,
+        c1: &G1Affine,
         session_id: u32,
         player: &Address,
-    ) -> BytesN<32> {
-        let mut pre = Bytes::from_array(env, &commitment.to_array());
-        pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        pre.append(&player.to_string().to_bytes());
-        pre.append(&Bytes::from_array(env, &CHALLENGE_TAG));
-        env.crypto().keccak256(&pre).into()
-    }
+    ) -> (Bytes, Bytes, G1Affine) {
+        let bls = env.crypto().bls12_381();
+        let pk = bls.g1_mul(&g1_generator(env), sk);
+        let share = bls.g1_mul(c1, sk);
 
-    /// Compute response = keccak256(seed_hash || challenge || blinding)
-    fn compute_response(
-        env: &Env,
-        seed_hash: &BytesN<32>,
-        challenge: &BytesN<32>,
-        blinding: &BytesN<32>,
-    ) -> BytesN<32> {
-        let mut pre = Bytes::from_array(env, &seed_hash.to_array());
-        pre.append(&Bytes::from_array(env, &challenge.to_array()));
-        pre.append(&Bytes::from_array(env, &blinding.to_array()));
-        env.crypto().keccak256(&pre).into()
+        let mut pi = Bytes::from_array(env, &ELGAMAL_KIND_DECRYPT.to_be_bytes());
+        pi.append(&g1_bytes(env, &pk));
+        pi.append(&g1_bytes(env, c1));
+        pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        pi.append(&player.to_string().to_bytes());
+
+        let proof = build_dleq_proof(env, &pi, &share, c1, sk, b"elgamal-dec");
+        (pi, proof, share)
     }
 
-    /// Build NIZK public inputs
-    fn encode_nizk_public_inputs(
+    /// Kind 1: re-encrypt (c1, c2) under `pk` with fresh randomness `s`.
+    /// Returns (public_inputs, proof).
+    fn build_reencrypt_proof(
         env: &Env,
-        seed_hash: &BytesN<32>,
-        commitment: &BytesN<32>,
-        nullifier: &BytesN<32>,
+        pk: &G1Affine,
+        c1: &G1Affine,
+        c2: &G1Affine,
+        s: &Fr,
         session_id: u32,
         player: &Address,
-    ) -> Bytes {
-        let mut buf = Bytes::from_array(env, &seed_hash.to_array());
-        buf.append(&Bytes::from_array(env, &commitment.to_array()));
-        buf.append(&Bytes::from_array(env, &nullifier.to_array()));
-        buf.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        buf.append(&player.to_string().to_bytes());
-        buf
+    ) -> (Bytes, Bytes) {
+        let bls = env.crypto().bls12_381();
+        let c1_new = bls.g1_add(c1, &bls.g1_mul(&g1_generator(env), s));
+        let c2_new = bls.g1_add(c2, &bls.g1_mul(pk, s));
+
+        let mut pi = Bytes::from_array(env, &ELGAMAL_KIND_REENCRYPT.to_be_bytes());
+        pi.append(&g1_bytes(env, pk));
+        pi.append(&g1_bytes(env, c1));
+        pi.append(&g1_bytes(env, c2));
+        pi.append(&g1_bytes(env, &c1_new));
+        pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        pi.append(&player.to_string().to_bytes());
+
+        let proof = build_dleq_proof(env, &pi, &c2_new, pk, s, b"elgamal-reenc");
+        (pi, proof)
     }
 
-    /// Build NIZK proof (blinding || response)
-    fn encode_nizk_proof(env: &Env, blinding: &BytesN<32>, response: &BytesN<32>) -> Bytes {
-        let mut buf = Bytes::from_array(env, &blinding.to_array());
-        buf.append(&Bytes::from_array(env, &response.to_array()));
-        buf
+    /// Joint key material for two players: (sk_a, sk_b, PK).
+    fn elgamal_keys(env: &Env) -> (Fr, Fr, G1Affine) {
+        let bls = env.crypto().bls12_381();
+        let sk_a = test_scalar(env, b"elgamal-sk", 0, 0);
+        let sk_b = test_scalar(env, b"elgamal-sk", 1, 0);
+        let pk = bls.g1_mul(&g1_generator(env), &(sk_a.clone() + sk_b.clone()));
+        (sk_a, sk_b, pk)
     }
 
-    /// Full NIZK proof generation helper (mirrors client-side logic)
-    fn generate_nizk_proof(
+    // ════════════════════════════════════════════════════════════════════════
+    //  Mental-poker ElGamal tests (Mode 15)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_elgamal_decrypt_shares_recover_card() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+
+        let (sk_a, sk_b, pk) = elgamal_keys(&env);
+        let (c1, c2) = elgamal_encrypt(&env, 17, &pk, &test_scalar(&env, b"elgamal-r", 17, 0));
+
+        let (pi_a, proof_a, d_a) = build_decrypt_share(&env, &sk_a, &c1, 700, &alice);
+        let (pi_b, proof_b, d_b) = build_decrypt_share(&env, &sk_b, &c1, 700, &bob);
+        assert!(client.verify(&pi_a, &proof_a), "Alice's share should verify");
+        assert!(client.verify(&pi_b, &proof_b), "Bob's share should verify");
+
+        let bls = env.crypto().bls12_381();
+        let m = bls.g1_add(&bls.g1_add(&c2, &-d_a), &-d_b);
+        assert_eq!(m, bls.g1_mul(&g1_generator(&env), &fr_u32(&env, 17)));
+    }
+
+    #[test]
+    fn test_elgamal_wrong_share_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (sk_a, _, pk) = elgamal_keys(&env);
+        let (c1, _) = elgamal_encrypt(&env, 3, &pk, &test_scalar(&env, b"elgamal-r", 3, 0));
+        let (pi, proof, _) = build_decrypt_share(&env, &sk_a, &c1, 701, &player);
+
+        // Replace D with a share computed under a different key
+        let bls = env.crypto().bls12_381();
+        let bogus = bls.g1_mul(&c1, &test_scalar(&env, b"elgamal-sk", 9, 0));
+        let mut tampered = g1_bytes(&env, &bogus);
+        tampered.append(&proof.slice(96..));
+        assert!(!client.verify(&pi, &tampered), "Share not matching pk_i must fail");
+    }
+
+    #[test]
+    fn test_elgamal_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (sk_a, _, pk) = elgamal_keys(&env);
+        let (c1, _) = elgamal_encrypt(&env, 5, &pk, &test_scalar(&env, b"elgamal-r", 5, 0));
+        let (mut pi, proof, _) = build_decrypt_share(&env, &sk_a, &c1, 702, &player);
+        pi.set(4 + 2 * 96 + 3, 0xFF);
+
+        assert!(!client.verify(&pi, &proof), "Session id is bound into the challenge");
+    }
+
+    #[test]
+    fn test_elgamal_reencryption_valid() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (_, _, pk) = elgamal_keys(&env);
+        let (c1, c2) = elgamal_encrypt(&env, 30, &pk, &test_scalar(&env, b"elgamal-r", 30, 0));
+        let s = test_scalar(&env, b"elgamal-s", 30, 0);
+        let (pi, proof) = build_reencrypt_proof(&env, &pk, &c1, &c2, &s, 703, &player);
+
+        assert!(client.verify(&pi, &proof), "Honest re-encryption should verify");
+    }
+
+    #[test]
+    fn test_elgamal_reencryption_changed_card_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (_, _, pk) = elgamal_keys(&env);
+        let (c1, c2) = elgamal_encrypt(&env, 30, &pk, &test_scalar(&env, b"elgamal-r", 30, 0));
+        let s = test_scalar(&env, b"elgamal-s", 30, 0);
+        let (pi, proof) = build_reencrypt_proof(&env, &pk, &c1, &c2, &s, 704, &player);
+
+        // Output c2' + G: the re-encrypted card would decrypt to 31
+        let bls = env.crypto().bls12_381();
+        let c2_new = bls.g1_add(&c2, &bls.g1_mul(&pk, &s));
+        let shifted = bls.g1_add(&c2_new, &g1_generator(&env));
+        let mut tampered = g1_bytes(&env, &shifted);
+        tampered.append(&proof.slice(96..));
+        assert!(!client.verify(&pi, &tampered), "Re-encryption must not change the plaintext");
+    }
+
+    #[test]
+    fn test_elgamal_unknown_kind_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (sk_a, _, pk) = elgamal_keys(&env);
+        let (c1, _) = elgamal_encrypt(&env, 1, &pk, &test_scalar(&env, b"elgamal-r", 1, 0));
+        let (mut pi, proof, _) = build_decrypt_share(&env, &sk_a, &c1, 705, &player);
+        pi.set(3, 7);
+
+        assert!(!client.verify(&pi, &proof), "Unknown statement kind must be rejected");
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Card comparison helpers (Mode 16)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Build a Mode 16 proof that `cards[winner]` beats `cards[1 - winner]`
+    /// in `trick_suit`, both committed with `card_commitment`. A false claim
+    /// still yields a well-formed proof (a missing real branch falls back to
+    /// branch 0), which the verifier must reject.
+    /// Returns (public_inputs, proof) with the budget reset to default.
+    fn build_card_compare_proof(
         env: &Env,
-        seed: &BytesN<32>,
-        blinding: &BytesN<32>,
+        cards: [u32; 2],
+        winner: u32,
+        trick_suit: u32,
         session_id: u32,
         player: &Address,
-    ) -> (Bytes, Bytes, BytesN<32>) {
-        let seed_hash = compute_seed_hash(env, seed);
-        let commitment = compute_nizk_commitment(env, &seed_hash, blinding, player);
-        let nullifier = compute_nullifier(env, &seed_hash, session_id);
-        let challenge = compute_fs_challenge(env, &commitment, session_id, player);
-        let response = compute_response(env, &seed_hash, &challenge, blinding);
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+
+        let mut public_inputs = Bytes::from_array(env, &trick_suit.to_be_bytes());
+        public_inputs.append(&Bytes::from_array(env, &winner.to_be_bytes()));
+        for card in cards {
+            public_inputs.append(&Bytes::from_array(env, &card_commitment(env, card).to_bytes().to_array()));
+        }
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+
+        // Per OR-proof: statement point P, value of branch 0, witness, real branch
+        let (win, lose) = (cards[winner as usize], cards[1 - winner as usize]);
+        let blinding = |card: u32| test_scalar(env, b"hand-r", card, 0);
+        let rank_in_suit = |card: u32| if card / 9 == trick_suit { card % 9 } else { 0 };
+        let gap = (win % 9).saturating_sub(lose % 9);
+        let win_point = card_commitment(env, win);
+        let lose_point = card_commitment(env, lose);
+        let statements = [
+            (win_point.clone(), 9 * trick_suit, blinding(win), rank_in_suit(win)),
+            (lose_point.clone(), 9 * trick_suit, blinding(lose), rank_in_suit(lose)),
+            (bls.g1_add(&win_point, &-lose_point), 0, blinding(win) - blinding(lose), gap),
+        ];
+
+        let mut proof = Bytes::new(env);
+        for (o, (p, base, _, real)) in statements.iter().enumerate() {
+            for j in 0..9u32 {
+                let label = 9 * o as u32 + j;
+                let r_j = if j == *real {
+                    bls.g1_mul(&h, &test_scalar(env, b"cmp-t", label, session_id))
+                } else {
+                    let e_j = test_scalar(env, b"cmp-e", label, session_id);
+                    bls.g1_msm(
+                        vec![env, h.clone(), p.clone(), g.clone()],
+                        vec![
+                            env,
+                            test_scalar(env, b"cmp-z", label, session_id),
+                            fr_neg(env, &e_j),
+                            e_j.clone() * fr_u32(env, base + j),
+                        ],
+                    )
+                };
+                proof.append(&Bytes::from_array(env, &r_j.to_bytes().to_array()));
+            }
+        }
 
-        let public_inputs = encode_nizk_public_inputs(env, &seed_hash, &commitment, &nullifier, session_id, player);
-        let proof = encode_nizk_proof(env, blinding, &response);
-        (public_inputs, proof, commitment)
+        let fixed = CARD_COMPARE_INPUTS_FIXED_LEN;
+        let mut preimage = public_inputs.slice(0..fixed);
+        preimage.append(&proof);
+        preimage.append(&public_inputs.slice(fixed..));
+        preimage.append(&Bytes::from_array(env, &CARD_COMPARE_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &preimage));
+
+        for (o, (_, _, witness, real)) in statements.iter().enumerate() {
+            let label = |j: u32| 9 * o as u32 + j;
+            let mut es: [Fr; 9] = core::array::from_fn(|j| test_scalar(env, b"cmp-e", label(j as u32), session_id));
+            let mut zs: [Fr; 9] = core::array::from_fn(|j| test_scalar(env, b"cmp-z", label(j as u32), session_id));
+            let mut e_real = e.clone();
+            for j in 0..9u32 {
+                if j != *real {
+                    e_real = e_real - es[j as usize].clone();
+                }
+            }
+            zs[*real as usize] = test_scalar(env, b"cmp-t", label(*real), session_id) + e_real.clone() * witness.clone();
+            es[*real as usize] = e_real;
+            for e_j in &es {
+                proof.append(&fr_bytes(env, e_j));
+            }
+            for z_j in &zs {
+                proof.append(&fr_bytes(env, z_j));
+            }
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
     }
 
-
     // ════════════════════════════════════════════════════════════════════════
-    //  NIZK seed proof tests (new!)
+    //  Card comparison tests (Mode 16)
     // ════════════════════════════════════════════════════════════════════════
 
     #[test]
-    fn test_nizk_seed_valid_proof() {
+    fn test_card_compare_valid_proof() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
-            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[
-            0xAA,0xBB,0xCC,0xDD,0x11,0x22,0x33,0x44,
-            0x55,0x66,0x77,0x88,0x99,0x00,0xEE,0xFF,
-            0x12,0x34,0x56,0x78,0x9A,0xBC,0xDE,0xF0,
-            0x13,0x57,0x9B,0xDF,0x24,0x68,0xAC,0xE0,
-        ]);
-        let session_id: u32 = 12345;
 
-        let (public_inputs, proof, _commitment) = generate_nizk_proof(
-            &env, &seed, &blinding, session_id, &player,
-        );
-
-        assert!(client.verify(&public_inputs, &proof));
+        // Trick suit hearts (1): 9♥ (16) beats 4♥ (11), whichever slot it is in
+        let (pi, proof) = build_card_compare_proof(&env, [16, 11], 0, 1, 800, &player);
+        assert_eq!(proof.len(), CARD_COMPARE_PROOF_LEN);
+        assert!(client.verify(&pi, &proof), "Higher card in the trick suit should verify");
+        let (pi, proof) = build_card_compare_proof(&env, [11, 16], 1, 1, 800, &player);
+        assert!(client.verify(&pi, &proof), "Winner may be the second commitment");
     }
 
     #[test]
-    fn test_nizk_seed_wrong_blinding_fails() {
+    fn test_card_compare_lower_card_fails() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
-            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
-        ]);
-        let real_blinding = BytesN::<32>::from_array(&env, &[0xAAu8; 32]);
-        let wrong_blinding = BytesN::<32>::from_array(&env, &[0xBBu8; 32]);
-        let session_id: u32 = 42;
-
-        // Generate proof with real blinding
-        let seed_hash = compute_seed_hash(&env, &seed);
-        let commitment = compute_nizk_commitment(&env, &seed_hash, &real_blinding, &player);
-        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
-
-        // But put wrong blinding in the proof
-        let challenge = compute_fs_challenge(&env, &commitment, session_id, &player);
-        let wrong_response = compute_response(&env, &seed_hash, &challenge, &wrong_blinding);
 
-        let public_inputs = encode_nizk_public_inputs(
-            &env, &seed_hash, &commitment, &nullifier, session_id, &player,
-        );
-        let proof = encode_nizk_proof(&env, &wrong_blinding, &wrong_response);
+        let (pi, proof) = build_card_compare_proof(&env, [11, 16], 0, 1, 801, &player);
+        assert!(!client.verify(&pi, &proof), "A lower card cannot be proven the winner");
 
-        // Commitment check will fail (wrong blinding doesn't match commitment)
-        assert!(!client.verify(&public_inputs, &proof));
+        // Flipping the winner of a valid proof must not verify either
+        let (mut pi, proof) = build_card_compare_proof(&env, [16, 11], 0, 1, 801, &player);
+        pi.set(7, 1);
+        assert!(!client.verify(&pi, &proof), "Winner is bound into the proof");
     }
 
     #[test]
-    fn test_nizk_seed_wrong_session_fails() {
+    fn test_card_compare_off_suit_fails() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
-            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[0xCCu8; 32]);
-        let real_session = 100u32;
-        let fake_session = 999u32;
-
-        // Generate proof for real session
-        let seed_hash = compute_seed_hash(&env, &seed);
-        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
-        let nullifier = compute_nullifier(&env, &seed_hash, real_session);
-
-        // Tamper: replace session_id with fake_session but keep old nullifier
-        let mut buf = Bytes::from_array(&env, &seed_hash.to_array());
-        buf.append(&Bytes::from_array(&env, &commitment.to_array()));
-        buf.append(&Bytes::from_array(&env, &nullifier.to_array()));
-        buf.append(&Bytes::from_array(&env, &fake_session.to_be_bytes())); // tampered!
-        buf.append(&player.to_string().to_bytes());
 
-        let challenge = compute_fs_challenge(&env, &commitment, real_session, &player);
-        let response = compute_response(&env, &seed_hash, &challenge, &blinding);
-        let proof = encode_nizk_proof(&env, &blinding, &response);
+        // 10♠ (8) outranks 4♥ (11) but is not in the trick suit
+        let (pi, proof) = build_card_compare_proof(&env, [8, 11], 0, 1, 802, &player);
+        assert!(!client.verify(&pi, &proof), "Both cards must be in the trick suit");
 
-        // Nullifier check will fail
-        assert!(!client.verify(&buf, &proof));
+        let (mut pi, proof) = build_card_compare_proof(&env, [16, 11], 0, 1, 802, &player);
+        pi.set(3, 0);
+        assert!(!client.verify(&pi, &proof), "Trick suit is bound into the proof");
     }
 
     #[test]
-    fn test_nizk_seed_different_player_fails() {
+    fn test_card_compare_wrong_session_fails() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
-            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[0xDDu8; 32]);
-        let session_id: u32 = 50;
+        let (mut pi, proof) = build_card_compare_proof(&env, [16, 11], 0, 1, 803, &player);
+        pi.set(CARD_COMPARE_INPUTS_FIXED_LEN + 3, 0xFF);
 
-        // Generate valid proof for player1
-        let (_public_inputs, proof, _) = generate_nizk_proof(
-            &env, &seed, &blinding, session_id, &player1,
-        );
+        assert!(!client.verify(&pi, &proof), "Session id is bound into the challenge");
+    }
 
-        // Replace player address with player2
-        let seed_hash = compute_seed_hash(&env, &seed);
-        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player1);
-        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+    // ════════════════════════════════════════════════════════════════════════
+    //  Commitment equality helpers (Mode 17)
+    // ════════════════════════════════════════════════════════════════════════
 
-        let mut tampered = Bytes::from_array(&env, &seed_hash.to_array());
-        tampered.append(&Bytes::from_array(&env, &commitment.to_array()));
-        tampered.append(&Bytes::from_array(&env, &nullifier.to_array()));
-        tampered.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        tampered.append(&player2.to_string().to_bytes()); // wrong player!
+    /// Build a Mode 17 proof for commitment pairs to `(card, card')`, with
+    /// blindings drawn per pair and side. The proof always uses `card` as the
+    /// shared opening, so a pair with `card != card'` yields a well-formed
+    /// proof the verifier must reject.
+    /// Returns (public_inputs, proof) with the budget reset to default.
+    fn build_commit_eq_proof(
+        env: &Env,
+        pairs: &[(u32, u32)],
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let n = pairs.len() as u32;
+        let blinding = |pair: u32, side: u32| test_scalar(env, b"eq-r", pair, side);
+        let nonce = |label: &[u8], pair: u32| test_scalar(env, label, pair, session_id);
+
+        let mut public_inputs = Bytes::from_array(env, &n.to_be_bytes());
+        for (i, (card, card_prime)) in pairs.iter().enumerate() {
+            for (side, value) in [*card, *card_prime].into_iter().enumerate() {
+                let c = bls.g1_msm(
+                    vec![env, g.clone(), h.clone()],
+                    vec![env, fr_u32(env, value), blinding(i as u32, side as u32)],
+                );
+                public_inputs.append(&Bytes::from_array(env, &c.to_bytes().to_array()));
+            }
+        }
+        let sid_offset = public_inputs.len();
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
 
-        // Commitment check will fail (commitment is bound to player1)
-        assert!(!client.verify(&tampered, &proof));
+        // First moves R = a·G + b·H, R' = a·G + b'·H
+        let mut preimage = public_inputs.slice(0..sid_offset);
+        let mut first_moves = Vec::new(env);
+        for i in 0..n {
+            for side in 0..2u32 {
+                let r = bls.g1_msm(
+                    vec![env, g.clone(), h.clone()],
+                    vec![env, nonce(b"eq-a", i), nonce(b"eq-b", 2 * i + side)],
+                );
+                let r = Bytes::from_array(env, &r.to_bytes().to_array());
+                preimage.append(&r);
+                first_moves.push_back(r);
+            }
+        }
+        preimage.append(&public_inputs.slice(sid_offset..));
+        preimage.append(&Bytes::from_array(env, &COMMIT_EQ_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &preimage));
+
+        let mut proof = Bytes::from_array(env, &n.to_be_bytes());
+        for (i, (card, _)) in pairs.iter().enumerate() {
+            let i = i as u32;
+            proof.append(&first_moves.get_unchecked(2 * i));
+            proof.append(&first_moves.get_unchecked(2 * i + 1));
+            proof.append(&fr_bytes(env, &(nonce(b"eq-a", i) + e.clone() * fr_u32(env, *card))));
+            for side in 0..2u32 {
+                let z = nonce(b"eq-b", 2 * i + side) + e.clone() * blinding(i, side);
+                proof.append(&fr_bytes(env, &z));
+            }
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    //  Commitment equality tests (Mode 17)
+    // ════════════════════════════════════════════════════════════════════════
+
     #[test]
-    fn test_nizk_seed_tampered_response_fails() {
+    fn test_commit_eq_valid_proof() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,
-            17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[0xEEu8; 32]);
-        let session_id: u32 = 77;
 
-        let seed_hash = compute_seed_hash(&env, &seed);
-        let commitment = compute_nizk_commitment(&env, &seed_hash, &blinding, &player);
-        let nullifier = compute_nullifier(&env, &seed_hash, session_id);
+        let (pi, proof) = build_commit_eq_proof(&env, &[(16, 16)], 900, &player);
+        assert_eq!(proof.len(), 4 + COMMIT_EQ_PAIR_PROOF_LEN);
+        assert!(client.verify(&pi, &proof), "Re-committed card should verify");
 
-        let public_inputs = encode_nizk_public_inputs(
-            &env, &seed_hash, &commitment, &nullifier, session_id, &player,
-        );
+        // A full batch fits in the default budget
+        let pairs: [(u32, u32); 9] = core::array::from_fn(|i| (4 * i as u32, 4 * i as u32));
+        let (pi, proof) = build_commit_eq_proof(&env, &pairs, 900, &player);
+        assert!(client.verify(&pi, &proof), "Batch of re-commitments should verify");
+    }
 
-        // Use correct blinding but fake response
-        let fake_response = BytesN::<32>::from_array(&env, &[0x42u8; 32]);
-        let proof = encode_nizk_proof(&env, &blinding, &fake_response);
+    #[test]
+    fn test_commit_eq_different_cards_fail() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // Response check will fail (but commitment passes, so response check catches it)
-        assert!(!client.verify(&public_inputs, &proof));
+        let (pi, proof) = build_commit_eq_proof(&env, &[(16, 17)], 901, &player);
+        assert!(!client.verify(&pi, &proof), "Commitments to different cards must not verify");
+
+        let (pi, proof) = build_commit_eq_proof(&env, &[(3, 3), (20, 2)], 901, &player);
+        assert!(!client.verify(&pi, &proof), "One bad pair fails the whole batch");
     }
 
     #[test]
-    fn test_nizk_multiple_sessions_same_seed() {
+    fn test_commit_eq_rejects_reused_blinding_and_bad_count() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let seed = BytesN::<32>::from_array(&env, &[
-            10,20,30,40,50,60,70,80,90,100,110,120,130,140,150,160,
-            170,180,190,200,210,220,230,240,250,5,15,25,35,45,55,65,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[0x99u8; 32]);
 
-        // Same seed, different sessions — all should verify independently
-        for sid in [1u32, 2, 100, 999] {
-            let (public_inputs, proof, _) = generate_nizk_proof(
-                &env, &seed, &blinding, sid, &player,
-            );
-            assert!(client.verify(&public_inputs, &proof), "Session {} should verify", sid);
-        }
+        // C' == C is not a re-commitment
+        let (pi, proof) = build_commit_eq_proof(&env, &[(16, 16)], 902, &player);
+        let mut same = pi.slice(0..100);
+        same.append(&pi.slice(4..100));
+        same.append(&pi.slice(196..));
+        assert!(!client.verify(&same, &proof), "Identical commitments must be rejected");
+
+        // The pair count in the inputs must match the proof
+        let (mut miscounted, proof) = build_commit_eq_proof(&env, &[(3, 3), (5, 5)], 902, &player);
+        miscounted.set(3, 1);
+        assert!(!client.verify(&miscounted, &proof), "Pair count is cross-checked");
     }
 
     #[test]
-    fn test_nizk_raw_seed_never_on_chain() {
-        // Verify that the raw seed bytes do NOT appear in public_inputs or proof
+    fn test_commit_eq_wrong_session_fails() {
         let env = Env::default();
-        let seed = BytesN::<32>::from_array(&env, &[
-            0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02, 0x03, 0x04,
-            0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
-            0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14,
-            0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C,
-        ]);
-        let blinding = BytesN::<32>::from_array(&env, &[0xFFu8; 32]);
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let session_id = 42u32;
 
-        let (public_inputs, proof, _) = generate_nizk_proof(
-            &env, &seed, &blinding, session_id, &player,
-        );
+        let (mut pi, proof) = build_commit_eq_proof(&env, &[(16, 16)], 903, &player);
+        pi.set(4 + 192 + 3, 0xFF);
 
-        // Check that the raw seed bytes don't appear as a contiguous
-        // 32-byte substring in either public_inputs or proof
-        let seed_arr = seed.to_array();
-        let pi_len = public_inputs.len();
-        let proof_len = proof.len();
+        assert!(!client.verify(&pi, &proof), "Session id is bound into the challenge");
+    }
 
-        // Check public inputs
-        let mut found_in_pi = false;
-        if pi_len >= 32 {
-            let mut offset = 0u32;
-            while offset <= pi_len - 32 {
-                let mut matches = true;
-                let mut j = 0usize;
-                while j < 32 {
-                    if public_inputs.get(offset + j as u32).unwrap_or(0) != seed_arr[j] {
-                        matches = false;
-                        break;
-                    }
-                    j += 1;
-                }
-                if matches {
-                    found_in_pi = true;
-                    break;
-                }
-                offset += 1;
-            }
+    // ════════════════════════════════════════════════════════════════════════
+    //  Deal consistency helpers (Mode 18)
+    // ════════════════════════════════════════════════════════════════════════
+
+    const DEAL_SEED_HASH1: [u8; 32] = [0x31; 32];
+    const DEAL_SEED_HASH2: [u8; 32] = [0x42; 32];
+
+    /// Cards the shuffle deals to `slot` in `session_id`. The PRNG needs a
+    /// contract context, so this runs as the verifier.
+    fn dealt_cards(env: &Env, contract_id: &Address, session_id: u32, slot: u32) -> [u32; 5] {
+        let sh1 = BytesN::from_array(env, &DEAL_SEED_HASH1);
+        let sh2 = BytesN::from_array(env, &DEAL_SEED_HASH2);
+        let seed = deal_seed(env, &sh1, &sh2, session_id);
+        let deck = env.as_contract(contract_id, || cangkulan_common::shuffled_deck(env, &seed));
+        core::array::from_fn(|i| deck[5 * slot as usize + i])
+    }
+
+    /// Build a Mode 18 proof for hand commitments to `cards` in `slot`.
+    /// Returns (public_inputs, proof).
+    fn build_deal_proof(env: &Env, cards: &[u32; 5], slot: u32, session_id: u32, player: &Address) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let blinding = |i: u32| test_scalar(env, b"deal-r", i, slot);
+        let nonce = |i: u32| test_scalar(env, b"deal-k", i, session_id);
+
+        let mut public_inputs = Bytes::from_array(env, &DEAL_SEED_HASH1);
+        public_inputs.append(&Bytes::from_array(env, &DEAL_SEED_HASH2));
+        public_inputs.append(&Bytes::from_array(env, &slot.to_be_bytes()));
+        for (i, card) in cards.iter().enumerate() {
+            let p = bls.g1_msm(vec![env, g.clone(), h.clone()], vec![env, fr_u32(env, *card), blinding(i as u32)]);
+            public_inputs.append(&Bytes::from_array(env, &p.to_bytes().to_array()));
         }
-        assert!(!found_in_pi, "Raw seed must NOT appear in public inputs");
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
 
-        // Check proof
-        let mut found_in_proof = false;
-        if proof_len >= 32 {
-            let mut offset = 0u32;
-            while offset <= proof_len - 32 {
-                let mut matches = true;
-                let mut j = 0usize;
-                while j < 32 {
-                    if proof.get(offset + j as u32).unwrap_or(0) != seed_arr[j] {
-                        matches = false;
-                        break;
-                    }
-                    j += 1;
-                }
-                if matches {
-                    found_in_proof = true;
-                    break;
-                }
-                offset += 1;
-            }
+        // First moves R_i = k_i·H
+        let mut preimage = public_inputs.slice(0..DEAL_INPUTS_FIXED_LEN);
+        let mut first_moves = Vec::new(env);
+        for i in 0..5 {
+            let r = Bytes::from_array(env, &bls.g1_mul(&h, &nonce(i)).to_bytes().to_array());
+            preimage.append(&r);
+            first_moves.push_back(r);
         }
-        assert!(!found_in_proof, "Raw seed must NOT appear in proof");
+        preimage.append(&public_inputs.slice(DEAL_INPUTS_FIXED_LEN..));
+        preimage.append(&Bytes::from_array(env, &DEAL_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &preimage));
+
+        let mut proof = Bytes::new(env);
+        for i in 0..5 {
+            proof.append(&first_moves.get_unchecked(i));
+            proof.append(&fr_bytes(env, &(nonce(i) + e.clone() * blinding(i))));
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Deal consistency tests (Mode 18)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_deal_valid_proof() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        for slot in 0..2 {
+            let cards = dealt_cards(&env, &contract_id, 1000, slot);
+            let (pi, proof) = build_deal_proof(&env, &cards, slot, 1000, &player);
+            assert_eq!(proof.len(), DEAL_PROOF_LEN);
+            assert!(client.verify(&pi, &proof), "Commitments to the dealt hand should verify");
+        }
+    }
+
+    #[test]
+    fn test_deal_wrong_cards_fail() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // The other player's hand
+        let cards = dealt_cards(&env, &contract_id, 1001, 1);
+        let (pi, proof) = build_deal_proof(&env, &cards, 0, 1001, &player);
+        assert!(!client.verify(&pi, &proof), "Another slot's cards must not verify");
+
+        // The right cards in the wrong order
+        let mut cards = dealt_cards(&env, &contract_id, 1001, 0);
+        cards.swap(0, 4);
+        let (pi, proof) = build_deal_proof(&env, &cards, 0, 1001, &player);
+        assert!(!client.verify(&pi, &proof), "Each commitment is bound to its deck position");
+    }
+
+    #[test]
+    fn test_deal_rejects_bad_slot_and_wrong_session() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let cards = dealt_cards(&env, &contract_id, 1002, 0);
+        let (pi, proof) = build_deal_proof(&env, &cards, 2, 1002, &player);
+        assert!(!client.verify(&pi, &proof), "Only slots 0 and 1 are dealt hands");
+
+        // Another session deals another deck
+        let (mut pi, proof) = build_deal_proof(&env, &cards, 0, 1002, &player);
+        pi.set(DEAL_INPUTS_FIXED_LEN + 3, 0xFF);
+        assert!(!client.verify(&pi, &proof), "Session id selects the deck");
     }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  BLS12-381 G1 standard generator
+    //  Threshold seed helpers (Mode 19)
     // ════════════════════════════════════════════════════════════════════════
 
-    fn bls12_381_g1_generator(env: &Env) -> G1Affine {
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        G1Affine::from_array(env, &g1_bytes)
+    fn threshold_seed_hash(env: &Env, party: u32) -> BytesN<32> {
+        env.crypto().keccak256(&Bytes::from_array(env, &[party as u8 + 1; 32])).into()
     }
 
-    /// Derive the Pedersen H generator (same as on-chain)
-    fn pedersen_h_generator(env: &Env) -> G1Affine {
+    /// Build a Mode 19 proof for `n` parties with threshold `t`, opening the
+    /// parties in `mask`. Returns (public_inputs, proof).
+    fn build_threshold_proof(env: &Env, n: u32, t: u32, mask: u32, session_id: u32, player: &Address) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
         let bls = env.crypto().bls12_381();
-        let msg = Bytes::from_slice(env, b"PEDERSEN_H");
-        let dst = Bytes::from_slice(env, b"SGS_CANGKULAN_V1");
-        bls.hash_to_g1(&msg, &dst)
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let blinding = |i: u32| test_scalar(env, b"th-r", i, 0);
+        let nonce = |i: u32| test_scalar(env, b"th-k", i, session_id);
+        let seed = |i: u32| Fr::from_bytes(threshold_seed_hash(env, i));
+
+        let mut public_inputs = Bytes::new(env);
+        for word in [n, t, mask] {
+            public_inputs.append(&Bytes::from_array(env, &word.to_be_bytes()));
+        }
+        let mut commitments = Vec::new(env);
+        for i in 0..n {
+            let c = bls.g1_msm(vec![env, g.clone(), h.clone()], vec![env, seed(i), blinding(i)]);
+            public_inputs.append(&Bytes::from_array(env, &c.to_bytes().to_array()));
+            commitments.push_back(c);
+        }
+        let opened = || (0..n).filter(move |i| mask & (1 << i) != 0);
+        for i in opened() {
+            public_inputs.append(&Bytes::from_array(env, &threshold_seed_hash(env, i).to_array()));
+        }
+        let mut tail = Bytes::from_array(env, &session_id.to_be_bytes());
+        tail.append(&player.to_string().to_bytes());
+        public_inputs.append(&tail);
+
+        // Each party proves on its own: R_i = k_i·H, z_i = k_i + e_i·r_i
+        let mut proof = Bytes::from_array(env, &mask.to_be_bytes());
+        for i in opened() {
+            let r = bls.g1_mul(&h, &nonce(i));
+            let mut preimage = Bytes::from_array(env, &i.to_be_bytes());
+            preimage.append(&Bytes::from_array(env, &commitments.get_unchecked(i).to_bytes().to_array()));
+            preimage.append(&Bytes::from_array(env, &r.to_bytes().to_array()));
+            preimage.append(&Bytes::from_array(env, &threshold_seed_hash(env, i).to_array()));
+            preimage.append(&tail);
+            preimage.append(&Bytes::from_array(env, &THRESHOLD_CHALLENGE_TAG));
+            let e = Fr::from_bytes(transcript::fs_digest(env, &preimage));
+
+            proof.append(&Bytes::from_array(env, &r.to_bytes().to_array()));
+            proof.append(&fr_bytes(env, &(nonce(i) + e * blinding(i))));
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
     }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  Pedersen+Sigma helpers (Mode 4)
+    //  Threshold seed tests (Mode 19)
     // ════════════════════════════════════════════════════════════════════════
 
-    /// Compute Pedersen commitment: C = seed_scalar·G + blinding_scalar·H
-    fn compute_pedersen_commitment(
-        env: &Env,
-        seed_scalar: &Fr,
-        blinding_scalar: &Fr,
-    ) -> G1Affine {
-        let bls = env.crypto().bls12_381();
-        let g = bls12_381_g1_generator(env);
-        let h = pedersen_h_generator(env);
-        bls.g1_msm(vec![env, g, h], vec![env, seed_scalar.clone(), blinding_scalar.clone()])
+    #[test]
+    fn test_threshold_valid_proof() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        // 3-of-4 with party 2 silent
+        let (pi, proof) = build_threshold_proof(&env, 4, 3, 0b1011, 1100, &player);
+        assert_eq!(proof.len(), 4 + 3 * THRESHOLD_PARTY_PROOF_LEN);
+        assert!(client.verify(&pi, &proof), "Threshold of openings should verify");
+
+        // All 8 parties open within the default budget
+        let (pi, proof) = build_threshold_proof(&env, 8, 8, 0xFF, 1100, &player);
+        assert!(client.verify(&pi, &proof), "Full opening should verify");
     }
 
-    /// Generate a Pedersen+Sigma proof (revised: Schnorr on D = C - s·G)
-    fn generate_pedersen_sigma_proof(
-        env: &Env,
-        seed_scalar: &Fr,
-        blinding_scalar: &Fr,
-        nonce_r: &Fr,
-        session_id: u32,
-        player: &Address,
-    ) -> (Bytes, Bytes) {
-        let bls = env.crypto().bls12_381();
-        let g = bls12_381_g1_generator(env);
-        let h = pedersen_h_generator(env);
+    #[test]
+    fn test_threshold_wrong_seed_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // Commitment: C = seed·G + blinding·H
-        let commitment = bls.g1_msm(
-            vec![env, g.clone(), h.clone()],
-            vec![env, seed_scalar.clone(), blinding_scalar.clone()],
-        );
+        let (mut pi, proof) = build_threshold_proof(&env, 3, 2, 0b110, 1101, &player);
+        // First opened seed hash, right after the three commitments
+        let offset = THRESHOLD_INPUTS_HEADER_LEN + 3 * 96;
+        pi.set(offset, pi.get_unchecked(offset) ^ 1);
+        assert!(!client.verify(&pi, &proof), "A wrong seed hash must not open the commitment");
+    }
 
-        // Nonce commitment: R = k_r·H (Schnorr on H subspace only)
-        let r_point = bls.g1_mul(&h, nonce_r);
+    #[test]
+    fn test_threshold_rejects_bad_statements() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // seed_hash bytes = seed_scalar as bytes
-        let seed_hash_bytes = seed_scalar.to_bytes();
+        // Fewer openings than the threshold
+        let (pi, proof) = build_threshold_proof(&env, 4, 3, 0b0011, 1102, &player);
+        assert!(!client.verify(&pi, &proof), "Openings below t must be rejected");
+
+        // Threshold above the party count
+        let (pi, proof) = build_threshold_proof(&env, 2, 3, 0b11, 1102, &player);
+        assert!(!client.verify(&pi, &proof), "t > n must be rejected");
+
+        // The mask in the inputs must match the proof
+        let (mut pi, proof) = build_threshold_proof(&env, 3, 2, 0b011, 1102, &player);
+        pi.set(11, 0b101);
+        assert!(!client.verify(&pi, &proof), "Opened mask is cross-checked");
+
+        // A party reusing another's commitment
+        let (pi, proof) = build_threshold_proof(&env, 3, 2, 0b011, 1102, &player);
+        let mut dup = pi.slice(0..THRESHOLD_INPUTS_HEADER_LEN + 2 * 96);
+        dup.append(&pi.slice(THRESHOLD_INPUTS_HEADER_LEN..THRESHOLD_INPUTS_HEADER_LEN + 96));
+        dup.append(&pi.slice(THRESHOLD_INPUTS_HEADER_LEN + 3 * 96..));
+        assert!(!client.verify(&dup, &proof), "Duplicate commitments must be rejected");
+    }
 
-        // Fiat-Shamir challenge: e = keccak256(C || R || seed_hash || session_id || player || "ZKP4")
-        let c_bytes = commitment.to_bytes();
-        let r_bytes = r_point.to_bytes();
-        let mut challenge_preimage = Bytes::from_array(env, &c_bytes.to_array());
-        challenge_preimage.append(&Bytes::from_array(env, &r_bytes.to_array()));
-        challenge_preimage.append(&Bytes::from_array(env, &seed_hash_bytes.to_array()));
-        challenge_preimage.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        challenge_preimage.append(&player.to_string().to_bytes());
-        challenge_preimage.append(&Bytes::from_array(env, &PEDERSEN_CHALLENGE_TAG));
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_preimage).into();
-        let e = Fr::from_bytes(e_hash);
+    #[test]
+    fn test_threshold_wrong_session_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (mut pi, proof) = build_threshold_proof(&env, 2, 2, 0b11, 1103, &player);
+        pi.set(THRESHOLD_INPUTS_HEADER_LEN + 2 * 96 + 2 * 32 + 3, 0xFF);
+        assert!(!client.verify(&pi, &proof), "Session id is bound into every challenge");
+    }
 
-        // Response: z_r = k_r + e·blinding
-        let z_r = bls.fr_add(nonce_r, &bls.fr_mul(&e, blinding_scalar));
+    // ════════════════════════════════════════════════════════════════════════
+    //  BLS attestation helpers (Mode 20)
+    // ════════════════════════════════════════════════════════════════════════
 
-        // Public inputs: C(96) || seed_hash(32) || session_id(4) || player(var)
-        let mut public_inputs = Bytes::from_array(env, &c_bytes.to_array());
-        public_inputs.append(&Bytes::from_array(env, &seed_hash_bytes.to_array()));
+    /// Build a Mode 20 attestation of `message` by signers `0..k`, with the
+    /// signature summed over `signing` (a subset, to forge a missing signer).
+    /// Returns (public_inputs, proof).
+    fn build_bls_attestation(env: &Env, k: u32, signing: u32, message: &[u8], session_id: u32, player: &Address) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let dst = Bytes::from_slice(env, BLS_ATTEST_DST);
+        let secret = |i: u32| test_scalar(env, b"bls-sk", i, 0);
+
+        let mut public_inputs = Bytes::from_array(env, &k.to_be_bytes());
+        let mut signature: Option<G2Affine> = None;
+        for i in 0..k {
+            let pk = Bytes::from_array(env, &bls.g1_mul(&g, &secret(i)).to_bytes().to_array());
+            public_inputs.append(&pk);
+            if i < signing {
+                let mut m = pk;
+                m.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+                m.append(&Bytes::from_slice(env, message));
+                let sig = bls.g2_mul(&bls.hash_to_g2(&m, &dst), &secret(i));
+                signature = Some(match signature {
+                    Some(acc) => bls.g2_add(&acc, &sig),
+                    None => sig,
+                });
+            }
+        }
+        public_inputs.append(&Bytes::from_array(env, &(message.len() as u32).to_be_bytes()));
+        public_inputs.append(&Bytes::from_slice(env, message));
         public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
         public_inputs.append(&player.to_string().to_bytes());
 
-        // Proof: R(96) || z_r(32) = 128 bytes
-        let mut proof = Bytes::from_array(env, &r_bytes.to_array());
-        proof.append(&Bytes::from_array(env, &z_r.to_bytes().to_array()));
-
+        let proof = Bytes::from_array(env, &signature.unwrap().to_bytes().to_array());
+        restore_verify_budget(env);
         (public_inputs, proof)
     }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  Pedersen+Sigma tests (Mode 4)
+    //  BLS attestation tests (Mode 20)
     // ════════════════════════════════════════════════════════════════════════
 
     #[test]
-    fn test_pedersen_sigma_valid_proof() {
+    fn test_bls_attestation_valid() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let session_id = 12345u32;
-
-        // Scalars (deterministic for test)
-        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xDE, 0xAD, 0xBE, 0xEF,
-        ]));
-        let blinding_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
-        ]));
-        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
-        ]));
 
-        let (public_inputs, proof) = generate_pedersen_sigma_proof(
-            &env, &seed_scalar, &blinding_scalar, &nonce_r,
-            session_id, &player,
-        );
+        let (pi, proof) = build_bls_attestation(&env, 1, 1, b"stakes=100;rules=lite", 1200, &player);
+        assert_eq!(proof.len(), BLS_SIGNATURE_LEN);
+        assert!(client.verify(&pi, &proof), "Single signer attestation should verify");
 
-        assert_eq!(proof.len(), 128);
-        assert!(client.verify(&public_inputs, &proof));
+        let (pi, proof) = build_bls_attestation(&env, BLS_MAX_SIGNERS, BLS_MAX_SIGNERS, b"stakes=100", 1200, &player);
+        assert!(client.verify(&pi, &proof), "Aggregate of every signer should verify");
     }
 
     #[test]
-    fn test_pedersen_sigma_wrong_z_r_fails() {
+    fn test_bls_attestation_forgeries_fail() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-        let session_id = 42u32;
-
-        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xDE, 0xAD, 0xBE, 0xEF,
-        ]));
-        let blinding_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
-        ]));
-        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
-        ]));
-
-        let (public_inputs, proof) = generate_pedersen_sigma_proof(
-            &env, &seed_scalar, &blinding_scalar, &nonce_r,
-            session_id, &player,
-        );
 
-        // Tamper with z_r: replace bytes [96..128) with garbage
-        let tampered_z_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[0x42u8; 32]));
-        let r_bytes = {
-            let mut arr = [0u8; 96];
-            let mut i = 0usize;
-            while i < 96 { arr[i] = proof.get(i as u32).unwrap_or(0); i += 1; }
-            arr
-        };
+        // One listed signer never signed
+        let (pi, proof) = build_bls_attestation(&env, 3, 2, b"stakes=100", 1201, &player);
+        assert!(!client.verify(&pi, &proof), "Every listed key must have signed");
 
-        let mut tampered_proof = Bytes::from_array(&env, &r_bytes);
-        tampered_proof.append(&Bytes::from_array(&env, &tampered_z_r.to_bytes().to_array()));
+        // Message changed after signing
+        let (mut pi, proof) = build_bls_attestation(&env, 1, 1, b"stakes=100", 1201, &player);
+        pi.set(4 + 96 + 4 + 7, b'9');
+        assert!(!client.verify(&pi, &proof), "Altered message must fail");
 
-        assert!(!client.verify(&public_inputs, &tampered_proof));
+        // Signed for another session
+        let (mut pi, proof) = build_bls_attestation(&env, 1, 1, b"stakes=100", 1201, &player);
+        pi.set(4 + 96 + 4 + 10 + 3, 0xFF);
+        assert!(!client.verify(&pi, &proof), "Session id is part of the signed message");
     }
 
     #[test]
-    fn test_pedersen_sigma_different_player_fails() {
+    fn test_bls_attestation_rejects_bad_statements() {
         let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        let session_id = 77u32;
-
-        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xAB, 0xCD, 0xEF, 0x01,
-        ]));
-        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x12, 0x34, 0x56, 0x78,
-        ]));
-        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x0E, 0x0F, 0x10, 0x11,
-        ]));
-
-        // Generate proof for player1
-        let (_public_inputs, proof) = generate_pedersen_sigma_proof(
-            &env, &seed_scalar, &blinding, &nonce_r,
-            session_id, &player1,
-        );
-
-        // Create public inputs with player2 but same commitment and seed_hash
-        let commitment = compute_pedersen_commitment(&env, &seed_scalar, &blinding);
-        let c_bytes = commitment.to_bytes();
-        let seed_hash_bytes = seed_scalar.to_bytes();
-        let mut tampered_inputs = Bytes::from_array(&env, &c_bytes.to_array());
-        tampered_inputs.append(&Bytes::from_array(&env, &seed_hash_bytes.to_array()));
-        tampered_inputs.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        tampered_inputs.append(&player2.to_string().to_bytes()); // wrong player!
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
 
-        // Challenge will be different → sigma check fails
-        assert!(!client.verify(&tampered_inputs, &proof));
+        // The same key listed twice
+        let (pi, proof) = build_bls_attestation(&env, 2, 2, b"rules", 1202, &player);
+        let mut dup = pi.slice(0..4 + 96);
+        dup.append(&pi.slice(4..4 + 96));
+        dup.append(&pi.slice(4 + 192..));
+        assert!(!client.verify(&dup, &proof), "Duplicate signers must be rejected");
+
+        // Signer count out of range
+        let (mut pi, proof) = build_bls_attestation(&env, 1, 1, b"rules", 1202, &player);
+        pi.set(3, 0);
+        assert!(!client.verify(&pi, &proof), "Zero signers must be rejected");
+
+        // Message longer than the limit
+        let long = [b'x'; BLS_MAX_MESSAGE_LEN as usize + 1];
+        let (pi, proof) = build_bls_attestation(&env, 1, 1, &long, 1202, &player);
+        assert!(!client.verify(&pi, &proof), "Oversized message must be rejected");
     }
 
-    #[test]
-    fn test_pedersen_sigma_multiple_sessions() {
-        let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
+    // ════════════════════════════════════════════════════════════════════════
+    //  Hand count helpers (Mode 21)
+    // ════════════════════════════════════════════════════════════════════════
 
-        let player = Address::generate(&env);
-        let seed = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
-        ]));
-        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x06, 0x07, 0x08, 0x09, 0x0A,
-        ]));
-        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x10, 0x11, 0x12, 0x13, 0x14,
-        ]));
+    /// Build a Mode 21 proof that the aggregate of `cards` holds `claimed`
+    /// cards. Each card adds c·G + r·H + K to A, or c·G + r·H when its bit in
+    /// `skip_k` is set. Returns (public_inputs, proof).
+    fn build_hand_count_proof(
+        env: &Env,
+        cards: &[u32],
+        skip_k: u32,
+        claimed: u32,
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let count_k = bls.hash_to_g1(
+            &Bytes::from_slice(env, HAND_COUNT_K_MSG),
+            &Bytes::from_slice(env, PEDERSEN_H_DST),
+        );
 
-        for sid in [1u32, 100, 999, 65535] {
-            let (public_inputs, proof) = generate_pedersen_sigma_proof(
-                &env, &seed, &blinding, &nonce_r, sid, &player,
+        let mut s = fr_u32(env, 0);
+        let mut r = fr_u32(env, 0);
+        let mut agg = bls.g1_mul(&g, &fr_u32(env, 0));
+        for (i, card) in cards.iter().enumerate() {
+            let blinding = test_scalar(env, b"count-r", session_id, i as u32);
+            let mut c = bls.g1_msm(
+                Vec::from_array(env, [g.clone(), h.clone()]),
+                Vec::from_array(env, [fr_u32(env, *card), blinding.clone()]),
             );
-            assert!(client.verify(&public_inputs, &proof), "Session {} should verify", sid);
+            if skip_k & (1 << i) == 0 {
+                c = bls.g1_add(&c, &count_k);
+            }
+            agg = bls.g1_add(&agg, &c);
+            s = s + fr_u32(env, *card);
+            r = r + blinding;
         }
-    }
 
-    #[test]
-    fn test_pedersen_sigma_wrong_seed_hash_fails() {
-        let env = Env::default();
-        let verifier_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
-
-        let player = Address::generate(&env);
-        let session_id = 55u32;
-
-        let seed_scalar = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC, 0xDD,
-        ]));
-        let wrong_seed = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44,
-        ]));
-        let blinding = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0xCA, 0xFE, 0xBA, 0xBE,
-        ]));
-        let nonce_r = Fr::from_bytes(BytesN::<32>::from_array(&env, &[
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x05, 0x06, 0x07, 0x08,
-        ]));
+        let a = test_scalar(env, b"count-a", session_id, 0);
+        let b = test_scalar(env, b"count-b", session_id, 0);
+        let r_point = bls.g1_msm(Vec::from_array(env, [g, h]), Vec::from_array(env, [a.clone(), b.clone()]));
 
-        // Generate valid proof for seed_scalar
-        let (_, proof) = generate_pedersen_sigma_proof(
-            &env, &seed_scalar, &blinding, &nonce_r,
-            session_id, &player,
-        );
+        let mut public_inputs = Bytes::from_array(env, &agg.to_bytes().to_array());
+        public_inputs.append(&Bytes::from_array(env, &claimed.to_be_bytes()));
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
 
-        // Build public inputs with WRONG seed_hash (wrong_seed instead of seed_scalar)
-        let commitment = compute_pedersen_commitment(&env, &seed_scalar, &blinding);
-        let c_bytes = commitment.to_bytes();
-        let mut tampered_inputs = Bytes::from_array(&env, &c_bytes.to_array());
-        tampered_inputs.append(&Bytes::from_array(&env, &wrong_seed.to_bytes().to_array()));
-        tampered_inputs.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        tampered_inputs.append(&player.to_string().to_bytes());
+        let mut pre = Bytes::from_array(env, &agg.to_bytes().to_array());
+        pre.append(&Bytes::from_array(env, &r_point.to_bytes().to_array()));
+        pre.append(&Bytes::from_array(env, &claimed.to_be_bytes()));
+        pre.append(&public_inputs.slice(HAND_COUNT_INPUTS_FIXED_LEN - 4..));
+        pre.append(&Bytes::from_array(env, &HAND_COUNT_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &pre));
+
+        let mut proof = Bytes::from_array(env, &e.to_bytes().to_array());
+        proof.append(&Bytes::from_array(env, &(a + e.clone() * s).to_bytes().to_array()));
+        proof.append(&Bytes::from_array(env, &(b + e * r).to_bytes().to_array()));
+        restore_verify_budget(env);
+        (public_inputs, proof)
+    }
 
-        // D = C - wrong_seed·G ≠ blinding·H → Schnorr check fails
-        assert!(!client.verify(&tampered_inputs, &proof));
+    // ════════════════════════════════════════════════════════════════════════
+    //  Hand count tests (Mode 21)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_hand_count_valid() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (pi, proof) = build_hand_count_proof(&env, &[3, 14, 22, 30, 35], 0, 5, 1301, &player);
+        assert_eq!(proof.len(), HAND_COUNT_PROOF_LEN);
+        assert!(client.verify(&pi, &proof), "Five-card hand should verify");
+        assert_eq!(ZkCommitmentVerifier::mode_of(&pi, &proof), Some(MODE_HAND_COUNT));
+
+        // An emptied hand is A = r·H: card 0 without its K, proven with k = 0
+        let (pi, proof) = build_hand_count_proof(&env, &[0], 1, 0, 1302, &player);
+        assert!(client.verify(&pi, &proof), "Empty hand should verify");
+
+        // With no blinding left A is the identity, which is not canonical
+        let (pi, proof) = build_hand_count_proof(&env, &[], 0, 0, 1303, &player);
+        assert!(!client.verify(&pi, &proof), "Identity aggregate must be rejected");
     }
 
+    #[test]
+    fn test_hand_count_wrong_count_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+        let hand = [1u32, 10, 19, 28];
+
+        for claimed in [0u32, 3, 5] {
+            let (pi, proof) = build_hand_count_proof(&env, &hand, 0, claimed, 1310, &player);
+            assert!(!client.verify(&pi, &proof), "4 cards must not prove {}", claimed);
+        }
 
+        // Changing k, the session or the player after proving breaks the challenge
+        let (pi, proof) = build_hand_count_proof(&env, &hand, 0, 4, 1311, &player);
+        assert!(client.verify(&pi, &proof));
+        let mut other_k = pi.clone();
+        other_k.set(99, 3);
+        assert!(!client.verify(&other_k, &proof));
+        let mut other_session = pi.clone();
+        other_session.set(103, 0);
+        assert!(!client.verify(&other_session, &proof));
+        let mut other_player = pi.slice(0..HAND_COUNT_INPUTS_FIXED_LEN);
+        other_player.append(&Address::generate(&env).to_string().to_bytes());
+        assert!(!client.verify(&other_player, &proof));
+
+        // More than a deck's worth of cards
+        let (pi, proof) = build_hand_count_proof(&env, &hand, 0, HAND_COUNT_MAX + 1, 1312, &player);
+        assert!(!client.verify(&pi, &proof));
+    }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  Mode 7: Card Play Ring Sigma tests
+    //  UltraHonk routing tests (Mode 22)
     // ════════════════════════════════════════════════════════════════════════
 
-    /// BLS12-381 Fr modular order
-    const FR_ORDER: [u8; 32] = [
-        0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48,
-        0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
-        0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe,
-        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
-    ];
+    /// Stands in for rs-soroban-ultrahonk: traps unless the proof starts with 1.
+    #[contract]
+    pub struct MockUltraHonk;
 
-    fn bytes_to_u256(a: &[u8; 32]) -> [u128; 2] {
-        let hi = u128::from_be_bytes(a[0..16].try_into().unwrap());
-        let lo = u128::from_be_bytes(a[16..32].try_into().unwrap());
-        [hi, lo]
-    }
-
-    fn fr_add_bytes(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
-        let [ah, al] = bytes_to_u256(a);
-        let [bh, bl] = bytes_to_u256(b);
-        let (sum_lo, carry) = al.overflowing_add(bl);
-        let sum_hi = ah.wrapping_add(bh).wrapping_add(if carry { 1 } else { 0 });
-        let [rh, rl] = bytes_to_u256(&FR_ORDER);
-        if sum_hi > rh || (sum_hi == rh && sum_lo >= rl) {
-            let (sub_lo, borrow) = sum_lo.overflowing_sub(rl);
-            let sub_hi = sum_hi.wrapping_sub(rh).wrapping_sub(if borrow { 1 } else { 0 });
-            let mut out = [0u8; 32];
-            out[0..16].copy_from_slice(&sub_hi.to_be_bytes());
-            out[16..32].copy_from_slice(&sub_lo.to_be_bytes());
-            out
-        } else {
-            let mut out = [0u8; 32];
-            out[0..16].copy_from_slice(&sum_hi.to_be_bytes());
-            out[16..32].copy_from_slice(&sum_lo.to_be_bytes());
-            out
-        }
-    }
-
-    fn fr_sub_bytes(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
-        let [rh, rl] = bytes_to_u256(&FR_ORDER);
-        let [bh, bl] = bytes_to_u256(b);
-        let (neg_lo, borrow) = rl.overflowing_sub(bl);
-        let neg_hi = rh.wrapping_sub(bh).wrapping_sub(if borrow { 1 } else { 0 });
-        let mut neg_b = [0u8; 32];
-        neg_b[0..16].copy_from_slice(&neg_hi.to_be_bytes());
-        neg_b[16..32].copy_from_slice(&neg_lo.to_be_bytes());
-        fr_add_bytes(a, &neg_b)
-    }
-
-    /// Multiply 32-byte BE number by a small u32, producing 33 bytes.
-    fn mul_scalar_be(a: &[u8; 32], b: u32) -> [u8; 33] {
-        let mut result = [0u8; 33];
-        let mut carry: u64 = 0;
-        let mut i = 31i32;
-        while i >= 0 {
-            let product = a[i as usize] as u64 * b as u64 + carry;
-            result[(i as usize) + 1] = (product & 0xFF) as u8;
-            carry = product >> 8;
-            i -= 1;
+    #[contractimpl]
+    impl MockUltraHonk {
+        pub fn verify_proof(_env: Env, public_inputs: Bytes, proof_bytes: Bytes) {
+            assert_eq!(public_inputs.len() % 32, 0);
+            assert_eq!(proof_bytes.get(0), Some(1), "invalid proof");
         }
-        result[0] = carry as u8;
-        result
     }
 
-    /// Add a 33-byte and 32-byte BE number.
-    fn add_33_32(a: &[u8; 33], b: &[u8; 32]) -> [u8; 33] {
-        let mut result = [0u8; 33];
-        let mut carry: u16 = 0;
-        let mut i = 32i32;
-        while i >= 1 {
-            let sum = a[i as usize] as u16 + b[(i as usize) - 1] as u16 + carry;
-            result[i as usize] = (sum & 0xFF) as u8;
-            carry = sum >> 8;
-            i -= 1;
-        }
-        result[0] = a[0].wrapping_add(carry as u8);
-        result
-    }
-
-    /// Reduce a 32-byte value modulo Fr order (for raw keccak256 hashes).
-    fn reduce_to_fr(v: &[u8; 32]) -> [u8; 32] {
-        let [vh, vl] = bytes_to_u256(v);
-        let [rh, rl] = bytes_to_u256(&FR_ORDER);
-        if vh > rh || (vh == rh && vl >= rl) {
-            let (sub_lo, borrow) = vl.overflowing_sub(rl);
-            let sub_hi = vh.wrapping_sub(rh).wrapping_sub(if borrow { 1 } else { 0 });
-            let mut out = [0u8; 32];
-            out[0..16].copy_from_slice(&sub_hi.to_be_bytes());
-            out[16..32].copy_from_slice(&sub_lo.to_be_bytes());
-            return reduce_to_fr(&out);
-        }
-        *v
-    }
-
-    /// Reduce a 33-byte value modulo Fr order.
-    fn reduce_mod_fr(val: &[u8; 33]) -> [u8; 32] {
-        if val[0] == 0 {
-            let mut v32 = [0u8; 32];
-            v32.copy_from_slice(&val[1..33]);
-            let [vh, vl] = bytes_to_u256(&v32);
-            let [rh, rl] = bytes_to_u256(&FR_ORDER);
-            if vh > rh || (vh == rh && vl >= rl) {
-                return fr_sub_bytes(&v32, &FR_ORDER);
-            }
-            return v32;
-        }
-        // Subtract FR_ORDER from the 33-byte value
-        let mut reduced = *val;
-        let mut borrow: u16 = 0;
-        let mut ii = 32i32;
-        while ii >= 1 {
-            let diff = reduced[ii as usize] as i32 - FR_ORDER[(ii as usize) - 1] as i32 - borrow as i32;
-            if diff < 0 {
-                reduced[ii as usize] = (diff + 256) as u8;
-                borrow = 1;
-            } else {
-                reduced[ii as usize] = diff as u8;
-                borrow = 0;
-            }
-            ii -= 1;
-        }
-        reduced[0] = reduced[0].wrapping_sub(borrow as u8);
-        if reduced[0] > 0 {
-            return reduce_mod_fr(&reduced);
-        }
-        let mut v32 = [0u8; 32];
-        v32.copy_from_slice(&reduced[1..33]);
-        v32
+    fn noir_proof(env: &Env, first: u8) -> Bytes {
+        let mut proof = Bytes::from_array(env, &[0u8; 14_080]);
+        proof.set(0, first);
+        proof
     }
 
-    /// Build a valid Ring Sigma proof for testing (mirrors frontend buildCardPlayRingProof).
-    fn build_ring_sigma_proof(
-        env: &Env,
-        card_id: u32,
-        blinding: &BytesN<32>,
-        valid_set: &[u32],
-        session_id: u32,
-        player: &Address,
-    ) -> (BytesN<32>, Bytes) {
-        let bls = env.crypto().bls12_381();
-        let n = valid_set.len();
-
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        let g = G1Affine::from_array(env, &g1_bytes);
-        let h = ZkCommitmentVerifier::pedersen_h(&bls, env);
-
-        // Fr(card_id)
-        let mut card_fr_arr = [0u8; 32];
-        card_fr_arr[31] = (card_id & 0xFF) as u8;
-        card_fr_arr[30] = ((card_id >> 8) & 0xFF) as u8;
-        let card_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &card_fr_arr));
-
-        // Fr(blinding)
-        let blinding_fr = Fr::from_bytes(blinding.clone());
-        let blinding_arr = blinding.to_array();
-
-        // C = card_id·G + blinding·H
-        let card_g = bls.g1_mul(&g, &card_fr);
-        let blind_h = bls.g1_mul(&h, &blinding_fr);
-        let commitment = bls.g1_add(&card_g, &blind_h);
-        let c_raw = commitment.to_bytes();
+    #[test]
+    fn test_ultrahonk_routes_to_registered_verifier() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let public_inputs = Bytes::from_array(&env, &[0u8; 32 * 32]);
+        let proof = noir_proof(&env, 1);
+        assert_eq!(ZkCommitmentVerifier::mode_of(&public_inputs, &proof), Some(MODE_ULTRAHONK));
 
-        // commit_hash = keccak256(C)
-        let commit_hash: BytesN<32> = env.crypto().keccak256(
-            &Bytes::from_array(env, &c_raw.to_array())
-        ).into();
-
-        // Find real index
-        let real_idx = valid_set.iter().position(|&c| c == card_id).unwrap();
-
-        // D_i = C − valid_set[i]·G
-        let mut d_points = soroban_sdk::Vec::<G1Affine>::new(env);
-        let mut ci = 0usize;
-        while ci < n {
-            let mut ci_fr_arr = [0u8; 32];
-            ci_fr_arr[31] = (valid_set[ci] & 0xFF) as u8;
-            ci_fr_arr[30] = ((valid_set[ci] >> 8) & 0xFF) as u8;
-            let ci_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &ci_fr_arr));
-            let ci_g = bls.g1_mul(&g, &ci_fr);
-            let neg_ci_g = -ci_g;
-            let d = bls.g1_add(&commitment, &neg_ci_g);
-            d_points.push_back(d);
-            ci += 1;
-        }
-
-        // Nonce k
-        let k_arr: [u8; 32] = [
-            0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-            0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, 0x01,
-            0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09,
-            0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11,
-        ];
-        let k_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &k_arr));
-        let r_real = bls.g1_mul(&h, &k_fr);
+        // Native modes above 4 KB keep their lengths
+        let card_compare = Bytes::from_array(&env, &[0u8; CARD_COMPARE_PROOF_LEN as usize]);
+        assert_eq!(ZkCommitmentVerifier::mode_of(&public_inputs, &card_compare), Some(MODE_CARD_COMPARE));
 
-        let mut e_arrs = [[0u8; 32]; 9];
-        let mut z_arrs = [[0u8; 32]; 9];
-        let mut r_points = soroban_sdk::Vec::<G1Affine>::new(env);
+        // Nothing registered: rejected without a trap
+        assert_eq!(client.get_ultrahonk_verifier(), None);
+        assert!(!client.verify(&public_inputs, &proof));
+        assert_eq!(
+            env.events().all().filter_by_contract(&contract_id).events().last().unwrap(),
+            &EvVerifyFailed {
+                reason: ZkVerifyError::UltraHonkVerifierNotSet as u32,
+                mode: MODE_ULTRAHONK,
+                session_id: None,
+                inputs_len: public_inputs.len(),
+                proof_len: proof.len(),
+                check: 0,
+            }
+            .to_xdr(&env, &contract_id)
+        );
 
-        let mut sum_other_e = [0u8; 32];
-        let mut idx = 0usize;
-        while idx < n {
-            if idx == real_idx {
-                r_points.push_back(r_real.clone());
-            } else {
-                let mut ei_arr = [0u8; 32];
-                ei_arr[31] = (idx as u8 + 1) * 17;
-                ei_arr[30] = (idx as u8 + 1) * 3;
-                let ei_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &ei_arr));
-
-                let mut zi_arr = [0u8; 32];
-                zi_arr[31] = (idx as u8 + 1) * 23;
-                zi_arr[30] = (idx as u8 + 1) * 7;
-                let zi_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &zi_arr));
-
-                let z_h = bls.g1_mul(&h, &zi_fr);
-                let e_d = bls.g1_mul(&d_points.get(idx as u32).unwrap(), &ei_fr);
-                let neg_e_d = -e_d;
-                let r_i = bls.g1_add(&z_h, &neg_e_d);
-
-                r_points.push_back(r_i);
-                e_arrs[idx] = ei_arr;
-                z_arrs[idx] = zi_arr;
-                sum_other_e = fr_add_bytes(&sum_other_e, &ei_arr);
+        let ultrahonk = env.register(MockUltraHonk, ());
+        client.set_ultrahonk_verifier(&ultrahonk);
+        assert_eq!(client.get_ultrahonk_verifier(), Some(ultrahonk));
+        assert!(client.verify(&public_inputs, &proof));
+        assert!(client.verify_from(&Address::generate(&env), &public_inputs, &proof));
+
+        // A proof the UltraHonk verifier rejects fails without trapping
+        let rejected = noir_proof(&env, 0);
+        assert!(!client.verify(&public_inputs, &rejected));
+        assert_eq!(
+            env.events().all().filter_by_contract(&contract_id).events().last().unwrap(),
+            &EvVerifyFailed {
+                reason: ZkVerifyError::UltraHonkProofRejected as u32,
+                mode: MODE_ULTRAHONK,
+                session_id: None,
+                inputs_len: public_inputs.len(),
+                proof_len: rejected.len(),
+                check: 1,
             }
-            idx += 1;
-        }
+            .to_xdr(&env, &contract_id)
+        );
 
-        // Fiat-Shamir challenge
-        let mut challenge_pre = Bytes::from_array(env, &c_raw.to_array());
-        let mut ri = 0usize;
-        while ri < n {
-            let r_bytes = r_points.get(ri as u32).unwrap().to_bytes();
-            challenge_pre.append(&Bytes::from_array(env, &r_bytes.to_array()));
-            ri += 1;
-        }
-        challenge_pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        challenge_pre.append(&player.to_string().to_bytes());
-        challenge_pre.append(&Bytes::from_array(env, &RING_CHALLENGE_TAG));
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_pre).into();
-        let e_arr = e_hash.to_array();
+        // And the operator can switch the route off like any other mode
+        client.set_mode_enabled(&MODE_ULTRAHONK, &false);
+        assert!(!client.verify(&public_inputs, &proof));
+    }
 
-        // e_j = e − Σ_{i≠j} e_i (mod Fr)
-        // Must reduce hash to canonical Fr range first (keccak256 can produce values >= FR_ORDER)
-        let e_reduced = reduce_to_fr(&e_arr);
-        let e_real = fr_sub_bytes(&e_reduced, &sum_other_e);
-        e_arrs[real_idx] = e_real;
+    #[test]
+    fn test_ultrahonk_routes_by_circuit_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let proof = noir_proof(&env, 1);
+        let mut public_inputs = Bytes::from_array(&env, &7u32.to_be_bytes());
+        public_inputs.append(&Bytes::from_array(&env, &[0u8; 32 * 32]));
 
-        // z_j = k + e_j * blinding (mod Fr)
-        // For small blinding values (fit in u32):
-        let blind_u32 = ((blinding_arr[28] as u32) << 24)
-            | ((blinding_arr[29] as u32) << 16)
-            | ((blinding_arr[30] as u32) << 8)
-            | (blinding_arr[31] as u32);
+        // A circuit id never falls back to the default verifier
+        client.set_ultrahonk_verifier(&env.register(MockUltraHonk, ()));
+        assert!(!client.verify(&public_inputs, &proof));
+        assert_eq!(
+            env.events().all().filter_by_contract(&contract_id).events().last().unwrap(),
+            &EvVerifyFailed {
+                reason: ZkVerifyError::VkNotFound as u32,
+                mode: MODE_ULTRAHONK,
+                session_id: None,
+                inputs_len: public_inputs.len(),
+                proof_len: proof.len(),
+                check: 0,
+            }
+            .to_xdr(&env, &contract_id)
+        );
 
-        let e_times_blind = mul_scalar_be(&e_real, blind_u32);
-        let sum = add_33_32(&e_times_blind, &k_arr);
-        z_arrs[real_idx] = reduce_mod_fr(&sum);
+        // The circuit's verifier gets the field elements without the id
+        let circuit = env.register(MockUltraHonk, ());
+        client.register_ultrahonk_circuit(&7, &circuit);
+        assert_eq!(client.get_ultrahonk_circuit(&7), Some(circuit));
+        assert!(client.verify(&public_inputs, &proof));
+        assert!(!client.verify(&public_inputs, &noir_proof(&env, 0)));
+    }
 
-        // Build proof: C(96) || [e_i(32) || z_i(32)] × N
-        let mut proof_data = Bytes::from_array(env, &c_raw.to_array());
-        let mut pi = 0usize;
-        while pi < n {
-            proof_data.append(&Bytes::from_array(env, &e_arrs[pi]));
-            proof_data.append(&Bytes::from_array(env, &z_arrs[pi]));
-            pi += 1;
-        }
+    // ════════════════════════════════════════════════════════════════════════
+    //  Vector commitment helpers (Mode 23)
+    // ════════════════════════════════════════════════════════════════════════
 
-        (commit_hash, proof_data)
+    fn vector_h(env: &Env, index: u32) -> G1Affine {
+        let mut dst = Bytes::from_slice(env, VECTOR_H_DST_PREFIX);
+        dst.append(&Bytes::from_array(env, &index.to_be_bytes()));
+        env.crypto().bls12_381().hash_to_g1(&Bytes::from_slice(env, VECTOR_H_MSG), &dst)
     }
 
-    fn build_ring_public_inputs(
+    /// Build a Mode 23 proof opening `opened` (increasing positions) of the
+    /// vector commitment to `committed`. The prover answers from `witness`,
+    /// which is the honest hand when it equals `committed`.
+    /// Returns (public_inputs, proof).
+    fn build_vector_opening_proof(
         env: &Env,
-        commit_hash: &BytesN<32>,
-        valid_set: &[u32],
+        committed: &[u32],
+        witness: &[u32],
+        opened: &[u32],
         session_id: u32,
         player: &Address,
-    ) -> Bytes {
-        let n = valid_set.len() as u32;
-        let mut pi = Bytes::from_array(env, &commit_hash.to_array());
-        pi.append(&Bytes::from_array(env, &n.to_be_bytes()));
-        let mut i = 0usize;
-        while i < valid_set.len() {
-            pi.append(&Bytes::from_array(env, &valid_set[i].to_be_bytes()));
-            i += 1;
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let h = pedersen_h(env);
+        let k = committed.len() as u32;
+        let m = opened.len() as u32;
+        let r = test_scalar(env, b"vector-r", session_id, 0);
+
+        let mut points = Vec::from_array(env, [h.clone()]);
+        let mut scalars = Vec::from_array(env, [r.clone()]);
+        for (i, card) in committed.iter().enumerate() {
+            points.push_back(vector_h(env, i as u32));
+            scalars.push_back(fr_u32(env, *card));
         }
-        pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        pi.append(&player.to_string().to_bytes());
-        pi
+        let vector = bls.g1_msm(points, scalars);
+
+        // Nonces for r and every unopened position
+        let a_r = test_scalar(env, b"vector-a", session_id, k);
+        let mut points = Vec::from_array(env, [h]);
+        let mut scalars = Vec::from_array(env, [a_r.clone()]);
+        for i in (0..k).filter(|i| !opened.contains(i)) {
+            points.push_back(vector_h(env, i));
+            scalars.push_back(test_scalar(env, b"vector-a", session_id, i));
+        }
+        let r_point = bls.g1_msm(points, scalars);
+
+        let mut public_inputs = Bytes::from_array(env, &vector.to_bytes().to_array());
+        public_inputs.append(&Bytes::from_array(env, &k.to_be_bytes()));
+        public_inputs.append(&Bytes::from_array(env, &m.to_be_bytes()));
+        for &position in opened {
+            public_inputs.append(&Bytes::from_array(env, &position.to_be_bytes()));
+            public_inputs.append(&Bytes::from_array(env, &witness[position as usize].to_be_bytes()));
+        }
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
+
+        let mut pre = Bytes::from_array(env, &vector.to_bytes().to_array());
+        pre.append(&Bytes::from_array(env, &r_point.to_bytes().to_array()));
+        pre.append(&public_inputs.slice(96..));
+        pre.append(&Bytes::from_array(env, &VECTOR_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &pre));
+
+        let mut proof = Bytes::from_array(env, &k.to_be_bytes());
+        proof.append(&Bytes::from_array(env, &m.to_be_bytes()));
+        proof.append(&fr_bytes(env, &e));
+        proof.append(&fr_bytes(env, &(a_r + e.clone() * r)));
+        for i in (0..k).filter(|i| !opened.contains(i)) {
+            let a_i = test_scalar(env, b"vector-a", session_id, i);
+            proof.append(&fr_bytes(env, &(a_i + e.clone() * fr_u32(env, witness[i as usize]))));
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    //  Vector commitment tests (Mode 23)
+    // ════════════════════════════════════════════════════════════════════════
+
     #[test]
-    fn test_ring_sigma_valid_proof_n1() {
+    fn test_vector_opening_valid() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
-
-        let card_id: u32 = 5;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 42; arr
-        });
-        let valid_set = [5u32];
-        let session_id = 100u32;
-
-        let (commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let public_inputs = build_ring_public_inputs(
-            &env, &commit_hash, &valid_set, session_id, &player,
-        );
-
-        assert!(client.verify(&public_inputs, &proof), "Ring sigma N=1 should verify");
+        let hand = [3u32, 14, 22, 30, 35];
+
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[2], 2301, &player);
+        assert_eq!(proof.len(), VECTOR_PROOF_HEADER_LEN + 32 * 4);
+        assert_eq!(ZkCommitmentVerifier::mode_of(&pi, &proof), Some(MODE_VECTOR_COMMIT));
+        assert!(client.verify(&pi, &proof), "Opening one position should verify");
+
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[0, 3, 4], 2302, &player);
+        assert!(client.verify(&pi, &proof), "Opening several positions should verify");
+
+        // m = 0 opens nothing, m = k opens the whole hand
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[], 2303, &player);
+        assert!(client.verify(&pi, &proof), "Empty opening should verify");
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[0, 1, 2, 3, 4], 2304, &player);
+        assert_eq!(proof.len(), VECTOR_PROOF_HEADER_LEN);
+        assert!(client.verify(&pi, &proof), "Full opening should verify");
+
+        // A full half-deck hand
+        let big: [u32; VECTOR_MAX_LEN as usize] = core::array::from_fn(|i| 2 * i as u32);
+        let (pi, proof) = build_vector_opening_proof(&env, &big, &big, &[7], 2305, &player);
+        assert!(client.verify(&pi, &proof), "Eighteen-card hand should verify");
     }
 
     #[test]
-    fn test_ring_sigma_valid_proof_n3() {
+    fn test_vector_opening_binds_positions() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
+        let hand = [3u32, 14, 22, 30];
+
+        // A permutation and a sum-preserving swap open a Mode 8 aggregate
+        // equally well, but not a vector commitment
+        let permuted = [14u32, 3, 22, 30];
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &permuted, &[0], 2310, &player);
+        assert!(!client.verify(&pi, &proof), "Permuted hand must be rejected");
+        let swapped = [4u32, 13, 22, 30];
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &swapped, &[1], 2311, &player);
+        assert!(!client.verify(&pi, &proof), "Sum-preserving swap must be rejected");
+
+        // Editing an opened card or position after proving
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[1], 2312, &player);
+        assert!(client.verify(&pi, &proof));
+        let mut other_card = pi.clone();
+        other_card.set(111, 15);
+        assert!(!client.verify(&other_card, &proof));
+        let mut other_position = pi.clone();
+        other_position.set(107, 2);
+        assert!(!client.verify(&other_position, &proof));
+        let mut other_session = pi.clone();
+        other_session.set(115, 0);
+        assert!(!client.verify(&other_session, &proof));
+
+        // Malformed statements
+        let mut out_of_range = pi.clone();
+        out_of_range.set(107, 4);
+        assert!(!client.verify(&out_of_range, &proof), "Position past k must be rejected");
+        let mut other_k = pi.clone();
+        other_k.set(99, 5);
+        assert!(!client.verify(&other_k, &proof), "k must match the proof");
+        let (pi, proof) = build_vector_opening_proof(&env, &hand, &hand, &[2, 1], 2313, &player);
+        assert!(!client.verify(&pi, &proof), "Unsorted positions must be rejected");
+        let (pi, proof) = build_vector_opening_proof(&env, &[3, 36], &[3, 36], &[1], 2314, &player);
+        assert!(!client.verify(&pi, &proof), "Card past the deck must be rejected");
+    }
 
-        let card_id: u32 = 10;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 7; arr
-        });
-        let valid_set = [9u32, 10, 11];
-        let session_id = 200u32;
+    // ════════════════════════════════════════════════════════════════════════
+    //  Linked card play helpers (Mode 24)
+    // ════════════════════════════════════════════════════════════════════════
 
-        let (commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let public_inputs = build_ring_public_inputs(
-            &env, &commit_hash, &valid_set, session_id, &player,
-        );
+    /// Build a Mode 24 proof that a fresh commitment to `card_id` is in
+    /// `valid_set` and matches its `card_commitment` among `hand`. A card
+    /// missing from either list is proven against the first member instead,
+    /// giving a proof the verifier must reject. Returns (public_inputs, proof).
+    fn build_linked_play_proof(
+        env: &Env,
+        card_id: u32,
+        valid_set: &[u32],
+        hand: &[u32],
+        session_id: u32,
+        player: &Address,
+    ) -> (Bytes, Bytes) {
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let g = g1_generator(env);
+        let h = pedersen_h(env);
+        let r = test_scalar(env, b"play-r", card_id, session_id);
+        let commitment = bls.g1_msm(vec![env, g.clone(), h.clone()], vec![env, fr_u32(env, card_id), r.clone()]);
+        let neg_c = -commitment.clone();
+        let c_bytes = Bytes::from_array(env, &commitment.to_bytes().to_array());
+        let commit_hash: BytesN<32> = env.crypto().keccak256(&c_bytes).into();
+
+        // Each ring as (member, weight) pairs: R_i = z_i·H + e_i·(−C) + (e_i·weight_i)·member_i
+        let mut public_inputs = Bytes::from_array(env, &commit_hash.to_array());
+        public_inputs.append(&Bytes::from_array(env, &(valid_set.len() as u32).to_be_bytes()));
+        let (mut cards, mut card_weights) = (Vec::new(env), Vec::new(env));
+        for &v in valid_set {
+            public_inputs.append(&Bytes::from_array(env, &v.to_be_bytes()));
+            cards.push_back(g.clone());
+            card_weights.push_back(fr_u32(env, v));
+        }
+        public_inputs.append(&Bytes::from_array(env, &(hand.len() as u32).to_be_bytes()));
+        let (mut dealt, mut dealt_weights) = (Vec::new(env), Vec::new(env));
+        for &card in hand {
+            let p = card_commitment(env, card);
+            public_inputs.append(&Bytes::from_array(env, &p.to_bytes().to_array()));
+            dealt.push_back(p);
+            dealt_weights.push_back(fr_u32(env, 1));
+        }
+        public_inputs.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        public_inputs.append(&player.to_string().to_bytes());
 
-        assert!(client.verify(&public_inputs, &proof), "Ring sigma N=3 should verify");
+        let real_hand = hand.iter().position(|&c| c == card_id).unwrap_or(0);
+        let rings = [(cards, card_weights), (dealt, dealt_weights)];
+        let reals = [valid_set.iter().position(|&c| c == card_id).unwrap_or(0) as u32, real_hand as u32];
+        let witnesses = [r.clone(), r - test_scalar(env, b"hand-r", hand[real_hand], 0)];
+
+        // First moves: simulated for every branch but the real one
+        let mut preimage = c_bytes;
+        let mut sims = [fr_u32(env, 0), fr_u32(env, 0)];
+        for (ring, (members, weights)) in rings.iter().enumerate() {
+            for i in 0..members.len() {
+                let r_i = if i == reals[ring] {
+                    bls.g1_mul(&h, &test_scalar(env, b"link-t", ring as u32, i))
+                } else {
+                    let e_i = test_scalar(env, b"link-e", ring as u32, i);
+                    sims[ring] = sims[ring].clone() + e_i.clone();
+                    bls.g1_msm(
+                        vec![env, h.clone(), neg_c.clone(), members.get_unchecked(i)],
+                        vec![env, test_scalar(env, b"link-z", ring as u32, i), e_i.clone(), e_i * weights.get_unchecked(i)],
+                    )
+                };
+                preimage.append(&Bytes::from_array(env, &r_i.to_bytes().to_array()));
+            }
+        }
+        preimage.append(&public_inputs.slice(32..));
+        preimage.append(&Bytes::from_array(env, &LINKED_CHALLENGE_TAG));
+        let e = Fr::from_bytes(transcript::fs_digest(env, &preimage));
+
+        let mut proof = Bytes::from_array(env, &(valid_set.len() as u32).to_be_bytes());
+        proof.append(&Bytes::from_array(env, &(hand.len() as u32).to_be_bytes()));
+        proof.append(&Bytes::from_array(env, &compress_g1(&commitment.to_bytes().to_array())));
+        for (ring, (members, _)) in rings.iter().enumerate() {
+            for i in 0..members.len() {
+                let (e_i, z_i) = if i == reals[ring] {
+                    let e_real = e.clone() - sims[ring].clone();
+                    let z_real = test_scalar(env, b"link-t", ring as u32, i) + e_real.clone() * witnesses[ring].clone();
+                    (e_real, z_real)
+                } else {
+                    (test_scalar(env, b"link-e", ring as u32, i), test_scalar(env, b"link-z", ring as u32, i))
+                };
+                proof.append(&fr_bytes(env, &e_i));
+                proof.append(&fr_bytes(env, &z_i));
+            }
+        }
+        restore_verify_budget(env);
+        (public_inputs, proof)
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    //  Linked card play tests (Mode 24)
+    // ════════════════════════════════════════════════════════════════════════
+
     #[test]
-    fn test_ring_sigma_wrong_commit_hash_fails() {
+    fn test_linked_play_valid() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
 
-        let card_id: u32 = 5;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 42; arr
-        });
-        let valid_set = [5u32];
-        let session_id = 100u32;
+        // Dealt 7♥(14), 2♠(27), 9♥(16), 3♦(1), 5♣(21); hearts are led
+        let hand = [14u32, 27, 16, 1, 21];
+        let (pi, proof) = build_linked_play_proof(&env, 16, &[14, 16], &hand, 2401, &player);
+        assert_eq!(proof.len(), LINKED_PROOF_HEADER_LEN + 64 * 7);
+        assert_eq!(ZkCommitmentVerifier::mode_of(&pi, &proof), Some(MODE_LINKED_PLAY));
+        assert!(client.verify(&pi, &proof), "Dealt card in the valid set should verify");
+
+        // The largest statement: a full suit against three dealt cards
+        let suit = [9u32, 10, 11, 12, 13, 14, 15, 16, 17];
+        let (pi, proof) = build_linked_play_proof(&env, 11, &suit, &[30, 11, 4], 2402, &player);
+        assert!(client.verify(&pi, &proof), "Nine plus three members should fit the default budget");
+    }
 
-        let (_commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let wrong_hash = BytesN::<32>::from_array(&env, &[0xAA; 32]);
-        let public_inputs = build_ring_public_inputs(
-            &env, &wrong_hash, &valid_set, session_id, &player,
-        );
+    #[test]
+    fn test_linked_play_undealt_card_fails() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+        let hand = [14u32, 27, 1];
+
+        // 8♥ (15) is a valid heart but was never dealt: no P_j matches C
+        let (pi, proof) = build_linked_play_proof(&env, 15, &[14, 15], &hand, 2410, &player);
+        assert!(!client.verify(&pi, &proof), "Undealt card must be rejected");
+
+        // A dealt card outside the valid set fails the card ring instead
+        let (pi, proof) = build_linked_play_proof(&env, 27, &[14, 15], &hand, 2411, &player);
+        assert!(!client.verify(&pi, &proof), "Off-suit card must be rejected");
+
+        // Swapping a hand commitment or the session after proving
+        let (pi, proof) = build_linked_play_proof(&env, 14, &[14, 15], &hand, 2412, &player);
+        assert!(client.verify(&pi, &proof));
+        let other = card_commitment(&env, 15).to_bytes().to_array();
+        let mut swapped = pi.clone();
+        for (k, byte) in other.iter().enumerate() {
+            swapped.set(48 + k as u32, *byte);
+        }
+        assert!(!client.verify(&swapped, &proof), "Hand commitments are bound into the challenge");
+        let mut other_session = pi.clone();
+        other_session.set(48 + 3 * 96 + 3, 0);
+        assert!(!client.verify(&other_session, &proof));
+
+        // k in the proof must match the inputs
+        let mut other_k = pi.clone();
+        other_k.set(47, 2);
+        assert!(!client.verify(&other_k, &proof));
+    }
 
-        assert!(!client.verify(&public_inputs, &proof), "Wrong commit_hash should fail");
+    // ════════════════════════════════════════════════════════════════════════
+    //  Ed25519 seed tests (Mode 25)
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Mode 25 `(public_inputs, proof)` for `seed`, committed under and
+    /// signed by `key`. The commitment is Mode 2's with `pk` as blinding.
+    fn build_ed25519_seed_proof(
+        env: &Env,
+        seed: &BytesN<32>,
+        key: &ed25519_dalek::SigningKey,
+        session_id: u32,
+        tail: &PlayerTail,
+    ) -> (Bytes, Bytes) {
+        use ed25519_dalek::Signer;
+        let pk = BytesN::<32>::from_array(env, &key.verifying_key().to_bytes());
+        let seed_hash = nizk::seed_hash(env, seed);
+        let commitment = nizk::commitment(env, &seed_hash, &pk, tail);
+        let nullifier = nizk::nullifier(env, &seed_hash, session_id);
+        let public_inputs = nizk::public_inputs(env, &seed_hash, &commitment, &nullifier, session_id, tail);
+
+        let mut message = public_inputs.clone();
+        message.append(&Bytes::from_array(env, &ED25519_CHALLENGE_TAG));
+        let mut buf = [0u8; 256];
+        let msg = &mut buf[..message.len() as usize];
+        message.copy_into_slice(msg);
+        let mut proof = Bytes::from_array(env, &[ED25519_PROOF_TAG]);
+        proof.append(&pk.into());
+        proof.append(&Bytes::from_array(env, &key.sign(msg).to_bytes()));
+        (public_inputs, proof)
     }
 
     #[test]
-    fn test_ring_sigma_wrong_valid_set_fails() {
+    fn test_ed25519_seed_valid() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
+        let key = ed25519_dalek::SigningKey::from_bytes(&[0x25; 32]);
+        let seed = BytesN::<32>::from_array(&env, &core::array::from_fn(|i| i as u8 * 7 + 1));
+
+        let (pi, proof) = build_ed25519_seed_proof(&env, &seed, &key, 2500, &PlayerTail::new(&player));
+        assert_eq!(proof.len(), ED25519_PROOF_LEN);
+        assert_eq!(ZkCommitmentVerifier::mode_of(&pi, &proof), Some(MODE_ED25519_SEED));
+        assert!(client.verify(&pi, &proof));
+
+        // The commitment covers the strkey alone; the signature covers the window
+        let tail = PlayerTail::new(&player).valid_until(env.ledger().sequence() + 10);
+        let (windowed, windowed_proof) = build_ed25519_seed_proof(&env, &seed, &key, 2501, &tail);
+        assert!(client.verify(&windowed, &windowed_proof));
+    }
 
-        let card_id: u32 = 5;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 42; arr
-        });
-        let valid_set = [5u32];
-        let session_id = 100u32;
+    #[test]
+    fn test_ed25519_seed_rejects_wrong_statement() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let tail = PlayerTail::new(&Address::generate(&env));
+        let key = ed25519_dalek::SigningKey::from_bytes(&[0x25; 32]);
+        let other_key = ed25519_dalek::SigningKey::from_bytes(&[0x52; 32]);
+        let seed = BytesN::<32>::from_array(&env, &core::array::from_fn(|i| i as u8 * 7 + 1));
+        let (pi, proof) = build_ed25519_seed_proof(&env, &seed, &key, 2502, &tail);
+
+        // A key other than the committed one fails the binding check
+        let (_, other_proof) = build_ed25519_seed_proof(&env, &seed, &other_key, 2502, &tail);
+        assert!(!client.verify(&pi, &other_proof));
+
+        // Another session's nullifier
+        let (other_session, _) = build_ed25519_seed_proof(&env, &seed, &key, 2503, &tail);
+        let mut mixed = pi.slice(..96);
+        mixed.append(&other_session.slice(96..));
+        assert!(!client.verify(&mixed, &proof));
+
+        // A bad signature traps in the host
+        let mut forged = proof.clone();
+        forged.set(ED25519_PROOF_LEN - 1, proof.get(ED25519_PROOF_LEN - 1).unwrap() ^ 1);
+        assert!(client.try_verify(&pi, &forged).is_err());
+    }
 
-        let (commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let wrong_set = [6u32];
-        let public_inputs = build_ring_public_inputs(
-            &env, &commit_hash, &wrong_set, session_id, &player,
-        );
+    // ════════════════════════════════════════════════════════════════════════
+    //  Proof format tag
+    // ════════════════════════════════════════════════════════════════════════
 
-        assert!(!client.verify(&public_inputs, &proof), "Wrong valid_set should fail");
+    #[test]
+    fn test_tagged_proofs_dispatch_on_tag() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let player = Address::generate(&env);
+
+        let (nizk_pi, nizk_proof) = sample_nizk_proof(&env);
+        let tagged = tag_proof(&env, MODE_NIZK_SEED, &nizk_proof);
+        assert_eq!(tagged.get_unchecked(0), 0x82);
+        let decoded = env.as_contract(&contract_id, || ZkCommitmentVerifier::decode(&env, &nizk_pi, &tagged));
+        assert_eq!(decoded, Ok((MODE_NIZK_SEED, nizk_proof.clone())));
+        assert!(client.verify(&nizk_pi, &tagged));
+        assert!(client.verify(&nizk_pi, &nizk_proof));
+
+        let blinding = BytesN::<32>::from_array(&env, &[0x17; 32]);
+        let (commit_hash, ring_proof) = build_ring_sigma_proof(&env, 10, &blinding, &[9, 10, 11], 6, &player);
+        let ring_pi = build_ring_public_inputs(&env, &commit_hash, &[9, 10, 11], 6, &player);
+        assert!(client.verify(&ring_pi, &tag_proof(&env, MODE_CARD_RING, &ring_proof)));
+
+        // Mode 25 keeps its 0xED byte behind the tag
+        let key = ed25519_dalek::SigningKey::from_bytes(&[0x25; 32]);
+        let seed = BytesN::<32>::from_array(&env, &core::array::from_fn(|i| i as u8 * 7 + 1));
+        let (ed_pi, ed_proof) = build_ed25519_seed_proof(&env, &seed, &key, 2504, &PlayerTail::new(&player));
+        assert!(client.verify(&ed_pi, &tag_proof(&env, MODE_ED25519_SEED, &ed_proof)));
+
+        // A tag naming another mode is read as a legacy proof, and fails
+        assert!(!client.verify(&nizk_pi, &tag_proof(&env, MODE_PEDERSEN_SEED, &nizk_proof)));
     }
 
     #[test]
-    fn test_ring_sigma_different_session_fails() {
+    fn test_legacy_proofs_can_be_refused() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        env.mock_all_auths();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &core::array::from_fn(|i| i as u8 + 1));
+        // The proof opens with the blinding; 0x11 carries no version bits
+        let blinding = BytesN::<32>::from_array(&env, &[0x11; 32]);
+        let (pi, proof, _) = generate_nizk_proof(&env, &seed, &blinding, 98, &Address::generate(&env));
+        assert!(client.is_legacy_proofs_enabled());
+
+        client.set_legacy_proofs_enabled(&false);
+        assert!(!client.is_legacy_proofs_enabled());
+        assert!(!client.verify(&pi, &proof));
+        assert_eq!(client.try_verify_debug(&pi, &proof), Err(Ok(ZkVerifyError::UnknownProofVersion)));
+        assert!(client.verify(&pi, &tag_proof(&env, MODE_NIZK_SEED, &proof)));
+
+        // The tag alone decides: a wrong mode fails in that mode's routine
+        assert!(!client.verify(&pi, &tag_proof(&env, MODE_HAND_COUNT, &proof)));
+        assert_eq!(client.try_verify_debug(&pi, &tag_proof(&env, 3, &proof)), Err(Ok(ZkVerifyError::UnknownMode)));
+
+        client.set_legacy_proofs_enabled(&true);
+        assert!(client.verify(&pi, &proof));
+    }
 
-        let card_id: u32 = 5;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 42; arr
-        });
-        let valid_set = [5u32];
-        let session_id = 100u32;
+    // ════════════════════════════════════════════════════════════════════════
+    //  Mode 26: Trick bundle
+    // ════════════════════════════════════════════════════════════════════════
 
-        let (commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let public_inputs = build_ring_public_inputs(
-            &env, &commit_hash, &valid_set, 999, &player,
-        );
+    /// The lead: card 10 (suit 1) from [9, 10, 11], by `player`.
+    fn build_trick_lead(env: &Env, session_id: u32, player: &Address) -> (Bytes, Bytes) {
+        let blinding = BytesN::<32>::from_array(env, &[0x26; 32]);
+        let (commit_hash, proof) = build_ring_sigma_proof(env, 10, &blinding, &[9, 10, 11], session_id, player);
+        (build_ring_public_inputs(env, &commit_hash, &[9, 10, 11], session_id, player), proof)
+    }
 
-        assert!(!client.verify(&public_inputs, &proof), "Different session should fail");
+    #[test]
+    fn test_trick_bundle_ring_and_ring() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+        let (lead_pi, lead_proof) = build_trick_lead(&env, 26, &alice);
+
+        // The follower chains onto the lead they have already seen
+        let link = trick::link(&env, (MODE_CARD_RING, &lead_pi, &lead_proof));
+        let tail = PlayerTail::new(&bob).linked(&link);
+        let (commit_hash, follow_proof) =
+            ring::prove(&env, 12, &[0x62; 32], &[12, 13], &TEST_NONCE, 26, &tail).unwrap();
+        let follow_pi = ring::public_inputs(&env, &commit_hash, &[12, 13], 26, &tail);
+
+        let (pi, proof) =
+            trick::bundle(&env, (MODE_CARD_RING, &lead_pi, &lead_proof), (MODE_CARD_RING, &follow_pi, &follow_proof));
+        assert_eq!(proof.get_unchecked(0), PROOF_FORMAT_V1 | MODE_TRICK_BUNDLE as u8);
+        assert!(client.verify(&pi, &proof));
+
+        // Each player's play counts under Mode 7
+        let log = client.get_verification_log(&26);
+        assert_eq!(log.len(), 2);
+        assert!(log.iter().all(|entry| entry.mode == MODE_CARD_RING && entry.count == 1));
     }
 
     #[test]
-    fn test_ring_sigma_different_player_fails() {
+    fn test_trick_bundle_ring_and_hand() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
-        let other_player = Address::generate(&env);
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+        let (lead_pi, lead_proof) = build_trick_lead(&env, 27, &alice);
+
+        // Bob holds no card of suit 1 and cangkuls
+        let link = trick::link(&env, (MODE_CARD_RING, &lead_pi, &lead_proof));
+        let tail = PlayerTail::new(&bob).linked(&link);
+        let hand_cards = [0, 2, 20];
+        let blindings = [[0x31; 32], [0x32; 32], [0x33; 32]];
+        let (commit_hash, hand_proof) = hand::prove(&env, &hand_cards, &blindings, 1, &TEST_NONCE, 27, &tail).unwrap();
+        let hand_pi = hand::public_inputs(&env, &commit_hash, 1, &hand_cards, 27, &tail);
+
+        let (pi, proof) =
+            trick::bundle(&env, (MODE_CARD_RING, &lead_pi, &lead_proof), (MODE_CANGKUL_HAND, &hand_pi, &hand_proof));
+        assert!(client.verify(&pi, &proof));
+    }
 
-        let card_id: u32 = 5;
-        let blinding = BytesN::<32>::from_array(&env, &{
-            let mut arr = [0u8; 32]; arr[31] = 42; arr
-        });
-        let valid_set = [5u32];
-        let session_id = 100u32;
+    #[test]
+    fn test_trick_bundle_rejects_unlinked_or_mismatched_parts() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        env.cost_estimate().budget().reset_unlimited();
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+        let (lead_pi, lead_proof) = build_trick_lead(&env, 28, &alice);
+        let lead = (MODE_CARD_RING, &lead_pi, &lead_proof);
+
+        // A standalone follow proof is not chained onto the lead
+        let (plain_pi, plain_proof) = build_trick_lead(&env, 28, &bob);
+        let (pi, proof) = trick::bundle(&env, lead, (MODE_CARD_RING, &plain_pi, &plain_proof));
+        assert!(!client.verify(&pi, &proof));
+
+        // A follow chained onto another lead
+        let (other_pi, other_proof) = build_trick_lead(&env, 28, &Address::generate(&env));
+        let link = trick::link(&env, (MODE_CARD_RING, &other_pi, &other_proof));
+        let tail = PlayerTail::new(&bob).linked(&link);
+        let (commit_hash, follow_proof) = ring::prove(&env, 12, &[0x62; 32], &[12, 13], &TEST_NONCE, 28, &tail).unwrap();
+        let follow_pi = ring::public_inputs(&env, &commit_hash, &[12, 13], 28, &tail);
+        let (pi, proof) = trick::bundle(&env, lead, (MODE_CARD_RING, &follow_pi, &follow_proof));
+        assert!(!client.verify(&pi, &proof));
+
+        // One player twice, or two sessions
+        let (pi, proof) = trick::bundle(&env, lead, lead);
+        assert!(!client.verify(&pi, &proof));
+        let (later_pi, later_proof) = build_trick_lead(&env, 29, &bob);
+        let (pi, proof) = trick::bundle(&env, lead, (MODE_CARD_RING, &later_pi, &later_proof));
+        assert!(!client.verify(&pi, &proof));
+
+        // No length rule: untagged, the bundle is not recognised
+        let link = trick::link(&env, lead);
+        let tail = PlayerTail::new(&bob).linked(&link);
+        let (commit_hash, follow_proof) = ring::prove(&env, 12, &[0x62; 32], &[12, 13], &TEST_NONCE, 28, &tail).unwrap();
+        let follow_pi = ring::public_inputs(&env, &commit_hash, &[12, 13], 28, &tail);
+        let (pi, proof) = trick::bundle(&env, lead, (MODE_CARD_RING, &follow_pi, &follow_proof));
+        assert!(client.verify(&pi, &proof));
+        assert!(!client.verify(&pi, &proof.slice(1..)));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Known-answer vectors
+    // ════════════════════════════════════════════════════════════════════════
 
-        let (commit_hash, proof) = build_ring_sigma_proof(
-            &env, card_id, &blinding, &valid_set, session_id, &player,
-        );
-        let public_inputs = build_ring_public_inputs(
-            &env, &commit_hash, &valid_set, session_id, &other_player,
-        );
+    #[test]
+    fn test_known_answer_vectors_match_verifier() {
+        let env = Env::default();
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        env.cost_estimate().budget().reset_unlimited();
+
+        let all = vectors::all(&env);
+        assert!(all.iter().any(|v| v.valid) && all.iter().any(|v| !v.valid));
+        for v in &all {
+            assert_eq!(client.verify(&v.public_inputs, &v.tagged_proof(&env)), v.valid, "{}", v.name);
+            // Mode 26 is tag-only; everything else still auto-detects
+            if v.mode != MODE_TRICK_BUNDLE {
+                assert_eq!(client.verify(&v.public_inputs, &v.proof), v.valid, "{} untagged", v.name);
+            }
+        }
 
-        assert!(!client.verify(&public_inputs, &proof), "Different player should fail");
+        // Byte-identical across runs
+        let (first, second) = (Env::default(), Env::default());
+        first.cost_estimate().budget().reset_unlimited();
+        second.cost_estimate().budget().reset_unlimited();
+        assert_eq!(vectors::to_json(&first), vectors::to_json(&second));
     }
 
     // ════════════════════════════════════════════════════════════════════════
-    //  Mode 8: Cangkul Hand Proof tests
+    //  Compressed G1 points (Modes 4, 7, 8)
     // ════════════════════════════════════════════════════════════════════════
 
-    /// Full Fr multiplication: a * b mod Fr_ORDER (shift-and-add).
-    fn fr_mul_bytes(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
-        let mut result = [0u8; 32];
-        let mut bi = 0usize;
-        while bi < 256 {
-            result = fr_add_bytes(&result, &result);
-            let byte_idx = bi / 8;
-            let bit_idx = 7 - (bi % 8);
-            if (b[byte_idx] >> bit_idx) & 1 == 1 {
-                result = fr_add_bytes(&result, a);
-            }
-            bi += 1;
+    /// (p − 1) / 2 for the BLS12-381 base field; y is the larger root iff y > this.
+    const FP_HALF: [u8; 48] = [
+        0x0d, 0x00, 0x88, 0xf5, 0x1c, 0xbf, 0xf3, 0x4d, 0x25, 0x8d, 0xd3, 0xdb,
+        0x21, 0xa5, 0xd6, 0x6b, 0xb2, 0x3b, 0xa5, 0xc2, 0x79, 0xc2, 0x89, 0x5f,
+        0xb3, 0x98, 0x69, 0x50, 0x7b, 0x58, 0x7b, 0x12, 0x0f, 0x55, 0xff, 0xff,
+        0x58, 0xa9, 0xff, 0xff, 0xdc, 0xff, 0x7f, 0xff, 0xff, 0xff, 0xd5, 0x55,
+    ];
+
+    /// Compress an uncompressed G1 encoding (0x80 compressed, 0x40 infinity, 0x20 larger y).
+    fn compress_g1(point: &[u8; 96]) -> [u8; 48] {
+        let mut out = [0u8; 48];
+        if point[0] & 0x40 != 0 {
+            out[0] = 0xC0;
+            return out;
         }
-        result
+        out.copy_from_slice(&point[..48]);
+        out[0] |= 0x80;
+        if point[48..] > FP_HALF[..] {
+            out[0] |= 0x20;
+        }
+        out
     }
 
-    /// Build a valid Mode 8 (Cangkul Hand) proof for testing.
-    fn build_cangkul_hand_proof(
-        env: &Env,
-        hand: &[u32],
-        blindings: &[[u8; 32]],
-        trick_suit: u32,
-        session_id: u32,
-        player: &Address,
-    ) -> (BytesN<32>, Bytes) {
+    /// Re-encode the G1 points at `offsets` (ascending) of `data` compressed.
+    fn compress_at(env: &Env, data: &Bytes, offsets: &[u32]) -> Bytes {
+        let mut out = Bytes::new(env);
+        let mut from = 0;
+        for &offset in offsets {
+            let mut raw = [0u8; 96];
+            data.slice(offset..offset + 96).copy_into_slice(&mut raw);
+            out.append(&data.slice(from..offset));
+            out.append(&Bytes::from_array(env, &compress_g1(&raw)));
+            from = offset + 96;
+        }
+        out.append(&data.slice(from..data.len()));
+        out
+    }
+
+    /// Flip the sort flag of the compressed point at `offset`, selecting −P.
+    fn flip_sort_flag(data: &Bytes, offset: u32) -> Bytes {
+        let mut flipped = data.clone();
+        flipped.set(offset, data.get_unchecked(offset) ^ 0x20);
+        flipped
+    }
+
+    #[test]
+    fn test_compressed_g1_decodes_to_same_point() {
+        let env = Env::default();
         let bls = env.crypto().bls12_381();
-        let k = hand.len();
-
-        let g1_bytes: [u8; 96] = [
-            0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
-            0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
-            0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
-            0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
-            0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
-            0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
-            0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
-            0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
-        ];
-        let g = G1Affine::from_array(env, &g1_bytes);
-        let h = ZkCommitmentVerifier::pedersen_h(&bls, env);
-
-        // Compute per-card commitments and accumulate
-        let mut agg_r = [0u8; 32];
-        let mut card_fr_arr = [0u8; 32];
-        card_fr_arr[31] = (hand[0] & 0xFF) as u8;
-        card_fr_arr[30] = ((hand[0] >> 8) & 0xFF) as u8;
-        let card_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &card_fr_arr));
-        let blind_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &blindings[0]));
-        let card_g = bls.g1_mul(&g, &card_fr);
-        let blind_h = bls.g1_mul(&h, &blind_fr);
-        let mut agg_point = bls.g1_add(&card_g, &blind_h);
-        agg_r = blindings[0];
-
-        let mut ci = 1usize;
-        while ci < k {
-            let mut ci_fr_arr = [0u8; 32];
-            ci_fr_arr[31] = (hand[ci] & 0xFF) as u8;
-            ci_fr_arr[30] = ((hand[ci] >> 8) & 0xFF) as u8;
-            let ci_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &ci_fr_arr));
-            let ci_blind_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &blindings[ci]));
-            let ci_g = bls.g1_mul(&g, &ci_fr);
-            let ci_h = bls.g1_mul(&h, &ci_blind_fr);
-            let ci_point = bls.g1_add(&ci_g, &ci_h);
-            agg_point = bls.g1_add(&agg_point, &ci_point);
-            agg_r = fr_add_bytes(&agg_r, &blindings[ci]);
-            ci += 1;
-        }
-
-        let a_raw = agg_point.to_bytes();
-        let commit_hash: BytesN<32> = env.crypto().keccak256(
-            &Bytes::from_array(env, &a_raw.to_array())
-        ).into();
-
-        // Schnorr nonce
-        let nonce_arr: [u8; 32] = [
-            0xAA, 0xBB, 0xCC, 0xDD, 0x11, 0x22, 0x33, 0x44,
-            0x55, 0x66, 0x77, 0x88, 0x99, 0x00, 0xAB, 0xCD,
-            0xEF, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD,
-            0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32,
-        ];
-        let nonce_fr = Fr::from_bytes(BytesN::<32>::from_array(env, &nonce_arr));
-        let r_point = bls.g1_mul(&h, &nonce_fr);
-        let r_raw = r_point.to_bytes();
-
-        // Fiat-Shamir
-        let mut challenge_pre = Bytes::from_array(env, &a_raw.to_array());
-        challenge_pre.append(&Bytes::from_array(env, &r_raw.to_array()));
-        challenge_pre.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
-        challenge_pre.append(&Bytes::from_array(env, &(k as u32).to_be_bytes()));
-        challenge_pre.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        challenge_pre.append(&player.to_string().to_bytes());
-        challenge_pre.append(&Bytes::from_array(env, &CANGKUL_CHALLENGE_TAG));
-        let e_hash: BytesN<32> = env.crypto().keccak256(&challenge_pre).into();
-        let e_reduced = reduce_to_fr(&e_hash.to_array());
-
-        // z = nonce + e · r_agg (mod Fr)
-        let e_times_r = fr_mul_bytes(&e_reduced, &agg_r);
-        let z_arr = fr_add_bytes(&nonce_arr, &e_times_r);
-
-        // Proof: k(4) || A(96) || R(96) || z(32)
-        let mut proof_data = Bytes::from_array(env, &(k as u32).to_be_bytes());
-        proof_data.append(&Bytes::from_array(env, &a_raw.to_array()));
-        proof_data.append(&Bytes::from_array(env, &r_raw.to_array()));
-        proof_data.append(&Bytes::from_array(env, &z_arr));
-
-        (commit_hash, proof_data)
+        let g = g1_generator(&env);
+        let h = pedersen_h(&env);
+
+        // Both y parities occur among these points
+        let mut larger_y = 0;
+        for point in [g.clone(), -g.clone(), h.clone(), -h, bls.g1_mul(&g, &fr_u32(&env, 0xC0FFEE))] {
+            let raw = point.to_bytes().to_array();
+            let compressed = compress_g1(&raw);
+            larger_y += (compressed[0] & 0x20 != 0) as u32;
+            assert_eq!(fp::decompress_g1(&compressed), Some(raw));
+
+            let data = Bytes::from_array(&env, &compressed);
+            let mut reader = ProofReader::new(&env, &data);
+            assert_eq!(reader.g1_len_at(0), G1_COMPRESSED_LEN);
+            assert_eq!(reader.g1_any(), Some(point));
+            assert!(!reader.saw_identity());
+        }
+        assert!(larger_y > 0 && larger_y < 5);
+
+        // The identity is recorded like its uncompressed form
+        let identity = Bytes::from_array(&env, &compress_g1(&[0x40; 96]));
+        let mut reader = ProofReader::new(&env, &identity);
+        assert!(reader.g1_any().is_some() && reader.saw_identity());
+
+        // Malformed encodings are refused
+        let mut x_is_p = fp_modulus();
+        x_is_p[0] |= 0x80;
+        let mut no_curve_point = [0u8; 48];
+        no_curve_point[0] = 0x80;
+        no_curve_point[47] = 1; // 1 + 4 is not a square mod p
+        let mut dirty_identity = [0u8; 48];
+        dirty_identity[0] = 0xC0;
+        dirty_identity[47] = 1;
+        let mut unflagged = compress_g1(&g.to_bytes().to_array());
+        unflagged[0] &= 0x7F;
+        for bad in [x_is_p, no_curve_point, dirty_identity, [0xE0; 48], unflagged] {
+            assert_eq!(fp::decompress_g1(&bad), None);
+        }
     }
 
-    fn build_cangkul_public_inputs(
-        env: &Env,
-        commit_hash: &BytesN<32>,
-        trick_suit: u32,
-        hand: &[u32],
-        session_id: u32,
-        player: &Address,
-    ) -> Bytes {
-        let k = hand.len() as u32;
-        let mut pi = Bytes::from_array(env, &commit_hash.to_array());
-        pi.append(&Bytes::from_array(env, &trick_suit.to_be_bytes()));
-        pi.append(&Bytes::from_array(env, &k.to_be_bytes()));
-        let mut i = 0usize;
-        while i < hand.len() {
-            pi.append(&Bytes::from_array(env, &hand[i].to_be_bytes()));
-            i += 1;
+    /// BLS12-381 base field modulus p, big-endian.
+    fn fp_modulus() -> [u8; 48] {
+        let mut p = FP_HALF;
+        // p = 2·half + 1
+        let mut carry = 1u16;
+        for byte in p.iter_mut().rev() {
+            let v = ((*byte as u16) << 1) + carry;
+            *byte = v as u8;
+            carry = v >> 8;
         }
-        pi.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        pi.append(&player.to_string().to_bytes());
-        pi
+        p
     }
 
     #[test]
-    fn test_cangkul_hand_valid_proof_single_card() {
+    fn test_pedersen_sigma_compressed_points() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
         let player = Address::generate(&env);
 
-        let hand = [10u32];
-        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &player,
+        let (public_inputs, proof) = generate_pedersen_sigma_proof(
+            &env, &fr_u32(&env, 0xDEAD_BEEF), &fr_u32(&env, 0xCAFE_BABE),
+            &fr_u32(&env, 0x0506_0708), 9, &player,
         );
+        let short_proof = compress_at(&env, &proof, &[0]);
+        let short_inputs = compress_at(&env, &public_inputs, &[0]);
+        assert_eq!(short_proof.len(), 80);
 
-        assert!(client.verify(&public_inputs, &proof), "Single card cangkul proof should pass");
+        assert!(client.verify(&public_inputs, &short_proof), "Compressed R should verify");
+        assert!(client.verify(&short_inputs, &proof), "Compressed C should verify");
+        assert!(client.verify(&short_inputs, &short_proof), "Both compressed should verify");
+
+        // −R and −C are valid points but not the ones the prover committed to
+        assert!(!client.verify(&public_inputs, &flip_sort_flag(&short_proof, 0)));
+        assert!(!client.verify(&flip_sort_flag(&short_inputs, 0), &proof));
     }
 
     #[test]
-    fn test_cangkul_hand_valid_proof_multi_card() {
+    fn test_ring_sigma_compressed_commitment() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
 
-        let hand = [9u32, 10, 11, 27, 28];
-        let blindings = [
-            {let mut a = [0u8; 32]; a[31] = 10; a},
-            {let mut a = [0u8; 32]; a[31] = 20; a},
-            {let mut a = [0u8; 32]; a[31] = 30; a},
-            {let mut a = [0u8; 32]; a[31] = 40; a},
-            {let mut a = [0u8; 32]; a[31] = 50; a},
-        ];
-        let trick_suit = 0u32;
-        let session_id = 200u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &player,
-        );
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 7; arr
+        });
+        let valid_set = [9u32, 10, 11];
+        let (commit_hash, proof) =
+            build_ring_sigma_proof(&env, 10, &blinding, &valid_set, 201, &player);
+        let public_inputs = build_ring_public_inputs(&env, &commit_hash, &valid_set, 201, &player);
+
+        let short = compress_at(&env, &proof, &[0]);
+        assert_eq!(short.len(), 48 + 3 * 64);
+        assert!(client.verify(&public_inputs, &short), "Compressed C should verify");
+        let ring = RingInputs {
+            commit_hash,
+            valid_set: vec![&env, 9u32, 10, 11],
+            session_id: 201,
+            player,
+        };
+        assert!(client.verify_ring(&ring, &short));
 
-        assert!(client.verify(&public_inputs, &proof), "Multi-card cangkul proof should pass");
+        // commit_hash binds the uncompressed C, so −C fails the binding check
+        assert!(!client.verify(&public_inputs, &flip_sort_flag(&short, 0)));
     }
 
     #[test]
-    fn test_cangkul_hand_valid_proof_suit2_excluded() {
+    fn test_cangkul_hand_compressed_points() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
 
-        let hand = [0u32, 1, 27, 28];
-        let blindings = [
-            {let mut a = [0u8; 32]; a[31] = 5; a},
-            {let mut a = [0u8; 32]; a[31] = 15; a},
-            {let mut a = [0u8; 32]; a[31] = 25; a},
-            {let mut a = [0u8; 32]; a[31] = 35; a},
-        ];
-        let trick_suit = 2u32;
-        let session_id = 300u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &player,
-        );
-
-        assert!(client.verify(&public_inputs, &proof));
+        let hand = [9u32, 10, 27];
+        let blindings = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let (commit_hash, proof) =
+            build_cangkul_hand_proof(&env, &hand, &blindings, 0, 301, &player);
+        let public_inputs = build_cangkul_public_inputs(&env, &commit_hash, 0, &hand, 301, &player);
+
+        let a_only = compress_at(&env, &proof, &[4]);
+        let r_only = compress_at(&env, &proof, &[100]);
+        let both = compress_at(&env, &proof, &[4, 100]);
+        assert_eq!((a_only.len(), both.len()), (180, 132));
+        assert!(client.verify(&public_inputs, &a_only), "Compressed A should verify");
+        assert!(client.verify(&public_inputs, &r_only), "Compressed R should verify");
+        assert!(client.verify(&public_inputs, &both), "Both compressed should verify");
+        let inputs = HandInputs {
+            commit_hash,
+            trick_suit: 0,
+            cards: vec![&env, 9u32, 10, 27],
+            session_id: 301,
+            player,
+        };
+        assert!(client.verify_hand(&inputs, &both));
+        assert!(!client.verify(&public_inputs, &flip_sort_flag(&both, 52)));
+
+        // A 132-byte Mode 11 proof is still routed to Mode 11
+        let (root, path) = build_merkle_proof(&env, &MERKLE_HAND, 4, 2);
+        assert_eq!(path.len(), both.len());
+        let pi = merkle_public_inputs(&env, &root, 1, &card_commitment(&env, 16));
+        assert!(client.verify(&pi, &path), "Depth-4 Merkle proof should verify");
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    //  Typed entrypoints (structured public inputs)
+    // ════════════════════════════════════════════════════════════════════════
+
     #[test]
-    fn test_cangkul_hand_suit_violation_fails() {
+    fn test_typed_nizk_matches_byte_layout() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
+        let verifier_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
+        let client = ZkCommitmentVerifierClient::new(&env, &verifier_id);
 
-        let hand = [5u32, 10];
-        let blindings = [
-            {let mut a = [0u8; 32]; a[31] = 42; a},
-            {let mut a = [0u8; 32]; a[31] = 43; a},
-        ];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
+        let player = Address::generate(&env);
+        let seed = BytesN::<32>::from_array(&env, &core::array::from_fn(|i| i as u8 + 1));
+        let blinding = BytesN::<32>::from_array(&env, &[0x5Au8; 32]);
+        let session_id = 77u32;
+        let (public_inputs, proof, commitment) =
+            generate_nizk_proof(&env, &seed, &blinding, session_id, &player);
 
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &player,
-        );
+        let seed_hash = compute_seed_hash(&env, &seed);
+        let inputs = NizkInputs {
+            seed_hash: seed_hash.clone(),
+            commitment,
+            nullifier: compute_nullifier(&env, &seed_hash, session_id),
+            session_id,
+            player,
+        };
+        assert_eq!(inputs.encode(&env), public_inputs);
+        assert!(client.verify_nizk(&inputs, &proof));
 
-        assert!(!client.verify(&public_inputs, &proof), "Card matching trick suit should fail");
+        // A truncated proof is rejected outright instead of being zero-padded
+        assert!(!client.verify_nizk(&inputs, &proof.slice(0..63)));
     }
 
     #[test]
-    fn test_cangkul_hand_wrong_session_fails() {
+    fn test_typed_ring_and_hand_verify() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
+        let contract_id = env.register(ZkCommitmentVerifier, (Address::generate(&env),));
         let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
         let player = Address::generate(&env);
 
-        let hand = [10u32];
-        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, 999, &player,
+        let blinding = BytesN::<32>::from_array(&env, &{
+            let mut arr = [0u8; 32]; arr[31] = 7; arr
+        });
+        let valid_set = [9u32, 10, 11];
+        let (commit_hash, proof) =
+            build_ring_sigma_proof(&env, 10, &blinding, &valid_set, 200, &player);
+        let ring = RingInputs {
+            commit_hash,
+            valid_set: vec![&env, 9u32, 10, 11],
+            session_id: 200,
+            player: player.clone(),
+        };
+        assert_eq!(
+            ring.encode(&env),
+            build_ring_public_inputs(&env, &ring.commit_hash, &valid_set, 200, &player),
         );
-
-        assert!(!client.verify(&public_inputs, &proof), "Wrong session should fail");
+        assert!(client.verify_ring(&ring, &proof));
+
+        // N comes from the set itself, so a proof for another N cannot slip through
+        let short = RingInputs { valid_set: vec![&env, 9u32, 10], ..ring.clone() };
+        assert!(!client.verify_ring(&short, &proof));
+        let empty = RingInputs { valid_set: vec![&env], ..ring };
+        assert!(!client.verify_ring(&empty, &proof));
+
+        let hand = [9u32, 10, 27];
+        let blindings = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let (commit_hash, proof) =
+            build_cangkul_hand_proof(&env, &hand, &blindings, 0, 300, &player);
+        let inputs = HandInputs {
+            commit_hash,
+            trick_suit: 0,
+            cards: vec![&env, 9u32, 10, 27],
+            session_id: 300,
+            player,
+        };
+        assert!(client.verify_hand(&inputs, &proof));
+        let fewer = HandInputs { cards: vec![&env, 9u32, 10], ..inputs };
+        assert!(!client.verify_hand(&fewer, &proof));
     }
+}
+
+/// Tests that need no contract exports, so they also run in the `lib`
+/// build.
+#[cfg(test)]
+mod lib_test {
+    use super::*;
+    use cangkulan_prover::{g1_generator, pedersen_h};
+
+    // ════════════════════════════════════════════════════════════════════════
+    //  Shuffle generators
+    // ════════════════════════════════════════════════════════════════════════
 
     #[test]
-    fn test_cangkul_hand_wrong_player_fails() {
+    fn test_shuffle_generators_match_derivation() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
-        let other = Address::generate(&env);
-
-        let hand = [10u32];
-        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
+        env.cost_estimate().budget().reset_unlimited();
+        let bls = env.crypto().bls12_381();
+        let dst = Bytes::from_slice(&env, PEDERSEN_H_DST);
+        for i in 0..SHUFFLE_DECK_SIZE {
+            let mut msg = Bytes::from_slice(&env, b"SHUFFLE_G");
+            msg.append(&Bytes::from_array(&env, &i.to_be_bytes()));
+            let start = (96 * i) as usize;
+            let g_i = bls.hash_to_g1(&msg, &dst).to_bytes().to_array();
+            assert_eq!(g_i[..], SHUFFLE_GENERATORS[start..start + 96], "generator {}", i);
+        }
+    }
 
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &other,
-        );
+    // ════════════════════════════════════════════════════════════════════════
+    //  Subgroup checks
+    // ════════════════════════════════════════════════════════════════════════
 
-        assert!(!client.verify(&public_inputs, &proof), "Wrong player should fail");
+    /// Subgroup checks stay one per point: (0, 2) has order 3, so two
+    /// points off the subgroup can sum into it. A random linear combination
+    /// would catch this only when the two scalars differ mod 3.
+    #[test]
+    fn test_subgroup_check_cannot_be_batched() {
+        let env = Env::default();
+        let bls = env.crypto().bls12_381();
+        let mut t = [0u8; 96];
+        t[95] = 2;
+        let t = G1Affine::from_array(&env, &t);
+        let p1 = bls.g1_add(&g1_generator(&env), &t);
+        let p2 = bls.g1_add(&pedersen_h(&env), &-t);
+
+        assert!(!bls.g1_is_in_subgroup(&p1) && !bls.g1_is_in_subgroup(&p2));
+        assert!(bls.g1_is_in_subgroup(&bls.g1_add(&p1, &p2)));
+        assert!(bls.g1_is_in_subgroup(&bls.g1_add(&bls.g1_add(&p1, &p1), &bls.g1_add(&p2, &p2))));
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    //  Poseidon Fiat-Shamir tests (`poseidon-fs` feature)
+    // ════════════════════════════════════════════════════════════════════════
+
+    #[cfg(feature = "poseidon-fs")]
     #[test]
-    fn test_cangkul_hand_k_mismatch_fails() {
+    fn test_poseidon2_known_answer() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
+        let (diag, rc) = cangkulan_common::poseidon::params(&env);
+        let mut input: Vec<U256> = Vec::new(&env);
+        for i in 0..3u32 {
+            input.push_back(U256::from_u32(&env, i));
+        }
+        let out = env.crypto_hazmat().poseidon2_permutation(
+            &input,
+            soroban_sdk::Symbol::new(&env, "BLS12_381"),
+            3,
+            5,
+            cangkulan_common::poseidon::ROUNDS_F,
+            cangkulan_common::poseidon::ROUNDS_P,
+            &diag,
+            &rc,
+        );
 
-        let hand = [10u32, 11];
-        let blindings = [
-            {let mut a = [0u8; 32]; a[31] = 42; a},
-            {let mut a = [0u8; 32]; a[31] = 43; a},
+        // Reference vector for Poseidon2 t = 3 over BLS12-381 Fr, input [0, 1, 2]
+        let expected: [[u8; 32]; 3] = [
+            hex32("1b152349b1950b6a8ca75ee4407b6e26ca5cca5650534e56ef3fd45761fbf5f0"),
+            hex32("4c5793c87d51bdc2c08a32108437dc0000bd0275868f09ebc5f36919af5b3891"),
+            hex32("1fc8ed171e67902ca49863159fe5ba6325318843d13976143b8125f08b50dc6b"),
         ];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &[10u32], session_id, &player,
-        );
+        for (i, e) in expected.iter().enumerate() {
+            let got = out.get_unchecked(i as u32).to_be_bytes();
+            assert_eq!(got, Bytes::from_array(&env, e), "lane {}", i);
+        }
+    }
 
-        assert!(!client.verify(&public_inputs, &proof), "k mismatch should fail");
+    #[cfg(feature = "poseidon-fs")]
+    fn hex32(s: &str) -> [u8; 32] {
+        let b = s.as_bytes();
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => c - b'0',
+            _ => c - b'a' + 10,
+        };
+        core::array::from_fn(|i| (nibble(b[2 * i]) << 4) | nibble(b[2 * i + 1]))
     }
 
+    #[cfg(feature = "poseidon-fs")]
     #[test]
-    fn test_cangkul_hand_invalid_trick_suit_fails() {
+    fn test_poseidon_fs_digest_separates_length() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
-
-        let hand = [10u32];
-        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
-        let trick_suit = 5u32;
-        let session_id = 100u32;
-
-        let (commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &commit_hash, trick_suit, &hand, session_id, &player,
-        );
+        // Same chunk values, different byte lengths: the capacity lane differs
+        let short = Bytes::from_array(&env, &[0u8; 31]);
+        let long = Bytes::from_array(&env, &[0u8; 62]);
+        let d_short = transcript::fs_digest(&env, &short);
+        let d_long = transcript::fs_digest(&env, &long);
+        assert_ne!(d_short, d_long);
+        let keccak: BytesN<32> = env.crypto().keccak256(&short).into();
+        assert_ne!(d_short, keccak);
+
+        // Digest is a canonical Fr element
+        assert_eq!(Fr::from_bytes(d_long.clone()).to_bytes(), d_long);
+    }
 
-        assert!(!client.verify(&public_inputs, &proof), "Invalid trick suit should fail");
+    #[cfg(not(feature = "poseidon-fs"))]
+    #[test]
+    fn test_fs_digest_defaults_to_keccak() {
+        let env = Env::default();
+        let pre = Bytes::from_array(&env, &PEDERSEN_CHALLENGE_TAG);
+        let expected: BytesN<32> = env.crypto().keccak256(&pre).into();
+        assert_eq!(transcript::fs_digest(&env, &pre), expected);
     }
 
     #[test]
-    fn test_cangkul_hand_commit_mismatch_fails() {
+    fn test_transcript_reader_and_builder_encodings() {
         let env = Env::default();
-        let contract_id = env.register(ZkCommitmentVerifier, ());
-        let client = ZkCommitmentVerifierClient::new(&env, &contract_id);
-        let player = Address::generate(&env);
+        let mut raw = [0u8; 40];
+        raw[3] = 7;
+        raw[39] = 9;
+        let data = Bytes::from_array(&env, &raw);
+
+        // Cursor and fixed-offset reads agree; reads past the end zero-pad
+        let mut reader = InputsReader::new(&env, &data);
+        assert_eq!(reader.u32(), 7);
+        assert_eq!(reader.digest(), reader_digest_at(&env, &data, 4));
+        assert_eq!(reader.rest(), data.slice(36..40));
+        assert_eq!(reader.rest().len(), 0);
+        assert_eq!(ProofReader::at(&env, &data, 38).u32(), 9 << 16);
+
+        // Scalars >= r and the G1 identity are remembered, not rejected
+        let mut wide = [0xffu8; 128];
+        wide[32] = 0x40;
+        wide[33..].fill(0);
+        let wide = Bytes::from_array(&env, &wide);
+        let mut proof = ProofReader::new(&env, &wide);
+        assert!(proof.scalars_canonical() && !proof.saw_identity());
+        proof.fr();
+        assert!(!proof.scalars_canonical());
+        proof.g1_at(32);
+        assert!(proof.saw_identity());
+
+        // Builder preimage is the plain concatenation followed by the tag
+        let fr = ZkCommitmentVerifier::fr_from_u32(&env, 5);
+        let got = FiatShamir::new(&env)
+            .u32(7)
+            .fr(&fr)
+            .bytes(&data)
+            .challenge_digest(&RANGE_CHALLENGE_TAG);
+        let mut pre = Bytes::from_array(&env, &7u32.to_be_bytes());
+        pre.append(&Bytes::from_array(&env, &fr.to_bytes().to_array()));
+        pre.append(&data);
+        pre.append(&Bytes::from_array(&env, &RANGE_CHALLENGE_TAG));
+        assert_eq!(got, transcript::fs_digest(&env, &pre));
+    }
 
-        let hand = [10u32];
-        let blindings = [{let mut a = [0u8; 32]; a[31] = 42; a}];
-        let trick_suit = 0u32;
-        let session_id = 100u32;
+    fn reader_digest_at(env: &Env, data: &Bytes, offset: u32) -> BytesN<32> {
+        InputsReader::new(env, data).digest_at(offset)
+    }
 
-        let (_commit_hash, proof) = build_cangkul_hand_proof(
-            &env, &hand, &blindings, trick_suit, session_id, &player,
-        );
-        let fake_hash = BytesN::<32>::from_array(&env, &[0xFFu8; 32]);
-        let public_inputs = build_cangkul_public_inputs(
-            &env, &fake_hash, trick_suit, &hand, session_id, &player,
-        );
+    // ════════════════════════════════════════════════════════════════════════
+    //  Embedded routines (`lib` feature)
+    // ════════════════════════════════════════════════════════════════════════
 
-        assert!(!client.verify(&public_inputs, &proof), "Wrong commit hash should fail");
+    #[cfg(feature = "lib")]
+    #[soroban_sdk::contract]
+    struct Embedder;
+
+    /// The per-mode routines verify in-process, in the embedder's context.
+    #[cfg(feature = "lib")]
+    #[test]
+    fn test_lib_routines_verify_in_process() {
+        use cangkulan_prover::{hand, ring, PlayerTail};
+        use soroban_sdk::{testutils::Address as _, Address};
+
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let embedder = env.register(Embedder, ());
+        let tail = PlayerTail::new(&Address::generate(&env));
+        let nonce = [0x5Au8; 32];
+
+        let valid_set = [9u32, 10, 11];
+        let (commit_hash, ring_proof) = ring::prove(&env, 10, &[7u8; 32], &valid_set, &nonce, 200, &tail).unwrap();
+        let ring_inputs = ring::public_inputs(&env, &commit_hash, &valid_set, 200, &tail);
+        let replayed = ring::public_inputs(&env, &commit_hash, &valid_set, 201, &tail);
+
+        let cards = [9u32, 10, 27];
+        let (commit_hash, hand_proof) =
+            hand::prove(&env, &cards, &[[1u8; 32], [2u8; 32], [3u8; 32]], 0, &nonce, 300, &tail).unwrap();
+        let hand_inputs = hand::public_inputs(&env, &commit_hash, 0, &cards, 300, &tail);
+
+        env.as_contract(&embedder, || {
+            assert!(ZkCommitmentVerifier::verify_card_play_ring(&env, &ring_inputs, &ring_proof));
+            assert!(!ZkCommitmentVerifier::verify_card_play_ring(&env, &replayed, &ring_proof));
+            assert!(ZkCommitmentVerifier::verify_cangkul_hand(&env, &hand_inputs, &hand_proof));
+        });
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,88 @@
+//! Proof builders for contracts that call this verifier (`testutils` feature).
+//!
+//! Game contracts checking card plays against the real verifier need proofs
+//! it accepts. These wrap `cangkulan-prover` for the two proofs a game
+//! builds per trick, Mode 7 (`commit_play_zk`) and Mode 8
+//! (`commit_cangkul_zk`), with the public-input tail of a plain
+//! `session_id || player` statement.
+//!
+//! The Schnorr nonce is derived from the public inputs and the blindings,
+//! so a proof is reproducible but no nonce repeats across statements.
+//! Anything beyond that (sha256 transcripts, ledger windows, network
+//! binding) goes through the re-exported prover modules directly.
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+pub use cangkulan_prover::{fr, hand, nizk, pedersen, ring, PlayerTail};
+
+/// Mode 7 `(commit_hash, proof)` for `card_id` committed under `blinding`,
+/// as `commit_play_zk` takes them, or `None` if `card_id` is not in
+/// `valid_set` or the set is too large.
+///
+/// `reveal_play` then opens the commitment with `(card_id, blinding)`.
+pub fn card_ring_proof(
+    env: &Env,
+    card_id: u32,
+    blinding: &[u8; 32],
+    valid_set: &[u32],
+    session_id: u32,
+    player: &Address,
+) -> Option<(BytesN<32>, Bytes)> {
+    let tail = PlayerTail::new(player);
+    let mut statement = ring::public_inputs(env, &BytesN::from_array(env, &[0u8; 32]), valid_set, session_id, &tail);
+    statement.append(&Bytes::from_array(env, &card_id.to_be_bytes()));
+    let nonce = derive_nonce(env, statement, &[*blinding]);
+    ring::prove(env, card_id, blinding, valid_set, &nonce, session_id, &tail)
+}
+
+/// As [`card_ring_proof`], but padded to `RING_MAX_SET_SIZE` members so
+/// the proof has the same length for any `valid_set`. `commit_play_zk`
+/// recognises the padding by that length.
+pub fn card_ring_proof_padded(
+    env: &Env,
+    card_id: u32,
+    blinding: &[u8; 32],
+    valid_set: &[u32],
+    session_id: u32,
+    player: &Address,
+) -> Option<(BytesN<32>, Bytes)> {
+    let tail = PlayerTail::new(player);
+    let mut statement =
+        ring::public_inputs_padded(env, &BytesN::from_array(env, &[0u8; 32]), valid_set, session_id, &tail);
+    statement.append(&Bytes::from_array(env, &card_id.to_be_bytes()));
+    let nonce = derive_nonce(env, statement, &[*blinding]);
+    ring::prove_padded(env, card_id, blinding, valid_set, &nonce, session_id, &tail)
+}
+
+/// Mode 8 `(commit_hash, proof)` that `hand` holds no card of `trick_suit`,
+/// with one blinding per card, as `commit_cangkul_zk` takes them. `None` if
+/// the hand is empty or the counts differ.
+///
+/// `reveal_play` opens the aggregate with the blinding sum, see
+/// [`blinding_sum`].
+pub fn cangkul_hand_proof(
+    env: &Env,
+    hand: &[u32],
+    blindings: &[[u8; 32]],
+    trick_suit: u32,
+    session_id: u32,
+    player: &Address,
+) -> Option<(BytesN<32>, Bytes)> {
+    let tail = PlayerTail::new(player);
+    let statement = hand::public_inputs(env, &BytesN::from_array(env, &[0u8; 32]), trick_suit, hand, session_id, &tail);
+    let nonce = derive_nonce(env, statement, blindings);
+    hand::prove(env, hand, blindings, trick_suit, &nonce, session_id, &tail)
+}
+
+/// `Σ blindings mod r`, the salt that opens a Mode 8 aggregate commitment.
+pub fn blinding_sum(blindings: &[[u8; 32]]) -> [u8; 32] {
+    blindings.iter().fold([0u8; 32], |acc, b| fr::add(&acc, &fr::reduce(b)))
+}
+
+/// `keccak256(statement || blindings…)`: unique per statement and secret.
+fn derive_nonce(env: &Env, mut statement: Bytes, blindings: &[[u8; 32]]) -> [u8; 32] {
+    for blinding in blindings {
+        statement.append(&Bytes::from_array(env, blinding));
+    }
+    env.crypto().keccak256(&statement).to_array()
+}
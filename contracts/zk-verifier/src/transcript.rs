@@ -0,0 +1,346 @@
+//! # Proof encoding and Fiat-Shamir transcripts
+//!
+//! Every mode reads the same few encodings out of `public_inputs`, `proof`
+//! and (for PLONK) the verification key:
+//!
+//! | Value  | Bytes | Encoding                                   |
+//! |--------|-------|--------------------------------------------|
+//! | G1     | 96    | uncompressed affine point                  |
+//! | G1     | 48    | compressed point (Modes 4, 7, 8 and 12)    |
+//! | G2     | 192   | uncompressed affine point                  |
+//! | Fr     | 32    | big-endian, canonical (< r)                |
+//! | u32    | 4     | big-endian                                 |
+//! | digest | 32    | raw keccak256 / `fs_digest` output         |
+//!
+//! `ProofReader`, `InputsReader` and `VkReader` decode them with a cursor
+//! (`g1`, `fr`, `u32`, ...) or at a fixed offset (`g1_at`, `fr_at`, ...).
+//! Modes that accept compressed points read them with `g1_any`, which
+//! expands a 48-byte encoding (compression flag set) in contract code and
+//! otherwise reads 96 bytes as `g1` does.
+//! Reads past the end are zero-padded, so callers must check lengths first.
+//! The reader kinds only differ in type, which keeps a function that takes
+//! a `ProofReader` from being handed the public inputs by mistake.
+//!
+//! `Fr::from_bytes` silently reduces mod r, so `z` and `z + r` would decode
+//! to the same scalar and give a proof two valid encodings. Readers instead
+//! remember every scalar `>= r` and every G1 identity they decode; modes
+//! query `scalars_canonical` and `saw_identity` before accepting. Hashes that
+//! are meant to be reduced (Mode 4's `seed_hash`) are read with `array` and
+//! converted explicitly.
+//!
+//! `FiatShamir` accumulates a challenge preimage in the same encodings and
+//! finishes it with a domain tag:
+//!
+//! ```text
+//! challenge = Fr(fs_digest(preimage || tag))
+//! ```
+//!
+//! Chained challenges (ρ after e, γ after β, ...) start from the previous
+//! digest with `FiatShamir::chained`. A transcript built with
+//! `FiatShamir::using(env, TranscriptHash::Sha256)` finishes with sha256
+//! instead of `fs_digest`; Modes 2, 4, 7, 8 and 12 choose it per proof.
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use soroban_sdk::crypto::bls12_381::{Fr, G1Affine, G2Affine};
+use soroban_sdk::{Bytes, BytesN, Env};
+use cangkulan_common::spec::{G1_COMPRESSED_LEN, G1_COMPRESSION_FLAG};
+
+use crate::fp;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Readers
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Reader kind for the `proof` argument.
+pub(crate) enum Proof {}
+/// Reader kind for the `public_inputs` argument.
+pub(crate) enum Inputs {}
+/// Reader kind for a registered verification key.
+pub(crate) enum Key {}
+
+pub(crate) type ProofReader<'a> = Reader<'a, Proof>;
+pub(crate) type InputsReader<'a> = Reader<'a, Inputs>;
+pub(crate) type VkReader<'a> = Reader<'a, Key>;
+
+/// BLS12-381 scalar field modulus r, big-endian.
+const FR_MODULUS: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08,
+    0x09, 0xa1, 0xd8, 0x05, 0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// Infinity flag of an uncompressed G1 encoding.
+const G1_INFINITY_FLAG: u8 = 0x40;
+
+/// Cursor over one of the byte strings a mode verifies.
+pub(crate) struct Reader<'a, K> {
+    env: &'a Env,
+    data: &'a Bytes,
+    pos: u32,
+    non_canonical: Cell<bool>,
+    identity: Cell<bool>,
+    kind: PhantomData<K>,
+}
+
+impl<'a, K> Reader<'a, K> {
+    pub(crate) fn new(env: &'a Env, data: &'a Bytes) -> Self {
+        Self::at(env, data, 0)
+    }
+
+    /// Reader whose cursor starts at `offset`.
+    pub(crate) fn at(env: &'a Env, data: &'a Bytes, offset: u32) -> Self {
+        Reader {
+            env,
+            data,
+            pos: offset,
+            non_canonical: Cell::new(false),
+            identity: Cell::new(false),
+            kind: PhantomData,
+        }
+    }
+
+    pub(crate) fn len(&self) -> u32 {
+        self.data.len()
+    }
+
+    /// Move the cursor to an absolute offset.
+    pub(crate) fn seek(&mut self, offset: u32) {
+        self.pos = offset;
+    }
+
+    /// Read N bytes, zero-padding anything past the end.
+    pub(crate) fn array<const N: usize>(&mut self) -> [u8; N] {
+        let mut arr = [0u8; N];
+        let start = self.pos;
+        let end = start + N as u32;
+        if end <= self.data.len() {
+            self.data.slice(start..end).copy_into_slice(&mut arr);
+        } else {
+            let mut i = 0usize;
+            while i < N {
+                arr[i] = self.data.get(start + i as u32).unwrap_or(0);
+                i += 1;
+            }
+        }
+        self.pos = end;
+        arr
+    }
+
+    pub(crate) fn g1(&mut self) -> G1Affine {
+        let point = self.g1_at(self.pos);
+        self.pos += 96;
+        point
+    }
+
+    /// G1 point in either encoding, chosen by the compression flag. `None`
+    /// if a compressed point does not decode to a point on the curve.
+    pub(crate) fn g1_any(&mut self) -> Option<G1Affine> {
+        if self.g1_len_at(self.pos) != G1_COMPRESSED_LEN {
+            return Some(self.g1());
+        }
+        let bytes = fp::decompress_g1(&self.array::<48>())?;
+        if bytes[0] & G1_INFINITY_FLAG != 0 {
+            self.identity.set(true);
+        }
+        Some(G1Affine::from_array(self.env, &bytes))
+    }
+
+    pub(crate) fn g2(&mut self) -> G2Affine {
+        G2Affine::from_array(self.env, &self.array::<192>())
+    }
+
+    /// 32-byte big-endian scalar. Values `>= r` are reduced but recorded.
+    pub(crate) fn fr(&mut self) -> Fr {
+        let scalar = self.fr_at(self.pos);
+        self.pos += 32;
+        scalar
+    }
+
+    pub(crate) fn u32(&mut self) -> u32 {
+        u32::from_be_bytes(self.array::<4>())
+    }
+
+    pub(crate) fn digest(&mut self) -> BytesN<32> {
+        BytesN::from_array(self.env, &self.array::<32>())
+    }
+
+    /// Everything from the cursor to the end.
+    pub(crate) fn rest(&mut self) -> Bytes {
+        let rest = self.slice(self.pos.min(self.len())..self.len());
+        self.pos = self.pos.max(self.len());
+        rest
+    }
+
+    pub(crate) fn g1_at(&self, offset: u32) -> G1Affine {
+        let bytes: [u8; 96] = Self::at(self.env, self.data, offset).array();
+        if bytes[0] & G1_INFINITY_FLAG != 0 {
+            self.identity.set(true);
+        }
+        G1Affine::from_array(self.env, &bytes)
+    }
+
+    /// Encoded length of the G1 point at `offset`: 48 if its compression
+    /// flag is set, else 96.
+    pub(crate) fn g1_len_at(&self, offset: u32) -> u32 {
+        if self.data.get(offset).unwrap_or(0) & G1_COMPRESSION_FLAG != 0 {
+            G1_COMPRESSED_LEN
+        } else {
+            96
+        }
+    }
+
+    pub(crate) fn fr_at(&self, offset: u32) -> Fr {
+        let bytes: [u8; 32] = Self::at(self.env, self.data, offset).array();
+        if bytes >= FR_MODULUS {
+            self.non_canonical.set(true);
+        }
+        Fr::from_bytes(BytesN::from_array(self.env, &bytes))
+    }
+
+    pub(crate) fn u32_at(&self, offset: u32) -> u32 {
+        Self::at(self.env, self.data, offset).u32()
+    }
+
+    pub(crate) fn digest_at(&self, offset: u32) -> BytesN<32> {
+        Self::at(self.env, self.data, offset).digest()
+    }
+
+    /// Raw bytes in `range`, for hashing a segment verbatim.
+    pub(crate) fn slice(&self, range: Range<u32>) -> Bytes {
+        self.data.slice(range)
+    }
+
+    /// Whether every scalar read so far was below r.
+    pub(crate) fn scalars_canonical(&self) -> bool {
+        !self.non_canonical.get()
+    }
+
+    /// Whether any G1 point read so far was the identity.
+    pub(crate) fn saw_identity(&self) -> bool {
+        self.identity.get()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//  Fiat-Shamir transcript
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Hash that finishes a challenge, selected by the public inputs'
+/// `TRANSCRIPT_HASH_TAG` trailer in the modes that accept one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TranscriptHash {
+    /// `fs_digest` (keccak256, or Poseidon2 with `poseidon-fs`).
+    Default,
+    /// sha256 in every build.
+    Sha256,
+}
+
+/// Challenge preimage builder shared by every mode.
+pub(crate) struct FiatShamir<'a> {
+    env: &'a Env,
+    preimage: Bytes,
+    hash: TranscriptHash,
+}
+
+impl<'a> FiatShamir<'a> {
+    pub(crate) fn new(env: &'a Env) -> Self {
+        Self::using(env, TranscriptHash::Default)
+    }
+
+    /// Transcript finished with `hash`.
+    pub(crate) fn using(env: &'a Env, hash: TranscriptHash) -> Self {
+        FiatShamir { env, preimage: Bytes::new(env), hash }
+    }
+
+    /// Transcript that starts from a previous challenge digest.
+    pub(crate) fn chained(env: &'a Env, previous: &BytesN<32>) -> Self {
+        let mut transcript = Self::new(env);
+        transcript.digest(previous);
+        transcript
+    }
+
+    pub(crate) fn bytes(&mut self, data: &Bytes) -> &mut Self {
+        self.preimage.append(data);
+        self
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self, data: &[u8; N]) -> &mut Self {
+        self.preimage.append(&Bytes::from_array(self.env, data));
+        self
+    }
+
+    pub(crate) fn g1(&mut self, point: &G1Affine) -> &mut Self {
+        self.array(&point.to_bytes().to_array())
+    }
+
+    pub(crate) fn fr(&mut self, scalar: &Fr) -> &mut Self {
+        self.array(&scalar.to_bytes().to_array())
+    }
+
+    pub(crate) fn u32(&mut self, value: u32) -> &mut Self {
+        self.array(&value.to_be_bytes())
+    }
+
+    pub(crate) fn digest(&mut self, digest: &BytesN<32>) -> &mut Self {
+        self.array(&digest.to_array())
+    }
+
+    /// `fs_digest(preimage || tag)`, for challenges that are chained on.
+    pub(crate) fn challenge_digest(&self, tag: &[u8; 4]) -> BytesN<32> {
+        match self.hash {
+            TranscriptHash::Default => fs_digest(self.env, &self.tagged(tag)),
+            TranscriptHash::Sha256 => self.env.crypto().sha256(&self.tagged(tag)).into(),
+        }
+    }
+
+    /// `Fr(fs_digest(preimage || tag))`.
+    pub(crate) fn challenge(&self, tag: &[u8; 4]) -> Fr {
+        Fr::from_bytes(self.challenge_digest(tag))
+    }
+
+    /// `keccak256(preimage || tag)` regardless of `poseidon-fs`, for Mode 2,
+    /// whose challenge is hashed again rather than read as a field element.
+    /// A sha256 transcript uses sha256 here too.
+    pub(crate) fn hash_challenge(&self, tag: &[u8; 4]) -> BytesN<32> {
+        self.hash.plain(self.env, &self.tagged(tag))
+    }
+
+    fn tagged(&self, tag: &[u8; 4]) -> Bytes {
+        let mut data = self.preimage.clone();
+        data.append(&Bytes::from_array(self.env, tag));
+        data
+    }
+}
+
+impl TranscriptHash {
+    /// keccak256 by default, sha256 when selected. For hashes that are
+    /// compared as bytes rather than read as field elements.
+    pub(crate) fn plain(self, env: &Env, data: &Bytes) -> BytesN<32> {
+        match self {
+            TranscriptHash::Default => env.crypto().keccak256(data).into(),
+            TranscriptHash::Sha256 => env.crypto().sha256(data).into(),
+        }
+    }
+}
+
+/// Fiat-Shamir digest behind every field-valued challenge (Modes 4, 7, 8,
+/// 9, 10, 12, 13, 14, 15); callers read it with `Fr::from_bytes`.
+///
+/// The default build is keccak256. With the `poseidon-fs` feature it is a
+/// Poseidon2 sponge over Fr, so a prover running inside a SNARK circuit
+/// can rebuild the transcript without a keccak gadget. Prover and verifier
+/// must agree on the build; the preimages and domain tags are unchanged.
+#[cfg(not(feature = "poseidon-fs"))]
+pub(crate) fn fs_digest(env: &Env, preimage: &Bytes) -> BytesN<32> {
+    env.crypto().keccak256(preimage).into()
+}
+
+/// Poseidon2 sponge from `cangkulan_common::poseidon`, shared with the
+/// prover so both hash transcripts identically.
+#[cfg(feature = "poseidon-fs")]
+pub(crate) fn fs_digest(env: &Env, preimage: &Bytes) -> BytesN<32> {
+    cangkulan_common::poseidon::sponge(env, preimage)
+}
@@ -215,10 +215,10 @@ if (existsSync(ULTRAHONK_WASM) && existsSync(ULTRAHONK_VK)) {
     deployed['ultrahonk-verifier'] = uhId;
     console.log(`  ✅ ultrahonk-verifier: ${uhId}\n`);
 
-    // Link to cangkulan
-    if (deployed['cangkulan']) {
-      await $`stellar contract invoke --id ${deployed['cangkulan']} --source-account ${admin.secret()} --network ${NETWORK_NAME} -- set_ultrahonk_verifier --verifier_addr ${uhId}`;
-      console.log(`  ✅ Linked ultrahonk-verifier → cangkulan\n`);
+    // Register with the ZK verifier, which routes Noir proofs to it (Mode 22)
+    if (deployed['zk-verifier']) {
+      await $`stellar contract invoke --id ${deployed['zk-verifier']} --source-account ${admin.secret()} --network ${NETWORK_NAME} -- set_ultrahonk_verifier --verifier ${uhId}`;
+      console.log(`  ✅ Linked ultrahonk-verifier → zk-verifier\n`);
     }
   } catch (err) {
     console.warn('⚠️  UltraHonk verifier deployment failed:', err);
@@ -360,17 +360,18 @@ const ULTRAHONK_VK = 'circuits/seed_verify/target/vk';
 
 let ultrahonkId = deployed['ultrahonk-verifier'] || existingContractIds['ultrahonk-verifier'] || '';
 const cangkulanId = deployed['cangkulan'] || existingContractIds['cangkulan'] || '';
+const zkVerifierId = deployed['zk-verifier'] || existingContractIds['zk-verifier'] || '';
 
 if (
   (await Bun.file(ULTRAHONK_WASM).exists()) &&
   (await Bun.file(ULTRAHONK_VK).exists())
 ) {
-  // Deploy UltraHonk verifier if not already deployed (or if cangkulan was just redeployed)
+  // Deploy UltraHonk verifier if not already deployed (or if zk-verifier was just redeployed)
   const needsUltraHonk =
     force ||
     !ultrahonkId ||
     !(await testnetContractExists(ultrahonkId)) ||
-    contracts.some((c) => c.packageName === 'cangkulan');
+    contracts.some((c) => c.packageName === 'zk-verifier');
 
   if (needsUltraHonk) {
     console.log('Deploying UltraHonk verifier (Noir proof verifier)...');
@@ -390,12 +391,12 @@ if (
     console.log(`✅ Using existing ultrahonk-verifier: ${ultrahonkId}\n`);
   }
 
-  // Link UltraHonk verifier to cangkulan contract
-  if (ultrahonkId && cangkulanId) {
-    console.log('Linking UltraHonk verifier to cangkulan contract...');
+  // Register UltraHonk verifier with the ZK verifier (Mode 22 routes Noir proofs to it)
+  if (ultrahonkId && zkVerifierId) {
+    console.log('Linking UltraHonk verifier to zk-verifier contract...');
     try {
-      await $`stellar contract invoke --id ${cangkulanId} --source-account ${adminSecret} --network ${NETWORK} -- set_ultrahonk_verifier --verifier_addr ${ultrahonkId}`;
-      console.log(`✅ cangkulan.set_ultrahonk_verifier → ${ultrahonkId}\n`);
+      await $`stellar contract invoke --id ${zkVerifierId} --source-account ${adminSecret} --network ${NETWORK} -- set_ultrahonk_verifier --verifier ${ultrahonkId}`;
+      console.log(`✅ zk-verifier.set_ultrahonk_verifier → ${ultrahonkId}\n`);
     } catch (error) {
       console.warn('⚠️  Failed to link UltraHonk verifier:', error);
     }